@@ -0,0 +1,186 @@
+// client/src/draft.rs
+// Borradores de formularios largos (APACHE, SAPS) en localStorage: se guardan con retardo
+// al escribir, bajo una clave paciente+escala, y se ofrecen al volver a abrir el formulario
+// para el mismo paciente
+
+use gloo_timers::callback::Timeout;
+use leptos::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+pub const DRAFT_PREFIX: &str = "uci_draft";
+/// Espera tras la última tecla antes de escribir el borrador
+pub const SAVE_DEBOUNCE_MS: u32 = 500;
+
+/// `uci_draft:<escala>:<paciente>`; sin paciente indicado el borrador queda en `new`
+pub fn draft_key(scale: &str, patient_id: &str) -> String {
+    let patient = patient_id.trim();
+    format!("{}:{}:{}", DRAFT_PREFIX, scale, if patient.is_empty() { "new" } else { patient })
+}
+
+/// Contenido guardado: los campos del formulario y cuándo se escribieron
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredDraft<T> {
+    pub saved_at: f64,
+    pub fields: T,
+}
+
+/// Borrador guardado bajo `key` entre las entradas (clave, JSON) de localStorage; uno que
+/// no se puede leer se ignora
+pub fn stored_draft<T: DeserializeOwned>(
+    key: &str,
+    entries: impl IntoIterator<Item = (String, String)>,
+) -> Option<StoredDraft<T>> {
+    entries
+        .into_iter()
+        .find(|(stored, _)| stored == key)
+        .and_then(|(_, json)| serde_json::from_str(&json).ok())
+}
+
+fn storage() -> Option<web_sys::Storage> {
+    web_sys::window().and_then(|w| w.local_storage().ok().flatten())
+}
+
+fn stored_entries(storage: &web_sys::Storage) -> Vec<(String, String)> {
+    let len = storage.length().unwrap_or(0);
+    (0..len)
+        .filter_map(|i| storage.key(i).ok().flatten())
+        .filter_map(|key| Some((key.clone(), storage.get_item(&key).ok().flatten()?)))
+        .collect()
+}
+
+/// Borrador de un formulario de escala abierto para un paciente
+pub struct FormDraft<T: Send + Sync + 'static> {
+    /// Paciente y escala, fijados al abrir el formulario
+    key: StoredValue<String>,
+    /// Borrador encontrado al abrir, pendiente de restaurar o descartar
+    pending: RwSignal<Option<T>>,
+    timer: StoredValue<Option<Timeout>, LocalStorage>,
+}
+
+impl<T: Send + Sync + 'static> Clone for FormDraft<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for FormDraft<T> {}
+
+impl<T> FormDraft<T>
+where
+    T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+{
+    /// Busca el borrador de este paciente y escala para ofrecerlo; el de otro paciente nunca
+    pub fn new(scale: &'static str, patient_id: &str) -> Self {
+        let key = draft_key(scale, patient_id);
+        let pending = storage().and_then(|s| stored_draft::<T>(&key, stored_entries(&s))).map(|draft| draft.fields);
+        Self {
+            key: StoredValue::new(key),
+            pending: RwSignal::new(pending),
+            timer: StoredValue::new_local(None),
+        }
+    }
+
+    pub fn pending(&self) -> Option<T> {
+        self.pending.get()
+    }
+
+    /// Programa el guardado; cada llamada reinicia la espera. Mientras se ofrece un
+    /// borrador anterior no se guarda, para no pisarlo antes de que se decida.
+    pub fn save_debounced(&self, fields: T) {
+        if self.pending.with_untracked(Option::is_some) {
+            return;
+        }
+        let key = self.key.get_value();
+        let timeout = Timeout::new(SAVE_DEBOUNCE_MS, move || {
+            let Some(storage) = storage() else { return };
+            let draft = StoredDraft { saved_at: js_sys::Date::now(), fields };
+            if let Ok(json) = serde_json::to_string(&draft) {
+                let _ = storage.set_item(&key, &json);
+            }
+        });
+        // Reemplazar el temporizador cancela el anterior
+        self.timer.set_value(Some(timeout));
+    }
+
+    /// Acepta el borrador ofrecido; quien llama lo vuelca en el formulario
+    pub fn take(&self) -> Option<T> {
+        let draft = self.pending.get_untracked();
+        self.pending.set(None);
+        draft
+    }
+
+    pub fn discard(&self) {
+        self.pending.set(None);
+        self.clear();
+    }
+
+    /// Borra el borrador guardado (tras un envío correcto)
+    pub fn clear(&self) {
+        self.timer.set_value(None);
+        if let Some(storage) = storage() {
+            let _ = storage.remove_item(&self.key.get_value());
+        }
+    }
+}
+
+/// Aviso para restaurar o descartar un borrador encontrado al abrir el formulario
+#[component]
+pub fn DraftRestoreBanner<T, R>(draft: FormDraft<T>, on_restore: R) -> impl IntoView
+where
+    T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+    R: Fn(T) + Copy + Send + Sync + 'static,
+{
+    move || {
+        draft.pending().map(|_| {
+            view! {
+                <div class="p-3 bg-amber-900/40 border border-amber-600 rounded flex items-center justify-between gap-3">
+                    <p class="text-amber-200 text-sm">"Hay un borrador sin enviar de este formulario. ¿Restaurarlo?"</p>
+                    <div class="flex gap-2">
+                        <button on:click=move |_| {
+                                if let Some(fields) = draft.take() {
+                                    on_restore(fields);
+                                }
+                            }
+                            class="px-3 py-1 bg-amber-600 hover:bg-amber-500 text-white text-sm rounded">
+                            "Restaurar"
+                        </button>
+                        <button on:click=move |_| draft.discard()
+                            class="px-3 py-1 bg-slate-600 hover:bg-slate-500 text-white text-sm rounded">
+                            "Descartar"
+                        </button>
+                    </div>
+                </div>
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_draft_is_found_by_patient_and_scale() {
+        assert_eq!(draft_key("apache", " p1 "), "uci_draft:apache:p1");
+        assert_eq!(draft_key("apache", ""), "uci_draft:apache:new");
+
+        let entry = |key: &str, saved_at: f64, age: &str| {
+            (key.to_string(), serde_json::to_string(&StoredDraft { saved_at, fields: age.to_string() }).unwrap())
+        };
+        let entries = vec![
+            entry("uci_draft:apache:p1", 10.0, "60"),
+            entry("uci_draft:apache:p2", 20.0, "70"),
+            entry("uci_draft:saps:p1", 30.0, "80"),
+            ("uci_draft:apache:roto".to_string(), "{".to_string()),
+            ("uci_token".to_string(), "abc".to_string()),
+        ];
+
+        // El borrador más reciente es de p2, pero al abrir para p1 solo se ofrece el suyo
+        let draft = stored_draft::<String>(&draft_key("apache", "p1"), entries.clone()).unwrap();
+        assert_eq!(draft.fields, "60");
+        assert_eq!(stored_draft::<String>(&draft_key("saps", "p1"), entries.clone()).unwrap().fields, "80");
+        assert!(stored_draft::<String>(&draft_key("apache", ""), entries.clone()).is_none());
+        assert!(stored_draft::<String>("uci_draft:apache:roto", entries).is_none());
+    }
+}
@@ -0,0 +1,301 @@
+// client/src/history_chart.rs
+// Evolución de las escalas del paciente en SVG, sin dependencias de gráficos en JS: la
+// gráfica de una escala, y las tablas por escala con su sparkline del historial
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use serde_json::Value;
+
+use crate::http;
+
+/// Orden de presentación de las escalas; las desconocidas van al final
+pub const SCALES: [&str; 5] = ["SOFA", "Glasgow", "NEWS2", "APACHE II", "SAPS II"];
+
+const WIDTH: f64 = 600.0;
+const HEIGHT: f64 = 240.0;
+const PADDING: f64 = 36.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Low,
+    Moderate,
+    High,
+}
+
+impl Severity {
+    pub fn color(&self) -> &'static str {
+        match self {
+            Severity::Low => "#10b981",
+            Severity::Moderate => "#f59e0b",
+            Severity::High => "#ef4444",
+        }
+    }
+}
+
+/// Banda de gravedad de un total. En Glasgow un valor bajo es el grave.
+pub fn severity(scale: &str, total: i64) -> Severity {
+    match scale {
+        "Glasgow" if total <= 8 => Severity::High,
+        "Glasgow" if total <= 12 => Severity::Moderate,
+        "Glasgow" => Severity::Low,
+        "SOFA" if total >= 12 => Severity::High,
+        "SOFA" if total >= 7 => Severity::Moderate,
+        "NEWS2" if total >= 7 => Severity::High,
+        "NEWS2" if total >= 5 => Severity::Moderate,
+        "APACHE II" if total >= 25 => Severity::High,
+        "APACHE II" if total >= 15 => Severity::Moderate,
+        "SAPS II" if total >= 50 => Severity::High,
+        "SAPS II" if total >= 30 => Severity::Moderate,
+        _ => Severity::Low,
+    }
+}
+
+/// Rango del eje Y de cada escala
+fn score_range(scale: &str) -> (f64, f64) {
+    match scale {
+        "Glasgow" => (3.0, 15.0),
+        "SOFA" => (0.0, 24.0),
+        "APACHE II" => (0.0, 71.0),
+        "SAPS II" => (0.0, 163.0),
+        _ => (0.0, 20.0),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlottedPoint {
+    pub x: f64,
+    pub y: f64,
+    pub total: i64,
+    /// Fecha corta (`dd/mm HH:MM`)
+    pub label: String,
+    pub severity: Severity,
+}
+
+/// Una evaluación del historial tal como se muestra en la tabla de su escala
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    /// Fecha corta (`dd/mm/aaaa HH:MM`)
+    pub label: String,
+    pub total: i64,
+    /// Interpretación, nivel de riesgo o gravedad, más la mortalidad si la escala la predice
+    pub detail: String,
+}
+
+/// Evaluaciones de `scale` con fecha y total, en orden cronológico. APACHE II y SAPS II
+/// guardados antes de tener `total` solo traen `score`.
+fn series<'a>(history: &'a [Value], scale: &str) -> Vec<(chrono::DateTime<chrono::FixedOffset>, i64, &'a Value)> {
+    let mut series: Vec<_> = history
+        .iter()
+        .filter(|a| a.get("scale").and_then(|v| v.as_str()) == Some(scale))
+        .filter_map(|a| {
+            let at = chrono::DateTime::parse_from_rfc3339(a.get("assessed_at")?.as_str()?).ok()?;
+            Some((at, a.get("total").or_else(|| a.get("score"))?.as_i64()?, a))
+        })
+        .collect();
+    series.sort_by_key(|(at, _, _)| *at);
+    series
+}
+
+/// Escalas con evaluaciones en el historial, en el orden de `SCALES`
+pub fn scales_in(history: &[Value]) -> Vec<String> {
+    let mut scales: Vec<String> = history
+        .iter()
+        .filter_map(|a| a.get("scale").and_then(|v| v.as_str()))
+        .filter(|scale| !series(history, scale).is_empty())
+        .map(String::from)
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    scales.sort_by_key(|scale| SCALES.iter().position(|s| s == scale).unwrap_or(SCALES.len()));
+    scales
+}
+
+/// Filas de la tabla de `scale`, en orden cronológico
+pub fn table(history: &[Value], scale: &str) -> Vec<HistoryEntry> {
+    series(history, scale)
+        .into_iter()
+        .map(|(at, total, assessment)| HistoryEntry {
+            label: at.format("%d/%m/%Y %H:%M").to_string(),
+            total,
+            detail: detail(assessment),
+        })
+        .collect()
+}
+
+/// Texto de la escala (interpretación, nivel de riesgo o gravedad) y, en APACHE II y
+/// SAPS II, la mortalidad predicha, que llega como número
+fn detail(assessment: &Value) -> String {
+    let text = ["interpretation", "risk_level", "severity"]
+        .iter()
+        .find_map(|key| assessment.get(*key).and_then(|v| v.as_str()))
+        .map(String::from);
+    let mortality = assessment
+        .get("predicted_mortality")
+        .and_then(|v| v.as_f64())
+        .map(|mortality| format!("mortalidad {:.1}%", mortality));
+    text.into_iter().chain(mortality).collect::<Vec<_>>().join(" · ")
+}
+
+/// Evaluaciones de `scale` en orden cronológico, situadas en el lienzo: el eje X es
+/// proporcional al tiempo transcurrido entre evaluaciones
+pub fn plot(history: &[Value], scale: &str) -> Vec<PlottedPoint> {
+    let points = series(history, scale);
+    let (Some((first, _, _)), Some((last, _, _))) = (points.first(), points.last()) else {
+        return Vec::new();
+    };
+    let span_ms = (*last - *first).num_milliseconds() as f64;
+    let (min, max) = score_range(scale);
+    let plot_width = WIDTH - 2.0 * PADDING;
+    let plot_height = HEIGHT - 2.0 * PADDING;
+
+    points
+        .iter()
+        .map(|(at, total, _)| {
+            let x = if span_ms > 0.0 {
+                PADDING + (*at - *first).num_milliseconds() as f64 / span_ms * plot_width
+            } else {
+                WIDTH / 2.0
+            };
+            let clamped = (*total as f64).clamp(min, max);
+            PlottedPoint {
+                x,
+                y: HEIGHT - PADDING - (clamped - min) / (max - min) * plot_height,
+                total: *total,
+                label: at.format("%d/%m %H:%M").to_string(),
+                severity: severity(scale, *total),
+            }
+        })
+        .collect()
+}
+
+/// Atributo `points` de la línea que une los puntos
+fn polyline(points: &[PlottedPoint]) -> String {
+    points.iter().map(|p| format!("{:.1},{:.1}", p.x, p.y)).collect::<Vec<_>>().join(" ")
+}
+
+/// La línea de la gráfica de `scale` en miniatura, sin ejes ni etiquetas
+#[component]
+pub fn ScaleSparkline(history: Vec<Value>, scale: String) -> impl IntoView {
+    view! {
+        <svg viewBox=format!("0 0 {} {}", WIDTH, HEIGHT) width="160" height="64" class="overflow-visible">
+            <polyline points=polyline(&plot(&history, &scale)) fill="none" stroke="#818cf8" stroke-width="6"/>
+        </svg>
+    }
+}
+
+#[component]
+pub fn ScaleHistoryChart(patient_id: String) -> impl IntoView {
+    // None mientras carga
+    let history = RwSignal::new(None::<Vec<Value>>);
+    let scale = RwSignal::new(SCALES[0].to_string());
+
+    // La página más reciente (descendente); se invierte para mostrarla en orden cronológico
+    let url = format!("/api/patients/{}/history?limit=500&order=desc", patient_id);
+    spawn_local(async move {
+        let mut items = match http::send_with_retry(|| reqwasm::http::Request::get(&url), |_| {}).await {
+            Ok(resp) => resp
+                .json::<Value>()
+                .await
+                .ok()
+                .and_then(|data| data.get("items").and_then(|v| v.as_array()).cloned())
+                .unwrap_or_default(),
+            Err(_) => Vec::new(),
+        };
+        items.reverse();
+        history.set(Some(items));
+    });
+
+    view! {
+        <div class="mt-3 p-4 bg-slate-900 rounded-lg border border-slate-700">
+            <div class="flex gap-2 mb-3">
+                {SCALES.into_iter().map(|name| view! {
+                    <button on:click=move |_| scale.set(name.to_string())
+                        class=move || if scale.get() == name {
+                            "px-3 py-1 rounded text-sm bg-indigo-600 text-white"
+                        } else {
+                            "px-3 py-1 rounded text-sm bg-slate-700 text-slate-300 hover:bg-slate-600"
+                        }>
+                        {name}
+                    </button>
+                }).collect::<Vec<_>>()}
+            </div>
+            {move || {
+                let Some(items) = history.get() else {
+                    return view! { <p class="text-slate-500 text-sm">"Cargando historial..."</p> }.into_any();
+                };
+                let name = scale.get();
+                let points = plot(&items, &name);
+                if points.is_empty() {
+                    return view! {
+                        <p class="text-slate-500 text-sm py-8 text-center">{format!("Sin evaluaciones {} registradas", name)}</p>
+                    }.into_any();
+                }
+
+                let line = polyline(&points);
+                view! {
+                    <svg viewBox=format!("0 0 {} {}", WIDTH, HEIGHT) class="w-full h-auto">
+                        <line x1=PADDING y1=HEIGHT - PADDING x2=WIDTH - PADDING y2=HEIGHT - PADDING stroke="#475569" stroke-width="1"/>
+                        <polyline points=line fill="none" stroke="#818cf8" stroke-width="2"/>
+                        {points.into_iter().map(|p| view! {
+                            <g>
+                                <circle cx=p.x cy=p.y r="5" fill=p.severity.color()>
+                                    <title>{format!("{}: {}", p.label, p.total)}</title>
+                                </circle>
+                                <text x=p.x y=p.y - 10.0 text-anchor="middle" font-size="11" fill="#e2e8f0">{p.total}</text>
+                                <text x=p.x y=HEIGHT - PADDING + 16.0 text-anchor="middle" font-size="10" fill="#94a3b8">{p.label}</text>
+                            </g>
+                        }).collect::<Vec<_>>()}
+                    </svg>
+                }.into_any()
+            }}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_plot_orders_by_time_and_colors_by_band() {
+        let history = vec![
+            json!({ "scale": "SOFA", "total": 12, "assessed_at": "2026-03-01T12:00:00Z" }),
+            json!({ "scale": "SOFA", "total": 2, "assessed_at": "2026-03-01T00:00:00Z" }),
+            json!({ "scale": "Glasgow", "total": 7, "assessed_at": "2026-03-01T06:00:00Z" }),
+            json!({ "scale": "SOFA", "total": 8, "assessed_at": "2026-03-01T06:00:00Z" }),
+        ];
+
+        let points = plot(&history, "SOFA");
+        assert_eq!(points.iter().map(|p| p.total).collect::<Vec<_>>(), vec![2, 8, 12]);
+        assert_eq!((points[0].x, points[2].x), (PADDING, WIDTH - PADDING));
+        assert_eq!(points[1].x, WIDTH / 2.0);
+        assert!(points[2].y < points[0].y);
+        assert_eq!(points.iter().map(|p| p.severity).collect::<Vec<_>>(), vec![Severity::Low, Severity::Moderate, Severity::High]);
+        assert_eq!(points[0].label, "01/03 00:00");
+
+        assert_eq!(plot(&history, "Glasgow")[0].severity, Severity::High);
+        assert!(plot(&history, "NEWS2").is_empty());
+    }
+
+    #[test]
+    fn test_tables_per_scale_in_time_order() {
+        let history = vec![
+            json!({ "scale": "SAPS II", "score": 40, "severity": "Grave", "predicted_mortality": 24.66, "assessed_at": "2026-03-01T08:00:00Z" }),
+            json!({ "scale": "Glasgow", "total": 9, "interpretation": "Moderado", "assessed_at": "2026-03-02T10:00:00Z" }),
+            json!({ "scale": "Glasgow", "total": 6, "interpretation": "Grave", "assessed_at": "2026-03-01T10:00:00Z" }),
+            json!({ "scale": "Otra", "total": 1, "assessed_at": "2026-03-01T10:00:00Z" }),
+            json!({ "scale": "NEWS2", "total": 12, "assessed_at": "no es una fecha" }),
+        ];
+
+        assert_eq!(scales_in(&history), vec!["Glasgow", "SAPS II", "Otra"]);
+        let glasgow = table(&history, "Glasgow");
+        assert_eq!(glasgow.iter().map(|e| e.total).collect::<Vec<_>>(), vec![6, 9]);
+        assert_eq!(glasgow[0].label, "01/03/2026 10:00");
+        assert_eq!(table(&history, "SAPS II")[0].detail, "Grave · mortalidad 24.7%");
+        assert_eq!(glasgow[0].detail, "Grave");
+        assert_eq!(plot(&history, "SAPS II")[0].severity, Severity::Moderate);
+        assert!(scales_in(&[]).is_empty());
+        assert_eq!(polyline(&plot(&history, "Glasgow")), "36.0,162.0 564.0,120.0");
+    }
+}
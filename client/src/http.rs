@@ -0,0 +1,82 @@
+// client/src/http.rs
+// Peticiones que respetan Retry-After en 429/503 en lugar de insistir
+
+use gloo_timers::future::TimeoutFuture;
+use reqwasm::http::{Request, Response};
+
+/// Reintentos máximos antes de devolver la última respuesta 429/503
+pub const MAX_RETRIES: u32 = 3;
+/// Espera máxima aceptada aunque el servidor pida más
+pub const MAX_RETRY_AFTER_SECS: u32 = 60;
+/// Espera cuando el servidor no envía Retry-After
+pub const DEFAULT_RETRY_AFTER_SECS: u32 = 5;
+
+/// Segundos a esperar antes de reintentar, o None si la respuesta no es reintentable.
+/// `Retry-After` puede venir en segundos o como fecha HTTP.
+pub fn retry_after_secs(status: u16, retry_after: Option<&str>, now_ms: f64) -> Option<u32> {
+    if status != 429 && status != 503 {
+        return None;
+    }
+
+    let secs = match retry_after.map(str::trim) {
+        Some(value) => match value.parse::<u32>() {
+            Ok(secs) => secs,
+            Err(_) => match chrono::DateTime::parse_from_rfc2822(value) {
+                Ok(date) => ((date.timestamp_millis() as f64 - now_ms) / 1000.0).ceil().max(0.0) as u32,
+                Err(_) => DEFAULT_RETRY_AFTER_SECS,
+            },
+        },
+        None => DEFAULT_RETRY_AFTER_SECS,
+    };
+
+    Some(secs.clamp(1, MAX_RETRY_AFTER_SECS))
+}
+
+/// Envía la petición construida por `build` y, ante 429/503, espera lo indicado por
+/// `Retry-After` antes de reintentar. `on_wait` recibe la cuenta atrás en segundos
+/// (0 cuando se reintenta o ya no hay espera).
+pub async fn send_with_retry(
+    build: impl Fn() -> Request,
+    on_wait: impl Fn(u32),
+) -> Result<Response, String> {
+    let mut attempt = 0;
+    loop {
+        let resp = build().send().await.map_err(|e| e.to_string())?;
+        let header = resp.headers().get("Retry-After");
+        let wait = retry_after_secs(resp.status(), header.as_deref(), js_sys::Date::now());
+
+        match wait {
+            Some(secs) if attempt < MAX_RETRIES => {
+                attempt += 1;
+                for remaining in (1..=secs).rev() {
+                    on_wait(remaining);
+                    TimeoutFuture::new(1_000).await;
+                }
+                on_wait(0);
+            }
+            _ => {
+                on_wait(0);
+                return Ok(resp);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_after_parsing() {
+        assert_eq!(retry_after_secs(200, Some("10"), 0.0), None);
+        assert_eq!(retry_after_secs(429, Some("10"), 0.0), Some(10));
+        assert_eq!(retry_after_secs(503, None, 0.0), Some(DEFAULT_RETRY_AFTER_SECS));
+        assert_eq!(retry_after_secs(429, Some("3600"), 0.0), Some(MAX_RETRY_AFTER_SECS));
+        assert_eq!(retry_after_secs(429, Some("0"), 0.0), Some(1));
+
+        // Fecha HTTP: 30 s después de "ahora"
+        let now = chrono::DateTime::parse_from_rfc2822("Wed, 21 Oct 2026 07:28:00 GMT").unwrap();
+        let header = "Wed, 21 Oct 2026 07:28:30 GMT";
+        assert_eq!(retry_after_secs(429, Some(header), now.timestamp_millis() as f64), Some(30));
+    }
+}
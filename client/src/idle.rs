@@ -0,0 +1,57 @@
+// client/src/idle.rs
+// Cierre de sesión por inactividad para estaciones compartidas de la UCI
+
+/// Clave de localStorage con los minutos de inactividad permitidos
+pub const TIMEOUT_STORAGE_KEY: &str = "olympus_idle_timeout_minutes";
+pub const DEFAULT_TIMEOUT_MINUTES: u32 = 15;
+/// Antelación del aviso antes del cierre
+pub const WARNING_SECS: u32 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleState {
+    Active,
+    /// Segundos que faltan para el cierre
+    Warning(u32),
+    Expired,
+}
+
+/// Minutos configurados en localStorage, o el valor por defecto si no hay uno válido
+pub fn timeout_minutes() -> u32 {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(TIMEOUT_STORAGE_KEY).ok().flatten())
+        .and_then(|raw| raw.trim().parse().ok())
+        .filter(|minutes| *minutes > 0)
+        .unwrap_or(DEFAULT_TIMEOUT_MINUTES)
+}
+
+/// Estado tras `idle_ms` sin interacción. El aviso nunca ocupa más de la mitad de la
+/// ventana, para que timeouts muy cortos no empiecen ya avisando.
+pub fn idle_state(idle_ms: f64, timeout_minutes: u32) -> IdleState {
+    let timeout_ms = timeout_minutes as f64 * 60_000.0;
+    let warning_ms = (WARNING_SECS as f64 * 1000.0).min(timeout_ms / 2.0);
+
+    if idle_ms >= timeout_ms {
+        IdleState::Expired
+    } else if idle_ms >= timeout_ms - warning_ms {
+        IdleState::Warning(((timeout_ms - idle_ms) / 1000.0).ceil() as u32)
+    } else {
+        IdleState::Active
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idle_state_windows() {
+        assert_eq!(idle_state(0.0, 15), IdleState::Active);
+        assert_eq!(idle_state(13.0 * 60_000.0, 15), IdleState::Active);
+        assert_eq!(idle_state(14.0 * 60_000.0 + 30_000.0, 15), IdleState::Warning(30));
+        assert_eq!(idle_state(15.0 * 60_000.0, 15), IdleState::Expired);
+        // Ventana de 1 minuto: el aviso empieza a los 30 s
+        assert_eq!(idle_state(20_000.0, 1), IdleState::Active);
+        assert_eq!(idle_state(40_000.0, 1), IdleState::Warning(20));
+    }
+}
@@ -3,33 +3,70 @@ use leptos::task::spawn_local;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::JsCast;
 
+pub mod draft;
+pub mod history_chart;
+pub mod http;
+pub mod idle;
+
 // ============================================
 // MODELS
 // ============================================
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Patient {
+    /// Se omite al enviar: el servidor lo asigna y un parche no puede cambiarlo
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     pub first_name: String,
     pub last_name: String,
     pub identity_card: String,
     pub principal_diagnosis: String,
+    /// Variante de `SkinColor` del servidor (ver `SKIN_COLORS`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skin_color: Option<String>,
+    /// Variante de `AdmissionType` del servidor (ver `ADMISSION_TYPES`); la usa SAPS II
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admission_type: Option<String>,
 }
 
+/// Valores de `olympus_core::patient::SkinColor` con su etiqueta, en el orden del enum
+pub const SKIN_COLORS: [(&str, &str); 6] = [
+    ("VeryFair", "Muy clara"),
+    ("Fair", "Clara"),
+    ("Olive", "Oliva"),
+    ("Brown", "Morena"),
+    ("DarkBrown", "Morena oscura"),
+    ("Black", "Negra"),
+];
+pub const DEFAULT_SKIN_COLOR: &str = "Fair";
+
+/// Valores de `olympus_core::patient::AdmissionType` con su etiqueta
+pub const ADMISSION_TYPES: [(&str, &str); 3] = [("Elective", "Electivo"), ("Urgent", "Urgente"), ("Transfer", "Traslado")];
+pub const DEFAULT_ADMISSION_TYPE: &str = "Urgent";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthResponse {
     pub success: bool,
     pub token: Option<String>,
     pub username: Option<String>,
+    #[serde(default)]
     pub message: String,
+    /// Motivo del rechazo (respuestas 4xx/5xx del servidor)
+    #[serde(default)]
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OtpResponse {
     pub success: bool,
     pub session_id: Option<String>,
+    #[serde(default)]
     pub message: String,
+    #[serde(default)]
+    pub error: Option<String>,
     pub requires_otp: Option<bool>,
+    /// Solo en desarrollo; en producción el código llega por Iris
+    pub otp_code: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -54,6 +91,24 @@ pub struct Theme {
     pub border_radius: String,
 }
 
+/// Olympus Dark compilado en el cliente: se aplica al arrancar para que las variables CSS
+/// nunca queden sin definir aunque el backend no responda
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            name: "Olympus Dark".to_string(),
+            primary_color: "#6366f1".to_string(),
+            secondary_color: "#8b5cf6".to_string(),
+            background: "#0f172a".to_string(),
+            surface: "#1e293b".to_string(),
+            text_primary: "#f8fafc".to_string(),
+            text_secondary: "#94a3b8".to_string(),
+            accent: "#f59e0b".to_string(),
+            border_radius: "0.75rem".to_string(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThemesResponse {
     pub themes: Vec<String>,
@@ -64,9 +119,118 @@ pub struct ThemesResponse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CurrentThemeResponse {
     pub theme: Theme,
+    /// Revisión del tema en el servidor; ausente si nunca se cambió
+    #[serde(default)]
+    pub revision: u64,
     pub controlled_by: String,
 }
 
+/// Respuesta de `POST /api/aphrodite/theme`: el tema vigente tras el cambio
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwitchThemeResponse {
+    pub success: bool,
+    pub theme: Option<Theme>,
+    #[serde(default)]
+    pub revision: u64,
+    pub message: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Componente de `GET /api/aphrodite/components`; solo se inyectan los `overrides`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentStyles {
+    pub id: String,
+    #[serde(default)]
+    pub overrides: std::collections::HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentsResponse {
+    pub components: Vec<ComponentStyles>,
+}
+
+/// Id de la hoja de estilos que Aphrodite inyecta en `<head>`
+const COMPONENT_STYLE_ID: &str = "aphrodite-components";
+
+/// Elementos a los que se aplica cada componente. El prefijo `#app-container` da más
+/// especificidad que las clases de Tailwind, así que la personalización prevalece.
+fn component_selector(id: &str) -> Option<&'static str> {
+    match id {
+        "button" => Some("#app-container button"),
+        "card" => Some("#app-container .olympus-card"),
+        "nav" => Some("#app-container nav"),
+        _ => None,
+    }
+}
+
+/// `borderRadius` → `border-radius`
+fn css_property(style_key: &str) -> String {
+    style_key.chars().fold(String::new(), |mut property, c| {
+        if c.is_ascii_uppercase() {
+            property.push('-');
+            property.push(c.to_ascii_lowercase());
+        } else {
+            property.push(c);
+        }
+        property
+    })
+}
+
+/// Hoja CSS con las personalizaciones; componentes sin selector conocido se ignoran
+pub fn component_css(components: &[ComponentStyles]) -> String {
+    let mut css = String::new();
+    for component in components {
+        let Some(selector) = component_selector(&component.id) else { continue };
+        if component.overrides.is_empty() {
+            continue;
+        }
+        let mut declarations: Vec<String> = component
+            .overrides
+            .iter()
+            .map(|(key, value)| format!("  {}: {};", css_property(key), value))
+            .collect();
+        declarations.sort();
+        css.push_str(&format!("{} {{\n{}\n}}\n", selector, declarations.join("\n")));
+    }
+    css
+}
+
+fn apply_component_styles(css: &str) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else { return };
+    let style = match document.get_element_by_id(COMPONENT_STYLE_ID) {
+        Some(existing) => existing,
+        None => {
+            let Ok(created) = document.create_element("style") else { return };
+            created.set_id(COMPONENT_STYLE_ID);
+            if let Some(head) = document.head() {
+                let _ = head.append_child(&created);
+            }
+            created
+        }
+    };
+    style.set_text_content(Some(css));
+}
+
+async fn load_component_styles() {
+    if let Ok(resp) = http::send_with_retry(|| reqwasm::http::Request::get("/api/aphrodite/components"), |_| {}).await {
+        if let Ok(data) = resp.json::<ComponentsResponse>().await {
+            apply_component_styles(&component_css(&data.components));
+        }
+    }
+}
+
+/// Aplica el tema solo si es al menos tan reciente como el ya aplicado: respuestas
+/// que llegan desordenadas tras cambios rápidos no revierten a un tema superado
+fn reconcile_theme(theme: &Theme, revision: u64, applied_revision: RwSignal<u64>, current_theme: RwSignal<String>) -> bool {
+    if revision < applied_revision.get_untracked() {
+        return false;
+    }
+    applied_revision.set(revision);
+    apply_theme_to_document(theme);
+    current_theme.set(theme.name.clone());
+    true
+}
+
 // ============================================
 // MAIN APP
 // ============================================
@@ -76,26 +240,99 @@ pub fn App() -> impl IntoView {
     let page = RwSignal::new("/".to_string());
     let is_logged_in = RwSignal::new(false);
     let current_user = RwSignal::new(String::new());
-    let current_theme = RwSignal::new("Olympus Dark".to_string());
-    
-    // Cargar tema actual al iniciar
+    let auth_token = RwSignal::new(String::new());
+    // Inactividad: última interacción (ms) y segundos restantes mientras se avisa
+    let last_activity = RwSignal::new(js_sys::Date::now());
+    let idle_warning = RwSignal::new(None::<u32>);
+    // Cada login invalida el temporizador del anterior
+    let session_generation = RwSignal::new(0u64);
+    let default_theme = Theme::default();
+    let current_theme = RwSignal::new(default_theme.name.clone());
+    // Revisión del servidor del tema aplicado en este cliente
+    let theme_revision = RwSignal::new(0u64);
+
+    // Tema por defecto de inmediato; el del servidor lo reemplaza si la petición responde
+    apply_theme_to_document(&default_theme);
     spawn_local(async move {
-        if let Ok(resp) = reqwasm::http::Request::get("/api/aphrodite/theme").send().await {
+        if let Ok(resp) = http::send_with_retry(|| reqwasm::http::Request::get("/api/aphrodite/theme"), |_| {}).await {
             if let Ok(data) = resp.json::<CurrentThemeResponse>().await {
-                let theme_name = data.theme.name.clone();
-                // Aplicar CSS variables al documento
-                apply_theme_to_document(&data.theme);
-                current_theme.set(theme_name);
+                reconcile_theme(&data.theme, data.revision, theme_revision, current_theme);
             }
         }
     });
+    // Estilos por componente: al arrancar y en cada navegación, para recoger cambios
+    Effect::new(move |_| {
+        page.track();
+        spawn_local(load_component_styles());
+    });
+
+    let logout = move || {
+        let token = auth_token.get_untracked();
+        if !token.is_empty() {
+            spawn_local(async move {
+                let _ = reqwasm::http::Request::post("/api/logout")
+                    .header("Authorization", &format!("Bearer {}", token))
+                    .send()
+                    .await;
+            });
+        }
+        auth_token.set(String::new());
+        idle_warning.set(None);
+        is_logged_in.set(false);
+        page.set("/".to_string());
+    };
+
+    // Cualquier interacción reinicia la cuenta (como mucho una vez por segundo)
+    for event in ["mousemove", "mousedown", "keydown", "scroll", "touchstart"] {
+        window_event_listener_untyped(event, move |_| {
+            let now = js_sys::Date::now();
+            if now - last_activity.get_untracked() > 1_000.0 {
+                last_activity.set(now);
+            }
+        });
+    }
+
+    let start_idle_timer = move || {
+        session_generation.update(|g| *g += 1);
+        let generation = session_generation.get_untracked();
+        last_activity.set(js_sys::Date::now());
+        let timeout_minutes = idle::timeout_minutes();
+
+        spawn_local(async move {
+            while is_logged_in.get_untracked() && session_generation.get_untracked() == generation {
+                gloo_timers::future::TimeoutFuture::new(1_000).await;
+                match idle::idle_state(js_sys::Date::now() - last_activity.get_untracked(), timeout_minutes) {
+                    idle::IdleState::Active => idle_warning.set(None),
+                    idle::IdleState::Warning(secs) => idle_warning.set(Some(secs)),
+                    idle::IdleState::Expired => {
+                        if is_logged_in.get_untracked() && session_generation.get_untracked() == generation {
+                            logout();
+                        }
+                        break;
+                    }
+                }
+            }
+        });
+    };
 
     view! {
         <div class="min-h-screen bg-slate-900" id="app-container">
+            {move || idle_warning.get().map(|secs| view! {
+                <div class="fixed bottom-4 right-4 z-50 bg-amber-600 text-white px-4 py-3 rounded shadow-lg flex items-center gap-3">
+                    <span>{format!("⏳ Sesión inactiva: se cerrará en {} s", secs)}</span>
+                    <button on:click=move |_| { last_activity.set(js_sys::Date::now()); idle_warning.set(None); }
+                        class="px-2 py-1 bg-amber-800 rounded hover:bg-amber-700">"Seguir conectado"</button>
+                </div>
+            })}
             {move || {
                 if !is_logged_in.get() {
                     view! { 
-                        <LoginPage on_login=move |u: String, _t: String| { is_logged_in.set(true); current_user.set(u); }/> 
+                        <LoginPage on_login=move |u: String, t: String| {
+                            is_logged_in.set(true);
+                            current_user.set(u);
+                            auth_token.set(t);
+                            start_idle_timer();
+                        }/> 
                     }.into_any()
                 } else {
                     view! {
@@ -117,7 +354,7 @@ pub fn App() -> impl IntoView {
                                         <span>"✨"</span>
                                         <span>"Aphrodite"</span>
                                     </button>
-                                    <button on:click=move |_| is_logged_in.set(false) class="px-3 py-1 bg-red-600 rounded hover:bg-red-500">Salir</button>
+                                    <button on:click=move |_| logout() class="px-3 py-1 bg-red-600 rounded hover:bg-red-500">Salir</button>
                                 </div>
                             </nav>
                             <main class="p-6 max-w-7xl mx-auto">
@@ -125,7 +362,7 @@ pub fn App() -> impl IntoView {
                                     match page.get().as_str() {
                                         "/patients" => view! { <PatientPage/> }.into_any(),
                                         "/scales" => view! { <ScalesPage/> }.into_any(),
-                                        "/aphrodite" => view! { <AphroditePage current_theme={current_theme}/> }.into_any(),
+                                        "/aphrodite" => view! { <AphroditePage current_theme={current_theme} theme_revision={theme_revision}/> }.into_any(),
                                         "/gods" => view! { <OlympusMonitor/> }.into_any(),
                                         _ => view! { <Dashboard/> }.into_any(),
                                     }
@@ -153,25 +390,40 @@ where F: Fn(String, String) + Clone + Send + Sync + 'static
     let step = RwSignal::new(1i32);
     let message = RwSignal::new(String::new());
     let loading = RwSignal::new(false);
+    // session_id devuelto por el paso 1; el paso 2 lo canjea por el token
+    let session_id = RwSignal::new(String::new());
+    // Código OTP devuelto por el servidor en desarrollo
+    let dev_otp = RwSignal::new(None::<String>);
+    // Segundos hasta el reintento automático tras un 429/503
+    let retry_in = RwSignal::new(0u32);
 
     let do_login = move |_| {
         loading.set(true);
+        message.set(String::new());
         let user = username.get();
+        let pass = password.get();
         
         spawn_local(async move {
-            let res = reqwasm::http::Request::post("/api/login_step1")
-                .header("Content-Type", "application/json")
-                .body(serde_json::json!({"username": user, "password": "admin123"}).to_string())
-                .send().await;
+            let body = serde_json::json!({"username": user, "password": pass}).to_string();
+            let res = http::send_with_retry(
+                || reqwasm::http::Request::post("/api/login_step1")
+                    .header("Content-Type", "application/json")
+                    .body(body.clone()),
+                move |secs| retry_in.set(secs),
+            ).await;
             
             loading.set(false);
             
             if let Ok(resp) = res {
                 if let Ok(data) = resp.json::<OtpResponse>().await {
                     if data.success {
+                        // La contraseña no se conserva en memoria una vez aceptada
+                        password.set(String::new());
+                        session_id.set(data.session_id.unwrap_or_default());
+                        dev_otp.set(data.otp_code);
                         step.set(2);
-                        message.set("Codigo OTP: 123456".to_string());
                     }
+                    message.set(data.error.unwrap_or(data.message));
                 }
             }
         });
@@ -182,19 +434,22 @@ where F: Fn(String, String) + Clone + Send + Sync + 'static
         let callback = on_login.clone();
         
         spawn_local(async move {
-            let res = reqwasm::http::Request::post("/api/login_step2")
-                .header("Content-Type", "application/json")
-                .body(serde_json::json!({"session_id": "session_123", "otp_code": otp.get()}).to_string())
-                .send().await;
+            let body = serde_json::json!({"session_id": session_id.get(), "otp_code": otp.get()}).to_string();
+            let res = http::send_with_retry(
+                || reqwasm::http::Request::post("/api/login_step2")
+                    .header("Content-Type", "application/json")
+                    .body(body.clone()),
+                move |secs| retry_in.set(secs),
+            ).await;
             
             loading.set(false);
             
             if let Ok(resp) = res {
                 if let Ok(data) = resp.json::<AuthResponse>().await {
                     if data.success {
-                        callback("admin".to_string(), "token".to_string());
+                        callback(data.username.unwrap_or_default(), data.token.unwrap_or_default());
                     } else {
-                        message.set(data.message);
+                        message.set(data.error.unwrap_or(data.message));
                     }
                 }
             }
@@ -221,7 +476,12 @@ where F: Fn(String, String) + Clone + Send + Sync + 'static
                         } else {
                             view! {
                                 <div>
-                                    <p class="text-indigo-300 text-sm mb-2 text-center">"Codigo OTP: 123456"</p>
+                                    <p class="text-indigo-300 text-sm mb-2 text-center">
+                                        {move || match dev_otp.get() {
+                                            Some(code) => format!("Codigo OTP (desarrollo): {}", code),
+                                            None => "Introduzca el codigo OTP recibido".to_string(),
+                                        }}
+                                    </p>
                                     <input type="text" placeholder="Codigo OTP" maxlength="6"
                                         on:input=move |e| otp.set(event_target_value(&e))
                                         class="w-full p-3 bg-slate-700 border border-slate-600 rounded text-white text-center text-2xl tracking-widest"/>
@@ -231,7 +491,10 @@ where F: Fn(String, String) + Clone + Send + Sync + 'static
                     }}
                     
                     {move || {
-                        if !message.get().is_empty() {
+                        let secs = retry_in.get();
+                        if secs > 0 {
+                            view! { <p class="text-amber-400 text-sm text-center">{format!("Demasiados intentos. Reintentando en {}s...", secs)}</p> }.into_any()
+                        } else if !message.get().is_empty() {
                             view! { <p class="text-amber-400 text-sm text-center">{message.get()}</p> }.into_any()
                         } else { view! { <div></div> }.into_any() }
                     }}
@@ -239,7 +502,7 @@ where F: Fn(String, String) + Clone + Send + Sync + 'static
                     <button on:click=move |ev| { if step.get() == 1 { do_login(ev); } else { do_verify(ev); } } 
                         disabled={loading.get()}
                         class="w-full py-3 bg-indigo-600 text-white rounded disabled:opacity-50">
-                        {move || if loading.get() { "Procesando..." } else { if step.get() == 1 { "Continuar" } else { "Verificar" } }}
+                        {move || if retry_in.get() > 0 { "Reintentando..." } else if loading.get() { "Procesando..." } else { if step.get() == 1 { "Continuar" } else { "Verificar" } }}
                     </button>
                 </div>
             </div>
@@ -257,25 +520,25 @@ fn Dashboard() -> impl IntoView {
         <div class="space-y-6">
             <h2 class="text-3xl font-bold text-white text-center">Panel de Control UCI</h2>
             <div class="grid grid-cols-4 gap-4">
-                <div class="bg-slate-800 p-6 rounded-xl border border-slate-700 text-center">
+                <div class="olympus-card bg-slate-800 p-6 rounded-xl border border-slate-700 text-center">
                     <p class="text-indigo-300">Pacientes</p>
                     <p class="text-4xl text-white font-bold">0</p>
                 </div>
-                <div class="bg-slate-800 p-6 rounded-xl border border-slate-700 text-center">
+                <div class="olympus-card bg-slate-800 p-6 rounded-xl border border-slate-700 text-center">
                     <p class="text-blue-300">En UCI</p>
                     <p class="text-4xl text-white font-bold">0</p>
                 </div>
-                <div class="bg-slate-800 p-6 rounded-xl border border-red-500/30 text-center">
+                <div class="olympus-card bg-slate-800 p-6 rounded-xl border border-red-500/30 text-center">
                     <p class="text-red-300">Criticos</p>
                     <p class="text-4xl text-red-400 font-bold">0</p>
                 </div>
-                <div class="bg-slate-800 p-6 rounded-xl border border-green-500/30 text-center">
+                <div class="olympus-card bg-slate-800 p-6 rounded-xl border border-green-500/30 text-center">
                     <p class="text-green-300">Estables</p>
                     <p class="text-4xl text-green-400 font-bold">0</p>
                 </div>
             </div>
             
-            <div class="bg-slate-800 p-6 rounded-xl border border-slate-700 mt-8">
+            <div class="olympus-card bg-slate-800 p-6 rounded-xl border border-slate-700 mt-8">
                 <div class="flex items-center justify-between">
                     <div>
                         <p class="text-white font-bold text-xl">Estado del Olimpo</p>
@@ -299,13 +562,17 @@ fn Dashboard() -> impl IntoView {
 fn PatientPage() -> impl IntoView {
     let patients = RwSignal::new(Vec::<Patient>::new());
     let show_form = RwSignal::new(false);
+    // Paciente en edición; None con el formulario abierto es un alta
+    let editing = RwSignal::new(None::<Patient>);
+    // Paciente cuyo historial se está viendo
+    let viewing = RwSignal::new(None::<Patient>);
     let message = RwSignal::new(String::new());
     
     let load_patients = move || {
         spawn_local(async move {
-            if let Ok(resp) = reqwasm::http::Request::get("/api/patients").send().await {
+            if let Ok(resp) = http::send_with_retry(|| reqwasm::http::Request::get("/api/patients"), |_| {}).await {
                 if let Ok(data) = resp.json::<serde_json::Value>().await {
-                    if let Some(list) = data.get("patients").and_then(|v| v.as_array()) {
+                    if let Some(list) = data.get("items").and_then(|v| v.as_array()) {
                         let parsed: Vec<Patient> = list.iter().filter_map(|x| serde_json::from_value(x.clone()).ok()).collect();
                         patients.set(parsed);
                     }
@@ -320,16 +587,19 @@ fn PatientPage() -> impl IntoView {
         <div class="space-y-6">
             <div class="flex justify-between items-center">
                 <h2 class="text-2xl text-white font-bold">Pacientes</h2>
-                <button on:click=move |_| show_form.set(true)
+                <button on:click=move |_| { viewing.set(None); editing.set(None); show_form.set(true); }
                     class="px-4 py-2 bg-indigo-600 text-white rounded hover:bg-indigo-500">
                     Nuevo Paciente
                 </button>
             </div>
             
             {move || {
-                if show_form.get() {
+                if let Some(patient) = viewing.get() {
+                    view! { <PatientHistory patient=patient on_back=move || viewing.set(None)/> }.into_any()
+                } else if show_form.get() {
                     view! { 
                         <PatientForm 
+                            patient=editing.get()
                             on_save=move || { show_form.set(false); load_patients(); message.set("Paciente guardado".to_string()); }
                             on_cancel=move || show_form.set(false)
                         /> 
@@ -342,7 +612,9 @@ fn PatientPage() -> impl IntoView {
                                     view! { <p class="text-green-400 mb-4">{message.get()}</p> }.into_any()
                                 } else { view! { <div></div> }.into_any() }
                             }}
-                            <PatientList patients={patients.get()} on_reload={load_patients}/>
+                            <PatientList patients={patients.get()} on_reload={load_patients}
+                                on_select=move |patient| viewing.set(Some(patient))
+                                on_edit=move |patient| { editing.set(Some(patient)); show_form.set(true); }/>
                         </>
                     }.into_any()
                 }
@@ -352,11 +624,17 @@ fn PatientPage() -> impl IntoView {
 }
 
 #[component]
-fn PatientList(patients: Vec<Patient>, on_reload: impl Fn() + 'static + Clone) -> impl IntoView {
+fn PatientList(
+    patients: Vec<Patient>,
+    on_reload: impl Fn() + 'static + Clone,
+    /// Clic en el nombre: abre el historial del paciente
+    on_select: impl Fn(Patient) + 'static + Clone,
+    on_edit: impl Fn(Patient) + 'static + Clone,
+) -> impl IntoView {
     let reload = on_reload.clone();
     
     view! {
-        <div class="bg-slate-800 rounded-xl border border-slate-700">
+        <div class="olympus-card bg-slate-800 rounded-xl border border-slate-700">
             {if patients.is_empty() {
                 view! {
                     <div class="p-8 text-center text-slate-500">
@@ -368,14 +646,31 @@ fn PatientList(patients: Vec<Patient>, on_reload: impl Fn() + 'static + Clone) -
                     <div class="divide-y divide-slate-700">
                         {patients.into_iter().map(|p| {
                             let id = p.id.clone().unwrap_or_default();
+                            let history_id = id.clone();
                             let reload = reload.clone();
+                            let on_edit = on_edit.clone();
+                            let on_select = on_select.clone();
+                            let selected = p.clone();
+                            let viewed = p.clone();
+                            let show_history = RwSignal::new(false);
                             view! {
-                                <div class="p-4 flex justify-between items-center">
-                                    <div>
+                                <div class="p-4">
+                                <div class="flex justify-between items-center">
+                                    <div on:click=move |_| on_select(viewed.clone())
+                                        class="cursor-pointer hover:opacity-80" title="Ver historial de escalas">
                                         <p class="text-white font-medium">{format!("{} {}", p.first_name, p.last_name)}</p>
                                         <p class="text-slate-500 text-sm">{p.identity_card.clone()}</p>
                                         <p class="text-slate-400 text-sm">{p.principal_diagnosis.clone()}</p>
                                     </div>
+                                    <div class="flex gap-2">
+                                    <button on:click=move |_| show_history.update(|shown| *shown = !*shown)
+                                        class="px-3 py-1 bg-indigo-600/20 text-indigo-300 rounded hover:bg-indigo-600/30">
+                                        Historial
+                                    </button>
+                                    <button on:click=move |_| on_edit(selected.clone())
+                                        class="px-3 py-1 bg-slate-600/20 text-slate-300 rounded hover:bg-slate-600/30">
+                                        Editar
+                                    </button>
                                     <button on:click=move |_| {
                                         let id = id.clone();
                                         let reload = reload.clone();
@@ -387,6 +682,11 @@ fn PatientList(patients: Vec<Patient>, on_reload: impl Fn() + 'static + Clone) -
                                         class="px-3 py-1 bg-red-600/20 text-red-400 rounded hover:bg-red-600/30">
                                         Eliminar
                                     </button>
+                                    </div>
+                                </div>
+                                {move || show_history.get().then(|| view! {
+                                    <history_chart::ScaleHistoryChart patient_id=history_id.clone()/>
+                                })}
                                 </div>
                             }
                         }).collect::<Vec<_>>()}
@@ -397,17 +697,163 @@ fn PatientList(patients: Vec<Patient>, on_reload: impl Fn() + 'static + Clone) -
     }
 }
 
+// ============================================
+// PATIENT HISTORY
+// ============================================
+
+#[component]
+fn PatientHistory(patient: Patient, on_back: impl Fn() + 'static) -> impl IntoView {
+    // None mientras carga; Err si el servidor no respondió
+    let history = RwSignal::new(None::<Result<Vec<serde_json::Value>, String>>);
+
+    let url = format!("/api/patients/{}/history?limit=500", patient.id.clone().unwrap_or_default());
+    spawn_local(async move {
+        let result = match http::send_with_retry(|| reqwasm::http::Request::get(&url), |_| {}).await {
+            Ok(resp) => resp
+                .json::<serde_json::Value>()
+                .await
+                .map(|data| data.get("items").and_then(|v| v.as_array()).cloned().unwrap_or_default())
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        history.set(Some(result));
+    });
+
+    view! {
+        <div class="space-y-4">
+            <div class="flex justify-between items-center">
+                <div>
+                    <h3 class="text-xl text-white font-bold">{format!("{} {}", patient.first_name, patient.last_name)}</h3>
+                    <p class="text-slate-400 text-sm">{patient.principal_diagnosis.clone()}</p>
+                </div>
+                <button on:click=move |_| on_back()
+                    class="px-4 py-2 bg-slate-700 text-white rounded hover:bg-slate-600">
+                    "Volver a pacientes"
+                </button>
+            </div>
+            {move || match history.get() {
+                None => view! { <p class="text-slate-500">"Cargando historial..."</p> }.into_any(),
+                Some(Err(e)) => view! { <p class="text-red-400">{format!("No se pudo cargar el historial: {}", e)}</p> }.into_any(),
+                Some(Ok(items)) if history_chart::scales_in(&items).is_empty() => view! {
+                    <div class="olympus-card bg-slate-800 p-8 rounded-xl border border-slate-700 text-center">
+                        <p class="text-slate-300 font-medium">"Este paciente aún no tiene evaluaciones"</p>
+                        <p class="text-slate-500 text-sm mt-1">"Las escalas que se le calculen aparecerán aquí con su evolución."</p>
+                    </div>
+                }.into_any(),
+                Some(Ok(items)) => history_chart::scales_in(&items).into_iter().map(|scale| {
+                    let entries = history_chart::table(&items, &scale);
+                    view! {
+                        <div class="olympus-card bg-slate-800 p-4 rounded-xl border border-slate-700">
+                            <div class="flex justify-between items-center mb-3">
+                                <h4 class="text-lg text-white font-semibold">{format!("{} ({})", scale, entries.len())}</h4>
+                                <history_chart::ScaleSparkline history=items.clone() scale=scale.clone()/>
+                            </div>
+                            <table class="w-full text-sm">
+                                <thead>
+                                    <tr class="text-slate-400 text-left border-b border-slate-700">
+                                        <th class="py-1">"Fecha"</th>
+                                        <th class="py-1">"Puntuación"</th>
+                                        <th class="py-1">"Interpretación"</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {entries.into_iter().map(|e| view! {
+                                        <tr class="text-slate-200 border-b border-slate-700/50">
+                                            <td class="py-1">{e.label}</td>
+                                            <td class="py-1 font-bold">{e.total}</td>
+                                            <td class="py-1 text-slate-400">{e.detail}</td>
+                                        </tr>
+                                    }).collect::<Vec<_>>()}
+                                </tbody>
+                            </table>
+                        </div>
+                    }
+                }).collect::<Vec<_>>().into_any(),
+            }}
+        </div>
+    }
+}
+
+/// Rechazo de la API al guardar un formulario: mensaje general y mensajes por campo
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FormErrors {
+    pub message: String,
+    pub fields: std::collections::HashMap<String, String>,
+}
+
+impl FormErrors {
+    /// None si la API aceptó la petición; si no, `error` y `errors` (campo → mensaje) del cuerpo
+    pub fn from_response(status: u16, body: &serde_json::Value) -> Option<Self> {
+        let rejected = !(200..300).contains(&status) || body.get("success") == Some(&serde_json::Value::Bool(false));
+        if !rejected {
+            return None;
+        }
+        // `error` puede ser el mensaje o un objeto `{ code, message }`
+        let message = body
+            .get("error")
+            .and_then(|e| e.as_str().or_else(|| e.get("message")?.as_str()))
+            .map(String::from)
+            .unwrap_or_else(|| format!("Error {} al guardar", status));
+        let fields = body
+            .get("errors")
+            .and_then(|e| e.as_object())
+            .map(|errors| {
+                errors
+                    .iter()
+                    .filter_map(|(field, msg)| Some((field.clone(), msg.as_str()?.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Some(Self { message, fields })
+    }
+
+    pub fn field(&self, name: &str) -> Option<String> {
+        self.fields.get(name).cloned()
+    }
+}
+
+/// Mensaje de validación bajo un campo del formulario
+fn field_error(errors: RwSignal<FormErrors>, name: &'static str) -> impl IntoView {
+    move || {
+        errors
+            .get()
+            .field(name)
+            .map(|msg| view! { <p class="text-red-400 text-xs mt-1">{msg}</p> })
+    }
+}
+
 #[component]
-fn PatientForm(on_save: impl Fn() + 'static + Clone, on_cancel: impl Fn() + 'static + Clone) -> impl IntoView {
-    let first_name = RwSignal::new(String::new());
-    let last_name = RwSignal::new(String::new());
-    let identity_card = RwSignal::new(String::new());
-    let diagnosis = RwSignal::new(String::new());
+fn PatientForm(
+    /// Paciente existente a editar; sin él el formulario da de alta uno nuevo
+    #[prop(default = None)] patient: Option<Patient>,
+    on_save: impl Fn() + 'static + Clone,
+    on_cancel: impl Fn() + 'static + Clone,
+) -> impl IntoView {
+    let existing_id = patient.as_ref().and_then(|p| p.id.clone());
+    let editing = existing_id.is_some();
+    let patient = patient.unwrap_or(Patient {
+        id: None,
+        first_name: String::new(),
+        last_name: String::new(),
+        identity_card: String::new(),
+        principal_diagnosis: String::new(),
+        skin_color: None,
+        admission_type: None,
+    });
+    let first_name = RwSignal::new(patient.first_name);
+    let last_name = RwSignal::new(patient.last_name);
+    let identity_card = RwSignal::new(patient.identity_card);
+    let diagnosis = RwSignal::new(patient.principal_diagnosis);
+    let skin_color = RwSignal::new(patient.skin_color.unwrap_or_else(|| DEFAULT_SKIN_COLOR.to_string()));
+    let admission_type = RwSignal::new(patient.admission_type.unwrap_or_else(|| DEFAULT_ADMISSION_TYPE.to_string()));
     let saving = RwSignal::new(false);
+    let errors = RwSignal::new(FormErrors::default());
     
     let save = move |_| {
         saving.set(true);
+        errors.set(FormErrors::default());
         let callback = on_save.clone();
+        let existing_id = existing_id.clone();
         
         spawn_local(async move {
             let patient = Patient {
@@ -416,35 +862,75 @@ fn PatientForm(on_save: impl Fn() + 'static + Clone, on_cancel: impl Fn() + 'sta
                 last_name: last_name.get(),
                 identity_card: identity_card.get(),
                 principal_diagnosis: diagnosis.get(),
+                skin_color: Some(skin_color.get()),
+                admission_type: Some(admission_type.get()),
             };
             
-            let _ = reqwasm::http::Request::post("/api/patients")
-                .header("Content-Type", "application/json")
+            // Edición: Merge Patch con los campos del formulario sobre el registro existente
+            let request = match &existing_id {
+                Some(id) => reqwasm::http::Request::patch(&format!("/api/patients/{}", id))
+                    .header("Content-Type", "application/merge-patch+json"),
+                None => reqwasm::http::Request::post("/api/patients")
+                    .header("Content-Type", "application/json"),
+            };
+            let res = request
                 .body(serde_json::to_string(&patient).unwrap_or_default())
                 .send().await;
             
             saving.set(false);
-            callback();
+            // Solo se cierra el formulario si la API confirma el alta (422/409 lo mantienen abierto)
+            let rejected = match res {
+                Ok(resp) => {
+                    let body = resp.json::<serde_json::Value>().await.unwrap_or_default();
+                    FormErrors::from_response(resp.status(), &body)
+                }
+                Err(e) => Some(FormErrors { message: format!("Error de red: {}", e), ..FormErrors::default() }),
+            };
+            match rejected {
+                Some(rejected) => errors.set(rejected),
+                None => callback(),
+            }
         });
     };
     
     view! {
-        <div class="bg-slate-800 p-6 rounded-xl border border-slate-700">
-            <h3 class="text-xl font-bold text-white mb-4">Nuevo Paciente</h3>
+        <div class="olympus-card bg-slate-800 p-6 rounded-xl border border-slate-700">
+            <h3 class="text-xl font-bold text-white mb-4">{if editing { "Editar Paciente" } else { "Nuevo Paciente" }}</h3>
+            
+            {move || {
+                let message = errors.get().message;
+                (!message.is_empty()).then(|| view! { <p class="text-red-400 text-sm mb-4">{message}</p> })
+            }}
             
             <div class="space-y-4">
-                <input type="text" placeholder="Nombre" 
-                    on:input=move |e| first_name.set(event_target_value(&e))
-                    class="w-full p-3 bg-slate-700 border border-slate-600 rounded text-white"/>
-                <input type="text" placeholder="Apellido" 
-                    on:input=move |e| last_name.set(event_target_value(&e))
-                    class="w-full p-3 bg-slate-700 border border-slate-600 rounded text-white"/>
-                <input type="text" placeholder="Cedula" 
-                    on:input=move |e| identity_card.set(event_target_value(&e))
-                    class="w-full p-3 bg-slate-700 border border-slate-600 rounded text-white"/>
-                <input type="text" placeholder="Diagnostico" 
-                    on:input=move |e| diagnosis.set(event_target_value(&e))
-                    class="w-full p-3 bg-slate-700 border border-slate-600 rounded text-white"/>
+                <div>
+                    <input type="text" placeholder="Nombre" prop:value=move || first_name.get()
+                        on:input=move |e| first_name.set(event_target_value(&e))
+                        class="w-full p-3 bg-slate-700 border border-slate-600 rounded text-white"/>
+                    {field_error(errors, "first_name")}
+                </div>
+                <div>
+                    <input type="text" placeholder="Apellido" prop:value=move || last_name.get()
+                        on:input=move |e| last_name.set(event_target_value(&e))
+                        class="w-full p-3 bg-slate-700 border border-slate-600 rounded text-white"/>
+                    {field_error(errors, "last_name")}
+                </div>
+                <div>
+                    <input type="text" placeholder="Cedula" prop:value=move || identity_card.get()
+                        on:input=move |e| identity_card.set(event_target_value(&e))
+                        class="w-full p-3 bg-slate-700 border border-slate-600 rounded text-white"/>
+                    {field_error(errors, "identity_card")}
+                </div>
+                <div>
+                    <input type="text" placeholder="Diagnostico" prop:value=move || diagnosis.get()
+                        on:input=move |e| diagnosis.set(event_target_value(&e))
+                        class="w-full p-3 bg-slate-700 border border-slate-600 rounded text-white"/>
+                    {field_error(errors, "principal_diagnosis")}
+                </div>
+                <div class="grid grid-cols-2 gap-4">
+                    <ChoiceSelect label="Color de piel" options=&SKIN_COLORS value=skin_color errors=errors field="skin_color"/>
+                    <ChoiceSelect label="Tipo de ingreso" options=&ADMISSION_TYPES value=admission_type errors=errors field="admission_type"/>
+                </div>
             </div>
             
             <div class="flex justify-end gap-3 mt-6">
@@ -461,6 +947,29 @@ fn PatientForm(on_save: impl Fn() + 'static + Clone, on_cancel: impl Fn() + 'sta
     }
 }
 
+/// Select con los valores de un enum del servidor; muestra la etiqueta y envía el valor
+#[component]
+fn ChoiceSelect(
+    label: &'static str,
+    options: &'static [(&'static str, &'static str)],
+    value: RwSignal<String>,
+    errors: RwSignal<FormErrors>,
+    field: &'static str,
+) -> impl IntoView {
+    view! {
+        <div>
+            <label class="text-slate-400 text-sm">{label}</label>
+            <select on:change=move |e| value.set(event_target_value(&e))
+                class="w-full p-3 bg-slate-700 border border-slate-600 rounded text-white">
+                {options.iter().map(|(option, text)| view! {
+                    <option value=*option selected=move || value.get() == *option>{*text}</option>
+                }).collect::<Vec<_>>()}
+            </select>
+            {field_error(errors, field)}
+        </div>
+    }
+}
+
 // ============================================
 // SCALES PAGE
 // ============================================
@@ -468,6 +977,8 @@ fn PatientForm(on_save: impl Fn() + 'static + Clone, on_cancel: impl Fn() + 'sta
 #[component]
 fn ScalesPage() -> impl IntoView {
     let scale = RwSignal::new("glasgow".to_string());
+    // Paciente de APACHE y SAPS; al cambiarlo el formulario se reabre con el borrador de ese paciente
+    let patient_id = RwSignal::new(String::new());
 
     view! {
         <div class="space-y-6">
@@ -494,15 +1005,29 @@ fn ScalesPage() -> impl IntoView {
                     class={move || format!("px-4 py-2 rounded {}", if scale.get() == "news2" { "bg-green-600 ring-2 ring-white" } else { "bg-green-600/50 hover:bg-green-600" })}>
                     NEWS2
                 </button>
+                <button on:click=move |_| scale.set("tidal".to_string())
+                    class={move || format!("px-4 py-2 rounded {}", if scale.get() == "tidal" { "bg-cyan-600 ring-2 ring-white" } else { "bg-cyan-600/50 hover:bg-cyan-600" })}>
+                    Vt Protector
+                </button>
             </div>
             
-            <div class="bg-slate-800 p-6 rounded-xl border border-slate-700 max-w-2xl mx-auto">
+            {move || matches!(scale.get().as_str(), "apache" | "saps").then(|| view! {
+                <div class="max-w-2xl mx-auto">
+                    <label class="text-slate-400 text-sm">ID de paciente (opcional)</label>
+                    <input type="text" prop:value=move || patient_id.get()
+                        on:change=move |e| patient_id.set(event_target_value(&e).trim().to_string())
+                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white"/>
+                </div>
+            })}
+
+            <div class="olympus-card bg-slate-800 p-6 rounded-xl border border-slate-700 max-w-2xl mx-auto">
                 {move || {
                     match scale.get().as_str() {
                         "sofa" => view! { <SofaForm/> }.into_any(),
-                        "apache" => view! { <ApacheForm/> }.into_any(),
-                        "saps" => view! { <SapsForm/> }.into_any(),
+                        "apache" => view! { <ApacheForm patient_id=patient_id.get()/> }.into_any(),
+                        "saps" => view! { <SapsForm patient_id=patient_id.get()/> }.into_any(),
                         "news2" => view! { <News2Form/> }.into_any(),
+                        "tidal" => view! { <TidalVolumeForm/> }.into_any(),
                         _ => view! { <GlasgowForm/> }.into_any(),
                     }
                 }}
@@ -583,100 +1108,808 @@ fn SofaForm() -> impl IntoView {
     }
 }
 
+/// Parámetros de APACHE II tal como los recibe `POST /api/scales/apache`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApacheIIRequest {
+    pub temperature: f32,
+    pub mean_arterial_pressure: i32,
+    pub heart_rate: i32,
+    pub respiratory_rate: i32,
+    /// "aa_gradient" o "pao2"
+    pub oxygenation_type: String,
+    pub oxygenation_value: i32,
+    pub arterial_ph: f32,
+    pub serum_sodium: i32,
+    pub serum_potassium: f32,
+    pub serum_creatinine: f32,
+    pub hematocrit: f32,
+    pub white_blood_count: f32,
+    pub glasgow_coma_score: u8,
+    pub age: u8,
+    /// "none", "elective", "non_elective" o "non_operative"
+    pub chronic_health: String,
+    pub patient_id: Option<String>,
+}
+
+impl ApacheIIRequest {
+    /// Las mismas comprobaciones que `to_apache()` en el servidor, para avisar antes de enviar
+    pub fn validate(&self) -> Result<(), String> {
+        if !["aa_gradient", "pao2"].contains(&self.oxygenation_type.as_str()) {
+            return Err(format!("Tipo de oxigenacion no valido: '{}'", self.oxygenation_type));
+        }
+        if !["none", "elective", "non_elective", "non_operative"].contains(&self.chronic_health.as_str()) {
+            return Err(format!("Enfermedad cronica no valida: '{}'", self.chronic_health));
+        }
+        if !(3..=15).contains(&self.glasgow_coma_score) {
+            return Err(format!("La escala de Glasgow debe estar entre 3 y 15 (se indico {})", self.glasgow_coma_score));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApacheIIResponse {
+    pub score: u8,
+    pub predicted_mortality: f32,
+    pub severity: String,
+    pub recommendation: String,
+}
+
+/// Valor numérico de un campo; un campo vacío o no numérico es un error con su etiqueta
+fn parse_input<T: std::str::FromStr>(label: &str, raw: &str) -> Result<T, String> {
+    raw.trim()
+        .parse()
+        .map_err(|_| format!("{}: valor no valido '{}'", label, raw.trim()))
+}
+
 #[component]
-fn ApacheForm() -> impl IntoView {
-    let temp = RwSignal::new(37.0f32);
-    let hr = RwSignal::new(80i32);
+fn ApacheInput(label: &'static str, value: RwSignal<String>) -> impl IntoView {
+    view! {
+        <div>
+            <label class="text-slate-400 text-sm">{label}</label>
+            <input type="number" step="any" prop:value=move || value.get()
+                on:input=move |e| value.set(event_target_value(&e))
+                class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white"/>
+        </div>
+    }
+}
+
+/// Campos del formulario APACHE II tal como se escriben, para su borrador
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApacheDraft {
+    pub temperature: String,
+    pub mean_arterial_pressure: String,
+    pub heart_rate: String,
+    pub respiratory_rate: String,
+    pub oxygenation_type: String,
+    pub oxygenation_value: String,
+    pub arterial_ph: String,
+    pub serum_sodium: String,
+    pub serum_potassium: String,
+    pub serum_creatinine: String,
+    pub hematocrit: String,
+    pub white_blood_count: String,
+    pub glasgow_coma_score: String,
+    pub age: String,
+    pub chronic_health: String,
+}
+
+/// `patient_id` se fija al abrir: el borrador se guarda y se busca bajo ese paciente
+#[component]
+fn ApacheForm(patient_id: String) -> impl IntoView {
+    let temperature = RwSignal::new("37".to_string());
+    let map = RwSignal::new("85".to_string());
+    let hr = RwSignal::new("80".to_string());
+    let rr = RwSignal::new("16".to_string());
+    let oxygenation_type = RwSignal::new("pao2".to_string());
+    let oxygenation_value = RwSignal::new("90".to_string());
+    let ph = RwSignal::new("7.40".to_string());
+    let sodium = RwSignal::new("140".to_string());
+    let potassium = RwSignal::new("4.0".to_string());
+    let creatinine = RwSignal::new("1.0".to_string());
+    let hematocrit = RwSignal::new("40".to_string());
+    let wbc = RwSignal::new("8".to_string());
+    let gcs = RwSignal::new("15".to_string());
+    let age = RwSignal::new("50".to_string());
+    let chronic_health = RwSignal::new("none".to_string());
+    let result = RwSignal::new(None::<ApacheIIResponse>);
+    let error = RwSignal::new(String::new());
+    let saving = RwSignal::new(false);
+
+    let fields = [
+        temperature, map, hr, rr, oxygenation_type, oxygenation_value, ph, sodium, potassium,
+        creatinine, hematocrit, wbc, gcs, age, chronic_health,
+    ];
+    let draft = draft::FormDraft::<ApacheDraft>::new("apache", &patient_id);
+    let restore = move |d: ApacheDraft| {
+        let values = [
+            d.temperature, d.mean_arterial_pressure, d.heart_rate, d.respiratory_rate, d.oxygenation_type,
+            d.oxygenation_value, d.arterial_ph, d.serum_sodium, d.serum_potassium, d.serum_creatinine,
+            d.hematocrit, d.white_blood_count, d.glasgow_coma_score, d.age, d.chronic_health,
+        ];
+        for (field, value) in fields.iter().zip(values) {
+            field.set(value);
+        }
+    };
+    // Guarda al escribir; la primera pasada solo registra los valores iniciales
+    Effect::new(move |initialized: Option<()>| {
+        let [
+            temperature, mean_arterial_pressure, heart_rate, respiratory_rate, oxygenation_type,
+            oxygenation_value, arterial_ph, serum_sodium, serum_potassium, serum_creatinine,
+            hematocrit, white_blood_count, glasgow_coma_score, age, chronic_health,
+        ] = fields.map(|field| field.get());
+        if initialized.is_some() {
+            draft.save_debounced(ApacheDraft {
+                temperature, mean_arterial_pressure, heart_rate, respiratory_rate, oxygenation_type,
+                oxygenation_value, arterial_ph, serum_sodium, serum_potassium, serum_creatinine,
+                hematocrit, white_blood_count, glasgow_coma_score, age, chronic_health,
+            });
+        }
+    });
+
+    let build = move || -> Result<ApacheIIRequest, String> {
+        let request = ApacheIIRequest {
+            temperature: parse_input("Temperatura", &temperature.get())?,
+            mean_arterial_pressure: parse_input("PAM", &map.get())?,
+            heart_rate: parse_input("FC", &hr.get())?,
+            respiratory_rate: parse_input("FR", &rr.get())?,
+            oxygenation_type: oxygenation_type.get(),
+            oxygenation_value: parse_input("Oxigenacion", &oxygenation_value.get())?,
+            arterial_ph: parse_input("pH arterial", &ph.get())?,
+            serum_sodium: parse_input("Sodio", &sodium.get())?,
+            serum_potassium: parse_input("Potasio", &potassium.get())?,
+            serum_creatinine: parse_input("Creatinina", &creatinine.get())?,
+            hematocrit: parse_input("Hematocrito", &hematocrit.get())?,
+            white_blood_count: parse_input("Leucocitos", &wbc.get())?,
+            glasgow_coma_score: parse_input("Glasgow", &gcs.get())?,
+            age: parse_input("Edad", &age.get())?,
+            chronic_health: chronic_health.get(),
+            patient_id: Some(patient_id.trim().to_string()).filter(|id| !id.is_empty()),
+        };
+        request.validate()?;
+        Ok(request)
+    };
+
+    let save = move |_| {
+        result.set(None);
+        let request = match build() {
+            Ok(request) => request,
+            Err(e) => {
+                error.set(e);
+                return;
+            }
+        };
+        error.set(String::new());
+        saving.set(true);
+        let body = serde_json::to_string(&request).unwrap_or_default();
+
+        spawn_local(async move {
+            let res = http::send_with_retry(
+                || reqwasm::http::Request::post("/api/scales/apache")
+                    .header("Content-Type", "application/json")
+                    .body(body.clone()),
+                |_| {},
+            ).await;
+
+            saving.set(false);
+            match res {
+                Ok(resp) => {
+                    let status = resp.status();
+                    let body = resp.json::<serde_json::Value>().await.unwrap_or_default();
+                    // Un 422 del servidor es el Err de `to_apache()`: se muestra su mensaje
+                    if let Some(rejected) = FormErrors::from_response(status, &body) {
+                        error.set(rejected.message);
+                    } else {
+                        match serde_json::from_value::<ApacheIIResponse>(body) {
+                            Ok(data) => {
+                                draft.clear();
+                                result.set(Some(data));
+                            }
+                            Err(_) => error.set("Respuesta de APACHE II no valida".to_string()),
+                        }
+                    }
+                }
+                Err(e) => error.set(format!("Error de red: {}", e)),
+            }
+        });
+    };
 
     view! {
         <div class="space-y-4">
             <h3 class="text-xl text-white font-bold text-center">APACHE II</h3>
             <p class="text-slate-400 text-center text-sm">Escala de gravedad fisiologica</p>
-            
-            <div class="grid grid-cols-2 gap-4">
+            <draft::DraftRestoreBanner draft=draft on_restore=restore/>
+
+            <div class="grid grid-cols-3 gap-4">
+                <ApacheInput label="Temperatura (C)" value=temperature/>
+                <ApacheInput label="PAM (mmHg)" value=map/>
+                <ApacheInput label="FC (lpm)" value=hr/>
+                <ApacheInput label="FR (rpm)" value=rr/>
                 <div>
-                    <label class="text-slate-400 text-sm">Temperatura (C)</label>
-                    <input type="number" value={temp.get()} 
-                        on:input=move |e| temp.set(event_target_value(&e).parse().unwrap_or(37.0))
-                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white"/>
+                    <label class="text-slate-400 text-sm">Oxigenacion</label>
+                    <select on:change=move |e| oxygenation_type.set(event_target_value(&e))
+                        prop:value=move || oxygenation_type.get()
+                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white">
+                        <option value="pao2">"PaO2 (FiO2 < 0.5)"</option>
+                        <option value="aa_gradient">"Gradiente A-a (FiO2 >= 0.5)"</option>
+                    </select>
                 </div>
+                <ApacheInput label="Valor (mmHg)" value=oxygenation_value/>
+                <ApacheInput label="pH arterial" value=ph/>
+                <ApacheInput label="Sodio (mEq/L)" value=sodium/>
+                <ApacheInput label="Potasio (mEq/L)" value=potassium/>
+                <ApacheInput label="Creatinina (mg/dL)" value=creatinine/>
+                <ApacheInput label="Hematocrito (%)" value=hematocrit/>
+                <ApacheInput label="Leucocitos (x1000/mm3)" value=wbc/>
+                <ApacheInput label="Glasgow (3-15)" value=gcs/>
+                <ApacheInput label="Edad" value=age/>
                 <div>
-                    <label class="text-slate-400 text-sm">FC (lpm)</label>
-                    <input type="number" value={hr.get()} 
-                        on:input=move |e| hr.set(event_target_value(&e).parse().unwrap_or(80))
-                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white"/>
+                    <label class="text-slate-400 text-sm">Enfermedad cronica</label>
+                    <select on:change=move |e| chronic_health.set(event_target_value(&e))
+                        prop:value=move || chronic_health.get()
+                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white">
+                        <option value="none">"Ninguna"</option>
+                        <option value="elective">"Postoperatorio electivo"</option>
+                        <option value="non_elective">"Postoperatorio urgente"</option>
+                        <option value="non_operative">"No quirurgico"</option>
+                    </select>
                 </div>
             </div>
+
+            {move || {
+                if !error.get().is_empty() {
+                    view! { <p class="text-red-400 text-sm text-center">{error.get()}</p> }.into_any()
+                } else if let Some(r) = result.get() {
+                    view! {
+                        <div class="space-y-2">
+                            <div class="grid grid-cols-2 gap-4 text-center">
+                                <div class="p-4 bg-slate-700 rounded-xl">
+                                    <p class="text-slate-400 text-sm mb-2">Puntuacion</p>
+                                    <p class="text-5xl font-bold text-white">{r.score}</p>
+                                    <p class="text-red-400 text-sm mt-2">{r.severity.clone()}</p>
+                                </div>
+                                <div class="p-4 bg-slate-700 rounded-xl">
+                                    <p class="text-slate-400 text-sm mb-2">Mortalidad predicha</p>
+                                    <p class="text-4xl font-bold text-white">{format!("{:.1}%", r.predicted_mortality)}</p>
+                                </div>
+                            </div>
+                            <p class="text-slate-300 text-sm text-center">{r.recommendation.clone()}</p>
+                        </div>
+                    }.into_any()
+                } else { view! { <div></div> }.into_any() }
+            }}
             
-            <button on:click=move |_| {}
-                class="w-full py-3 bg-red-600 hover:bg-red-500 text-white rounded transition">
-                Guardar APACHE
+            <button on:click=save disabled=move || saving.get()
+                class="w-full py-3 bg-red-600 hover:bg-red-500 text-white rounded transition disabled:opacity-50">
+                {move || if saving.get() { "Calculando..." } else { "Guardar APACHE" }}
             </button>
         </div>
     }
 }
 
+/// Parámetros de SAPS II tal como los recibe `POST /api/scales/saps`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SAPSIIRequest {
+    pub age: u8,
+    pub heart_rate: i32,
+    pub systolic_bp: i32,
+    pub temperature: f32,
+    /// Solo con ventilación mecánica o CPAP
+    pub pao2_fio2: Option<i32>,
+    pub urinary_output: f32,
+    pub serum_urea: f32,
+    pub white_blood_count: f32,
+    pub serum_potassium: f32,
+    pub serum_sodium: i32,
+    pub serum_bicarbonate: f32,
+    pub bilirubin: f32,
+    pub glasgow: u8,
+    /// "none", "cancer", "hematologic" o "aids"
+    pub chronic_disease: String,
+    /// "scheduled", "medical" o "unscheduled"; vacío, el servidor usa el del paciente
+    pub admission_type: String,
+    pub patient_id: Option<String>,
+}
+
+impl SAPSIIRequest {
+    /// Las mismas comprobaciones que `calculate()` en el servidor, para avisar antes de enviar
+    pub fn validate(&self) -> Result<(), String> {
+        if !["none", "cancer", "hematologic", "aids"].contains(&self.chronic_disease.as_str()) {
+            return Err(format!("Enfermedad cronica no valida: '{}'", self.chronic_disease));
+        }
+        if !["", "scheduled", "medical", "unscheduled"].contains(&self.admission_type.as_str()) {
+            return Err(format!("Tipo de ingreso no valido: '{}'", self.admission_type));
+        }
+        if !(3..=15).contains(&self.glasgow) {
+            return Err(format!("La escala de Glasgow debe estar entre 3 y 15 (se indico {})", self.glasgow));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SAPSIIResponse {
+    pub score: u8,
+    pub predicted_mortality: f32,
+    pub severity: String,
+    pub recommendation: String,
+}
+
+/// Campos del formulario SAPS II tal como se escriben, para su borrador
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SapsDraft {
+    pub age: String,
+    pub heart_rate: String,
+    pub systolic_bp: String,
+    pub temperature: String,
+    pub pao2_fio2: String,
+    pub urinary_output: String,
+    pub serum_urea: String,
+    pub white_blood_count: String,
+    pub serum_potassium: String,
+    pub serum_sodium: String,
+    pub serum_bicarbonate: String,
+    pub bilirubin: String,
+    pub glasgow: String,
+    pub chronic_disease: String,
+    pub admission_type: String,
+}
+
+/// `patient_id` se fija al abrir, como en APACHE II
 #[component]
-fn SapsForm() -> impl IntoView {
-    let age = RwSignal::new(50i32);
-    let hr = RwSignal::new(80i32);
+fn SapsForm(patient_id: String) -> impl IntoView {
+    let age = RwSignal::new("50".to_string());
+    let hr = RwSignal::new("80".to_string());
+    let systolic_bp = RwSignal::new("120".to_string());
+    let temperature = RwSignal::new("37".to_string());
+    let pao2_fio2 = RwSignal::new(String::new());
+    let urinary_output = RwSignal::new("1.5".to_string());
+    let urea = RwSignal::new("30".to_string());
+    let wbc = RwSignal::new("8".to_string());
+    let potassium = RwSignal::new("4.0".to_string());
+    let sodium = RwSignal::new("140".to_string());
+    let bicarbonate = RwSignal::new("24".to_string());
+    let bilirubin = RwSignal::new("1.0".to_string());
+    let gcs = RwSignal::new("15".to_string());
+    let chronic_disease = RwSignal::new("none".to_string());
+    let admission_type = RwSignal::new(String::new());
+    let result = RwSignal::new(None::<SAPSIIResponse>);
+    let error = RwSignal::new(String::new());
+    let saving = RwSignal::new(false);
+
+    let fields = [
+        age, hr, systolic_bp, temperature, pao2_fio2, urinary_output, urea, wbc, potassium,
+        sodium, bicarbonate, bilirubin, gcs, chronic_disease, admission_type,
+    ];
+    let draft = draft::FormDraft::<SapsDraft>::new("saps", &patient_id);
+    let restore = move |d: SapsDraft| {
+        let values = [
+            d.age, d.heart_rate, d.systolic_bp, d.temperature, d.pao2_fio2, d.urinary_output, d.serum_urea,
+            d.white_blood_count, d.serum_potassium, d.serum_sodium, d.serum_bicarbonate, d.bilirubin,
+            d.glasgow, d.chronic_disease, d.admission_type,
+        ];
+        for (field, value) in fields.iter().zip(values) {
+            field.set(value);
+        }
+    };
+    Effect::new(move |initialized: Option<()>| {
+        let [
+            age, heart_rate, systolic_bp, temperature, pao2_fio2, urinary_output, serum_urea,
+            white_blood_count, serum_potassium, serum_sodium, serum_bicarbonate, bilirubin,
+            glasgow, chronic_disease, admission_type,
+        ] = fields.map(|field| field.get());
+        if initialized.is_some() {
+            draft.save_debounced(SapsDraft {
+                age, heart_rate, systolic_bp, temperature, pao2_fio2, urinary_output, serum_urea,
+                white_blood_count, serum_potassium, serum_sodium, serum_bicarbonate, bilirubin,
+                glasgow, chronic_disease, admission_type,
+            });
+        }
+    });
+
+    let build = move || -> Result<SAPSIIRequest, String> {
+        let pao2_fio2 = pao2_fio2.get();
+        let request = SAPSIIRequest {
+            age: parse_input("Edad", &age.get())?,
+            heart_rate: parse_input("FC", &hr.get())?,
+            systolic_bp: parse_input("PAS", &systolic_bp.get())?,
+            temperature: parse_input("Temperatura", &temperature.get())?,
+            pao2_fio2: if pao2_fio2.trim().is_empty() { None } else { Some(parse_input("PaO2/FiO2", &pao2_fio2)?) },
+            urinary_output: parse_input("Diuresis", &urinary_output.get())?,
+            serum_urea: parse_input("Urea", &urea.get())?,
+            white_blood_count: parse_input("Leucocitos", &wbc.get())?,
+            serum_potassium: parse_input("Potasio", &potassium.get())?,
+            serum_sodium: parse_input("Sodio", &sodium.get())?,
+            serum_bicarbonate: parse_input("Bicarbonato", &bicarbonate.get())?,
+            bilirubin: parse_input("Bilirrubina", &bilirubin.get())?,
+            glasgow: parse_input("Glasgow", &gcs.get())?,
+            chronic_disease: chronic_disease.get(),
+            admission_type: admission_type.get(),
+            patient_id: Some(patient_id.trim().to_string()).filter(|id| !id.is_empty()),
+        };
+        request.validate()?;
+        Ok(request)
+    };
+
+    let save = move |_| {
+        result.set(None);
+        let request = match build() {
+            Ok(request) => request,
+            Err(e) => {
+                error.set(e);
+                return;
+            }
+        };
+        error.set(String::new());
+        saving.set(true);
+        let body = serde_json::to_string(&request).unwrap_or_default();
+
+        spawn_local(async move {
+            let res = http::send_with_retry(
+                || reqwasm::http::Request::post("/api/scales/saps")
+                    .header("Content-Type", "application/json")
+                    .body(body.clone()),
+                |_| {},
+            ).await;
+
+            saving.set(false);
+            match res {
+                Ok(resp) => {
+                    let status = resp.status();
+                    let body = resp.json::<serde_json::Value>().await.unwrap_or_default();
+                    if let Some(rejected) = FormErrors::from_response(status, &body) {
+                        error.set(rejected.message);
+                    } else {
+                        match serde_json::from_value::<SAPSIIResponse>(body) {
+                            // Guardada: el borrador de este paciente ya no hace falta
+                            Ok(data) => {
+                                draft.clear();
+                                result.set(Some(data));
+                            }
+                            Err(_) => error.set("Respuesta de SAPS II no valida".to_string()),
+                        }
+                    }
+                }
+                Err(e) => error.set(format!("Error de red: {}", e)),
+            }
+        });
+    };
 
     view! {
         <div class="space-y-4">
             <h3 class="text-xl text-white font-bold text-center">SAPS II</h3>
-            
-            <div class="grid grid-cols-2 gap-4">
+            <draft::DraftRestoreBanner draft=draft on_restore=restore/>
+
+            <div class="grid grid-cols-3 gap-4">
+                <ApacheInput label="Edad" value=age/>
+                <ApacheInput label="FC (lpm)" value=hr/>
+                <ApacheInput label="PAS (mmHg)" value=systolic_bp/>
+                <ApacheInput label="Temperatura (C)" value=temperature/>
+                <ApacheInput label="PaO2/FiO2 (solo VM/CPAP)" value=pao2_fio2/>
+                <ApacheInput label="Diuresis (L/dia)" value=urinary_output/>
+                <ApacheInput label="Urea (mg/dL)" value=urea/>
+                <ApacheInput label="Leucocitos (x1000/mm3)" value=wbc/>
+                <ApacheInput label="Potasio (mmol/L)" value=potassium/>
+                <ApacheInput label="Sodio (mmol/L)" value=sodium/>
+                <ApacheInput label="Bicarbonato (mmol/L)" value=bicarbonate/>
+                <ApacheInput label="Bilirrubina (mg/dL)" value=bilirubin/>
+                <ApacheInput label="Glasgow (3-15)" value=gcs/>
                 <div>
-                    <label class="text-slate-400 text-sm">Edad</label>
-                    <input type="number" value={age.get()} 
-                        on:input=move |e| age.set(event_target_value(&e).parse().unwrap_or(50))
-                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white"/>
+                    <label class="text-slate-400 text-sm">Enfermedad cronica</label>
+                    <select on:change=move |e| chronic_disease.set(event_target_value(&e))
+                        prop:value=move || chronic_disease.get()
+                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white">
+                        <option value="none">"Ninguna"</option>
+                        <option value="cancer">"Cancer metastasico"</option>
+                        <option value="hematologic">"Neoplasia hematologica"</option>
+                        <option value="aids">"SIDA"</option>
+                    </select>
                 </div>
                 <div>
-                    <label class="text-slate-400 text-sm">FC (lpm)</label>
-                    <input type="number" value={hr.get()} 
-                        on:input=move |e| hr.set(event_target_value(&e).parse().unwrap_or(80))
-                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white"/>
+                    <label class="text-slate-400 text-sm">Tipo de ingreso</label>
+                    <select on:change=move |e| admission_type.set(event_target_value(&e))
+                        prop:value=move || admission_type.get()
+                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white">
+                        <option value="">"El del paciente"</option>
+                        <option value="scheduled">"Cirugia programada"</option>
+                        <option value="medical">"Medico"</option>
+                        <option value="unscheduled">"Cirugia urgente"</option>
+                    </select>
                 </div>
             </div>
-            
-            <button on:click=move |_| {}
-                class="w-full py-3 bg-orange-600 hover:bg-orange-500 text-white rounded transition">
-                Guardar SAPS
+
+            {move || {
+                if !error.get().is_empty() {
+                    view! { <p class="text-red-400 text-sm text-center">{error.get()}</p> }.into_any()
+                } else if let Some(r) = result.get() {
+                    view! {
+                        <div class="space-y-2">
+                            <div class="grid grid-cols-2 gap-4 text-center">
+                                <div class="p-4 bg-slate-700 rounded-xl">
+                                    <p class="text-slate-400 text-sm mb-2">Puntuacion</p>
+                                    <p class="text-5xl font-bold text-white">{r.score}</p>
+                                    <p class="text-orange-400 text-sm mt-2">{r.severity.clone()}</p>
+                                </div>
+                                <div class="p-4 bg-slate-700 rounded-xl">
+                                    <p class="text-slate-400 text-sm mb-2">Mortalidad predicha</p>
+                                    <p class="text-4xl font-bold text-white">{format!("{:.1}%", r.predicted_mortality)}</p>
+                                </div>
+                            </div>
+                            <p class="text-slate-300 text-sm text-center">{r.recommendation.clone()}</p>
+                        </div>
+                    }.into_any()
+                } else { view! { <div></div> }.into_any() }
+            }}
+
+            <button on:click=save disabled=move || saving.get()
+                class="w-full py-3 bg-orange-600 hover:bg-orange-500 text-white rounded transition disabled:opacity-50">
+                {move || if saving.get() { "Calculando..." } else { "Guardar SAPS" }}
             </button>
         </div>
     }
 }
 
+// Bandas NEWS2 servidas por /api/scales/news2/chart-thresholds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct News2Band {
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    pub score: i32,
+    pub color: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct News2Parameter {
+    pub parameter: String,
+    pub unit: String,
+    pub bands: Vec<News2Band>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct News2ChartThresholds {
+    pub colors: Vec<String>,
+    pub parameters: Vec<News2Parameter>,
+}
+
+impl News2ChartThresholds {
+    /// Banda en la que cae el valor mostrado (redondeado a un decimal como en el servidor)
+    pub fn band_for(&self, parameter: &str, value: f32) -> Option<&News2Band> {
+        let value = (value * 10.0).round() / 10.0;
+        self.parameters
+            .iter()
+            .find(|p| p.parameter == parameter)?
+            .bands
+            .iter()
+            .find(|b| b.min.is_none_or(|min| value >= min) && b.max.is_none_or(|max| value <= max))
+    }
+
+    /// Sub-puntuación de un parámetro; fuera de toda banda puntúa 3 como `news2_sub_score`
+    pub fn sub_score(&self, parameter: &str, value: f32) -> i32 {
+        self.band_for(parameter, value).map(|b| b.score).unwrap_or(3)
+    }
+}
+
+/// Puntos por oxígeno suplementario y por conciencia distinta de Alerta (como en el servidor)
+pub const NEWS2_SUPPLEMENTAL_OXYGEN_SCORE: i32 = 2;
+pub const NEWS2_ALTERED_CONSCIOUSNESS_SCORE: i32 = 3;
+
+/// Observación NEWS2 completa del formulario
+#[derive(Debug, Clone, PartialEq)]
+pub struct News2Observation {
+    pub respiration_rate: i32,
+    pub oxygen_saturation: i32,
+    pub temperature: f32,
+    pub heart_rate: i32,
+    pub systolic_bp: i32,
+    /// Nivel ACVPU: alert, new_confusion, voice, pain o unresponsive
+    pub consciousness: String,
+    pub supplemental_oxygen: bool,
+}
+
+impl News2Observation {
+    /// Total con las mismas bandas que `calculate_news2`
+    pub fn total(&self, thresholds: &News2ChartThresholds) -> i32 {
+        let vitals = thresholds.sub_score("respiration_rate", self.respiration_rate as f32)
+            + thresholds.sub_score("oxygen_saturation", self.oxygen_saturation as f32)
+            + thresholds.sub_score("temperature", self.temperature)
+            + thresholds.sub_score("heart_rate", self.heart_rate as f32)
+            + thresholds.sub_score("systolic_bp", self.systolic_bp as f32);
+        let consciousness = if self.consciousness == "alert" { 0 } else { NEWS2_ALTERED_CONSCIOUSNESS_SCORE };
+        let oxygen = if self.supplemental_oxygen { NEWS2_SUPPLEMENTAL_OXYGEN_SCORE } else { 0 };
+        vitals + consciousness + oxygen
+    }
+}
+
+/// Últimos signos vitales crudos de `GET /api/patients/:id/vitals`; lo no registrado queda en None
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct LatestVitals {
+    pub respiration_rate: Option<i32>,
+    pub oxygen_saturation: Option<i32>,
+    pub temperature: Option<f32>,
+    pub heart_rate: Option<i32>,
+    pub systolic_bp: Option<i32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VitalsHistory {
+    pub latest: Option<LatestVitals>,
+}
+
+/// Banda de riesgo del total (mismos cortes que `NEWS2_RISK_BANDS`)
+pub fn news2_risk(total: i32) -> &'static str {
+    match total {
+        ..=4 => "Bajo riesgo",
+        5..=6 => "Riesgo moderado",
+        _ => "Alto riesgo",
+    }
+}
+
+/// Borde del campo con el color de su sub-puntuación; sin datos, el estilo por defecto
+fn news2_shade(thresholds: Option<&News2ChartThresholds>, parameter: &str, value: f32) -> String {
+    thresholds
+        .and_then(|t| t.band_for(parameter, value))
+        .map(|band| format!("border-color: {}; box-shadow: inset 4px 0 0 {};", band.color, band.color))
+        .unwrap_or_default()
+}
+
 #[component]
 fn News2Form() -> impl IntoView {
     let resp_rate = RwSignal::new(16i32);
+    let spo2 = RwSignal::new(97i32);
+    let temp = RwSignal::new(37.0f32);
     let hr = RwSignal::new(80i32);
+    let sbp = RwSignal::new(120i32);
+    let consciousness = RwSignal::new("alert".to_string());
+    let supplemental_oxygen = RwSignal::new(false);
     let total = RwSignal::new(0i32);
+    let thresholds = RwSignal::new(None::<News2ChartThresholds>);
+    let patient_id = RwSignal::new(String::new());
+    let prefill_status = RwSignal::new(String::new());
+
+    // Prellenado con los últimos signos vitales crudos registrados para el paciente
+    let prefill = move |_| {
+        let id = patient_id.get().trim().to_string();
+        if id.is_empty() {
+            prefill_status.set("Indique el ID de paciente".to_string());
+            return;
+        }
+        prefill_status.set("Cargando signos vitales...".to_string());
+        spawn_local(async move {
+            let url = format!("/api/patients/{}/vitals", id);
+            let latest = match http::send_with_retry(|| reqwasm::http::Request::get(&url), |_| {}).await {
+                Ok(resp) => resp.json::<VitalsHistory>().await.ok().and_then(|history| history.latest),
+                Err(e) => {
+                    prefill_status.set(format!("Error de red: {}", e));
+                    return;
+                }
+            };
+            let Some(vitals) = latest else {
+                prefill_status.set("Sin signos vitales registrados para este paciente".to_string());
+                return;
+            };
+            if let Some(value) = vitals.respiration_rate {
+                resp_rate.set(value);
+            }
+            if let Some(value) = vitals.oxygen_saturation {
+                spo2.set(value);
+            }
+            if let Some(value) = vitals.temperature {
+                temp.set(value);
+            }
+            if let Some(value) = vitals.heart_rate {
+                hr.set(value);
+            }
+            if let Some(value) = vitals.systolic_bp {
+                sbp.set(value);
+            }
+            prefill_status.set("Signos vitales cargados".to_string());
+        });
+    };
+
+    spawn_local(async move {
+        if let Ok(resp) = http::send_with_retry(|| reqwasm::http::Request::get("/api/scales/news2/chart-thresholds"), |_| {}).await {
+            if let Ok(data) = resp.json::<News2ChartThresholds>().await {
+                thresholds.set(Some(data));
+            }
+        }
+    });
+
+    Effect::new(move |_| {
+        let observation = News2Observation {
+            respiration_rate: resp_rate.get(),
+            oxygen_saturation: spo2.get(),
+            temperature: temp.get(),
+            heart_rate: hr.get(),
+            systolic_bp: sbp.get(),
+            consciousness: consciousness.get(),
+            supplemental_oxygen: supplemental_oxygen.get(),
+        };
+        if let Some(t) = thresholds.get() {
+            total.set(observation.total(&t));
+        }
+    });
 
     view! {
         <div class="space-y-4">
             <h3 class="text-xl text-white font-bold text-center">NEWS2</h3>
             <p class="text-slate-400 text-center text-sm">National Early Warning Score</p>
+
+            <div class="flex gap-2 items-end">
+                <div class="flex-1">
+                    <label class="text-slate-400 text-sm">ID de paciente (opcional)</label>
+                    <input type="text" on:input=move |e| patient_id.set(event_target_value(&e))
+                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white"/>
+                </div>
+                <button on:click=prefill
+                    class="px-4 py-2 bg-slate-600 hover:bg-slate-500 text-white rounded transition">
+                    "Usar ultimos signos vitales"
+                </button>
+            </div>
+            <p class="text-slate-400 text-sm">{move || prefill_status.get()}</p>
             
             <div class="grid grid-cols-2 gap-4">
                 <div>
                     <label class="text-slate-400 text-sm">FR (rpm)</label>
-                    <input type="number" value={resp_rate.get()} 
+                    <input type="number" prop:value=move || resp_rate.get()
                         on:input=move |e| resp_rate.set(event_target_value(&e).parse().unwrap_or(16))
+                        style=move || thresholds.with(|t| news2_shade(t.as_ref(), "respiration_rate", resp_rate.get() as f32))
+                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white"/>
+                </div>
+                <div>
+                    <label class="text-slate-400 text-sm">SpO2 (%)</label>
+                    <input type="number" prop:value=move || spo2.get()
+                        on:input=move |e| spo2.set(event_target_value(&e).parse().unwrap_or(97))
+                        style=move || thresholds.with(|t| news2_shade(t.as_ref(), "oxygen_saturation", spo2.get() as f32))
+                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white"/>
+                </div>
+                <div>
+                    <label class="text-slate-400 text-sm">Temperatura (C)</label>
+                    <input type="number" step="0.1" prop:value=move || temp.get()
+                        on:input=move |e| temp.set(event_target_value(&e).parse().unwrap_or(37.0))
+                        style=move || thresholds.with(|t| news2_shade(t.as_ref(), "temperature", temp.get()))
                         class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white"/>
                 </div>
                 <div>
                     <label class="text-slate-400 text-sm">FC (lpm)</label>
                     <input type="number" value={hr.get()} 
                         on:input=move |e| hr.set(event_target_value(&e).parse().unwrap_or(80))
+                        style=move || thresholds.with(|t| news2_shade(t.as_ref(), "heart_rate", hr.get() as f32))
+                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white"/>
+                </div>
+                <div>
+                    <label class="text-slate-400 text-sm">PAS (mmHg)</label>
+                    <input type="number" prop:value=move || sbp.get()
+                        on:input=move |e| sbp.set(event_target_value(&e).parse().unwrap_or(120))
+                        style=move || thresholds.with(|t| news2_shade(t.as_ref(), "systolic_bp", sbp.get() as f32))
                         class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white"/>
                 </div>
+                <div>
+                    <label class="text-slate-400 text-sm">Conciencia (ACVPU)</label>
+                    <select on:change=move |e| consciousness.set(event_target_value(&e))
+                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white">
+                        <option value="alert">"Alerta"</option>
+                        <option value="new_confusion">"Confusion nueva"</option>
+                        <option value="voice">"Responde a la voz"</option>
+                        <option value="pain">"Responde al dolor"</option>
+                        <option value="unresponsive">"No responde"</option>
+                    </select>
+                </div>
             </div>
+
+            <label class="flex items-center gap-2 text-slate-300 text-sm">
+                <input type="checkbox" prop:checked=move || supplemental_oxygen.get()
+                    on:change=move |e| supplemental_oxygen.set(event_target_checked(&e))/>
+                "Oxigeno suplementario"
+            </label>
             
             <div class="text-center p-6 bg-slate-700 rounded-xl">
                 <p class="text-slate-400 text-sm mb-2">Puntuacion</p>
-                <p class="text-5xl font-bold text-white">{total.get()}</p>
+                <p class="text-5xl font-bold text-white">
+                    {move || if thresholds.with(Option::is_some) { total.get().to_string() } else { "...".to_string() }}
+                </p>
+                <p class="text-green-400 text-sm mt-2">
+                    {move || thresholds.with(Option::is_some).then(|| news2_risk(total.get()))}
+                </p>
             </div>
             
             <button on:click=move |_| {}
@@ -687,6 +1920,110 @@ fn News2Form() -> impl IntoView {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TidalVolumeResult {
+    pub ideal_body_weight_kg: f64,
+    pub target_ml_per_kg: f64,
+    pub tidal_volume_ml: f64,
+    pub min_tidal_volume_ml: f64,
+    pub max_tidal_volume_ml: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TidalVolumeResponse {
+    pub success: bool,
+    pub result: Option<TidalVolumeResult>,
+    pub error: Option<String>,
+}
+
+#[component]
+fn TidalVolumeForm() -> impl IntoView {
+    let height = RwSignal::new(170.0f64);
+    let sex = RwSignal::new("male".to_string());
+    let target = RwSignal::new(6.0f64);
+    let result = RwSignal::new(None::<TidalVolumeResult>);
+    let error = RwSignal::new(String::new());
+
+    let calculate = move |_| {
+        let body = serde_json::json!({
+            "height_cm": height.get(),
+            "sex": sex.get(),
+            "target_ml_per_kg": target.get(),
+        }).to_string();
+
+        spawn_local(async move {
+            let res = http::send_with_retry(
+                || reqwasm::http::Request::post("/api/tools/tidal-volume")
+                    .header("Content-Type", "application/json")
+                    .body(body.clone()),
+                |_| {},
+            ).await;
+
+            if let Ok(resp) = res {
+                if let Ok(data) = resp.json::<TidalVolumeResponse>().await {
+                    error.set(data.error.unwrap_or_default());
+                    result.set(data.result);
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class="space-y-4">
+            <h3 class="text-xl text-white font-bold text-center">Volumen Corriente Protector</h3>
+            <p class="text-slate-400 text-center text-sm">Peso ideal (Devine) para pacientes en ventilacion mecanica</p>
+
+            <div class="grid grid-cols-3 gap-4">
+                <div>
+                    <label class="text-slate-400 text-sm">Talla (cm)</label>
+                    <input type="number" min="120" max="250" value={height.get()}
+                        on:input=move |e| height.set(event_target_value(&e).parse().unwrap_or(170.0))
+                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white"/>
+                </div>
+                <div>
+                    <label class="text-slate-400 text-sm">Sexo</label>
+                    <select on:change=move |e| sex.set(event_target_value(&e))
+                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white">
+                        <option value="male">"Masculino"</option>
+                        <option value="female">"Femenino"</option>
+                    </select>
+                </div>
+                <div>
+                    <label class="text-slate-400 text-sm">Objetivo (mL/kg)</label>
+                    <input type="number" min="4" max="8" step="0.5" value={target.get()}
+                        on:input=move |e| target.set(event_target_value(&e).parse().unwrap_or(6.0))
+                        class="w-full p-2 bg-slate-700 border border-slate-600 rounded text-white"/>
+                </div>
+            </div>
+
+            {move || {
+                if !error.get().is_empty() {
+                    view! { <p class="text-amber-400 text-sm text-center">{error.get()}</p> }.into_any()
+                } else if let Some(r) = result.get() {
+                    view! {
+                        <div class="grid grid-cols-2 gap-4 text-center">
+                            <div class="p-4 bg-slate-700 rounded-xl">
+                                <p class="text-slate-400 text-sm mb-2">Peso Ideal</p>
+                                <p class="text-4xl font-bold text-white">{format!("{:.1} kg", r.ideal_body_weight_kg)}</p>
+                            </div>
+                            <div class="p-4 bg-slate-700 rounded-xl">
+                                <p class="text-slate-400 text-sm mb-2">{format!("Vt a {} mL/kg", r.target_ml_per_kg)}</p>
+                                <p class="text-4xl font-bold text-white">{format!("{:.0} mL", r.tidal_volume_ml)}</p>
+                                <p class="text-cyan-400 text-sm mt-2">{format!("Rango protector: {:.0}-{:.0} mL", r.min_tidal_volume_ml, r.max_tidal_volume_ml)}</p>
+                            </div>
+                        </div>
+                    }.into_any()
+                } else { view! { <div></div> }.into_any() }
+            }}
+
+            <button on:click=calculate
+                class="w-full py-3 bg-cyan-600 hover:bg-cyan-500 text-white rounded transition">
+                Calcular
+            </button>
+        </div>
+    }
+}
+
 #[component]
 fn ScaleSlider(label: &'static str, value: RwSignal<i32>, min: i32, max: i32) -> impl IntoView {
     view! {
@@ -785,7 +2122,7 @@ fn OlympusMonitor() -> impl IntoView {
                 }).collect::<Vec<_>>()}
             </div>
             
-            <div class="bg-slate-800 p-6 rounded-xl border border-slate-700 mt-8">
+            <div class="olympus-card bg-slate-800 p-6 rounded-xl border border-slate-700 mt-8">
                 <div class="flex items-center justify-between">
                     <div>
                         <p class="text-white font-bold text-xl">Zeus - Orquestador Principal</p>
@@ -834,7 +2171,7 @@ fn apply_theme_to_document(theme: &Theme) {
 // ============================================
 
 #[component]
-fn AphroditePage(current_theme: RwSignal<String>) -> impl IntoView {
+fn AphroditePage(current_theme: RwSignal<String>, theme_revision: RwSignal<u64>) -> impl IntoView {
     let themes = RwSignal::new(Vec::<String>::new());
     let selected_theme = RwSignal::new(String::new());
     let message = RwSignal::new(String::new());
@@ -871,15 +2208,17 @@ fn AphroditePage(current_theme: RwSignal<String>) -> impl IntoView {
             
             loading.set(false);
             
-            if let Ok(_) = res {
-                current_theme.set(theme_name.clone());
-                message.set(format!("✨ Tema cambiado a: {}", theme_name));
-                
-                // Recargar tema actual para aplicar CSS
-                if let Ok(resp) = reqwasm::http::Request::get("/api/aphrodite/theme").send().await {
-                    if let Ok(data) = resp.json::<CurrentThemeResponse>().await {
-                        apply_theme_to_document(&data.theme);
+            // El servidor responde con el tema vigente: puede no ser el elegido si otro
+            // cliente lo cambió justo después, y es ese el que se aplica
+            if let Ok(resp) = res {
+                match resp.json::<SwitchThemeResponse>().await {
+                    Ok(SwitchThemeResponse { theme: Some(theme), revision, .. }) => {
+                        if reconcile_theme(&theme, revision, theme_revision, current_theme) {
+                            message.set(format!("✨ Tema cambiado a: {}", theme.name));
+                        }
                     }
+                    Ok(data) => message.set(data.error.unwrap_or_else(|| format!("No se pudo aplicar {}", theme_name))),
+                    Err(e) => message.set(format!("Respuesta inválida: {}", e)),
                 }
             }
         });
@@ -897,7 +2236,7 @@ fn AphroditePage(current_theme: RwSignal<String>) -> impl IntoView {
             
             <div class="grid grid-cols-1 lg:grid-cols-2 gap-6">
                 // Selector de Temas
-                <div class="bg-slate-800 p-6 rounded-xl border border-pink-500/30">
+                <div class="olympus-card bg-slate-800 p-6 rounded-xl border border-pink-500/30">
                     <h3 class="text-xl font-bold text-white mb-4 flex items-center gap-2">
                         <span>"🎨"</span>
                         <span>"Temas Disponibles"</span>
@@ -953,7 +2292,7 @@ fn AphroditePage(current_theme: RwSignal<String>) -> impl IntoView {
                 </div>
                 
                 // Preview del Tema
-                <div class="bg-slate-800 p-6 rounded-xl border border-pink-500/30">
+                <div class="olympus-card bg-slate-800 p-6 rounded-xl border border-pink-500/30">
                     <h3 class="text-xl font-bold text-white mb-4 flex items-center gap-2">
                         <span>"👁️"</span>
                         <span>"Vista Previa"</span>
@@ -1014,3 +2353,160 @@ fn AphroditePage(current_theme: RwSignal<String>) -> impl IntoView {
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_component_css_only_includes_overrides() {
+        let components = vec![
+            ComponentStyles { id: "button".to_string(), overrides: [("borderRadius".to_string(), "9999px".to_string())].into_iter().collect() },
+            ComponentStyles { id: "card".to_string(), overrides: Default::default() },
+            ComponentStyles { id: "slider".to_string(), overrides: [("zIndex".to_string(), "5".to_string())].into_iter().collect() },
+        ];
+        assert_eq!(component_css(&components), "#app-container button {\n  border-radius: 9999px;\n}\n");
+    }
+
+    #[test]
+    fn test_form_errors_from_rejected_response() {
+        assert_eq!(FormErrors::from_response(200, &serde_json::json!({ "success": true })), None);
+
+        let conflict = serde_json::json!({
+            "success": false,
+            "error": "Ya existe un paciente con la cédula V123",
+            "errors": { "identity_card": "Ya existe un paciente con la cédula V123" },
+        });
+        let errors = FormErrors::from_response(409, &conflict).unwrap();
+        assert_eq!(errors.field("identity_card").as_deref(), Some("Ya existe un paciente con la cédula V123"));
+        assert_eq!(errors.field("first_name"), None);
+
+        // Éxito aparente con success: false, o error sin cuerpo
+        assert!(FormErrors::from_response(200, &serde_json::json!({ "success": false })).is_some());
+        assert_eq!(FormErrors::from_response(500, &serde_json::Value::Null).unwrap().message, "Error 500 al guardar");
+    }
+
+    #[test]
+    fn test_news2_total_and_risk_from_server_bands() {
+        let band = |min: Option<f32>, max: Option<f32>, score: i32| serde_json::json!({ "min": min, "max": max, "score": score, "color": "#fff" });
+        let thresholds: News2ChartThresholds = serde_json::from_value(serde_json::json!({
+            "colors": [],
+            "parameters": [
+                { "parameter": "respiration_rate", "unit": "rpm", "bands": [band(None, Some(8.0), 3), band(Some(9.0), Some(11.0), 1), band(Some(12.0), Some(20.0), 0), band(Some(21.0), Some(24.0), 2), band(Some(25.0), None, 3)] },
+                { "parameter": "oxygen_saturation", "unit": "%", "bands": [band(None, Some(91.0), 3), band(Some(92.0), Some(93.0), 2), band(Some(94.0), Some(95.0), 1), band(Some(96.0), None, 0)] },
+                { "parameter": "temperature", "unit": "°C", "bands": [band(None, Some(35.0), 3), band(Some(35.1), Some(36.0), 1), band(Some(36.1), Some(38.0), 0), band(Some(38.1), Some(39.0), 1), band(Some(39.1), None, 2)] },
+                { "parameter": "heart_rate", "unit": "lpm", "bands": [band(None, Some(40.0), 3), band(Some(41.0), Some(50.0), 1), band(Some(51.0), Some(90.0), 0), band(Some(91.0), Some(110.0), 1), band(Some(111.0), Some(130.0), 2), band(Some(131.0), None, 3)] },
+                { "parameter": "systolic_bp", "unit": "mmHg", "bands": [band(None, Some(90.0), 3), band(Some(91.0), Some(100.0), 2), band(Some(101.0), Some(110.0), 1), band(Some(111.0), Some(219.0), 0), band(Some(220.0), None, 3)] },
+            ],
+        }))
+        .unwrap();
+
+        let mut observation = News2Observation {
+            respiration_rate: 16,
+            oxygen_saturation: 97,
+            temperature: 37.0,
+            heart_rate: 80,
+            systolic_bp: 120,
+            consciousness: "alert".to_string(),
+            supplemental_oxygen: false,
+        };
+        assert_eq!(observation.total(&thresholds), 0);
+        assert_eq!(news2_risk(0), "Bajo riesgo");
+
+        // FR 22 (2) + SpO2 93 (2) + oxígeno (2)
+        observation.respiration_rate = 22;
+        observation.oxygen_saturation = 93;
+        observation.supplemental_oxygen = true;
+        assert_eq!(observation.total(&thresholds), 6);
+        assert_eq!(news2_risk(6), "Riesgo moderado");
+
+        observation.consciousness = "voice".to_string();
+        assert_eq!(observation.total(&thresholds), 9);
+        assert_eq!(news2_risk(9), "Alto riesgo");
+    }
+
+    #[test]
+    fn test_latest_vitals_from_server_response() {
+        // Forma de GET /api/patients/:id/vitals: la observación trae además diastolic_bp, ids...
+        let history: VitalsHistory = serde_json::from_value(serde_json::json!({
+            "patient_id": "p1",
+            "latest": { "id": "vitals:1", "patient_id": "p1", "respiration_rate": 24, "temperature": 38.4,
+                        "heart_rate": 112, "diastolic_bp": 60, "recorded_at": "2026-10-16T08:00:00Z" },
+            "observations": []
+        }))
+        .unwrap();
+        let latest = history.latest.unwrap();
+        assert_eq!(latest.respiration_rate, Some(24));
+        assert_eq!(latest.heart_rate, Some(112));
+        assert_eq!(latest.oxygen_saturation, None);
+
+        let empty: VitalsHistory = serde_json::from_value(serde_json::json!({ "patient_id": "p1", "latest": null, "observations": [] })).unwrap();
+        assert!(empty.latest.is_none());
+    }
+
+    #[test]
+    fn test_apache_request_validation() {
+        let mut request = ApacheIIRequest {
+            temperature: 38.5,
+            mean_arterial_pressure: 65,
+            heart_rate: 120,
+            respiratory_rate: 28,
+            oxygenation_type: "pao2".to_string(),
+            oxygenation_value: 65,
+            arterial_ph: 7.30,
+            serum_sodium: 150,
+            serum_potassium: 5.8,
+            serum_creatinine: 2.1,
+            hematocrit: 29.0,
+            white_blood_count: 18.0,
+            glasgow_coma_score: 12,
+            age: 70,
+            chronic_health: "non_operative".to_string(),
+            patient_id: None,
+        };
+        assert_eq!(request.validate(), Ok(()));
+
+        request.glasgow_coma_score = 2;
+        assert!(request.validate().unwrap_err().contains("entre 3 y 15"));
+        request.glasgow_coma_score = 12;
+        request.oxygenation_type = "fio2".to_string();
+        assert!(request.validate().is_err());
+
+        assert_eq!(parse_input::<f32>("pH arterial", " 7.35 "), Ok(7.35));
+        assert_eq!(parse_input::<u8>("Glasgow", "").unwrap_err(), "Glasgow: valor no valido ''");
+
+        // El 422 del servidor trae el mensaje dentro de `error`
+        let rejected = serde_json::json!({ "error": { "code": "validation_error", "message": "Glasgow Coma Score must be between 3 and 15" } });
+        assert_eq!(FormErrors::from_response(422, &rejected).unwrap().message, "Glasgow Coma Score must be between 3 and 15");
+    }
+
+    #[test]
+    fn test_saps_request_validation() {
+        let mut request = SAPSIIRequest {
+            age: 65,
+            heart_rate: 130,
+            systolic_bp: 85,
+            temperature: 37.0,
+            pao2_fio2: None,
+            urinary_output: 0.8,
+            serum_urea: 50.0,
+            white_blood_count: 8.0,
+            serum_potassium: 4.0,
+            serum_sodium: 140,
+            serum_bicarbonate: 24.0,
+            bilirubin: 1.0,
+            glasgow: 10,
+            chronic_disease: "none".to_string(),
+            admission_type: String::new(),
+            patient_id: Some("p1".to_string()),
+        };
+        // Sin tipo de ingreso el servidor usa el del paciente
+        assert_eq!(request.validate(), Ok(()));
+
+        request.admission_type = "urgent".to_string();
+        assert!(request.validate().unwrap_err().contains("Tipo de ingreso"));
+        request.admission_type = "medical".to_string();
+        request.glasgow = 16;
+        assert!(request.validate().unwrap_err().contains("entre 3 y 15"));
+    }
+}
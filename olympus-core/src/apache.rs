@@ -26,3 +26,448 @@ pub struct ApacheAssessment {
     pub assessed_by: String,
     pub assessed_at: String,
 }
+
+/// FiO2 a partir de la cual APACHE II puntúa el gradiente A-aDO2 en lugar de la PaO2
+pub const AA_GRADIENT_FIO2_THRESHOLD: f32 = 0.5;
+
+/// Un pH arterial fuera de este rango no es compatible con la vida
+pub const PLAUSIBLE_ARTERIAL_PH: std::ops::RangeInclusive<f32> = 6.5..=8.0;
+
+fn room_air_fio2() -> f32 {
+    0.21
+}
+
+/// Parámetros de APACHE II tal como los reciben los servidores
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApacheIIRequest {
+    /// °C
+    pub temperature: f32,
+    pub mean_arterial_pressure: i32,
+    pub heart_rate: i32,
+    pub respiratory_rate: i32,
+    /// "aa_gradient" o "pao2"
+    pub oxygenation_type: String,
+    /// mmHg
+    pub oxygenation_value: i32,
+    /// Fracción inspirada de oxígeno (0.21-1.0); decide qué oxigenación se puntúa.
+    /// Aire ambiente si se omite.
+    #[serde(default = "room_air_fio2")]
+    pub fio2: f32,
+    pub arterial_ph: f32,
+    /// mEq/L
+    pub serum_sodium: i32,
+    /// mEq/L
+    pub serum_potassium: f32,
+    /// mg/dL (doble si hay fracaso renal agudo)
+    pub serum_creatinine: f32,
+    /// %
+    pub hematocrit: f32,
+    /// x1000/mm³
+    pub white_blood_count: f32,
+    /// 3-15
+    pub glasgow_coma_score: u8,
+    pub age: u8,
+    /// "none", "elective", "non_elective" o "non_operative"
+    pub chronic_health: String,
+    pub patient_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApacheIIResponse {
+    pub score: u8,
+    /// %
+    pub predicted_mortality: f32,
+    pub severity: String,
+    pub recommendation: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ApacheOxygenation {
+    /// FiO2 ≥ 0.5: gradiente A-aDO2 (mmHg)
+    AAGradient(i32),
+    /// FiO2 < 0.5: PaO2 (mmHg)
+    PaO2(i32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChronicHealth {
+    None,
+    ElectiveSurgery,
+    NonElectiveSurgery,
+    NonOperative,
+}
+
+/// Valoración APACHE II ya validada
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApacheII {
+    pub temperature: f32,
+    pub mean_arterial_pressure: i32,
+    pub heart_rate: i32,
+    pub respiratory_rate: i32,
+    pub oxygenation: ApacheOxygenation,
+    pub arterial_ph: f32,
+    pub serum_sodium: i32,
+    pub serum_potassium: f32,
+    pub serum_creatinine: f32,
+    pub hematocrit: f32,
+    pub white_blood_count: f32,
+    pub glasgow_coma_score: u8,
+    pub age: u8,
+    pub chronic_health: ChronicHealth,
+}
+
+impl ApacheIIRequest {
+    /// Oxigenación que exige la FiO2: A-aDO2 con FiO2 ≥ 0.5, PaO2 por debajo
+    pub fn required_oxygenation_type(&self) -> &'static str {
+        if self.fio2 >= AA_GRADIENT_FIO2_THRESHOLD {
+            "aa_gradient"
+        } else {
+            "pao2"
+        }
+    }
+
+    pub fn to_apache(&self) -> Result<ApacheII, String> {
+        if !(0.21..=1.0).contains(&self.fio2) {
+            return Err(format!("FiO2 debe estar entre 0.21 y 1.0 (se indicó {})", self.fio2));
+        }
+
+        let oxygenation = match (self.oxygenation_type.as_str(), self.required_oxygenation_type()) {
+            ("aa_gradient", "aa_gradient") => ApacheOxygenation::AAGradient(self.oxygenation_value),
+            ("pao2", "pao2") => ApacheOxygenation::PaO2(self.oxygenation_value),
+            ("pao2", _) => {
+                return Err(format!(
+                    "FiO2 {:.2} ≥ 0.5: APACHE II puntúa el gradiente A-aDO2, indique oxygenation_type \"aa_gradient\"",
+                    self.fio2
+                ))
+            }
+            ("aa_gradient", _) => {
+                return Err(format!(
+                    "FiO2 {:.2} < 0.5: APACHE II puntúa la PaO2, indique oxygenation_type \"pao2\"",
+                    self.fio2
+                ))
+            }
+            (other, _) => return Err(format!("Tipo de oxigenación no válido: '{}'", other)),
+        };
+
+        let chronic_health = match self.chronic_health.as_str() {
+            "none" => ChronicHealth::None,
+            "elective" => ChronicHealth::ElectiveSurgery,
+            "non_elective" => ChronicHealth::NonElectiveSurgery,
+            "non_operative" => ChronicHealth::NonOperative,
+            other => return Err(format!("Enfermedad crónica no válida: '{}'", other)),
+        };
+
+        if !(3..=15).contains(&self.glasgow_coma_score) {
+            return Err(format!("La escala de Glasgow debe estar entre 3 y 15 (se indicó {})", self.glasgow_coma_score));
+        }
+
+        if !PLAUSIBLE_ARTERIAL_PH.contains(&self.arterial_ph) {
+            return Err(format!(
+                "pH arterial {} fisiológicamente imposible ({}-{})",
+                self.arterial_ph,
+                PLAUSIBLE_ARTERIAL_PH.start(),
+                PLAUSIBLE_ARTERIAL_PH.end()
+            ));
+        }
+
+        Ok(ApacheII {
+            temperature: self.temperature,
+            mean_arterial_pressure: self.mean_arterial_pressure,
+            heart_rate: self.heart_rate,
+            respiratory_rate: self.respiratory_rate,
+            oxygenation,
+            arterial_ph: self.arterial_ph,
+            serum_sodium: self.serum_sodium,
+            serum_potassium: self.serum_potassium,
+            serum_creatinine: self.serum_creatinine,
+            hematocrit: self.hematocrit,
+            white_blood_count: self.white_blood_count,
+            glasgow_coma_score: self.glasgow_coma_score,
+            age: self.age,
+            chronic_health,
+        })
+    }
+
+    /// Valida y calcula puntuación, mortalidad y gravedad
+    pub fn calculate(&self) -> Result<ApacheIIResponse, String> {
+        let apache = self.to_apache()?;
+        let score = apache.calculate_score();
+        let (severity, recommendation) = severity(score);
+        Ok(ApacheIIResponse {
+            score,
+            predicted_mortality: predicted_mortality(score),
+            severity: severity.to_string(),
+            recommendation: recommendation.to_string(),
+        })
+    }
+}
+
+impl ApacheII {
+    /// Puntuación APACHE II (0-71): fisiología aguda, edad y salud crónica
+    pub fn calculate_score(&self) -> u8 {
+        temperature_score(self.temperature)
+            + map_score(self.mean_arterial_pressure)
+            + heart_rate_score(self.heart_rate)
+            + respiratory_rate_score(self.respiratory_rate)
+            + oxygenation_score(self.oxygenation)
+            + ph_score(self.arterial_ph)
+            + sodium_score(self.serum_sodium)
+            + potassium_score(self.serum_potassium)
+            + creatinine_score(self.serum_creatinine)
+            + hematocrit_score(self.hematocrit)
+            + wbc_score(self.white_blood_count)
+            // Glasgow invertido: 15 - GCS
+            + (15 - self.glasgow_coma_score)
+            + age_score(self.age)
+            + chronic_health_score(self.chronic_health)
+    }
+}
+
+pub fn temperature_score(temperature: f32) -> u8 {
+    match temperature {
+        t if t >= 41.0 => 4,
+        t if t >= 39.0 => 3,
+        t if t >= 38.5 => 1,
+        t if t >= 36.0 => 0,
+        t if t >= 34.0 => 1,
+        t if t >= 32.0 => 2,
+        t if t >= 30.0 => 3,
+        _ => 4,
+    }
+}
+
+pub fn map_score(map: i32) -> u8 {
+    match map {
+        160.. => 4,
+        130..=159 => 3,
+        110..=129 => 2,
+        70..=109 => 0,
+        50..=69 => 2,
+        _ => 4,
+    }
+}
+
+pub fn heart_rate_score(hr: i32) -> u8 {
+    match hr {
+        180.. => 4,
+        140..=179 => 3,
+        110..=139 => 2,
+        70..=109 => 0,
+        55..=69 => 2,
+        40..=54 => 3,
+        _ => 4,
+    }
+}
+
+pub fn respiratory_rate_score(rr: i32) -> u8 {
+    match rr {
+        50.. => 4,
+        35..=49 => 3,
+        25..=34 => 1,
+        12..=24 => 0,
+        10..=11 => 1,
+        6..=9 => 2,
+        _ => 4,
+    }
+}
+
+pub fn oxygenation_score(oxygenation: ApacheOxygenation) -> u8 {
+    match oxygenation {
+        ApacheOxygenation::AAGradient(aa) => match aa {
+            500.. => 4,
+            350..=499 => 3,
+            200..=349 => 2,
+            _ => 0,
+        },
+        ApacheOxygenation::PaO2(pao2) => match pao2 {
+            70.. => 0,
+            61..=69 => 1,
+            55..=60 => 3,
+            _ => 4,
+        },
+    }
+}
+
+pub fn ph_score(ph: f32) -> u8 {
+    match ph {
+        ph if ph >= 7.70 => 4,
+        ph if ph >= 7.60 => 3,
+        ph if ph >= 7.50 => 1,
+        ph if ph >= 7.33 => 0,
+        ph if ph >= 7.25 => 2,
+        ph if ph >= 7.15 => 3,
+        _ => 4,
+    }
+}
+
+pub fn sodium_score(na: i32) -> u8 {
+    match na {
+        180.. => 4,
+        160..=179 => 3,
+        155..=159 => 2,
+        150..=154 => 1,
+        130..=149 => 0,
+        120..=129 => 2,
+        111..=119 => 3,
+        _ => 4,
+    }
+}
+
+pub fn potassium_score(k: f32) -> u8 {
+    match k {
+        k if k >= 7.0 => 4,
+        k if k >= 6.0 => 3,
+        k if k >= 5.5 => 1,
+        k if k >= 3.5 => 0,
+        k if k >= 3.0 => 1,
+        k if k >= 2.5 => 2,
+        _ => 4,
+    }
+}
+
+pub fn creatinine_score(creatinine: f32) -> u8 {
+    match creatinine {
+        cr if cr >= 3.5 => 4,
+        cr if cr >= 2.0 => 3,
+        cr if cr >= 1.5 => 2,
+        cr if cr >= 0.6 => 0,
+        _ => 2,
+    }
+}
+
+pub fn hematocrit_score(hematocrit: f32) -> u8 {
+    match hematocrit {
+        hct if hct >= 60.0 => 4,
+        hct if hct >= 50.0 => 2,
+        hct if hct >= 46.0 => 1,
+        hct if hct >= 30.0 => 0,
+        hct if hct >= 20.0 => 2,
+        _ => 4,
+    }
+}
+
+pub fn wbc_score(wbc: f32) -> u8 {
+    match wbc {
+        wbc if wbc >= 40.0 => 4,
+        wbc if wbc >= 20.0 => 2,
+        wbc if wbc >= 15.0 => 1,
+        wbc if wbc >= 3.0 => 0,
+        wbc if wbc >= 1.0 => 2,
+        _ => 4,
+    }
+}
+
+pub fn age_score(age: u8) -> u8 {
+    match age {
+        75.. => 6,
+        65..=74 => 5,
+        55..=64 => 3,
+        45..=54 => 2,
+        _ => 0,
+    }
+}
+
+pub fn chronic_health_score(chronic_health: ChronicHealth) -> u8 {
+    match chronic_health {
+        ChronicHealth::None => 0,
+        ChronicHealth::ElectiveSurgery => 2,
+        ChronicHealth::NonElectiveSurgery | ChronicHealth::NonOperative => 5,
+    }
+}
+
+/// Mortalidad predicha (%) aproximada por tramos de puntuación
+pub fn predicted_mortality(score: u8) -> f32 {
+    match score {
+        0..=4 => 4.0,
+        5..=9 => 8.0,
+        10..=14 => 15.0,
+        15..=19 => 25.0,
+        20..=24 => 40.0,
+        25..=29 => 55.0,
+        30..=34 => 73.0,
+        _ => 85.0,
+    }
+}
+
+/// Gravedad y recomendación según la puntuación
+pub fn severity(score: u8) -> (&'static str, &'static str) {
+    match score {
+        0..=9 => ("Bajo riesgo", "Mortalidad predicha < 10%. Monitoreo estándar en UCI."),
+        10..=14 => ("Riesgo moderado", "Mortalidad predicha 10-25%. Requiere vigilancia estrecha."),
+        15..=24 => ("Alto riesgo", "Mortalidad predicha 25-55%. Requiere intervención intensiva."),
+        25..=34 => ("Riesgo muy alto", "Mortalidad predicha 55-85%. Cuidados críticos máximos."),
+        _ => ("Riesgo extremo", "Mortalidad predicha > 85%. Pronóstico muy grave."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> ApacheIIRequest {
+        ApacheIIRequest {
+            temperature: 37.0,
+            mean_arterial_pressure: 90,
+            heart_rate: 80,
+            respiratory_rate: 16,
+            oxygenation_type: "pao2".to_string(),
+            oxygenation_value: 90,
+            fio2: 0.21,
+            arterial_ph: 7.40,
+            serum_sodium: 140,
+            serum_potassium: 4.0,
+            serum_creatinine: 1.0,
+            hematocrit: 40.0,
+            white_blood_count: 8.0,
+            glasgow_coma_score: 15,
+            age: 40,
+            chronic_health: "none".to_string(),
+            patient_id: None,
+        }
+    }
+
+    #[test]
+    fn test_scores_and_validates_request() {
+        assert_eq!(request().calculate().unwrap().score, 0);
+
+        // Sepsis en no quirúrgico de 70 años: Temp 39.2 (3), PAM 60 (2), FC 125 (2), FR 28 (1),
+        // PaO2 58 (3), creatinina 2.1 (3), leucocitos 22 (2), GCS 12 (3), edad (5), crónico (5)
+        let septic = ApacheIIRequest {
+            temperature: 39.2,
+            mean_arterial_pressure: 60,
+            heart_rate: 125,
+            respiratory_rate: 28,
+            oxygenation_value: 58,
+            serum_creatinine: 2.1,
+            white_blood_count: 22.0,
+            glasgow_coma_score: 12,
+            age: 70,
+            chronic_health: "non_operative".to_string(),
+            ..request()
+        };
+        let response = septic.calculate().unwrap();
+        assert_eq!(response.score, 29);
+        assert_eq!(response.predicted_mortality, 55.0);
+        assert_eq!(response.severity, "Riesgo muy alto");
+
+        // Con FiO2 ≥ 0.5 se exige el gradiente A-aDO2
+        let ventilated = ApacheIIRequest { fio2: 0.6, ..request() };
+        assert!(ventilated.calculate().unwrap_err().contains("aa_gradient"));
+        let gradient = ApacheIIRequest { oxygenation_type: "aa_gradient".to_string(), oxygenation_value: 360, ..ventilated };
+        assert_eq!(gradient.calculate().unwrap().score, 3);
+
+        assert!(ApacheIIRequest { glasgow_coma_score: 2, ..request() }.calculate().is_err());
+        assert!(ApacheIIRequest { arterial_ph: 74.0, ..request() }.calculate().is_err());
+        assert!(ApacheIIRequest { chronic_health: "other".to_string(), ..request() }.calculate().is_err());
+
+        // Sin fio2 se asume aire ambiente
+        let parsed: ApacheIIRequest = serde_json::from_value(serde_json::json!({
+            "temperature": 37.0, "mean_arterial_pressure": 90, "heart_rate": 80, "respiratory_rate": 16,
+            "oxygenation_type": "pao2", "oxygenation_value": 90, "arterial_ph": 7.4, "serum_sodium": 140,
+            "serum_potassium": 4.0, "serum_creatinine": 1.0, "hematocrit": 40.0, "white_blood_count": 8.0,
+            "glasgow_coma_score": 15, "age": 40, "chronic_health": "none", "patient_id": null
+        }))
+        .unwrap();
+        assert_eq!(parsed, request());
+    }
+}
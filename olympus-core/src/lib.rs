@@ -9,6 +9,7 @@ pub mod patient;
 pub mod saps;
 pub mod sofa;
 pub mod user;
+pub mod vitals;
 
 use serde::{Deserialize, Serialize};
 
@@ -24,8 +25,8 @@ pub struct Thing {
     pub id: Id,
 }
 
-impl ToString for Thing {
-    fn to_string(&self) -> String {
-        format!("{}:{}", self.tb, self.id.string)
+impl std::fmt::Display for Thing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.tb, self.id.string)
     }
 }
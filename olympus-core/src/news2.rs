@@ -18,3 +18,193 @@ pub struct News2Assessment {
     pub assessed_by: String,
     pub assessed_at: String,
 }
+
+/// Constantes vitales de entrada para NEWS2 (escala 1 de SpO2)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct News2Vitals {
+    pub respiratory_rate: i32,
+    pub oxygen_saturation: i32,
+    pub supplemental_oxygen: bool,
+    /// °C; se redondea a una décima antes de puntuar
+    pub temperature: f32,
+    pub systolic_bp: i32,
+    pub heart_rate: i32,
+    /// `false` ante confusión nueva, respuesta a la voz, al dolor o ninguna (CVPU)
+    pub alert: bool,
+}
+
+/// Puntuación de cada parámetro, en el orden del gráfico NEWS2
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct News2SubScores {
+    pub respiratory_rate: i32,
+    pub oxygen_saturation: i32,
+    pub supplemental_oxygen: i32,
+    pub systolic_bp: i32,
+    pub heart_rate: i32,
+    pub consciousness: i32,
+    pub temperature: i32,
+}
+
+impl News2SubScores {
+    pub fn total(&self) -> i32 {
+        self.respiratory_rate
+            + self.oxygen_saturation
+            + self.supplemental_oxygen
+            + self.systolic_bp
+            + self.heart_rate
+            + self.consciousness
+            + self.temperature
+    }
+}
+
+pub fn respiratory_rate_score(rr: i32) -> i32 {
+    match rr {
+        ..=8 => 3,
+        9..=11 => 1,
+        12..=20 => 0,
+        21..=24 => 2,
+        _ => 3,
+    }
+}
+
+pub fn oxygen_saturation_score(spo2: i32) -> i32 {
+    match spo2 {
+        ..=91 => 3,
+        92..=93 => 2,
+        94..=95 => 1,
+        _ => 0,
+    }
+}
+
+pub fn systolic_bp_score(sbp: i32) -> i32 {
+    match sbp {
+        ..=90 => 3,
+        91..=100 => 2,
+        101..=110 => 1,
+        111..=219 => 0,
+        _ => 3,
+    }
+}
+
+pub fn heart_rate_score(hr: i32) -> i32 {
+    match hr {
+        ..=40 => 3,
+        41..=50 => 1,
+        51..=90 => 0,
+        91..=110 => 1,
+        111..=130 => 2,
+        _ => 3,
+    }
+}
+
+pub fn temperature_score(temperature: f32) -> i32 {
+    // En décimas de grado para que 36.0 y 36.1 no dependan del error de coma flotante
+    match (temperature * 10.0).round() as i32 {
+        ..=350 => 3,
+        351..=360 => 1,
+        361..=380 => 0,
+        381..=390 => 1,
+        _ => 2,
+    }
+}
+
+/// Puntuación NEWS2 total y desglose por parámetro; función pura, sin estado
+pub fn news2_score(vitals: &News2Vitals) -> (i32, News2SubScores) {
+    let per_param = News2SubScores {
+        respiratory_rate: respiratory_rate_score(vitals.respiratory_rate),
+        oxygen_saturation: oxygen_saturation_score(vitals.oxygen_saturation),
+        supplemental_oxygen: if vitals.supplemental_oxygen { 2 } else { 0 },
+        systolic_bp: systolic_bp_score(vitals.systolic_bp),
+        heart_rate: heart_rate_score(vitals.heart_rate),
+        consciousness: if vitals.alert { 0 } else { 3 },
+        temperature: temperature_score(vitals.temperature),
+    };
+    (per_param.total(), per_param)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NORMAL: News2Vitals = News2Vitals {
+        respiratory_rate: 16,
+        oxygen_saturation: 98,
+        supplemental_oxygen: false,
+        temperature: 37.0,
+        systolic_bp: 120,
+        heart_rate: 70,
+        alert: true,
+    };
+
+    /// (valor, puntuación esperada) a ambos lados de cada corte del gráfico
+    fn assert_edges<T: Copy + std::fmt::Debug>(name: &str, score: fn(T) -> i32, cases: &[(T, i32)]) {
+        for &(value, expected) in cases {
+            assert_eq!(score(value), expected, "{} = {:?}", name, value);
+        }
+    }
+
+    #[test]
+    fn test_band_edges() {
+        assert_edges("FR", respiratory_rate_score, &[
+            (0, 3), (8, 3), (9, 1), (11, 1), (12, 0), (20, 0), (21, 2), (24, 2), (25, 3), (40, 3),
+        ]);
+        assert_edges("SpO2", oxygen_saturation_score, &[
+            (85, 3), (91, 3), (92, 2), (93, 2), (94, 1), (95, 1), (96, 0), (100, 0),
+        ]);
+        assert_edges("PAS", systolic_bp_score, &[
+            (70, 3), (90, 3), (91, 2), (100, 2), (101, 1), (110, 1), (111, 0), (219, 0), (220, 3),
+        ]);
+        assert_edges("FC", heart_rate_score, &[
+            (30, 3), (40, 3), (41, 1), (50, 1), (51, 0), (90, 0), (91, 1), (110, 1), (111, 2), (130, 2), (131, 3),
+        ]);
+        assert_edges("Temp", temperature_score, &[
+            (34.0, 3), (35.0, 3), (35.1, 1), (36.0, 1), (36.1, 0), (38.0, 0), (38.1, 1), (39.0, 1), (39.1, 2), (41.0, 2),
+        ]);
+    }
+
+    #[test]
+    fn test_reference_cases() {
+        let cases = [
+            // Paciente estable
+            (NORMAL, 0),
+            // Oxígeno suplementario solo
+            (News2Vitals { supplemental_oxygen: true, ..NORMAL }, 2),
+            // Confusión nueva: 3 por sí sola (umbral de respuesta urgente por parámetro rojo)
+            (News2Vitals { alert: false, ..NORMAL }, 3),
+            // Sepsis incipiente: FR 22 (2), FC 105 (1), Temp 38.5 (1)
+            (News2Vitals { respiratory_rate: 22, heart_rate: 105, temperature: 38.5, ..NORMAL }, 4),
+            // Deterioro agudo: FR 24 (2), SpO2 93 (2) con O2 (2), PAS 105 (1), FC 115 (2), Temp 38.5 (1)
+            (
+                News2Vitals {
+                    respiratory_rate: 24,
+                    oxygen_saturation: 93,
+                    supplemental_oxygen: true,
+                    temperature: 38.5,
+                    systolic_bp: 105,
+                    heart_rate: 115,
+                    alert: true,
+                },
+                10,
+            ),
+            // Shock: todos los parámetros en rojo
+            (
+                News2Vitals {
+                    respiratory_rate: 30,
+                    oxygen_saturation: 88,
+                    supplemental_oxygen: true,
+                    temperature: 34.8,
+                    systolic_bp: 85,
+                    heart_rate: 140,
+                    alert: false,
+                },
+                20,
+            ),
+        ];
+
+        for (vitals, expected) in cases {
+            let (total, per_param) = news2_score(&vitals);
+            assert_eq!(total, expected, "{:?}", vitals);
+            assert_eq!(per_param.total(), total);
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use crate::Thing;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub enum SkinColor {
     VeryFair,
+    #[default]
     Fair,
     Olive,
     Brown,
@@ -11,27 +12,43 @@ pub enum SkinColor {
     Black,
 }
 
-impl Default for SkinColor {
-    fn default() -> Self {
-        SkinColor::Fair
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub enum AdmissionType {
     Elective,
+    #[default]
     Urgent,
     Transfer,
 }
 
-impl Default for AdmissionType {
-    fn default() -> Self {
-        AdmissionType::Urgent
+impl SkinColor {
+    /// Variantes en el orden del formulario
+    pub const ALL: [SkinColor; 6] = [
+        SkinColor::VeryFair,
+        SkinColor::Fair,
+        SkinColor::Olive,
+        SkinColor::Brown,
+        SkinColor::DarkBrown,
+        SkinColor::Black,
+    ];
+}
+
+impl AdmissionType {
+    pub const ALL: [AdmissionType; 3] = [AdmissionType::Elective, AdmissionType::Urgent, AdmissionType::Transfer];
+
+    /// Tipo de ingreso de SAPS II (`SAPSIIRequest::admission_type`): un ingreso electivo
+    /// es cirugía programada, uno urgente cirugía no programada y un traslado, médico
+    pub fn saps_admission_type(&self) -> &'static str {
+        match self {
+            AdmissionType::Elective => "scheduled",
+            AdmissionType::Urgent => "unscheduled",
+            AdmissionType::Transfer => "medical",
+        }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub enum CivilStatus {
+    #[default]
     Single,
     Married,
     Divorced,
@@ -39,37 +56,21 @@ pub enum CivilStatus {
     Cohabiting,
 }
 
-impl Default for CivilStatus {
-    fn default() -> Self {
-        CivilStatus::Single
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub enum Gender {
+    #[default]
     Male,
     Female,
     Other,
 }
 
-impl Default for Gender {
-    fn default() -> Self {
-        Gender::Male
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub enum YesNo {
     Yes,
+    #[default]
     No,
 }
 
-impl Default for YesNo {
-    fn default() -> Self {
-        YesNo::No
-    }
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct FamilyMember {
     pub name: String,
@@ -121,27 +122,51 @@ pub struct Patient {
     pub integrity_hash: String,
 }
 
+/// Datos de alta de un paciente; los campos clínicos opcionales empiezan vacíos
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NewPatient {
+    pub first_name: String,
+    pub last_name: String,
+    pub identity_card: String,
+    pub nationality: String,
+    pub civil_status: CivilStatus,
+    pub gender: Gender,
+    pub date_of_birth: String,
+    pub address: String,
+    pub clinical_history_number: String,
+    pub hospital_admission_date: String,
+    pub uci_admission_date: String,
+    pub skin_color: SkinColor,
+    pub diagnosis: String,
+    pub transfer_migration: YesNo,
+    pub uci_history: YesNo,
+    pub mechanical_ventilation: YesNo,
+    pub admission_type: AdmissionType,
+    pub invasive_processes: Option<String>,
+}
+
 impl Patient {
-    pub fn new(
-        first_name: String,
-        last_name: String,
-        identity_card: String,
-        nationality: String,
-        civil_status: CivilStatus,
-        gender: Gender,
-        date_of_birth: String,
-        address: String,
-        clinical_history_number: String,
-        hospital_admission_date: String,
-        uci_admission_date: String,
-        skin_color: SkinColor,
-        diagnosis: String,
-        transfer_migration: YesNo,
-        uci_history: YesNo,
-        mechanical_ventilation: YesNo,
-        admission_type: AdmissionType,
-        invasive_processes: Option<String>,
-    ) -> Self {
+    pub fn new(data: NewPatient) -> Self {
+        let NewPatient {
+            first_name,
+            last_name,
+            identity_card,
+            nationality,
+            civil_status,
+            gender,
+            date_of_birth,
+            address,
+            clinical_history_number,
+            hospital_admission_date,
+            uci_admission_date,
+            skin_color,
+            diagnosis,
+            transfer_migration,
+            uci_history,
+            mechanical_ventilation,
+            admission_type,
+            invasive_processes,
+        } = data;
         let hospital_stay_days =
             Self::calculate_hospital_stay(&hospital_admission_date, &uci_admission_date);
 
@@ -183,13 +208,13 @@ impl Patient {
         let hospital = chrono::DateTime::parse_from_rfc3339(hospital_date)
             .or_else(|_| {
                 chrono::NaiveDate::parse_from_str(hospital_date, "%Y-%m-%d")
-                    .map(|d| d.and_hms_opt(0, 0, 0).unwrap().and_utc())
+                    .map(|d| d.and_hms_opt(0, 0, 0).unwrap().and_utc().fixed_offset())
             })
             .ok()?;
         let uci = chrono::DateTime::parse_from_rfc3339(uci_date)
             .or_else(|_| {
                 chrono::NaiveDate::parse_from_str(uci_date, "%Y-%m-%d")
-                    .map(|d| d.and_hms_opt(0, 0, 0).unwrap().and_utc())
+                    .map(|d| d.and_hms_opt(0, 0, 0).unwrap().and_utc().fixed_offset())
             })
             .ok()?;
 
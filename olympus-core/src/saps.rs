@@ -25,3 +25,361 @@ pub struct SapsAssessment {
     pub assessed_by: String,
     pub assessed_at: String,
 }
+
+/// Parámetros de SAPS II tal como los reciben los servidores
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SAPSIIRequest {
+    pub age: u8,
+    pub heart_rate: i32,
+    pub systolic_bp: i32,
+    /// °C
+    pub temperature: f32,
+    /// Solo con ventilación mecánica o CPAP
+    pub pao2_fio2: Option<i32>,
+    /// L/día
+    pub urinary_output: f32,
+    /// mg/dL
+    pub serum_urea: f32,
+    /// x10³/mm³
+    pub white_blood_count: f32,
+    /// mmol/L
+    pub serum_potassium: f32,
+    /// mmol/L
+    pub serum_sodium: i32,
+    /// mmol/L
+    pub serum_bicarbonate: f32,
+    /// mg/dL
+    pub bilirubin: f32,
+    /// 3-15
+    pub glasgow: u8,
+    /// "none", "cancer", "hematologic" o "aids"
+    pub chronic_disease: String,
+    /// "scheduled", "medical" o "unscheduled"; vacío, el servidor usa el del paciente
+    #[serde(default)]
+    pub admission_type: String,
+    pub patient_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SAPSIIResponse {
+    pub score: u8,
+    /// %
+    pub predicted_mortality: f32,
+    pub severity: String,
+    pub recommendation: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChronicDisease {
+    None,
+    MetastaticCancer,
+    Hematologic,
+    Aids,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AdmissionType {
+    ScheduledSurgical,
+    Medical,
+    UnscheduledSurgical,
+}
+
+/// Valoración SAPS II ya validada
+#[derive(Debug, Clone, PartialEq)]
+pub struct SAPSII {
+    pub age: u8,
+    pub heart_rate: i32,
+    pub systolic_bp: i32,
+    pub temperature: f32,
+    pub pao2_fio2: Option<i32>,
+    pub urinary_output: f32,
+    pub serum_urea: f32,
+    pub white_blood_count: f32,
+    pub serum_potassium: f32,
+    pub serum_sodium: i32,
+    pub serum_bicarbonate: f32,
+    pub bilirubin: f32,
+    pub glasgow_coma_score: u8,
+    pub chronic_disease: ChronicDisease,
+    pub admission_type: AdmissionType,
+}
+
+impl SAPSIIRequest {
+    pub fn to_saps(&self) -> Result<SAPSII, String> {
+        let chronic_disease = match self.chronic_disease.as_str() {
+            "none" => ChronicDisease::None,
+            "cancer" => ChronicDisease::MetastaticCancer,
+            "hematologic" => ChronicDisease::Hematologic,
+            "aids" => ChronicDisease::Aids,
+            other => return Err(format!("Enfermedad crónica no válida: '{}'", other)),
+        };
+
+        let admission_type = match self.admission_type.as_str() {
+            "scheduled" => AdmissionType::ScheduledSurgical,
+            "medical" => AdmissionType::Medical,
+            "unscheduled" => AdmissionType::UnscheduledSurgical,
+            other => return Err(format!("Tipo de ingreso no válido: '{}'", other)),
+        };
+
+        if !(3..=15).contains(&self.glasgow) {
+            return Err(format!("La escala de Glasgow debe estar entre 3 y 15 (se indicó {})", self.glasgow));
+        }
+
+        Ok(SAPSII {
+            age: self.age,
+            heart_rate: self.heart_rate,
+            systolic_bp: self.systolic_bp,
+            temperature: self.temperature,
+            pao2_fio2: self.pao2_fio2,
+            urinary_output: self.urinary_output,
+            serum_urea: self.serum_urea,
+            white_blood_count: self.white_blood_count,
+            serum_potassium: self.serum_potassium,
+            serum_sodium: self.serum_sodium,
+            serum_bicarbonate: self.serum_bicarbonate,
+            bilirubin: self.bilirubin,
+            glasgow_coma_score: self.glasgow,
+            chronic_disease,
+            admission_type,
+        })
+    }
+
+    /// Valida y calcula puntuación, mortalidad y gravedad
+    pub fn calculate(&self) -> Result<SAPSIIResponse, String> {
+        let score = self.to_saps()?.calculate_score();
+        let mortality = predicted_mortality(score);
+        let (severity, prognosis) = severity(score);
+        Ok(SAPSIIResponse {
+            score,
+            predicted_mortality: mortality,
+            severity: severity.to_string(),
+            recommendation: format!("Mortalidad predicha: {:.1}%. {}", mortality, prognosis),
+        })
+    }
+}
+
+impl SAPSII {
+    /// Puntuación SAPS II (0-163)
+    pub fn calculate_score(&self) -> u8 {
+        age_score(self.age)
+            + heart_rate_score(self.heart_rate)
+            + systolic_bp_score(self.systolic_bp)
+            + temperature_score(self.temperature)
+            + pao2_fio2_score(self.pao2_fio2)
+            + urinary_output_score(self.urinary_output)
+            + urea_score(self.serum_urea)
+            + wbc_score(self.white_blood_count)
+            + potassium_score(self.serum_potassium)
+            + sodium_score(self.serum_sodium)
+            + bicarbonate_score(self.serum_bicarbonate)
+            + bilirubin_score(self.bilirubin)
+            + glasgow_score(self.glasgow_coma_score)
+            + chronic_disease_score(self.chronic_disease)
+            + admission_type_score(self.admission_type)
+    }
+}
+
+pub fn age_score(age: u8) -> u8 {
+    match age {
+        0..=39 => 0,
+        40..=59 => 7,
+        60..=69 => 12,
+        70..=74 => 15,
+        75..=79 => 16,
+        _ => 18,
+    }
+}
+
+pub fn heart_rate_score(hr: i32) -> u8 {
+    match hr {
+        ..=39 => 11,
+        40..=69 => 2,
+        70..=119 => 0,
+        120..=159 => 4,
+        _ => 7,
+    }
+}
+
+pub fn systolic_bp_score(sbp: i32) -> u8 {
+    match sbp {
+        ..=69 => 13,
+        70..=99 => 5,
+        100..=199 => 0,
+        _ => 2,
+    }
+}
+
+pub fn temperature_score(temperature: f32) -> u8 {
+    if temperature < 39.0 {
+        0
+    } else {
+        3
+    }
+}
+
+/// Sin ventilación ni CPAP no puntúa
+pub fn pao2_fio2_score(ratio: Option<i32>) -> u8 {
+    match ratio {
+        None => 0,
+        Some(..=99) => 11,
+        Some(100..=199) => 9,
+        Some(_) => 6,
+    }
+}
+
+pub fn urinary_output_score(litres_per_day: f32) -> u8 {
+    match litres_per_day {
+        uo if uo < 0.5 => 11,
+        uo if uo < 1.0 => 4,
+        _ => 0,
+    }
+}
+
+pub fn urea_score(urea_mg_dl: f32) -> u8 {
+    match urea_mg_dl {
+        u if u < 28.0 => 0,
+        u if u < 84.0 => 6,
+        _ => 10,
+    }
+}
+
+pub fn wbc_score(wbc: f32) -> u8 {
+    match wbc {
+        wbc if wbc < 1.0 => 12,
+        wbc if wbc < 20.0 => 0,
+        _ => 3,
+    }
+}
+
+pub fn potassium_score(k: f32) -> u8 {
+    if (3.0..5.0).contains(&k) {
+        0
+    } else {
+        3
+    }
+}
+
+pub fn sodium_score(na: i32) -> u8 {
+    match na {
+        ..=124 => 5,
+        125..=144 => 0,
+        _ => 1,
+    }
+}
+
+pub fn bicarbonate_score(hco3: f32) -> u8 {
+    match hco3 {
+        hco3 if hco3 < 15.0 => 6,
+        hco3 if hco3 < 20.0 => 3,
+        _ => 0,
+    }
+}
+
+pub fn bilirubin_score(bilirubin: f32) -> u8 {
+    match bilirubin {
+        bil if bil < 4.0 => 0,
+        bil if bil < 6.0 => 4,
+        _ => 9,
+    }
+}
+
+pub fn glasgow_score(gcs: u8) -> u8 {
+    match gcs {
+        14..=15 => 0,
+        11..=13 => 5,
+        9..=10 => 7,
+        6..=8 => 13,
+        _ => 26,
+    }
+}
+
+pub fn chronic_disease_score(chronic_disease: ChronicDisease) -> u8 {
+    match chronic_disease {
+        ChronicDisease::None => 0,
+        ChronicDisease::MetastaticCancer => 9,
+        ChronicDisease::Hematologic => 10,
+        ChronicDisease::Aids => 17,
+    }
+}
+
+pub fn admission_type_score(admission_type: AdmissionType) -> u8 {
+    match admission_type {
+        AdmissionType::ScheduledSurgical => 0,
+        AdmissionType::Medical => 6,
+        AdmissionType::UnscheduledSurgical => 8,
+    }
+}
+
+/// Mortalidad predicha (%) por la regresión logística de Le Gall:
+/// logit = -7.7631 + 0.0737·SAPS + 0.9971·ln(SAPS + 1)
+pub fn predicted_mortality(score: u8) -> f32 {
+    let score = score as f32;
+    let logit = -7.7631 + 0.0737 * score + 0.9971 * (score + 1.0).ln();
+    (logit.exp() / (1.0 + logit.exp()) * 100.0).min(99.9)
+}
+
+/// Gravedad y pronóstico según la puntuación
+pub fn severity(score: u8) -> (&'static str, &'static str) {
+    match score {
+        0..=29 => ("Bajo riesgo", "Pronóstico favorable."),
+        30..=49 => ("Riesgo moderado", "Requiere monitoreo continuo."),
+        50..=69 => ("Alto riesgo", "Requiere cuidados intensivos."),
+        _ => ("Riesgo muy alto", "Pronóstico grave."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> SAPSIIRequest {
+        SAPSIIRequest {
+            age: 35,
+            heart_rate: 80,
+            systolic_bp: 120,
+            temperature: 37.0,
+            pao2_fio2: None,
+            urinary_output: 1.5,
+            serum_urea: 20.0,
+            white_blood_count: 8.0,
+            serum_potassium: 4.0,
+            serum_sodium: 140,
+            serum_bicarbonate: 24.0,
+            bilirubin: 1.0,
+            glasgow: 15,
+            chronic_disease: "none".to_string(),
+            admission_type: "scheduled".to_string(),
+            patient_id: None,
+        }
+    }
+
+    #[test]
+    fn test_scores_and_validates_request() {
+        let healthy = request().calculate().unwrap();
+        assert_eq!(healthy.score, 0);
+        assert!(healthy.predicted_mortality < 1.0);
+
+        // Ingreso médico de 65 años ventilado: edad (12), FC 130 (4), PAS 85 (5), PaO2/FiO2 150 (9),
+        // diuresis 0.8 (4), urea 50 (6), GCS 10 (7), médico (6)
+        let ventilated = SAPSIIRequest {
+            age: 65,
+            heart_rate: 130,
+            systolic_bp: 85,
+            pao2_fio2: Some(150),
+            urinary_output: 0.8,
+            serum_urea: 50.0,
+            glasgow: 10,
+            admission_type: "medical".to_string(),
+            ..request()
+        };
+        let response = ventilated.calculate().unwrap();
+        assert_eq!(response.score, 53);
+        assert_eq!(response.severity, "Alto riesgo");
+        assert!((response.predicted_mortality - 53.2).abs() < 1.0, "{}", response.predicted_mortality);
+        assert!(response.recommendation.starts_with("Mortalidad predicha: "));
+
+        assert!(SAPSIIRequest { glasgow: 16, ..request() }.calculate().is_err());
+        assert!(SAPSIIRequest { admission_type: "urgent".to_string(), ..request() }.calculate().is_err());
+        assert!(SAPSIIRequest { chronic_disease: "diabetes".to_string(), ..request() }.calculate().is_err());
+    }
+}
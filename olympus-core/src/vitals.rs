@@ -0,0 +1,201 @@
+use crate::apache::ApacheAssessment;
+use crate::saps::SapsAssessment;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Rango fisiológicamente plausible de una constante; fuera de él es un error de captura
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VitalRange {
+    pub field: &'static str,
+    pub unit: &'static str,
+    pub min: f64,
+    pub max: f64,
+}
+
+pub const RESPIRATION_RATE_RANGE: VitalRange = VitalRange { field: "respiration_rate", unit: "rpm", min: 0.0, max: 80.0 };
+pub const OXYGEN_SATURATION_RANGE: VitalRange = VitalRange { field: "oxygen_saturation", unit: "%", min: 0.0, max: 100.0 };
+pub const TEMPERATURE_RANGE: VitalRange = VitalRange { field: "temperature", unit: "°C", min: 25.0, max: 45.0 };
+pub const HEART_RATE_RANGE: VitalRange = VitalRange { field: "heart_rate", unit: "lpm", min: 0.0, max: 300.0 };
+pub const SYSTOLIC_BP_RANGE: VitalRange = VitalRange { field: "systolic_bp", unit: "mmHg", min: 0.0, max: 300.0 };
+
+#[derive(Debug, Clone, PartialEq, Error, Serialize)]
+#[error("{field} = {value} fuera de rango ({min}-{max} {unit})")]
+pub struct VitalSignError {
+    pub field: &'static str,
+    pub value: f64,
+    pub min: f64,
+    pub max: f64,
+    pub unit: &'static str,
+}
+
+impl VitalRange {
+    fn check(&self, value: Option<f64>) -> Option<VitalSignError> {
+        let value = value?;
+        (!(self.min..=self.max).contains(&value)).then_some(VitalSignError {
+            field: self.field,
+            value,
+            min: self.min,
+            max: self.max,
+            unit: self.unit,
+        })
+    }
+}
+
+/// Constantes vitales básicas. Una única definición para NEWS2, las observaciones de
+/// constantes y la comparación de escalas; cada campo puede faltar en una observación.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct VitalSigns {
+    #[serde(default)]
+    pub respiration_rate: Option<i32>,
+    #[serde(default)]
+    pub oxygen_saturation: Option<i32>,
+    /// °C
+    #[serde(default)]
+    pub temperature: Option<f32>,
+    #[serde(default)]
+    pub heart_rate: Option<i32>,
+    #[serde(default)]
+    pub systolic_bp: Option<i32>,
+}
+
+impl VitalSigns {
+    /// Comprueba los rangos de las constantes informadas; las ausentes no cuentan
+    pub fn validate(&self) -> Result<(), Vec<VitalSignError>> {
+        let errors: Vec<VitalSignError> = [
+            RESPIRATION_RATE_RANGE.check(self.respiration_rate.map(f64::from)),
+            OXYGEN_SATURATION_RANGE.check(self.oxygen_saturation.map(f64::from)),
+            TEMPERATURE_RANGE.check(self.temperature.map(f64::from)),
+            HEART_RATE_RANGE.check(self.heart_rate.map(f64::from)),
+            SYSTOLIC_BP_RANGE.check(self.systolic_bp.map(f64::from)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Campos sin informar, en el orden de la definición
+    pub fn missing(&self) -> Vec<&'static str> {
+        [
+            (RESPIRATION_RATE_RANGE.field, self.respiration_rate.is_none()),
+            (OXYGEN_SATURATION_RANGE.field, self.oxygen_saturation.is_none()),
+            (TEMPERATURE_RANGE.field, self.temperature.is_none()),
+            (HEART_RATE_RANGE.field, self.heart_rate.is_none()),
+            (SYSTOLIC_BP_RANGE.field, self.systolic_bp.is_none()),
+        ]
+        .into_iter()
+        .filter_map(|(field, absent)| absent.then_some(field))
+        .collect()
+    }
+
+    /// Las cinco constantes, si están todas
+    pub fn complete(&self) -> Option<CompleteVitalSigns> {
+        CompleteVitalSigns::try_from(*self).ok()
+    }
+}
+
+/// Las cinco constantes presentes: lo que exige NEWS2
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CompleteVitalSigns {
+    pub respiration_rate: i32,
+    pub oxygen_saturation: i32,
+    /// °C
+    pub temperature: f32,
+    pub heart_rate: i32,
+    pub systolic_bp: i32,
+}
+
+impl CompleteVitalSigns {
+    pub fn validate(&self) -> Result<(), Vec<VitalSignError>> {
+        VitalSigns::from(*self).validate()
+    }
+}
+
+impl From<CompleteVitalSigns> for VitalSigns {
+    fn from(vitals: CompleteVitalSigns) -> Self {
+        Self {
+            respiration_rate: Some(vitals.respiration_rate),
+            oxygen_saturation: Some(vitals.oxygen_saturation),
+            temperature: Some(vitals.temperature),
+            heart_rate: Some(vitals.heart_rate),
+            systolic_bp: Some(vitals.systolic_bp),
+        }
+    }
+}
+
+/// Falla con la lista de campos ausentes
+impl TryFrom<VitalSigns> for CompleteVitalSigns {
+    type Error = Vec<&'static str>;
+
+    fn try_from(vitals: VitalSigns) -> Result<Self, Self::Error> {
+        match (vitals.respiration_rate, vitals.oxygen_saturation, vitals.temperature, vitals.heart_rate, vitals.systolic_bp) {
+            (Some(respiration_rate), Some(oxygen_saturation), Some(temperature), Some(heart_rate), Some(systolic_bp)) => Ok(Self {
+                respiration_rate,
+                oxygen_saturation,
+                temperature,
+                heart_rate,
+                systolic_bp,
+            }),
+            _ => Err(vitals.missing()),
+        }
+    }
+}
+
+/// APACHE II registra temperatura, frecuencia cardíaca y respiratoria (la presión es la media)
+impl From<&ApacheAssessment> for VitalSigns {
+    fn from(assessment: &ApacheAssessment) -> Self {
+        Self {
+            respiration_rate: Some(assessment.respiratory_rate),
+            temperature: Some(assessment.temperature),
+            heart_rate: Some(assessment.heart_rate),
+            ..Default::default()
+        }
+    }
+}
+
+/// SAPS II registra temperatura, frecuencia cardíaca y presión sistólica
+impl From<&SapsAssessment> for VitalSigns {
+    fn from(assessment: &SapsAssessment) -> Self {
+        Self {
+            temperature: Some(assessment.temperature as f32),
+            heart_rate: Some(assessment.heart_rate),
+            systolic_bp: Some(assessment.systolic_bp),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ranges_and_completeness() {
+        let complete = CompleteVitalSigns {
+            respiration_rate: 16,
+            oxygen_saturation: 97,
+            temperature: 37.0,
+            heart_rate: 80,
+            systolic_bp: 120,
+        };
+        assert_eq!(complete.validate(), Ok(()));
+        assert_eq!(VitalSigns::from(complete).complete(), Some(complete));
+
+        let partial = VitalSigns { heart_rate: Some(350), temperature: Some(36.5), ..Default::default() };
+        let errors = partial.validate().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "heart_rate");
+        assert_eq!(
+            CompleteVitalSigns::try_from(partial).unwrap_err(),
+            vec!["respiration_rate", "oxygen_saturation", "systolic_bp"]
+        );
+
+        // Serializa con los mismos nombres que las peticiones de la API
+        let parsed: VitalSigns = serde_json::from_str(r#"{ "respiration_rate": 22, "temperature": 38.5 }"#).unwrap();
+        assert_eq!(parsed.missing(), vec!["oxygen_saturation", "heart_rate", "systolic_bp"]);
+    }
+}
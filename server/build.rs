@@ -0,0 +1,33 @@
+// server/build.rs
+// Metadatos de compilación: commit de git y marca de tiempo del build
+
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Salida de `git <args>`; `None` fuera de un repositorio o sin git
+fn git(args: &[&str]) -> Option<String> {
+    Command::new("git")
+        .args(args)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| s.trim().to_string())
+}
+
+fn main() {
+    let commit = git(&["rev-parse", "--short", "HEAD"]).unwrap_or_else(|| "unknown".to_string());
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    println!("cargo:rustc-env=OLYMPUS_GIT_COMMIT={}", commit);
+    println!("cargo:rustc-env=OLYMPUS_BUILD_TIMESTAMP={}", timestamp);
+    // Ruta del .git resuelta por git: el script sirve desde cualquier crate del repositorio
+    if let Some(git_dir) = git(&["rev-parse", "--absolute-git-dir"]) {
+        println!("cargo:rerun-if-changed={}/HEAD", git_dir);
+        println!("cargo:rerun-if-changed={}/refs", git_dir);
+    }
+}
@@ -4,6 +4,7 @@
 
 use async_trait::async_trait;
 use super::{ActorMessage, GodName, MessagePayload, OlympianActor, GodHealth};
+use super::hestia::HestiaStore;
 use chrono::Utc;
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
@@ -54,23 +55,18 @@ pub struct Component {
     pub active: bool,
 }
 
-pub struct Aphrodite {
-    current_theme: Theme,
-    available_themes: Vec<Theme>,
-    components: HashMap<String, Component>,
-    messages_count: u64,
-    theme_changes: u64,
-}
+// Claves en Hestia
+pub const CURRENT_THEME_KEY: &str = "aphrodite.current_theme";
+pub const CUSTOM_THEMES_KEY: &str = "aphrodite.custom_themes";
+pub const COMPONENTS_KEY: &str = "aphrodite.components";
 
-impl Aphrodite {
-    pub fn new() -> Self {
-        let mut themes = Vec::new();
-        
+/// Temas incluidos de serie; el primero es el tema por defecto
+pub fn builtin_themes() -> Vec<Theme> {
+    vec![
         // Tema por defecto (Olympus Dark)
-        themes.push(Theme::default());
-        
+        Theme::default(),
         // Tema Claro
-        themes.push(Theme {
+        Theme {
             name: "Olympus Light".to_string(),
             primary_color: "#4f46e5".to_string(),
             secondary_color: "#7c3aed".to_string(),
@@ -84,10 +80,9 @@ impl Aphrodite {
             error: "#ef4444".to_string(),
             border_radius: "0.75rem".to_string(),
             font_family: "Inter, system-ui, sans-serif".to_string(),
-        });
-        
+        },
         // Tema Dorado (Oro del Olimpo)
-        themes.push(Theme {
+        Theme {
             name: "Golden Olympus".to_string(),
             primary_color: "#fbbf24".to_string(),
             secondary_color: "#f59e0b".to_string(),
@@ -101,10 +96,9 @@ impl Aphrodite {
             error: "#f87171".to_string(),
             border_radius: "1rem".to_string(),
             font_family: "Georgia, serif".to_string(),
-        });
-        
+        },
         // Tema Cósmico
-        themes.push(Theme {
+        Theme {
             name: "Cosmic".to_string(),
             primary_color: "#06b6d4".to_string(),
             secondary_color: "#8b5cf6".to_string(),
@@ -118,51 +112,211 @@ impl Aphrodite {
             error: "#f472b6".to_string(),
             border_radius: "0.5rem".to_string(),
             font_family: "SF Mono, monospace".to_string(),
-        });
-        
-        let mut components = HashMap::new();
-        
-        // Componentes base
-        components.insert("button".to_string(), Component {
-            id: "button".to_string(),
-            name: "Botón".to_string(),
-            component_type: "button".to_string(),
-            styles: [
-                ("padding".to_string(), "0.75rem 1.5rem".to_string()),
-                ("borderRadius".to_string(), "0.5rem".to_string()),
-                ("fontWeight".to_string(), "600".to_string()),
-                ("transition".to_string(), "all 0.2s".to_string()),
-            ].into_iter().collect(),
-            active: true,
-        });
-        
-        components.insert("card".to_string(), Component {
-            id: "card".to_string(),
-            name: "Tarjeta".to_string(),
-            component_type: "card".to_string(),
-            styles: [
-                ("padding".to_string(), "1.5rem".to_string()),
-                ("borderRadius".to_string(), "0.75rem".to_string()),
-                ("borderWidth".to_string(), "1px".to_string()),
-                ("boxShadow".to_string(), "0 4px 6px -1px rgba(0,0,0,0.1)".to_string()),
-            ].into_iter().collect(),
-            active: true,
-        });
+        },
+    ]
+}
+
+/// Componentes base con sus estilos de serie
+pub fn default_components() -> HashMap<String, Component> {
+    let mut components = HashMap::new();
+
+    components.insert("button".to_string(), Component {
+        id: "button".to_string(),
+        name: "Botón".to_string(),
+        component_type: "button".to_string(),
+        styles: [
+            ("padding".to_string(), "0.75rem 1.5rem".to_string()),
+            ("borderRadius".to_string(), "0.5rem".to_string()),
+            ("fontWeight".to_string(), "600".to_string()),
+            ("transition".to_string(), "all 0.2s".to_string()),
+        ].into_iter().collect(),
+        active: true,
+    });
+
+    components.insert("card".to_string(), Component {
+        id: "card".to_string(),
+        name: "Tarjeta".to_string(),
+        component_type: "card".to_string(),
+        styles: [
+            ("padding".to_string(), "1.5rem".to_string()),
+            ("borderRadius".to_string(), "0.75rem".to_string()),
+            ("borderWidth".to_string(), "1px".to_string()),
+            ("boxShadow".to_string(), "0 4px 6px -1px rgba(0,0,0,0.1)".to_string()),
+        ].into_iter().collect(),
+        active: true,
+    });
+
+    components.insert("nav".to_string(), Component {
+        id: "nav".to_string(),
+        name: "Navegación".to_string(),
+        component_type: "nav".to_string(),
+        styles: [
+            ("padding".to_string(), "1rem".to_string()),
+            ("position".to_string(), "sticky".to_string()),
+            ("top".to_string(), "0".to_string()),
+            ("zIndex".to_string(), "50".to_string()),
+        ].into_iter().collect(),
+        active: true,
+    });
+
+    components
+}
+
+/// Componentes vigentes: los de serie con los estilos guardados en Hestia encima
+pub fn persisted_components(store: &HestiaStore) -> HashMap<String, Component> {
+    let mut components = default_components();
+    if let Some(saved) = store
+        .get(COMPONENTS_KEY)
+        .and_then(|v| serde_json::from_value::<HashMap<String, Component>>(v).ok())
+    {
+        components.extend(saved);
+    }
+    components
+}
+
+/// Estilos que difieren de los de serie: lo único que el frontend inyecta, para no
+/// pisar las clases propias de cada elemento con los valores por defecto
+pub fn style_overrides(component: &Component) -> HashMap<String, String> {
+    let defaults = default_components().remove(&component.id).map(|c| c.styles).unwrap_or_default();
+    component
+        .styles
+        .iter()
+        .filter(|(key, value)| defaults.get(*key) != Some(*value))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+/// Los estilos acaban dentro de una hoja CSS: la clave debe ser una propiedad en
+/// camelCase y el valor no puede cerrar la declaración ni la regla
+pub fn validate_style(style_key: &str, style_value: &str) -> Result<(), String> {
+    if style_key.is_empty() || !style_key.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(format!("Propiedad de estilo no válida: '{}'", style_key));
+    }
+    if style_value.trim().is_empty() || style_value.contains([';', '{', '}', '<', '>']) {
+        return Err(format!("Valor de estilo no válido para {}: '{}'", style_key, style_value));
+    }
+    Ok(())
+}
+
+/// Actualiza un estilo directamente en Hestia, igual que `apply_theme`: la API y
+/// Aphrodite leen de ahí, así que una lectura inmediata ya ve el cambio
+pub async fn apply_component_style(store: &HestiaStore, component_id: &str, style_key: &str, style_value: &str) -> Result<Component, String> {
+    validate_style(style_key, style_value)?;
+    store.update(COMPONENTS_KEY, |current| {
+        let mut components = default_components();
+        if let Ok(saved) = serde_json::from_value::<HashMap<String, Component>>(current.clone()) {
+            components.extend(saved);
+        }
+        let component = components
+            .get_mut(component_id)
+            .ok_or_else(|| format!("Componente '{}' no encontrado", component_id))?;
+        component.styles.insert(style_key.to_string(), style_value.to_string());
+        let updated = component.clone();
+        *current = serde_json::json!(components);
+        Ok(updated)
+    })
+    .await?
+}
+
+/// Tema activo persistido, si alguna vez se cambió
+pub fn persisted_theme(store: &HestiaStore) -> Option<Theme> {
+    serde_json::from_value(store.get(CURRENT_THEME_KEY)?).ok()
+}
+
+/// Tema activo junto con su revisión (número de cambios aplicados), leídos a la vez
+pub fn persisted_theme_with_revision(store: &HestiaStore) -> Option<(Theme, u64)> {
+    let stored = store.get(CURRENT_THEME_KEY)?;
+    let revision = stored.get("revision").and_then(|r| r.as_u64()).unwrap_or(0);
+    Some((serde_json::from_value(stored).ok()?, revision))
+}
+
+/// Tema vigente: el persistido o, si nunca se cambió, el tema por defecto
+pub fn current_theme(store: &HestiaStore) -> Theme {
+    persisted_theme(store).unwrap_or_default()
+}
+
+/// Variables CSS del tema, con la paleta completa
+pub fn css_variables(theme: &Theme) -> String {
+    format!(
+        r#":root {{
+  --color-primary: {};
+  --color-secondary: {};
+  --color-background: {};
+  --color-surface: {};
+  --color-text-primary: {};
+  --color-text-secondary: {};
+  --color-accent: {};
+  --color-success: {};
+  --color-warning: {};
+  --color-error: {};
+  --border-radius: {};
+  --font-family: {};
+}}"#,
+        theme.primary_color,
+        theme.secondary_color,
+        theme.background,
+        theme.surface,
+        theme.text_primary,
+        theme.text_secondary,
+        theme.accent,
+        theme.success,
+        theme.warning,
+        theme.error,
+        theme.border_radius,
+        theme.font_family,
+    )
+}
+
+/// Busca un tema de serie o personalizado por nombre
+pub fn find_theme(store: &HestiaStore, name: &str) -> Option<Theme> {
+    persisted_custom_themes(store)
+        .into_iter()
+        .chain(builtin_themes())
+        .find(|t| t.name == name)
+}
+
+/// Activa el tema en Hestia, que es la fuente de verdad: el cambio y su revisión se
+/// escriben bajo el mismo bloqueo, así que dos cambios simultáneos quedan ordenados
+/// y el de mayor revisión es el vigente para todos los clientes.
+pub async fn apply_theme(store: &HestiaStore, name: &str) -> Result<(Theme, u64), String> {
+    let theme = find_theme(store, name).ok_or_else(|| format!("Tema '{}' no encontrado", name))?;
+    let mut stored = serde_json::to_value(&theme).map_err(|e| e.to_string())?;
+
+    let revision = store.update(CURRENT_THEME_KEY, |current| {
+        let revision = current.get("revision").and_then(|r| r.as_u64()).unwrap_or(0) + 1;
+        stored["revision"] = serde_json::json!(revision);
+        *current = stored;
+        revision
+    })
+    .await?;
+    Ok((theme, revision))
+}
+
+/// Temas personalizados persistidos
+pub fn persisted_custom_themes(store: &HestiaStore) -> Vec<Theme> {
+    store
+        .get(CUSTOM_THEMES_KEY)
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+pub struct Aphrodite {
+    store: HestiaStore,
+    current_theme: Theme,
+    available_themes: Vec<Theme>,
+    components: HashMap<String, Component>,
+    messages_count: u64,
+    theme_changes: u64,
+}
+
+impl Aphrodite {
+    pub fn new(store: HestiaStore) -> Self {
+        let themes = builtin_themes();
         
-        components.insert("nav".to_string(), Component {
-            id: "nav".to_string(),
-            name: "Navegación".to_string(),
-            component_type: "nav".to_string(),
-            styles: [
-                ("padding".to_string(), "1rem".to_string()),
-                ("position".to_string(), "sticky".to_string()),
-                ("top".to_string(), "0".to_string()),
-                ("zIndex".to_string(), "50".to_string()),
-            ].into_iter().collect(),
-            active: true,
-        });
+        let components = default_components();
         
         Self {
+            store,
             current_theme: themes[0].clone(),
             available_themes: themes,
             components,
@@ -171,14 +325,64 @@ impl Aphrodite {
         }
     }
     
-    fn switch_theme(&mut self, theme_name: &str) -> Result<Theme, String> {
-        if let Some(theme) = self.available_themes.iter().find(|t| t.name == theme_name) {
-            self.current_theme = theme.clone();
-            self.theme_changes += 1;
-            tracing::info!("🎨 Aphrodite: Cambiado a tema '{}'", theme_name);
-            Ok(theme.clone())
-        } else {
-            Err(format!("Tema '{}' no encontrado", theme_name))
+    async fn persist(&self, key: &str, value: serde_json::Value) {
+        if let Err(e) = self.store.set(key, value).await {
+            tracing::error!("🎨 Aphrodite: No se pudo persistir {}: {}", key, e);
+        }
+    }
+
+    /// Restaura tema activo, temas personalizados y estilos guardados en Hestia
+    fn load_persisted(&mut self) {
+        for custom in persisted_custom_themes(&self.store) {
+            self.upsert_theme(custom);
+        }
+
+        self.components = persisted_components(&self.store);
+
+        if let Some(saved) = persisted_theme(&self.store) {
+            // Preferir la definición actual del tema si sigue existiendo
+            self.current_theme = self
+                .available_themes
+                .iter()
+                .find(|t| t.name == saved.name)
+                .cloned()
+                .unwrap_or(saved);
+            tracing::info!("🎨 Aphrodite: Tema persistido '{}' restaurado", self.current_theme.name);
+        }
+    }
+
+    fn upsert_theme(&mut self, theme: Theme) {
+        match self.available_themes.iter_mut().find(|t| t.name == theme.name) {
+            Some(existing) => *existing = theme,
+            None => self.available_themes.push(theme),
+        }
+    }
+
+    async fn create_custom_theme(&mut self, theme: Theme) {
+        let mut custom = persisted_custom_themes(&self.store);
+        custom.retain(|t| t.name != theme.name);
+        custom.push(theme.clone());
+        self.persist(CUSTOM_THEMES_KEY, serde_json::json!(custom)).await;
+
+        tracing::info!("🎨 Aphrodite: Tema personalizado '{}' creado", theme.name);
+        self.upsert_theme(theme);
+    }
+
+    async fn switch_theme(&mut self, theme_name: &str) -> Result<Theme, String> {
+        let (theme, revision) = apply_theme(&self.store, theme_name).await?;
+        self.current_theme = theme.clone();
+        self.theme_changes += 1;
+        tracing::info!("🎨 Aphrodite: Cambiado a tema '{}' (revisión {})", theme_name, revision);
+        Ok(theme)
+    }
+
+    /// El tema ya se aplicó en Hestia (desde la API): solo alinear el estado en memoria
+    fn sync_theme(&mut self) {
+        if let Some(saved) = persisted_theme(&self.store) {
+            if saved.name != self.current_theme.name {
+                self.theme_changes += 1;
+            }
+            self.current_theme = saved;
         }
     }
     
@@ -190,14 +394,16 @@ impl Aphrodite {
         self.available_themes.iter().map(|t| t.name.clone()).collect()
     }
     
-    fn update_component_style(&mut self, component_id: &str, style_key: &str, style_value: &str) -> Result<(), String> {
-        if let Some(component) = self.components.get_mut(component_id) {
-            component.styles.insert(style_key.to_string(), style_value.to_string());
-            tracing::info!("🎨 Aphrodite: Actualizado {}.{} = {}", component_id, style_key, style_value);
-            Ok(())
-        } else {
-            Err(format!("Componente '{}' no encontrado", component_id))
-        }
+    async fn update_component_style(&mut self, component_id: &str, style_key: &str, style_value: &str) -> Result<(), String> {
+        let component = apply_component_style(&self.store, component_id, style_key, style_value).await?;
+        self.components.insert(component.id.clone(), component);
+        tracing::info!("🎨 Aphrodite: Actualizado {}.{} = {}", component_id, style_key, style_value);
+        Ok(())
+    }
+
+    /// Los estilos ya se escribieron en Hestia (desde la API): recargarlos
+    fn sync_components(&mut self) {
+        self.components = persisted_components(&self.store);
     }
     
     fn get_component_styles(&self, component_id: &str) -> Option<HashMap<String, String>> {
@@ -205,34 +411,7 @@ impl Aphrodite {
     }
     
     fn generate_css_variables(&self) -> String {
-        format!(
-            r#":root {{
-  --color-primary: {};
-  --color-secondary: {};
-  --color-background: {};
-  --color-surface: {};
-  --color-text-primary: {};
-  --color-text-secondary: {};
-  --color-accent: {};
-  --color-success: {};
-  --color-warning: {};
-  --color-error: {};
-  --border-radius: {};
-  --font-family: {};
-}}"#,
-            self.current_theme.primary_color,
-            self.current_theme.secondary_color,
-            self.current_theme.background,
-            self.current_theme.surface,
-            self.current_theme.text_primary,
-            self.current_theme.text_secondary,
-            self.current_theme.accent,
-            self.current_theme.success,
-            self.current_theme.warning,
-            self.current_theme.error,
-            self.current_theme.border_radius,
-            self.current_theme.font_family,
-        )
+        css_variables(&self.current_theme)
     }
 }
 
@@ -294,7 +473,7 @@ impl OlympianActor for Aphrodite {
                 let result = match action.as_str() {
                     "switch_theme" => {
                         if let Some(theme_name) = data.get("theme_name").and_then(|v| v.as_str()) {
-                            match self.switch_theme(theme_name) {
+                            match self.switch_theme(theme_name).await {
                                 Ok(theme) => serde_json::json!({
                                     "success": true,
                                     "theme": theme,
@@ -313,13 +492,29 @@ impl OlympianActor for Aphrodite {
                         }
                     }
                     
+                    "sync_theme" => {
+                        self.sync_theme();
+                        serde_json::json!({
+                            "success": true,
+                            "theme": self.current_theme,
+                        })
+                    }
+                    
+                    "sync_components" => {
+                        self.sync_components();
+                        serde_json::json!({
+                            "success": true,
+                            "components": self.components.len(),
+                        })
+                    }
+                    
                     "update_component_style" => {
                         let component_id = data.get("component_id").and_then(|v| v.as_str());
                         let style_key = data.get("style_key").and_then(|v| v.as_str());
                         let style_value = data.get("style_value").and_then(|v| v.as_str());
                         
                         if let (Some(cid), Some(key), Some(val)) = (component_id, style_key, style_value) {
-                            match self.update_component_style(cid, key, val) {
+                            match self.update_component_style(cid, key, val).await {
                                 Ok(_) => serde_json::json!({
                                     "success": true,
                                     "message": "Estilo actualizado",
@@ -339,10 +534,20 @@ impl OlympianActor for Aphrodite {
                     
                     "create_custom_theme" => {
                         // Crear tema personalizado desde datos
-                        serde_json::json!({
-                            "success": true,
-                            "message": "Tema personalizado creado (demo)",
-                        })
+                        match serde_json::from_value::<Theme>(data.clone()) {
+                            Ok(theme) => {
+                                let name = theme.name.clone();
+                                self.create_custom_theme(theme).await;
+                                serde_json::json!({
+                                    "success": true,
+                                    "message": format!("Tema personalizado '{}' creado", name),
+                                })
+                            }
+                            Err(e) => serde_json::json!({
+                                "success": false,
+                                "error": format!("Tema inválido: {}", e),
+                            }),
+                        }
                     }
                     
                     _ => return None,
@@ -376,6 +581,7 @@ impl OlympianActor for Aphrodite {
 
     async fn initialize(&mut self) -> Result<(), String> {
         tracing::info!("🎨 Aphrodite: Inicializando sistema de belleza...");
+        self.load_persisted();
         tracing::info!("🎨 Aphrodite: {} temas disponibles", self.available_themes.len());
         tracing::info!("🎨 Aphrodite: {} componentes registrados", self.components.len());
         Ok(())
@@ -386,3 +592,67 @@ impl OlympianActor for Aphrodite {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_theme_survives_restart() {
+        let store = HestiaStore::in_memory();
+
+        let mut aphrodite = Aphrodite::new(store.clone());
+        aphrodite.initialize().await.unwrap();
+        aphrodite.switch_theme("Cosmic").await.unwrap();
+        aphrodite.update_component_style("card", "padding", "2rem").await.unwrap();
+
+        // Nueva instancia sobre el mismo almacén, como tras un reinicio
+        let mut restarted = Aphrodite::new(store);
+        restarted.initialize().await.unwrap();
+        assert_eq!(restarted.get_current_theme().name, "Cosmic");
+        assert_eq!(restarted.get_component_styles("card").unwrap()["padding"], "2rem");
+    }
+
+    #[tokio::test]
+    async fn test_component_style_overrides_are_persisted_and_validated() {
+        let store = HestiaStore::in_memory();
+        assert!(persisted_components(&store)["button"].styles.contains_key("padding"));
+        assert!(style_overrides(&persisted_components(&store)["button"]).is_empty());
+
+        apply_component_style(&store, "button", "borderRadius", "9999px").await.unwrap();
+        let overrides = style_overrides(&persisted_components(&store)["button"]);
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(overrides["borderRadius"], "9999px");
+
+        assert!(apply_component_style(&store, "slider", "borderRadius", "1rem").await.is_err());
+        assert!(apply_component_style(&store, "button", "border-radius", "1rem").await.is_err());
+        assert!(apply_component_style(&store, "button", "color", "red} body {display:none").await.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_concurrent_switches_converge_on_highest_revision() {
+        let store = HestiaStore::in_memory();
+        let handles: Vec<_> = ["Cosmic", "Olympus Light"]
+            .into_iter()
+            .map(|name| {
+                let store = store.clone();
+                tokio::spawn(async move { apply_theme(&store, name).await.unwrap() })
+            })
+            .collect();
+        let mut results: Vec<(Theme, u64)> = Vec::new();
+        for handle in handles {
+            results.push(handle.await.unwrap());
+        }
+
+        // Cada cambio obtiene su propia revisión y el vigente es el de la mayor
+        let mut revisions: Vec<u64> = results.iter().map(|(_, r)| *r).collect();
+        revisions.sort();
+        assert_eq!(revisions, vec![1, 2]);
+        let winner = results.iter().max_by_key(|(_, r)| *r).unwrap();
+        let (current, revision) = persisted_theme_with_revision(&store).unwrap();
+        assert_eq!((current.name.as_str(), revision), (winner.0.name.as_str(), 2));
+
+        assert!(apply_theme(&store, "Inexistente").await.is_err());
+        assert_eq!(persisted_theme_with_revision(&store).unwrap().1, 2);
+    }
+}
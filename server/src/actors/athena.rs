@@ -2,45 +2,78 @@
 // Athena: Escalas Médicas, ML y Análisis Clínico
 
 use async_trait::async_trait;
-use super::{ActorMessage, GodName, MessagePayload, OlympianActor, GodHealth};
+use super::{stats_reset_response, ActorMessage, GodName, MessagePayload, OlympianActor, GodHealth, RESET_STATS_ACTION};
 use chrono::Utc;
+use std::collections::BTreeMap;
+use crate::i18n::Lang;
+use olympus_core::apache::ApacheIIRequest;
+use olympus_core::saps::SAPSIIRequest;
+use crate::scales::{
+    apache_result, glasgow_result, news2_sub_score, saps_result, HEART_RATE_BANDS, OXYGEN_SATURATION_BANDS, RESPIRATION_RATE_BANDS, SYSTOLIC_BP_BANDS, TEMPERATURE_BANDS,
+};
 
 pub struct Athena {
-    scales_calculated: u64,
+    /// Cálculos por escala ("Glasgow", "SOFA", "NEWS2", "APACHE II", "SAPS II")
+    scales_calculated: BTreeMap<&'static str, u64>,
     messages_count: u64,
 }
 
 impl Athena {
     pub fn new() -> Self {
         Self {
-            scales_calculated: 0,
+            scales_calculated: BTreeMap::new(),
             messages_count: 0,
         }
     }
 
-    fn calculate_glasgow(&mut self, eye: i32, verbal: i32, motor: i32) -> serde_json::Value {
-        self.scales_calculated += 1;
-        let total = eye + verbal + motor;
-        
-        let interpretation = match total {
-            3..=8 => "Coma severo",
-            9..=12 => "Coma moderado",
-            13..=15 => "Coma leve/Normal",
-            _ => "Error",
-        };
+    fn count_calculation(&mut self, scale: &'static str) {
+        *self.scales_calculated.entry(scale).or_insert(0) += 1;
+    }
 
-        serde_json::json!({
-            "eye": eye,
-            "verbal": verbal,
-            "motor": motor,
-            "total": total,
-            "interpretation": interpretation,
-            "scale": "Glasgow"
-        })
+    fn total_calculated(&self) -> u64 {
+        self.scales_calculated.values().sum()
+    }
+
+    fn invalid_command(&self, to: GodName, error: String) -> ActorMessage {
+        ActorMessage::new(
+            GodName::Athena,
+            to,
+            MessagePayload::Response { success: false, data: serde_json::Value::Null, error: Some(error) },
+        )
+    }
+
+    fn reset_stats(&mut self, to: GodName) -> ActorMessage {
+        let previous = serde_json::json!({
+            "messages_processed": self.messages_count,
+            "scales_calculated": self.total_calculated(),
+            "by_scale": self.scales_calculated,
+        });
+        self.messages_count = 0;
+        self.scales_calculated.clear();
+        stats_reset_response(GodName::Athena, to, previous)
+    }
+
+    /// Resultado completo (interpretación localizada y GCS-P); la API lo devuelve tal cual
+    fn calculate_glasgow(&mut self, req: &crate::GlasgowRequest, lang: Lang) -> serde_json::Value {
+        self.count_calculation("Glasgow");
+        glasgow_result(req, lang)
+    }
+
+    /// Puntuación, mortalidad y gravedad con los mismos cálculos que el servidor monolítico
+    fn calculate_apache(&mut self, req: &ApacheIIRequest) -> Result<serde_json::Value, String> {
+        let result = apache_result(req)?;
+        self.count_calculation("APACHE II");
+        Ok(result)
+    }
+
+    fn calculate_saps(&mut self, req: &SAPSIIRequest) -> Result<serde_json::Value, String> {
+        let result = saps_result(req)?;
+        self.count_calculation("SAPS II");
+        Ok(result)
     }
 
     fn calculate_sofa(&mut self, resp: i32, coag: i32, liver: i32, cardio: i32, cns: i32, renal: i32) -> serde_json::Value {
-        self.scales_calculated += 1;
+        self.count_calculation("SOFA");
         let total = resp + coag + liver + cardio + cns + renal;
         
         let mortality = match total {
@@ -65,53 +98,14 @@ impl Athena {
     }
 
     fn calculate_news2(&mut self, resp_rate: i32, spo2: i32, temp: f32, hr: i32, systolic: i32) -> serde_json::Value {
-        self.scales_calculated += 1;
+        self.count_calculation("NEWS2");
         
-        // Simplificación de NEWS2
-        let resp_score = match resp_rate {
-            0..=8 => 3,
-            9..=11 => 1,
-            12..=20 => 0,
-            21..=24 => 2,
-            25..=i32::MAX => 3,
-            _ => 0,
-        };
-
-        let spo2_score = match spo2 {
-            0..=91 => 3,
-            92..=93 => 2,
-            94..=95 => 1,
-            96..=100 => 0,
-            _ => 0,
-        };
-
-        let temp_score = match temp {
-            t if t < 35.0 => 3,
-            t if t >= 35.0 && t <= 36.0 => 1,
-            t if t > 36.0 && t <= 38.0 => 0,
-            t if t > 38.0 && t <= 39.0 => 1,
-            t if t > 39.0 => 2,
-            _ => 0,
-        };
-
-        let hr_score = match hr {
-            0..=40 => 3,
-            41..=50 => 1,
-            51..=90 => 0,
-            91..=110 => 1,
-            111..=130 => 2,
-            131..=i32::MAX => 3,
-            _ => 0,
-        };
-
-        let bp_score = match systolic {
-            0..=90 => 3,
-            91..=100 => 2,
-            101..=110 => 1,
-            111..=219 => 0,
-            220..=i32::MAX => 3,
-            _ => 0,
-        };
+        // Mismas bandas que el gráfico de observaciones (/api/scales/news2/chart-thresholds)
+        let resp_score = news2_sub_score(&RESPIRATION_RATE_BANDS, resp_rate as f32);
+        let spo2_score = news2_sub_score(&OXYGEN_SATURATION_BANDS, spo2 as f32);
+        let temp_score = news2_sub_score(&TEMPERATURE_BANDS, temp);
+        let hr_score = news2_sub_score(&HEART_RATE_BANDS, hr as f32);
+        let bp_score = news2_sub_score(&SYSTOLIC_BP_BANDS, systolic as f32);
 
         let total = resp_score + spo2_score + temp_score + hr_score + bp_score;
 
@@ -148,10 +142,32 @@ impl OlympianActor for Athena {
             MessagePayload::Command { action, data } => {
                 let result = match action.as_str() {
                     "calculate_glasgow" => {
-                        let eye = data.get("eye")?.as_i64()? as i32;
-                        let verbal = data.get("verbal")?.as_i64()? as i32;
-                        let motor = data.get("motor")?.as_i64()? as i32;
-                        self.calculate_glasgow(eye, verbal, motor)
+                        let req = match serde_json::from_value(data.clone()) {
+                            Ok(req) => req,
+                            Err(e) => return Some(self.invalid_command(msg.from, format!("Glasgow no válido: {}", e))),
+                        };
+                        let lang = data.get("lang").and_then(|l| serde_json::from_value(l.clone()).ok()).unwrap_or_default();
+                        self.calculate_glasgow(&req, lang)
+                    }
+
+                    "calculate_apache" => {
+                        let calculated = serde_json::from_value(data.clone())
+                            .map_err(|e| e.to_string())
+                            .and_then(|req| self.calculate_apache(&req));
+                        match calculated {
+                            Ok(result) => result,
+                            Err(e) => return Some(self.invalid_command(msg.from, format!("APACHE II no válido: {}", e))),
+                        }
+                    }
+
+                    "calculate_saps" => {
+                        let calculated = serde_json::from_value(data.clone())
+                            .map_err(|e| e.to_string())
+                            .and_then(|req| self.calculate_saps(&req));
+                        match calculated {
+                            Ok(result) => result,
+                            Err(e) => return Some(self.invalid_command(msg.from, format!("SAPS II no válido: {}", e))),
+                        }
                     }
 
                     "calculate_sofa" => {
@@ -173,6 +189,8 @@ impl OlympianActor for Athena {
                         self.calculate_news2(resp, spo2, temp, hr, systolic)
                     }
 
+                    RESET_STATS_ACTION => return Some(self.reset_stats(msg.from)),
+
                     _ => return None,
                 };
 
@@ -198,7 +216,7 @@ impl OlympianActor for Athena {
             last_heartbeat: Utc::now(),
             messages_processed: self.messages_count,
             uptime_seconds: 0,
-            status: format!("Analyzed {} scales", self.scales_calculated),
+            status: format!("Analyzed {} scales", self.total_calculated()),
         }
     }
 
@@ -208,7 +226,42 @@ impl OlympianActor for Athena {
     }
 
     async fn shutdown(&mut self) -> Result<(), String> {
-        tracing::info!("🧠 Athena: Guardando {} cálculos...", self.scales_calculated);
+        tracing::info!("🧠 Athena: Guardando {} cálculos...", self.total_calculated());
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn command(action: &str, data: serde_json::Value) -> ActorMessage {
+        ActorMessage::new(
+            GodName::Zeus,
+            GodName::Athena,
+            MessagePayload::Command { action: action.to_string(), data },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_reset_stats_returns_previous_counts() {
+        let mut athena = Athena::new();
+        let glasgow = json!({ "eye": 4, "verbal": 5, "motor": 6 });
+        athena.handle_message(command("calculate_glasgow", glasgow.clone())).await;
+        athena.handle_message(command("calculate_glasgow", glasgow)).await;
+        let sofa = json!({ "respiratory": 1, "coagulation": 0, "liver": 0, "cardiovascular": 2, "cns": 0, "renal": 1 });
+        athena.handle_message(command("calculate_sofa", sofa)).await;
+
+        let reply = athena.handle_message(command(RESET_STATS_ACTION, json!({}))).await.unwrap();
+        let MessagePayload::Response { data, .. } = reply.payload else {
+            panic!("reset_stats debe responder");
+        };
+        assert_eq!(data["previous"]["scales_calculated"], 3);
+        assert_eq!(data["previous"]["by_scale"], json!({ "Glasgow": 2, "SOFA": 1 }));
+        assert_eq!(data["previous"]["messages_processed"], 4);
+
+        assert_eq!(athena.total_calculated(), 0);
+        assert_eq!(athena.health().await.messages_processed, 0);
+    }
+}
@@ -2,46 +2,261 @@
 // Erinyes: Monitoreo, Heartbeats y Alertas
 
 use async_trait::async_trait;
-use super::{ActorMessage, GodName, MessagePayload, OlympianActor, GodHealth};
-use chrono::Utc;
-use std::collections::HashMap;
+use super::{ActorMessage, GodName, MessagePayload, OlympianActor, GodHealth, TrinityMembers};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+/// Intentos de recuperación tras los que Erinyes deja de reintentar y escala a Zeus
+pub const MAX_RECOVERY_ATTEMPTS: u32 = 5;
+
+/// Nivel de vigilancia: la Trinidad se monitorea con intervalos más agresivos
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MonitoringTier {
+    Trinity,
+    Standard,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeartbeatConfig {
+    pub tier: MonitoringTier,
+    /// Cada cuánto se espera un heartbeat
+    pub interval_ms: u64,
+    /// Sin heartbeat durante este tiempo el dios se considera caído
+    pub timeout_ms: u64,
+}
+
+impl HeartbeatConfig {
+    pub const TRINITY: Self = Self { tier: MonitoringTier::Trinity, interval_ms: 2_000, timeout_ms: 6_000 };
+    pub const STANDARD: Self = Self { tier: MonitoringTier::Standard, interval_ms: 10_000, timeout_ms: 60_000 };
+
+    pub fn for_god(god: GodName, trinity: &TrinityMembers) -> Self {
+        if trinity.contains(god) {
+            Self::TRINITY
+        } else {
+            Self::STANDARD
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HeartbeatRegistration {
+    pub config: HeartbeatConfig,
+    pub registered_at: DateTime<Utc>,
+    pub last_heartbeat: Option<DateTime<Utc>>,
+}
+
+impl HeartbeatRegistration {
+    /// Sano si el último heartbeat (o el registro, si aún no hubo ninguno) está dentro del timeout
+    pub fn is_healthy(&self, now: DateTime<Utc>) -> bool {
+        let since = self.last_heartbeat.unwrap_or(self.registered_at);
+        (now - since).num_milliseconds() <= self.config.timeout_ms as i64
+    }
+}
+
+/// Dioses registrados en Erinyes, compartido con los handlers de la API
+#[derive(Clone, Default)]
+pub struct HeartbeatRegistry {
+    registrations: Arc<RwLock<HashMap<GodName, HeartbeatRegistration>>>,
+}
+
+impl HeartbeatRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registra (o reconfigura) un dios; conserva su último heartbeat si ya estaba
+    pub fn register(&self, god: GodName, config: HeartbeatConfig) {
+        let mut registrations = self.registrations.write().unwrap_or_else(|e| e.into_inner());
+        let last_heartbeat = registrations.get(&god).and_then(|r| r.last_heartbeat);
+        registrations.insert(god, HeartbeatRegistration { config, registered_at: Utc::now(), last_heartbeat });
+    }
+
+    /// Anota un heartbeat; los dioses no registrados no se monitorean
+    pub fn beat(&self, god: GodName, at: DateTime<Utc>) -> bool {
+        let mut registrations = self.registrations.write().unwrap_or_else(|e| e.into_inner());
+        match registrations.get_mut(&god) {
+            Some(registration) => {
+                registration.last_heartbeat = Some(at);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn get(&self, god: GodName) -> Option<HeartbeatRegistration> {
+        self.registrations.read().unwrap_or_else(|e| e.into_inner()).get(&god).cloned()
+    }
+
+    /// Registros en el orden de `GodName::ALL`
+    pub fn snapshot(&self) -> Vec<(GodName, HeartbeatRegistration)> {
+        let registrations = self.registrations.read().unwrap_or_else(|e| e.into_inner());
+        GodName::ALL
+            .into_iter()
+            .filter_map(|god| registrations.get(&god).map(|r| (god, r.clone())))
+            .collect()
+    }
+}
+
+/// Urgencia de una recuperación: crítica si el dios pertenece a la Trinidad
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryUrgency {
+    Critical,
+    Standard,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryStatus {
+    /// Detectada, aún sin intentos
+    Queued,
+    Running,
+    /// Agotó `MAX_RECOVERY_ATTEMPTS`; ya no consume intentos
+    Escalated,
+}
+
+/// Recuperación en curso de un dios sin heartbeat
+#[derive(Debug, Clone, Serialize)]
+pub struct Recovery {
+    pub id: u64,
+    pub god: GodName,
+    pub urgency: RecoveryUrgency,
+    pub status: RecoveryStatus,
+    pub attempts: u32,
+    pub requested_at: DateTime<Utc>,
+    pub last_attempt: Option<DateTime<Utc>>,
+}
+
+#[derive(Default)]
+struct RecoveryState {
+    next_id: u64,
+    active: HashMap<GodName, Recovery>,
+    /// Dioses cuya recuperación canceló un operador; no se reabre hasta que vuelvan a latir
+    cancelled: HashSet<GodName>,
+}
+
+/// Recuperaciones de Erinyes, compartidas con los handlers de la API
+#[derive(Clone, Default)]
+pub struct RecoveryRegistry {
+    state: Arc<RwLock<RecoveryState>>,
+}
+
+impl RecoveryRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `god` sigue sin heartbeat: abre su recuperación o, si pasó un timeout desde el último
+    /// intento, consume otro. Devuelve la recuperación solo si cambió
+    pub fn attempt(&self, god: GodName, config: HeartbeatConfig, now: DateTime<Utc>) -> Option<Recovery> {
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+        if state.cancelled.contains(&god) {
+            return None;
+        }
+        if !state.active.contains_key(&god) {
+            state.next_id += 1;
+            let recovery = Recovery {
+                id: state.next_id,
+                god,
+                urgency: match config.tier {
+                    MonitoringTier::Trinity => RecoveryUrgency::Critical,
+                    MonitoringTier::Standard => RecoveryUrgency::Standard,
+                },
+                status: RecoveryStatus::Queued,
+                attempts: 0,
+                requested_at: now,
+                last_attempt: None,
+            };
+            state.active.insert(god, recovery.clone());
+            return Some(recovery);
+        }
+
+        let recovery = state.active.get_mut(&god)?;
+        let since = recovery.last_attempt.unwrap_or(recovery.requested_at);
+        if recovery.status == RecoveryStatus::Escalated || (now - since).num_milliseconds() < config.timeout_ms as i64 {
+            return None;
+        }
+        recovery.attempts += 1;
+        recovery.last_attempt = Some(now);
+        recovery.status = if recovery.attempts >= MAX_RECOVERY_ATTEMPTS {
+            RecoveryStatus::Escalated
+        } else {
+            RecoveryStatus::Running
+        };
+        Some(recovery.clone())
+    }
+
+    /// `god` volvió a latir: termina su recuperación y se olvida una cancelación previa
+    pub fn resolve(&self, god: GodName) -> Option<Recovery> {
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+        state.cancelled.remove(&god);
+        state.active.remove(&god)
+    }
+
+    /// Cancela una recuperación; el dios no consume más intentos hasta que vuelva a latir
+    pub fn cancel(&self, id: u64) -> Option<Recovery> {
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+        let god = state.active.values().find(|recovery| recovery.id == id)?.god;
+        state.cancelled.insert(god);
+        state.active.remove(&god)
+    }
+
+    /// Recuperaciones activas, las más antiguas primero
+    pub fn list(&self) -> Vec<Recovery> {
+        let state = self.state.read().unwrap_or_else(|e| e.into_inner());
+        let mut recoveries: Vec<Recovery> = state.active.values().cloned().collect();
+        recoveries.sort_by_key(|recovery| recovery.id);
+        recoveries
+    }
+}
 
 pub struct Erinyes {
-    heartbeats: HashMap<GodName, i64>,
+    registry: HeartbeatRegistry,
+    recoveries: RecoveryRegistry,
+    trinity: TrinityMembers,
     alerts_triggered: u64,
     messages_count: u64,
 }
 
 impl Erinyes {
-    pub fn new() -> Self {
-        let mut heartbeats = HashMap::new();
-        // Inicializar heartbeats para todos los dioses
-        for god in [
-            GodName::Zeus, GodName::Hades, GodName::Poseidon,
-            GodName::Athena, GodName::Hermes, GodName::Hestia,
-            GodName::Apollo, GodName::Artemis, GodName::Hera,
-            GodName::Ares, GodName::Hefesto, GodName::Chronos,
-            GodName::Moirai, GodName::Chaos, GodName::Aurora,
-            GodName::Aphrodite, GodName::Iris, GodName::Demeter,
-            GodName::Dionysus, GodName::Erinyes,
-        ] {
-            heartbeats.insert(god, Utc::now().timestamp());
-        }
-
+    pub fn new(registry: HeartbeatRegistry, recoveries: RecoveryRegistry, trinity: TrinityMembers) -> Self {
         Self {
-            heartbeats,
+            registry,
+            recoveries,
+            trinity,
             alerts_triggered: 0,
             messages_count: 0,
         }
     }
 
     fn check_health(&self, god: GodName) -> bool {
-        if let Some(last_beat) = self.heartbeats.get(&god) {
-            let now = Utc::now().timestamp();
-            let diff = now - *last_beat;
-            diff < 60 // Considerar saludable si heartbeat en últimos 60 segundos
-        } else {
-            false
+        self.registry.get(god).is_some_and(|r| r.is_healthy(Utc::now()))
+    }
+
+    /// Abre o reintenta la recuperación de cada dios registrado que perdió el heartbeat
+    pub fn sweep_recoveries(&mut self, now: DateTime<Utc>) {
+        for (god, registration) in self.registry.snapshot() {
+            if registration.is_healthy(now) {
+                continue;
+            }
+            let Some(recovery) = self.recoveries.attempt(god, registration.config, now) else {
+                continue;
+            };
+            self.alerts_triggered += 1;
+            match recovery.status {
+                RecoveryStatus::Queued => tracing::warn!("👁️ Erinyes: {} sin heartbeat, recuperación {} en cola", god.as_str(), recovery.id),
+                RecoveryStatus::Running => tracing::warn!(
+                    "👁️ Erinyes: recuperación {} de {}, intento {}/{}",
+                    recovery.id, god.as_str(), recovery.attempts, MAX_RECOVERY_ATTEMPTS
+                ),
+                RecoveryStatus::Escalated => tracing::error!(
+                    "🔥 Erinyes: {} sigue caído tras {} intentos, escalando a Zeus",
+                    god.as_str(), recovery.attempts
+                ),
+            }
         }
     }
 }
@@ -57,25 +272,50 @@ impl OlympianActor for Erinyes {
 
         match &msg.payload {
             MessagePayload::Heartbeat { timestamp } => {
-                self.heartbeats.insert(msg.from, timestamp.timestamp());
-                tracing::debug!("💓 Erinyes: Heartbeat from {:?}", msg.from);
+                if self.registry.beat(msg.from, *timestamp) {
+                    tracing::debug!("💓 Erinyes: Heartbeat from {:?}", msg.from);
+                    if let Some(recovery) = self.recoveries.resolve(msg.from) {
+                        tracing::info!("👁️ Erinyes: {} volvió a latir, recuperación {} terminada", msg.from.as_str(), recovery.id);
+                    }
+                } else {
+                    tracing::debug!("💓 Erinyes: Heartbeat ignorado de {:?} (no registrado)", msg.from);
+                }
+                self.sweep_recoveries(Utc::now());
+                None
+            }
+
+            MessagePayload::Command { action, data } if action == "register_actor" => {
+                let god: GodName = serde_json::from_value(data.get("actor")?.clone()).ok()?;
+                let config = data
+                    .get("config")
+                    .and_then(|c| serde_json::from_value(c.clone()).ok())
+                    .unwrap_or_else(|| HeartbeatConfig::for_god(god, &self.trinity));
+                self.registry.register(god, config);
+                tracing::info!("👁️ Erinyes: {} registrado ({:?}, cada {} ms)", god.as_str(), config.tier, config.interval_ms);
                 None
             }
 
             MessagePayload::Query { query_type, .. } => {
                 if query_type == "get_health" {
+                    self.sweep_recoveries(Utc::now());
                     let mut health_data = Vec::new();
                     
-                    for (god, _) in &self.heartbeats {
-                        let healthy = self.check_health(*god);
+                    for (god, registration) in self.registry.snapshot() {
+                        let healthy = self.check_health(god);
+                        let critical = !healthy && registration.config.tier == MonitoringTier::Trinity;
                         if !healthy {
                             self.alerts_triggered += 1;
                         }
+                        if critical {
+                            tracing::error!("🔱 Erinyes: {} (Trinidad) sin heartbeat: alerta crítica", god.as_str());
+                        }
                         
                         health_data.push(serde_json::json!({
                             "god": god.as_str(),
                             "healthy": healthy,
-                            "last_seen": self.heartbeats.get(god),
+                            "tier": registration.config.tier,
+                            "critical": critical,
+                            "last_seen": registration.last_heartbeat,
                         }));
                     }
 
@@ -97,8 +337,9 @@ impl OlympianActor for Erinyes {
     }
 
     async fn health(&self) -> GodHealth {
-        let healthy_count = self.heartbeats.keys()
-            .filter(|g| self.check_health(**g))
+        let registered = self.registry.snapshot();
+        let healthy_count = registered.iter()
+            .filter(|(god, _)| self.check_health(*god))
             .count();
 
         GodHealth {
@@ -107,12 +348,12 @@ impl OlympianActor for Erinyes {
             last_heartbeat: Utc::now(),
             messages_processed: self.messages_count,
             uptime_seconds: 0,
-            status: format!("Monitoring {} gods, {} healthy", self.heartbeats.len(), healthy_count),
+            status: format!("Monitoring {} gods, {} healthy", registered.len(), healthy_count),
         }
     }
 
     async fn initialize(&mut self) -> Result<(), String> {
-        tracing::info!("👁️ Erinyes: Iniciando monitoreo de {} dioses...", self.heartbeats.len());
+        tracing::info!("👁️ Erinyes: Iniciando monitoreo; Genesis registrará a los dioses habilitados");
         Ok(())
     }
 
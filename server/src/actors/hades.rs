@@ -1,36 +1,110 @@
 // server/src/actors/hades.rs
 // Hades: Seguridad, Autenticación y Cifrado
 
+use argon2::password_hash::{rand_core::{OsRng, RngCore}, PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
 use async_trait::async_trait;
 use super::{ActorMessage, GodName, MessagePayload, OlympianActor, GodHealth};
+use crate::auth::{AuthError, AuthStore};
+use crate::otp::{Environment, ENVIRONMENT_ENV};
 use chrono::Utc;
 
+pub const JWT_SECRET_ENV: &str = "OLYMPUS_JWT_SECRET";
+pub const JWT_SECRET_FILE_ENV: &str = "OLYMPUS_JWT_SECRET_FILE";
+pub const MIN_JWT_SECRET_BYTES: usize = 32;
+
+/// Secreto de firma de Hades; `Debug` nunca lo muestra
+#[derive(Clone)]
+pub struct JwtSecret(String);
+
+impl JwtSecret {
+    /// `OLYMPUS_JWT_SECRET` o `OLYMPUS_JWT_SECRET_FILE`; sin ninguno solo arranca en
+    /// desarrollo, con un secreto efímero
+    pub fn from_env(environment: Environment) -> Result<Self, String> {
+        let inline = std::env::var(JWT_SECRET_ENV).ok();
+        let file = std::env::var(JWT_SECRET_FILE_ENV).ok();
+        Self::load(environment, inline.as_deref(), file.as_deref())
+    }
+
+    pub fn load(environment: Environment, inline: Option<&str>, file: Option<&str>) -> Result<Self, String> {
+        let non_empty = |v: Option<&str>| v.map(str::trim).filter(|v| !v.is_empty()).map(str::to_string);
+        let secret = match (non_empty(inline), non_empty(file)) {
+            (Some(_), Some(_)) => {
+                return Err(format!("Defina solo uno de {} y {}", JWT_SECRET_ENV, JWT_SECRET_FILE_ENV));
+            }
+            (Some(secret), None) => secret,
+            (None, Some(path)) => std::fs::read_to_string(&path)
+                .map_err(|e| format!("{}: no se pudo leer {}: {}", JWT_SECRET_FILE_ENV, path, e))?
+                .trim_end_matches(['\r', '\n'])
+                .to_string(),
+            (None, None) if environment == Environment::Development => {
+                tracing::warn!("🔒 Hades: sin {}; se usa un secreto efímero (solo desarrollo)", JWT_SECRET_ENV);
+                return Ok(Self::ephemeral());
+            }
+            (None, None) => {
+                return Err(format!(
+                    "{} o {} es obligatorio fuera de {}=development",
+                    JWT_SECRET_ENV, JWT_SECRET_FILE_ENV, ENVIRONMENT_ENV
+                ));
+            }
+        };
+        if secret.len() < MIN_JWT_SECRET_BYTES {
+            return Err(format!("El secreto JWT debe tener al menos {} bytes", MIN_JWT_SECRET_BYTES));
+        }
+        Ok(Self(secret))
+    }
+
+    /// Aleatorio por proceso: las sesiones no sobreviven un reinicio
+    pub fn ephemeral() -> Self {
+        let mut bytes = [0u8; MIN_JWT_SECRET_BYTES];
+        OsRng.fill_bytes(&mut bytes);
+        Self(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+    }
+
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for JwtSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("JwtSecret(REDACTED)")
+    }
+}
+
+/// Hash Argon2id en formato PHC (incluye sal y parámetros)
+pub fn hash_password(password: &str) -> Result<String, String> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| e.to_string())
+}
+
+pub fn verify_password(password: &str, hash: &str) -> bool {
+    PasswordHash::new(hash)
+        .map(|parsed| Argon2::default().verify_password(password.as_bytes(), &parsed).is_ok())
+        .unwrap_or(false)
+}
+
 pub struct Hades {
-    jwt_secret: String,
+    /// Credenciales compartidas con la API (hash Argon2id)
+    auth: AuthStore,
     active_sessions: Vec<String>,
     messages_count: u64,
 }
 
 impl Hades {
-    pub fn new() -> Self {
+    pub fn new(auth: AuthStore) -> Self {
         Self {
-            jwt_secret: "olympus_secret_key_2026".to_string(),
+            auth,
             active_sessions: Vec::new(),
             messages_count: 0,
         }
     }
 
     fn validate_credentials(&self, username: &str, password: &str) -> bool {
-        username == "admin" && password == "admin123"
-    }
-
-    fn generate_token(&self, username: &str) -> String {
-        // Simulación simple de JWT
-        format!("jwt_{}_{}_{}", username, Utc::now().timestamp(), self.jwt_secret.chars().take(8).collect::<String>())
-    }
-
-    fn validate_otp(&self, code: &str) -> bool {
-        code == "123456"
+        self.auth.verify(username, password)
     }
 
     fn create_session(&mut self, username: &str) -> String {
@@ -66,7 +140,7 @@ impl OlympianActor for Hades {
                                     data: serde_json::json!({
                                         "requires_otp": true,
                                         "session_id": session,
-                                        "message": "Código OTP enviado: 123456"
+                                        "message": "Código OTP enviado por Iris"
                                     }),
                                     error: None,
                                 }
@@ -85,35 +159,38 @@ impl OlympianActor for Hades {
                     }
 
                     "verify_otp" => {
+                        let session_id = data.get("session_id")?.as_str()?;
                         let code = data.get("otp_code")?.as_str()?;
+
+                        // El código es el generado para este login pendiente; lo consume AuthStore
+                        let (success, data, error) = match self.auth.complete_login(session_id, code) {
+                            Ok((username, token)) => (
+                                true,
+                                serde_json::json!({
+                                    "token": token,
+                                    "username": username,
+                                    "message": "¡Zeus aprueba tu acceso!"
+                                }),
+                                None,
+                            ),
+                            Err(e) => {
+                                let reason = if matches!(e, AuthError::InvalidOtp) { "invalid_otp" } else { "invalid_session" };
+                                (false, serde_json::json!({ "reason": reason }), Some(e.message()))
+                            }
+                        };
+                        Some(ActorMessage::new(
+                            GodName::Hades,
+                            msg.from,
+                            MessagePayload::Response { success, data, error },
+                        ))
+                    }
+
+                    "password_changed" => {
                         let username = data.get("username")?.as_str()?;
-                        
-                        if self.validate_otp(code) {
-                            let token = self.generate_token(username);
-                            Some(ActorMessage::new(
-                                GodName::Hades,
-                                msg.from,
-                                MessagePayload::Response {
-                                    success: true,
-                                    data: serde_json::json!({
-                                        "token": token,
-                                        "username": username,
-                                        "message": "¡Zeus aprueba tu acceso!"
-                                    }),
-                                    error: None,
-                                }
-                            ))
-                        } else {
-                            Some(ActorMessage::new(
-                                GodName::Hades,
-                                msg.from,
-                                MessagePayload::Response {
-                                    success: false,
-                                    data: serde_json::json!({}),
-                                    error: Some("Código OTP inválido".to_string()),
-                                }
-                            ))
-                        }
+                        let revoked = data.get("revoked_sessions").and_then(|v| v.as_u64()).unwrap_or(0);
+                        self.active_sessions.retain(|session| !session.starts_with(&format!("session_{}_", username)));
+                        tracing::info!("🔑 Hades: contraseña cambiada para {} ({} sesiones revocadas)", username, revoked);
+                        None
                     }
 
                     "logout" => {
@@ -159,3 +236,88 @@ impl OlympianActor for Hades {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::PasswordPolicy;
+    use serde_json::json;
+
+    fn authenticate(password: &str) -> ActorMessage {
+        ActorMessage::new(
+            GodName::Zeus,
+            GodName::Hades,
+            MessagePayload::Command { action: "authenticate".to_string(), data: json!({ "username": "admin", "password": password }) },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_checks_the_stored_credential() {
+        let auth = AuthStore::with_default_admin(PasswordPolicy::default()).unwrap();
+        let mut hades = Hades::new(auth.clone());
+        let success = |reply: Option<ActorMessage>| matches!(reply.unwrap().payload, MessagePayload::Response { success: true, .. });
+
+        assert!(success(hades.handle_message(authenticate("admin123")).await));
+        assert!(!success(hades.handle_message(authenticate("otra")).await));
+
+        // Tras cambiar la contraseña la anterior deja de valer también para Hades
+        let session_id = auth.begin_login("admin", "000000");
+        let (_, token) = auth.complete_login(&session_id, "000000").unwrap();
+        auth.change_password(&token, "admin123", "Nueva2026x").unwrap();
+        assert!(!success(hades.handle_message(authenticate("admin123")).await));
+        assert!(success(hades.handle_message(authenticate("Nueva2026x")).await));
+    }
+
+    #[tokio::test]
+    async fn test_verify_otp_only_accepts_the_pending_login_code() {
+        let auth = AuthStore::with_default_admin(PasswordPolicy::default()).unwrap();
+        let mut hades = Hades::new(auth.clone());
+        let verify = |session_id: &str, code: &str| {
+            ActorMessage::new(
+                GodName::Zeus,
+                GodName::Hades,
+                MessagePayload::Command { action: "verify_otp".to_string(), data: json!({ "session_id": session_id, "otp_code": code }) },
+            )
+        };
+        let response = |reply: Option<ActorMessage>| match reply.unwrap().payload {
+            MessagePayload::Response { success, data, .. } => (success, data),
+            other => panic!("respuesta inesperada: {:?}", other),
+        };
+
+        let session_id = auth.begin_login("admin", "482913");
+        let (success, data) = response(hades.handle_message(verify(&session_id, "123456")).await);
+        assert!(!success);
+        assert_eq!(data["reason"], "invalid_otp");
+
+        let (success, data) = response(hades.handle_message(verify(&session_id, "482913")).await);
+        assert!(success);
+        assert_eq!(data["username"], "admin");
+        assert!(auth.session(data["token"].as_str().unwrap()).is_some());
+
+        // El login ya se consumió
+        let (success, data) = response(hades.handle_message(verify(&session_id, "482913")).await);
+        assert!(!success);
+        assert_eq!(data["reason"], "invalid_session");
+    }
+
+    #[test]
+    fn test_jwt_secret_is_required_outside_development() {
+        let strong = "k".repeat(MIN_JWT_SECRET_BYTES);
+        assert!(JwtSecret::load(Environment::Production, None, None).is_err());
+        assert!(JwtSecret::load(Environment::Staging, Some("corto"), None).is_err());
+        assert!(JwtSecret::load(Environment::Production, Some(&strong), Some("/run/secrets/jwt")).is_err());
+        assert_eq!(JwtSecret::load(Environment::Production, Some(&strong), None).unwrap().expose(), strong);
+
+        let path = std::env::temp_dir().join(format!("jwt-{}", uuid::Uuid::new_v4()));
+        std::fs::write(&path, format!("{}\n", strong)).unwrap();
+        let from_file = JwtSecret::load(Environment::Production, None, path.to_str()).unwrap();
+        assert_eq!(from_file.expose(), strong);
+        assert!(!format!("{:?}", from_file).contains(&strong));
+        let _ = std::fs::remove_file(path);
+
+        // En desarrollo arranca con un secreto efímero distinto en cada proceso
+        let dev = JwtSecret::load(Environment::Development, None, None).unwrap();
+        assert!(dev.expose().len() >= MIN_JWT_SECRET_BYTES);
+        assert_ne!(dev.expose(), JwtSecret::ephemeral().expose());
+    }
+}
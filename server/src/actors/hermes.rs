@@ -2,7 +2,7 @@
 // Hermes: Mensajería y Routing
 
 use async_trait::async_trait;
-use super::{ActorMessage, GodName, MessagePayload, OlympianActor, GodHealth};
+use super::{ActorMessage, GodName, OlympianActor, GodHealth};
 use chrono::Utc;
 
 pub struct Hermes {
@@ -14,7 +14,7 @@ pub struct Hermes {
 impl Hermes {
     pub fn new() -> Self {
         Self {
-            routes: vec!["Zeus", "Hades", "Poseidon", "Athena"].iter().map(|s| s.to_string()).collect(),
+            routes: ["Zeus", "Hades", "Poseidon", "Athena"].iter().map(|s| s.to_string()).collect(),
             messages_routed: 0,
             messages_count: 0,
         }
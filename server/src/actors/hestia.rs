@@ -4,16 +4,173 @@
 use async_trait::async_trait;
 use super::{ActorMessage, GodName, MessagePayload, OlympianActor, GodHealth};
 use chrono::Utc;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+/// Almacén clave-valor persistente de Hestia: un archivo JSON que sobrevive reinicios.
+/// Se comparte (clonando el handle) con los dioses que necesitan guardar estado.
+#[derive(Clone)]
+pub struct HestiaStore {
+    path: Option<PathBuf>,
+    data: Arc<RwLock<Map<String, Value>>>,
+    /// Generación de cada instantánea, asignada bajo el bloqueo de `data`
+    generation: Arc<AtomicU64>,
+    /// Serializa las escrituras del archivo y guarda la última generación escrita
+    writer: Arc<Mutex<u64>>,
+}
+
+impl HestiaStore {
+    /// Abre (o crea) el almacén en `path`; un archivo ilegible se trata como vacío
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let data = match std::fs::read_to_string(&path) {
+            Ok(raw) => serde_json::from_str(&raw).unwrap_or_else(|e| {
+                tracing::warn!("🏛️ Hestia: {} corrupto, se ignora: {}", path.display(), e);
+                Map::new()
+            }),
+            Err(_) => Map::new(),
+        };
+
+        Self::with_data(Some(path), data)
+    }
+
+    /// Almacén solo en memoria (tests o despliegues sin disco)
+    pub fn in_memory() -> Self {
+        Self::with_data(None, Map::new())
+    }
+
+    fn with_data(path: Option<PathBuf>, data: Map<String, Value>) -> Self {
+        Self {
+            path,
+            data: Arc::new(RwLock::new(data)),
+            generation: Arc::new(AtomicU64::new(0)),
+            writer: Arc::new(Mutex::new(0)),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<Value> {
+        self.data.read().ok()?.get(key).cloned()
+    }
+
+    /// Guarda el valor y reescribe el archivo (temporal + rename para no dejarlo a medias)
+    pub async fn set(&self, key: &str, value: Value) -> Result<(), String> {
+        self.update(key, |current| *current = value).await
+    }
+
+    /// Lee-modifica-escribe: el cambio en memoria es atómico bajo el bloqueo de `data`.
+    /// En disco gana siempre la instantánea más reciente: una escritura más antigua que
+    /// termina después no la pisa. `current` es `Null` si la clave no existía.
+    pub async fn update<T>(&self, key: &str, f: impl FnOnce(&mut Value) -> T) -> Result<T, String> {
+        let (result, generation, snapshot) = {
+            let mut data = self.data.write().map_err(|e| e.to_string())?;
+            let current = data.entry(key.to_string()).or_insert(Value::Null);
+            let result = f(current);
+            let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+            (result, generation, serde_json::to_vec_pretty(&*data).map_err(|e| e.to_string())?)
+        };
+        self.write_snapshot(generation, snapshot).await?;
+        Ok(result)
+    }
+
+    /// El archivo se escribe en un hilo bloqueante para no detener el runtime
+    async fn write_snapshot(&self, generation: u64, snapshot: Vec<u8>) -> Result<(), String> {
+        let Some(path) = self.path.clone() else {
+            return Ok(());
+        };
+        let writer = self.writer.clone();
+        tokio::task::spawn_blocking(move || {
+            // Se mantiene hasta el rename: dos escrituras nunca se intercalan
+            let mut written = writer.lock().unwrap_or_else(|e| e.into_inner());
+            if *written >= generation {
+                return Ok(());
+            }
+            write_atomically(&path, generation, &snapshot)?;
+            *written = generation;
+            Ok(())
+        })
+        .await
+        .map_err(|e| e.to_string())?
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.read().map(|d| d.len()).unwrap_or(0)
+    }
+}
+
+/// Temporal con nombre único (proceso y generación) junto al archivo y luego rename
+fn write_atomically(path: &Path, generation: u64, snapshot: &[u8]) -> Result<(), String> {
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let file_name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    let tmp = path.with_file_name(format!(".{}.{}.{}.tmp", file_name, std::process::id(), generation));
+    if let Err(e) = std::fs::write(&tmp, snapshot).and_then(|_| std::fs::rename(&tmp, path)) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.to_string());
+    }
+    Ok(())
+}
+
+/// Caché volátil de Hestia: entradas con TTL solo en memoria, nunca se escriben en disco
+#[derive(Clone, Default)]
+pub struct HestiaCache {
+    entries: Arc<RwLock<HashMap<String, (Instant, Value)>>>,
+    hits: Arc<AtomicU64>,
+    misses: Arc<AtomicU64>,
+}
+
+impl HestiaCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Valor vigente de la clave; las entradas caducadas se descartan al leerlas
+    pub fn get(&self, key: &str) -> Option<Value> {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        let value = match entries.get(key) {
+            Some((expires_at, value)) if *expires_at > Instant::now() => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        };
+        let counter = if value.is_some() { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        value
+    }
+
+    pub fn set(&self, key: &str, value: Value, ttl: Duration) {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        entries.retain(|_, (expires_at, _)| *expires_at > now);
+        entries.insert(key.to_string(), (now + ttl, value));
+    }
+
+    pub fn stats(&self) -> Value {
+        serde_json::json!({
+            "entries": self.entries.read().map(|e| e.len()).unwrap_or(0),
+            "hits": self.hits.load(Ordering::Relaxed),
+            "misses": self.misses.load(Ordering::Relaxed),
+        })
+    }
+}
 
 pub struct Hestia {
+    store: HestiaStore,
     cached_items: u64,
     persisted_items: u64,
     messages_count: u64,
 }
 
 impl Hestia {
-    pub fn new() -> Self {
+    pub fn new(store: HestiaStore) -> Self {
         Self {
+            store,
             cached_items: 0,
             persisted_items: 0,
             messages_count: 0,
@@ -38,8 +195,17 @@ impl OlympianActor for Hestia {
                         tracing::debug!("🏛️ Hestia: Cached item");
                     }
                     "persist" => {
-                        self.persisted_items += 1;
-                        tracing::debug!("🏛️ Hestia: Persisted item");
+                        let key = data.get("key").and_then(|k| k.as_str());
+                        match (key, data.get("value")) {
+                            (Some(key), Some(value)) => match self.store.set(key, value.clone()).await {
+                                Ok(()) => {
+                                    self.persisted_items += 1;
+                                    tracing::debug!("🏛️ Hestia: Persisted {}", key);
+                                }
+                                Err(e) => tracing::error!("🏛️ Hestia: No se pudo persistir {}: {}", key, e),
+                            },
+                            _ => tracing::warn!("🏛️ Hestia: persist requiere 'key' y 'value'"),
+                        }
                     }
                     _ => {}
                 }
@@ -62,6 +228,7 @@ impl OlympianActor for Hestia {
 
     async fn initialize(&mut self) -> Result<(), String> {
         tracing::info!("🏛️ Hestia: Conectando a Valkey...");
+        tracing::info!("🏛️ Hestia: {} claves persistidas cargadas", self.store.len());
         Ok(())
     }
 
@@ -70,3 +237,57 @@ impl OlympianActor for Hestia {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_store_survives_reopen() {
+        let path = std::env::temp_dir().join(format!("hestia-{}.json", uuid::Uuid::new_v4()));
+
+        let store = HestiaStore::open(&path);
+        store.set("aphrodite.current_theme", json!("Cosmic")).await.unwrap();
+
+        let reopened = HestiaStore::open(&path);
+        assert_eq!(reopened.get("aphrodite.current_theme"), Some(json!("Cosmic")));
+        assert_eq!(reopened.get("missing"), None);
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_writers_keep_the_latest_snapshot() {
+        let dir = std::env::temp_dir().join(format!("hestia-{}", uuid::Uuid::new_v4()));
+        let path = dir.join("store.json");
+        let store = HestiaStore::open(&path);
+
+        let writers: Vec<_> = (0..32)
+            .map(|i| {
+                let store = store.clone();
+                tokio::spawn(async move { store.set(&format!("k{}", i), json!(i)).await })
+            })
+            .collect();
+        for writer in writers {
+            writer.await.unwrap().unwrap();
+        }
+
+        // Ninguna escritura antigua pisó a una posterior y no quedan temporales
+        let reopened = HestiaStore::open(&path);
+        assert_eq!(reopened.len(), 32);
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_cache_entries_expire() {
+        let cache = HestiaCache::new();
+        cache.set("k", json!(1), Duration::from_secs(60));
+        cache.set("short", json!(2), Duration::ZERO);
+        assert_eq!(cache.get("k"), Some(json!(1)));
+        assert_eq!(cache.get("short"), None);
+        assert_eq!(cache.stats(), json!({ "entries": 1, "hits": 1, "misses": 1 }));
+    }
+}
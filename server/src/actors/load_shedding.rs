@@ -0,0 +1,189 @@
+// server/src/actors/load_shedding.rs
+// Descarga de carga: Demeter mide la presión de memoria y se la informa a Zeus; con presión
+// crítica Zeus activa el modo y la API rechaza las rutas no esenciales (analítica,
+// importaciones) y los jobs en segundo plano se pausan hasta que la presión baja
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+use super::{ActorMessage, GodName, MessagePayload};
+
+pub const MEMORY_CRITICAL_ENV: &str = "OLYMPUS_LOAD_SHED_MEMORY_PCT";
+pub const SCAN_INTERVAL_ENV: &str = "OLYMPUS_LOAD_SHED_SCAN_SECS";
+pub const DEFAULT_MEMORY_CRITICAL_PCT: f64 = 90.0;
+pub const DEFAULT_SCAN_INTERVAL_SECS: u64 = 10;
+/// La descarga termina cuando la memoria baja este margen por debajo del umbral crítico
+pub const RECOVERY_MARGIN_PCT: f64 = 10.0;
+/// Evento de Demeter a Zeus con la lectura de memoria
+pub const RESOURCE_PRESSURE_EVENT: &str = "resource_pressure";
+
+/// Episodio de descarga en curso
+#[derive(Debug, Clone, Serialize)]
+pub struct Episode {
+    pub since: DateTime<Utc>,
+    /// Última lectura de memoria en uso (%)
+    pub memory_pct: f64,
+}
+
+/// Estado compartido por Zeus (decide), los jobs y la API (lo consultan)
+#[derive(Clone)]
+pub struct LoadShedding {
+    episode: Arc<RwLock<Option<Episode>>>,
+    memory_critical_pct: f64,
+    /// Cada cuánto informa Demeter; cero desactiva la medición
+    scan_interval: Duration,
+}
+
+impl Default for LoadShedding {
+    fn default() -> Self {
+        Self::new(DEFAULT_MEMORY_CRITICAL_PCT, Duration::from_secs(DEFAULT_SCAN_INTERVAL_SECS))
+    }
+}
+
+impl LoadShedding {
+    pub fn new(memory_critical_pct: f64, scan_interval: Duration) -> Self {
+        Self { episode: Arc::new(RwLock::new(None)), memory_critical_pct, scan_interval }
+    }
+
+    /// `OLYMPUS_LOAD_SHED_MEMORY_PCT` (90 por defecto) y `OLYMPUS_LOAD_SHED_SCAN_SECS` (10; 0 lo desactiva)
+    pub fn from_env() -> Result<Self, String> {
+        let memory_critical_pct = match std::env::var(MEMORY_CRITICAL_ENV) {
+            Ok(raw) => match raw.trim().parse::<f64>() {
+                Ok(pct) if pct > RECOVERY_MARGIN_PCT && pct <= 100.0 => pct,
+                _ => {
+                    return Err(format!(
+                        "{} debe ser un porcentaje entre {} y 100: '{}'",
+                        MEMORY_CRITICAL_ENV, RECOVERY_MARGIN_PCT, raw
+                    ))
+                }
+            },
+            Err(_) => DEFAULT_MEMORY_CRITICAL_PCT,
+        };
+        let secs = match std::env::var(SCAN_INTERVAL_ENV) {
+            Ok(raw) => raw
+                .trim()
+                .parse()
+                .map_err(|_| format!("{} debe ser un número de segundos: '{}'", SCAN_INTERVAL_ENV, raw))?,
+            Err(_) => DEFAULT_SCAN_INTERVAL_SECS,
+        };
+        Ok(Self::new(memory_critical_pct, Duration::from_secs(secs)))
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.episode.read().unwrap_or_else(|e| e.into_inner()).is_some()
+    }
+
+    pub fn episode(&self) -> Option<Episode> {
+        self.episode.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Aplica una lectura de Demeter. Se activa al llegar al umbral crítico y se desactiva
+    /// solo por debajo del margen de recuperación; devuelve el nuevo estado si cambió.
+    pub fn observe(&self, memory_pct: f64) -> Option<bool> {
+        let mut episode = self.episode.write().unwrap_or_else(|e| e.into_inner());
+        match episode.as_mut() {
+            None if memory_pct >= self.memory_critical_pct => {
+                *episode = Some(Episode { since: Utc::now(), memory_pct });
+                Some(true)
+            }
+            Some(_) if memory_pct < self.memory_critical_pct - RECOVERY_MARGIN_PCT => {
+                *episode = None;
+                Some(false)
+            }
+            Some(current) => {
+                current.memory_pct = memory_pct;
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn status(&self) -> serde_json::Value {
+        let episode = self.episode();
+        serde_json::json!({
+            "active": episode.is_some(),
+            "since": episode.as_ref().map(|e| e.since),
+            "memory_pct": episode.as_ref().map(|e| e.memory_pct),
+            "memory_critical_pct": self.memory_critical_pct,
+            "recovery_pct": self.memory_critical_pct - RECOVERY_MARGIN_PCT,
+        })
+    }
+}
+
+/// Memoria en uso (%) a partir del contenido de `/proc/meminfo`
+fn parse_meminfo(raw: &str) -> Option<f64> {
+    let field = |name: &str| -> Option<f64> {
+        raw.lines()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+            .and_then(|rest| rest.split_whitespace().next()?.parse().ok())
+    };
+    let total = field("MemTotal")?;
+    let available = field("MemAvailable")?;
+    (total > 0.0).then(|| (total - available) / total * 100.0)
+}
+
+/// Lectura de Demeter; `None` fuera de Linux
+pub fn memory_usage_pct() -> Option<f64> {
+    parse_meminfo(&std::fs::read_to_string("/proc/meminfo").ok()?)
+}
+
+/// Demeter informa periódicamente la presión de memoria a Zeus mientras ambos estén desplegados
+pub async fn watch(
+    shedding: LoadShedding,
+    senders: Arc<tokio::sync::RwLock<HashMap<GodName, mpsc::Sender<ActorMessage>>>>,
+) {
+    if shedding.scan_interval.is_zero() {
+        return;
+    }
+    let mut interval = tokio::time::interval(shedding.scan_interval);
+    loop {
+        interval.tick().await;
+        let (demeter_deployed, zeus) = {
+            let senders = senders.read().await;
+            (senders.contains_key(&GodName::Demeter), senders.get(&GodName::Zeus).cloned())
+        };
+        let (true, Some(zeus)) = (demeter_deployed, zeus) else {
+            return;
+        };
+        let Some(memory_pct) = memory_usage_pct() else {
+            tracing::warn!("🌾 Demeter: no se puede leer la memoria del sistema; descarga de carga desactivada");
+            return;
+        };
+        let report = ActorMessage::new(
+            GodName::Demeter,
+            GodName::Zeus,
+            MessagePayload::Event {
+                event_type: RESOURCE_PRESSURE_EVENT.to_string(),
+                data: serde_json::json!({ "memory_pct": memory_pct }),
+            },
+        );
+        let _ = zeus.send(report).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_critical_pressure_sheds_until_recovery_margin() {
+        let shedding = LoadShedding::new(90.0, Duration::from_secs(10));
+        assert_eq!(shedding.observe(85.0), None);
+        assert_eq!(shedding.observe(93.5), Some(true));
+        assert!(shedding.is_active());
+
+        // Dentro del margen de recuperación se mantiene la descarga
+        assert_eq!(shedding.observe(84.0), None);
+        assert_eq!(shedding.episode().unwrap().memory_pct, 84.0);
+        assert_eq!(shedding.observe(79.0), Some(false));
+        assert!(!shedding.is_active());
+
+        let meminfo = "MemTotal:       16000000 kB\nMemFree:         1000000 kB\nMemAvailable:    4000000 kB\n";
+        assert_eq!(parse_meminfo(meminfo), Some(75.0));
+        assert_eq!(parse_meminfo("MemTotal: 100 kB\n"), None);
+    }
+}
@@ -0,0 +1,184 @@
+// server/src/actors/mailbox.rs
+// Profundidad de los buzones (mpsc) de cada dios y detección de saturación sostenida
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+use super::{ActorMessage, GodName};
+
+/// Fracción de la capacidad a partir de la cual el buzón se considera casi lleno
+pub const HIGH_WATER_RATIO: f64 = 0.8;
+/// Muestras consecutivas sobre el umbral antes de marcar al dios como Degraded
+pub const SUSTAINED_SAMPLES: u32 = 3;
+/// Intervalo de muestreo de los buzones
+pub const SAMPLE_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum MailboxStatus {
+    Active,
+    Degraded,
+    /// El receptor ya no existe: la tarea del dios terminó
+    Unavailable,
+}
+
+impl MailboxStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MailboxStatus::Active => "Active",
+            MailboxStatus::Degraded => "Degraded",
+            MailboxStatus::Unavailable => "Unavailable",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MailboxGauge {
+    /// Mensajes pendientes en el buzón
+    pub depth: usize,
+    pub capacity: usize,
+    /// Muestras consecutivas por encima de `HIGH_WATER_RATIO`
+    pub saturated_samples: u32,
+    pub status: MailboxStatus,
+}
+
+impl MailboxGauge {
+    pub fn usage(&self) -> f64 {
+        if self.capacity == 0 {
+            0.0
+        } else {
+            self.depth as f64 / self.capacity as f64
+        }
+    }
+}
+
+/// Última lectura del buzón de cada dios, compartida con los handlers de la API
+#[derive(Clone, Default)]
+pub struct MailboxMonitor {
+    gauges: Arc<RwLock<HashMap<GodName, MailboxGauge>>>,
+}
+
+impl MailboxMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registra una lectura; el estado pasa a Degraded tras `SUSTAINED_SAMPLES` lecturas
+    /// seguidas sobre el umbral y vuelve a Active en cuanto el buzón baja de él
+    pub fn observe(&self, god: GodName, depth: usize, capacity: usize) -> MailboxStatus {
+        let mut gauges = self.gauges.write().unwrap_or_else(|e| e.into_inner());
+        let previous = gauges.get(&god).map(|g| g.saturated_samples).unwrap_or(0);
+
+        let saturated = capacity > 0 && depth as f64 >= capacity as f64 * HIGH_WATER_RATIO;
+        let saturated_samples = if saturated { previous.saturating_add(1) } else { 0 };
+        let status = if saturated_samples >= SUSTAINED_SAMPLES {
+            MailboxStatus::Degraded
+        } else {
+            MailboxStatus::Active
+        };
+
+        if status == MailboxStatus::Degraded && previous + 1 == SUSTAINED_SAMPLES {
+            tracing::warn!("📬 [{}] Buzón saturado: {}/{} mensajes pendientes", god.as_str(), depth, capacity);
+        }
+
+        gauges.insert(god, MailboxGauge { depth, capacity, saturated_samples, status });
+        status
+    }
+
+    /// Canal cerrado: el dios deja de aceptar mensajes hasta que se reinicie el servidor
+    pub fn mark_unavailable(&self, god: GodName) {
+        let mut gauges = self.gauges.write().unwrap_or_else(|e| e.into_inner());
+        let capacity = gauges.get(&god).map(|g| g.capacity).unwrap_or(0);
+        let previous = gauges.insert(god, MailboxGauge { depth: 0, capacity, saturated_samples: 0, status: MailboxStatus::Unavailable });
+
+        if previous.map(|g| g.status) != Some(MailboxStatus::Unavailable) {
+            tracing::error!("💀 [{}] Buzón cerrado: el dios no está disponible", god.as_str());
+        }
+    }
+
+    /// Lee la profundidad actual de cada buzón (`max_capacity - capacity`)
+    pub fn sample(&self, senders: &HashMap<GodName, mpsc::Sender<ActorMessage>>) {
+        for (god, sender) in senders {
+            if sender.is_closed() {
+                self.mark_unavailable(*god);
+                continue;
+            }
+            let capacity = sender.max_capacity();
+            self.observe(*god, capacity - sender.capacity(), capacity);
+        }
+    }
+
+    pub fn gauge(&self, god: GodName) -> Option<MailboxGauge> {
+        self.gauges.read().unwrap_or_else(|e| e.into_inner()).get(&god).cloned()
+    }
+
+    pub fn degraded(&self) -> Vec<GodName> {
+        self.with_status(MailboxStatus::Degraded)
+    }
+
+    pub fn unavailable(&self) -> Vec<GodName> {
+        self.with_status(MailboxStatus::Unavailable)
+    }
+
+    pub fn is_unavailable(&self, god: GodName) -> bool {
+        self.gauge(god).is_some_and(|gauge| gauge.status == MailboxStatus::Unavailable)
+    }
+
+    fn with_status(&self, status: MailboxStatus) -> Vec<GodName> {
+        self.gauges
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .filter(|(_, gauge)| gauge.status == status)
+            .map(|(god, _)| *god)
+            .collect()
+    }
+}
+
+/// Muestrea los buzones periódicamente mientras viva el servidor
+pub async fn watch(
+    monitor: MailboxMonitor,
+    senders: Arc<tokio::sync::RwLock<HashMap<GodName, mpsc::Sender<ActorMessage>>>>,
+) {
+    let mut interval = tokio::time::interval(SAMPLE_INTERVAL);
+    loop {
+        interval.tick().await;
+        monitor.sample(&*senders.read().await);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actors::MessagePayload;
+
+    #[test]
+    fn test_flooded_mailbox_degrades_before_dropping() {
+        // Buzón sin consumidor: los mensajes se acumulan como bajo carga
+        let (tx, _rx) = mpsc::channel::<ActorMessage>(10);
+        let senders = HashMap::from([(GodName::Athena, tx.clone())]);
+        let monitor = MailboxMonitor::new();
+
+        for _ in 0..8 {
+            let msg = ActorMessage::new(GodName::Zeus, GodName::Athena, MessagePayload::Heartbeat { timestamp: chrono::Utc::now() });
+            tx.try_send(msg).unwrap();
+        }
+
+        monitor.sample(&senders);
+        monitor.sample(&senders);
+        assert_eq!(monitor.gauge(GodName::Athena).unwrap().status, MailboxStatus::Active);
+
+        monitor.sample(&senders);
+        let gauge = monitor.gauge(GodName::Athena).unwrap();
+        assert_eq!((gauge.depth, gauge.capacity), (8, 10));
+        assert_eq!(gauge.status, MailboxStatus::Degraded);
+        assert_eq!(monitor.degraded(), vec![GodName::Athena]);
+        // Aún queda sitio: nada se ha perdido todavía
+        assert!(tx.capacity() > 0);
+
+        // Al vaciarse el buzón se recupera
+        assert_eq!(monitor.observe(GodName::Athena, 1, 10), MailboxStatus::Active);
+    }
+}
@@ -1,7 +1,7 @@
 // server/src/actors/minor_gods.rs
 // Dioses menores del Olimpo - Implementaciones básicas
 
-use super::{ActorMessage, GodHealth, GodName, MessagePayload, OlympianActor};
+use super::{stats_reset_response, ActorMessage, GodHealth, GodName, MessagePayload, OlympianActor, RESET_STATS_ACTION};
 use async_trait::async_trait;
 use chrono::Utc;
 
@@ -40,6 +40,11 @@ macro_rules! define_minor_god {
                             MessagePayload::Heartbeat { timestamp: Utc::now() }
                         ))
                     }
+                    MessagePayload::Command { action, .. } if action == RESET_STATS_ACTION => {
+                        let previous = serde_json::json!({ "messages_processed": self.messages_count });
+                        self.messages_count = 0;
+                        Some(stats_reset_response(GodName::$name, msg.from, previous))
+                    }
                     _ => None
                 }
             }
@@ -5,7 +5,7 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::{mpsc, RwLock};
+use tokio::sync::{mpsc, oneshot, RwLock};
 use chrono::{DateTime, Utc};
 
 pub mod zeus;
@@ -16,16 +16,26 @@ pub mod hermes;
 pub mod hestia;
 pub mod erinyes;
 pub mod aphrodite;
+pub mod load_shedding;
+pub mod mailbox;
+pub mod reply_latency;
+pub mod surreal;
+pub mod trinity;
 pub mod minor_gods;
 
 pub use zeus::Zeus;
-pub use hades::Hades;
+pub use hades::{Hades, JwtSecret};
 pub use poseidon::Poseidon;
 pub use athena::Athena;
 pub use hermes::Hermes;
-pub use hestia::Hestia;
-pub use erinyes::Erinyes;
+pub use hestia::{Hestia, HestiaCache, HestiaStore};
+pub use erinyes::{Erinyes, HeartbeatConfig, HeartbeatRegistry, MonitoringTier, RecoveryRegistry};
 pub use aphrodite::Aphrodite;
+pub use load_shedding::LoadShedding;
+pub use mailbox::MailboxMonitor;
+pub use reply_latency::ReplyLatencyMonitor;
+pub use surreal::SurrealStore;
+pub use trinity::TrinityMembers;
 pub use minor_gods::{Apollo, Artemis, Hera, Ares, Hefesto, Chronos, Moirai, Chaos, Aurora, Iris, Demeter, Dionysus};
 
 // Nombres de los 20 dioses
@@ -54,6 +64,25 @@ pub enum GodName {
 }
 
 impl GodName {
+    /// Los 20 dioses en orden de ignición
+    pub const ALL: [GodName; 20] = [
+        GodName::Zeus, GodName::Hades, GodName::Poseidon,
+        GodName::Athena, GodName::Hermes, GodName::Hestia,
+        GodName::Erinyes, GodName::Aphrodite, GodName::Apollo,
+        GodName::Artemis, GodName::Hera, GodName::Ares,
+        GodName::Hefesto, GodName::Chronos, GodName::Moirai,
+        GodName::Chaos, GodName::Aurora, GodName::Iris,
+        GodName::Demeter, GodName::Dionysus,
+    ];
+
+    /// La Trinidad no se puede deshabilitar
+    pub const TRINITY: [GodName; 3] = [GodName::Zeus, GodName::Hades, GodName::Poseidon];
+
+    /// Busca un dios por nombre sin distinguir mayúsculas
+    pub fn from_name(name: &str) -> Option<GodName> {
+        Self::ALL.into_iter().find(|god| god.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             GodName::Zeus => "Zeus",
@@ -123,13 +152,17 @@ pub enum MessagePayload {
 }
 
 // Mensaje entre actores
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ActorMessage {
     pub id: String,
     pub from: GodName,
     pub to: GodName,
     pub payload: MessagePayload,
     pub timestamp: DateTime<Utc>,
+    /// Quien envió el comando espera aquí la respuesta del dios (petición/respuesta).
+    /// Sin canal el mensaje es de tipo "enviar y olvidar".
+    #[serde(skip)]
+    pub respond_to: Option<oneshot::Sender<ResponsePayload>>,
 }
 
 impl ActorMessage {
@@ -140,10 +173,53 @@ impl ActorMessage {
             to,
             payload,
             timestamp: Utc::now(),
+            respond_to: None,
+        }
+    }
+
+    /// Adjunta un canal de respuesta; el runtime del dios entrega por él su `MessagePayload::Response`
+    pub fn with_reply(mut self) -> (Self, oneshot::Receiver<ResponsePayload>) {
+        let (tx, rx) = oneshot::channel();
+        self.respond_to = Some(tx);
+        (self, rx)
+    }
+}
+
+/// Respuesta de un dios a un comando, tal como la recibe quien la espera
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResponsePayload {
+    pub success: bool,
+    pub data: serde_json::Value,
+    pub error: Option<String>,
+}
+
+impl ResponsePayload {
+    /// Solo un `MessagePayload::Response` es una respuesta; cualquier otro mensaje se descarta
+    pub fn from_message(msg: ActorMessage) -> Option<Self> {
+        match msg.payload {
+            MessagePayload::Response { success, data, error } => Some(Self { success, data, error }),
+            _ => None,
         }
     }
 }
 
+/// Acción común de los dioses instrumentados: pone a cero sus contadores (p. ej. entre
+/// pruebas de carga) y responde con los valores que tenían
+pub const RESET_STATS_ACTION: &str = "reset_stats";
+
+pub fn stats_reset_response(god: GodName, to: GodName, previous: serde_json::Value) -> ActorMessage {
+    tracing::info!("🔄 {}: contadores reiniciados", god.as_str());
+    ActorMessage::new(
+        god,
+        to,
+        MessagePayload::Response {
+            success: true,
+            data: serde_json::json!({ "reset": true, "previous": previous }),
+            error: None,
+        },
+    )
+}
+
 // Estado de salud de un dios
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GodHealth {
@@ -171,6 +247,9 @@ pub struct ActorRuntime {
     inbox: mpsc::Receiver<ActorMessage>,
     messages_processed: u64,
     start_time: DateTime<Utc>,
+    reply_latency: Option<ReplyLatencyMonitor>,
+    /// Buzón de Erinyes y membresía de la Trinidad (que fija el intervalo de latido)
+    heartbeats: Option<(mpsc::Sender<ActorMessage>, TrinityMembers)>,
 }
 
 impl ActorRuntime {
@@ -180,9 +259,46 @@ impl ActorRuntime {
             inbox,
             messages_processed: 0,
             start_time: Utc::now(),
+            reply_latency: None,
+            heartbeats: None,
         }
     }
 
+    /// Confirma en el monitor cada mensaje procesado (latencia de respuesta por dios)
+    pub fn with_reply_latency(mut self, monitor: ReplyLatencyMonitor) -> Self {
+        self.reply_latency = Some(monitor);
+        self
+    }
+
+    /// El dios late hacia Erinyes desde su propio bucle: si se bloquea procesando un
+    /// mensaje deja de latir y Erinyes lo detecta
+    pub fn with_heartbeats(mut self, erinyes: mpsc::Sender<ActorMessage>, trinity: TrinityMembers) -> Self {
+        self.heartbeats = Some((erinyes, trinity));
+        self
+    }
+
+    /// Envía un heartbeat y devuelve cuándo toca el siguiente. El intervalo se relee en
+    /// cada latido porque la membresía de la Trinidad puede cambiar en caliente.
+    /// `try_send`: Erinyes también late hacia su propio buzón y no puede esperarse a sí misma
+    fn beat(&mut self, name: GodName) -> tokio::time::Instant {
+        let Some((erinyes, trinity)) = &self.heartbeats else {
+            return tokio::time::Instant::now();
+        };
+        let heartbeat = ActorMessage::new(name, GodName::Erinyes, MessagePayload::Heartbeat { timestamp: Utc::now() });
+        let period = std::time::Duration::from_millis(HeartbeatConfig::for_god(name, trinity).interval_ms);
+        match erinyes.try_send(heartbeat) {
+            Ok(()) => {}
+            Err(mpsc::error::TrySendError::Full(_)) => {
+                tracing::debug!("💓 [{}] Buzón de Erinyes lleno: heartbeat omitido", name.as_str());
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => {
+                tracing::warn!("💓 [{}] Erinyes no está disponible: se dejan de enviar heartbeats", name.as_str());
+                self.heartbeats = None;
+            }
+        }
+        tokio::time::Instant::now() + period
+    }
+
     pub async fn run(mut self) {
         let name = self.actor.name();
         tracing::info!("🌟 [{}] Actor iniciado", name.as_str());
@@ -195,16 +311,35 @@ impl ActorRuntime {
 
         tracing::info!("✨ [{}] Actor listo", name.as_str());
 
-        // Loop principal
+        // Loop principal: mensajes del buzón y, entre ellos, los heartbeats propios
+        let mut next_heartbeat = tokio::time::Instant::now();
         loop {
-            match self.inbox.recv().await {
-                Some(msg) => {
+            let received = tokio::select! {
+                received = self.inbox.recv() => received,
+                _ = tokio::time::sleep_until(next_heartbeat), if self.heartbeats.is_some() => {
+                    next_heartbeat = self.beat(name);
+                    continue;
+                }
+            };
+            match received {
+                Some(mut msg) => {
                     let should_shutdown = matches!(msg.payload, MessagePayload::Shutdown { .. });
+                    let message_id = msg.id.clone();
+                    let respond_to = msg.respond_to.take();
                     
-                    if let Some(response) = self.actor.handle_message(msg).await {
-                        // Si hay respuesta, manejarla (por ahora solo log)
+                    let response = self.actor.handle_message(msg).await;
+                    if response.is_some() {
                         tracing::debug!("📨 [{}] Respuesta generada", name.as_str());
                     }
+                    // Sin respuesta el canal se cierra y quien espera lo detecta de inmediato
+                    if let Some(respond_to) = respond_to {
+                        if let Some(reply) = response.and_then(ResponsePayload::from_message) {
+                            let _ = respond_to.send(reply);
+                        }
+                    }
+                    if let Some(monitor) = &self.reply_latency {
+                        monitor.acknowledge(&message_id);
+                    }
                     
                     self.messages_processed += 1;
 
@@ -263,3 +398,78 @@ impl OlympusInner {
         self.health.values().cloned().collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    /// Se queda bloqueado en su primer mensaje hasta que el test lo libera
+    struct StuckHermes {
+        release: Option<oneshot::Receiver<()>>,
+    }
+
+    #[async_trait]
+    impl OlympianActor for StuckHermes {
+        fn name(&self) -> GodName {
+            GodName::Hermes
+        }
+
+        async fn handle_message(&mut self, _msg: ActorMessage) -> Option<ActorMessage> {
+            if let Some(release) = self.release.take() {
+                let _ = release.await;
+            }
+            None
+        }
+
+        async fn health(&self) -> GodHealth {
+            GodHealth {
+                name: GodName::Hermes,
+                healthy: true,
+                last_heartbeat: Utc::now(),
+                messages_processed: 0,
+                uptime_seconds: 0,
+                status: "test".to_string(),
+            }
+        }
+
+        async fn initialize(&mut self) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    fn drain_heartbeats(rx: &mut mpsc::Receiver<ActorMessage>) -> usize {
+        std::iter::from_fn(|| rx.try_recv().ok())
+            .filter(|msg| msg.from == GodName::Hermes && matches!(msg.payload, MessagePayload::Heartbeat { .. }))
+            .count()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_blocked_god_stops_beating() {
+        let interval = Duration::from_millis(HeartbeatConfig::STANDARD.interval_ms);
+        let (erinyes_tx, mut erinyes_rx) = mpsc::channel(100);
+        let (tx, rx) = mpsc::channel(10);
+        let (release_tx, release_rx) = oneshot::channel();
+        let runtime = ActorRuntime::new(Box::new(StuckHermes { release: Some(release_rx) }), rx)
+            .with_heartbeats(erinyes_tx, TrinityMembers::new());
+        tokio::spawn(runtime.run());
+
+        // Libre, late al arrancar y en cada intervalo
+        tokio::time::sleep(interval * 3 + Duration::from_millis(1)).await;
+        assert_eq!(drain_heartbeats(&mut erinyes_rx), 4);
+
+        // Bloqueado en un mensaje, su bucle no avanza y deja de latir
+        let msg = ActorMessage::new(GodName::Zeus, GodName::Hermes, MessagePayload::Query { query_type: "x".to_string(), params: serde_json::Value::Null });
+        tx.send(msg).await.unwrap();
+        tokio::time::sleep(interval * 5).await;
+        assert_eq!(drain_heartbeats(&mut erinyes_rx), 0);
+
+        release_tx.send(()).unwrap();
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        assert_eq!(drain_heartbeats(&mut erinyes_rx), 1);
+    }
+}
@@ -2,59 +2,195 @@
 // Poseidon: Flujo de Datos y Conexión a SurrealDB
 
 use async_trait::async_trait;
-use super::{ActorMessage, GodName, MessagePayload, OlympianActor, GodHealth};
+use super::{stats_reset_response, ActorMessage, GodName, MessagePayload, OlympianActor, GodHealth, RESET_STATS_ACTION};
+use super::surreal::{MergeOutcome, SurrealStore, UpdateOutcome};
 use chrono::Utc;
+use serde_json::{json, Value};
 
 pub struct Poseidon {
+    store: SurrealStore,
     surreal_connected: bool,
     queries_executed: u64,
     messages_count: u64,
 }
 
 impl Poseidon {
-    pub fn new() -> Self {
+    pub fn new(store: SurrealStore) -> Self {
         Self {
+            store,
             surreal_connected: false,
             queries_executed: 0,
             messages_count: 0,
         }
     }
 
-    async fn query_patients(&mut self) -> serde_json::Value {
+    async fn query_patients(&mut self) -> Result<Value, String> {
         self.queries_executed += 1;
-        // Simulación de query a SurrealDB
-        serde_json::json!({
-            "patients": [
-                {"id": "1", "first_name": "Juan", "last_name": "Perez", "diagnosis": "Neumonia"},
-                {"id": "2", "first_name": "Maria", "last_name": "Garcia", "diagnosis": "Postquirurgico"},
-            ],
+        let patients = self.store.patients().await?;
+        Ok(json!({
+            "patients": patients,
             "source": "SurrealDB",
             "queried_by": "Poseidon"
-        })
+        }))
     }
 
-    async fn create_patient(&mut self, data: &serde_json::Value) -> serde_json::Value {
+    async fn query_patient(&mut self, id: &str) -> Result<Value, String> {
         self.queries_executed += 1;
-        let id = uuid::Uuid::new_v4().to_string();
-        
-        serde_json::json!({
-            "id": id,
+        let patient = self.store.patient(id).await?;
+        Ok(json!({
+            "patient": patient,
+            "source": "SurrealDB",
+            "queried_by": "Poseidon"
+        }))
+    }
+
+    async fn count_patients(&mut self) -> Result<Value, String> {
+        self.queries_executed += 1;
+        let groups = self.store.count_patients().await?;
+        Ok(json!({
+            "groups": groups,
+            "source": "SurrealDB",
+            "queried_by": "Poseidon"
+        }))
+    }
+
+    /// El id de la respuesta es el del registro guardado
+    async fn create_patient(&mut self, data: &Value) -> Result<Value, String> {
+        self.queries_executed += 1;
+        let patient = self.store.create_patient(data.clone()).await?;
+
+        Ok(json!({
+            "id": patient["id"],
+            "created": true,
+            "patient": patient,
+            "source": "SurrealDB",
+            "created_by": "Poseidon"
+        }))
+    }
+
+    /// `data`: `{ patient, expected_updated_at }`. `updated: false` con `rejected` si el
+    /// registro ya no está en la versión que se leyó; en ese caso no se escribió nada
+    async fn update_patient(&mut self, data: &Value) -> Result<Value, String> {
+        self.queries_executed += 1;
+        let patient = data.get("patient").cloned().ok_or("Actualización sin paciente")?;
+        let expected = data.get("expected_updated_at").and_then(|v| v.as_str()).unwrap_or_default();
+        let outcome = self.store.update_patient(patient, expected).await?;
+
+        let mut response = json!({
+            "id": data["patient"]["id"],
+            "updated": false,
+            "source": "SurrealDB",
+            "updated_by": "Poseidon"
+        });
+        match outcome {
+            UpdateOutcome::Updated => response["updated"] = json!(true),
+            UpdateOutcome::NotFound => response["rejected"] = json!("not_found"),
+            UpdateOutcome::Stale(updated_at) => {
+                response["rejected"] = json!("stale");
+                response["updated_at"] = json!(updated_at);
+            }
+            UpdateOutcome::DuplicateIdentityCard(duplicate_id) => {
+                response["rejected"] = json!("duplicate_identity_card");
+                response["duplicate_id"] = json!(duplicate_id);
+            }
+        }
+        Ok(response)
+    }
+
+    /// La evaluación ya trae su id; se guarda tal cual
+    async fn create_assessment(&mut self, data: &Value) -> Result<Value, String> {
+        self.queries_executed += 1;
+        self.store.create_assessment(data.clone()).await?;
+
+        Ok(json!({
+            "id": data["id"],
             "created": true,
-            "data": data,
             "source": "SurrealDB",
             "created_by": "Poseidon"
-        })
+        }))
     }
 
-    async fn delete_patient(&mut self, id: &str) -> serde_json::Value {
+    /// `merged: false` con `rejected` si la transacción encontró a alguno de los dos
+    /// pacientes inexistente o ya retirado; en ese caso no se escribió nada
+    async fn merge_patients(&mut self, target_id: &str, source_id: &str) -> Result<Value, String> {
+        // Re-apuntar las evaluaciones y borrar lógicamente el origen en una transacción
         self.queries_executed += 1;
-        
-        serde_json::json!({
+        let outcome = self.store.merge_patients(target_id, source_id, &Utc::now().to_rfc3339()).await?;
+
+        let mut data = json!({
+            "target_id": target_id,
+            "source_id": source_id,
+            "merged": false,
+            "source": "SurrealDB",
+            "merged_by": "Poseidon"
+        });
+        match outcome {
+            MergeOutcome::Merged(assessments) => {
+                data["merged"] = json!(true);
+                data["assessments"] = json!(assessments);
+            }
+            MergeOutcome::NotFound => data["rejected"] = json!("not_found"),
+            MergeOutcome::SourceRetired => data["rejected"] = json!("source_retired"),
+            MergeOutcome::TargetRetired(merged_into) => {
+                data["rejected"] = json!("target_retired");
+                data["merged_into"] = json!(merged_into);
+            }
+        }
+        Ok(data)
+    }
+
+    fn reset_stats(&mut self, to: GodName) -> ActorMessage {
+        let previous = json!({
+            "messages_processed": self.messages_count,
+            "queries_executed": self.queries_executed,
+        });
+        self.messages_count = 0;
+        self.queries_executed = 0;
+        stats_reset_response(GodName::Poseidon, to, previous)
+    }
+
+    /// Borrado lógico: el registro queda con `deleted` y cuenta como eliminado.
+    /// `deleted: false` si no existía o ya estaba eliminado
+    async fn delete_patient(&mut self, id: &str) -> Result<Value, String> {
+        self.queries_executed += 1;
+        let exists = self.store.patient(id).await?.is_some_and(|p| p["deleted"] != true);
+        if exists {
+            self.store
+                .merge_patient(id, json!({ "deleted": true, "updated_at": Utc::now().to_rfc3339() }))
+                .await?;
+        }
+
+        Ok(json!({
+            "id": id,
+            "deleted": exists,
+            "source": "SurrealDB",
+            "deleted_by": "Poseidon"
+        }))
+    }
+
+    /// Retira el registro por completo (alta revertida por una cédula duplicada)
+    async fn discard_patient(&mut self, id: &str) -> Result<Value, String> {
+        self.queries_executed += 1;
+        let discarded = self.store.delete_patient(id).await?;
+
+        Ok(json!({
             "id": id,
-            "deleted": true,
+            "discarded": discarded,
             "source": "SurrealDB",
             "deleted_by": "Poseidon"
-        })
+        }))
+    }
+
+    /// Un fallo de SurrealDB se devuelve como respuesta fallida, nunca como éxito simulado
+    fn respond(&self, to: GodName, result: Result<Value, String>) -> ActorMessage {
+        let payload = match result {
+            Ok(data) => MessagePayload::Response { success: true, data, error: None },
+            Err(e) => {
+                tracing::error!("🌊 Poseidon: error de SurrealDB: {}", e);
+                MessagePayload::Response { success: false, data: Value::Null, error: Some(e) }
+            }
+        };
+        ActorMessage::new(GodName::Poseidon, to, payload)
     }
 }
 
@@ -74,28 +210,19 @@ impl OlympianActor for Poseidon {
                         self.query_patients().await
                     }
 
+                    "count_patients" => {
+                        self.count_patients().await
+                    }
+
                     "get_patient" => {
                         let id = params.get("id")?.as_str()?;
-                        serde_json::json!({
-                            "id": id,
-                            "first_name": "Demo",
-                            "last_name": "Patient",
-                            "queried_by": "Poseidon"
-                        })
+                        self.query_patient(id).await
                     }
 
                     _ => return None,
                 };
 
-                Some(ActorMessage::new(
-                    GodName::Poseidon,
-                    msg.from,
-                    MessagePayload::Response {
-                        success: true,
-                        data: result,
-                        error: None,
-                    }
-                ))
+                Some(self.respond(msg.from, result))
             }
 
             MessagePayload::Command { action, data } => {
@@ -104,23 +231,36 @@ impl OlympianActor for Poseidon {
                         self.create_patient(data).await
                     }
 
+                    "update_patient" => {
+                        self.update_patient(data).await
+                    }
+
                     "delete_patient" => {
                         let id = data.get("id")?.as_str()?;
                         self.delete_patient(id).await
                     }
 
+                    "discard_patient" => {
+                        let id = data.get("id")?.as_str()?;
+                        self.discard_patient(id).await
+                    }
+
+                    "create_assessment" => {
+                        self.create_assessment(data).await
+                    }
+
+                    "merge_patients" => {
+                        let target_id = data.get("target_id")?.as_str()?;
+                        let source_id = data.get("source_id")?.as_str()?;
+                        self.merge_patients(target_id, source_id).await
+                    }
+
+                    RESET_STATS_ACTION => return Some(self.reset_stats(msg.from)),
+
                     _ => return None,
                 };
 
-                Some(ActorMessage::new(
-                    GodName::Poseidon,
-                    msg.from,
-                    MessagePayload::Response {
-                        success: true,
-                        data: result,
-                        error: None,
-                    }
-                ))
+                Some(self.respond(msg.from, result))
             }
 
             _ => None
@@ -130,7 +270,7 @@ impl OlympianActor for Poseidon {
     async fn health(&self) -> GodHealth {
         GodHealth {
             name: GodName::Poseidon,
-            healthy: self.surreal_connected || true, // Siempre saludable en demo
+            healthy: self.surreal_connected,
             last_heartbeat: Utc::now(),
             messages_processed: self.messages_count,
             uptime_seconds: 0,
@@ -140,6 +280,7 @@ impl OlympianActor for Poseidon {
 
     async fn initialize(&mut self) -> Result<(), String> {
         tracing::info!("🌊 Poseidon: Conectando a SurrealDB...");
+        self.store.health().await?;
         self.surreal_connected = true;
         tracing::info!("🌊 Poseidon: Conectado a SurrealDB");
         Ok(())
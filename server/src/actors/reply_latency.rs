@@ -0,0 +1,278 @@
+// server/src/actors/reply_latency.rs
+// Latencia de respuesta de cada dios (p50/p95) y comandos sin respuesta a tiempo
+
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use super::GodName;
+
+pub const TIMEOUT_ENV: &str = "OLYMPUS_REPLY_TIMEOUT_MS";
+/// Ventana por defecto para que un dios procese un comando de la API
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(2);
+/// Muestras recientes por dios sobre las que se calculan los percentiles
+pub const WINDOW_SAMPLES: usize = 256;
+/// Un comando sin respuesta se olvida pasadas tantas ventanas (ya contado como timeout)
+const FORGET_AFTER_TIMEOUTS: u32 = 10;
+/// Intervalo con que se revisan los comandos pendientes
+pub const SWEEP_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Latencia de respuesta de un dios, tal como se expone en las métricas
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReplyLatency {
+    /// Muestras en la ventana actual
+    pub samples: usize,
+    pub p50_ms: Option<f64>,
+    pub p95_ms: Option<f64>,
+    /// Comandos que no recibieron respuesta dentro de `timeout_ms`
+    pub timeouts: u64,
+    /// Comandos enviados aún sin respuesta
+    pub pending: usize,
+    pub timeout_ms: u64,
+}
+
+#[derive(Debug, Default)]
+struct GodLatency {
+    samples: VecDeque<Duration>,
+    timeouts: u64,
+}
+
+#[derive(Debug)]
+struct Pending {
+    god: GodName,
+    sent_at: Instant,
+    /// Ya contado como timeout; si la respuesta llega tarde se registra su latencia igualmente
+    timed_out: bool,
+}
+
+#[derive(Default)]
+struct Inner {
+    gods: HashMap<GodName, GodLatency>,
+    pending: HashMap<String, Pending>,
+}
+
+/// Comandos enviados desde la API y su confirmación por el runtime del dios,
+/// compartido entre los handlers y los `ActorRuntime`
+#[derive(Clone)]
+pub struct ReplyLatencyMonitor {
+    timeout: Duration,
+    inner: Arc<RwLock<Inner>>,
+}
+
+impl Default for ReplyLatencyMonitor {
+    fn default() -> Self {
+        Self::new(DEFAULT_TIMEOUT)
+    }
+}
+
+impl ReplyLatencyMonitor {
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout, inner: Arc::default() }
+    }
+
+    /// Lee `OLYMPUS_REPLY_TIMEOUT_MS` (2000 por defecto)
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var(TIMEOUT_ENV) {
+            Ok(raw) => match raw.trim().parse::<u64>() {
+                Ok(ms) if ms > 0 => Ok(Self::new(Duration::from_millis(ms))),
+                _ => Err(format!("{} debe ser un número de milisegundos mayor que 0: '{}'", TIMEOUT_ENV, raw)),
+            },
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// Ventana de respuesta; también lo que espera un handler la respuesta de un dios
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Anota un comando antes de enviarlo (el dios podría procesarlo antes de volver del `send`)
+    pub fn expect(&self, god: GodName, message_id: &str) {
+        let mut inner = self.inner.write().unwrap_or_else(|e| e.into_inner());
+        inner
+            .pending
+            .insert(message_id.to_string(), Pending { god, sent_at: Instant::now(), timed_out: false });
+    }
+
+    /// El envío falló: el comando nunca llegará al dios
+    pub fn forget(&self, message_id: &str) {
+        self.inner.write().unwrap_or_else(|e| e.into_inner()).pending.remove(message_id);
+    }
+
+    /// El runtime del dios terminó de procesar el mensaje; los mensajes que no salieron
+    /// de la API (heartbeats, mensajes entre dioses) no están anotados y se ignoran
+    pub fn acknowledge(&self, message_id: &str) {
+        let mut inner = self.inner.write().unwrap_or_else(|e| e.into_inner());
+        let Some(pending) = inner.pending.remove(message_id) else {
+            return;
+        };
+        let elapsed = pending.sent_at.elapsed();
+        let late = !pending.timed_out && elapsed > self.timeout;
+        let god = inner.gods.entry(pending.god).or_default();
+        if god.samples.len() == WINDOW_SAMPLES {
+            god.samples.pop_front();
+        }
+        god.samples.push_back(elapsed);
+        if late {
+            god.timeouts += 1;
+        }
+    }
+
+    /// Cuenta como timeout los comandos que superaron la ventana sin respuesta
+    pub fn sweep(&self) {
+        let mut inner = self.inner.write().unwrap_or_else(|e| e.into_inner());
+        let forget_after = self.timeout * FORGET_AFTER_TIMEOUTS;
+        let mut expired = Vec::new();
+        inner.pending.retain(|_, pending| {
+            let elapsed = pending.sent_at.elapsed();
+            if !pending.timed_out && elapsed > self.timeout {
+                pending.timed_out = true;
+                expired.push(pending.god);
+            }
+            elapsed <= forget_after
+        });
+        for god in expired {
+            tracing::warn!("⏱️ [{}] Comando sin respuesta tras {} ms", god.as_str(), self.timeout.as_millis());
+            inner.gods.entry(god).or_default().timeouts += 1;
+        }
+    }
+
+    pub fn latency(&self, god: GodName) -> ReplyLatency {
+        self.sweep();
+        let inner = self.inner.read().unwrap_or_else(|e| e.into_inner());
+        let mut samples: Vec<Duration> = inner
+            .gods
+            .get(&god)
+            .map(|g| g.samples.iter().copied().collect())
+            .unwrap_or_default();
+        samples.sort();
+        ReplyLatency {
+            samples: samples.len(),
+            p50_ms: percentile_ms(&samples, 50),
+            p95_ms: percentile_ms(&samples, 95),
+            timeouts: inner.gods.get(&god).map(|g| g.timeouts).unwrap_or(0),
+            pending: inner.pending.values().filter(|p| p.god == god).count(),
+            timeout_ms: self.timeout.as_millis() as u64,
+        }
+    }
+
+    /// Timeouts acumulados de todos los dioses
+    pub fn total_timeouts(&self) -> u64 {
+        self.sweep();
+        self.inner.read().unwrap_or_else(|e| e.into_inner()).gods.values().map(|g| g.timeouts).sum()
+    }
+}
+
+/// Percentil por el método del rango más cercano sobre muestras ordenadas
+fn percentile_ms(sorted: &[Duration], percentile: usize) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (percentile * sorted.len()).div_ceil(100).max(1);
+    Some(sorted[rank - 1].as_secs_f64() * 1000.0)
+}
+
+/// Revisa periódicamente los comandos pendientes mientras viva el servidor
+pub async fn watch(monitor: ReplyLatencyMonitor) {
+    let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+    loop {
+        interval.tick().await;
+        monitor.sweep();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actors::{ActorMessage, ActorRuntime, GodHealth, MessagePayload, OlympianActor};
+    use async_trait::async_trait;
+    use tokio::sync::mpsc;
+
+    /// Aphrodite que tarda `delay` en procesar cada mensaje
+    struct SlowAphrodite {
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl OlympianActor for SlowAphrodite {
+        fn name(&self) -> GodName {
+            GodName::Aphrodite
+        }
+
+        async fn handle_message(&mut self, _msg: ActorMessage) -> Option<ActorMessage> {
+            tokio::time::sleep(self.delay).await;
+            None
+        }
+
+        async fn health(&self) -> GodHealth {
+            GodHealth {
+                name: GodName::Aphrodite,
+                healthy: true,
+                last_heartbeat: chrono::Utc::now(),
+                messages_processed: 0,
+                uptime_seconds: 0,
+                status: "test".to_string(),
+            }
+        }
+
+        async fn initialize(&mut self) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_percentiles() {
+        let samples: Vec<Duration> = (1..=100).map(Duration::from_millis).collect();
+        assert_eq!(percentile_ms(&samples, 50), Some(50.0));
+        assert_eq!(percentile_ms(&samples, 95), Some(95.0));
+        assert_eq!(percentile_ms(&samples[..1], 95), Some(1.0));
+        assert_eq!(percentile_ms(&[], 50), None);
+    }
+
+    #[tokio::test]
+    async fn test_slow_god_shows_latency_and_timeouts() {
+        let monitor = ReplyLatencyMonitor::new(Duration::from_millis(20));
+        let (tx, rx) = mpsc::channel(10);
+        let actor = SlowAphrodite { delay: Duration::from_millis(40) };
+        tokio::spawn(ActorRuntime::new(Box::new(actor), rx).with_reply_latency(monitor.clone()).run());
+
+        for _ in 0..3 {
+            let msg = ActorMessage::new(
+                GodName::Zeus,
+                GodName::Aphrodite,
+                MessagePayload::Query { query_type: "get_current_theme".to_string(), params: serde_json::json!({}) },
+            );
+            monitor.expect(GodName::Aphrodite, &msg.id);
+            tx.send(msg).await.unwrap();
+        }
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        let latency = monitor.latency(GodName::Aphrodite);
+        assert_eq!(latency.samples, 3);
+        assert_eq!(latency.pending, 0);
+        assert!(latency.p50_ms.unwrap() >= 40.0);
+        assert!(latency.p95_ms.unwrap() >= latency.p50_ms.unwrap());
+        assert_eq!(latency.timeouts, 3);
+        assert_eq!(monitor.total_timeouts(), 3);
+
+        // Un dios que nunca contesta cuenta como timeout al barrer, una sola vez
+        monitor.expect(GodName::Hera, "sin-respuesta");
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        monitor.sweep();
+        assert_eq!(monitor.latency(GodName::Hera).timeouts, 1);
+        assert_eq!(monitor.latency(GodName::Hera).pending, 1);
+        assert_eq!(monitor.latency(GodName::Zeus), ReplyLatency {
+            samples: 0,
+            p50_ms: None,
+            p95_ms: None,
+            timeouts: 0,
+            pending: 0,
+            timeout_ms: 20,
+        });
+    }
+}
@@ -0,0 +1,449 @@
+// server/src/actors/surreal.rs
+// Almacén de pacientes y evaluaciones de Poseidon en SurrealDB. Cada paciente es un registro
+// de la tabla `patient` cuya clave es la parte del id tras `patient:` (las evaluaciones, igual
+// en `assessment`); el resto del registro es el mismo JSON que devuelve la API.
+
+use serde::Deserialize;
+use serde_json::Value;
+use surrealdb::engine::any::{self, Any};
+use surrealdb::opt::auth::Root;
+use surrealdb::Surreal;
+
+pub const URL_ENV: &str = "OLYMPUS_SURREAL_URL";
+pub const USER_ENV: &str = "OLYMPUS_SURREAL_USER";
+pub const PASS_ENV: &str = "OLYMPUS_SURREAL_PASS";
+pub const NAMESPACE: &str = "olympus";
+pub const DATABASE: &str = "uci";
+pub const PATIENT_TABLE: &str = "patient";
+pub const ASSESSMENT_TABLE: &str = "assessment";
+
+/// Proyección que devuelve la clave del registro como texto en lugar del record id
+const SELECT_RECORD: &str = "SELECT *, meta::id(id) AS id FROM";
+
+/// Fusión en un único bloque. SurrealDB ejecuta cada sentencia en su propia transacción:
+/// la comprobación de ambos pacientes y las dos escrituras se confirman juntas, y una
+/// fusión concurrente que ya retiró a alguno de los dos hace que esta se rechace sin escribir
+const MERGE_PATIENTS: &str = "{
+    LET $source = (SELECT VALUE deleted = true FROM type::thing($table, $source_key));
+    LET $target = (SELECT VALUE deleted = true FROM type::thing($table, $target_key));
+    IF array::len($source) = 0 OR array::len($target) = 0 {
+        RETURN { rejected: 'not_found' };
+    } ELSE IF $source[0] {
+        RETURN { rejected: 'source_retired' };
+    } ELSE IF $target[0] {
+        RETURN { rejected: 'target_retired' };
+    } ELSE {
+        LET $moved = (SELECT VALUE meta::id(id) FROM type::table($assessments) WHERE patient_id = $source_id);
+        UPDATE type::table($assessments) SET patient_id = $target_id WHERE patient_id = $source_id RETURN NONE;
+        UPDATE type::thing($table, $source_key) MERGE { deleted: true, merged_into: $target_id, updated_at: $updated_at } RETURN NONE;
+        RETURN { moved: $moved };
+    };
+}";
+
+/// Reemplazo condicional, también en un único bloque: solo se escribe si el registro sigue
+/// activo con el `updated_at` que se leyó y, si cambia la cédula, ningún otro paciente activo
+/// la tiene. Dos PATCH sobre la misma versión no pueden confirmarse ambos
+const UPDATE_PATIENT: &str = "{
+    LET $current = (SELECT updated_at, identity_card, deleted = true AS deleted FROM type::thing($table, $key));
+    IF array::len($current) = 0 OR $current[0].deleted {
+        RETURN { rejected: 'not_found' };
+    } ELSE IF ($current[0].updated_at ?? '') != $expected {
+        RETURN { rejected: 'stale', updated_at: $current[0].updated_at };
+    } ELSE {
+        LET $duplicates = (SELECT VALUE meta::id(id) FROM type::table($table)
+            WHERE $patient.identity_card != $current[0].identity_card
+                AND identity_card = $patient.identity_card AND deleted != true AND id != type::thing($table, $key));
+        IF array::len($duplicates) > 0 {
+            RETURN { rejected: 'duplicate_identity_card', duplicate: $duplicates[0] };
+        } ELSE {
+            UPDATE type::thing($table, $key) CONTENT $patient RETURN NONE;
+            RETURN { updated: true };
+        };
+    };
+}";
+
+/// Resultado de `update_patient`
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdateOutcome {
+    Updated,
+    /// No existe o está eliminado
+    NotFound,
+    /// Otra escritura cambió el registro; con su `updated_at` actual
+    Stale(Option<String>),
+    /// Id del paciente activo que ya tiene la cédula
+    DuplicateIdentityCard(String),
+}
+
+#[derive(Deserialize)]
+struct UpdateRow {
+    rejected: Option<String>,
+    updated_at: Option<String>,
+    duplicate: Option<String>,
+}
+
+/// Resultado de `merge_patients`
+#[derive(Debug, Clone, PartialEq)]
+pub enum MergeOutcome {
+    /// Ids de las evaluaciones que pasaron al destino
+    Merged(Vec<String>),
+    /// Alguno de los dos pacientes no existe
+    NotFound,
+    /// El origen ya fue fusionado o eliminado
+    SourceRetired,
+    /// El destino ya fue fusionado o eliminado; con su `merged_into` si lo tiene
+    TargetRetired(Option<String>),
+}
+
+#[derive(Deserialize)]
+struct MergeRow {
+    rejected: Option<String>,
+    #[serde(default)]
+    moved: Vec<String>,
+}
+
+/// Handle clonable a la base de datos; Poseidon es su único usuario
+#[derive(Clone)]
+pub struct SurrealStore {
+    db: Surreal<Any>,
+}
+
+impl SurrealStore {
+    /// Conecta a `url` (`surrealkv://ruta`, `ws://host:8000`, `mem://`...) y selecciona
+    /// el namespace y la base de datos de Olympus
+    pub async fn connect(url: &str, credentials: Option<(String, String)>) -> Result<Self, String> {
+        let db = any::connect(url).await.map_err(|e| format!("SurrealDB {}: {}", url, e))?;
+        if let Some((username, password)) = credentials {
+            db.signin(Root { username: &username, password: &password })
+                .await
+                .map_err(|e| format!("SurrealDB {}: credenciales rechazadas: {}", url, e))?;
+        }
+        db.use_ns(NAMESPACE).use_db(DATABASE).await.map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+
+    /// Base de datos solo en memoria (tests)
+    #[cfg(test)]
+    pub async fn in_memory() -> Result<Self, String> {
+        Self::connect("mem://", None).await
+    }
+
+    /// Ejecuta SurrealQL sin resultado (tests: esquemas y registros de apoyo)
+    #[cfg(test)]
+    pub async fn execute(&self, sql: &str) -> Result<(), String> {
+        self.db.query(sql).await.map_err(|e| e.to_string())?.check().map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// `OLYMPUS_SURREAL_URL` (por defecto `surrealkv://<data_dir>/surreal`, en disco) y,
+    /// para un servidor remoto, `OLYMPUS_SURREAL_USER` y `OLYMPUS_SURREAL_PASS`
+    pub async fn from_env(data_dir: &str) -> Result<Self, String> {
+        let url = std::env::var(URL_ENV).unwrap_or_else(|_| {
+            format!("surrealkv://{}", std::path::Path::new(data_dir).join("surreal").display())
+        });
+        let credentials = match (std::env::var(USER_ENV), std::env::var(PASS_ENV)) {
+            (Ok(username), Ok(password)) => Some((username, password)),
+            (Err(_), Err(_)) => None,
+            _ => return Err(format!("{} y {} deben definirse juntos", USER_ENV, PASS_ENV)),
+        };
+        Self::connect(&url, credentials).await
+    }
+
+    pub async fn health(&self) -> Result<(), String> {
+        self.db.health().await.map_err(|e| e.to_string())
+    }
+
+    pub async fn patients(&self) -> Result<Vec<Value>, String> {
+        let mut response = self
+            .db
+            .query(format!("{} type::table($table)", SELECT_RECORD))
+            .bind(("table", PATIENT_TABLE))
+            .await
+            .map_err(|e| e.to_string())?;
+        let records: Vec<Value> = response.take(0).map_err(|e| e.to_string())?;
+        Ok(records.into_iter().map(|record| with_record_id(PATIENT_TABLE, record)).collect())
+    }
+
+    pub async fn patient(&self, id: &str) -> Result<Option<Value>, String> {
+        let mut response = self
+            .db
+            .query(format!("{} type::thing($table, $key)", SELECT_RECORD))
+            .bind(("table", PATIENT_TABLE))
+            .bind(("key", record_key(PATIENT_TABLE, id)?))
+            .await
+            .map_err(|e| e.to_string())?;
+        let records: Vec<Value> = response.take(0).map_err(|e| e.to_string())?;
+        Ok(records.into_iter().next().map(|record| with_record_id(PATIENT_TABLE, record)))
+    }
+
+    /// Recuento agrupado por estado y tipo de ingreso; solo viajan los grupos
+    pub async fn count_patients(&self) -> Result<Vec<Value>, String> {
+        let mut response = self
+            .db
+            .query(
+                "SELECT deleted = true AS is_deleted, type::is::string(discharged_at) AS is_discharged, \
+                 incomplete = true AS is_incomplete, admission_type, count() AS count \
+                 FROM type::table($table) GROUP BY is_deleted, is_discharged, is_incomplete, admission_type",
+            )
+            .bind(("table", PATIENT_TABLE))
+            .await
+            .map_err(|e| e.to_string())?;
+        response.take(0).map_err(|e| e.to_string())
+    }
+
+    /// Crea el registro con la clave de `patient["id"]` o, si no trae id, con un ULID que
+    /// asigna la base de datos (`rand::ulid()`), y devuelve el registro guardado con su id
+    pub async fn create_patient(&self, mut patient: Value) -> Result<Value, String> {
+        let key = match patient.as_object_mut().and_then(|p| p.remove("id")) {
+            Some(Value::String(id)) => Some(record_key(PATIENT_TABLE, &id)?),
+            _ => None,
+        };
+        let mut response = self
+            .db
+            .query("CREATE type::thing($table, $key ?? rand::ulid()) CONTENT $patient RETURN meta::id(id) AS id")
+            .bind(("table", PATIENT_TABLE))
+            .bind(("key", key))
+            .bind(("patient", patient))
+            .await
+            .map_err(|e| e.to_string())?
+            .check()
+            .map_err(|e| e.to_string())?;
+        let created: Vec<Value> = response.take(0).map_err(|e| e.to_string())?;
+        let key = created
+            .first()
+            .and_then(|record| record["id"].as_str())
+            .map(str::to_string)
+            .ok_or_else(|| "SurrealDB no devolvió el id del paciente".to_string())?;
+        let id = record_id(PATIENT_TABLE, &key);
+        self.patient(&id)
+            .await?
+            .ok_or_else(|| format!("SurrealDB no devolvió el paciente {}", id))
+    }
+
+    /// Reemplaza el registro completo si su `updated_at` sigue siendo `expected_updated_at`
+    pub async fn update_patient(&self, mut patient: Value, expected_updated_at: &str) -> Result<UpdateOutcome, String> {
+        let Some(Value::String(id)) = patient.as_object_mut().and_then(|p| p.remove("id")) else {
+            return Err("Paciente sin id".to_string());
+        };
+        let mut response = self
+            .db
+            .query(UPDATE_PATIENT)
+            .bind(("table", PATIENT_TABLE))
+            .bind(("key", record_key(PATIENT_TABLE, &id)?))
+            .bind(("expected", expected_updated_at.to_string()))
+            .bind(("patient", patient))
+            .await
+            .map_err(|e| e.to_string())?
+            .check()
+            .map_err(|e| e.to_string())?;
+        let row: Option<UpdateRow> = response.take(0).map_err(|e| e.to_string())?;
+        let row = row.ok_or_else(|| "SurrealDB no devolvió el resultado de la actualización".to_string())?;
+
+        Ok(match row.rejected.as_deref() {
+            None => UpdateOutcome::Updated,
+            Some("stale") => UpdateOutcome::Stale(row.updated_at),
+            Some("duplicate_identity_card") => {
+                UpdateOutcome::DuplicateIdentityCard(record_id(PATIENT_TABLE, row.duplicate.as_deref().unwrap_or_default()))
+            }
+            Some(_) => UpdateOutcome::NotFound,
+        })
+    }
+
+    /// Fusiona `fields` en el registro existente
+    pub async fn merge_patient(&self, id: &str, fields: Value) -> Result<(), String> {
+        self.db
+            .query("UPDATE type::thing($table, $key) MERGE $fields RETURN NONE")
+            .bind(("table", PATIENT_TABLE))
+            .bind(("key", record_key(PATIENT_TABLE, id)?))
+            .bind(("fields", fields))
+            .await
+            .map_err(|e| e.to_string())?
+            .check()
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Fusión de duplicados en una sola transacción: las evaluaciones del origen pasan al
+    /// destino y el origen queda borrado lógicamente con `merged_into`
+    pub async fn merge_patients(&self, target_id: &str, source_id: &str, updated_at: &str) -> Result<MergeOutcome, String> {
+        let mut response = self
+            .db
+            .query(MERGE_PATIENTS)
+            .bind(("assessments", ASSESSMENT_TABLE))
+            .bind(("table", PATIENT_TABLE))
+            .bind(("source_key", record_key(PATIENT_TABLE, source_id)?))
+            .bind(("target_key", record_key(PATIENT_TABLE, target_id)?))
+            .bind(("target_id", target_id.to_string()))
+            .bind(("source_id", source_id.to_string()))
+            .bind(("updated_at", updated_at.to_string()))
+            .await
+            .map_err(|e| e.to_string())?
+            .check()
+            .map_err(|e| e.to_string())?;
+        let row: Option<MergeRow> = response.take(0).map_err(|e| e.to_string())?;
+        let row = row.ok_or_else(|| "SurrealDB no devolvió el resultado de la fusión".to_string())?;
+
+        Ok(match row.rejected.as_deref() {
+            None => MergeOutcome::Merged(row.moved.iter().map(|key| record_id(ASSESSMENT_TABLE, key)).collect()),
+            Some("source_retired") => MergeOutcome::SourceRetired,
+            Some("target_retired") => {
+                let merged_into = self.patient(target_id).await?.and_then(|p| p["merged_into"].as_str().map(str::to_string));
+                MergeOutcome::TargetRetired(merged_into)
+            }
+            Some(_) => MergeOutcome::NotFound,
+        })
+    }
+
+    pub async fn assessments(&self) -> Result<Vec<Value>, String> {
+        let mut response = self
+            .db
+            .query(format!("{} type::table($table)", SELECT_RECORD))
+            .bind(("table", ASSESSMENT_TABLE))
+            .await
+            .map_err(|e| e.to_string())?;
+        let records: Vec<Value> = response.take(0).map_err(|e| e.to_string())?;
+        Ok(records.into_iter().map(|record| with_record_id(ASSESSMENT_TABLE, record)).collect())
+    }
+
+    /// Guarda la evaluación con la clave de su `id` (`assessment:<clave>`)
+    pub async fn create_assessment(&self, mut assessment: Value) -> Result<(), String> {
+        let Some(Value::String(id)) = assessment.as_object_mut().and_then(|a| a.remove("id")) else {
+            return Err("Evaluación sin id".to_string());
+        };
+        self.db
+            .query("CREATE type::thing($table, $key) CONTENT $assessment RETURN NONE")
+            .bind(("table", ASSESSMENT_TABLE))
+            .bind(("key", record_key(ASSESSMENT_TABLE, &id)?))
+            .bind(("assessment", assessment))
+            .await
+            .map_err(|e| e.to_string())?
+            .check()
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// `false` si el paciente no existía
+    pub async fn delete_patient(&self, id: &str) -> Result<bool, String> {
+        if self.patient(id).await?.is_none() {
+            return Ok(false);
+        }
+        self.db
+            .query("DELETE type::thing($table, $key) RETURN NONE")
+            .bind(("table", PATIENT_TABLE))
+            .bind(("key", record_key(PATIENT_TABLE, id)?))
+            .await
+            .map_err(|e| e.to_string())?
+            .check()
+            .map_err(|e| e.to_string())?;
+        Ok(true)
+    }
+}
+
+/// `<tabla>:<clave>` → `<clave>`
+fn record_key(table: &str, id: &str) -> Result<String, String> {
+    id.strip_prefix(table)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .filter(|key| !key.is_empty())
+        .map(str::to_string)
+        .ok_or_else(|| format!("Id de {} no válido: '{}'", table, id))
+}
+
+fn record_id(table: &str, key: &str) -> String {
+    format!("{}:{}", table, key)
+}
+
+/// La proyección deja la clave en `id`; la API usa el id completo `<tabla>:<clave>`
+fn with_record_id(table: &str, mut record: Value) -> Value {
+    if let Some(key) = record.get("id").and_then(Value::as_str).map(|key| record_id(table, key)) {
+        record["id"] = Value::String(key);
+    }
+    record
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_patients_round_trip_through_the_database() {
+        let store = SurrealStore::in_memory().await.unwrap();
+
+        let created = store.create_patient(json!({ "id": "patient:1", "first_name": "Ana" })).await.unwrap();
+        assert_eq!(created, json!({ "id": "patient:1", "first_name": "Ana" }));
+        let assigned = store.create_patient(json!({ "first_name": "Eva" })).await.unwrap();
+        assert!(assigned["id"].as_str().unwrap().starts_with("patient:"));
+        assert!(store.create_patient(json!({ "id": "patient:1", "first_name": "Otra" })).await.is_err());
+
+        store.merge_patient("patient:1", json!({ "deleted": true })).await.unwrap();
+        assert_eq!(store.patient("patient:1").await.unwrap().unwrap()["deleted"], true);
+        assert_eq!(store.patients().await.unwrap().len(), 2);
+
+        assert!(store.delete_patient("patient:1").await.unwrap());
+        assert!(!store.delete_patient("patient:1").await.unwrap());
+        assert_eq!(store.patient("patient:1").await.unwrap(), None);
+        assert!(store.patient("otro:1").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_count_groups_patients_in_the_database() {
+        let store = SurrealStore::in_memory().await.unwrap();
+        for patient in [
+            json!({ "id": "patient:1", "admission_type": "emergency" }),
+            json!({ "id": "patient:2", "admission_type": "emergency" }),
+            json!({ "id": "patient:3", "admission_type": "emergency", "discharged_at": "2026-01-02T10:00:00Z" }),
+            json!({ "id": "patient:4", "deleted": true }),
+        ] {
+            store.create_patient(patient).await.unwrap();
+        }
+
+        let groups = store.count_patients().await.unwrap();
+        let count = |deleted: bool, discharged: bool| {
+            groups
+                .iter()
+                .find(|g| g["is_deleted"] == deleted && g["is_discharged"] == discharged)
+                .map(|g| g["count"].clone())
+        };
+        assert_eq!(groups.len(), 3);
+        assert_eq!(count(false, false), Some(json!(2)));
+        assert_eq!(count(false, true), Some(json!(1)));
+        assert_eq!(count(true, false), Some(json!(1)));
+    }
+
+    #[tokio::test]
+    async fn test_merge_moves_assessments_and_retires_the_source_together() {
+        let store = SurrealStore::in_memory().await.unwrap();
+        for (id, first_name) in [("patient:1", "Ana"), ("patient:2", "Ana"), ("patient:3", "Eva")] {
+            store.create_patient(json!({ "id": id, "first_name": first_name })).await.unwrap();
+        }
+        for (id, patient_id) in [("assessment:1", "patient:2"), ("assessment:2", "patient:2"), ("assessment:3", "patient:3")] {
+            store.create_assessment(json!({ "id": id, "patient_id": patient_id, "scale": "Glasgow" })).await.unwrap();
+        }
+
+        let merged = store.merge_patients("patient:1", "patient:2", "2026-01-01T00:00:00Z").await.unwrap();
+        let MergeOutcome::Merged(mut moved) = merged else { panic!("fusión rechazada") };
+        moved.sort();
+        assert_eq!(moved, vec!["assessment:1", "assessment:2"]);
+        let owners: Vec<(String, String)> = store
+            .assessments()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|a| (a["id"].as_str().unwrap().to_string(), a["patient_id"].as_str().unwrap().to_string()))
+            .collect();
+        assert_eq!(owners.len(), 3);
+        assert!(owners.contains(&("assessment:1".to_string(), "patient:1".to_string())));
+        assert!(owners.contains(&("assessment:3".to_string(), "patient:3".to_string())));
+        assert_eq!(store.patient("patient:2").await.unwrap().unwrap()["merged_into"], "patient:1");
+        assert_eq!(store.patient("patient:1").await.unwrap().unwrap().get("deleted"), None);
+
+        // La transacción vuelve a comprobar el estado: la fusión inversa ya no procede
+        let reverse = store.merge_patients("patient:2", "patient:1", "2026-01-01T00:00:01Z").await.unwrap();
+        assert_eq!(reverse, MergeOutcome::TargetRetired(Some("patient:1".to_string())));
+        let retired = store.merge_patients("patient:3", "patient:2", "2026-01-01T00:00:01Z").await.unwrap();
+        assert_eq!(retired, MergeOutcome::SourceRetired);
+        let missing = store.merge_patients("patient:3", "patient:9", "2026-01-01T00:00:01Z").await.unwrap();
+        assert_eq!(missing, MergeOutcome::NotFound);
+        assert_eq!(store.patient("patient:1").await.unwrap().unwrap().get("deleted"), None);
+        assert_eq!(store.patient("patient:3").await.unwrap().unwrap().get("deleted"), None);
+    }
+}
@@ -0,0 +1,70 @@
+// server/src/actors/trinity.rs
+// Miembros de la Trinidad: compartidos por Zeus (estado crítico), Erinyes (nivel de
+// vigilancia) y la API. Los fundadores (Zeus, Hades, Poseidon) no se pueden retirar.
+
+use std::sync::{Arc, RwLock};
+
+use super::GodName;
+
+#[derive(Clone)]
+pub struct TrinityMembers {
+    members: Arc<RwLock<Vec<GodName>>>,
+}
+
+impl Default for TrinityMembers {
+    fn default() -> Self {
+        Self { members: Arc::new(RwLock::new(GodName::TRINITY.to_vec())) }
+    }
+}
+
+impl TrinityMembers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Miembros en el orden de `GodName::ALL`
+    pub fn members(&self) -> Vec<GodName> {
+        self.members.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub fn contains(&self, god: GodName) -> bool {
+        self.members.read().unwrap_or_else(|e| e.into_inner()).contains(&god)
+    }
+
+    /// Reemplaza la membresía. `is_enabled` descarta dioses que Genesis no desplegó.
+    /// Devuelve los dioses cuyo nivel cambió (entrantes y salientes).
+    pub fn set(&self, requested: &[GodName], is_enabled: impl Fn(GodName) -> bool) -> Result<Vec<GodName>, String> {
+        if let Some(founder) = GodName::TRINITY.iter().find(|god| !requested.contains(god)) {
+            return Err(format!("{} es fundador de la Trinidad y no se puede retirar", founder.as_str()));
+        }
+        if let Some(disabled) = requested.iter().find(|god| !is_enabled(**god)) {
+            return Err(format!("{} está deshabilitado y no puede formar parte de la Trinidad", disabled.as_str()));
+        }
+
+        let members: Vec<GodName> = GodName::ALL.into_iter().filter(|god| requested.contains(god)).collect();
+        let mut current = self.members.write().unwrap_or_else(|e| e.into_inner());
+        let changed = GodName::ALL
+            .into_iter()
+            .filter(|god| current.contains(god) != members.contains(god))
+            .collect();
+        *current = members;
+        Ok(changed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_keeps_founders_and_reports_changes() {
+        let trinity = TrinityMembers::new();
+        assert!(trinity.set(&[GodName::Zeus, GodName::Hades], |_| true).is_err());
+        assert!(trinity.set(&[GodName::Zeus, GodName::Hades, GodName::Poseidon, GodName::Chaos], |god| god != GodName::Chaos).is_err());
+
+        let changed = trinity.set(&[GodName::Hestia, GodName::Zeus, GodName::Hades, GodName::Poseidon], |_| true).unwrap();
+        assert_eq!(changed, vec![GodName::Hestia]);
+        assert!(trinity.contains(GodName::Hestia));
+        assert_eq!(trinity.members(), vec![GodName::Zeus, GodName::Hades, GodName::Poseidon, GodName::Hestia]);
+    }
+}
@@ -2,34 +2,45 @@
 // Zeus: Gobernador Supremo y Supervisor del Olimpo
 
 use async_trait::async_trait;
-use super::{ActorMessage, GodName, MessagePayload, OlympianActor, GodHealth};
+use super::{ActorMessage, GodName, MessagePayload, OlympianActor, GodHealth, LoadShedding, TrinityMembers};
+use super::load_shedding::RESOURCE_PRESSURE_EVENT;
 use chrono::Utc;
 use std::collections::HashMap;
 
 pub struct Zeus {
     supervised_actors: HashMap<GodName, bool>, // nombre -> salud
     restart_count: HashMap<GodName, u32>,
+    trinity: TrinityMembers,
+    load_shedding: LoadShedding,
 }
 
 impl Zeus {
-    pub fn new() -> Self {
-        let mut supervised = HashMap::new();
+    /// Supervisa solo los dioses indicados (los habilitados en Genesis)
+    pub fn with_supervised(gods: impl IntoIterator<Item = GodName>, trinity: TrinityMembers, load_shedding: LoadShedding) -> Self {
         // Todos los dioses excepto Zeus mismo
-        for god in [
-            GodName::Hades, GodName::Poseidon, GodName::Athena,
-            GodName::Hermes, GodName::Hestia, GodName::Erinyes,
-            GodName::Apollo, GodName::Artemis, GodName::Hera,
-            GodName::Ares, GodName::Hefesto, GodName::Chronos,
-            GodName::Moirai, GodName::Chaos, GodName::Aurora,
-            GodName::Aphrodite, GodName::Iris, GodName::Demeter,
-            GodName::Dionysus,
-        ] {
-            supervised.insert(god, true);
-        }
+        let supervised = gods
+            .into_iter()
+            .filter(|god| *god != GodName::Zeus)
+            .map(|god| (god, true))
+            .collect();
 
         Self {
             supervised_actors: supervised,
             restart_count: HashMap::new(),
+            trinity,
+            load_shedding,
+        }
+    }
+
+    /// Lectura de memoria de Demeter: Zeus decide si entra o sale de la descarga de carga
+    fn handle_resource_pressure(&self, memory_pct: f64) {
+        match self.load_shedding.observe(memory_pct) {
+            Some(true) => tracing::warn!(
+                "⚡ Zeus: presión crítica de memoria ({:.1}%), se suspende la carga no esencial",
+                memory_pct
+            ),
+            Some(false) => tracing::info!("⚡ Zeus: presión de memoria normalizada ({:.1}%), se reanuda la carga no esencial", memory_pct),
+            None => {}
         }
     }
 
@@ -42,6 +53,9 @@ impl Zeus {
                 *count += 1;
                 tracing::warn!("⚡ Zeus: {:?} reportado como no saludable (reinicios: {})", from, *count);
                 
+                if self.trinity.contains(from) {
+                    tracing::error!("🔱 Zeus: {:?} pertenece a la Trinidad: Olimpo en estado crítico", from);
+                }
                 if *count > 5 {
                     tracing::error!("🔥 Zeus: {:?} ha fallado demasiadas veces, escalando...", from);
                 }
@@ -51,6 +65,15 @@ impl Zeus {
         }
     }
 
+    /// Miembros de la Trinidad reportados como no saludables: cualquiera basta para el estado crítico
+    fn trinity_unhealthy(&self) -> Vec<GodName> {
+        self.trinity
+            .members()
+            .into_iter()
+            .filter(|god| self.supervised_actors.get(god) == Some(&false))
+            .collect()
+    }
+
     async fn get_supervision_status(&self) -> serde_json::Value {
         let healthy: Vec<_> = self.supervised_actors
             .iter()
@@ -64,12 +87,18 @@ impl Zeus {
             .map(|(n, _)| n.as_str())
             .collect();
 
+        let trinity_unhealthy: Vec<_> = self.trinity_unhealthy().iter().map(|god| god.as_str()).collect();
+
         serde_json::json!({
+            "trinity_members": self.trinity.members().iter().map(|god| god.as_str()).collect::<Vec<_>>(),
+            "trinity_unhealthy": trinity_unhealthy,
+            "trinity_critical": !trinity_unhealthy.is_empty(),
             "total": self.supervised_actors.len(),
             "healthy": healthy.len(),
             "unhealthy": unhealthy.len(),
             "healthy_list": healthy,
             "unhealthy_list": unhealthy,
+            "load_shedding": self.load_shedding.status(),
         })
     }
 }
@@ -93,6 +122,10 @@ impl OlympianActor for Zeus {
                     if let Ok(healthy) = serde_json::from_value::<bool>(data.clone()) {
                         self.handle_supervision(msg.from, healthy).await;
                     }
+                } else if event_type == RESOURCE_PRESSURE_EVENT && msg.from == GodName::Demeter {
+                    if let Some(memory_pct) = data.get("memory_pct").and_then(|v| v.as_f64()) {
+                        self.handle_resource_pressure(memory_pct);
+                    }
                 }
                 None
             }
@@ -142,3 +175,51 @@ impl OlympianActor for Zeus {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_trinity_member_failure_is_critical() {
+        let trinity = TrinityMembers::new();
+        let mut zeus = Zeus::with_supervised(GodName::ALL, trinity.clone(), LoadShedding::default());
+
+        zeus.handle_supervision(GodName::Hestia, false).await;
+        assert_eq!(zeus.get_supervision_status().await["trinity_critical"], false);
+
+        trinity
+            .set(&[GodName::Zeus, GodName::Hades, GodName::Poseidon, GodName::Hestia], |_| true)
+            .unwrap();
+        let status = zeus.get_supervision_status().await;
+        assert_eq!(status["trinity_critical"], true);
+        assert_eq!(status["trinity_unhealthy"], serde_json::json!(["Hestia"]));
+    }
+
+    #[tokio::test]
+    async fn test_demeter_critical_pressure_starts_load_shedding() {
+        let shedding = LoadShedding::new(90.0, std::time::Duration::from_secs(10));
+        let mut zeus = Zeus::with_supervised(GodName::ALL, TrinityMembers::new(), shedding.clone());
+        let report = |from: GodName, memory_pct: f64| {
+            ActorMessage::new(
+                from,
+                GodName::Zeus,
+                MessagePayload::Event {
+                    event_type: RESOURCE_PRESSURE_EVENT.to_string(),
+                    data: serde_json::json!({ "memory_pct": memory_pct }),
+                },
+            )
+        };
+
+        // Solo cuentan las lecturas de Demeter
+        zeus.handle_message(report(GodName::Chaos, 97.0)).await;
+        assert!(!shedding.is_active());
+
+        zeus.handle_message(report(GodName::Demeter, 97.0)).await;
+        assert!(shedding.is_active());
+        assert_eq!(zeus.get_supervision_status().await["load_shedding"]["active"], true);
+
+        zeus.handle_message(report(GodName::Demeter, 60.0)).await;
+        assert!(!shedding.is_active());
+    }
+}
@@ -0,0 +1,187 @@
+// server/src/api.rs
+// Respuestas de la API: éxitos tipados por endpoint y errores con su código HTTP.
+// Ambos comparten la forma `{ "success": bool, ... }`; los errores llevan siempre `error`.
+
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::actors::erinyes::Recovery;
+use crate::auth::AuthError;
+use crate::clinical_alerts::ClinicalAlert;
+use crate::pagination::Paginated;
+
+pub type ApiResult<T> = Result<Json<T>, ApiError>;
+
+/// Error de un handler: `{ "success": false, "error": "...", ...details }`
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub error: String,
+    /// Campos adicionales del cuerpo (`errors` por campo, `god`, `violations`...)
+    pub details: Map<String, Value>,
+}
+
+impl ApiError {
+    pub fn new(status: StatusCode, error: impl Into<String>) -> Self {
+        Self { status, error: error.into(), details: Map::new() }
+    }
+
+    pub fn bad_request(error: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, error)
+    }
+
+    pub fn unauthorized(error: impl Into<String>) -> Self {
+        Self::new(StatusCode::UNAUTHORIZED, error)
+    }
+
+    pub fn not_found(error: impl Into<String>) -> Self {
+        Self::new(StatusCode::NOT_FOUND, error)
+    }
+
+    pub fn conflict(error: impl Into<String>) -> Self {
+        Self::new(StatusCode::CONFLICT, error)
+    }
+
+    pub fn unprocessable(error: impl Into<String>) -> Self {
+        Self::new(StatusCode::UNPROCESSABLE_ENTITY, error)
+    }
+
+    pub fn internal(error: impl Into<String>) -> Self {
+        Self::new(StatusCode::INTERNAL_SERVER_ERROR, error)
+    }
+
+    pub fn service_unavailable(error: impl Into<String>) -> Self {
+        Self::new(StatusCode::SERVICE_UNAVAILABLE, error)
+    }
+
+    /// Agrega un campo al cuerpo del error
+    pub fn with(mut self, key: &str, value: impl Serialize) -> Self {
+        self.details.insert(key.to_string(), json!(value));
+        self
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let mut body = self.details;
+        body.insert("success".to_string(), json!(false));
+        body.insert("error".to_string(), json!(self.error));
+        (self.status, Json(Value::Object(body))).into_response()
+    }
+}
+
+/// Errores de credenciales; las contraseñas débiles detallan cada regla incumplida
+impl From<AuthError> for ApiError {
+    fn from(e: AuthError) -> Self {
+        let status = match e {
+            AuthError::Unauthenticated | AuthError::InvalidCredentials | AuthError::InvalidOtp => StatusCode::UNAUTHORIZED,
+            AuthError::WeakPassword(_) => StatusCode::BAD_REQUEST,
+            AuthError::UserExists => StatusCode::CONFLICT,
+            AuthError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        let error = Self::new(status, e.message());
+        match e {
+            AuthError::WeakPassword(violations) => error.with("violations", violations),
+            _ => error,
+        }
+    }
+}
+
+/// Paciente creado, leído o actualizado
+#[derive(Debug, Serialize)]
+pub struct PatientResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub message: String,
+    pub patient: Value,
+}
+
+/// Página de pacientes; `patients` repite `items` para los clientes anteriores a la paginación
+#[derive(Debug, Serialize)]
+pub struct PatientsPage {
+    #[serde(flatten)]
+    pub page: Paginated<Value>,
+    pub patients: Vec<Value>,
+}
+
+impl From<Paginated<Value>> for PatientsPage {
+    fn from(page: Paginated<Value>) -> Self {
+        Self { patients: page.items.clone(), page }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct MessageResponse {
+    pub success: bool,
+    pub message: String,
+}
+
+impl MessageResponse {
+    pub fn ok(message: impl Into<String>) -> Self {
+        Self { success: true, message: message.into() }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct MergeResponse {
+    pub success: bool,
+    pub target_id: String,
+    pub source_id: String,
+    pub assessments_moved: usize,
+    /// Campos demográficos distintos entre ambos, para resolución manual
+    pub conflicts: Vec<Value>,
+    pub message: String,
+}
+
+/// Alerta clínica creada, reconocida o resuelta
+#[derive(Debug, Serialize)]
+pub struct ClinicalAlertResponse {
+    pub success: bool,
+    pub alert: ClinicalAlert,
+}
+
+/// Recuperación de Erinyes cancelada por el administrador
+#[derive(Debug, Serialize)]
+pub struct RecoveryCancelledResponse {
+    pub success: bool,
+    pub cancelled: Recovery,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PasswordChangedResponse {
+    pub success: bool,
+    pub revoked_sessions: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UserCreatedResponse {
+    pub success: bool,
+    pub username: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_error_body_shares_the_success_shape() {
+        let response = ApiError::unprocessable("Campos obligatorios vacíos: first_name")
+            .with("errors", json!({ "first_name": "Campo obligatorio" }))
+            .into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["success"], false);
+        assert_eq!(body["error"], "Campos obligatorios vacíos: first_name");
+        assert_eq!(body["errors"]["first_name"], "Campo obligatorio");
+
+        let weak = ApiError::from(AuthError::WeakPassword(Vec::new()));
+        assert_eq!(weak.status, StatusCode::BAD_REQUEST);
+        assert!(weak.details.contains_key("violations"));
+    }
+}
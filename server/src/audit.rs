@@ -0,0 +1,89 @@
+// server/src/audit.rs
+// Rastro de auditoría de Nemesis: cada petición que modifica datos (POST, PUT, PATCH,
+// DELETE) deja una entrada con usuario, método, ruta y estado. Se consulta paginado en
+// `/api/nemesis/audit`; vive en memoria y conserva las últimas `MAX_ENTRIES`.
+
+use axum::http::Method;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+pub const MAX_ENTRIES: usize = 10_000;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditEntry {
+    pub id: u64,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// Usuario de la sesión; `None` en peticiones sin token válido (login, calculadoras)
+    pub username: Option<String>,
+    pub method: String,
+    pub path: String,
+    pub status: u16,
+}
+
+#[derive(Debug, Default)]
+struct AuditInner {
+    entries: VecDeque<AuditEntry>,
+    next_id: u64,
+}
+
+#[derive(Clone, Default)]
+pub struct AuditLog {
+    inner: Arc<Mutex<AuditInner>>,
+}
+
+impl AuditLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Las lecturas no se auditan
+    pub fn is_audited(method: &Method) -> bool {
+        matches!(*method, Method::POST | Method::PUT | Method::PATCH | Method::DELETE)
+    }
+
+    pub fn record(&self, username: Option<String>, method: &Method, path: &str, status: u16) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.next_id += 1;
+        let entry = AuditEntry {
+            id: inner.next_id,
+            timestamp: chrono::Utc::now(),
+            username,
+            method: method.to_string(),
+            path: path.to_string(),
+            status,
+        };
+        if inner.entries.len() == MAX_ENTRIES {
+            inner.entries.pop_front();
+        }
+        inner.entries.push_back(entry);
+    }
+
+    /// Entradas como JSON, de la más antigua a la más reciente, para `PageParams::apply`
+    pub fn entries(&self) -> Vec<Value> {
+        let inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.entries.iter().filter_map(|entry| serde_json::to_value(entry).ok()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_the_latest_entries() {
+        let log = AuditLog::new();
+        assert!(AuditLog::is_audited(&Method::PATCH));
+        assert!(!AuditLog::is_audited(&Method::GET));
+
+        for i in 0..MAX_ENTRIES + 2 {
+            log.record(Some("ana".to_string()), &Method::POST, &format!("/api/patients/{}", i), 201);
+        }
+        let entries = log.entries();
+        assert_eq!(entries.len(), MAX_ENTRIES);
+        assert_eq!(entries[0]["id"], 3);
+        assert_eq!(entries[0]["path"], "/api/patients/2");
+        assert_eq!(entries[MAX_ENTRIES - 1]["username"], "ana");
+    }
+}
@@ -0,0 +1,617 @@
+// server/src/auth.rs
+// Credenciales y sesiones: contraseñas con Argon2id (Hades) y tokens revocables
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+use crate::actors::hades;
+use crate::otp::Environment;
+
+/// Longitud mínima de contraseña, configurable con `OLYMPUS_PASSWORD_MIN_LENGTH`
+pub const MIN_LENGTH_ENV: &str = "OLYMPUS_PASSWORD_MIN_LENGTH";
+pub const DEFAULT_MIN_LENGTH: usize = 8;
+/// Clases de caracteres obligatorias separadas por comas (`letter,uppercase,lowercase,digit,symbol`)
+pub const REQUIRED_CLASSES_ENV: &str = "OLYMPUS_PASSWORD_REQUIRED_CLASSES";
+/// `false` permite contraseñas de la lista de contraseñas comunes
+pub const REJECT_COMMON_ENV: &str = "OLYMPUS_PASSWORD_REJECT_COMMON";
+
+/// Contraseñas habituales que se rechazan aunque cumplan el resto de reglas
+/// (la comparación ignora mayúsculas)
+const COMMON_PASSWORDS: [&str; 24] = [
+    "123456", "12345678", "123456789", "1234567890", "111111", "000000",
+    "password", "password1", "password123", "passw0rd", "qwerty", "qwerty123",
+    "abc123", "abc12345", "admin", "admin123", "letmein", "welcome1",
+    "iloveyou", "contraseña", "contrasena1", "olympus", "olympus123", "hospital1",
+];
+
+/// Usuario inicial mientras no exista gestión de usuarios
+pub const DEFAULT_USERNAME: &str = "admin";
+/// Contraseña sembrada solo en desarrollo; debe cambiarse en el primer acceso
+const DEFAULT_PASSWORD: &str = "admin123";
+/// Contraseña del administrador, obligatoria fuera de desarrollo y sujeta a la política
+pub const ADMIN_PASSWORD_ENV: &str = "OLYMPUS_ADMIN_PASSWORD";
+
+/// Códigos OTP erróneos admitidos antes de descartar el login pendiente
+pub const MAX_OTP_ATTEMPTS: u32 = 3;
+
+/// Vigencia de un token de sesión en segundos, configurable con `OLYMPUS_SESSION_TTL_SECS`
+pub const SESSION_TTL_ENV: &str = "OLYMPUS_SESSION_TTL_SECS";
+/// Una guardia de 12 horas
+pub const DEFAULT_SESSION_TTL_SECS: u64 = 12 * 60 * 60;
+
+/// Clave de Hestia con los hashes de contraseña, para que sobrevivan un reinicio
+pub const CREDENTIALS_KEY: &str = "auth:credentials";
+
+pub fn session_ttl_from_env() -> Result<std::time::Duration, String> {
+    match std::env::var(SESSION_TTL_ENV) {
+        Ok(raw) => match raw.trim().parse() {
+            Ok(0) | Err(_) => Err(format!("{} debe ser un entero positivo: '{}'", SESSION_TTL_ENV, raw)),
+            Ok(secs) => Ok(std::time::Duration::from_secs(secs)),
+        },
+        Err(_) => Ok(std::time::Duration::from_secs(DEFAULT_SESSION_TTL_SECS)),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CharClass {
+    Letter,
+    Uppercase,
+    Lowercase,
+    Digit,
+    Symbol,
+}
+
+impl CharClass {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CharClass::Letter => "letter",
+            CharClass::Uppercase => "uppercase",
+            CharClass::Lowercase => "lowercase",
+            CharClass::Digit => "digit",
+            CharClass::Symbol => "symbol",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "letter" => Some(CharClass::Letter),
+            "uppercase" | "upper" => Some(CharClass::Uppercase),
+            "lowercase" | "lower" => Some(CharClass::Lowercase),
+            "digit" | "number" => Some(CharClass::Digit),
+            "symbol" | "special" => Some(CharClass::Symbol),
+            _ => None,
+        }
+    }
+
+    fn matches(&self, c: char) -> bool {
+        match self {
+            CharClass::Letter => c.is_alphabetic(),
+            CharClass::Uppercase => c.is_uppercase(),
+            CharClass::Lowercase => c.is_lowercase(),
+            CharClass::Digit => c.is_ascii_digit(),
+            CharClass::Symbol => !c.is_alphanumeric() && !c.is_whitespace(),
+        }
+    }
+
+    fn requirement(&self) -> &'static str {
+        match self {
+            CharClass::Letter => "La contraseña debe incluir al menos una letra",
+            CharClass::Uppercase => "La contraseña debe incluir al menos una mayúscula",
+            CharClass::Lowercase => "La contraseña debe incluir al menos una minúscula",
+            CharClass::Digit => "La contraseña debe incluir al menos un número",
+            CharClass::Symbol => "La contraseña debe incluir al menos un símbolo",
+        }
+    }
+}
+
+/// Regla incumplida: `rule` es estable para el frontend, `message` es para el usuario
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PolicyViolation {
+    pub rule: &'static str,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub required_classes: Vec<CharClass>,
+    pub reject_common: bool,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: DEFAULT_MIN_LENGTH,
+            required_classes: vec![CharClass::Letter, CharClass::Digit],
+            reject_common: true,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    pub fn from_env() -> Result<Self, String> {
+        let mut policy = Self::default();
+        if let Ok(raw) = std::env::var(MIN_LENGTH_ENV) {
+            policy.min_length = raw
+                .trim()
+                .parse()
+                .map_err(|_| format!("{} debe ser un entero: '{}'", MIN_LENGTH_ENV, raw))?;
+        }
+        if let Ok(raw) = std::env::var(REQUIRED_CLASSES_ENV) {
+            policy.required_classes = raw
+                .split(',')
+                .filter(|name| !name.trim().is_empty())
+                .map(|name| {
+                    CharClass::from_name(name)
+                        .ok_or_else(|| format!("{}: clase de caracteres desconocida '{}'", REQUIRED_CLASSES_ENV, name.trim()))
+                })
+                .collect::<Result<_, _>>()?;
+        }
+        if let Ok(raw) = std::env::var(REJECT_COMMON_ENV) {
+            policy.reject_common = match raw.trim().to_lowercase().as_str() {
+                "true" | "1" | "yes" => true,
+                "false" | "0" | "no" => false,
+                _ => return Err(format!("{} debe ser true o false: '{}'", REJECT_COMMON_ENV, raw)),
+            };
+        }
+        Ok(policy)
+    }
+
+    /// Todas las reglas incumplidas, no solo la primera, para mostrarlas juntas
+    pub fn check(&self, password: &str) -> Result<(), Vec<PolicyViolation>> {
+        let mut violations = Vec::new();
+        if password.chars().count() < self.min_length {
+            violations.push(PolicyViolation {
+                rule: "min_length",
+                message: format!("La contraseña debe tener al menos {} caracteres", self.min_length),
+            });
+        }
+        for class in &self.required_classes {
+            if !password.chars().any(|c| class.matches(c)) {
+                violations.push(PolicyViolation { rule: class.as_str(), message: class.requirement().to_string() });
+            }
+        }
+        if self.reject_common && COMMON_PASSWORDS.contains(&password.to_lowercase().as_str()) {
+            violations.push(PolicyViolation {
+                rule: "common_password",
+                message: "La contraseña es demasiado común".to_string(),
+            });
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthError {
+    /// Token ausente, desconocido o revocado
+    Unauthenticated,
+    InvalidCredentials,
+    /// Código OTP incorrecto para un login pendiente
+    InvalidOtp,
+    /// La contraseña incumple la política; una entrada por regla
+    WeakPassword(Vec<PolicyViolation>),
+    UserExists,
+    Internal(String),
+}
+
+impl AuthError {
+    pub fn message(&self) -> String {
+        match self {
+            AuthError::Unauthenticated => "Sesión no válida o expirada".to_string(),
+            AuthError::InvalidCredentials => "Credenciales inválidas".to_string(),
+            AuthError::InvalidOtp => "Código OTP inválido".to_string(),
+            AuthError::WeakPassword(violations) => {
+                violations.iter().map(|v| v.message.as_str()).collect::<Vec<_>>().join("; ")
+            }
+            AuthError::UserExists => "El usuario ya existe".to_string(),
+            AuthError::Internal(e) => format!("Error interno: {}", e),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Session {
+    pub username: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Login con la contraseña verificada, a la espera del código OTP
+struct PendingLogin {
+    username: String,
+    otp_code: String,
+    failed_attempts: u32,
+}
+
+/// Credencial tal como se guarda en Hestia (nunca la contraseña, solo su hash)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredCredential {
+    password_hash: String,
+    #[serde(default)]
+    must_change_password: bool,
+}
+
+#[derive(Default)]
+struct AuthState {
+    /// usuario → hash Argon2id
+    password_hashes: HashMap<String, String>,
+    /// session_id del paso 1 (contraseña correcta, OTP pendiente) → login pendiente
+    pending_logins: HashMap<String, PendingLogin>,
+    /// token → sesión
+    sessions: HashMap<String, Session>,
+    /// Usuarios con la contraseña por defecto: sin privilegios hasta cambiarla
+    must_change_password: HashSet<String>,
+}
+
+#[derive(Clone)]
+pub struct AuthStore {
+    state: Arc<RwLock<AuthState>>,
+    policy: PasswordPolicy,
+    session_ttl: std::time::Duration,
+}
+
+impl AuthStore {
+    pub fn new(policy: PasswordPolicy) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(AuthState::default())),
+            policy,
+            session_ttl: std::time::Duration::from_secs(DEFAULT_SESSION_TTL_SECS),
+        }
+    }
+
+    /// Almacén con el administrador de `OLYMPUS_ADMIN_PASSWORD` y la vigencia de sesión
+    /// de `OLYMPUS_SESSION_TTL_SECS`
+    pub fn from_env(policy: PasswordPolicy, environment: Environment) -> Result<Self, String> {
+        let store = Self::with_admin(policy, environment, std::env::var(ADMIN_PASSWORD_ENV).ok().as_deref())?;
+        Ok(store.with_session_ttl(session_ttl_from_env()?))
+    }
+
+    pub fn with_session_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.session_ttl = ttl;
+        self
+    }
+
+    pub fn session_ttl(&self) -> std::time::Duration {
+        self.session_ttl
+    }
+
+    /// Hashes guardados en Hestia (`credentials()` de un arranque anterior). Prevalecen sobre
+    /// los sembrados: una contraseña cambiada por la API no vuelve a la de la configuración
+    pub fn restore(&self, credentials: &serde_json::Value) -> Result<usize, String> {
+        let credentials: HashMap<String, StoredCredential> = match credentials {
+            serde_json::Value::Null => return Ok(0),
+            stored => serde_json::from_value(stored.clone()).map_err(|e| format!("{}: {}", CREDENTIALS_KEY, e))?,
+        };
+        let mut state = self.write();
+        for (username, credential) in &credentials {
+            state.password_hashes.insert(username.clone(), credential.password_hash.clone());
+            if credential.must_change_password {
+                state.must_change_password.insert(username.clone());
+            } else {
+                state.must_change_password.remove(username);
+            }
+        }
+        Ok(credentials.len())
+    }
+
+    /// Instantánea de los hashes para guardarla en Hestia bajo `CREDENTIALS_KEY`
+    pub fn credentials(&self) -> serde_json::Value {
+        let state = self.read();
+        let credentials: HashMap<&String, StoredCredential> = state
+            .password_hashes
+            .iter()
+            .map(|(username, hash)| {
+                let must_change_password = state.must_change_password.contains(username);
+                (username, StoredCredential { password_hash: hash.clone(), must_change_password })
+            })
+            .collect();
+        serde_json::to_value(credentials).unwrap_or_default()
+    }
+
+    /// La contraseña configurada pasa por la política como cualquier otra. Sin ella solo
+    /// desarrollo arranca, con la credencial por defecto marcada para cambiarse
+    pub fn with_admin(policy: PasswordPolicy, environment: Environment, password: Option<&str>) -> Result<Self, String> {
+        let Some(password) = password.filter(|p| !p.is_empty()) else {
+            if environment == Environment::Development {
+                return Self::with_default_admin(policy);
+            }
+            return Err(format!(
+                "{} es obligatorio fuera de desarrollo: no se admite la credencial por defecto",
+                ADMIN_PASSWORD_ENV
+            ));
+        };
+        if password == DEFAULT_PASSWORD && environment != Environment::Development {
+            return Err(format!("{}: la credencial por defecto solo se admite en desarrollo", ADMIN_PASSWORD_ENV));
+        }
+        policy
+            .check(password)
+            .map_err(|violations| format!("{}: {}", ADMIN_PASSWORD_ENV, AuthError::WeakPassword(violations).message()))?;
+
+        let store = Self::new(policy);
+        store.set_password(DEFAULT_USERNAME, password)?;
+        Ok(store)
+    }
+
+    /// Almacén con la credencial por defecto (desarrollo y tests), pendiente de cambio
+    pub fn with_default_admin(policy: PasswordPolicy) -> Result<Self, String> {
+        let store = Self::new(policy);
+        store.set_password(DEFAULT_USERNAME, DEFAULT_PASSWORD)?;
+        store.write().must_change_password.insert(DEFAULT_USERNAME.to_string());
+        Ok(store)
+    }
+
+    pub fn policy(&self) -> &PasswordPolicy {
+        &self.policy
+    }
+
+    /// Alta de usuario; la contraseña debe cumplir la política del despliegue
+    pub fn create_user(&self, username: &str, password: &str) -> Result<(), AuthError> {
+        if self.read().password_hashes.contains_key(username) {
+            return Err(AuthError::UserExists);
+        }
+        self.policy.check(password).map_err(AuthError::WeakPassword)?;
+
+        let hash = hades::hash_password(password).map_err(AuthError::Internal)?;
+        let mut state = self.write();
+        if state.password_hashes.contains_key(username) {
+            return Err(AuthError::UserExists);
+        }
+        state.password_hashes.insert(username.to_string(), hash);
+        Ok(())
+    }
+
+    fn set_password(&self, username: &str, password: &str) -> Result<(), String> {
+        let hash = hades::hash_password(password)?;
+        self.write().password_hashes.insert(username.to_string(), hash);
+        Ok(())
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, AuthState> {
+        self.state.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, AuthState> {
+        self.state.write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn verify(&self, username: &str, password: &str) -> bool {
+        // El hash se copia para no verificar Argon2 con el lock tomado
+        let hash = self.read().password_hashes.get(username).cloned();
+        hash.is_some_and(|hash| hades::verify_password(password, &hash))
+    }
+
+    /// Paso 1 superado: devuelve el session_id con el que se canjea `otp_code`
+    pub fn begin_login(&self, username: &str, otp_code: &str) -> String {
+        let session_id = uuid::Uuid::new_v4().to_string();
+        let pending = PendingLogin { username: username.to_string(), otp_code: otp_code.to_string(), failed_attempts: 0 };
+        self.write().pending_logins.insert(session_id.clone(), pending);
+        session_id
+    }
+
+    /// Descarta un login pendiente (p. ej. si Iris no pudo entregar el código)
+    pub fn abort_login(&self, session_id: &str) {
+        self.write().pending_logins.remove(session_id);
+    }
+
+    /// Paso 2: con el código correcto consume el session_id y emite un token de sesión.
+    /// Tras `MAX_OTP_ATTEMPTS` códigos erróneos el login pendiente se descarta.
+    pub fn complete_login(&self, session_id: &str, otp_code: &str) -> Result<(String, String), AuthError> {
+        let mut state = self.write();
+        let pending = state.pending_logins.get_mut(session_id).ok_or(AuthError::Unauthenticated)?;
+        if pending.otp_code != otp_code.trim() {
+            pending.failed_attempts += 1;
+            if pending.failed_attempts >= MAX_OTP_ATTEMPTS {
+                state.pending_logins.remove(session_id);
+            }
+            return Err(AuthError::InvalidOtp);
+        }
+
+        let username = state.pending_logins.remove(session_id).map(|p| p.username).unwrap_or_default();
+        let token = uuid::Uuid::new_v4().simple().to_string();
+        state.sessions.insert(token.clone(), Session { username: username.clone(), created_at: chrono::Utc::now() });
+        Ok((username, token))
+    }
+
+    /// Sesión vigente del token; pasada `session_ttl` desde el login se descarta
+    pub fn session(&self, token: &str) -> Option<Session> {
+        let session = self.read().sessions.get(token).cloned()?;
+        let age = (chrono::Utc::now() - session.created_at).to_std().unwrap_or_default();
+        if age >= self.session_ttl {
+            self.write().sessions.remove(token);
+            return None;
+        }
+        Some(session)
+    }
+
+    /// El usuario entra con la contraseña por defecto y aún no la ha cambiado
+    pub fn must_change_password(&self, username: &str) -> bool {
+        self.read().must_change_password.contains(username)
+    }
+
+    /// Sesión del administrador; las demás sesiones no acceden a `/api/admin/*` restringido,
+    /// ni la suya mientras conserve la contraseña por defecto
+    pub fn admin_session(&self, token: &str) -> Option<Session> {
+        self.session(token)
+            .filter(|session| session.username == DEFAULT_USERNAME && !self.must_change_password(&session.username))
+    }
+
+    pub fn logout(&self, token: &str) -> bool {
+        self.write().sessions.remove(token).is_some()
+    }
+
+    /// Cambia la contraseña del usuario del token y cierra el resto de sus sesiones.
+    /// Devuelve cuántas sesiones se revocaron.
+    pub fn change_password(&self, token: &str, current: &str, new: &str) -> Result<(String, usize), AuthError> {
+        let username = self.session(token).ok_or(AuthError::Unauthenticated)?.username;
+        if !self.verify(&username, current) {
+            return Err(AuthError::InvalidCredentials);
+        }
+        self.policy.check(new).map_err(AuthError::WeakPassword)?;
+
+        let hash = hades::hash_password(new).map_err(AuthError::Internal)?;
+        let mut state = self.write();
+        state.password_hashes.insert(username.clone(), hash);
+        state.must_change_password.remove(&username);
+
+        let before = state.sessions.len();
+        state.sessions.retain(|t, session| t == token || session.username != username);
+        let revoked = before - state.sessions.len();
+        state.pending_logins.retain(|_, pending| pending.username != username);
+        Ok((username, revoked))
+    }
+}
+
+/// Token de `Authorization: Bearer <token>`
+pub fn bearer_token(headers: &axum::http::HeaderMap) -> Option<&str> {
+    headers
+        .get(axum::http::header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn login(store: &AuthStore, username: &str, password: &str) -> Option<String> {
+        if !store.verify(username, password) {
+            return None;
+        }
+        let session_id = store.begin_login(username, "482913");
+        store.complete_login(&session_id, "482913").ok().map(|(_, token)| token)
+    }
+
+    #[test]
+    fn test_wrong_otp_codes_discard_the_pending_login() {
+        let store = AuthStore::with_default_admin(PasswordPolicy::default()).unwrap();
+        let session_id = store.begin_login("admin", "482913");
+        for _ in 1..MAX_OTP_ATTEMPTS {
+            assert_eq!(store.complete_login(&session_id, "000000"), Err(AuthError::InvalidOtp));
+        }
+        assert_eq!(store.complete_login(&session_id, "482913").unwrap().0, "admin");
+
+        let session_id = store.begin_login("admin", "482913");
+        for _ in 0..MAX_OTP_ATTEMPTS {
+            assert_eq!(store.complete_login(&session_id, "000000"), Err(AuthError::InvalidOtp));
+        }
+        assert_eq!(store.complete_login(&session_id, "482913"), Err(AuthError::Unauthenticated));
+    }
+
+    #[test]
+    fn test_change_password_revokes_other_sessions() {
+        let store = AuthStore::with_default_admin(PasswordPolicy::default()).unwrap();
+        let laptop = login(&store, "admin", "admin123").unwrap();
+        let workstation = login(&store, "admin", "admin123").unwrap();
+
+        assert_eq!(store.change_password(&laptop, "wrong", "Nueva2026x"), Err(AuthError::InvalidCredentials));
+        assert!(matches!(store.change_password(&laptop, "admin123", "corta1"), Err(AuthError::WeakPassword(_))));
+        assert_eq!(store.change_password("desconocido", "admin123", "Nueva2026x"), Err(AuthError::Unauthenticated));
+
+        let (username, revoked) = store.change_password(&laptop, "admin123", "Nueva2026x").unwrap();
+        assert_eq!((username.as_str(), revoked), ("admin", 1));
+        assert!(store.session(&laptop).is_some());
+        assert!(store.session(&workstation).is_none());
+
+        assert!(login(&store, "admin", "admin123").is_none());
+        assert!(login(&store, "admin", "Nueva2026x").is_some());
+    }
+
+    #[test]
+    fn test_changed_credentials_survive_a_restart() {
+        let store = AuthStore::with_default_admin(PasswordPolicy::default()).unwrap();
+        let token = login(&store, "admin", "admin123").unwrap();
+        store.change_password(&token, "admin123", "Nueva2026x").unwrap();
+        store.create_user("ana", "Inicial#Guardia-2026").unwrap();
+        let saved = store.credentials();
+        assert!(!saved.to_string().contains("Nueva2026x"));
+
+        // El nuevo proceso siembra la credencial por defecto, pero gana la guardada
+        let restarted = AuthStore::with_default_admin(PasswordPolicy::default()).unwrap();
+        assert_eq!(restarted.restore(&saved), Ok(2));
+        assert!(login(&restarted, "admin", "admin123").is_none());
+        assert!(login(&restarted, "admin", "Nueva2026x").is_some());
+        assert!(!restarted.must_change_password("admin"));
+        assert!(login(&restarted, "ana", "Inicial#Guardia-2026").is_some());
+        assert_eq!(restarted.restore(&serde_json::Value::Null), Ok(0));
+    }
+
+    #[test]
+    fn test_sessions_expire_after_their_ttl() {
+        let store = AuthStore::with_default_admin(PasswordPolicy::default()).unwrap();
+        let token = login(&store, "admin", "admin123").unwrap();
+        assert!(store.session(&token).is_some());
+
+        let expired = store.clone().with_session_ttl(std::time::Duration::ZERO);
+        assert!(expired.session(&token).is_none());
+        // Caducada, la sesión se descarta también para el almacén compartido
+        assert!(store.session(&token).is_none());
+    }
+
+    #[test]
+    fn test_admin_credential_follows_the_policy_outside_development() {
+        let strict = PasswordPolicy { min_length: 12, ..PasswordPolicy::default() };
+        assert!(AuthStore::with_admin(strict.clone(), Environment::Production, None).is_err());
+        assert!(AuthStore::with_admin(strict.clone(), Environment::Staging, Some("admin123")).is_err());
+        let short = AuthStore::with_admin(strict.clone(), Environment::Production, Some("abc12345")).err().unwrap();
+        assert!(short.contains("al menos 12 caracteres"));
+
+        let store = AuthStore::with_admin(strict.clone(), Environment::Production, Some("Guardia-2026x")).unwrap();
+        assert!(!store.must_change_password("admin"));
+        let token = login(&store, "admin", "Guardia-2026x").unwrap();
+        assert!(store.admin_session(&token).is_some());
+
+        // En desarrollo se siembra admin123, sin privilegios hasta cambiarla
+        let store = AuthStore::with_admin(strict, Environment::Development, None).unwrap();
+        assert!(store.must_change_password("admin"));
+        let token = login(&store, "admin", "admin123").unwrap();
+        assert!(store.admin_session(&token).is_none());
+        store.change_password(&token, "admin123", "Guardia-2026x").unwrap();
+        assert!(!store.must_change_password("admin"));
+        assert!(store.admin_session(&token).is_some());
+    }
+
+    #[test]
+    fn test_policy_min_length() {
+        let policy = PasswordPolicy { min_length: 12, ..PasswordPolicy::default() };
+        assert!(policy.check("abc12345").is_err());
+        assert!(policy.check("abcdefgh1234").is_ok());
+        assert!(policy.check("abcdefghijkl").is_err());
+    }
+
+    #[test]
+    fn test_policy_reports_each_violated_rule() {
+        let policy = PasswordPolicy {
+            min_length: 12,
+            required_classes: vec![CharClass::Uppercase, CharClass::Digit, CharClass::Symbol],
+            reject_common: true,
+        };
+        let rules = |password: &str| -> Vec<&str> {
+            policy.check(password).err().unwrap_or_default().iter().map(|v| v.rule).collect()
+        };
+
+        assert_eq!(rules("Abcdef1!"), vec!["min_length"]);
+        assert_eq!(rules("abcdefghijkl"), vec!["uppercase", "digit", "symbol"]);
+        assert_eq!(rules("Password123!"), Vec::<&str>::new());
+        assert_eq!(rules("PASSWORD123"), vec!["min_length", "symbol", "common_password"]);
+        assert!(policy.check("Abcdef1!").unwrap_err()[0].message.contains("12 caracteres"));
+    }
+
+    #[test]
+    fn test_create_user_applies_policy() {
+        let store = AuthStore::with_default_admin(PasswordPolicy::default()).unwrap();
+        assert!(matches!(store.create_user("enfermeria", "password1"), Err(AuthError::WeakPassword(_))));
+        assert_eq!(store.create_user("admin", "Segura2026x"), Err(AuthError::UserExists));
+
+        store.create_user("enfermeria", "Segura2026x").unwrap();
+        assert!(login(&store, "enfermeria", "Segura2026x").is_some());
+    }
+}
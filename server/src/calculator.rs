@@ -0,0 +1,168 @@
+// server/src/calculator.rs
+// Modo calculadora (OLYMPUS_MODE=calculator): solo las escalas, sin Genesis ni persistencia
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{any, get, post},
+    Json, Router,
+};
+use serde_json::json;
+
+use olympus_core::apache::ApacheIIRequest;
+use olympus_core::saps::SAPSIIRequest;
+
+use crate::api::ApiError;
+use crate::i18n::Lang;
+use crate::{critical_labs, scales, GlasgowRequest, News2Request, SofaRequest};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunMode {
+    /// Sistema completo de actores
+    Full,
+    /// Solo cálculo de escalas
+    Calculator,
+}
+
+impl RunMode {
+    /// Lee `OLYMPUS_MODE` (`full` por defecto o `calculator`)
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var("OLYMPUS_MODE") {
+            Ok(mode) => Self::parse(&mode),
+            Err(_) => Ok(RunMode::Full),
+        }
+    }
+
+    fn parse(mode: &str) -> Result<Self, String> {
+        match mode.trim().to_ascii_lowercase().as_str() {
+            "" | "full" => Ok(RunMode::Full),
+            "calculator" => Ok(RunMode::Calculator),
+            other => Err(format!("OLYMPUS_MODE desconocido: '{}' (full o calculator)", other)),
+        }
+    }
+}
+
+/// Rutas del modo calculadora: calculan sin validar pacientes ni guardar evaluaciones
+pub fn router() -> Router {
+    Router::new()
+        .route("/api/scales/glasgow", post(glasgow))
+        .route("/api/scales/sofa", post(sofa))
+        .route("/api/scales/news2", post(news2))
+        .route("/api/scales/apache", post(apache))
+        .route("/api/scales/saps", post(saps))
+        .route("/api/scales/news2/chart-thresholds", get(crate::news2_chart_thresholds))
+        .route("/api/scales/:scale/bands", get(crate::scale_bands))
+        .route("/api/scales/compare", post(crate::compare_scales))
+        .route("/api/version", get(crate::api_version))
+        .route("/api/olympus", any(gods_not_deployed))
+        .route("/api/olympus/*rest", any(gods_not_deployed))
+}
+
+async fn glasgow(lang: Lang, Json(req): Json<GlasgowRequest>) -> Json<serde_json::Value> {
+    Json(scales::glasgow_result(&req, lang))
+}
+
+async fn sofa(Json(req): Json<SofaRequest>) -> Json<serde_json::Value> {
+    Json(scales::sofa_result(&req))
+}
+
+async fn news2(lang: Lang, Json(req): Json<News2Request>) -> Response {
+    if let Err(errors) = req.vitals.validate() {
+        return (StatusCode::UNPROCESSABLE_ENTITY, Json(scales::invalid_vitals_body(&errors))).into_response();
+    }
+    Json(scales::news2_result(&req, lang)).into_response()
+}
+
+async fn apache(Json(req): Json<ApacheIIRequest>) -> Response {
+    with_critical_labs(scales::apache_result(&req), &json!(req), "APACHE II")
+}
+
+async fn saps(Json(req): Json<SAPSIIRequest>) -> Response {
+    with_critical_labs(scales::saps_result(&req), &json!(req), "SAPS II")
+}
+
+/// Mismo resultado (y mismo 422) que en el modo completo, donde los calcula Athena
+fn with_critical_labs(result: Result<serde_json::Value, String>, input: &serde_json::Value, scale: &str) -> Response {
+    match result {
+        Ok(mut result) => {
+            critical_labs::flag(&mut result, input);
+            Json(result).into_response()
+        }
+        Err(error) => ApiError::unprocessable(format!("{} no válido: {}", scale, error)).into_response(),
+    }
+}
+
+async fn gods_not_deployed() -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        Json(json!({
+            "success": false,
+            "error": "Modo calculadora: el sistema de dioses no está desplegado",
+        })),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request};
+    use tower::ServiceExt;
+
+    async fn call(request: Request<Body>) -> (StatusCode, serde_json::Value) {
+        let response = router().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_calculator_serves_scales_only() {
+        let request = Request::post("/api/scales/glasgow")
+            .header("content-type", "application/json")
+            .body(Body::from(r#"{"eye": 3, "verbal": 4, "motor": 5}"#))
+            .unwrap();
+        let (status, body) = call(request).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["total"], 12);
+
+        let (status, body) = call(Request::get("/api/olympus/gods").body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["success"], false);
+
+        let apache = json!({
+            "temperature": 39.0, "mean_arterial_pressure": 65, "heart_rate": 120, "respiratory_rate": 28,
+            "oxygenation_type": "pao2", "oxygenation_value": 65, "arterial_ph": 7.30, "serum_sodium": 135,
+            "serum_potassium": 7.0, "serum_creatinine": 2.0, "hematocrit": 35.0, "white_blood_count": 15.0,
+            "glasgow_coma_score": 12, "age": 70, "chronic_health": "none"
+        });
+        let request = |uri: &str, body: &serde_json::Value| {
+            Request::post(uri).header("content-type", "application/json").body(Body::from(body.to_string())).unwrap()
+        };
+        let (status, body) = call(request("/api/scales/apache", &apache)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["scale"], "APACHE II");
+        assert_eq!(body["total"], body["score"]);
+        assert_eq!(body["critical_lab"], true);
+
+        let saps = json!({
+            "age": 70, "heart_rate": 120, "systolic_bp": 90, "temperature": 39.0, "urinary_output": 0.8,
+            "serum_urea": 40.0, "white_blood_count": 15.0, "serum_potassium": 4.0, "serum_sodium": 135,
+            "serum_bicarbonate": 18.0, "bilirubin": 1.0, "glasgow": 12, "chronic_disease": "none",
+            "admission_type": "medical"
+        });
+        let (status, body) = call(request("/api/scales/saps", &saps)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["scale"], "SAPS II");
+
+        let mut bogus = saps.clone();
+        bogus["admission_type"] = json!("bogus");
+        let (status, body) = call(request("/api/scales/saps", &bogus)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["success"], false);
+
+        assert_eq!(RunMode::parse("Calculator"), Ok(RunMode::Calculator));
+        assert_eq!(RunMode::parse(""), Ok(RunMode::Full));
+        assert!(RunMode::parse("edge").is_err());
+    }
+}
@@ -0,0 +1,272 @@
+// server/src/clinical_alerts.rs
+// Alertas clínicas (deterioro del paciente), separadas de las alertas de sistema de Erinyes.
+// Se guardan en Hestia bajo `clinical_alerts` para sobrevivir reinicios.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::actors::HestiaStore;
+use crate::critical_labs::CriticalLabFinding;
+
+pub const STORE_KEY: &str = "clinical_alerts";
+
+/// Caída de Glasgow (puntos) respecto a la evaluación anterior que dispara una alerta
+pub const GLASGOW_DROP_THRESHOLD: i64 = 2;
+/// NEWS2 a partir del cual se requiere revisión urgente
+pub const NEWS2_ALERT_THRESHOLD: i64 = 5;
+pub const NEWS2_EMERGENCY_THRESHOLD: i64 = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertCategory {
+    GlasgowDrop,
+    HighNews2,
+    CriticalLab,
+    /// Recordatorio de Chronos: NEWS2 sin repetir dentro de la cadencia de su riesgo
+    OverdueReassessment,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertSeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClinicalAlert {
+    pub id: String,
+    pub patient_id: String,
+    pub category: AlertCategory,
+    pub severity: AlertSeverity,
+    pub message: String,
+    pub created_at: DateTime<Utc>,
+    pub acknowledged: bool,
+    #[serde(default)]
+    pub acknowledged_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub resolved: bool,
+    #[serde(default)]
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+/// Alerta creada manualmente (p. ej. un laboratorio crítico) o por una escala
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewClinicalAlert {
+    pub patient_id: String,
+    pub category: AlertCategory,
+    pub severity: AlertSeverity,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AlertFilter {
+    pub patient_id: Option<String>,
+    /// Por defecto solo se listan las alertas sin resolver
+    #[serde(default)]
+    pub include_resolved: bool,
+}
+
+fn load(value: &Value) -> Vec<ClinicalAlert> {
+    serde_json::from_value(value.clone()).unwrap_or_default()
+}
+
+/// Alertas que cumplen el filtro, de la más reciente a la más antigua
+pub fn list(store: &HestiaStore, filter: &AlertFilter) -> Vec<ClinicalAlert> {
+    let mut alerts: Vec<ClinicalAlert> = store
+        .get(STORE_KEY)
+        .map(|value| load(&value))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|a| filter.patient_id.as_deref().is_none_or(|id| a.patient_id == id))
+        .filter(|a| filter.include_resolved || !a.resolved)
+        .collect();
+    alerts.sort_by_key(|a| std::cmp::Reverse(a.created_at));
+    alerts
+}
+
+/// Campos obligatorios de una alerta nueva
+pub fn validate(new: &NewClinicalAlert) -> Result<(), String> {
+    if new.patient_id.trim().is_empty() {
+        return Err("'patient_id' es obligatorio".to_string());
+    }
+    if new.message.trim().is_empty() {
+        return Err("'message' es obligatorio".to_string());
+    }
+    Ok(())
+}
+
+pub async fn raise(store: &HestiaStore, new: NewClinicalAlert) -> Result<ClinicalAlert, String> {
+    validate(&new)?;
+
+    let alert = ClinicalAlert {
+        id: uuid::Uuid::new_v4().to_string(),
+        patient_id: new.patient_id,
+        category: new.category,
+        severity: new.severity,
+        message: new.message,
+        created_at: Utc::now(),
+        acknowledged: false,
+        acknowledged_at: None,
+        resolved: false,
+        resolved_at: None,
+    };
+
+    let stored = serde_json::to_value(&alert).map_err(|e| e.to_string())?;
+    store.update(STORE_KEY, |current| match current {
+        Value::Array(alerts) => alerts.push(stored),
+        other => *other = Value::Array(vec![stored]),
+    }).await?;
+    Ok(alert)
+}
+
+/// Aplica `change` a la alerta `id`; `Ok(None)` si no existe
+async fn transition(store: &HestiaStore, id: &str, change: impl FnOnce(&mut ClinicalAlert)) -> Result<Option<ClinicalAlert>, String> {
+    store.update(STORE_KEY, |current| {
+        let mut alerts = load(current);
+        let alert = alerts.iter_mut().find(|a| a.id == id)?;
+        change(alert);
+        let updated = alert.clone();
+        *current = serde_json::to_value(&alerts).unwrap_or(Value::Array(Vec::new()));
+        Some(updated)
+    }).await
+}
+
+pub async fn acknowledge(store: &HestiaStore, id: &str) -> Result<Option<ClinicalAlert>, String> {
+    transition(store, id, |alert| {
+        if !alert.acknowledged {
+            alert.acknowledged = true;
+            alert.acknowledged_at = Some(Utc::now());
+        }
+    })
+    .await
+}
+
+/// Resolver implica reconocer: una alerta resuelta nunca queda pendiente de acuse
+pub async fn resolve(store: &HestiaStore, id: &str) -> Result<Option<ClinicalAlert>, String> {
+    transition(store, id, |alert| {
+        let now = Utc::now();
+        if !alert.acknowledged {
+            alert.acknowledged = true;
+            alert.acknowledged_at = Some(now);
+        }
+        if !alert.resolved {
+            alert.resolved = true;
+            alert.resolved_at = Some(now);
+        }
+    })
+    .await
+}
+
+/// Alertas que genera una evaluación recién guardada. `previous_glasgow` es el total
+/// de la evaluación Glasgow anterior del mismo paciente, si la hay.
+/// Los laboratorios críticos (`critical_labs`) alertan con cualquier puntaje.
+pub fn from_assessment(result: &Value, previous_glasgow: Option<i64>) -> Vec<NewClinicalAlert> {
+    let patient_id = result["patient_id"].as_str().unwrap_or_default().to_string();
+    let findings: Vec<CriticalLabFinding> = serde_json::from_value(result["critical_labs"].clone()).unwrap_or_default();
+    let mut alerts: Vec<NewClinicalAlert> = findings
+        .iter()
+        .map(|finding| NewClinicalAlert {
+            patient_id: patient_id.clone(),
+            category: AlertCategory::CriticalLab,
+            severity: AlertSeverity::Critical,
+            message: format!("Laboratorio crítico en {}: {}", result["scale"].as_str().unwrap_or("evaluación"), finding.describe()),
+        })
+        .collect();
+    let Some(total) = result["total"].as_i64() else {
+        return alerts;
+    };
+
+    alerts.extend(match result["scale"].as_str() {
+        Some("Glasgow") => previous_glasgow
+            .filter(|previous| previous - total >= GLASGOW_DROP_THRESHOLD)
+            .map(|previous| NewClinicalAlert {
+                patient_id,
+                category: AlertCategory::GlasgowDrop,
+                severity: if total <= 8 { AlertSeverity::Critical } else { AlertSeverity::High },
+                message: format!("Glasgow descendió de {} a {}", previous, total),
+            })
+            .into_iter()
+            .collect(),
+        Some("NEWS2") if total >= NEWS2_ALERT_THRESHOLD => vec![NewClinicalAlert {
+            patient_id,
+            category: AlertCategory::HighNews2,
+            severity: if total >= NEWS2_EMERGENCY_THRESHOLD { AlertSeverity::Critical } else { AlertSeverity::High },
+            message: format!("NEWS2 de {}: requiere valoración clínica urgente", total),
+        }],
+        _ => Vec::new(),
+    });
+    alerts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_alerts_from_assessments() {
+        let glasgow = json!({ "scale": "Glasgow", "patient_id": "p1", "total": 9 });
+        let alerts = from_assessment(&glasgow, Some(14));
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].category, AlertCategory::GlasgowDrop);
+        assert_eq!(alerts[0].severity, AlertSeverity::High);
+        assert!(from_assessment(&glasgow, Some(10)).is_empty());
+        assert!(from_assessment(&glasgow, None).is_empty());
+
+        let news2 = json!({ "scale": "NEWS2", "patient_id": "p1", "total": 7 });
+        assert_eq!(from_assessment(&news2, None)[0].severity, AlertSeverity::Critical);
+        assert!(from_assessment(&json!({ "scale": "NEWS2", "patient_id": "p1", "total": 2 }), None).is_empty());
+
+        // Un laboratorio crítico alerta aunque el APACHE sea moderado
+        let apache = json!({
+            "scale": "APACHE II", "patient_id": "p1", "total": 12,
+            "critical_labs": [{ "lab": "serum_potassium", "value": 7.0, "threshold": 6.5, "direction": "high" }],
+        });
+        let alerts = from_assessment(&apache, None);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].category, AlertCategory::CriticalLab);
+        assert_eq!(alerts[0].severity, AlertSeverity::Critical);
+    }
+
+    #[tokio::test]
+    async fn test_acknowledge_persists_across_reopen() {
+        let path = std::env::temp_dir().join(format!("clinical-alerts-{}.json", uuid::Uuid::new_v4()));
+        let store = HestiaStore::open(&path);
+
+        let lab = raise(&store, NewClinicalAlert {
+            patient_id: "p1".to_string(),
+            category: AlertCategory::CriticalLab,
+            severity: AlertSeverity::Critical,
+            message: "Potasio 6.8 mmol/L".to_string(),
+        })
+        .await
+        .unwrap();
+        raise(&store, NewClinicalAlert {
+            patient_id: "p2".to_string(),
+            category: AlertCategory::HighNews2,
+            severity: AlertSeverity::High,
+            message: "NEWS2 de 6".to_string(),
+        })
+        .await
+        .unwrap();
+        assert!(acknowledge(&store, &lab.id).await.unwrap().unwrap().acknowledged);
+        assert!(acknowledge(&store, "missing").await.unwrap().is_none());
+
+        let reopened = HestiaStore::open(&path);
+        let p1 = AlertFilter { patient_id: Some("p1".to_string()), include_resolved: false };
+        let alerts = list(&reopened, &p1);
+        assert_eq!(alerts.len(), 1);
+        assert!(alerts[0].acknowledged);
+
+        resolve(&reopened, &lab.id).await.unwrap();
+        assert!(list(&reopened, &p1).is_empty());
+        assert_eq!(list(&reopened, &AlertFilter { include_resolved: true, ..AlertFilter::default() }).len(), 2);
+
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -0,0 +1,174 @@
+// server/src/critical_labs.rs
+// Valores de laboratorio críticos: alertan de inmediato sin importar el puntaje de la escala.
+// La tabla por defecto se ajusta con OLYMPUS_CRITICAL_LABS.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::OnceLock;
+
+pub const CRITICAL_LABS_ENV: &str = "OLYMPUS_CRITICAL_LABS";
+
+/// Fuera de [low, high] el valor es crítico; un límite ausente no se comprueba
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CriticalLabThreshold {
+    pub lab: String,
+    pub low: Option<f64>,
+    pub high: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CriticalDirection {
+    Low,
+    High,
+}
+
+/// Laboratorio fuera de su umbral en una evaluación
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CriticalLabFinding {
+    pub lab: String,
+    pub value: f64,
+    pub threshold: f64,
+    pub direction: CriticalDirection,
+}
+
+impl CriticalLabFinding {
+    pub fn describe(&self) -> String {
+        let op = match self.direction {
+            CriticalDirection::Low => "<",
+            CriticalDirection::High => ">",
+        };
+        format!("{} {} (crítico {} {})", self.lab, self.value, op, self.threshold)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CriticalLabTable {
+    pub thresholds: Vec<CriticalLabThreshold>,
+}
+
+impl Default for CriticalLabTable {
+    fn default() -> Self {
+        let t = |lab: &str, low: Option<f64>, high: Option<f64>| CriticalLabThreshold { lab: lab.to_string(), low, high };
+        Self {
+            thresholds: vec![
+                t("serum_potassium", Some(2.5), Some(6.5)),
+                t("arterial_ph", Some(7.2), Some(7.6)),
+                t("serum_sodium", Some(120.0), Some(160.0)),
+                t("serum_bicarbonate", Some(10.0), Some(40.0)),
+                t("white_blood_count", Some(1.0), Some(50.0)),
+                t("hematocrit", Some(20.0), Some(60.0)),
+                t("serum_creatinine", None, Some(10.0)),
+                t("bilirubin", None, Some(15.0)),
+            ],
+        }
+    }
+}
+
+impl CriticalLabTable {
+    /// `OLYMPUS_CRITICAL_LABS`: umbrales que reemplazan a los por defecto, p. ej.
+    /// `serum_potassium=2.8:6.0,arterial_ph=7.15:` (un lado vacío no se comprueba)
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var(CRITICAL_LABS_ENV) {
+            Ok(raw) => Self::default().with_overrides(&raw),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn with_overrides(mut self, raw: &str) -> Result<Self, String> {
+        let bound = |raw: &str| -> Result<Option<f64>, String> {
+            match raw.trim() {
+                "" => Ok(None),
+                value => value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|v| v.is_finite())
+                    .map(Some)
+                    .ok_or_else(|| format!("{}: límite no numérico '{}'", CRITICAL_LABS_ENV, value)),
+            }
+        };
+
+        for entry in raw.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (lab, range) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("{} espera lab=bajo:alto: '{}'", CRITICAL_LABS_ENV, entry))?;
+            let (low, high) = range
+                .split_once(':')
+                .ok_or_else(|| format!("{} espera lab=bajo:alto: '{}'", CRITICAL_LABS_ENV, entry))?;
+            let (low, high) = (bound(low)?, bound(high)?);
+            if let (Some(low), Some(high)) = (low, high) {
+                if low >= high {
+                    return Err(format!("{}: el límite bajo de {} ({}) no es menor que el alto ({})", CRITICAL_LABS_ENV, lab.trim(), low, high));
+                }
+            }
+            self.set_threshold(lab.trim(), low, high);
+        }
+        Ok(self)
+    }
+
+    /// Reemplaza (o agrega) el umbral de un laboratorio
+    pub fn set_threshold(&mut self, lab: &str, low: Option<f64>, high: Option<f64>) {
+        self.thresholds.retain(|t| t.lab != lab);
+        self.thresholds.push(CriticalLabThreshold { lab: lab.to_string(), low, high });
+    }
+
+    /// Laboratorios de la entrada de una escala (campos numéricos con el nombre del
+    /// umbral) que caen fuera de la tabla
+    pub fn check(&self, input: &Value) -> Vec<CriticalLabFinding> {
+        self.thresholds
+            .iter()
+            .filter_map(|threshold| {
+                let value = input.get(&threshold.lab)?.as_f64()?;
+                let (limit, direction) = match (threshold.low, threshold.high) {
+                    (Some(low), _) if value < low => (low, CriticalDirection::Low),
+                    (_, Some(high)) if value > high => (high, CriticalDirection::High),
+                    _ => return None,
+                };
+                Some(CriticalLabFinding { lab: threshold.lab.clone(), value, threshold: limit, direction })
+            })
+            .collect()
+    }
+}
+
+static CRITICAL_LABS: OnceLock<CriticalLabTable> = OnceLock::new();
+
+/// Fija la tabla para todo el proceso; se llama una vez al arrancar
+pub fn configure(table: CriticalLabTable) {
+    let _ = CRITICAL_LABS.set(table);
+}
+
+pub fn table() -> &'static CriticalLabTable {
+    CRITICAL_LABS.get_or_init(CriticalLabTable::default)
+}
+
+/// Marca el resultado de una escala con los laboratorios críticos de su entrada; la
+/// alerta la levanta `clinical_alerts::from_assessment` al guardar la evaluación
+pub fn flag(result: &mut Value, input: &Value) {
+    let findings = table().check(input);
+    result["critical_lab"] = Value::Bool(!findings.is_empty());
+    result["critical_labs"] = serde_json::to_value(findings).unwrap_or_default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_critical_values_and_overrides() {
+        let table = CriticalLabTable::default();
+        let findings = table.check(&json!({ "serum_potassium": 7.0, "arterial_ph": 7.35, "age": 200 }));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].lab, "serum_potassium");
+        assert_eq!(findings[0].direction, CriticalDirection::High);
+
+        let table = table.with_overrides("serum_potassium=2.5:7.5, arterial_ph=7.3:").unwrap();
+        assert!(table.check(&json!({ "serum_potassium": 7.0 })).is_empty());
+        assert_eq!(table.check(&json!({ "arterial_ph": 9.0 })).len(), 0);
+        assert_eq!(table.check(&json!({ "arterial_ph": 7.25 }))[0].direction, CriticalDirection::Low);
+
+        assert!(CriticalLabTable::default().with_overrides("serum_potassium=6.5:2.5").is_err());
+        assert!(CriticalLabTable::default().with_overrides("serum_potassium").is_err());
+        assert!(CriticalLabTable::default().with_overrides("serum_potassium=alto:").is_err());
+    }
+}
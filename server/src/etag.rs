@@ -0,0 +1,68 @@
+// server/src/etag.rs
+// ETag por contenido para GETs consultados con frecuencia (dashboards)
+
+use axum::{
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// ETag fuerte derivado del cuerpo serializado
+pub fn etag_for(body: &[u8]) -> String {
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    format!("\"{:016x}\"", hasher.finish())
+}
+
+/// Comprueba If-None-Match (lista separada por comas, `*` o ETags débiles)
+fn matches(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(|tag| tag.trim().trim_start_matches("W/"))
+        .any(|tag| tag == "*" || tag == etag)
+}
+
+/// Respuesta JSON con ETag; 304 sin cuerpo si el cliente ya tiene esta versión
+pub fn json_with_etag(headers: &HeaderMap, value: &serde_json::Value) -> Response {
+    let body = serde_json::to_vec(value).unwrap_or_default();
+    let etag = etag_for(&body);
+    let etag_header = HeaderValue::from_str(&etag).expect("ETag hexadecimal válido");
+
+    if matches(headers, &etag) {
+        return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag_header)]).into_response();
+    }
+
+    (
+        [
+            (header::ETAG, etag_header),
+            (header::CONTENT_TYPE, HeaderValue::from_static("application/json")),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_matching_etag_returns_304() {
+        let value = json!({ "patient": { "id": "p1" } });
+        let first = json_with_etag(&HeaderMap::new(), &value);
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = first.headers().get(header::ETAG).unwrap().clone();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, etag);
+        assert_eq!(json_with_etag(&headers, &value).status(), StatusCode::NOT_MODIFIED);
+
+        let changed = json!({ "patient": { "id": "p1", "bed": 4 } });
+        assert_eq!(json_with_etag(&headers, &changed).status(), StatusCode::OK);
+    }
+}
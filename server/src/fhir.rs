@@ -0,0 +1,163 @@
+// server/src/fhir.rs
+// Exportación de un paciente como JSON estilo FHIR R4 (no certificado)
+//
+// Forma estable del documento:
+//   Bundle (type "collection")
+//     entry[0].resource  Patient      id, identifier[cédula], name[family, given]
+//     entry[n].resource  Observation  una por cada Glasgow/SOFA/NEWS2 guardado:
+//         status "final", category "survey", subject "Patient/<id>",
+//         effectiveDateTime = assessed_at, valueInteger = total,
+//         code LOINC cuando existe (Glasgow 9269-2) o del sistema local `SCALE_SYSTEM`,
+//         component[] con las subpuntuaciones (ojo/verbal/motor, órganos SOFA)
+
+use serde_json::{json, Value};
+
+pub const LOINC_SYSTEM: &str = "http://loinc.org";
+/// Sistema de códigos propio para escalas sin código LOINC
+pub const SCALE_SYSTEM: &str = "urn:olympus:scales";
+pub const IDENTITY_CARD_SYSTEM: &str = "urn:olympus:identity-card";
+
+fn coding(system: &str, code: &str, display: &str) -> Value {
+    json!({ "coding": [{ "system": system, "code": code, "display": display }], "text": display })
+}
+
+/// Código de la puntuación total de cada escala
+fn scale_code(scale: &str) -> Option<Value> {
+    match scale {
+        "Glasgow" => Some(coding(LOINC_SYSTEM, "9269-2", "Glasgow coma score total")),
+        "SOFA" => Some(coding(SCALE_SYSTEM, "sofa-total", "SOFA score total")),
+        "NEWS2" => Some(coding(SCALE_SYSTEM, "news2-total", "NEWS2 score total")),
+        _ => None,
+    }
+}
+
+/// Subpuntuaciones: Glasgow con sus códigos LOINC, SOFA por órgano
+fn components(assessment: &Value) -> Vec<Value> {
+    let component = |code: Value, value: &Value| {
+        value.as_i64().map(|v| json!({ "code": code, "valueInteger": v }))
+    };
+
+    match assessment["scale"].as_str() {
+        Some("Glasgow") => [
+            ("9267-6", "Glasgow coma score eye opening", "eye"),
+            ("9270-0", "Glasgow coma score verbal", "verbal"),
+            ("9268-4", "Glasgow coma score motor", "motor"),
+        ]
+        .iter()
+        .filter_map(|(code, display, field)| component(coding(LOINC_SYSTEM, code, display), &assessment[*field]))
+        .collect(),
+        Some("SOFA") => assessment["components"]
+            .as_object()
+            .map(|organs| {
+                organs
+                    .iter()
+                    .filter_map(|(organ, value)| {
+                        let code = format!("sofa-{}", organ);
+                        component(coding(SCALE_SYSTEM, &code, &format!("SOFA {}", organ)), value)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+pub fn patient_resource(patient: &Value) -> Value {
+    json!({
+        "resourceType": "Patient",
+        "id": patient["id"],
+        "identifier": [{ "system": IDENTITY_CARD_SYSTEM, "value": patient["identity_card"] }],
+        "name": [{ "use": "official", "family": patient["last_name"], "given": [patient["first_name"]] }],
+        "extension": [{
+            "url": "urn:olympus:principal-diagnosis",
+            "valueString": patient["principal_diagnosis"],
+        }],
+    })
+}
+
+/// Observation para una evaluación guardada; None si la escala no se exporta
+pub fn observation_resource(patient_id: &str, assessment: &Value) -> Option<Value> {
+    let code = scale_code(assessment["scale"].as_str()?)?;
+    let total = assessment["total"].as_i64()?;
+
+    let mut observation = json!({
+        "resourceType": "Observation",
+        "id": assessment["id"],
+        "status": "final",
+        "category": [{
+            "coding": [{
+                "system": "http://terminology.hl7.org/CodeSystem/observation-category",
+                "code": "survey",
+            }],
+        }],
+        "code": code,
+        "subject": { "reference": format!("Patient/{}", patient_id) },
+        "effectiveDateTime": assessment["assessed_at"],
+        "valueInteger": total,
+    });
+
+    let components = components(assessment);
+    if !components.is_empty() {
+        observation["component"] = json!(components);
+    }
+    Some(observation)
+}
+
+/// Bundle con el Patient seguido de sus Observations en orden cronológico
+pub fn patient_bundle(patient: &Value, assessments: &[Value]) -> Value {
+    let patient_id = patient["id"].as_str().unwrap_or_default();
+
+    let mut sorted: Vec<&Value> = assessments.iter().collect();
+    sorted.sort_by_key(|a| a["assessed_at"].as_str().unwrap_or_default().to_string());
+
+    let mut entries = vec![json!({ "resource": patient_resource(patient) })];
+    entries.extend(
+        sorted
+            .into_iter()
+            .filter_map(|a| observation_resource(patient_id, a))
+            .map(|resource| json!({ "resource": resource })),
+    );
+
+    json!({
+        "resourceType": "Bundle",
+        "type": "collection",
+        "timestamp": chrono::Utc::now().to_rfc3339(),
+        "total": entries.len(),
+        "entry": entries,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundle_shape() {
+        let patient = json!({
+            "id": "p1", "first_name": "Ana", "last_name": "Pérez",
+            "identity_card": "V-123", "principal_diagnosis": "Sepsis",
+        });
+        let assessments = vec![
+            json!({ "id": "a2", "scale": "SOFA", "total": 7, "assessed_at": "2026-01-02T00:00:00Z",
+                    "components": { "respiratory": 2, "renal": 1 } }),
+            json!({ "id": "a1", "scale": "Glasgow", "total": 13, "eye": 3, "verbal": 4, "motor": 6,
+                    "assessed_at": "2026-01-01T00:00:00Z" }),
+        ];
+
+        let bundle = patient_bundle(&patient, &assessments);
+        let entries = bundle["entry"].as_array().unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0]["resource"]["resourceType"], "Patient");
+        assert_eq!(entries[0]["resource"]["identifier"][0]["value"], "V-123");
+
+        let glasgow = &entries[1]["resource"];
+        assert_eq!(glasgow["code"]["coding"][0]["code"], "9269-2");
+        assert_eq!(glasgow["valueInteger"], 13);
+        assert_eq!(glasgow["subject"]["reference"], "Patient/p1");
+        assert_eq!(glasgow["component"].as_array().unwrap().len(), 3);
+
+        let sofa = &entries[2]["resource"];
+        assert_eq!(sofa["code"]["coding"][0]["system"], SCALE_SYSTEM);
+        assert_eq!(sofa["valueInteger"], 7);
+    }
+}
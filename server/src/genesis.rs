@@ -2,84 +2,137 @@
 // Genesis: Bootloader del Olimpo - Inicia los 20 Dioses
 
 use crate::actors::*;
-use std::collections::HashMap;
+use crate::auth::AuthStore;
+use std::collections::{HashMap, HashSet};
 use tokio::sync::mpsc;
 
 pub struct OlympusGenesis;
 
+/// Dioses que Genesis debe desplegar (por defecto, los 20)
+#[derive(Debug, Clone)]
+pub struct GenesisConfig {
+    enabled: HashSet<GodName>,
+}
+
+impl Default for GenesisConfig {
+    fn default() -> Self {
+        Self { enabled: GodName::ALL.into_iter().collect() }
+    }
+}
+
+impl GenesisConfig {
+    /// Lee `OLYMPUS_DISABLED_GODS` (lista separada por comas, p. ej. `Chaos,Dionysus`)
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var("OLYMPUS_DISABLED_GODS") {
+            Ok(list) => Self::default().disable_list(&list),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn disable_list(mut self, list: &str) -> Result<Self, String> {
+        for name in list.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            let god = GodName::from_name(name).ok_or_else(|| format!("Dios desconocido: '{}'", name))?;
+            if GodName::TRINITY.contains(&god) {
+                return Err(format!("{} pertenece a la Trinidad y no se puede deshabilitar", god.as_str()));
+            }
+            self.enabled.remove(&god);
+        }
+        Ok(self)
+    }
+
+    pub fn is_enabled(&self, god: GodName) -> bool {
+        self.enabled.contains(&god)
+    }
+
+    /// Dioses habilitados en orden de ignición
+    pub fn enabled(&self) -> Vec<GodName> {
+        GodName::ALL.into_iter().filter(|god| self.is_enabled(*god)).collect()
+    }
+
+    pub fn disabled(&self) -> Vec<GodName> {
+        GodName::ALL.into_iter().filter(|god| !self.is_enabled(*god)).collect()
+    }
+}
+
 impl OlympusGenesis {
-    pub async fn ignite() -> Result<HashMap<GodName, mpsc::Sender<ActorMessage>>, Box<dyn std::error::Error>> {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn ignite(config: &GenesisConfig, store: HestiaStore, heartbeats: HeartbeatRegistry, recoveries: RecoveryRegistry, trinity: TrinityMembers, replies: ReplyLatencyMonitor, load_shedding: LoadShedding, auth: AuthStore, patients: SurrealStore) -> Result<HashMap<GodName, mpsc::Sender<ActorMessage>>, Box<dyn std::error::Error>> {
         tracing::info!("✨ GENESIS: Iniciando secuencia de ignición del Olimpo v15...");
 
         let mut senders: HashMap<GodName, mpsc::Sender<ActorMessage>> = HashMap::new();
 
+        // === VIGILANCIA: el buzón de Erinyes se crea primero ===
+        // Los registros entran antes que cualquier heartbeat, y cada dios late hacia
+        // Erinyes desde su propio bucle (también Erinyes, que así demuestra que su buzón avanza)
+        let mut erinyes_channel = config.is_enabled(GodName::Erinyes).then(|| mpsc::channel(1000));
+        let erinyes_tx = erinyes_channel.as_ref().map(|(tx, _)| tx.clone());
+        match &erinyes_tx {
+            Some(erinyes_tx) => {
+                let enabled = config.enabled();
+                for message in registration_messages(&enabled, &trinity) {
+                    erinyes_tx.send(message).await?;
+                }
+                tracing::info!("👁️ {} dioses registrados en Erinyes", enabled.len());
+            }
+            None => tracing::warn!("👁️ Erinyes deshabilitada: los dioses no tendrán monitoreo de heartbeats"),
+        }
+        let runtime = |actor: Box<dyn OlympianActor>, rx: mpsc::Receiver<ActorMessage>| {
+            let runtime = ActorRuntime::new(actor, rx).with_reply_latency(replies.clone());
+            match &erinyes_tx {
+                Some(erinyes_tx) => runtime.with_heartbeats(erinyes_tx.clone(), trinity.clone()),
+                None => runtime,
+            }
+        };
+
         // === TRINIDAD PRINCIPAL ===
         
-        // 1. Zeus (Gobernador) - primero
+        // 1. Zeus (Gobernador) - primero, supervisando solo los dioses habilitados
         let (zeus_tx, zeus_rx) = mpsc::channel(1000);
-        let zeus = Zeus::new();
-        let zeus_runtime = ActorRuntime::new(Box::new(zeus), zeus_rx);
+        let zeus = Zeus::with_supervised(config.enabled(), trinity.clone(), load_shedding);
+        let zeus_runtime = runtime(Box::new(zeus), zeus_rx);
         tokio::spawn(zeus_runtime.run());
         senders.insert(GodName::Zeus, zeus_tx);
         tracing::info!("⚡ Zeus desplegado");
 
         // 2. Hades (Seguridad)
         let (hades_tx, hades_rx) = mpsc::channel(1000);
-        let hades = Hades::new();
-        let hades_runtime = ActorRuntime::new(Box::new(hades), hades_rx);
+        let hades = Hades::new(auth);
+        let hades_runtime = runtime(Box::new(hades), hades_rx);
         tokio::spawn(hades_runtime.run());
         senders.insert(GodName::Hades, hades_tx);
         tracing::info!("🔒 Hades desplegado");
 
         // 3. Poseidon (Datos)
         let (poseidon_tx, poseidon_rx) = mpsc::channel(1000);
-        let poseidon = Poseidon::new();
-        let poseidon_runtime = ActorRuntime::new(Box::new(poseidon), poseidon_rx);
+        let poseidon = Poseidon::new(patients);
+        let poseidon_runtime = runtime(Box::new(poseidon), poseidon_rx);
         tokio::spawn(poseidon_runtime.run());
         senders.insert(GodName::Poseidon, poseidon_tx);
         tracing::info!("🌊 Poseidon desplegado");
 
         // === DIOSES CLAVE ===
 
-        // 4. Athena (Escalas/ML)
-        let (athena_tx, athena_rx) = mpsc::channel(1000);
-        let athena = Athena::new();
-        let athena_runtime = ActorRuntime::new(Box::new(athena), athena_rx);
-        tokio::spawn(athena_runtime.run());
-        senders.insert(GodName::Athena, athena_tx);
-        tracing::info!("🧠 Athena desplegada");
-
-        // 5. Hermes (Mensajería)
-        let (hermes_tx, hermes_rx) = mpsc::channel(1000);
-        let hermes = Hermes::new();
-        let hermes_runtime = ActorRuntime::new(Box::new(hermes), hermes_rx);
-        tokio::spawn(hermes_runtime.run());
-        senders.insert(GodName::Hermes, hermes_tx);
-        tracing::info!("📨 Hermes desplegado");
-
-        // 6. Hestia (Persistencia)
-        let (hestia_tx, hestia_rx) = mpsc::channel(1000);
-        let hestia = Hestia::new();
-        let hestia_runtime = ActorRuntime::new(Box::new(hestia), hestia_rx);
-        tokio::spawn(hestia_runtime.run());
-        senders.insert(GodName::Hestia, hestia_tx);
-        tracing::info!("🏛️ Hestia desplegada");
-
-        // 7. Erinyes (Monitoreo)
-        let (erinyes_tx, erinyes_rx) = mpsc::channel(1000);
-        let erinyes = Erinyes::new();
-        let erinyes_runtime = ActorRuntime::new(Box::new(erinyes), erinyes_rx);
-        tokio::spawn(erinyes_runtime.run());
-        senders.insert(GodName::Erinyes, erinyes_tx);
-        tracing::info!("👁️ Erinyes desplegado");
-
-        // 8. Aphrodite (UI/UX) - Diosa de la Belleza
-        let (aphrodite_tx, aphrodite_rx) = mpsc::channel(1000);
-        let aphrodite = Aphrodite::new();
-        let aphrodite_runtime = ActorRuntime::new(Box::new(aphrodite), aphrodite_rx);
-        tokio::spawn(aphrodite_runtime.run());
-        senders.insert(GodName::Aphrodite, aphrodite_tx);
-        tracing::info!("🎨 Aphrodite desplegada - Gestionando UI/Temas");
+        let key_gods: Vec<(GodName, Box<dyn OlympianActor>, &str)> = vec![
+            (GodName::Athena, Box::new(Athena::new()), "🧠 Athena desplegada"),
+            (GodName::Hermes, Box::new(Hermes::new()), "📨 Hermes desplegado"),
+            (GodName::Hestia, Box::new(Hestia::new(store.clone())), "🏛️ Hestia desplegada"),
+            (GodName::Erinyes, Box::new(Erinyes::new(heartbeats, recoveries, trinity.clone())), "👁️ Erinyes desplegado"),
+            (GodName::Aphrodite, Box::new(Aphrodite::new(store)), "🎨 Aphrodite desplegada - Gestionando UI/Temas"),
+        ];
+
+        for (name, actor, banner) in key_gods {
+            if !config.is_enabled(name) {
+                tracing::info!("⏸️ {} deshabilitado por configuración", name.as_str());
+                continue;
+            }
+            let (tx, rx) = match name {
+                GodName::Erinyes => erinyes_channel.take().unwrap_or_else(|| mpsc::channel(1000)),
+                _ => mpsc::channel(1000),
+            };
+            tokio::spawn(runtime(actor, rx).run());
+            senders.insert(name, tx);
+            tracing::info!("{}", banner);
+        }
 
         // === DIOSES MENORES (12) ===
 
@@ -99,73 +152,55 @@ impl OlympusGenesis {
         ];
 
         for (name, actor) in minor_gods {
+            if !config.is_enabled(name) {
+                tracing::info!("⏸️ {} deshabilitado por configuración", name.as_str());
+                continue;
+            }
             let (tx, rx) = mpsc::channel(100);
-            let runtime = ActorRuntime::new(actor, rx);
-            tokio::spawn(runtime.run());
+            tokio::spawn(runtime(actor, rx).run());
             senders.insert(name, tx);
             tracing::info!("✨ {} desplegado", name.as_str());
         }
 
-        // Iniciar heartbeat loop
-        let senders_clone = senders.clone();
-        tokio::spawn(async move {
-            loop {
-                tokio::time::sleep(tokio::time::Duration::from_secs(10)).await;
-                
-                // Enviar heartbeat a Erinyes
-                if let Some(erinyes_tx) = senders_clone.get(&GodName::Erinyes) {
-                    for (god, tx) in &senders_clone {
-                        if *god != GodName::Erinyes {
-                            let heartbeat = ActorMessage::new(
-                                *god,
-                                GodName::Erinyes,
-                                MessagePayload::Heartbeat { timestamp: chrono::Utc::now() }
-                            );
-                            let _ = tx.send(heartbeat).await;
-                        }
-                    }
-                }
-            }
-        });
-
         tracing::info!("🌌 GENESIS: {} Dioses desplegados. La Trinidad vigila.", senders.len());
         
         Ok(senders)
     }
 }
 
+/// Un `register_actor` por dios: la Trinidad con la configuración agresiva, el resto con la estándar
+pub fn registration_messages(gods: &[GodName], trinity: &TrinityMembers) -> Vec<ActorMessage> {
+    gods.iter()
+        .map(|god| {
+            ActorMessage::new(
+                GodName::Zeus,
+                GodName::Erinyes,
+                MessagePayload::Command {
+                    action: "register_actor".to_string(),
+                    data: serde_json::json!({ "actor": god, "config": HeartbeatConfig::for_god(*god, trinity) }),
+                },
+            )
+        })
+        .collect()
+}
+
 // Función helper para obtener estado de salud de todos los dioses
 pub async fn get_all_gods_health(
     senders: &HashMap<GodName, mpsc::Sender<ActorMessage>>
 ) -> Vec<GodHealth> {
-    let mut health_data = Vec::new();
-    
-    for (god, tx) in senders {
-        // Crear mensaje de consulta de salud
-        let msg = ActorMessage::new(
-            GodName::Zeus,
-            *god,
-            MessagePayload::Query { 
-                query_type: "health_check".to_string(),
-                params: serde_json::json!({}),
-            }
-        );
-        
-        // En una implementación completa, esperaríamos respuesta
-        // Por ahora, devolvemos datos simulados basados en el estado
-        let health = GodHealth {
+    // En una implementación completa se consultaría a cada dios y se esperaría
+    // respuesta; por ahora, datos simulados basados en el estado
+    senders
+        .keys()
+        .map(|god| GodHealth {
             name: *god,
             healthy: true,
             last_heartbeat: chrono::Utc::now(),
             messages_processed: 0,
             uptime_seconds: 0,
             status: "Active".to_string(),
-        };
-        
-        health_data.push(health);
-    }
-    
-    health_data
+        })
+        .collect()
 }
 
 // Función para enviar mensaje a un dios específico
@@ -180,3 +215,51 @@ pub async fn send_to_god(
         Err(format!("God {:?} not found", god))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disable_list() {
+        let config = GenesisConfig::default().disable_list("chaos, Dionysus").unwrap();
+        assert!(!config.is_enabled(GodName::Chaos));
+        assert!(!config.is_enabled(GodName::Dionysus));
+        assert_eq!(config.enabled().len(), 18);
+        assert_eq!(config.disabled(), vec![GodName::Chaos, GodName::Dionysus]);
+
+        assert!(GenesisConfig::default().disable_list("Hades").is_err());
+        assert!(GenesisConfig::default().disable_list("Kronos").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_registration_uses_tier_configs() {
+        let registry = HeartbeatRegistry::new();
+        let trinity = TrinityMembers::new();
+        let mut erinyes = Erinyes::new(registry.clone(), RecoveryRegistry::new(), trinity.clone());
+        let enabled = GenesisConfig::default().disable_list("Chaos").unwrap().enabled();
+
+        for message in registration_messages(&enabled, &trinity) {
+            erinyes.handle_message(message).await;
+        }
+
+        let registered = registry.snapshot();
+        assert_eq!(registered.len(), 19);
+        assert!(registry.get(GodName::Chaos).is_none());
+        assert_eq!(registry.get(GodName::Hades).unwrap().config, HeartbeatConfig::TRINITY);
+        assert_eq!(registry.get(GodName::Athena).unwrap().config, HeartbeatConfig::STANDARD);
+        assert!(registered.iter().all(|(_, r)| r.is_healthy(chrono::Utc::now())));
+
+        // Un heartbeat de un dios no registrado no lo da de alta
+        let stray = ActorMessage::new(GodName::Chaos, GodName::Erinyes, MessagePayload::Heartbeat { timestamp: chrono::Utc::now() });
+        erinyes.handle_message(stray).await;
+        assert!(registry.get(GodName::Chaos).is_none());
+
+        // Al entrar en la Trinidad, Hestia pasa a la vigilancia agresiva
+        trinity.set(&[GodName::Zeus, GodName::Hades, GodName::Poseidon, GodName::Hestia], |_| true).unwrap();
+        for message in registration_messages(&[GodName::Hestia], &trinity) {
+            erinyes.handle_message(message).await;
+        }
+        assert_eq!(registry.get(GodName::Hestia).unwrap().config.tier, MonitoringTier::Trinity);
+    }
+}
@@ -0,0 +1,111 @@
+// server/src/i18n.rs
+// Localización de las interpretaciones clínicas (ES por defecto, EN opcional)
+
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Query},
+    http::{header::ACCEPT_LANGUAGE, request::Parts},
+};
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+
+/// Se serializa como su código ("es", "en") al viajar en los comandos a los dioses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Lang {
+    #[default]
+    Es,
+    En,
+}
+
+impl Lang {
+    fn parse(value: &str) -> Option<Self> {
+        let primary = value.trim().split(['-', '_']).next()?.to_ascii_lowercase();
+        match primary.as_str() {
+            "es" => Some(Lang::Es),
+            "en" => Some(Lang::En),
+            _ => None,
+        }
+    }
+
+    /// Primer idioma soportado de un encabezado Accept-Language
+    fn from_accept_language(header: &str) -> Option<Self> {
+        header
+            .split(',')
+            .filter_map(|part| part.split(';').next())
+            .find_map(Lang::parse)
+    }
+
+    /// Traduce una interpretación; las cadenas canónicas están en español
+    pub fn tr(self, es: &'static str) -> &'static str {
+        if self == Lang::Es {
+            return es;
+        }
+
+        match es {
+            // Glasgow
+            "Coma severo" => "Severe coma",
+            "Coma moderado" => "Moderate coma",
+            "Coma leve/Normal" => "Mild coma/Normal",
+            "Coma profundo" => "Deep coma",
+            // NEWS2
+            "Bajo riesgo" => "Low risk",
+            "Riesgo moderado" => "Medium risk",
+            "Riesgo moderado - revisar urgentemente" => "Medium risk - urgent review",
+            "Alto riesgo - respuesta de emergencia" => "High risk - emergency response",
+            // APACHE II / SAPS II
+            "Alto riesgo" => "High risk",
+            "Riesgo muy alto" => "Very high risk",
+            "Riesgo extremo" => "Extreme risk",
+            // SOFA
+            "SOFA parcial: faltan datos de algunos órganos; el total es un mínimo" => "Partial SOFA: some organ data is missing; the total is a lower bound",
+            _ => es,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct LangQuery {
+    lang: Option<String>,
+}
+
+// `?lang=` tiene prioridad sobre Accept-Language
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for Lang {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        if let Ok(Query(q)) = Query::<LangQuery>::from_request_parts(parts, state).await {
+            if let Some(lang) = q.lang.as_deref().and_then(Lang::parse) {
+                return Ok(lang);
+            }
+        }
+
+        let from_header = parts
+            .headers
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(Lang::from_accept_language);
+
+        Ok(from_header.unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_language_picks_first_supported() {
+        assert_eq!(Lang::from_accept_language("fr-FR, en-US;q=0.8, es;q=0.5"), Some(Lang::En));
+        assert_eq!(Lang::from_accept_language("es-VE"), Some(Lang::Es));
+        assert_eq!(Lang::from_accept_language("de, fr"), None);
+    }
+
+    #[test]
+    fn test_translation_defaults_to_spanish() {
+        assert_eq!(Lang::default().tr("Coma severo"), "Coma severo");
+        assert_eq!(Lang::En.tr("Coma severo"), "Severe coma");
+        assert_eq!(Lang::En.tr("Sin traducción"), "Sin traducción");
+    }
+}
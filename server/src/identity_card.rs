@@ -0,0 +1,121 @@
+// server/src/identity_card.rs
+// Cédula canónica (sin separadores y en mayúsculas) para guardar y detectar duplicados
+
+use regex::Regex;
+use serde_json::Value;
+use std::sync::OnceLock;
+
+pub const PATTERN_ENV: &str = "OLYMPUS_IDENTITY_CARD_PATTERN";
+pub const NORMALIZE_ENV: &str = "OLYMPUS_IDENTITY_CARD_NORMALIZE";
+
+/// Separadores que se eliminan al normalizar ("v 12.345.678" -> "V12345678")
+const SEPARATORS: [char; 4] = ['.', '-', '_', '/'];
+
+#[derive(Debug, Clone)]
+pub struct IdentityCardPolicy {
+    /// Quitar separadores y pasar a mayúsculas (activo por defecto)
+    pub normalize: bool,
+    /// Formato exigido al valor ya normalizado; sin patrón se acepta cualquiera
+    pub pattern: Option<Regex>,
+}
+
+impl Default for IdentityCardPolicy {
+    fn default() -> Self {
+        Self { normalize: true, pattern: None }
+    }
+}
+
+impl IdentityCardPolicy {
+    /// `OLYMPUS_IDENTITY_CARD_NORMALIZE` (true/false) y `OLYMPUS_IDENTITY_CARD_PATTERN` (regex)
+    pub fn from_env() -> Result<Self, String> {
+        let normalize = match std::env::var(NORMALIZE_ENV) {
+            Ok(raw) => raw.trim().parse().map_err(|_| format!("{} debe ser true o false: '{}'", NORMALIZE_ENV, raw))?,
+            Err(_) => true,
+        };
+        let pattern = match std::env::var(PATTERN_ENV) {
+            Ok(raw) if !raw.trim().is_empty() => {
+                Some(Regex::new(raw.trim()).map_err(|e| format!("{} no es una regex válida: {}", PATTERN_ENV, e))?)
+            }
+            _ => None,
+        };
+        Ok(Self { normalize, pattern })
+    }
+
+    pub fn normalize(&self, raw: &str) -> String {
+        if !self.normalize {
+            return raw.trim().to_string();
+        }
+        raw.chars()
+            .filter(|c| !c.is_whitespace() && !SEPARATORS.contains(c))
+            .flat_map(char::to_uppercase)
+            .collect()
+    }
+
+    /// Valor normalizado, o error si queda vacío o no cumple el patrón
+    pub fn canonical(&self, raw: &str) -> Result<String, String> {
+        let card = self.normalize(raw);
+        if card.is_empty() {
+            return Err("La cédula es obligatoria".to_string());
+        }
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(&card) {
+                return Err(format!("Cédula '{}' con formato inválido (patrón {})", raw, pattern.as_str()));
+            }
+        }
+        Ok(card)
+    }
+}
+
+static POLICY: OnceLock<IdentityCardPolicy> = OnceLock::new();
+
+/// Fija la política para todo el proceso; se llama una vez al arrancar
+pub fn configure(policy: IdentityCardPolicy) {
+    let _ = POLICY.set(policy);
+}
+
+pub fn policy() -> &'static IdentityCardPolicy {
+    POLICY.get_or_init(IdentityCardPolicy::default)
+}
+
+/// Paciente no eliminado, distinto de `exclude_id`, con la misma cédula normalizada
+pub fn find_duplicate<'a>(
+    patients: impl IntoIterator<Item = &'a Value>,
+    card: &str,
+    exclude_id: Option<&str>,
+) -> Option<&'a Value> {
+    let card = policy().normalize(card);
+    patients.into_iter().find(|p| {
+        !p.get("deleted").and_then(|d| d.as_bool()).unwrap_or(false)
+            && p.get("id").and_then(|v| v.as_str()) != exclude_id
+            && p.get("identity_card").and_then(|v| v.as_str()).map(|c| policy().normalize(c)) == Some(card.clone())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_separators_and_case_are_normalized() {
+        let policy = IdentityCardPolicy::default();
+        assert_eq!(policy.canonical("v 12.345.678"), Ok("V12345678".to_string()));
+        assert_eq!(policy.canonical("V-12345678"), Ok("V12345678".to_string()));
+        assert!(policy.canonical(" - ").is_err());
+
+        let strict = IdentityCardPolicy { normalize: true, pattern: Some(Regex::new(r"^[VE]\d{6,9}$").unwrap()) };
+        assert!(strict.canonical("v 12.345.678").is_ok());
+        assert!(strict.canonical("X-1").is_err());
+
+        let raw = IdentityCardPolicy { normalize: false, pattern: None };
+        assert_eq!(raw.normalize(" v-1 "), "v-1");
+
+        let patients = [
+            json!({ "id": "patient:1", "identity_card": "V12345678" }),
+            json!({ "id": "patient:2", "identity_card": "V-999", "deleted": true }),
+        ];
+        assert_eq!(find_duplicate(&patients, "v 12.345.678", None).unwrap()["id"], "patient:1");
+        assert!(find_duplicate(&patients, "V12345678", Some("patient:1")).is_none());
+        assert!(find_duplicate(&patients, "V999", None).is_none());
+    }
+}
@@ -0,0 +1,82 @@
+// server/src/ids.rs
+// Generación de identificadores inyectable: ULID en producción, secuencial en tests
+
+#[cfg(test)]
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+pub trait IdGenerator: Send + Sync {
+    /// Nuevo id para un registro de tipo `kind` (`patient`, `assessment`, ...)
+    fn next_id(&self, kind: &str) -> String;
+}
+
+/// `kind:<ULID>`, la misma forma que los ids `rand::ulid()` de SurrealDB. Los ULID son
+/// monótonos dentro del mismo milisegundo, así que ordenar por id es ordenar por creación.
+#[derive(Default)]
+pub struct UlidIds {
+    generator: Mutex<ulid::Generator>,
+}
+
+impl UlidIds {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl IdGenerator for UlidIds {
+    fn next_id(&self, kind: &str) -> String {
+        let mut generator = self.generator.lock().unwrap_or_else(|e| e.into_inner());
+        // Solo falla si se agotan 2^80 ids en un milisegundo
+        let ulid = generator.generate().unwrap_or_else(|_| ulid::Ulid::new());
+        format!("{}:{}", kind, ulid)
+    }
+}
+
+/// `kind:1`, `kind:2`, ... con un contador independiente por tipo
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub struct SequentialIds {
+    counters: Mutex<HashMap<String, u64>>,
+}
+
+#[cfg(test)]
+impl SequentialIds {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+impl IdGenerator for SequentialIds {
+    fn next_id(&self, kind: &str) -> String {
+        let mut counters = self.counters.lock().unwrap_or_else(|e| e.into_inner());
+        let counter = counters.entry(kind.to_string()).or_insert(0);
+        *counter += 1;
+        format!("{}:{}", kind, counter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequential_ids_count_per_kind() {
+        let ids = SequentialIds::new();
+        assert_eq!(ids.next_id("patient"), "patient:1");
+        assert_eq!(ids.next_id("assessment"), "assessment:1");
+        assert_eq!(ids.next_id("patient"), "patient:2");
+    }
+
+    #[test]
+    fn test_ulid_ids_sort_in_creation_order() {
+        let ids = UlidIds::new();
+        let created: Vec<String> = (0..100).map(|_| ids.next_id("patient")).collect();
+        assert!(created.iter().all(|id| id.starts_with("patient:") && id.len() == "patient:".len() + 26));
+
+        let mut sorted = created.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted, created);
+    }
+}
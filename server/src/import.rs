@@ -0,0 +1,345 @@
+// server/src/import.rs
+// Importación masiva de evaluaciones (NDJSON) emparejando pacientes por cédula
+
+use axum::body::BodyDataStream;
+use futures_util::StreamExt;
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+use crate::actors::{ActorMessage, GodName, MessagePayload};
+use crate::api::ApiError;
+use crate::critical_labs;
+use crate::i18n::Lang;
+use crate::identity_card;
+use crate::patient_patch;
+use crate::scales::Scale;
+use crate::{scales, AppState, GlasgowRequest, News2Request, SofaRequest};
+
+/// Longitud máxima de una línea NDJSON; una evaluación real ocupa unos cientos de bytes
+pub const MAX_LINE_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Deserialize)]
+pub struct ImportOptions {
+    #[serde(default)]
+    pub create_missing: bool,
+}
+
+/// Una línea del archivo: `{"identity_card": "...", "scale": "glasgow", "inputs": {...}}`,
+/// con `assessed_at` opcional para evaluaciones históricas
+#[derive(Debug, Deserialize)]
+struct ImportLine {
+    identity_card: String,
+    scale: String,
+    inputs: serde_json::Value,
+    #[serde(default)]
+    assessed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Default)]
+struct ImportSummary {
+    processed: usize,
+    imported: usize,
+    failed: usize,
+    created_patients: usize,
+    unmatched: Vec<String>,
+}
+
+/// Calcula la escala con el paciente ya resuelto
+fn evaluate(line: &ImportLine, patient_id: &str, lang: Lang) -> Result<serde_json::Value, String> {
+    let mut inputs = line.inputs.clone();
+    let Some(fields) = inputs.as_object_mut() else {
+        return Err("'inputs' debe ser un objeto".to_string());
+    };
+    fields.insert("patient_id".to_string(), json!(patient_id));
+
+    let invalid = |e: serde_json::Error| format!("Datos inválidos para {}: {}", line.scale, e);
+    let scale: Scale = line.scale.parse().map_err(|_| format!("Escala no soportada: {}", line.scale))?;
+    match scale {
+        Scale::Glasgow => Ok(scales::glasgow_result(&serde_json::from_value::<GlasgowRequest>(inputs).map_err(invalid)?, lang)),
+        Scale::Sofa => Ok(scales::sofa_result(&serde_json::from_value::<SofaRequest>(inputs).map_err(invalid)?)),
+        Scale::News2 => {
+            let req = serde_json::from_value::<News2Request>(inputs).map_err(invalid)?;
+            req.vitals.validate().map_err(|errors| {
+                let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+                format!("Datos inválidos para {}: {}", line.scale, errors.join("; "))
+            })?;
+            Ok(scales::news2_result(&req, lang))
+        }
+        // Como en los endpoints: un laboratorio crítico queda marcado y alerta al guardarse
+        Scale::Apache => {
+            let mut result = scales::apache_result(&serde_json::from_value(inputs.clone()).map_err(invalid)?)
+                .map_err(|e| format!("Datos inválidos para {}: {}", line.scale, e))?;
+            critical_labs::flag(&mut result, &inputs);
+            Ok(result)
+        }
+        Scale::Saps => {
+            let mut result = scales::saps_result(&serde_json::from_value(inputs.clone()).map_err(invalid)?)
+                .map_err(|e| format!("Datos inválidos para {}: {}", line.scale, e))?;
+            critical_labs::flag(&mut result, &inputs);
+            Ok(result)
+        }
+    }
+}
+
+/// Resuelve el paciente por cédula normalizada; crea un registro mínimo si se permite
+async fn resolve_patient(
+    state: &AppState,
+    index: &mut HashMap<String, String>,
+    identity_card: &str,
+    create_missing: bool,
+) -> Result<Option<(String, bool)>, ApiError> {
+    let identity_card = identity_card::policy().normalize(identity_card);
+    let identity_card = identity_card.as_str();
+    if let Some(id) = index.get(identity_card) {
+        return Ok(Some((id.clone(), false)));
+    }
+    if !create_missing {
+        return Ok(None);
+    }
+
+    // Igual que en el alta normal: Poseidon lo guarda en SurrealDB y asigna el id
+    let mut stub = json!({
+        "first_name": "",
+        "last_name": "",
+        "identity_card": identity_card,
+        "principal_diagnosis": "",
+        "stub": true,
+    });
+    patient_patch::touch(&mut stub);
+    let msg = ActorMessage::new(
+        GodName::Zeus,
+        GodName::Poseidon,
+        MessagePayload::Command {
+            action: "create_patient".to_string(),
+            data: stub,
+        }
+    );
+    let mut created = crate::ask_poseidon(state, msg).await?;
+    let stub = created["patient"].take();
+    let Some(id) = stub.get("id").and_then(|id| id.as_str()).map(str::to_string) else {
+        return Err(crate::storage_error(Some("Poseidon no devolvió el id del paciente".to_string())));
+    };
+
+    // Si otra alta con la misma cédula entró mientras tanto, se usa esa y el esbozo se retira
+    let mut patients = state.patients.write().await;
+    if let Some(existing) = identity_card::find_duplicate(patients.values(), identity_card, None) {
+        let existing_id = existing.get("id").and_then(|id| id.as_str()).unwrap_or_default().to_string();
+        drop(patients);
+        let rollback = ActorMessage::new(
+            GodName::Zeus,
+            GodName::Poseidon,
+            MessagePayload::Command {
+                action: "discard_patient".to_string(),
+                data: json!({ "id": &id }),
+            }
+        );
+        crate::ask_poseidon(state, rollback).await?;
+        index.insert(identity_card.to_string(), existing_id.clone());
+        return Ok(Some((existing_id, false)));
+    }
+    patients.insert(id.clone(), stub);
+    drop(patients);
+
+    index.insert(identity_card.to_string(), id.clone());
+    Ok(Some((id, true)))
+}
+
+async fn process_line(
+    state: &AppState,
+    index: &mut HashMap<String, String>,
+    raw: &str,
+    create_missing: bool,
+    lang: Lang,
+    summary: &mut ImportSummary,
+) -> serde_json::Value {
+    let line: ImportLine = match serde_json::from_str(raw) {
+        Ok(line) => line,
+        Err(e) => return json!({ "success": false, "error": format!("JSON inválido: {}", e) }),
+    };
+
+    let resolved = match resolve_patient(state, index, &line.identity_card, create_missing).await {
+        Ok(resolved) => resolved,
+        Err(e) => {
+            return json!({
+                "success": false,
+                "identity_card": line.identity_card,
+                "error": e.error,
+            })
+        }
+    };
+    let Some((patient_id, created)) = resolved else {
+        if !summary.unmatched.contains(&line.identity_card) {
+            summary.unmatched.push(line.identity_card.clone());
+        }
+        return json!({
+            "success": false,
+            "identity_card": line.identity_card,
+            "error": "Paciente no encontrado",
+        });
+    };
+    if created {
+        summary.created_patients += 1;
+    }
+
+    match evaluate(&line, &patient_id, lang) {
+        Ok(result) => {
+            if let Err(e) = crate::store_assessment_at(state, &patient_id, &result, line.assessed_at).await {
+                return json!({
+                    "success": false,
+                    "identity_card": line.identity_card,
+                    "error": e.error,
+                });
+            }
+            json!({
+                "success": true,
+                "identity_card": line.identity_card,
+                "patient_id": patient_id,
+                "patient_created": created,
+                "scale": result["scale"],
+                "total": result["total"],
+            })
+        }
+        Err(error) => json!({
+            "success": false,
+            "identity_card": line.identity_card,
+            "error": error,
+        }),
+    }
+}
+
+/// Procesa el cuerpo línea a línea y envía un resultado NDJSON por cada una,
+/// más un resumen final
+pub async fn run(
+    state: AppState,
+    lang: Lang,
+    options: ImportOptions,
+    mut body: BodyDataStream,
+    tx: mpsc::Sender<String>,
+) {
+    // Índice cédula -> id de los pacientes activos
+    let mut index: HashMap<String, String> = state
+        .patients
+        .read()
+        .await
+        .values()
+        .filter(|p| !p.get("deleted").and_then(|d| d.as_bool()).unwrap_or(false))
+        .filter_map(|p| {
+            let card = identity_card::policy().normalize(p.get("identity_card")?.as_str()?);
+            Some((card, p.get("id")?.as_str()?.to_string()))
+        })
+        .collect();
+
+    let mut summary = ImportSummary::default();
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut line_number = 0;
+    let mut finished = false;
+
+    while !finished {
+        match body.next().await {
+            Some(Ok(chunk)) => buffer.extend_from_slice(&chunk),
+            Some(Err(e)) => {
+                let _ = tx.send(format!("{}\n", json!({ "success": false, "error": format!("Error leyendo el cuerpo: {}", e) }))).await;
+                return;
+            }
+            // Fin del cuerpo: la última línea puede no terminar en salto de línea
+            None => {
+                buffer.push(b'\n');
+                finished = true;
+            }
+        }
+
+        // Sin salto de línea a la vista: el búfer no puede crecer más allá de una línea
+        if buffer.len() > MAX_LINE_BYTES && !buffer.contains(&b'\n') {
+            let error = format!("Línea {} supera el máximo de {} bytes", line_number + 1, MAX_LINE_BYTES);
+            let _ = tx.send(format!("{}\n", json!({ "success": false, "error": error, "line": line_number + 1 }))).await;
+            return;
+        }
+
+        while let Some(pos) = buffer.iter().position(|b| *b == b'\n') {
+            let raw: Vec<u8> = buffer.drain(..=pos).collect();
+            line_number += 1;
+            if raw.len() > MAX_LINE_BYTES + 1 {
+                let error = format!("Línea {} supera el máximo de {} bytes", line_number, MAX_LINE_BYTES);
+                let _ = tx.send(format!("{}\n", json!({ "success": false, "error": error, "line": line_number }))).await;
+                return;
+            }
+            let raw = String::from_utf8_lossy(&raw);
+            let raw = raw.trim();
+            if raw.is_empty() {
+                continue;
+            }
+
+            summary.processed += 1;
+            let mut result = process_line(&state, &mut index, raw, options.create_missing, lang, &mut summary).await;
+            if result["success"].as_bool().unwrap_or(false) {
+                summary.imported += 1;
+            } else {
+                summary.failed += 1;
+            }
+            result["line"] = json!(line_number);
+
+            // El cliente cerró la conexión: no tiene sentido seguir
+            if tx.send(format!("{}\n", result)).await.is_err() {
+                return;
+            }
+        }
+    }
+
+    let _ = tx
+        .send(format!(
+            "{}\n",
+            json!({
+                "summary": {
+                    "processed": summary.processed,
+                    "imported": summary.imported,
+                    "failed": summary.failed,
+                    "created_patients": summary.created_patients,
+                    "unmatched_identity_cards": summary.unmatched,
+                }
+            })
+        ))
+        .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_mixed_scales() {
+        let glasgow: ImportLine = serde_json::from_value(json!({
+            "identity_card": "V-1", "scale": "Glasgow", "inputs": { "eye": 4, "verbal": 5, "motor": 6 }
+        })).unwrap();
+        let result = evaluate(&glasgow, "p1", Lang::Es).unwrap();
+        assert_eq!(result["total"], 15);
+        assert_eq!(result["patient_id"], "p1");
+
+        let news2: ImportLine = serde_json::from_value(json!({
+            "identity_card": "V-1", "scale": "news2", "inputs": { "respiration_rate": 16 }
+        })).unwrap();
+        assert!(evaluate(&news2, "p1", Lang::Es).unwrap_err().contains("news2"));
+
+        let unknown: ImportLine = serde_json::from_value(json!({
+            "identity_card": "V-1", "scale": "apgar", "inputs": {}
+        })).unwrap();
+        assert!(evaluate(&unknown, "p1", Lang::Es).is_err());
+    }
+
+    #[test]
+    fn test_lab_scales_are_flagged_and_keep_their_time() {
+        let saps: ImportLine = serde_json::from_value(json!({
+            "identity_card": "V-1", "scale": "saps", "assessed_at": "2026-03-01T08:00:00Z", "inputs": {
+                "age": 65, "heart_rate": 130, "systolic_bp": 85, "temperature": 37.0, "pao2_fio2": 150,
+                "urinary_output": 0.8, "serum_urea": 50.0, "white_blood_count": 8.0, "serum_potassium": 7.0,
+                "serum_sodium": 140, "serum_bicarbonate": 24.0, "bilirubin": 1.0, "glasgow": 10,
+                "chronic_disease": "none", "admission_type": "scheduled",
+            }
+        })).unwrap();
+        assert_eq!(saps.assessed_at.unwrap().to_rfc3339(), "2026-03-01T08:00:00+00:00");
+        let result = evaluate(&saps, "p1", Lang::Es).unwrap();
+        assert_eq!(result["critical_lab"], true);
+        assert_eq!(result["critical_labs"][0]["lab"], "serum_potassium");
+    }
+}
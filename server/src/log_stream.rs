@@ -0,0 +1,238 @@
+// server/src/log_stream.rs
+// Consola de depuración: los eventos de `tracing` se reenvían a los suscriptores del
+// SSE `/api/admin/logs/stream`. Cada petición HTTP abre un span con su `trace_id`.
+
+use axum::{
+    body::Body,
+    http::{HeaderValue, Request},
+    middleware::Next,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        Response,
+    },
+};
+use futures_util::Stream;
+use serde::Serialize;
+use std::convert::Infallible;
+use tokio::sync::broadcast;
+use tracing::field::{Field, Visit};
+use tracing::{Instrument, Level, Metadata, Subscriber};
+use tracing_subscriber::filter::{filter_fn, FilterFn};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// Nivel mínimo reenviado, configurable con `OLYMPUS_LOG_STREAM_LEVEL` (error, warn, info...)
+pub const LEVEL_ENV: &str = "OLYMPUS_LOG_STREAM_LEVEL";
+pub const DEFAULT_LEVEL: Level = Level::WARN;
+pub const TRACE_ID_HEADER: &str = "x-trace-id";
+/// Registros que puede acumular un suscriptor lento antes de perder los más antiguos
+const CHANNEL_CAPACITY: usize = 1024;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogRecord {
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub level: String,
+    pub target: String,
+    pub message: String,
+    pub trace_id: Option<String>,
+    /// Campos del evento distintos de `message`
+    pub fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Layer de `tracing` que publica los registros a partir de `level`
+#[derive(Clone)]
+pub struct LogStream {
+    sender: broadcast::Sender<LogRecord>,
+    level: Level,
+}
+
+impl LogStream {
+    pub fn new(level: Level) -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { sender, level }
+    }
+
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var(LEVEL_ENV) {
+            Ok(raw) => raw
+                .trim()
+                .parse()
+                .map(Self::new)
+                .map_err(|_| format!("{} debe ser error, warn, info, debug o trace: '{}'", LEVEL_ENV, raw)),
+            Err(_) => Ok(Self::new(DEFAULT_LEVEL)),
+        }
+    }
+
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    /// Eventos desde `level`; los spans hasta INFO también, para heredar su `trace_id`
+    pub fn filter(&self) -> FilterFn<impl Fn(&Metadata<'_>) -> bool> {
+        let level = self.level;
+        filter_fn(move |metadata| {
+            if metadata.is_span() {
+                *metadata.level() <= Level::INFO
+            } else {
+                *metadata.level() <= level
+            }
+        })
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<LogRecord> {
+        self.sender.subscribe()
+    }
+}
+
+/// `trace_id` de la petición, guardado en las extensiones de su span
+struct TraceId(String);
+
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    trace_id: Option<String>,
+    fields: serde_json::Map<String, serde_json::Value>,
+}
+
+impl FieldVisitor {
+    fn insert(&mut self, field: &Field, value: serde_json::Value) {
+        match field.name() {
+            "message" => self.message = value.as_str().map(str::to_string).unwrap_or_else(|| value.to_string()),
+            "trace_id" => self.trace_id = Some(value.as_str().map(str::to_string).unwrap_or_else(|| value.to_string())),
+            name => {
+                self.fields.insert(name.to_string(), value);
+            }
+        }
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.insert(field, serde_json::json!(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, serde_json::json!(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, serde_json::json!(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, serde_json::json!(value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.insert(field, serde_json::json!(format!("{:?}", value)));
+    }
+}
+
+impl<S> Layer<S> for LogStream
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &tracing::span::Attributes<'_>, id: &tracing::span::Id, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        if let (Some(trace_id), Some(span)) = (visitor.trace_id, ctx.span(id)) {
+            span.extensions_mut().insert(TraceId(trace_id));
+        }
+    }
+
+    fn on_event(&self, event: &tracing::Event<'_>, ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        // Sin suscriptores no se formatea nada
+        if *metadata.level() > self.level || self.sender.receiver_count() == 0 {
+            return;
+        }
+
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let trace_id = visitor.trace_id.or_else(|| {
+            ctx.event_scope(event)?
+                .find_map(|span| span.extensions().get::<TraceId>().map(|t| t.0.clone()))
+        });
+
+        let _ = self.sender.send(LogRecord {
+            timestamp: chrono::Utc::now(),
+            level: metadata.level().to_string(),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+            trace_id,
+            fields: visitor.fields,
+        });
+    }
+}
+
+/// Abre un span `request` con el `trace_id` (el de `x-trace-id` o uno nuevo), registra
+/// el resultado de la petición y devuelve el `trace_id` en la respuesta
+pub async fn trace_requests(req: Request<Body>, next: Next) -> Response {
+    let trace_id = req
+        .headers()
+        .get(TRACE_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| uuid::Uuid::new_v4().simple().to_string());
+    let method = req.method().clone();
+    let path = req.uri().path().to_string();
+    let span = tracing::info_span!("request", trace_id = %trace_id, method = %method, path = %path);
+
+    let started = std::time::Instant::now();
+    let mut response = next.run(req).instrument(span.clone()).await;
+    let status = response.status().as_u16();
+    let elapsed_ms = started.elapsed().as_millis() as u64;
+
+    span.in_scope(|| match status {
+        500.. => tracing::error!(status, elapsed_ms, "{} {} → {}", method, path, status),
+        400..=499 => tracing::warn!(status, elapsed_ms, "{} {} → {}", method, path, status),
+        _ => tracing::info!(status, elapsed_ms, "{} {} → {}", method, path, status),
+    });
+
+    if let Ok(value) = HeaderValue::from_str(&trace_id) {
+        response.headers_mut().insert(TRACE_ID_HEADER, value);
+    }
+    response
+}
+
+/// Registros como eventos SSE `log`; si el suscriptor se retrasa se emite `lagged`
+pub fn sse(receiver: broadcast::Receiver<LogRecord>) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let stream = futures_util::stream::unfold(receiver, |mut receiver| async move {
+        let event = match receiver.recv().await {
+            Ok(record) => Event::default().event("log").json_data(&record).unwrap_or_default(),
+            Err(broadcast::error::RecvError::Lagged(skipped)) => Event::default().event("lagged").data(skipped.to_string()),
+            Err(broadcast::error::RecvError::Closed) => return None,
+        };
+        Some((Ok(event), receiver))
+    });
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn test_forwards_warn_with_trace_id() {
+        let stream = LogStream::new(Level::WARN);
+        let mut receiver = stream.subscribe();
+        let subscriber = tracing_subscriber::registry().with(stream.clone().with_filter(stream.filter()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("request", trace_id = "abc123");
+            let _guard = span.enter();
+            tracing::info!("no se reenvía");
+            tracing::warn!(status = 404, "GET /api/x → 404");
+        });
+
+        let record = receiver.try_recv().unwrap();
+        assert_eq!(record.level, "WARN");
+        assert_eq!(record.message, "GET /api/x → 404");
+        assert_eq!(record.trace_id.as_deref(), Some("abc123"));
+        assert_eq!(record.fields["status"], 404);
+        assert!(receiver.try_recv().is_err());
+    }
+}
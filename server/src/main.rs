@@ -1,10 +1,14 @@
 use axum::{
-    routing::{get, post, delete},
+    routing::{get, post},
     Router,
     Json,
-    extract::{Path, State},
+    body::Body,
+    extract::{Path, Query, State},
+    http::{HeaderMap, Request, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
 };
-use tower_http::{services::ServeDir, cors::{CorsLayer, Any}};
+use tower_http::{services::ServeDir, cors::{CorsLayer, Any}, compression::CompressionLayer};
 use std::sync::Arc;
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
@@ -13,16 +17,76 @@ use tokio::sync::{mpsc, RwLock};
 
 // Importar sistema de actores
 mod actors;
+mod api;
+mod audit;
+mod auth;
+mod calculator;
+mod clinical_alerts;
+mod critical_labs;
+mod etag;
+mod fhir;
 mod genesis;
-
-use actors::{GodName, ActorMessage, MessagePayload};
-use genesis::OlympusGenesis;
+mod i18n;
+mod identity_card;
+mod ids;
+mod import;
+mod log_stream;
+mod otp;
+mod pagination;
+mod patient_counts;
+mod patient_patch;
+mod reassessment;
+mod request_body;
+mod request_log;
+mod scale_cache;
+mod scales;
+mod static_files;
+mod trends;
+mod ventilation;
+mod version;
+
+use actors::{GodName, ActorMessage, HeartbeatConfig, HeartbeatRegistry, HestiaCache, HestiaStore, LoadShedding, MailboxMonitor, MessagePayload, RecoveryRegistry, ReplyLatencyMonitor, ResponsePayload, SurrealStore, TrinityMembers};
+use api::{ApiError, ApiResult};
+use genesis::{GenesisConfig, OlympusGenesis};
+use i18n::Lang;
+use olympus_core::apache::ApacheIIRequest;
+use olympus_core::patient::{AdmissionType, SkinColor};
+use olympus_core::saps::SAPSIIRequest;
+use olympus_core::vitals::{CompleteVitalSigns, VitalSignError};
+use pagination::{PageParams, Paginated};
 
 // Estado del servidor
 #[derive(Clone)]
 pub struct AppState {
     pub patients: Arc<RwLock<HashMap<String, serde_json::Value>>>,
+    pub assessments: Arc<RwLock<HashMap<String, serde_json::Value>>>,
+    pub vitals_observations: Arc<RwLock<HashMap<String, serde_json::Value>>>,
     pub god_senders: Arc<RwLock<HashMap<GodName, mpsc::Sender<ActorMessage>>>>,
+    pub genesis_config: Arc<GenesisConfig>,
+    pub hestia: HestiaStore,
+    pub mailboxes: MailboxMonitor,
+    pub heartbeats: HeartbeatRegistry,
+    /// Recuperaciones de Erinyes de los dioses que perdieron el heartbeat
+    pub recoveries: RecoveryRegistry,
+    /// Latencia de respuesta de cada dios a los comandos de la API
+    pub replies: ReplyLatencyMonitor,
+    pub trinity: TrinityMembers,
+    /// Descarga de carga que Zeus activa ante presión crítica de memoria informada por Demeter
+    pub load_shedding: LoadShedding,
+    pub auth: auth::AuthStore,
+    /// Secreto de firma de Hades; `/api/olympus/config` lo muestra redactado
+    pub jwt_secret: actors::JwtSecret,
+    /// Entrega de códigos OTP por Iris y si se exponen en la respuesta (solo desarrollo)
+    pub otp: otp::OtpDelivery,
+    pub log_stream: log_stream::LogStream,
+    /// Peticiones que modificaron datos, para `/api/nemesis/audit`
+    pub audit: audit::AuditLog,
+    /// Resultados recientes de escalas por hash de la entrada (caché volátil de Hestia)
+    pub scale_cache: scale_cache::ScaleCache,
+    /// Límite de cuerpo descomprimido (`OLYMPUS_MAX_BODY_BYTES`); la importación lo aplica al leerlo entero
+    pub max_body_bytes: usize,
+    /// Ids de pacientes, evaluaciones y observaciones (secuenciales en tests)
+    pub ids: Arc<dyn ids::IdGenerator>,
     pub start_time: std::time::Instant,
 }
 
@@ -34,6 +98,12 @@ pub struct Patient {
     pub last_name: String,
     pub identity_card: String,
     pub principal_diagnosis: String,
+    /// Variante de `olympus_core::patient::SkinColor`; sin ella se guarda la de por defecto
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skin_color: Option<String>,
+    /// Variante de `olympus_core::patient::AdmissionType`; la usa SAPS II
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admission_type: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,21 +122,194 @@ pub struct OtpRequest {
 pub struct AuthResponse {
     pub success: bool,
     pub token: Option<String>,
+    /// Identificador del login en curso, devuelto por el paso 1 para completar el OTP
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
     pub username: Option<String>,
+    /// Código OTP del paso 1; solo se incluye en desarrollo (OLYMPUS_ENV=development)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub otp_code: Option<String>,
+    /// El usuario entró con la contraseña por defecto y debe cambiarla antes de administrar
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub must_change_password: bool,
     pub message: String,
 }
 
 #[tokio::main]
 async fn main() {
-    // Inicializar tracing
-    tracing_subscriber::fmt::init();
+    // Inicializar tracing: consola (INFO+) y consola de depuración por SSE
+    // (OLYMPUS_LOG_STREAM_LEVEL, WARN+ por defecto)
+    let log_stream = match log_stream::LogStream::from_env() {
+        Ok(log_stream) => log_stream,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+    {
+        use tracing_subscriber::{filter::LevelFilter, prelude::*};
+        tracing_subscriber::registry()
+            .with(tracing_subscriber::fmt::layer().with_filter(LevelFilter::INFO))
+            .with(log_stream.clone().with_filter(log_stream.filter()))
+            .init();
+    }
     
-    println!("🏔️  OLYMPUS SYSTEM v15 - ACTOR SYSTEM  🏔️");
+    println!("🏔️  OLYMPUS SYSTEM {} - ACTOR SYSTEM  🏔️", version::display_version());
     println!("⚡  20 Divine Gods - OTP Architecture");
     println!("🚀  Integrando sistema de actores...");
 
-    // IGNICION: Iniciar los 20 dioses
-    let god_senders = match OlympusGenesis::ignite().await {
+    // Frontend compilado: configurable con OLYMPUS_DIST_DIR, omitible con --api-only
+    let api_only = std::env::args().any(|arg| arg == "--api-only");
+    let dist_dir = std::env::var("OLYMPUS_DIST_DIR").unwrap_or_else(|_| "dist".to_string());
+    if !api_only && !std::path::Path::new(&dist_dir).join("index.html").exists() {
+        eprintln!("❌ No se encontró el frontend compilado en '{}/'", dist_dir);
+        eprintln!("   Ejecuta `trunk build` en client/ para generarlo,");
+        eprintln!("   define OLYMPUS_DIST_DIR con la ruta correcta,");
+        eprintln!("   o arranca con --api-only para servir solo la API.");
+        std::process::exit(1);
+    }
+
+    // Cédulas: normalización (OLYMPUS_IDENTITY_CARD_NORMALIZE) y formato (OLYMPUS_IDENTITY_CARD_PATTERN)
+    match identity_card::IdentityCardPolicy::from_env() {
+        Ok(policy) => identity_card::configure(policy),
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    }
+
+    // Cuerpos gzip: límite sobre el tamaño descomprimido (OLYMPUS_MAX_BODY_BYTES)
+    let max_body_bytes = match request_body::max_bytes_from_env() {
+        Ok(max) => max,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // Registro de auditoría de peticiones: OLYMPUS_REQUEST_LOG=off|on|bodies
+    let request_log = match request_log::RequestLog::from_env() {
+        Ok(mode) => mode,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // OLYMPUS_MODE=calculator: solo escalas, sin Genesis, Hestia ni dioses
+    match calculator::RunMode::from_env() {
+        Ok(calculator::RunMode::Calculator) => {
+            println!("🧮 Modo calculadora: solo /api/scales/*, sin dioses ni persistencia");
+            let app = finish_router(calculator::router(), api_only, &dist_dir, max_body_bytes, request_log);
+            serve(app, api_only, &dist_dir).await;
+            return;
+        }
+        Ok(calculator::RunMode::Full) => {}
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    }
+
+    // Dioses habilitados: OLYMPUS_DISABLED_GODS=Chaos,Dionysus omite los indicados
+    let genesis_config = match GenesisConfig::from_env() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("❌ OLYMPUS_DISABLED_GODS inválido: {}", e);
+            std::process::exit(1);
+        }
+    };
+    for god in genesis_config.disabled() {
+        println!("⏸️  {} deshabilitado", god.as_str());
+    }
+
+    // Estado persistente de Hestia: configurable con OLYMPUS_DATA_DIR
+    let data_dir = std::env::var("OLYMPUS_DATA_DIR").unwrap_or_else(|_| "data".to_string());
+    let hestia = HestiaStore::open(std::path::Path::new(&data_dir).join("hestia.json"));
+
+    // Pacientes de Poseidon en SurrealDB: OLYMPUS_SURREAL_URL (por defecto en OLYMPUS_DATA_DIR)
+    let patient_store = match SurrealStore::from_env(&data_dir).await {
+        Ok(store) => store,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+    // Caché L1 de pacientes: parte de lo que ya está guardado
+    let patients: HashMap<String, serde_json::Value> = match patient_store.patients().await {
+        Ok(list) => list
+            .into_iter()
+            .filter_map(|p| Some((p.get("id")?.as_str()?.to_string(), p)))
+            .collect(),
+        Err(e) => {
+            eprintln!("❌ No se pudieron leer los pacientes de SurrealDB: {}", e);
+            std::process::exit(1);
+        }
+    };
+    println!("🌊 {} pacientes cargados de SurrealDB", patients.len());
+    // Las evaluaciones también: la fusión de pacientes las re-apunta en SurrealDB
+    let assessments: HashMap<String, serde_json::Value> = match patient_store.assessments().await {
+        Ok(list) => list
+            .into_iter()
+            .filter_map(|a| Some((a.get("id")?.as_str()?.to_string(), a)))
+            .collect(),
+        Err(e) => {
+            eprintln!("❌ No se pudieron leer las evaluaciones de SurrealDB: {}", e);
+            std::process::exit(1);
+        }
+    };
+    println!("🌊 {} evaluaciones cargadas de SurrealDB", assessments.len());
+
+    // IGNICION: Iniciar los dioses habilitados y registrarlos en Erinyes
+    let heartbeats = HeartbeatRegistry::new();
+    let recoveries = RecoveryRegistry::new();
+    let trinity = TrinityMembers::new();
+    // Ventana de respuesta de los dioses: OLYMPUS_REPLY_TIMEOUT_MS (2000 por defecto)
+    let replies = match ReplyLatencyMonitor::from_env() {
+        Ok(replies) => replies,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+    // Descarga de carga: OLYMPUS_LOAD_SHED_MEMORY_PCT y OLYMPUS_LOAD_SHED_SCAN_SECS
+    let load_shedding = match LoadShedding::from_env() {
+        Ok(load_shedding) => load_shedding,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+    // Credenciales (Argon2id; Hades y la API comparten el almacén); la política de contraseñas se lee de
+    // OLYMPUS_PASSWORD_MIN_LENGTH, OLYMPUS_PASSWORD_REQUIRED_CLASSES y OLYMPUS_PASSWORD_REJECT_COMMON.
+    // OLYMPUS_ADMIN_PASSWORD es obligatorio fuera de desarrollo y debe cumplir la política.
+    // Los hashes guardados en Hestia (contraseñas cambiadas, usuarios creados) prevalecen
+    let auth = match otp::Environment::from_env().and_then(|environment| {
+        let auth = auth::PasswordPolicy::from_env().and_then(|policy| auth::AuthStore::from_env(policy, environment))?;
+        auth.restore(&hestia.get(auth::CREDENTIALS_KEY).unwrap_or_default())?;
+        Ok(auth)
+    }) {
+        Ok(auth) => {
+            if auth.must_change_password(auth::DEFAULT_USERNAME) {
+                println!("⚠️ Credencial de desarrollo '{}' activa: cámbiala antes de administrar", auth::DEFAULT_USERNAME);
+            }
+            auth
+        }
+        Err(e) => {
+            eprintln!("❌ Configuración de credenciales inválida: {}", e);
+            std::process::exit(1);
+        }
+    };
+    // Secreto de Hades: OLYMPUS_JWT_SECRET u OLYMPUS_JWT_SECRET_FILE, obligatorio fuera de desarrollo
+    let jwt_secret = match otp::Environment::from_env().and_then(actors::JwtSecret::from_env) {
+        Ok(secret) => secret,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let god_senders = match OlympusGenesis::ignite(&genesis_config, hestia.clone(), heartbeats.clone(), recoveries.clone(), trinity.clone(), replies.clone(), load_shedding.clone(), auth.clone(), patient_store).await {
         Ok(senders) => {
             println!("✅ {} Dioses iniciados correctamente", senders.len());
             Arc::new(RwLock::new(senders))
@@ -77,18 +320,86 @@ async fn main() {
         }
     };
 
+    // Profundidad de los buzones: un dios con el buzón casi lleno pasa a Degraded
+    let mailboxes = MailboxMonitor::new();
+    tokio::spawn(actors::mailbox::watch(mailboxes.clone(), god_senders.clone()));
+    tokio::spawn(actors::reply_latency::watch(replies.clone()));
+    tokio::spawn(actors::load_shedding::watch(load_shedding.clone(), god_senders.clone()));
+
+    // Caché de resultados de escalas: OLYMPUS_SCALE_CACHE_TTL_MS (0 la desactiva)
+    let scale_cache = match scale_cache::ScaleCache::from_env(HestiaCache::new()) {
+        Ok(cache) => cache,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // Tamaño de página por defecto y máximo de los listados
+    match pagination::PageLimits::from_env() {
+        Ok(limits) => pagination::configure(limits),
+        Err(e) => {
+            eprintln!("❌ Paginación mal configurada: {}", e);
+            std::process::exit(1);
+        }
+    }
+
+    // Laboratorios críticos de APACHE/SAPS: OLYMPUS_CRITICAL_LABS ajusta la tabla por defecto
+    match critical_labs::CriticalLabTable::from_env() {
+        Ok(table) => critical_labs::configure(table),
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    }
+
+    // Códigos OTP: Iris los entrega por OLYMPUS_OTP_CHANNEL (obligatorio fuera de desarrollo);
+    // solo OLYMPUS_ENV=development los devuelve y sin OLYMPUS_ENV se asume producción
+    let otp = match otp::OtpDelivery::from_env() {
+        Ok(otp) => otp,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+    if otp.expose_in_response {
+        println!("⚠️  OTP incluido en la respuesta de login (OLYMPUS_ENV=development)");
+    }
+
+    // Recordatorios de reevaluación: OLYMPUS_REASSESSMENT_CADENCE_MIN y OLYMPUS_REASSESSMENT_SCAN_SECS
+    let reminders = match reassessment::ReminderConfig::from_env() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+
     // Estado compartido
     let state = AppState {
-        patients: Arc::new(RwLock::new(HashMap::new())),
+        patients: Arc::new(RwLock::new(patients)),
+        assessments: Arc::new(RwLock::new(assessments)),
+        vitals_observations: Arc::new(RwLock::new(HashMap::new())),
         god_senders,
+        genesis_config: Arc::new(genesis_config),
+        hestia,
+        mailboxes,
+        heartbeats,
+        recoveries,
+        replies,
+        trinity,
+        load_shedding,
+        auth,
+        jwt_secret,
+        otp,
+        log_stream,
+        audit: audit::AuditLog::new(),
+        scale_cache,
+        max_body_bytes,
+        ids: Arc::new(ids::UlidIds::new()),
         start_time: std::time::Instant::now(),
     };
-
-    // Configurar CORS
-    let cors = CorsLayer::new()
-        .allow_origin(Any)
-        .allow_methods(Any)
-        .allow_headers(Any);
+    tokio::spawn(reassessment::watch(state.clone(), reminders));
 
     // Crear router
     let app = Router::new()
@@ -96,34 +407,118 @@ async fn main() {
         .route("/api/login_step1", post(login_step1))
         .route("/api/login_step2", post(login_step2))
         .route("/api/logout", post(logout))
+        .route("/api/auth/change-password", post(change_password))
         // Pacientes (usa Poseidon)
         .route("/api/patients", get(get_patients).post(create_patient))
-        .route("/api/patients/:id", get(get_patient).delete(delete_patient))
+        .route("/api/patients/quick", post(quick_add_patient))
+        .route("/api/patients/count", get(count_patients))
+        .route("/api/patients/:id", get(get_patient).delete(delete_patient).patch(patch_patient))
+        .route("/api/patients/:id/history", get(get_patient_history))
+        .route("/api/patients/:id/history/export", get(export_patient_history))
+        .route("/api/patients/:id/fhir", get(export_patient_fhir))
+        .route("/api/patients/:id/vitals", get(get_vitals).post(record_vitals))
+        .route("/api/patients/:id/merge", post(merge_patients))
+        .route("/api/patients/:id/discharge", post(discharge_patient))
+        .route("/api/patients/:id/sofa/organ-trends", get(sofa_organ_trends))
+        .route("/api/patients/:id/scales/:scale/latest", get(latest_scale_assessment))
+        .route("/api/patients/:id/scales/:scale/slope", get(scale_trend_slope))
         // Escalas (usa Athena)
         .route("/api/scales/glasgow", post(calculate_glasgow))
         .route("/api/scales/sofa", post(calculate_sofa))
         .route("/api/scales/news2", post(calculate_news2))
+        .route("/api/scales/apache", post(calculate_apache))
+        .route("/api/scales/saps", post(calculate_saps))
+        .route("/api/scales/news2/chart-thresholds", get(news2_chart_thresholds))
+        .route("/api/scales/:scale/bands", get(scale_bands))
+        .route("/api/scales/compare", post(compare_scales))
+        .route("/api/assessments/import", post(import_assessments))
+        // Alertas clínicas
+        .route("/api/clinical-alerts", get(list_clinical_alerts).post(create_clinical_alert))
+        .route("/api/clinical-alerts/:id/acknowledge", post(acknowledge_clinical_alert))
+        .route("/api/clinical-alerts/:id/resolve", post(resolve_clinical_alert))
+        // Herramientas clínicas
+        .route("/api/tools/tidal-volume", post(calculate_tidal_volume))
         // Monitoreo (usa Zeus y Erinyes)
         .route("/api/status", get(api_status))
+        .route("/api/version", get(api_version))
         .route("/api/olympus/gods", get(api_gods))
+        .route("/api/olympus/gods/enabled", get(api_gods_enabled))
+        .route("/api/olympus/gods/:name", get(api_god))
         .route("/api/olympus/trinity", get(api_trinity))
+        .route("/api/olympus/trinity/members", get(get_trinity_members).put(set_trinity_members))
+        .route("/api/olympus/health", get(api_olympus_health))
+        .route("/api/olympus/config", get(api_olympus_config))
+        .route("/api/olympus/config/:key", get(api_olympus_config_key))
+        .route("/api/erinyes/recoveries", get(list_recoveries))
+        .route("/api/erinyes/recoveries/:id/cancel", post(cancel_recovery))
         .route("/api/admin/stats", get(api_stats))
+        // Analítica (usa Dionysus)
+        .route("/api/dionysus/analytics", get(dionysus_analytics))
+        .route("/api/admin/users", post(create_user))
+        .route("/api/admin/logs/stream", get(stream_logs))
+        // Auditoría (Nemesis)
+        .route("/api/nemesis/audit", get(list_audit))
         // UI/Temas (usa Aphrodite - Diosa de la Belleza)
         .route("/api/aphrodite/theme", get(get_current_theme).post(switch_theme))
         .route("/api/aphrodite/themes", get(get_all_themes))
         .route("/api/aphrodite/css", get(get_css_variables))
         .route("/api/aphrodite/components", get(get_components).post(update_component))
-        // Archivos estáticos
-        .fallback_service(ServeDir::new("dist"))
+        // Rutas de dioses deshabilitados responden 503
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_enabled_god))
+        // Durante la descarga de carga las rutas no esenciales también
+        .route_layer(middleware::from_fn_with_state(state.clone(), shed_heavy_load))
+        // Por fuera de las anteriores, para auditar también las peticiones rechazadas
+        .route_layer(middleware::from_fn_with_state(state.clone(), audit_mutations));
+
+    let enabled_gods = state.genesis_config.enabled().len();
+    let app = finish_router(app.with_state(state), api_only, &dist_dir, max_body_bytes, request_log);
+
+    println!("⚡ Zeus supervisando {} dioses", enabled_gods - 1);
+    serve(app, api_only, &dist_dir).await;
+}
+
+/// Capas comunes a todos los modos: archivos estáticos, límite de cuerpo, trazas, auditoría y CORS
+fn finish_router(
+    app: Router,
+    api_only: bool,
+    dist_dir: &str,
+    max_body_bytes: usize,
+    request_log: request_log::RequestLog,
+) -> Router {
+    // Archivos estáticos: comprimidos (gzip/br) y con caché según si llevan hash
+    let app = if api_only {
+        app
+    } else {
+        let static_files = Router::new()
+            .fallback_service(ServeDir::new(dist_dir))
+            .layer(middleware::from_fn(static_files::cache_headers))
+            .layer(CompressionLayer::new());
+        app.fallback_service(static_files)
+    };
+
+    // Configurar CORS
+    let cors = CorsLayer::new()
+        .allow_origin(Any)
+        .allow_methods(Any)
+        .allow_headers(Any);
+
+    // El registro de auditoría queda por dentro del límite de cuerpo y de la descompresión
+    let app = request_log::apply(app, request_log);
+    request_body::apply(app, max_body_bytes)
+        .layer(middleware::from_fn(log_stream::trace_requests))
         .layer(cors)
-        .with_state(state);
+}
 
+async fn serve(app: Router, api_only: bool, dist_dir: &str) {
     let addr = "127.0.0.1:3000";
     let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
-    
-    println!("🚀 Servidor Axum + Actores corriendo en http://{}", addr);
-    println!("📁 Sirviendo archivos estáticos desde dist/");
-    println!("⚡ Zeus supervisando {} dioses", 20);
+
+    println!("🚀 Servidor Axum corriendo en http://{}", addr);
+    if api_only {
+        println!("📁 Modo --api-only: sin archivos estáticos");
+    } else {
+        println!("📁 Sirviendo archivos estáticos desde {}/", dist_dir);
+    }
 
     axum::serve(listener, app).await.unwrap();
 }
@@ -133,7 +528,7 @@ async fn main() {
 async fn login_step1(
     State(state): State<AppState>,
     Json(req): Json<AuthRequest>,
-) -> Json<AuthResponse> {
+) -> ApiResult<AuthResponse> {
     // Enviar mensaje a Hades para autenticar
     let msg = ActorMessage::new(
         GodName::Zeus,
@@ -149,33 +544,58 @@ async fn login_step1(
 
     // En una implementación completa, esperaríamos respuesta async
     // Por ahora, simulamos la respuesta
-    let senders = state.god_senders.read().await;
-    if let Some(hades_tx) = senders.get(&GodName::Hades) {
-        let _ = hades_tx.send(msg).await;
+    if let Some(unavailable) = send_to_god(&state, GodName::Hades, msg).await {
+        return Err(unavailable);
     }
 
-    // Simular respuesta
-    if req.username == "admin" && req.password == "admin123" {
-        Json(AuthResponse {
-            success: true,
-            token: None,
-            username: Some(req.username),
-            message: "Código OTP enviado: 123456".to_string(),
-        })
-    } else {
-        Json(AuthResponse {
-            success: false,
-            token: None,
-            username: None,
-            message: "Credenciales inválidas".to_string(),
-        })
+    if !state.auth.verify(&req.username, &req.password) {
+        return Err(ApiError::unauthorized("Credenciales inválidas"));
+    }
+
+    // Iris entrega el código; el mensaje a Iris no lo incluye
+    let code = otp::generate_code();
+    let session_id = state.auth.begin_login(&req.username, &code);
+    let deliver = ActorMessage::new(
+        GodName::Zeus,
+        GodName::Iris,
+        MessagePayload::Command {
+            action: "deliver_otp".to_string(),
+            data: json!({
+                "username": req.username,
+                "channel": state.otp.channel.as_str(),
+            }),
+        }
+    );
+    if let Some(unavailable) = send_to_god(&state, GodName::Iris, deliver).await {
+        state.auth.abort_login(&session_id);
+        return Err(unavailable);
     }
+    if let Err(e) = state.otp.deliver(&req.username, &code).await {
+        tracing::error!("📨 {}", e);
+        state.auth.abort_login(&session_id);
+        return Err(ApiError::internal("No se pudo enviar el código OTP"));
+    }
+
+    let (otp_code, message) = if state.otp.expose_in_response {
+        (Some(code.clone()), format!("Código OTP (desarrollo): {}", code))
+    } else {
+        (None, format!("Código OTP enviado por Iris ({})", state.otp.channel.as_str()))
+    };
+    Ok(Json(AuthResponse {
+        success: true,
+        token: None,
+        session_id: Some(session_id),
+        username: Some(req.username),
+        otp_code,
+        must_change_password: false,
+        message,
+    }))
 }
 
 async fn login_step2(
     State(state): State<AppState>,
     Json(req): Json<OtpRequest>,
-) -> Json<AuthResponse> {
+) -> ApiResult<AuthResponse> {
     let msg = ActorMessage::new(
         GodName::Zeus,
         GodName::Hades,
@@ -183,368 +603,1787 @@ async fn login_step2(
             action: "verify_otp".to_string(),
             data: json!({
                 "otp_code": req.otp_code,
-                "username": "admin",
+                "session_id": req.session_id,
             }),
         }
     );
 
-    let senders = state.god_senders.read().await;
-    if let Some(hades_tx) = senders.get(&GodName::Hades) {
-        let _ = hades_tx.send(msg).await;
+    // Hades valida el código contra el login pendiente y emite la sesión
+    match ask_god(&state, GodName::Hades, msg).await? {
+        ResponsePayload { success: true, data, .. } => {
+            let username = data["username"].as_str().unwrap_or_default().to_string();
+            Ok(Json(AuthResponse {
+                success: true,
+                token: data["token"].as_str().map(str::to_string),
+                session_id: None,
+                must_change_password: state.auth.must_change_password(&username),
+                username: Some(username),
+                otp_code: None,
+                message: "¡Zeus aprueba tu acceso!".to_string(),
+            }))
+        }
+        ResponsePayload { data, .. } if data["reason"] == "invalid_otp" => Err(auth::AuthError::InvalidOtp.into()),
+        _ => Err(ApiError::unauthorized("Sesión de login inválida o expirada")),
     }
+}
 
-    if req.otp_code == "123456" {
-        Json(AuthResponse {
-            success: true,
-            token: Some("jwt_token_olympus_2026".to_string()),
-            username: Some("admin".to_string()),
-            message: "¡Zeus aprueba tu acceso!".to_string(),
-        })
-    } else {
-        Json(AuthResponse {
-            success: false,
-            token: None,
-            username: None,
-            message: "Código OTP inválido".to_string(),
-        })
+async fn logout(State(state): State<AppState>, headers: HeaderMap) -> Json<AuthResponse> {
+    if let Some(token) = auth::bearer_token(&headers) {
+        state.auth.logout(token);
     }
-}
 
-async fn logout() -> Json<AuthResponse> {
     Json(AuthResponse {
         success: true,
         token: None,
+        session_id: None,
         username: None,
+        otp_code: None,
+        must_change_password: false,
         message: "Sesión cerrada - Hades protege tu salida".to_string(),
     })
 }
 
-// === PACIENTES (Poseidon) ===
+#[derive(Debug, Deserialize)]
+struct ChangePasswordRequest {
+    current_password: String,
+    new_password: String,
+}
+
+/// Cambia la contraseña del usuario del token y cierra sus demás sesiones
+async fn change_password(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<ChangePasswordRequest>,
+) -> ApiResult<api::PasswordChangedResponse> {
+    let token = auth::bearer_token(&headers).ok_or(auth::AuthError::Unauthenticated)?;
+    let (username, revoked) = state.auth.change_password(token, &req.current_password, &req.new_password)?;
+    save_credentials(&state).await?;
 
-async fn get_patients(State(state): State<AppState>) -> Json<serde_json::Value> {
-    // Enviar mensaje a Poseidon
     let msg = ActorMessage::new(
         GodName::Zeus,
-        GodName::Poseidon,
-        MessagePayload::Query {
-            query_type: "get_patients".to_string(),
-            params: json!({}),
+        GodName::Hades,
+        MessagePayload::Command {
+            action: "password_changed".to_string(),
+            data: json!({ "username": username, "revoked_sessions": revoked }),
         }
     );
-
-    let senders = state.god_senders.read().await;
-    if let Some(poseidon_tx) = senders.get(&GodName::Poseidon) {
-        let _ = poseidon_tx.send(msg).await;
+    // La contraseña ya cambió: el aviso a Hades es informativo y no revierte ni falla el cambio
+    if send_to_god(&state, GodName::Hades, msg).await.is_some() {
+        tracing::warn!("🔐 Hades no recibió el cambio de contraseña de '{}'", username);
     }
 
-    // Por ahora, leer de memoria
-    let patients = state.patients.read().await;
-    let list: Vec<_> = patients.values().cloned().collect();
-    Json(json!({ "patients": list }))
+    Ok(Json(api::PasswordChangedResponse {
+        success: true,
+        revoked_sessions: revoked,
+        message: "Contraseña actualizada".to_string(),
+    }))
 }
 
-async fn get_patient(
+/// Hashes a Hestia; sin esto un reinicio devolvería la contraseña anterior y perdería los usuarios.
+/// La instantánea se toma bajo el bloqueo de Hestia: la última escritura lleva el último cambio
+async fn save_credentials(state: &AppState) -> Result<(), ApiError> {
+    let auth = state.auth.clone();
+    state
+        .hestia
+        .update(auth::CREDENTIALS_KEY, move |stored| *stored = auth.credentials())
+        .await
+        .map_err(|e| ApiError::internal("No se pudieron guardar las credenciales").with("message", e))
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateUserRequest {
+    username: String,
+    password: String,
+}
+
+/// Alta de usuario (solo administrador) sujeta a la política de contraseñas
+async fn create_user(
     State(state): State<AppState>,
-    Path(id): Path<String>,
-) -> Json<serde_json::Value> {
-    let patients = state.patients.read().await;
-    match patients.get(&id) {
-        Some(p) => Json(json!({ "patient": p })),
-        None => Json(json!({ "error": "Paciente no encontrado" })),
+    headers: HeaderMap,
+    Json(req): Json<CreateUserRequest>,
+) -> Result<(StatusCode, Json<api::UserCreatedResponse>), ApiError> {
+    if auth::bearer_token(&headers).and_then(|token| state.auth.admin_session(token)).is_none() {
+        return Err(ApiError::unauthorized("Solo el administrador puede crear usuarios"));
+    }
+    let username = req.username.trim();
+    if username.is_empty() {
+        return Err(ApiError::bad_request("username vacío"));
     }
+
+    state.auth.create_user(username, &req.password)?;
+    save_credentials(&state).await?;
+    tracing::info!("🔐 Usuario '{}' creado", username);
+    Ok((StatusCode::CREATED, Json(api::UserCreatedResponse { success: true, username: username.to_string() })))
 }
 
-async fn create_patient(
+// === PACIENTES (Poseidon) ===
+
+async fn get_patients(
     State(state): State<AppState>,
-    Json(patient): Json<Patient>,
-) -> Json<serde_json::Value> {
-    let id = uuid::Uuid::new_v4().to_string();
-    
-    // Enviar a Poseidon
+    page: PageParams,
+) -> ApiResult<api::PatientsPage> {
+    // Enviar mensaje a Poseidon
     let msg = ActorMessage::new(
         GodName::Zeus,
         GodName::Poseidon,
-        MessagePayload::Command {
-            action: "create_patient".to_string(),
-            data: json!({
-                "id": &id,
-                "first_name": &patient.first_name,
-                "last_name": &patient.last_name,
-                "identity_card": &patient.identity_card,
-                "principal_diagnosis": &patient.principal_diagnosis,
-            }),
+        MessagePayload::Query {
+            query_type: "get_patients".to_string(),
+            params: json!({}),
         }
     );
 
-    let senders = state.god_senders.read().await;
-    if let Some(poseidon_tx) = senders.get(&GodName::Poseidon) {
-        let _ = poseidon_tx.send(msg).await;
-    }
+    let mut data = ask_poseidon(&state, msg).await?;
+    let mut list: Vec<serde_json::Value> = serde_json::from_value(data["patients"].take()).unwrap_or_default();
+    list.retain(|p| !p.get("deleted").and_then(|d| d.as_bool()).unwrap_or(false));
+    // Los ids son ULID: ordenar por id es ordenar por fecha de creación
+    list.sort_by(|a, b| a["id"].as_str().cmp(&b["id"].as_str()));
+    Ok(Json(api::PatientsPage::from(page.apply(list))))
+}
 
-    // Guardar en memoria
-    let patient_json = json!({
-        "id": id,
-        "first_name": patient.first_name,
-        "last_name": patient.last_name,
-        "identity_card": patient.identity_card,
-        "principal_diagnosis": patient.principal_diagnosis,
-    });
-    
-    state.patients.write().await.insert(id.clone(), patient_json.clone());
-    
-    Json(json!({ 
-        "success": true, 
-        "id": id,
-        "message": "Paciente creado exitosamente",
-        "patient": patient_json
-    }))
+#[derive(Debug, Deserialize)]
+struct PatientCountQuery {
+    /// `admission_type` añade el desglose por tipo de ingreso
+    by: Option<String>,
 }
 
-async fn delete_patient(
+// Recuentos por estado para el panel y monitores externos, sin transferir registros
+async fn count_patients(
     State(state): State<AppState>,
-    Path(id): Path<String>,
-) -> Json<serde_json::Value> {
-    // Enviar a Poseidon
+    Query(query): Query<PatientCountQuery>,
+) -> ApiResult<patient_counts::PatientCounts> {
+    let by_admission_type = match query.by.as_deref() {
+        None => false,
+        Some("admission_type") => true,
+        Some(other) => {
+            return Err(ApiError::bad_request(format!("Desglose no soportado: '{}' (use admission_type)", other)));
+        }
+    };
+
     let msg = ActorMessage::new(
         GodName::Zeus,
         GodName::Poseidon,
-        MessagePayload::Command {
-            action: "delete_patient".to_string(),
-            data: json!({ "id": &id }),
+        MessagePayload::Query {
+            query_type: "count_patients".to_string(),
+            params: json!({}),
         }
     );
+    let mut data = ask_poseidon(&state, msg).await?;
+    let groups: Vec<patient_counts::CountGroup> = serde_json::from_value(data["groups"].take())
+        .map_err(|e| storage_error(Some(format!("Recuento de pacientes no válido: {}", e))))?;
+    Ok(Json(patient_counts::from_groups(&groups, by_admission_type)))
+}
 
-    let senders = state.god_senders.read().await;
-    if let Some(poseidon_tx) = senders.get(&GodName::Poseidon) {
-        let _ = poseidon_tx.send(msg).await;
+async fn get_patient(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let cached = state.patients.read().await.get(&id).cloned();
+    let patient = match cached {
+        Some(patient) => Some(patient),
+        None => {
+            let msg = ActorMessage::new(
+                GodName::Zeus,
+                GodName::Poseidon,
+                MessagePayload::Query {
+                    query_type: "get_patient".to_string(),
+                    params: json!({ "id": &id }),
+                }
+            );
+            let mut data = ask_poseidon(&state, msg).await?;
+            let patient = Some(data["patient"].take()).filter(|p| !p.is_null());
+            if let Some(patient) = &patient {
+                state.patients.write().await.insert(id.clone(), patient.clone());
+            }
+            patient
+        }
+    };
+    let patient = patient.ok_or_else(|| ApiError::not_found("Paciente no encontrado"))?;
+    // Eliminado o fusionado: el registro se conserva pero ya no se sirve
+    if patient.get("deleted").and_then(|d| d.as_bool()).unwrap_or(false) {
+        let error = ApiError::not_found("Paciente no encontrado");
+        return Err(match patient.get("merged_into") {
+            Some(merged_into) => error.with("merged_into", merged_into),
+            None => error,
+        });
     }
-
-    state.patients.write().await.remove(&id);
-    
-    Json(json!({ 
-        "success": true, 
-        "message": "Paciente eliminado exitosamente" 
-    }))
+    Ok(etag::json_with_etag(&headers, &json!({ "patient": patient })))
 }
 
-// === ESCALAS (Athena) ===
+// Historial de evaluaciones del paciente (por defecto de la más antigua a la más reciente)
+async fn get_patient_history(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    page: PageParams,
+    headers: HeaderMap,
+) -> Response {
+    let assessments = state.assessments.read().await;
+    let history: Vec<serde_json::Value> = assessments
+        .values()
+        .filter(|a| a.get("patient_id").and_then(|v| v.as_str()) == Some(id.as_str()))
+        .cloned()
+        .collect();
+
+    let mut body = json!(page.sort_or("assessed_at").apply(history));
+    body["patient_id"] = json!(id);
+    // `assessments` repite `items` para los clientes anteriores a la paginación
+    body["assessments"] = body["items"].clone();
+    etag::json_with_etag(&headers, &body)
+}
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GlasgowRequest {
-    pub patient_id: String,
-    pub eye: i32,
-    pub verbal: i32,
-    pub motor: i32,
+// Historial como CSV, de la más antigua a la más reciente; cada evaluación se lee como `dyn ScaleResult`
+async fn export_patient_history(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Response {
+    let assessments = state.assessments.read().await;
+    let mut history: Vec<&serde_json::Value> = assessments
+        .values()
+        .filter(|a| a.get("patient_id").and_then(|v| v.as_str()) == Some(id.as_str()))
+        .collect();
+    history.sort_by_key(|a| a.get("assessed_at").and_then(|v| v.as_str()).unwrap_or_default().to_string());
+
+    let results: Vec<(&str, scales::StoredResult)> = history
+        .iter()
+        .filter_map(|a| Some((a.get("assessed_at")?.as_str()?, scales::StoredResult::new(a)?)))
+        .collect();
+    let rows: Vec<(&str, &dyn scales::ScaleResult)> = results
+        .iter()
+        .map(|(assessed_at, result)| (*assessed_at, result as &dyn scales::ScaleResult))
+        .collect();
+
+    ([(axum::http::header::CONTENT_TYPE, "text/csv; charset=utf-8")], scales::export_csv(&rows)).into_response()
 }
 
-async fn calculate_glasgow(
+// Última evaluación del paciente en una escala; `:scale` admite alias (gcs, news-2...)
+async fn latest_scale_assessment(
     State(state): State<AppState>,
-    Json(req): Json<GlasgowRequest>,
-) -> Json<serde_json::Value> {
-    let msg = ActorMessage::new(
-        GodName::Zeus,
-        GodName::Athena,
-        MessagePayload::Command {
-            action: "calculate_glasgow".to_string(),
-            data: json!({
-                "eye": req.eye,
-                "verbal": req.verbal,
-                "motor": req.motor,
-            }),
+    Path((id, scale)): Path<(String, String)>,
+) -> Response {
+    let scale: scales::Scale = match scale.parse() {
+        Ok(scale) => scale,
+        Err(error) => {
+            return (StatusCode::NOT_FOUND, Json(json!({ "success": false, "error": error }))).into_response();
         }
-    );
+    };
 
-    let senders = state.god_senders.read().await;
-    if let Some(athena_tx) = senders.get(&GodName::Athena) {
-        let _ = athena_tx.send(msg).await;
+    let assessments = state.assessments.read().await;
+    let latest = assessments
+        .values()
+        .filter(|a| a.get("patient_id").and_then(|v| v.as_str()) == Some(id.as_str()))
+        .filter(|a| a.get("scale").and_then(|v| v.as_str()) == Some(scale.as_str()))
+        .max_by_key(|a| a.get("assessed_at").and_then(|v| v.as_str()).unwrap_or_default().to_string());
+
+    match latest {
+        Some(assessment) => Json(json!({
+            "success": true,
+            "scale": scale.as_str(),
+            "patient_id": id,
+            "assessment": assessment,
+        }))
+        .into_response(),
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(json!({
+                "success": false,
+                "error": format!("Sin evaluaciones {} para el paciente", scale.as_str()),
+            })),
+        )
+            .into_response(),
     }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SlopeQuery {
+    pub window_hours: Option<f64>,
+}
+
+// Pendiente (puntos/hora) de la escala en las últimas `window_hours` (24 por defecto)
+async fn scale_trend_slope(
+    State(state): State<AppState>,
+    Path((id, scale)): Path<(String, String)>,
+    Query(query): Query<SlopeQuery>,
+) -> Response {
+    let scale: scales::Scale = match scale.parse() {
+        Ok(scale) => scale,
+        Err(error) => {
+            return (StatusCode::NOT_FOUND, Json(json!({ "success": false, "error": error }))).into_response();
+        }
+    };
+    let window_hours = query.window_hours.unwrap_or(trends::DEFAULT_WINDOW_HOURS);
+    // Una ventana enorme haría desbordar la resta de fechas: se rechaza en vez de entrar en pánico
+    let Some(since) = trends::window_start(chrono::Utc::now(), window_hours) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "success": false, "error": "'window_hours' debe ser un número positivo dentro del rango de fechas" })),
+        )
+            .into_response();
+    };
 
-    // Calcular respuesta
-    let total = req.eye + req.verbal + req.motor;
-    let interpretation = match total {
-        3..=8 => "Coma severo",
-        9..=12 => "Coma moderado",
-        13..=15 => "Coma leve/Normal",
-        _ => "Error",
+    let mut points: Vec<(chrono::DateTime<chrono::Utc>, f64)> = state
+        .assessments
+        .read()
+        .await
+        .values()
+        .filter(|a| a.get("patient_id").and_then(|v| v.as_str()) == Some(id.as_str()))
+        .filter(|a| a.get("scale").and_then(|v| v.as_str()) == Some(scale.as_str()))
+        .filter_map(|a| {
+            let at = chrono::DateTime::parse_from_rfc3339(a.get("assessed_at")?.as_str()?).ok()?;
+            Some((at.with_timezone(&chrono::Utc), a.get("total")?.as_f64()?))
+        })
+        .filter(|(at, _)| *at >= since)
+        .collect();
+    points.sort_by_key(|(at, _)| *at);
+
+    let Some(slope) = trends::slope_per_hour(&points) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(json!({
+                "success": false,
+                "error": format!("Se requieren al menos dos evaluaciones {} en momentos distintos dentro de la ventana", scale.as_str()),
+                "points": points.len(),
+            })),
+        )
+            .into_response();
     };
 
     Json(json!({
         "success": true,
-        "scale": "Glasgow",
-        "patient_id": req.patient_id,
-        "eye": req.eye,
-        "verbal": req.verbal,
-        "motor": req.motor,
-        "total": total,
-        "interpretation": interpretation,
-        "calculated_by": "Athena"
+        "patient_id": id,
+        "scale": scale.as_str(),
+        "window_hours": window_hours,
+        "points": points.len(),
+        "from": points.first().map(|(at, _)| at),
+        "to": points.last().map(|(at, _)| at),
+        "slope_per_hour": slope,
+        "direction": trends::direction(scale, slope),
     }))
+    .into_response()
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SofaRequest {
-    pub patient_id: String,
-    pub respiratory: i32,
+// Paciente y sus evaluaciones como Bundle estilo FHIR (forma documentada en fhir.rs)
+async fn export_patient_fhir(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Response {
+    let Some(patient) = state.patients.read().await.get(&id).cloned() else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(json!({ "success": false, "error": "Paciente no encontrado" })),
+        )
+            .into_response();
+    };
+
+    let assessments: Vec<serde_json::Value> = state
+        .assessments
+        .read()
+        .await
+        .values()
+        .filter(|a| a.get("patient_id").and_then(|v| v.as_str()) == Some(id.as_str()))
+        .cloned()
+        .collect();
+
+    (
+        [(axum::http::header::CONTENT_TYPE, "application/fhir+json")],
+        Json(fhir::patient_bundle(&patient, &assessments)),
+    )
+        .into_response()
+}
+
+// Signos vitales crudos (sin escala); alimentan el prellenado de NEWS2 y otras escalas
+async fn record_vitals(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(mut vitals): Json<scales::Vitals>,
+) -> Response {
+    if !state.patients.read().await.contains_key(&id) {
+        return Json(json!({
+            "success": false,
+            "error": "Paciente no encontrado",
+        }))
+        .into_response();
+    }
+    if let Err(errors) = vitals.vital_signs.validate() {
+        return invalid_vitals(&errors);
+    }
+
+    vitals.patient_id = id;
+    let obs_id = state.ids.next_id("vitals");
+    let mut observation = serde_json::to_value(&vitals).unwrap_or_else(|_| json!({}));
+    observation["id"] = json!(&obs_id);
+    observation["recorded_at"] = json!(chrono::Utc::now().to_rfc3339());
+
+    state.vitals_observations.write().await.insert(obs_id, observation.clone());
+
+    Json(json!({
+        "success": true,
+        "observation": observation,
+    }))
+    .into_response()
+}
+
+// Observaciones del paciente, de la más reciente a la más antigua
+async fn get_vitals(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Json<serde_json::Value> {
+    let observations = state.vitals_observations.read().await;
+    let mut list: Vec<&serde_json::Value> = observations
+        .values()
+        .filter(|o| o.get("patient_id").and_then(|v| v.as_str()) == Some(id.as_str()))
+        .collect();
+    list.sort_by_key(|o| std::cmp::Reverse(o.get("recorded_at").and_then(|v| v.as_str()).unwrap_or_default().to_string()));
+
+    Json(json!({
+        "patient_id": id,
+        "latest": list.first(),
+        "observations": list,
+    }))
+}
+
+async fn create_patient(
+    State(state): State<AppState>,
+    Json(mut patient): Json<Patient>,
+) -> ApiResult<api::PatientResponse> {
+    let empty: serde_json::Map<String, serde_json::Value> = [
+        ("first_name", &patient.first_name),
+        ("last_name", &patient.last_name),
+        ("principal_diagnosis", &patient.principal_diagnosis),
+    ]
+    .into_iter()
+    .filter(|(_, value)| value.trim().is_empty())
+    .map(|(field, _)| (field.to_string(), json!("Campo obligatorio")))
+    .collect();
+    if !empty.is_empty() {
+        let fields: Vec<&str> = empty.keys().map(String::as_str).collect();
+        return Err(ApiError::unprocessable(format!("Campos obligatorios vacíos: {}", fields.join(", "))).with("errors", &empty));
+    }
+
+    let choices = json!({
+        "skin_color": patient.skin_color.map_or_else(|| json!(SkinColor::default()), |v| json!(v)),
+        "admission_type": patient.admission_type.map_or_else(|| json!(AdmissionType::default()), |v| json!(v)),
+    });
+    if let Some(invalid) = invalid_patient_choices(&choices) {
+        return Err(invalid);
+    }
+
+    patient.identity_card = identity_card::policy().canonical(&patient.identity_card).map_err(invalid_identity_card)?;
+    if let Some(duplicate) = duplicate_identity_card(&*state.patients.read().await, &patient.identity_card, None) {
+        return Err(duplicate);
+    }
+
+    // Sin id: lo asigna SurrealDB al crear el registro
+    let mut patient_json = json!({
+        "first_name": patient.first_name,
+        "last_name": patient.last_name,
+        "identity_card": patient.identity_card,
+        "principal_diagnosis": patient.principal_diagnosis,
+        "skin_color": choices["skin_color"],
+        "admission_type": choices["admission_type"],
+    });
+    patient_patch::touch(&mut patient_json);
+
+    // Poseidon lo guarda en SurrealDB; solo entonces entra en la caché. El bloqueo de
+    // escritura no se mantiene durante la ida y vuelta al actor
+    let msg = ActorMessage::new(
+        GodName::Zeus,
+        GodName::Poseidon,
+        MessagePayload::Command {
+            action: "create_patient".to_string(),
+            data: patient_json,
+        }
+    );
+    let mut created = ask_poseidon(&state, msg).await?;
+    let patient_json = created["patient"].take();
+    let Some(id) = patient_json.get("id").and_then(|id| id.as_str()).map(str::to_string) else {
+        return Err(storage_error(Some("Poseidon no devolvió el id del paciente".to_string())));
+    };
+
+    // Se vuelve a comprobar con el bloqueo de escritura: si otra alta simultánea con la
+    // misma cédula entró primero, este registro se retira de SurrealDB
+    let mut patients = state.patients.write().await;
+    if let Some(duplicate) = duplicate_identity_card(&patients, &patient.identity_card, None) {
+        drop(patients);
+        let rollback = ActorMessage::new(
+            GodName::Zeus,
+            GodName::Poseidon,
+            MessagePayload::Command {
+                action: "discard_patient".to_string(),
+                data: json!({ "id": &id }),
+            }
+        );
+        ask_poseidon(&state, rollback).await?;
+        return Err(duplicate);
+    }
+    patients.insert(id.clone(), patient_json.clone());
+    drop(patients);
+
+    Ok(Json(api::PatientResponse {
+        success: true,
+        id: Some(id),
+        message: "Paciente creado exitosamente".to_string(),
+        patient: patient_json,
+    }))
+}
+
+// `errors` asocia cada mensaje a su campo para que el formulario lo muestre junto a él
+fn invalid_identity_card(error: String) -> ApiError {
+    ApiError::unprocessable(&error).with("errors", json!({ "identity_card": error }))
+}
+
+/// `skin_color` y `admission_type` deben ser variantes de los enums de olympus-core
+fn invalid_patient_choices(patient: &serde_json::Value) -> Option<ApiError> {
+    let mut errors = serde_json::Map::new();
+    check_choice(patient, "skin_color", &SkinColor::ALL, &mut errors);
+    check_choice(patient, "admission_type", &AdmissionType::ALL, &mut errors);
+    if errors.is_empty() {
+        return None;
+    }
+    let fields: Vec<&str> = errors.keys().map(String::as_str).collect();
+    Some(ApiError::unprocessable(format!("Valores no válidos: {}", fields.join(", "))).with("errors", &errors))
+}
+
+fn check_choice<T: serde::de::DeserializeOwned + Serialize>(
+    patient: &serde_json::Value,
+    field: &str,
+    options: &[T],
+    errors: &mut serde_json::Map<String, serde_json::Value>,
+) {
+    let Some(value) = patient.get(field) else {
+        return;
+    };
+    if serde_json::from_value::<T>(value.clone()).is_err() {
+        let names: Vec<String> = options.iter().map(|o| json!(o).as_str().unwrap_or_default().to_string()).collect();
+        errors.insert(field.to_string(), json!(format!("Valor no válido {}; use {}", value, names.join(", "))));
+    }
+}
+
+// Otro paciente activo con la misma cédula normalizada
+fn duplicate_identity_card(
+    patients: &HashMap<String, serde_json::Value>,
+    card: &str,
+    exclude_id: Option<&str>,
+) -> Option<ApiError> {
+    let existing = identity_card::find_duplicate(patients.values(), card, exclude_id)?;
+    let error = format!("Ya existe un paciente con la cédula {}", card);
+    Some(
+        ApiError::conflict(&error)
+            .with("errors", json!({ "identity_card": error }))
+            .with("duplicate_id", existing.get("id")),
+    )
+}
+
+/// Ingreso rápido (admisión de emergencia): solo nombre y diagnóstico
+#[derive(Debug, Clone, Deserialize)]
+pub struct QuickPatientRequest {
+    pub first_name: String,
+    pub last_name: String,
+    pub principal_diagnosis: String,
+}
+
+// Paciente con cédula provisional y `incomplete: true`; se completa luego con PATCH
+async fn quick_add_patient(
+    State(state): State<AppState>,
+    Json(req): Json<QuickPatientRequest>,
+) -> Result<(StatusCode, Json<api::PatientResponse>), ApiError> {
+    let missing: Vec<&str> = [
+        ("first_name", &req.first_name),
+        ("last_name", &req.last_name),
+        ("principal_diagnosis", &req.principal_diagnosis),
+    ]
+    .into_iter()
+    .filter(|(_, value)| value.trim().is_empty())
+    .map(|(field, _)| field)
+    .collect();
+    if !missing.is_empty() {
+        return Err(ApiError::bad_request(format!("Campos obligatorios vacíos: {}", missing.join(", "))));
+    }
+
+    let id = state.ids.next_id("patient");
+    let mut patient_json = json!({
+        "id": &id,
+        "first_name": req.first_name.trim(),
+        "last_name": req.last_name.trim(),
+        "identity_card": patient_patch::provisional_identity_card(&id),
+        "principal_diagnosis": req.principal_diagnosis.trim(),
+        "incomplete": true,
+    });
+    patient_patch::touch(&mut patient_json);
+
+    let msg = ActorMessage::new(
+        GodName::Zeus,
+        GodName::Poseidon,
+        MessagePayload::Command {
+            action: "create_patient".to_string(),
+            data: patient_json,
+        }
+    );
+    let mut created = ask_poseidon(&state, msg).await?;
+    let patient_json = created["patient"].take();
+
+    state.patients.write().await.insert(id.clone(), patient_json.clone());
+
+    Ok((
+        StatusCode::CREATED,
+        Json(api::PatientResponse {
+            success: true,
+            id: Some(id),
+            message: "Ingreso rápido registrado; complete los datos demográficos".to_string(),
+            patient: patient_json,
+        }),
+    ))
+}
+
+// Borrado lógico: el registro sigue en SurrealDB con `deleted` y entra en los recuentos
+async fn delete_patient(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<api::MessageResponse> {
+    let msg = ActorMessage::new(
+        GodName::Zeus,
+        GodName::Poseidon,
+        MessagePayload::Command {
+            action: "delete_patient".to_string(),
+            data: json!({ "id": &id }),
+        }
+    );
+    let data = ask_poseidon(&state, msg).await?;
+    if data["deleted"] != true {
+        return Err(ApiError::not_found("Paciente no encontrado"));
+    }
+
+    if let Some(patient) = state.patients.write().await.get_mut(&id) {
+        patient["deleted"] = json!(true);
+        patient_patch::touch(patient);
+    }
+    Ok(Json(api::MessageResponse::ok("Paciente eliminado exitosamente")))
+}
+
+// Actualización parcial: JSON Patch (arreglo de operaciones) o Merge Patch (objeto)
+async fn patch_patient(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(patch_doc): Json<serde_json::Value>,
+) -> ApiResult<api::PatientResponse> {
+    // Se valida con el bloqueo de lectura; no se mantiene durante la ida y vuelta a Hera y Poseidon.
+    // Poseidon solo escribe si el registro sigue en la versión leída aquí
+    let patients = state.patients.read().await;
+
+    let current = patients
+        .get(&id)
+        .filter(|p| !p.get("deleted").and_then(|d| d.as_bool()).unwrap_or(false))
+        .ok_or_else(|| ApiError::not_found("Paciente no encontrado"))?;
+
+    let mut patched = patient_patch::apply(current, &patch_doc).map_err(ApiError::unprocessable)?;
+
+    // Una cédula nueva se normaliza y no puede coincidir con la de otro paciente
+    if patched.get("identity_card") != current.get("identity_card") {
+        let raw = patched.get("identity_card").and_then(|v| v.as_str()).unwrap_or_default();
+        let card = identity_card::policy().canonical(raw).map_err(invalid_identity_card)?;
+        if let Some(duplicate) = duplicate_identity_card(&patients, &card, Some(&id)) {
+            return Err(duplicate);
+        }
+        patched["identity_card"] = json!(card);
+    }
+    drop(patients);
+    if let Some(invalid) = invalid_patient_choices(&patched) {
+        return Err(invalid);
+    }
+    let expected_updated_at = patched.get("updated_at").cloned().unwrap_or_default();
+    patient_patch::touch(&mut patched);
+
+    // Hera valida y Poseidon persiste el registro resultante
+    let validate = ActorMessage::new(
+        GodName::Zeus,
+        GodName::Hera,
+        MessagePayload::Command {
+            action: "validate_patient".to_string(),
+            data: patched.clone(),
+        }
+    );
+    if let Some(unavailable) = send_to_god(&state, GodName::Hera, validate).await {
+        return Err(unavailable);
+    }
+    update_patient(&state, &id, &patched, &expected_updated_at).await?;
+
+    Ok(Json(api::PatientResponse {
+        success: true,
+        id: None,
+        message: "Paciente actualizado exitosamente".to_string(),
+        patient: patched,
+    }))
+}
+
+/// Persiste `patient` solo si sigue en la versión `expected_updated_at` (409 si otra escritura
+/// llegó antes, 404 si se eliminó entretanto) y después lo deja en la caché
+async fn update_patient(
+    state: &AppState,
+    id: &str,
+    patient: &serde_json::Value,
+    expected_updated_at: &serde_json::Value,
+) -> Result<(), ApiError> {
+    let update = ActorMessage::new(
+        GodName::Zeus,
+        GodName::Poseidon,
+        MessagePayload::Command {
+            action: "update_patient".to_string(),
+            data: json!({ "patient": patient, "expected_updated_at": expected_updated_at }),
+        }
+    );
+    let updated = ask_poseidon(state, update).await?;
+    match updated["rejected"].as_str() {
+        None => {}
+        Some("stale") => {
+            return Err(ApiError::conflict("El paciente fue modificado por otra petición; vuelva a cargarlo")
+                .with("updated_at", &updated["updated_at"]));
+        }
+        Some("duplicate_identity_card") => {
+            let card = patient.get("identity_card").and_then(|v| v.as_str()).unwrap_or_default();
+            let error = format!("Ya existe un paciente con la cédula {}", card);
+            return Err(ApiError::conflict(&error)
+                .with("errors", json!({ "identity_card": error }))
+                .with("duplicate_id", &updated["duplicate_id"]));
+        }
+        Some(_) => return Err(ApiError::not_found("Paciente no encontrado")),
+    }
+
+    // Si la caché ya no está en la versión leída, una escritura posterior a esta ya la actualizó
+    let mut patients = state.patients.write().await;
+    let cached_updated_at = patients.get(id).map(|p| p.get("updated_at").cloned().unwrap_or_default());
+    if cached_updated_at.as_ref() == Some(expected_updated_at) {
+        patients.insert(id.to_string(), patient.clone());
+    }
+    Ok(())
+}
+
+// Egreso: fija `discharged_at`; el paciente deja de contarse como activo
+async fn discharge_patient(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<api::PatientResponse> {
+    let mut discharged = state
+        .patients
+        .read()
+        .await
+        .get(&id)
+        .filter(|p| !p.get("deleted").and_then(|d| d.as_bool()).unwrap_or(false))
+        .cloned()
+        .ok_or_else(|| ApiError::not_found("Paciente no encontrado"))?;
+    if discharged.get("discharged_at").is_some_and(|v| !v.is_null()) {
+        return Err(ApiError::conflict("El paciente ya fue egresado").with("discharged_at", &discharged["discharged_at"]));
+    }
+    let expected_updated_at = discharged.get("updated_at").cloned().unwrap_or_default();
+    discharged["discharged_at"] = json!(chrono::Utc::now().to_rfc3339());
+    patient_patch::touch(&mut discharged);
+
+    update_patient(&state, &id, &discharged, &expected_updated_at).await?;
+
+    Ok(Json(api::PatientResponse {
+        success: true,
+        id: None,
+        message: "Paciente egresado".to_string(),
+        patient: discharged,
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeRequest {
+    pub source_id: String,
+}
+
+// Campos demográficos que se comparan al fusionar duplicados
+const MERGE_DEMOGRAPHIC_FIELDS: [&str; 4] = ["first_name", "last_name", "identity_card", "principal_diagnosis"];
+
+async fn merge_patients(
+    State(state): State<AppState>,
+    Path(target_id): Path<String>,
+    Json(req): Json<MergeRequest>,
+) -> ApiResult<api::MergeResponse> {
+    if req.source_id == target_id {
+        return Err(ApiError::bad_request("No se puede fusionar un paciente consigo mismo"));
+    }
+
+    // Comprobación previa sobre la caché para responder con el detalle habitual; la
+    // transacción de Poseidon vuelve a comprobarlo contra SurrealDB
+    let patients = state.patients.read().await;
+    let (Some(target), Some(source)) = (patients.get(&target_id).cloned(), patients.get(&req.source_id).cloned()) else {
+        return Err(ApiError::not_found("Paciente no encontrado"));
+    };
+    drop(patients);
+
+    if source.get("deleted").and_then(|d| d.as_bool()).unwrap_or(false) {
+        return Err(ApiError::conflict("El paciente origen ya fue fusionado o eliminado"));
+    }
+    // Fusionar hacia un paciente eliminado o ya fusionado dejaría las evaluaciones colgando
+    if target.get("deleted").and_then(|d| d.as_bool()).unwrap_or(false) {
+        let error = ApiError::conflict("El paciente destino ya fue fusionado o eliminado");
+        return Err(match target.get("merged_into") {
+            Some(merged_into) => error.with("merged_into", merged_into),
+            None => error,
+        });
+    }
+
+    // Los datos demográficos en conflicto no se sobrescriben: se devuelven para resolución manual
+    let conflicts: Vec<serde_json::Value> = MERGE_DEMOGRAPHIC_FIELDS
+        .iter()
+        .filter(|field| target.get(**field) != source.get(**field))
+        .map(|field| json!({
+            "field": field,
+            "target": target.get(*field),
+            "source": source.get(*field),
+        }))
+        .collect();
+
+    let msg = ActorMessage::new(
+        GodName::Zeus,
+        GodName::Poseidon,
+        MessagePayload::Command {
+            action: "merge_patients".to_string(),
+            data: json!({ "target_id": &target_id, "source_id": &req.source_id }),
+        }
+    );
+    let merged = ask_poseidon(&state, msg).await?;
+    match merged["rejected"].as_str() {
+        None => {}
+        Some("source_retired") => return Err(ApiError::conflict("El paciente origen ya fue fusionado o eliminado")),
+        Some("target_retired") => {
+            let error = ApiError::conflict("El paciente destino ya fue fusionado o eliminado");
+            return Err(match merged.get("merged_into").filter(|m| !m.is_null()) {
+                Some(merged_into) => error.with("merged_into", merged_into),
+                None => error,
+            });
+        }
+        Some(_) => return Err(ApiError::not_found("Paciente no encontrado")),
+    }
+
+    // SurrealDB ya tiene la fusión; la caché re-apunta exactamente las evaluaciones que movió
+    let moved: Vec<String> = serde_json::from_value(merged["assessments"].clone()).unwrap_or_default();
+    let mut assessments = state.assessments.write().await;
+    for id in &moved {
+        if let Some(assessment) = assessments.get_mut(id) {
+            assessment["patient_id"] = json!(&target_id);
+        }
+    }
+    drop(assessments);
+
+    let mut patients = state.patients.write().await;
+    if let Some(source) = patients.get_mut(&req.source_id) {
+        source["deleted"] = json!(true);
+        source["merged_into"] = json!(&target_id);
+        patient_patch::touch(source);
+    }
+    if let Some(target) = patients.get_mut(&target_id) {
+        patient_patch::touch(target);
+    }
+
+    Ok(Json(api::MergeResponse {
+        success: true,
+        target_id,
+        source_id: req.source_id,
+        assessments_moved: moved.len(),
+        conflicts,
+        message: "Pacientes fusionados exitosamente".to_string(),
+    }))
+}
+
+// === ESCALAS (Athena) ===
+
+/// 422 si la evaluación referencia un paciente inexistente (o eliminado); sin
+/// `patient_id` el cálculo es anónimo y se acepta
+async fn reject_unknown_patient(state: &AppState, patient_id: &str) -> Option<ApiError> {
+    if patient_id.is_empty() {
+        return None;
+    }
+    let exists = state
+        .patients
+        .read()
+        .await
+        .get(patient_id)
+        .is_some_and(|p| !p.get("deleted").and_then(|d| d.as_bool()).unwrap_or(false));
+    if exists {
+        return None;
+    }
+    Some(ApiError::unprocessable("unknown patient_id").with("patient_id", patient_id))
+}
+
+// Guarda el resultado de una escala como evaluación del paciente
+async fn store_assessment(state: &AppState, patient_id: &str, result: &serde_json::Value) -> Result<(), ApiError> {
+    store_assessment_at(state, patient_id, result, None).await
+}
+
+/// Igual que `store_assessment`, con la hora de la evaluación indicada por el cliente
+async fn store_assessment_at(
+    state: &AppState,
+    patient_id: &str,
+    result: &serde_json::Value,
+    assessed_at: Option<chrono::DateTime<chrono::Utc>>,
+) -> Result<(), ApiError> {
+    if patient_id.is_empty() {
+        return Ok(());
+    }
+
+    let id = state.ids.next_id("assessment");
+    let mut assessment = result.clone();
+    assessment["id"] = json!(&id);
+    assessment["assessed_at"] = json!(assessed_at.unwrap_or_else(chrono::Utc::now).to_rfc3339());
+
+    // Poseidon la guarda en SurrealDB, donde la fusión de pacientes la re-apunta; solo
+    // entonces entra en la caché
+    let msg = ActorMessage::new(
+        GodName::Zeus,
+        GodName::Poseidon,
+        MessagePayload::Command {
+            action: "create_assessment".to_string(),
+            data: assessment.clone(),
+        }
+    );
+    ask_poseidon(state, msg).await?;
+
+    let mut assessments = state.assessments.write().await;
+    let previous_glasgow = assessments
+        .values()
+        .filter(|a| a["patient_id"].as_str() == Some(patient_id) && a["scale"] == "Glasgow")
+        .max_by_key(|a| a["assessed_at"].as_str().unwrap_or_default().to_string())
+        .and_then(|a| a["total"].as_i64());
+    assessments.insert(id, assessment);
+    drop(assessments);
+
+    for alert in clinical_alerts::from_assessment(result, previous_glasgow) {
+        if let Err(e) = clinical_alerts::raise(&state.hestia, alert).await {
+            tracing::error!("🚨 No se pudo guardar la alerta clínica: {}", e);
+        }
+    }
+    Ok(())
+}
+
+// === ALERTAS CLÍNICAS (independientes de las alertas de sistema de Erinyes) ===
+
+async fn list_clinical_alerts(
+    State(state): State<AppState>,
+    Query(filter): Query<clinical_alerts::AlertFilter>,
+    page: PageParams,
+) -> ApiResult<Paginated<clinical_alerts::ClinicalAlert>> {
+    // Sin `sort` se conserva el orden de `list`: de la más reciente a la más antigua
+    Ok(Json(page.apply_to(clinical_alerts::list(&state.hestia, &filter))))
+}
+
+async fn create_clinical_alert(
+    State(state): State<AppState>,
+    Json(alert): Json<clinical_alerts::NewClinicalAlert>,
+) -> ApiResult<api::ClinicalAlertResponse> {
+    clinical_alerts::validate(&alert).map_err(ApiError::unprocessable)?;
+    let alert = clinical_alerts::raise(&state.hestia, alert).await.map_err(ApiError::internal)?;
+    Ok(Json(api::ClinicalAlertResponse { success: true, alert }))
+}
+
+/// Un fallo al escribir en Hestia es un 500: la transición no quedó guardada
+fn alert_transition_response(
+    id: &str,
+    result: Result<Option<clinical_alerts::ClinicalAlert>, String>,
+) -> ApiResult<api::ClinicalAlertResponse> {
+    match result.map_err(ApiError::internal)? {
+        Some(alert) => Ok(Json(api::ClinicalAlertResponse { success: true, alert })),
+        None => Err(ApiError::not_found(format!("Alerta no encontrada: {}", id))),
+    }
+}
+
+async fn acknowledge_clinical_alert(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<api::ClinicalAlertResponse> {
+    alert_transition_response(&id, clinical_alerts::acknowledge(&state.hestia, &id).await)
+}
+
+async fn resolve_clinical_alert(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<api::ClinicalAlertResponse> {
+    alert_transition_response(&id, clinical_alerts::resolve(&state.hestia, &id).await)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlasgowRequest {
+    /// Vacío u omitido: cálculo anónimo que no se guarda
+    #[serde(default)]
+    pub patient_id: String,
+    pub eye: i32,
+    pub verbal: i32,
+    pub motor: i32,
+    /// Reactividad pupilar: si se informa, el resultado incluye también GCS-P
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pupils: Option<scales::Pupils>,
+}
+
+async fn calculate_glasgow(
+    State(state): State<AppState>,
+    lang: Lang,
+    Json(req): Json<GlasgowRequest>,
+) -> Response {
+    if let Some(rejection) = reject_unknown_patient(&state, &req.patient_id).await {
+        return rejection.into_response();
+    }
+
+    let mut data = json!(req);
+    data["lang"] = json!(lang);
+    let result = match ask_athena(&state, "calculate_glasgow", data).await {
+        Ok(result) => result,
+        Err(response) => return response.into_response(),
+    };
+
+    if let Err(e) = store_assessment(&state, &req.patient_id, &result).await {
+        return e.into_response();
+    }
+    Json(result).into_response()
+}
+
+/// El resultado es el que calcula Athena; si rechaza los parámetros es un 422
+async fn ask_athena(state: &AppState, action: &str, data: serde_json::Value) -> Result<serde_json::Value, ApiError> {
+    let msg = ActorMessage::new(
+        GodName::Zeus,
+        GodName::Athena,
+        MessagePayload::Command {
+            action: action.to_string(),
+            data,
+        }
+    );
+
+    match ask_god(state, GodName::Athena, msg).await? {
+        ResponsePayload { success: true, data, .. } => Ok(data),
+        ResponsePayload { error, .. } => Err(ApiError::unprocessable(error.unwrap_or_else(|| "Athena rechazó el cálculo".to_string()))),
+    }
+}
+
+/// APACHE II con los parámetros completos; las mismas estructuras que el servidor monolítico
+async fn calculate_apache(
+    State(state): State<AppState>,
+    Json(req): Json<ApacheIIRequest>,
+) -> Response {
+    let patient_id = req.patient_id.clone().unwrap_or_default();
+    if let Some(rejection) = reject_unknown_patient(&state, &patient_id).await {
+        return rejection.into_response();
+    }
+
+    // Como en SOFA: la misma entrada dentro del TTL no se recalcula, pero cada envío con
+    // paciente se guarda como una evaluación más
+    let result = match state.scale_cache.get("apache", &req) {
+        Some(cached) => cached,
+        None => {
+            let input = json!(req);
+            let mut result = match ask_athena(&state, "calculate_apache", input.clone()).await {
+                Ok(result) => result,
+                Err(response) => return response.into_response(),
+            };
+            // Un laboratorio crítico alerta aunque la gravedad global sea moderada
+            critical_labs::flag(&mut result, &input);
+            state.scale_cache.put("apache", &req, &result);
+            result
+        }
+    };
+
+    if let Err(e) = store_assessment(&state, &patient_id, &result).await {
+        return e.into_response();
+    }
+    Json(result).into_response()
+}
+
+async fn calculate_saps(
+    State(state): State<AppState>,
+    Json(mut req): Json<SAPSIIRequest>,
+) -> Response {
+    let patient_id = req.patient_id.clone().unwrap_or_default();
+    if let Some(rejection) = reject_unknown_patient(&state, &patient_id).await {
+        return rejection.into_response();
+    }
+    // Sin tipo de ingreso en la petición se usa el registrado en la admisión del paciente
+    if req.admission_type.trim().is_empty() {
+        let admission = state
+            .patients
+            .read()
+            .await
+            .get(&patient_id)
+            .and_then(|p| serde_json::from_value::<AdmissionType>(p.get("admission_type")?.clone()).ok());
+        if let Some(admission) = admission {
+            req.admission_type = admission.saps_admission_type().to_string();
+        }
+    }
+
+    let result = match state.scale_cache.get("saps", &req) {
+        Some(cached) => cached,
+        None => {
+            let input = json!(req);
+            let mut result = match ask_athena(&state, "calculate_saps", input.clone()).await {
+                Ok(result) => result,
+                Err(response) => return response.into_response(),
+            };
+            critical_labs::flag(&mut result, &input);
+            state.scale_cache.put("saps", &req, &result);
+            result
+        }
+    };
+
+    if let Err(e) = store_assessment(&state, &patient_id, &result).await {
+        return e.into_response();
+    }
+    Json(result).into_response()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SofaRequest {
+    /// Vacío u omitido: cálculo anónimo que no se guarda
+    #[serde(default)]
+    pub patient_id: String,
+    pub respiratory: i32,
     pub coagulation: i32,
     pub liver: i32,
     pub cardiovascular: i32,
     pub cns: i32,
     pub renal: i32,
+    /// Hora real de la evaluación; sin ella se usa la de llegada
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assessed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+async fn calculate_sofa(
+    State(state): State<AppState>,
+    Json(req): Json<SofaRequest>,
+) -> Response {
+    if let Some(rejection) = reject_unknown_patient(&state, &req.patient_id).await {
+        return rejection.into_response();
+    }
+
+    // La misma entrada dentro del TTL devuelve el resultado ya calculado sin volver a
+    // calcularlo; con paciente, cada envío se guarda igualmente como una evaluación más
+    if let Some(cached) = state.scale_cache.get("sofa", &req) {
+        if let Err(e) = store_assessment_at(&state, &req.patient_id, &cached, req.assessed_at).await {
+            return e.into_response();
+        }
+        return Json(cached).into_response();
+    }
+
+    let msg = ActorMessage::new(
+        GodName::Zeus,
+        GodName::Athena,
+        MessagePayload::Command {
+            action: "calculate_sofa".to_string(),
+            data: json!({
+                "respiratory": req.respiratory,
+                "coagulation": req.coagulation,
+                "liver": req.liver,
+                "cardiovascular": req.cardiovascular,
+                "cns": req.cns,
+                "renal": req.renal,
+            }),
+        }
+    );
+
+    if let Some(unavailable) = send_to_god(&state, GodName::Athena, msg).await {
+        return unavailable.into_response();
+    }
+
+    let result = scales::sofa_result(&req);
+    state.scale_cache.put("sofa", &req, &result);
+
+    if let Err(e) = store_assessment_at(&state, &req.patient_id, &result, req.assessed_at).await {
+        return e.into_response();
+    }
+    Json(result).into_response()
+}
+
+// Sistemas orgánicos evaluados por SOFA
+const SOFA_ORGANS: [&str; 6] = ["respiratory", "coagulation", "liver", "cardiovascular", "cns", "renal"];
+
+async fn sofa_organ_trends(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Json<serde_json::Value> {
+    let assessments = state.assessments.read().await;
+    let mut sofa: Vec<&serde_json::Value> = assessments
+        .values()
+        .filter(|a| a.get("patient_id").and_then(|v| v.as_str()) == Some(id.as_str()))
+        .filter(|a| a.get("scale").and_then(|v| v.as_str()) == Some("SOFA"))
+        .collect();
+    sofa.sort_by_key(|a| a.get("assessed_at").and_then(|v| v.as_str()).unwrap_or_default().to_string());
+
+    // Una serie temporal por órgano
+    let mut organs = serde_json::Map::new();
+    for organ in SOFA_ORGANS {
+        let series: Vec<serde_json::Value> = sofa
+            .iter()
+            .map(|a| json!({
+                "assessed_at": a.get("assessed_at"),
+                "score": a.get("components").and_then(|c| c.get(organ)),
+            }))
+            .collect();
+        organs.insert(organ.to_string(), json!(series));
+    }
+
+    Json(json!({
+        "patient_id": id,
+        "assessments": sofa.len(),
+        "organs": organs,
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct News2Request {
+    /// Vacío u omitido: cálculo anónimo que no se guarda
+    #[serde(default)]
+    pub patient_id: String,
+    #[serde(flatten)]
+    pub vitals: CompleteVitalSigns,
+    /// Nivel ACVPU observado
+    #[serde(default)]
+    pub consciousness: Option<scales::Acvpu>,
+    /// Glasgow total: si no se informa ACVPU, la conciencia se aproxima con `avpu_from_gcs`
+    #[serde(default)]
+    pub gcs: Option<i32>,
+    /// Oxígeno suplementario: suma 2 puntos
+    #[serde(default)]
+    pub supplemental_oxygen: bool,
+}
+
+// Bandas por parámetro para colorear los gráficos con la misma lógica que la puntuación
+async fn news2_chart_thresholds() -> Json<serde_json::Value> {
+    Json(scales::news2_chart_thresholds())
+}
+
+// Umbrales de interpretación del total, generados con las mismas constantes que la puntuación
+async fn scale_bands(lang: Lang, Path(scale): Path<String>) -> Response {
+    match scale.parse::<scales::Scale>() {
+        Ok(scale) => Json(scales::bands_body(scale, lang)).into_response(),
+        Err(error) => (StatusCode::NOT_FOUND, Json(json!({ "success": false, "error": error }))).into_response(),
+    }
+}
+
+async fn calculate_news2(
+    State(state): State<AppState>,
+    lang: Lang,
+    Json(req): Json<News2Request>,
+) -> Response {
+    if let Some(rejection) = reject_unknown_patient(&state, &req.patient_id).await {
+        return rejection.into_response();
+    }
+    if let Err(errors) = req.vitals.validate() {
+        return invalid_vitals(&errors);
+    }
+
+    let msg = ActorMessage::new(
+        GodName::Zeus,
+        GodName::Athena,
+        MessagePayload::Command {
+            action: "calculate_news2".to_string(),
+            data: json!(req.vitals),
+        }
+    );
+
+    if let Some(unavailable) = send_to_god(&state, GodName::Athena, msg).await {
+        return unavailable.into_response();
+    }
+
+    let result = scales::news2_result(&req, lang);
+
+    if let Err(e) = store_assessment(&state, &req.patient_id, &result).await {
+        return e.into_response();
+    }
+    Json(result).into_response()
+}
+
+// Todas las escalas aplicables a partir de un único registro de signos vitales
+async fn compare_scales(
+    lang: Lang,
+    Json(vitals): Json<scales::Vitals>,
+) -> Response {
+    if let Err(errors) = vitals.vital_signs.validate() {
+        return invalid_vitals(&errors);
+    }
+    Json(scales::compare(&vitals, lang)).into_response()
+}
+
+/// 422 con cada constante fuera de su rango plausible
+fn invalid_vitals(errors: &[VitalSignError]) -> Response {
+    (StatusCode::UNPROCESSABLE_ENTITY, Json(scales::invalid_vitals_body(errors))).into_response()
+}
+
+// Peso ideal y volumen corriente protector para pacientes ventilados
+async fn calculate_tidal_volume(
+    Json(req): Json<ventilation::TidalVolumeRequest>,
+) -> Json<serde_json::Value> {
+    match ventilation::tidal_volume(&req) {
+        Ok(result) => Json(json!({
+            "success": true,
+            "height_cm": req.height_cm,
+            "sex": req.sex,
+            "result": result,
+            "formula": "Devine",
+        })),
+        Err(e) => Json(json!({
+            "success": false,
+            "error": e,
+        })),
+    }
+}
+
+// Importación NDJSON: una evaluación por línea, un resultado por línea (en streaming)
+async fn import_assessments(
+    State(state): State<AppState>,
+    lang: Lang,
+    Query(options): Query<import::ImportOptions>,
+    body: Body,
+) -> Response {
+    // Se importa a medida que llega. Un Content-Length excesivo ya lo rechaza con 413 la capa
+    // de límite; un cuerpo sin longitud (o gzip) que lo supera corta el flujo y `run` informa
+    // el error en la última línea, como una línea que excede `MAX_LINE_BYTES`
+    let body = Body::new(http_body_util::Limited::new(body, state.max_body_bytes));
+
+    let (tx, rx) = mpsc::channel::<String>(64);
+    tokio::spawn(import::run(state, lang, options, body.into_data_stream(), tx));
+
+    let stream = futures_util::stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|line| (Ok::<_, std::convert::Infallible>(line), rx))
+    });
+
+    (
+        [(axum::http::header::CONTENT_TYPE, "application/x-ndjson")],
+        Body::from_stream(stream),
+    )
+        .into_response()
+}
+
+// === MONITOREO (Zeus + Erinyes) ===
+
+async fn api_status(State(state): State<AppState>) -> Json<serde_json::Value> {
+    let uptime = state.start_time.elapsed().as_secs();
+    let senders = state.god_senders.read().await;
+    
+    Json(json!({
+        "status": "active",
+        "version": version::display_version(),
+        "mode": "Olympus Actor System",
+        "active_gods": senders.len(),
+        "uptime_seconds": uptime,
+        "message": format!("Sistema operativo con {} dioses divinos", senders.len()),
+        "trinity": ["Zeus", "Hades", "Poseidon"],
+    }))
+}
+
+async fn api_version() -> Json<version::BuildInfo> {
+    Json(version::build_info())
+}
+
+async fn api_gods(State(state): State<AppState>) -> Json<serde_json::Value> {
+    let senders = state.god_senders.read().await;
+    
+    // Construir lista de dioses con datos simulados (en producción vendrían de health checks)
+    let gods: Vec<serde_json::Value> = senders.keys().map(|god| {
+        let mailbox = state.mailboxes.gauge(*god);
+        json!({
+            "name": god.as_str(),
+            "domain": god.domain(),
+            "active": !state.mailboxes.is_unavailable(*god),
+            "status": mailbox.as_ref().map(|m| m.status.as_str()).unwrap_or("Active"),
+            "messages_processed": 0,
+            "mailbox": mailbox,
+            "reply_latency": state.replies.latency(*god),
+            "uptime_seconds": state.start_time.elapsed().as_secs(),
+        })
+    }).collect();
+    let disabled: Vec<&str> = state.genesis_config.disabled().iter().map(|god| god.as_str()).collect();
+    let degraded = state.mailboxes.degraded();
+    let unavailable = state.mailboxes.unavailable();
+    let trinity_degraded = degraded.iter().chain(&unavailable).any(|god| state.trinity.contains(*god));
+    let degraded: Vec<&str> = degraded.iter().map(|god| god.as_str()).collect();
+    let unavailable: Vec<&str> = unavailable.iter().map(|god| god.as_str()).collect();
+
+    Json(json!({
+        "gods": gods,
+        "total": gods.len(),
+        "disabled": disabled,
+        "degraded": degraded,
+        "unavailable": unavailable,
+        "all_active": degraded.is_empty() && unavailable.is_empty(),
+        "trinity_status": if trinity_degraded { "Degraded" } else { "Healthy" },
+    }))
+}
+
+/// Los 20 dioses con su dominio y si Genesis los desplegó, incluidos los deshabilitados
+async fn api_gods_enabled(State(state): State<AppState>) -> Json<serde_json::Value> {
+    let senders = state.god_senders.read().await;
+    let gods: Vec<serde_json::Value> = GodName::ALL
+        .iter()
+        .map(|god| {
+            let enabled = state.genesis_config.is_enabled(*god);
+            json!({
+                "name": god.as_str(),
+                "domain": god.domain(),
+                "enabled": enabled,
+                "trinity": state.trinity.contains(*god),
+                // Desplegado y con el buzón abierto
+                "running": enabled && senders.contains_key(god) && !state.mailboxes.is_unavailable(*god),
+            })
+        })
+        .collect();
+
+    Json(json!({
+        "total": gods.len(),
+        "enabled": state.genesis_config.enabled().len(),
+        "disabled": state.genesis_config.disabled().len(),
+        "gods": gods,
+    }))
+}
+
+async fn api_god(State(state): State<AppState>, Path(name): Path<String>) -> Response {
+    let Some(god) = GodName::from_name(&name) else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(json!({ "success": false, "error": format!("Dios desconocido: '{}'", name) })),
+        )
+            .into_response();
+    };
+    if !state.genesis_config.is_enabled(god) {
+        return god_disabled(god).into_response();
+    }
+    if state.mailboxes.is_unavailable(god) {
+        return god_unavailable(god).into_response();
+    }
+
+    let mailbox = state.mailboxes.gauge(god);
+    Json(json!({
+        "name": god.as_str(),
+        "domain": god.domain(),
+        "active": true,
+        "status": mailbox.as_ref().map(|m| m.status.as_str()).unwrap_or("Active"),
+        "mailbox": mailbox,
+        "reply_latency": state.replies.latency(god),
+        "uptime_seconds": state.start_time.elapsed().as_secs(),
+    }))
+    .into_response()
+}
+
+// Rastro de auditoría paginado (mismos parámetros que los demás listados), solo para el administrador
+async fn list_audit(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    page: PageParams,
+) -> ApiResult<Paginated<serde_json::Value>> {
+    let token = auth::bearer_token(&headers).ok_or(auth::AuthError::Unauthenticated)?;
+    if state.auth.admin_session(token).is_none() {
+        return Err(match state.auth.session(token) {
+            Some(_) => ApiError::new(StatusCode::FORBIDDEN, "Solo el administrador puede ver la auditoría"),
+            None => auth::AuthError::Unauthenticated.into(),
+        });
+    }
+    Ok(Json(page.sort_or("id").apply(state.audit.entries())))
+}
+
+#[derive(Debug, Deserialize)]
+struct LogStreamQuery {
+    /// EventSource no permite cabeceras: el token puede ir en la query
+    token: Option<String>,
+}
+
+// Logs en vivo por SSE, solo para el administrador
+async fn stream_logs(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<LogStreamQuery>,
+) -> Response {
+    let Some(token) = auth::bearer_token(&headers).map(str::to_string).or(query.token) else {
+        return (StatusCode::UNAUTHORIZED, Json(json!({ "success": false, "error": auth::AuthError::Unauthenticated.message() }))).into_response();
+    };
+    if state.auth.admin_session(&token).is_none() {
+        let status = if state.auth.session(&token).is_some() { StatusCode::FORBIDDEN } else { StatusCode::UNAUTHORIZED };
+        return (status, Json(json!({ "success": false, "error": "Solo el administrador puede ver los logs" }))).into_response();
+    }
+
+    tracing::info!("📜 Consola de logs abierta (nivel {})", state.log_stream.level());
+    log_stream::sse(state.log_stream.subscribe()).into_response()
+}
+
+/// Dioses registrados en Erinyes con su nivel de vigilancia y último heartbeat
+async fn api_olympus_health(State(state): State<AppState>) -> Json<serde_json::Value> {
+    let now = chrono::Utc::now();
+    let registered = state.heartbeats.snapshot();
+    let gods: Vec<serde_json::Value> = registered.iter().map(|(god, registration)| {
+        json!({
+            "name": god.as_str(),
+            "tier": registration.config.tier,
+            "interval_ms": registration.config.interval_ms,
+            "timeout_ms": registration.config.timeout_ms,
+            "registered_at": registration.registered_at,
+            "last_heartbeat": registration.last_heartbeat,
+            "healthy": registration.is_healthy(now),
+        })
+    }).collect();
+
+    let enabled = state.genesis_config.enabled();
+    let unregistered: Vec<&str> = enabled
+        .iter()
+        .filter(|god| !registered.iter().any(|(registered, _)| registered == *god))
+        .map(|god| god.as_str())
+        .collect();
+    let unhealthy: Vec<&str> = registered
+        .iter()
+        .filter(|(_, registration)| !registration.is_healthy(now))
+        .map(|(god, _)| god.as_str())
+        .collect();
+    let trinity_critical = registered
+        .iter()
+        .any(|(_, registration)| registration.config.tier == actors::MonitoringTier::Trinity && !registration.is_healthy(now));
+
+    Json(json!({
+        "gods": gods,
+        "registered": registered.len(),
+        "enabled": enabled.len(),
+        "all_registered": unregistered.is_empty(),
+        "unregistered": unregistered,
+        "unhealthy": unhealthy,
+        "trinity_critical": trinity_critical,
+        "timestamp": now,
+    }))
+}
+
+/// 503 para cualquier ruta servida por un dios deshabilitado en Genesis
+fn god_disabled(god: GodName) -> ApiError {
+    ApiError::service_unavailable("god disabled")
+        .with("god", god.as_str())
+        .with("message", format!("{} está deshabilitado en este despliegue", god.as_str()))
+}
+
+/// 503 para un dios desplegado cuya tarea terminó (su canal está cerrado)
+fn god_unavailable(god: GodName) -> ApiError {
+    ApiError::service_unavailable("god unavailable")
+        .with("god", god.as_str())
+        .with("message", format!("{} no está disponible", god.as_str()))
+}
+
+/// Entrega `msg` al dios. Si su receptor ya no existe (la tarea del dios terminó) lo
+/// marca como no disponible y devuelve el 503 para el llamador, en lugar de perder el
+/// mensaje en silencio. Un dios sin canal (deshabilitado en Genesis) se omite.
+async fn send_to_god(state: &AppState, god: GodName, msg: ActorMessage) -> Option<ApiError> {
+    let sender = state.god_senders.read().await.get(&god).cloned()?;
+    let message_id = msg.id.clone();
+    state.replies.expect(god, &message_id);
+    if sender.send(msg).await.is_ok() {
+        return None;
+    }
+    state.replies.forget(&message_id);
+    state.mailboxes.mark_unavailable(god);
+    Some(god_unavailable(god))
+}
+
+/// 504 para un dios que no respondió dentro de la ventana de respuesta
+fn god_timeout(god: GodName) -> ApiError {
+    ApiError::new(StatusCode::GATEWAY_TIMEOUT, "god timeout")
+        .with("god", god.as_str())
+        .with("message", format!("{} no respondió a tiempo", god.as_str()))
+}
+
+/// 502 para un dios que procesó el comando sin responder (acción que no reconoce)
+fn god_no_reply(god: GodName) -> ApiError {
+    ApiError::new(StatusCode::BAD_GATEWAY, "god no reply")
+        .with("god", god.as_str())
+        .with("message", format!("{} no respondió al comando", god.as_str()))
+}
+
+/// Petición/respuesta: entrega `msg` al dios y espera su `ResponsePayload`, de modo que
+/// el resultado que devuelve la API es el que calculó el dios y no uno rehecho en el
+/// handler. Es el patrón para los comandos cuyo resultado pertenece a un dios:
+///
+/// 1. el handler envía los datos completos de la petición (y `lang` si localiza),
+/// 2. el dios responde con `MessagePayload::Response`, que el runtime entrega por
+///    `ActorMessage::respond_to`,
+/// 3. el handler serializa `data` o, con `success: false`, devuelve 422 con `error`.
+///
+/// Los errores ya vienen como respuesta HTTP: 503 si el dios está caído o no desplegado,
+/// 504 si no responde en `OLYMPUS_REPLY_TIMEOUT_MS` y 502 si termina sin responder.
+async fn ask_god(state: &AppState, god: GodName, msg: ActorMessage) -> Result<ResponsePayload, ApiError> {
+    let deployed = state.god_senders.read().await.contains_key(&god);
+    if !deployed {
+        return Err(god_disabled(god));
+    }
+    let (msg, reply) = msg.with_reply();
+    if let Some(unavailable) = send_to_god(state, god, msg).await {
+        return Err(unavailable);
+    }
+    match tokio::time::timeout(state.replies.timeout(), reply).await {
+        Ok(Ok(response)) => Ok(response),
+        Ok(Err(_)) => Err(god_no_reply(god)),
+        Err(_) => Err(god_timeout(god)),
+    }
+}
+
+/// Respuesta de Poseidon; un fallo de SurrealDB es un 500 y no se simula el éxito en memoria
+async fn ask_poseidon(state: &AppState, msg: ActorMessage) -> Result<serde_json::Value, ApiError> {
+    match ask_god(state, GodName::Poseidon, msg).await? {
+        ResponsePayload { success: true, data, .. } => Ok(data),
+        ResponsePayload { error, .. } => Err(storage_error(error)),
+    }
 }
 
-async fn calculate_sofa(
-    State(state): State<AppState>,
-    Json(req): Json<SofaRequest>,
-) -> Json<serde_json::Value> {
-    let msg = ActorMessage::new(
-        GodName::Zeus,
-        GodName::Athena,
-        MessagePayload::Command {
-            action: "calculate_sofa".to_string(),
-            data: json!({
-                "respiratory": req.respiratory,
-                "coagulation": req.coagulation,
-                "liver": req.liver,
-                "cardiovascular": req.cardiovascular,
-                "cns": req.cns,
-                "renal": req.renal,
-            }),
-        }
-    );
+fn storage_error(error: Option<String>) -> ApiError {
+    ApiError::internal("storage error")
+        .with("god", GodName::Poseidon.as_str())
+        .with("message", error.unwrap_or_else(|| "Poseidon no pudo acceder a SurrealDB".to_string()))
+}
 
-    let senders = state.god_senders.read().await;
-    if let Some(athena_tx) = senders.get(&GodName::Athena) {
-        let _ = athena_tx.send(msg).await;
+/// Dios responsable de cada grupo de rutas
+fn route_god(path: &str) -> Option<GodName> {
+    if path.starts_with("/api/scales/") {
+        Some(GodName::Athena)
+    } else if path.starts_with("/api/aphrodite/") {
+        Some(GodName::Aphrodite)
+    } else if path.starts_with("/api/dionysus/") {
+        Some(GodName::Dionysus)
+    } else if path.starts_with("/api/erinyes/") {
+        Some(GodName::Erinyes)
+    } else {
+        None
     }
+}
+
+/// Rutas no esenciales (analítica, importaciones masivas) que se suspenden durante la
+/// descarga de carga; pacientes y cálculo de escalas siguen atendiéndose
+fn is_heavy_route(path: &str) -> bool {
+    path.starts_with("/api/dionysus/") || path == "/api/assessments/import"
+}
 
-    let total = req.respiratory + req.coagulation + req.liver + req.cardiovascular + req.cns + req.renal;
-    let mortality = match total {
-        0..=6 => "< 10%",
-        7..=9 => "15-20%",
-        10..=12 => "40-50%",
-        13..=24 => "> 80%",
-        _ => "Error",
+async fn shed_heavy_load(State(state): State<AppState>, request: Request<Body>, next: Next) -> Response {
+    if !is_heavy_route(request.uri().path()) {
+        return next.run(request).await;
+    }
+    let Some(episode) = state.load_shedding.episode() else {
+        return next.run(request).await;
     };
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        [(axum::http::header::RETRY_AFTER, "60")],
+        Json(json!({
+            "success": false,
+            "error": "load shedding",
+            "message": "Carga no esencial suspendida por presión crítica de recursos; reintente más tarde",
+            "since": episode.since,
+            "memory_pct": episode.memory_pct,
+        })),
+    )
+        .into_response()
+}
 
-    Json(json!({
-        "success": true,
-        "scale": "SOFA",
-        "patient_id": req.patient_id,
-        "total": total,
-        "predicted_mortality": mortality,
-        "calculated_by": "Athena"
-    }))
+/// Registra en el rastro de Nemesis las peticiones que modifican datos
+async fn audit_mutations(State(state): State<AppState>, request: Request<Body>, next: Next) -> Response {
+    if !audit::AuditLog::is_audited(request.method()) {
+        return next.run(request).await;
+    }
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let username = auth::bearer_token(request.headers())
+        .and_then(|token| state.auth.session(token))
+        .map(|session| session.username);
+    let response = next.run(request).await;
+    state.audit.record(username, &method, &path, response.status().as_u16());
+    response
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct News2Request {
-    pub patient_id: String,
-    pub respiration_rate: i32,
-    pub oxygen_saturation: i32,
-    pub temperature: f32,
-    pub heart_rate: i32,
-    pub systolic_bp: i32,
+async fn require_enabled_god(State(state): State<AppState>, request: Request<Body>, next: Next) -> Response {
+    match route_god(request.uri().path()) {
+        Some(god) if !state.genesis_config.is_enabled(god) => god_disabled(god).into_response(),
+        _ => next.run(request).await,
+    }
 }
 
-async fn calculate_news2(
-    State(state): State<AppState>,
-    Json(req): Json<News2Request>,
-) -> Json<serde_json::Value> {
-    let msg = ActorMessage::new(
-        GodName::Zeus,
-        GodName::Athena,
-        MessagePayload::Command {
-            action: "calculate_news2".to_string(),
-            data: json!({
-                "respiration_rate": req.respiration_rate,
-                "oxygen_saturation": req.oxygen_saturation,
-                "temperature": req.temperature,
-                "heart_rate": req.heart_rate,
-                "systolic_bp": req.systolic_bp,
-            }),
-        }
-    );
+/// Valor que sustituye a los secretos en `/api/olympus/config`
+const REDACTED: &str = "[redacted]";
 
-    let senders = state.god_senders.read().await;
-    if let Some(athena_tx) = senders.get(&GodName::Athena) {
-        let _ = athena_tx.send(msg).await;
-    }
+fn is_secret_key(key: &str) -> bool {
+    ["secret", "password", "token", "api_key"].iter().any(|marker| key.contains(marker))
+}
 
-    // Calcular NEWS2 simplificado
-    let resp_score = match req.respiration_rate {
-        0..=8 => 3, 9..=11 => 1, 12..=20 => 0, 21..=24 => 2, _ => 3,
-    };
-    let spo2_score = match req.oxygen_saturation {
-        0..=91 => 3, 92..=93 => 2, 94..=95 => 1, _ => 0,
-    };
-    let temp_score = match req.temperature {
-        t if t < 35.0 => 3, t if t <= 36.0 => 1, t if t <= 38.0 => 0, t if t <= 39.0 => 1, _ => 2,
-    };
-    let hr_score = match req.heart_rate {
-        0..=40 => 3, 41..=50 => 1, 51..=90 => 0, 91..=110 => 1, 111..=130 => 2, _ => 3,
-    };
-    let bp_score = match req.systolic_bp {
-        0..=90 => 3, 91..=100 => 2, 101..=110 => 1, 111..=219 => 0, _ => 3,
-    };
+/// Configuración efectiva del despliegue: la que usan Genesis, los bucles de heartbeat y
+/// las capas de la API; los secretos se sustituyen por `REDACTED`
+fn olympus_config(state: &AppState) -> serde_json::Map<String, serde_json::Value> {
+    let names = |gods: Vec<GodName>| gods.iter().map(|god| god.as_str()).collect::<Vec<_>>();
+    let page_limits = pagination::limits();
+    let config = json!({
+        "enabled_gods": names(state.genesis_config.enabled()),
+        "disabled_gods": names(state.genesis_config.disabled()),
+        "trinity_members": names(state.trinity.members()),
+        "heartbeat_trinity_interval_ms": HeartbeatConfig::TRINITY.interval_ms,
+        "heartbeat_trinity_timeout_ms": HeartbeatConfig::TRINITY.timeout_ms,
+        "heartbeat_standard_interval_ms": HeartbeatConfig::STANDARD.interval_ms,
+        "heartbeat_standard_timeout_ms": HeartbeatConfig::STANDARD.timeout_ms,
+        "max_recovery_attempts": actors::erinyes::MAX_RECOVERY_ATTEMPTS,
+        "reply_timeout_ms": state.replies.timeout().as_millis() as u64,
+        "load_shed_memory_pct": state.load_shedding.status()["memory_critical_pct"],
+        "scale_cache_ttl_ms": state.scale_cache.stats()["ttl_ms"],
+        "page_limit_default": page_limits.default,
+        "page_limit_max": page_limits.max,
+        // Nunca se lee el secreto: solo consta que está configurado
+        "jwt_secret": REDACTED,
+        "session_ttl_secs": state.auth.session_ttl().as_secs(),
+        "otp_channel": state.otp.channel.as_str(),
+        "otp_in_response": state.otp.expose_in_response,
+        "log_stream_level": state.log_stream.level().to_string(),
+    });
 
-    let total = resp_score + spo2_score + temp_score + hr_score + bp_score;
-    let risk = match total {
-        0..=4 => "Bajo riesgo",
-        5..=6 => "Riesgo moderado",
-        _ => "Alto riesgo - respuesta de emergencia",
+    let serde_json::Value::Object(mut config) = config else {
+        unreachable!("la configuración se construye como objeto");
     };
+    for (key, value) in config.iter_mut() {
+        if is_secret_key(key) {
+            *value = json!(REDACTED);
+        }
+    }
+    config
+}
 
-    Json(json!({
-        "success": true,
-        "scale": "NEWS2",
-        "patient_id": req.patient_id,
-        "total": total,
-        "risk_level": risk,
-        "calculated_by": "Athena"
-    }))
+async fn api_olympus_config(State(state): State<AppState>) -> Json<serde_json::Value> {
+    Json(serde_json::Value::Object(olympus_config(&state)))
 }
 
-// === MONITOREO (Zeus + Erinyes) ===
+async fn api_olympus_config_key(State(state): State<AppState>, Path(key): Path<String>) -> Response {
+    match olympus_config(&state).remove(&key) {
+        Some(value) => Json(json!({ "key": key, "value": value })).into_response(),
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(json!({ "success": false, "error": format!("Clave de configuración desconocida: '{}'", key) })),
+        )
+            .into_response(),
+    }
+}
 
-async fn api_status(State(state): State<AppState>) -> Json<serde_json::Value> {
-    let uptime = state.start_time.elapsed().as_secs();
-    let senders = state.god_senders.read().await;
-    
+/// Recuperaciones en cola o en curso, con su urgencia y los intentos consumidos
+async fn list_recoveries(State(state): State<AppState>) -> Json<serde_json::Value> {
+    let recoveries = state.recoveries.list();
     Json(json!({
-        "status": "active",
-        "version": "v15.0.0",
-        "mode": "Olympus Actor System",
-        "active_gods": senders.len(),
-        "uptime_seconds": uptime,
-        "message": "Sistema operativo con 20 dioses divinos",
-        "trinity": ["Zeus", "Hades", "Poseidon"],
+        "total": recoveries.len(),
+        "max_attempts": actors::erinyes::MAX_RECOVERY_ATTEMPTS,
+        "recoveries": recoveries,
     }))
 }
 
-async fn api_gods(State(state): State<AppState>) -> Json<serde_json::Value> {
-    let senders = state.god_senders.read().await;
-    
-    // Construir lista de dioses con datos simulados (en producción vendrían de health checks)
-    let gods: Vec<serde_json::Value> = senders.keys().map(|god| {
-        json!({
-            "name": god.as_str(),
-            "domain": god.domain(),
-            "active": true,
-            "status": "Active",
-            "messages_processed": 0,
-            "uptime_seconds": state.start_time.elapsed().as_secs(),
-        })
-    }).collect();
+// Aborta una recuperación desbocada (solo administrador); el dios deja de consumir intentos
+// hasta que vuelva a latir
+async fn cancel_recovery(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<u64>,
+) -> ApiResult<api::RecoveryCancelledResponse> {
+    let token = auth::bearer_token(&headers).ok_or(auth::AuthError::Unauthenticated)?;
+    if state.auth.admin_session(token).is_none() {
+        return Err(match state.auth.session(token) {
+            Some(_) => ApiError::new(StatusCode::FORBIDDEN, "Solo el administrador puede cancelar recuperaciones"),
+            None => auth::AuthError::Unauthenticated.into(),
+        });
+    }
 
-    Json(json!({
-        "gods": gods,
-        "total": gods.len(),
-        "all_active": true,
-        "trinity_status": "Healthy",
-    }))
+    let recovery = state
+        .recoveries
+        .cancel(id)
+        .ok_or_else(|| ApiError::not_found(format!("Recuperación {} no encontrada o ya terminada", id)))?;
+    tracing::warn!("🛑 Recuperación {} de {} cancelada por el administrador", id, recovery.god.as_str());
+    Ok(Json(api::RecoveryCancelledResponse { success: true, cancelled: recovery }))
 }
 
-async fn api_trinity(State(state): State<AppState>) -> Json<serde_json::Value> {
+async fn api_trinity(State(state): State<AppState>) -> Response {
     // Consultar estado de la Trinidad a Zeus
     let msg = ActorMessage::new(
         GodName::Zeus,
@@ -555,9 +2394,8 @@ async fn api_trinity(State(state): State<AppState>) -> Json<serde_json::Value> {
         }
     );
 
-    let senders = state.god_senders.read().await;
-    if let Some(zeus_tx) = senders.get(&GodName::Zeus) {
-        let _ = zeus_tx.send(msg).await;
+    if let Some(unavailable) = send_to_god(&state, GodName::Zeus, msg).await {
+        return unavailable.into_response();
     }
 
     Json(json!({
@@ -567,27 +2405,164 @@ async fn api_trinity(State(state): State<AppState>) -> Json<serde_json::Value> {
             "poseidon": { "name": "Poseidon", "domain": "DataFlow", "healthy": true, "status": "Connecting" },
         },
         "all_healthy": true,
-        "supervised_actors": 19,
+        "supervised_actors": state.genesis_config.enabled().len() - 1,
     }))
+    .into_response()
+}
+
+fn trinity_members_body(state: &AppState) -> serde_json::Value {
+    let members: Vec<&str> = state.trinity.members().iter().map(|god| god.as_str()).collect();
+    let founders: Vec<&str> = GodName::TRINITY.iter().map(|god| god.as_str()).collect();
+    json!({ "members": members, "founders": founders })
+}
+
+async fn get_trinity_members(State(state): State<AppState>) -> Json<serde_json::Value> {
+    Json(trinity_members_body(&state))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrinityMembersRequest {
+    pub members: Vec<String>,
+}
+
+// Cambia la membresía (solo administrador); Erinyes reajusta el nivel de vigilancia
+// de los dioses que entran o salen y Zeus usa la nueva lista para el estado crítico
+async fn set_trinity_members(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<TrinityMembersRequest>,
+) -> Response {
+    let session = auth::bearer_token(&headers).and_then(|token| state.auth.admin_session(token));
+    if session.is_none() {
+        return (
+            StatusCode::UNAUTHORIZED,
+            Json(json!({ "success": false, "error": "Solo el administrador puede cambiar la Trinidad" })),
+        )
+            .into_response();
+    }
+
+    let mut requested = Vec::new();
+    for name in &req.members {
+        match GodName::from_name(name) {
+            Some(god) => requested.push(god),
+            None => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "success": false, "error": format!("Dios desconocido: '{}'", name) })),
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    let changed = match state.trinity.set(&requested, |god| state.genesis_config.is_enabled(god)) {
+        Ok(changed) => changed,
+        Err(error) => {
+            return (StatusCode::BAD_REQUEST, Json(json!({ "success": false, "error": error }))).into_response();
+        }
+    };
+
+    for message in genesis::registration_messages(&changed, &state.trinity) {
+        if let Some(unavailable) = send_to_god(&state, GodName::Erinyes, message).await {
+            return unavailable.into_response();
+        }
+    }
+    tracing::info!("🔱 Trinidad actualizada: {:?}", state.trinity.members());
+
+    let mut body = trinity_members_body(&state);
+    body["success"] = json!(true);
+    body["changed"] = json!(changed.iter().map(|god| god.as_str()).collect::<Vec<_>>());
+    Json(body).into_response()
 }
 
 async fn api_stats(State(state): State<AppState>) -> Json<serde_json::Value> {
     let patients = state.patients.read().await;
     let senders = state.god_senders.read().await;
     
+    let incomplete = patients
+        .values()
+        .filter(|p| p.get("incomplete").and_then(|v| v.as_bool()).unwrap_or(false))
+        .count();
+
     Json(json!({
         "total_patients": patients.len(),
         "active_patients": patients.len(),
+        "incomplete_patients": incomplete,
         "olympus_gods": senders.len(),
         "gods_active": senders.len(),
         "system_uptime": format!("{}s", state.start_time.elapsed().as_secs()),
+        "reply_timeouts": state.replies.total_timeouts(),
+        "scale_cache": state.scale_cache.stats(),
         "trinity_healthy": true,
     }))
 }
 
+// === ANALÍTICA (Dionysus) ===
+
+// Resumen agregado de la unidad: pacientes, evaluaciones por escala y alertas abiertas
+async fn dionysus_analytics(State(state): State<AppState>) -> Response {
+    let msg = ActorMessage::new(
+        GodName::Zeus,
+        GodName::Dionysus,
+        MessagePayload::Query {
+            query_type: "analytics".to_string(),
+            params: json!({}),
+        }
+    );
+
+    if let Some(unavailable) = send_to_god(&state, GodName::Dionysus, msg).await {
+        return unavailable.into_response();
+    }
+
+    let (total_patients, active_patients) = {
+        let patients = state.patients.read().await;
+        let active = patients
+            .values()
+            .filter(|p| !p.get("deleted").and_then(|d| d.as_bool()).unwrap_or(false))
+            .count();
+        (patients.len(), active)
+    };
+
+    let mut by_scale: std::collections::BTreeMap<String, (usize, f64, usize)> = std::collections::BTreeMap::new();
+    let total_assessments = {
+        let assessments = state.assessments.read().await;
+        for assessment in assessments.values() {
+            let Some(scale) = assessment.get("scale").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let entry = by_scale.entry(scale.to_string()).or_default();
+            entry.0 += 1;
+            if let Some(total) = assessment.get("total").and_then(|v| v.as_f64()) {
+                entry.1 += total;
+                entry.2 += 1;
+            }
+        }
+        assessments.len()
+    };
+    let by_scale: serde_json::Map<String, serde_json::Value> = by_scale
+        .into_iter()
+        .map(|(scale, (count, sum, scored))| {
+            let mean = (scored > 0).then(|| sum / scored as f64);
+            (scale, json!({ "count": count, "mean_total": mean }))
+        })
+        .collect();
+
+    let open_alerts = clinical_alerts::list(&state.hestia, &clinical_alerts::AlertFilter::default()).len();
+
+    Json(json!({
+        "success": true,
+        "generated_at": chrono::Utc::now(),
+        "patients": { "total": total_patients, "active": active_patients },
+        "assessments": { "total": total_assessments, "by_scale": by_scale },
+        "vitals_observations": state.vitals_observations.read().await.len(),
+        "open_clinical_alerts": open_alerts,
+    }))
+    .into_response()
+}
+
 // === UI/TEMAS (Aphrodite - Diosa de la Belleza) ===
 
-async fn get_current_theme(State(state): State<AppState>) -> Json<serde_json::Value> {
+async fn get_current_theme(State(state): State<AppState>) -> Response {
     let msg = ActorMessage::new(
         GodName::Zeus,
         GodName::Aphrodite,
@@ -597,26 +2572,19 @@ async fn get_current_theme(State(state): State<AppState>) -> Json<serde_json::Va
         }
     );
 
-    let senders = state.god_senders.read().await;
-    if let Some(aphrodite_tx) = senders.get(&GodName::Aphrodite) {
-        let _ = aphrodite_tx.send(msg).await;
+    if let Some(unavailable) = send_to_god(&state, GodName::Aphrodite, msg).await {
+        return unavailable.into_response();
     }
 
-    // Respuesta por defecto (en producción vendría del actor)
+    // Tema persistido por Aphrodite en Hestia; si nunca se cambió, el por defecto (revisión 0)
+    let (theme, revision) = actors::aphrodite::persisted_theme_with_revision(&state.hestia).unwrap_or_default();
     Json(json!({
-        "theme": {
-            "name": "Olympus Dark",
-            "primary_color": "#6366f1",
-            "secondary_color": "#8b5cf6",
-            "background": "#0f172a",
-            "surface": "#1e293b",
-            "text_primary": "#f8fafc",
-            "text_secondary": "#94a3b8",
-            "accent": "#f59e0b",
-            "border_radius": "0.75rem",
-        },
+        "theme": theme,
+        "revision": revision,
+        "css_variables": actors::aphrodite::css_variables(&theme),
         "controlled_by": "Aphrodite"
     }))
+    .into_response()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -624,34 +2592,47 @@ pub struct SwitchThemeRequest {
     pub theme_name: String,
 }
 
+/// Aplica el tema en Hestia y responde con el tema vigente tras el cambio y su revisión.
+/// Si otro cliente cambió el tema justo después, se devuelve el suyo: el cliente debe
+/// aplicar siempre lo que responde el servidor, no su selección local.
 async fn switch_theme(
     State(state): State<AppState>,
     Json(req): Json<SwitchThemeRequest>,
-) -> Json<serde_json::Value> {
+) -> Response {
+    if let Err(e) = actors::aphrodite::apply_theme(&state.hestia, &req.theme_name).await {
+        return (StatusCode::NOT_FOUND, Json(json!({ "success": false, "error": e }))).into_response();
+    }
+
+    // Aphrodite alinea su estado en memoria con el tema ya persistido
     let msg = ActorMessage::new(
         GodName::Zeus,
         GodName::Aphrodite,
         MessagePayload::Command {
-            action: "switch_theme".to_string(),
+            action: "sync_theme".to_string(),
             data: json!({
                 "theme_name": req.theme_name,
             }),
         }
     );
 
-    let senders = state.god_senders.read().await;
-    if let Some(aphrodite_tx) = senders.get(&GodName::Aphrodite) {
-        let _ = aphrodite_tx.send(msg).await;
+    if let Some(unavailable) = send_to_god(&state, GodName::Aphrodite, msg).await {
+        return unavailable.into_response();
     }
 
-    Json(json!({
-        "success": true,
-        "message": format!("🎨 Aphrodite cambió el tema a: {}", req.theme_name),
-        "theme": req.theme_name,
-    }))
+    let (current, revision) = actors::aphrodite::persisted_theme_with_revision(&state.hestia).unwrap_or_default();
+    (
+        StatusCode::OK,
+        Json(json!({
+            "success": true,
+            "message": format!("🎨 Aphrodite cambió el tema a: {}", current.name),
+            "theme": current,
+            "revision": revision,
+        })),
+    )
+        .into_response()
 }
 
-async fn get_all_themes(State(state): State<AppState>) -> Json<serde_json::Value> {
+async fn get_all_themes(State(state): State<AppState>) -> Response {
     let msg = ActorMessage::new(
         GodName::Zeus,
         GodName::Aphrodite,
@@ -661,24 +2642,27 @@ async fn get_all_themes(State(state): State<AppState>) -> Json<serde_json::Value
         }
     );
 
-    let senders = state.god_senders.read().await;
-    if let Some(aphrodite_tx) = senders.get(&GodName::Aphrodite) {
-        let _ = aphrodite_tx.send(msg).await;
+    if let Some(unavailable) = send_to_god(&state, GodName::Aphrodite, msg).await {
+        return unavailable.into_response();
+    }
+
+    let mut themes: Vec<String> = actors::aphrodite::builtin_themes().into_iter().map(|t| t.name).collect();
+    for custom in actors::aphrodite::persisted_custom_themes(&state.hestia) {
+        if !themes.contains(&custom.name) {
+            themes.push(custom.name);
+        }
     }
+    let current = actors::aphrodite::current_theme(&state.hestia).name;
 
     Json(json!({
-        "themes": [
-            "Olympus Dark",
-            "Olympus Light", 
-            "Golden Olympus",
-            "Cosmic"
-        ],
-        "current": "Olympus Dark",
+        "themes": themes,
+        "current": current,
         "designed_by": "Aphrodite"
     }))
+    .into_response()
 }
 
-async fn get_css_variables(State(state): State<AppState>) -> Json<serde_json::Value> {
+async fn get_css_variables(State(state): State<AppState>) -> Response {
     let msg = ActorMessage::new(
         GodName::Zeus,
         GodName::Aphrodite,
@@ -688,64 +2672,34 @@ async fn get_css_variables(State(state): State<AppState>) -> Json<serde_json::Va
         }
     );
 
-    let senders = state.god_senders.read().await;
-    if let Some(aphrodite_tx) = senders.get(&GodName::Aphrodite) {
-        let _ = aphrodite_tx.send(msg).await;
+    if let Some(unavailable) = send_to_god(&state, GodName::Aphrodite, msg).await {
+        return unavailable.into_response();
     }
 
+    // Variables del tema vigente en Hestia, no solo la paleta oscura
+    let theme = actors::aphrodite::current_theme(&state.hestia);
     Json(json!({
-        "css": r#":root {
-  --color-primary: #6366f1;
-  --color-secondary: #8b5cf6;
-  --color-background: #0f172a;
-  --color-surface: #1e293b;
-  --color-text-primary: #f8fafc;
-  --color-text-secondary: #94a3b8;
-  --color-accent: #f59e0b;
-  --border-radius: 0.75rem;
-}"#,
+        "css": actors::aphrodite::css_variables(&theme),
+        "theme": theme.name,
         "styled_by": "Aphrodite"
     }))
+    .into_response()
 }
 
 async fn get_components(State(state): State<AppState>) -> Json<serde_json::Value> {
-    let msg = ActorMessage::new(
-        GodName::Zeus,
-        GodName::Aphrodite,
-        MessagePayload::Query {
-            query_type: "get_component_styles".to_string(),
-            params: json!({}),
-        }
-    );
-
-    let senders = state.god_senders.read().await;
-    if let Some(aphrodite_tx) = senders.get(&GodName::Aphrodite) {
-        let _ = aphrodite_tx.send(msg).await;
-    }
+    // Hestia es la fuente de verdad de los estilos, como del tema
+    let mut components: Vec<_> = actors::aphrodite::persisted_components(&state.hestia).into_values().collect();
+    components.sort_by(|a, b| a.id.cmp(&b.id));
 
     Json(json!({
-        "components": [
-            {
-                "id": "button",
-                "name": "Botón",
-                "type": "button",
-                "styles": {
-                    "padding": "0.75rem 1.5rem",
-                    "borderRadius": "0.5rem",
-                    "fontWeight": "600"
-                }
-            },
-            {
-                "id": "card",
-                "name": "Tarjeta",
-                "type": "card",
-                "styles": {
-                    "padding": "1.5rem",
-                    "borderRadius": "0.75rem",
-                    "borderWidth": "1px"
-                }
-            }
-        ],
+        "components": components.iter().map(|c| json!({
+            "id": c.id,
+            "name": c.name,
+            "type": c.component_type,
+            "styles": c.styles,
+            "overrides": actors::aphrodite::style_overrides(c),
+            "active": c.active,
+        })).collect::<Vec<_>>(),
         "managed_by": "Aphrodite"
     }))
 }
@@ -760,28 +2714,996 @@ pub struct UpdateComponentRequest {
 async fn update_component(
     State(state): State<AppState>,
     Json(req): Json<UpdateComponentRequest>,
-) -> Json<serde_json::Value> {
+) -> Response {
+    let component = match actors::aphrodite::apply_component_style(&state.hestia, &req.component_id, &req.style_key, &req.style_value).await {
+        Ok(component) => component,
+        Err(e) => {
+            let status = if e.contains("no encontrado") { StatusCode::NOT_FOUND } else { StatusCode::BAD_REQUEST };
+            return (status, Json(json!({ "success": false, "error": e }))).into_response();
+        }
+    };
+
+    // Aphrodite alinea su copia en memoria con lo ya persistido
     let msg = ActorMessage::new(
         GodName::Zeus,
         GodName::Aphrodite,
         MessagePayload::Command {
-            action: "update_component_style".to_string(),
-            data: json!({
-                "component_id": req.component_id,
-                "style_key": req.style_key,
-                "style_value": req.style_value,
-            }),
+            action: "sync_components".to_string(),
+            data: json!({}),
         }
     );
 
-    let senders = state.god_senders.read().await;
-    if let Some(aphrodite_tx) = senders.get(&GodName::Aphrodite) {
-        let _ = aphrodite_tx.send(msg).await;
+    if let Some(unavailable) = send_to_god(&state, GodName::Aphrodite, msg).await {
+        return unavailable.into_response();
     }
 
     Json(json!({
         "success": true,
+        "component": component,
         "message": format!("🎨 Aphrodite actualizó {}.{} = {}", 
             req.component_id, req.style_key, req.style_value),
     }))
+    .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Estado mínimo sin dioses desplegados: los handlers solo tocan la memoria
+    fn test_state(ids: Arc<dyn ids::IdGenerator>) -> AppState {
+        let hestia_path = std::env::temp_dir().join(format!("olympus-test-{}.json", uuid::Uuid::new_v4()));
+        AppState {
+            patients: Arc::new(RwLock::new(HashMap::new())),
+            assessments: Arc::new(RwLock::new(HashMap::new())),
+            vitals_observations: Arc::new(RwLock::new(HashMap::new())),
+            god_senders: Arc::new(RwLock::new(HashMap::new())),
+            genesis_config: Arc::new(GenesisConfig::default()),
+            hestia: HestiaStore::open(hestia_path),
+            mailboxes: MailboxMonitor::new(),
+            heartbeats: HeartbeatRegistry::new(),
+            recoveries: RecoveryRegistry::new(),
+            replies: ReplyLatencyMonitor::default(),
+            trinity: TrinityMembers::new(),
+            load_shedding: LoadShedding::default(),
+            auth: auth::AuthStore::new(auth::PasswordPolicy::default()),
+            jwt_secret: actors::JwtSecret::ephemeral(),
+            otp: otp::OtpDelivery::new(otp::Environment::Development, otp::OtpChannel::Log),
+            log_stream: log_stream::LogStream::new(tracing::Level::WARN),
+            audit: audit::AuditLog::new(),
+            scale_cache: scale_cache::ScaleCache::new(HestiaCache::new(), std::time::Duration::from_secs(60)),
+            max_body_bytes: request_body::DEFAULT_MAX_BODY_BYTES,
+            ids,
+            start_time: std::time::Instant::now(),
+        }
+    }
+
+    fn patient(first_name: &str) -> Patient {
+        Patient {
+            id: None,
+            first_name: first_name.to_string(),
+            last_name: "Pérez".to_string(),
+            identity_card: format!("V-{}", first_name),
+            principal_diagnosis: "Sepsis".to_string(),
+            skin_color: None,
+            admission_type: None,
+        }
+    }
+
+    async fn body_json(response: impl IntoResponse) -> serde_json::Value {
+        let bytes = axum::body::to_bytes(response.into_response().into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    /// Despliega un dios real con su runtime, para los handlers que esperan su respuesta
+    async fn deploy(state: &AppState, actor: Box<dyn actors::OlympianActor>) {
+        let god = actor.name();
+        let (tx, rx) = mpsc::channel(8);
+        tokio::spawn(actors::ActorRuntime::new(actor, rx).with_reply_latency(state.replies.clone()).run());
+        state.god_senders.write().await.insert(god, tx);
+    }
+
+    /// Poseidon con una base de datos SurrealDB en memoria
+    async fn deploy_poseidon(state: &AppState) -> SurrealStore {
+        let store = SurrealStore::in_memory().await.unwrap();
+        deploy(state, Box::new(actors::Poseidon::new(store.clone()))).await;
+        store
+    }
+
+    /// Alta por el handler; devuelve el id que asignó SurrealDB
+    async fn admit(state: &AppState, patient: Patient) -> String {
+        let created = body_json(create_patient(State(state.clone()), Json(patient)).await).await;
+        created["id"].as_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_quick_add_is_persisted_before_caching() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        let request = || QuickPatientRequest {
+            first_name: "Ana".to_string(),
+            last_name: "Pérez".to_string(),
+            principal_diagnosis: "Politrauma".to_string(),
+        };
+
+        // Sin Poseidon no hay ingreso: ni 201 ni paciente en caché
+        let response = quick_add_patient(State(state.clone()), Json(request())).await.into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert!(state.patients.read().await.is_empty());
+
+        let store = deploy_poseidon(&state).await;
+        let response = quick_add_patient(State(state.clone()), Json(request())).await.into_response();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let id = body_json(response).await["id"].as_str().unwrap().to_string();
+        assert_eq!(store.patient(&id).await.unwrap().unwrap()["incomplete"], true);
+    }
+
+    #[tokio::test]
+    async fn test_create_patient_returns_the_database_id() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        let store = deploy_poseidon(&state).await;
+
+        let first = admit(&state, patient("Ana")).await;
+        let second = admit(&state, patient("Eva")).await;
+
+        assert!(first.starts_with("patient:") && second.starts_with("patient:"));
+        assert_ne!(first, second);
+        assert_eq!(store.patient(&second).await.unwrap().unwrap()["first_name"], "Eva");
+        assert_eq!(state.patients.read().await[&second]["first_name"], "Eva");
+    }
+
+    #[tokio::test]
+    async fn test_edited_patient_sorts_first_by_updated_at() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        deploy_poseidon(&state).await;
+        let ana = admit(&state, patient("Ana")).await;
+        let eva = admit(&state, patient("Eva")).await;
+        let created = state.patients.read().await[&ana].clone();
+        assert_eq!(created["created_at"], created["updated_at"]);
+
+        tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+        let patch = json!({ "principal_diagnosis": "Shock séptico" });
+        let edited = body_json(patch_patient(State(state.clone()), Path(ana.clone()), Json(patch)).await).await;
+        assert_eq!(edited["patient"]["created_at"], created["created_at"]);
+        assert!(edited["patient"]["updated_at"].as_str() > created["updated_at"].as_str());
+
+        // Un parche no puede reescribir las marcas de tiempo
+        let forged = json!({ "updated_at": "2000-01-01T00:00:00.000000Z" });
+        let rejected = body_json(patch_patient(State(state.clone()), Path(eva.clone()), Json(forged)).await).await;
+        assert_eq!(rejected["success"], false);
+
+        let page = PageParams { sort: Some("updated_at".to_string()), order: pagination::SortOrder::Desc, ..PageParams::default() };
+        let list = body_json(get_patients(State(state.clone()), page).await).await;
+        let ids: Vec<_> = list["items"].as_array().unwrap().iter().map(|p| p["id"].clone()).collect();
+        assert_eq!(ids, vec![json!(ana), json!(eva)]);
+        assert_eq!(list["patients"], list["items"]);
+    }
+
+    #[tokio::test]
+    async fn test_patch_on_a_stale_version_is_rejected() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        let store = deploy_poseidon(&state).await;
+        let ana = admit(&state, patient("Ana")).await;
+        let read = state.patients.read().await[&ana].clone();
+
+        let first = json!({ "principal_diagnosis": "Shock séptico" });
+        let response = patch_patient(State(state.clone()), Path(ana.clone()), Json(first)).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // Un segundo PATCH que partió de la misma versión no pisa al primero
+        state.patients.write().await.insert(ana.clone(), read.clone());
+        let second = json!({ "principal_diagnosis": "Neumonía" });
+        let response = patch_patient(State(state.clone()), Path(ana.clone()), Json(second)).await.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(store.patient(&ana).await.unwrap().unwrap()["principal_diagnosis"], "Shock séptico");
+
+        // Ni resucita a un paciente eliminado mientras estaba en vuelo
+        let deleted = delete_patient(State(state.clone()), Path(ana.clone())).await.into_response();
+        assert_eq!(deleted.status(), StatusCode::OK);
+        let mut in_flight = store.patient(&ana).await.unwrap().unwrap();
+        in_flight["deleted"] = json!(false);
+        state.patients.write().await.insert(ana.clone(), in_flight);
+        let late = json!({ "principal_diagnosis": "Neumonía" });
+        let response = patch_patient(State(state.clone()), Path(ana.clone()), Json(late)).await.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(store.patient(&ana).await.unwrap().unwrap()["deleted"], true);
+    }
+
+    #[tokio::test]
+    async fn test_patients_are_stored_in_surrealdb_behind_the_cache() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        let store = deploy_poseidon(&state).await;
+        let ana = admit(&state, patient("Ana")).await;
+        assert_eq!(store.patient(&ana).await.unwrap().unwrap()["first_name"], "Ana");
+
+        // Sin caché (como tras un reinicio) el listado y la ficha salen de SurrealDB
+        state.patients.write().await.clear();
+        let list = body_json(get_patients(State(state.clone()), PageParams::default()).await).await;
+        assert_eq!(list["items"][0]["id"], ana);
+        let fetched = get_patient(State(state.clone()), Path(ana.clone()), HeaderMap::new()).await;
+        assert_eq!(body_json(fetched).await["patient"]["first_name"], "Ana");
+        assert!(state.patients.read().await.contains_key(&ana));
+
+        // Si SurrealDB rechaza la escritura el alta falla y no queda en memoria
+        store.execute("DEFINE FIELD first_name ON patient ASSERT $value != 'Eva'").await.unwrap();
+        let response = create_patient(State(state.clone()), Json(patient("Eva"))).await.into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body_json(response).await["error"], "storage error");
+        assert_eq!(state.patients.read().await.len(), 1);
+
+        // DELETE es un borrado lógico: el registro queda en SurrealDB pero ya no se sirve
+        assert!(delete_patient(State(state.clone()), Path(ana.clone())).await.is_ok());
+        assert_eq!(store.patient(&ana).await.unwrap().unwrap()["deleted"], true);
+        let fetched = get_patient(State(state.clone()), Path(ana.clone()), HeaderMap::new()).await;
+        assert_eq!(fetched.into_response().status(), StatusCode::NOT_FOUND);
+        let again = delete_patient(State(state.clone()), Path(ana.clone())).await;
+        assert_eq!(again.into_response().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_merge_rejects_deleted_or_merged_targets() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        let store = deploy_poseidon(&state).await;
+        let ana = admit(&state, patient("Ana")).await;
+        let eva = admit(&state, patient("Eva")).await;
+        let luz = admit(&state, patient("Luz")).await;
+        let merge = |target: &str, source: &str| {
+            let req = MergeRequest { source_id: source.to_string() };
+            merge_patients(State(state.clone()), Path(target.to_string()), Json(req))
+        };
+
+        let merged = body_json(merge(&ana, &eva).await).await;
+        assert_eq!(merged["success"], true);
+        assert_eq!(store.patient(&eva).await.unwrap().unwrap()["merged_into"], ana);
+
+        // Eva ya fue fusionada en Ana: no puede recibir a Luz
+        let response = merge(&eva, &luz).await.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(body_json(response).await["merged_into"], ana);
+        assert_ne!(state.patients.read().await[&luz].get("deleted"), Some(&json!(true)));
+
+        assert!(delete_patient(State(state.clone()), Path(ana.clone())).await.is_ok());
+        let response = merge(&ana, &luz).await.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let response = merge("patient:99", &luz).await.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_merge_moves_stored_assessments_in_the_database() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        let store = deploy_poseidon(&state).await;
+        deploy(&state, Box::new(actors::Athena::new())).await;
+        let ana = admit(&state, patient("Ana")).await;
+        let eva = admit(&state, patient("Eva")).await;
+        let glasgow = GlasgowRequest { patient_id: eva.clone(), eye: 3, verbal: 4, motor: 5, pupils: None };
+        assert_eq!(calculate_glasgow(State(state.clone()), Lang::Es, Json(glasgow)).await.status(), StatusCode::OK);
+        assert_eq!(store.assessments().await.unwrap()[0]["patient_id"], eva);
+        let merge = |target: &str, source: &str| {
+            let req = MergeRequest { source_id: source.to_string() };
+            merge_patients(State(state.clone()), Path(target.to_string()), Json(req))
+        };
+
+        let stale_eva = state.patients.read().await[&eva].clone();
+        let merged = body_json(merge(&ana, &eva).await).await;
+        assert_eq!(merged["assessments_moved"], 1);
+        assert_eq!(store.assessments().await.unwrap()[0]["patient_id"], ana);
+        assert!(state.assessments.read().await.values().all(|a| a["patient_id"] == ana));
+
+        // Con la caché aún sin la fusión (otra petición en curso) la transacción la rechaza
+        state.patients.write().await.insert(eva.clone(), stale_eva);
+        let response = merge(&eva, &ana).await.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(body_json(response).await["merged_into"], ana);
+        assert_eq!(store.patient(&ana).await.unwrap().unwrap().get("deleted"), None);
+    }
+
+    #[tokio::test]
+    async fn test_production_login_never_returns_the_otp() {
+        let outbox = std::env::temp_dir().join(format!("olympus-otp-{}.jsonl", uuid::Uuid::new_v4()));
+        let mut state = test_state(Arc::new(ids::SequentialIds::new()));
+        state.auth = auth::AuthStore::with_default_admin(auth::PasswordPolicy::default()).unwrap();
+        state.otp = otp::OtpDelivery::new(otp::Environment::Production, otp::OtpChannel::File(outbox.clone()));
+        deploy(&state, Box::new(actors::Hades::new(state.auth.clone()))).await;
+
+        let credentials = AuthRequest { username: "admin".to_string(), password: "admin123".to_string() };
+        let step1 = body_json(login_step1(State(state.clone()), Json(credentials)).await).await;
+        assert_eq!(step1["success"], true);
+        assert!(step1.get("otp_code").is_none());
+
+        // El código solo llega por el canal de Iris
+        let delivered: serde_json::Value = serde_json::from_str(std::fs::read_to_string(&outbox).unwrap().trim()).unwrap();
+        let code = delivered["otp_code"].as_str().unwrap().to_string();
+        assert_eq!(delivered["username"], "admin");
+        assert!(!step1.to_string().contains(&code));
+        std::fs::remove_file(outbox).unwrap();
+
+        let session_id = step1["session_id"].as_str().unwrap().to_string();
+        let wrong = OtpRequest { session_id: session_id.clone(), otp_code: "abcdef".to_string() };
+        let rejected = login_step2(State(state.clone()), Json(wrong)).await.into_response();
+        assert_eq!(rejected.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(body_json(rejected).await["error"], "Código OTP inválido");
+        let step2 = body_json(login_step2(State(state.clone()), Json(OtpRequest { session_id, otp_code: code })).await).await;
+        assert_eq!(step2["success"], true);
+        assert!(step2["token"].is_string());
+        assert_eq!(step2["must_change_password"], true);
+
+        // En desarrollo el código acompaña a la respuesta
+        state.otp = otp::OtpDelivery::new(otp::Environment::Development, otp::OtpChannel::Log);
+        let credentials = AuthRequest { username: "admin".to_string(), password: "admin123".to_string() };
+        let step1 = body_json(login_step1(State(state), Json(credentials)).await).await;
+        assert_eq!(step1["otp_code"].as_str().unwrap().len(), otp::OTP_DIGITS);
+    }
+
+    #[tokio::test]
+    async fn test_rejected_patient_reports_the_offending_field() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        deploy_poseidon(&state).await;
+        assert!(create_patient(State(state.clone()), Json(patient("Ana"))).await.is_ok());
+
+        let duplicate = Patient { first_name: "Otra".to_string(), ..patient("Ana") };
+        let response = create_patient(State(state.clone()), Json(duplicate)).await.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let body = body_json(response).await;
+        assert_eq!(body["errors"]["identity_card"], "Ya existe un paciente con la cédula VANA");
+
+        let blank = Patient { last_name: " ".to_string(), ..patient("Eva") };
+        let response = create_patient(State(state.clone()), Json(blank)).await.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body_json(response).await["errors"], json!({ "last_name": "Campo obligatorio" }));
+        assert_eq!(state.patients.read().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_assessment_for_unknown_patient_is_rejected() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        deploy_poseidon(&state).await;
+        deploy(&state, Box::new(actors::Athena::new())).await;
+        let glasgow = |patient_id: &str| GlasgowRequest { patient_id: patient_id.to_string(), eye: 3, verbal: 4, motor: 5, pupils: None };
+
+        let rejected = calculate_glasgow(State(state.clone()), Lang::Es, Json(glasgow("patient:404"))).await;
+        assert_eq!(rejected.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(state.assessments.read().await.is_empty());
+
+        // Anónimo: se calcula pero no se guarda
+        let anonymous = calculate_glasgow(State(state.clone()), Lang::Es, Json(glasgow(""))).await;
+        assert_eq!(anonymous.status(), StatusCode::OK);
+
+        let ana = admit(&state, patient("Ana")).await;
+        let stored = calculate_glasgow(State(state.clone()), Lang::Es, Json(glasgow(&ana))).await;
+        assert_eq!(stored.status(), StatusCode::OK);
+        assert_eq!(state.assessments.read().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_glasgow_result_is_athenas_reply() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        let req = GlasgowRequest { patient_id: String::new(), eye: 2, verbal: 2, motor: 4, pupils: Some(scales::Pupils::None) };
+
+        // Sin Athena desplegada no hay quien calcule
+        let response = calculate_glasgow(State(state.clone()), Lang::En, Json(req.clone())).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        deploy(&state, Box::new(actors::Athena::new())).await;
+        let body = body_json(calculate_glasgow(State(state.clone()), Lang::En, Json(req.clone())).await).await;
+        assert_eq!(body["total"], 8);
+        assert_eq!(body["interpretation"], "Severe coma");
+        assert_eq!(body["gcs_p"], 6);
+        assert_eq!(body["calculated_by"], "Athena");
+        assert_eq!(state.replies.latency(GodName::Athena).samples, 1);
+
+        // Un dios que procesa el comando sin responder no deja al handler esperando
+        let (msg, reply) = ActorMessage::new(
+            GodName::Zeus,
+            GodName::Athena,
+            MessagePayload::Command { action: "unknown".to_string(), data: json!({}) },
+        )
+        .with_reply();
+        state.god_senders.read().await[&GodName::Athena].send(msg).await.unwrap();
+        assert!(reply.await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_apache_and_saps_are_calculated_by_athena() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        deploy_poseidon(&state).await;
+        deploy(&state, Box::new(actors::Athena::new())).await;
+        let created = body_json(create_patient(State(state.clone()), Json(patient("Ana"))).await).await;
+        let patient_id = created["id"].as_str().unwrap().to_string();
+
+        let apache: ApacheIIRequest = serde_json::from_value(json!({
+            "temperature": 39.2, "mean_arterial_pressure": 60, "heart_rate": 125, "respiratory_rate": 28,
+            "oxygenation_type": "pao2", "oxygenation_value": 58, "arterial_ph": 7.4, "serum_sodium": 140,
+            "serum_potassium": 4.0, "serum_creatinine": 2.1, "hematocrit": 40.0, "white_blood_count": 22.0,
+            "glasgow_coma_score": 12, "age": 70, "chronic_health": "non_operative", "patient_id": patient_id,
+        }))
+        .unwrap();
+        let body = body_json(calculate_apache(State(state.clone()), Json(apache.clone())).await).await;
+        assert_eq!((body["scale"].as_str(), body["score"].as_u64()), (Some("APACHE II"), Some(29)));
+        assert_eq!(body["predicted_mortality"], 55.0);
+        assert_eq!(body["severity"], "Riesgo muy alto");
+        assert_eq!(body["critical_lab"], false);
+
+        let saps: SAPSIIRequest = serde_json::from_value(json!({
+            "age": 65, "heart_rate": 130, "systolic_bp": 85, "temperature": 37.0, "pao2_fio2": 150,
+            "urinary_output": 0.8, "serum_urea": 50.0, "white_blood_count": 8.0, "serum_potassium": 4.0,
+            "serum_sodium": 140, "serum_bicarbonate": 24.0, "bilirubin": 1.0, "glasgow": 10,
+            "chronic_disease": "none", "admission_type": "medical", "patient_id": patient_id,
+        }))
+        .unwrap();
+        let body = body_json(calculate_saps(State(state.clone()), Json(saps)).await).await;
+        assert_eq!((body["scale"].as_str(), body["score"].as_u64()), (Some("SAPS II"), Some(53)));
+        assert_eq!(body["severity"], "Alto riesgo");
+
+        let stored: Vec<String> = state.assessments.read().await.values().map(|a| a["scale"].as_str().unwrap().to_string()).collect();
+        assert_eq!(stored.len(), 2);
+        assert!(stored.contains(&"APACHE II".to_string()) && stored.contains(&"SAPS II".to_string()));
+        let csv = export_patient_history(State(state.clone()), Path(patient_id.clone())).await;
+        let csv = String::from_utf8(axum::body::to_bytes(csv.into_body(), usize::MAX).await.unwrap().to_vec()).unwrap();
+        assert_eq!(csv.lines().count(), 3);
+        assert!(csv.contains(",APACHE II,29,Riesgo muy alto,55.0\n"));
+
+        // Athena rechaza los parámetros inválidos y no se guarda nada
+        let invalid = ApacheIIRequest { fio2: 0.6, ..apache };
+        let response = calculate_apache(State(state.clone()), Json(invalid)).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body_json(response).await["error"].as_str().unwrap().contains("aa_gradient"));
+        assert_eq!(state.assessments.read().await.len(), 2);
+        assert!(clinical_alerts::list(&state.hestia, &clinical_alerts::AlertFilter::default()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_critical_labs_raise_alerts_regardless_of_score() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        deploy_poseidon(&state).await;
+        deploy(&state, Box::new(actors::Athena::new())).await;
+        let ana = admit(&state, patient("Ana")).await;
+
+        // APACHE bajo con potasio 7.0
+        let apache: ApacheIIRequest = serde_json::from_value(json!({
+            "temperature": 37.0, "mean_arterial_pressure": 85, "heart_rate": 80, "respiratory_rate": 16,
+            "oxygenation_type": "pao2", "oxygenation_value": 90, "arterial_ph": 7.4, "serum_sodium": 140,
+            "serum_potassium": 7.0, "serum_creatinine": 1.0, "hematocrit": 40.0, "white_blood_count": 8.0,
+            "glasgow_coma_score": 15, "age": 40, "chronic_health": "none", "patient_id": &ana,
+        }))
+        .unwrap();
+        let body = body_json(calculate_apache(State(state.clone()), Json(apache)).await).await;
+        assert!(body["score"].as_u64().unwrap() < 10);
+        assert_eq!(body["critical_lab"], true);
+        assert_eq!(body["critical_labs"][0]["lab"], "serum_potassium");
+
+        // SAPS II con sodio 165
+        let saps: SAPSIIRequest = serde_json::from_value(json!({
+            "age": 30, "heart_rate": 80, "systolic_bp": 120, "temperature": 37.0, "pao2_fio2": null,
+            "urinary_output": 1.5, "serum_urea": 20.0, "white_blood_count": 8.0, "serum_potassium": 4.0,
+            "serum_sodium": 165, "serum_bicarbonate": 24.0, "bilirubin": 1.0, "glasgow": 15,
+            "chronic_disease": "none", "admission_type": "scheduled", "patient_id": &ana,
+        }))
+        .unwrap();
+        let body = body_json(calculate_saps(State(state.clone()), Json(saps)).await).await;
+        assert_eq!(body["critical_labs"][0]["lab"], "serum_sodium");
+
+        let alerts = clinical_alerts::list(&state.hestia, &clinical_alerts::AlertFilter::default());
+        assert_eq!(alerts.len(), 2);
+        assert!(alerts.iter().all(|a| a.category == clinical_alerts::AlertCategory::CriticalLab && a.patient_id == ana));
+    }
+
+    #[tokio::test]
+    async fn test_admission_type_is_validated_and_feeds_saps() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        deploy_poseidon(&state).await;
+        deploy(&state, Box::new(actors::Athena::new())).await;
+
+        let invalid = Patient { admission_type: Some("Sometimes".to_string()), ..patient("Eva") };
+        let response = create_patient(State(state.clone()), Json(invalid)).await.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body_json(response).await["errors"]["admission_type"].as_str().unwrap().contains("Elective, Urgent, Transfer"));
+        assert!(state.patients.read().await.is_empty());
+
+        let elective = Patient { admission_type: Some("Elective".to_string()), skin_color: Some("Olive".to_string()), ..patient("Ana") };
+        let created = body_json(create_patient(State(state.clone()), Json(elective)).await).await;
+        assert_eq!((created["patient"]["admission_type"].as_str(), created["patient"]["skin_color"].as_str()), (Some("Elective"), Some("Olive")));
+        let defaulted = body_json(create_patient(State(state.clone()), Json(patient("Eva"))).await).await;
+        assert_eq!((defaulted["patient"]["admission_type"].as_str(), defaulted["patient"]["skin_color"].as_str()), (Some("Urgent"), Some("Fair")));
+
+        let ana = created["id"].as_str().unwrap().to_string();
+        let patch = json!({ "skin_color": "Purple" });
+        let response = patch_patient(State(state.clone()), Path(ana.clone()), Json(patch)).await;
+        assert!(body_json(response).await["errors"]["skin_color"].as_str().is_some());
+
+        // Sin tipo de ingreso en la petición, el electivo del paciente puntúa como cirugía programada (0)
+        let saps: SAPSIIRequest = serde_json::from_value(json!({
+            "age": 65, "heart_rate": 130, "systolic_bp": 85, "temperature": 37.0, "pao2_fio2": 150,
+            "urinary_output": 0.8, "serum_urea": 50.0, "white_blood_count": 8.0, "serum_potassium": 4.0,
+            "serum_sodium": 140, "serum_bicarbonate": 24.0, "bilirubin": 1.0, "glasgow": 10,
+            "chronic_disease": "none", "patient_id": &ana,
+        }))
+        .unwrap();
+        let body = body_json(calculate_saps(State(state.clone()), Json(saps)).await).await;
+        assert_eq!(body["score"], 47);
+    }
+
+    #[tokio::test]
+    async fn test_css_variables_follow_the_persisted_theme() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        let css = body_json(get_css_variables(State(state.clone())).await).await;
+        assert_eq!(css["theme"], "Olympus Dark");
+        assert!(css["css"].as_str().unwrap().contains("--color-primary: #6366f1;"));
+        let current = body_json(get_current_theme(State(state.clone())).await).await;
+        assert_eq!((current["theme"]["font_family"].as_str(), current["revision"].as_u64()), (Some("Inter, system-ui, sans-serif"), Some(0)));
+
+        let switched = switch_theme(State(state.clone()), Json(SwitchThemeRequest { theme_name: "Golden Olympus".to_string() })).await;
+        assert_eq!(switched.status(), StatusCode::OK);
+
+        let css = body_json(get_css_variables(State(state.clone())).await).await;
+        let css = css["css"].as_str().unwrap();
+        assert!(css.contains("--color-primary: #fbbf24;"));
+        assert!(css.contains("--font-family: Georgia, serif;"));
+        let current = body_json(get_current_theme(State(state.clone())).await).await;
+        assert_eq!(current["theme"]["name"], "Golden Olympus");
+        assert_eq!(current["theme"]["error"], "#f87171");
+        assert_eq!(body_json(get_all_themes(State(state)).await).await["current"], "Golden Olympus");
+    }
+
+    #[tokio::test]
+    async fn test_request_to_crashed_god_returns_503() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        // Receptor descartado: como si la tarea de Poseidon hubiera terminado
+        let (tx, rx) = mpsc::channel(8);
+        drop(rx);
+        state.god_senders.write().await.insert(GodName::Poseidon, tx);
+
+        let response = create_patient(State(state.clone()), Json(patient("Ana"))).await.into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body_json(response).await["error"], "god unavailable");
+        assert!(state.patients.read().await.is_empty());
+        assert!(state.mailboxes.is_unavailable(GodName::Poseidon));
+
+        // Las siguientes peticiones también fallan en lugar de simular éxito
+        let response = get_patients(State(state.clone()), PageParams::default()).await.into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let response = api_god(State(state.clone()), Path("poseidon".to_string())).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_identical_sofa_requests_hit_the_cache() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        deploy_poseidon(&state).await;
+        let sofa = |patient_id: &str, assessed_at: Option<&str>| -> SofaRequest {
+            serde_json::from_value(json!({
+                "patient_id": patient_id, "respiratory": 2, "coagulation": 1, "liver": 0,
+                "cardiovascular": 3, "cns": 1, "renal": 2, "assessed_at": assessed_at,
+            }))
+            .unwrap()
+        };
+
+        // Cálculo anónimo repetido: un cálculo y un acierto
+        let first = body_json(calculate_sofa(State(state.clone()), Json(sofa("", None))).await).await;
+        let second = body_json(calculate_sofa(State(state.clone()), Json(sofa("", None))).await).await;
+        assert_eq!(first, second);
+        assert_eq!(state.scale_cache.stats()["hits"], 1);
+        assert_eq!(state.scale_cache.stats()["misses"], 1);
+
+        // Con paciente la repetición sale de la caché, pero cada envío queda guardado
+        let ana = admit(&state, patient("Ana")).await;
+        calculate_sofa(State(state.clone()), Json(sofa(&ana, None))).await;
+        calculate_sofa(State(state.clone()), Json(sofa(&ana, None))).await;
+        assert_eq!(state.scale_cache.stats()["hits"], 2);
+        assert_eq!(state.assessments.read().await.len(), 2);
+
+        // También con assessed_at propio, cada uno con su hora
+        let at = Some("2026-03-01T08:00:00Z");
+        calculate_sofa(State(state.clone()), Json(sofa(&ana, at))).await;
+        calculate_sofa(State(state.clone()), Json(sofa(&ana, at))).await;
+        let assessments = state.assessments.read().await;
+        assert_eq!(assessments.len(), 4);
+        assert_eq!(assessments.values().filter(|a| a["assessed_at"] == "2026-03-01T08:00:00+00:00").count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_identical_apache_requests_hit_the_cache() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        deploy_poseidon(&state).await;
+        deploy(&state, Box::new(actors::Athena::new())).await;
+        let apache: ApacheIIRequest = serde_json::from_value(json!({
+            "temperature": 38.5, "mean_arterial_pressure": 65, "heart_rate": 120, "respiratory_rate": 28,
+            "oxygenation_type": "pao2", "oxygenation_value": 65, "arterial_ph": 7.30, "serum_sodium": 135,
+            "serum_potassium": 4.0, "serum_creatinine": 2.0, "hematocrit": 35.0, "white_blood_count": 15.0,
+            "glasgow_coma_score": 12, "age": 70, "chronic_health": "none"
+        }))
+        .unwrap();
+
+        let first = body_json(calculate_apache(State(state.clone()), Json(apache.clone())).await).await;
+        let second = body_json(calculate_apache(State(state.clone()), Json(apache.clone())).await).await;
+        assert_eq!(first["scale"], "APACHE II");
+        assert_eq!(first, second);
+        assert_eq!(state.scale_cache.stats()["hits"], 1);
+
+        // El acierto de caché no se salta el guardado de la evaluación del paciente
+        let ana = admit(&state, patient("Ana")).await;
+        let for_ana = ApacheIIRequest { patient_id: Some(ana.clone()), ..apache };
+        calculate_apache(State(state.clone()), Json(for_ana.clone())).await;
+        calculate_apache(State(state.clone()), Json(for_ana)).await;
+        assert_eq!(state.scale_cache.stats()["hits"], 2);
+        let stored = state.assessments.read().await.values().filter(|a| a["patient_id"] == json!(ana)).count();
+        assert_eq!(stored, 2);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_god_is_listed_with_its_domain() {
+        let mut state = test_state(Arc::new(ids::SequentialIds::new()));
+        state.genesis_config = Arc::new(GenesisConfig::default().disable_list("Chaos").unwrap());
+        let (tx, _rx) = mpsc::channel(8);
+        state.god_senders.write().await.insert(GodName::Zeus, tx);
+
+        let Json(body) = api_gods_enabled(State(state)).await;
+        assert_eq!(body["total"], 20);
+        assert_eq!(body["disabled"], 1);
+        let god = |name: &str| body["gods"].as_array().unwrap().iter().find(|g| g["name"] == name).unwrap().clone();
+        assert_eq!(god("Chaos"), json!({
+            "name": "Chaos", "domain": "Testing", "enabled": false, "trinity": false, "running": false,
+        }));
+        assert_eq!(god("Zeus")["trinity"], true);
+        assert_eq!(god("Zeus")["running"], true);
+    }
+
+    #[tokio::test]
+    async fn test_overdue_reassessment_reminder_until_reassessed() {
+        use chrono::{Duration, Utc};
+
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        let news2 = |total: i64, at: chrono::DateTime<Utc>| {
+            json!({ "scale": "NEWS2", "patient_id": "patient:1", "total": total, "assessed_at": at.to_rfc3339() })
+        };
+        let now = Utc::now();
+        state.patients.write().await.insert("patient:1".to_string(), json!({ "id": "patient:1" }));
+        state.assessments.write().await.insert("a1".to_string(), news2(6, now - Duration::hours(5)));
+        let cadence = reassessment::Cadence::default();
+
+        // Riesgo medio sin reevaluar en 4 h: un único recordatorio
+        let raised = reassessment::scan(&state, &cadence, now).await;
+        assert_eq!(raised.len(), 1);
+        assert_eq!(raised[0].category, clinical_alerts::AlertCategory::OverdueReassessment);
+        assert!(reassessment::scan(&state, &cadence, now).await.is_empty());
+
+        // Reevaluado: el recordatorio se resuelve; al volver a vencer se emite otro
+        state.assessments.write().await.insert("a2".to_string(), news2(6, now - Duration::minutes(1)));
+        assert!(reassessment::scan(&state, &cadence, now).await.is_empty());
+        assert!(clinical_alerts::list(&state.hestia, &clinical_alerts::AlertFilter::default()).is_empty());
+        assert_eq!(reassessment::scan(&state, &cadence, now + Duration::hours(5)).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_load_shedding_rejects_analytics_but_keeps_core_routes() {
+        use tower::ServiceExt;
+
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        deploy_poseidon(&state).await;
+        deploy(&state, Box::new(actors::Athena::new())).await;
+        let app = Router::new()
+            .route("/api/patients", get(get_patients))
+            .route("/api/scales/glasgow", post(calculate_glasgow))
+            .route("/api/dionysus/analytics", get(dionysus_analytics))
+            .route_layer(middleware::from_fn_with_state(state.clone(), shed_heavy_load))
+            .with_state(state.clone());
+        let call = |uri: &str, body: Option<serde_json::Value>| {
+            let request = match body {
+                Some(body) => Request::post(uri).header("content-type", "application/json").body(Body::from(body.to_string())),
+                None => Request::get(uri).body(Body::empty()),
+            };
+            app.clone().oneshot(request.unwrap())
+        };
+        let glasgow = json!({ "eye": 4, "verbal": 5, "motor": 6 });
+
+        assert_eq!(call("/api/dionysus/analytics", None).await.unwrap().status(), StatusCode::OK);
+
+        // Demeter informa memoria crítica
+        assert_eq!(state.load_shedding.observe(96.0), Some(true));
+        let shed = call("/api/dionysus/analytics", None).await.unwrap();
+        assert_eq!(shed.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert!(shed.headers().contains_key(axum::http::header::RETRY_AFTER));
+        assert_eq!(body_json(shed).await["error"], "load shedding");
+        assert_eq!(call("/api/patients", None).await.unwrap().status(), StatusCode::OK);
+        assert_eq!(call("/api/scales/glasgow", Some(glasgow)).await.unwrap().status(), StatusCode::OK);
+
+        // La presión baja del margen de recuperación: la analítica vuelve
+        assert_eq!(state.load_shedding.observe(70.0), Some(false));
+        let analytics = call("/api/dionysus/analytics", None).await.unwrap();
+        assert_eq!(analytics.status(), StatusCode::OK);
+        assert_eq!(body_json(analytics).await["patients"]["total"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_import_stops_at_oversized_bodies_and_lines() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        let store = deploy_poseidon(&state).await;
+        let line = r#"{"identity_card": "V-1", "scale": "glasgow", "inputs": {"eye": 4, "verbal": 5, "motor": 6}}"#;
+        let import = |max_body_bytes: usize, body: String| {
+            let state = AppState { max_body_bytes, ..state.clone() };
+            let options = import::ImportOptions { create_missing: true };
+            async move {
+                let response = import_assessments(State(state), Lang::Es, Query(options), Body::from(body)).await;
+                assert_eq!(response.status(), StatusCode::OK);
+                let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                String::from_utf8_lossy(&bytes)
+                    .lines()
+                    .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        // El cuerpo que supera el límite corta la importación sin resumen
+        let results = import(256, format!("{}\n", line).repeat(4)).await;
+        assert_eq!(results.len(), 1);
+        assert!(results[0]["error"].as_str().unwrap().contains("Error leyendo el cuerpo"));
+        assert!(state.patients.read().await.is_empty());
+
+        // Una línea desmedida se informa con su número; las anteriores ya se importaron
+        let long_line = format!("{}\n{}\n{}\n", line, " ".repeat(import::MAX_LINE_BYTES + 1), line);
+        let results = import(request_body::DEFAULT_MAX_BODY_BYTES, long_line).await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["success"], true);
+        assert_eq!((results[1]["success"].as_bool(), results[1]["line"].as_u64()), (Some(false), Some(2)));
+        assert_eq!(store.assessments().await.unwrap().len(), 1);
+
+        let results = import(256, format!("{}\n", line)).await;
+        assert!(results.last().unwrap()["summary"].is_object());
+    }
+
+    #[tokio::test]
+    async fn test_import_stubs_are_persisted_through_poseidon() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        let store = deploy_poseidon(&state).await;
+        let body = concat!(
+            r#"{"identity_card": "V-7", "scale": "glasgow", "inputs": {"eye": 4, "verbal": 5, "motor": 6}}"#, "\n",
+            r#"{"identity_card": "V-7", "scale": "glasgow", "inputs": {"eye": 3, "verbal": 4, "motor": 5}}"#,
+        );
+        let options = import::ImportOptions { create_missing: true };
+        let response = import_assessments(State(state.clone()), Lang::Es, Query(options), Body::from(body)).await;
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let summary: serde_json::Value = serde_json::from_str(String::from_utf8_lossy(&bytes).lines().last().unwrap()).unwrap();
+        assert_eq!(summary["summary"]["created_patients"], 1);
+
+        // El esbozo está en SurrealDB con el mismo id que en la caché
+        let stored = store.patients().await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0]["stub"], true);
+        let id = stored[0]["id"].as_str().unwrap();
+        assert!(state.patients.read().await.contains_key(id));
+        assert_eq!(store.assessments().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_audit_trail_pages_like_the_patient_list() {
+        use tower::ServiceExt;
+
+        let mut state = test_state(Arc::new(ids::SequentialIds::new()));
+        state.auth = auth::AuthStore::with_admin(auth::PasswordPolicy::default(), otp::Environment::Production, Some("Guardia-2026x")).unwrap();
+        deploy_poseidon(&state).await;
+        let pending = state.auth.begin_login("admin", "123456");
+        let (_, token) = state.auth.complete_login(&pending, "123456").unwrap();
+        let app = Router::new()
+            .route("/api/patients", get(get_patients).post(create_patient))
+            .route("/api/nemesis/audit", get(list_audit))
+            .route_layer(middleware::from_fn_with_state(state.clone(), audit_mutations))
+            .with_state(state.clone());
+        let call = |request: axum::http::request::Builder, body: Body| app.clone().oneshot(request.body(body).unwrap());
+        let authorized = |request: axum::http::request::Builder| request.header("authorization", format!("Bearer {}", token));
+
+        for name in ["Ana", "Eva", "Luz"] {
+            let request = authorized(Request::post("/api/patients")).header("content-type", "application/json");
+            let response = call(request, Body::from(serde_json::to_string(&patient(name)).unwrap())).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        // Las lecturas no dejan rastro; las peticiones anónimas sí, sin usuario
+        call(Request::get("/api/patients"), Body::empty()).await.unwrap();
+        let anonymous = call(Request::post("/api/patients").header("content-type", "application/json"), Body::from("{}")).await.unwrap();
+        assert_eq!(anonymous.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let audit = call(authorized(Request::get("/api/nemesis/audit?sort=id&order=desc&limit=2")), Body::empty()).await.unwrap();
+        assert_eq!(audit.status(), StatusCode::OK);
+        let audit = body_json(audit).await;
+        assert_eq!(audit["total"], 4);
+        assert_eq!(audit["limit"], 2);
+        assert_eq!(audit["items"][0]["status"], 422);
+        assert_eq!(audit["items"][0]["username"], serde_json::Value::Null);
+        assert_eq!(audit["items"][1]["username"], "admin");
+        assert_eq!(audit["items"][1]["method"], "POST");
+
+        let patients = body_json(call(Request::get("/api/patients?sort=first_name&order=desc&limit=2"), Body::empty()).await.unwrap()).await;
+        assert_eq!(patients["total"], 3);
+        assert_eq!(patients["items"][0]["first_name"], "Luz");
+        assert_eq!(patients["patients"], patients["items"]);
+
+        // Solo el administrador
+        assert_eq!(call(Request::get("/api/nemesis/audit"), Body::empty()).await.unwrap().status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_clinical_alerts_page_like_the_audit_trail() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        let alert = |patient_id: &str, message: &str| clinical_alerts::NewClinicalAlert {
+            patient_id: patient_id.to_string(),
+            category: clinical_alerts::AlertCategory::CriticalLab,
+            severity: clinical_alerts::AlertSeverity::Critical,
+            message: message.to_string(),
+        };
+        for (patient_id, message) in [("p1", "Potasio 7.0"), ("p1", "Sodio 165"), ("p2", "Lactato 6")] {
+            assert!(create_clinical_alert(State(state.clone()), Json(alert(patient_id, message))).await.is_ok());
+        }
+        let response = create_clinical_alert(State(state.clone()), Json(alert("p1", " "))).await.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let p1 = clinical_alerts::AlertFilter { patient_id: Some("p1".to_string()), include_resolved: false };
+        let page = PageParams { limit: 1, ..PageParams::default() };
+        let body = body_json(list_clinical_alerts(State(state.clone()), Query(p1), page).await).await;
+        assert_eq!((body["total"].as_u64(), body["limit"].as_u64()), (Some(2), Some(1)));
+        assert_eq!(body["items"][0]["message"], "Sodio 165");
+
+        let id = body["items"][0]["id"].as_str().unwrap().to_string();
+        let acknowledged = body_json(acknowledge_clinical_alert(State(state.clone()), Path(id)).await).await;
+        assert_eq!(acknowledged["alert"]["acknowledged"], true);
+        let missing = acknowledge_clinical_alert(State(state.clone()), Path("nope".to_string())).await.into_response();
+        assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_vital_signs_validated_alike_for_news2_vitals_and_compare() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        state.patients.write().await.insert("patient:1".to_string(), json!({ "id": "patient:1" }));
+        let tachycardia = json!({
+            "respiration_rate": 18, "oxygen_saturation": 96, "temperature": 37.2, "heart_rate": 420, "systolic_bp": 110,
+        });
+
+        let news2: News2Request = serde_json::from_value(tachycardia.clone()).unwrap();
+        let response = calculate_news2(State(state.clone()), Lang::Es, Json(news2)).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body_json(response).await["errors"]["heart_rate"].is_string());
+
+        let vitals: scales::Vitals = serde_json::from_value(tachycardia.clone()).unwrap();
+        let response = record_vitals(State(state.clone()), Path("patient:1".to_string()), Json(vitals.clone())).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(state.vitals_observations.read().await.is_empty());
+        assert_eq!(compare_scales(Lang::Es, Json(vitals)).await.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        // Con valores plausibles la observación se guarda con los mismos nombres de campo
+        let mut plausible = tachycardia;
+        plausible["heart_rate"] = json!(88);
+        let vitals: scales::Vitals = serde_json::from_value(plausible).unwrap();
+        let response = record_vitals(State(state.clone()), Path("patient:1".to_string()), Json(vitals)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_json(response).await["observation"]["heart_rate"], 88);
+    }
+
+    #[tokio::test]
+    async fn test_stuck_recovery_is_listed_and_cancelled() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        state.heartbeats.register(GodName::Hermes, HeartbeatConfig::STANDARD);
+        let mut erinyes = actors::Erinyes::new(state.heartbeats.clone(), state.recoveries.clone(), TrinityMembers::new());
+        let timeout = chrono::Duration::milliseconds(HeartbeatConfig::STANDARD.timeout_ms as i64 + 1);
+        let mut now = chrono::Utc::now() + timeout;
+
+        // Hermes deja de latir: la recuperación entra en cola y consume un intento por timeout
+        erinyes.sweep_recoveries(now);
+        now += timeout;
+        erinyes.sweep_recoveries(now);
+        erinyes.sweep_recoveries(now);
+        let listed = list_recoveries(State(state.clone())).await.0;
+        assert_eq!(listed["total"], 1);
+        assert_eq!(listed["recoveries"][0]["god"], "Hermes");
+        assert_eq!(listed["recoveries"][0]["urgency"], "standard");
+        assert_eq!(listed["recoveries"][0]["status"], "running");
+        assert_eq!(listed["recoveries"][0]["attempts"], 1);
+        let id = listed["recoveries"][0]["id"].as_u64().unwrap();
+
+        // Solo el administrador cancela: 401 sin sesión, 403 con la de otro usuario
+        let bearer = |username: &str| {
+            let pending = state.auth.begin_login(username, "123456");
+            let (_, token) = state.auth.complete_login(&pending, "123456").unwrap();
+            let mut headers = HeaderMap::new();
+            headers.insert(axum::http::header::AUTHORIZATION, format!("Bearer {}", token).parse().unwrap());
+            headers
+        };
+        let response = cancel_recovery(State(state.clone()), HeaderMap::new(), Path(id)).await.into_response();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let response = cancel_recovery(State(state.clone()), bearer("ana"), Path(id)).await.into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let headers = bearer("admin");
+        let response = cancel_recovery(State(state.clone()), headers.clone(), Path(id)).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_json(response).await["cancelled"]["god"], "Hermes");
+        let response = cancel_recovery(State(state.clone()), headers, Path(id)).await.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        // Cancelada: Hermes sigue caído pero ya no consume intentos ni reabre la recuperación
+        now += timeout;
+        erinyes.sweep_recoveries(now);
+        assert_eq!(list_recoveries(State(state.clone())).await.0["total"], 0);
+
+        // Si vuelve a latir y cae otra vez, se abre una nueva
+        let beat = ActorMessage::new(GodName::Hermes, GodName::Erinyes, MessagePayload::Heartbeat { timestamp: now });
+        actors::OlympianActor::handle_message(&mut erinyes, beat).await;
+        erinyes.sweep_recoveries(now + timeout);
+        let listed = list_recoveries(State(state.clone())).await.0;
+        assert_eq!(listed["recoveries"][0]["status"], "queued");
+        assert_ne!(listed["recoveries"][0]["id"].as_u64().unwrap(), id);
+    }
+
+    #[tokio::test]
+    async fn test_discharge_moves_patient_out_of_active_count() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        let store = deploy_poseidon(&state).await;
+        let ana = admit(&state, patient("Ana")).await;
+        let eva = admit(&state, patient("Eva")).await;
+
+        let response = discharge_patient(State(state.clone()), Path(ana.clone())).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(store.patient(&ana).await.unwrap().unwrap()["discharged_at"].is_string());
+
+        let counts = count_patients(State(state.clone()), Query(PatientCountQuery { by: None })).await.unwrap().0;
+        assert_eq!((counts.total, counts.by_status.active, counts.by_status.discharged), (2, 1, 1));
+
+        let again = discharge_patient(State(state.clone()), Path(ana.clone())).await.into_response();
+        assert_eq!(again.status(), StatusCode::CONFLICT);
+        let missing = discharge_patient(State(state.clone()), Path("patient:9".to_string())).await.into_response();
+        assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+
+        // El egreso no se falsifica por PATCH
+        let forged = json!({ "discharged_at": "2026-01-01T00:00:00Z" });
+        let response = patch_patient(State(state.clone()), Path(eva.clone()), Json(forged)).await.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        assert!(delete_patient(State(state.clone()), Path(eva.clone())).await.is_ok());
+        let counts = count_patients(State(state.clone()), Query(PatientCountQuery { by: None })).await.unwrap().0;
+        assert_eq!((counts.total, counts.by_status.active, counts.by_status.deleted), (1, 0, 1));
+    }
+
+    #[tokio::test]
+    async fn test_password_change_succeeds_without_hades() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+        state.auth.create_user("ana", "Inicial#Guardia-2026").unwrap();
+        let pending = state.auth.begin_login("ana", "123456");
+        let (_, token) = state.auth.complete_login(&pending, "123456").unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(axum::http::header::AUTHORIZATION, format!("Bearer {}", token).parse().unwrap());
+        let request = |current: &str| ChangePasswordRequest {
+            current_password: current.to_string(),
+            new_password: "Olimpo#Guardia-2026".to_string(),
+        };
+
+        // Hades no está desplegado: el cambio ya está hecho y se informa como tal
+        let response = change_password(State(state.clone()), headers.clone(), Json(request("Inicial#Guardia-2026"))).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = change_password(State(state.clone()), headers, Json(request("Inicial#Guardia-2026"))).await.into_response();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        // El hash nuevo quedó en Hestia y es el que carga el siguiente arranque
+        let restarted = auth::AuthStore::new(auth::PasswordPolicy::default());
+        restarted.restore(&state.hestia.get(auth::CREDENTIALS_KEY).unwrap()).unwrap();
+        assert!(restarted.verify("ana", "Olimpo#Guardia-2026"));
+    }
+
+    #[tokio::test]
+    async fn test_olympus_config_reports_live_intervals() {
+        let state = test_state(Arc::new(ids::SequentialIds::new()));
+
+        let config = api_olympus_config(State(state.clone())).await.0;
+        assert_eq!(config["heartbeat_trinity_interval_ms"], HeartbeatConfig::TRINITY.interval_ms);
+        assert_eq!(config["heartbeat_standard_interval_ms"], HeartbeatConfig::STANDARD.interval_ms);
+        assert_eq!(config["reply_timeout_ms"], state.replies.timeout().as_millis() as u64);
+        assert_eq!(config["enabled_gods"].as_array().unwrap().len(), GodName::ALL.len());
+
+        let response = api_olympus_config_key(State(state.clone()), Path("heartbeat_trinity_timeout_ms".to_string())).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_json(response).await["value"], HeartbeatConfig::TRINITY.timeout_ms);
+        let response = api_olympus_config_key(State(state.clone()), Path("nonexistent".to_string())).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        // El secreto de Hades aparece, pero nunca su valor
+        assert_eq!(config["jwt_secret"], REDACTED);
+        let response = api_olympus_config_key(State(state.clone()), Path("jwt_secret".to_string())).await;
+        let body = body_json(response).await;
+        assert_eq!(body["value"], REDACTED);
+        assert!(!body.to_string().contains(state.jwt_secret.expose()));
+    }
 }
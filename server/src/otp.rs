@@ -0,0 +1,227 @@
+// server/src/otp.rs
+// Códigos OTP del segundo paso del login: se generan por login y los entrega Iris.
+// Solo en desarrollo se devuelven además en la respuesta de `login_step1`; sin OLYMPUS_ENV
+// se asume producción.
+
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper_util::client::legacy::Client;
+use hyper_util::rt::TokioExecutor;
+use serde_json::json;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const ENVIRONMENT_ENV: &str = "OLYMPUS_ENV";
+pub const CHANNEL_ENV: &str = "OLYMPUS_OTP_CHANNEL";
+pub const OTP_DIGITS: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Environment {
+    Development,
+    Staging,
+    Production,
+}
+
+impl Environment {
+    /// `OLYMPUS_ENV`: development, staging o production (por defecto). Sin configurar se
+    /// asume producción: el código nunca se expone por omisión
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var(ENVIRONMENT_ENV) {
+            Ok(raw) => match raw.trim().to_ascii_lowercase().as_str() {
+                "dev" | "development" => Ok(Environment::Development),
+                "staging" => Ok(Environment::Staging),
+                "" | "prod" | "production" => Ok(Environment::Production),
+                _ => Err(format!("{} debe ser development, staging o production: '{}'", ENVIRONMENT_ENV, raw)),
+            },
+            Err(_) => Ok(Environment::Production),
+        }
+    }
+}
+
+/// Canal por el que Iris entrega el código
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OtpChannel {
+    /// Registro del servidor (target `olympus::iris`). Solo anota que se emitió un código,
+    /// nunca el código, así que únicamente sirve en desarrollo
+    Log,
+    /// Una línea JSON por código en el archivo indicado; un relay de correo o webhook la consume
+    File(PathBuf),
+    /// POST de `{ username, otp_code, issued_at }` a un relay HTTP interno que lo reenvía
+    /// por correo o SMS. Solo `http://`: el servidor no incluye cliente TLS
+    Webhook(hyper::Uri),
+}
+
+impl OtpChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OtpChannel::Log => "log",
+            OtpChannel::File(_) => "file",
+            OtpChannel::Webhook(_) => "webhook",
+        }
+    }
+
+    /// `log`, `file:<ruta>` o `webhook:http://<host>/<ruta>`
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let invalid = || format!("{} debe ser log, file:<ruta> o webhook:http://<host>/<ruta>: '{}'", CHANNEL_ENV, raw);
+        match raw.trim() {
+            "log" => Ok(OtpChannel::Log),
+            other => {
+                if let Some(path) = other.strip_prefix("file:").map(str::trim).filter(|p| !p.is_empty()) {
+                    return Ok(OtpChannel::File(PathBuf::from(path)));
+                }
+                let url = other.strip_prefix("webhook:").map(str::trim).ok_or_else(invalid)?;
+                let uri: hyper::Uri = url.parse().map_err(|_| invalid())?;
+                if uri.scheme_str() != Some("http") || uri.host().is_none() {
+                    return Err(invalid());
+                }
+                Ok(OtpChannel::Webhook(uri))
+            }
+        }
+    }
+}
+
+/// Tiempo máximo de espera del relay del webhook
+pub const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone)]
+pub struct OtpDelivery {
+    pub channel: OtpChannel,
+    /// El código viaja también en la respuesta del paso 1 (solo desarrollo)
+    pub expose_in_response: bool,
+}
+
+impl OtpDelivery {
+    pub fn new(environment: Environment, channel: OtpChannel) -> Self {
+        Self { channel, expose_in_response: environment == Environment::Development }
+    }
+
+    /// Fuera de desarrollo el canal es obligatorio y debe entregar el código de verdad
+    pub fn configure(environment: Environment, channel: Option<&str>) -> Result<Self, String> {
+        let channel = match channel.map(str::trim).filter(|c| !c.is_empty()) {
+            Some(raw) => OtpChannel::parse(raw)?,
+            None => OtpChannel::Log,
+        };
+        if channel == OtpChannel::Log && environment != Environment::Development {
+            return Err(format!(
+                "{} debe ser file:<ruta> o webhook:<url> fuera de {}=development: el registro no entrega el código",
+                CHANNEL_ENV, ENVIRONMENT_ENV
+            ));
+        }
+        Ok(Self::new(environment, channel))
+    }
+
+    /// `OLYMPUS_ENV` y `OLYMPUS_OTP_CHANNEL` (`log` por defecto solo en desarrollo)
+    pub fn from_env() -> Result<Self, String> {
+        Self::configure(Environment::from_env()?, std::env::var(CHANNEL_ENV).ok().as_deref())
+    }
+
+    /// Iris entrega el código al usuario por el canal configurado
+    pub async fn deliver(&self, username: &str, code: &str) -> Result<(), String> {
+        let line = json!({
+            "username": username,
+            "otp_code": code,
+            "issued_at": chrono::Utc::now().to_rfc3339(),
+        });
+        match &self.channel {
+            OtpChannel::Log => {
+                tracing::info!(target: "olympus::iris", username, "📨 Iris: código OTP emitido para {}", username);
+                Ok(())
+            }
+            OtpChannel::File(path) => std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .and_then(|mut file| writeln!(file, "{}", line))
+                .map_err(|e| format!("Iris no pudo escribir en {}: {}", path.display(), e)),
+            OtpChannel::Webhook(uri) => post_webhook(uri, line.to_string()).await,
+        }
+    }
+}
+
+async fn post_webhook(uri: &hyper::Uri, body: String) -> Result<(), String> {
+    let client = Client::builder(TokioExecutor::new()).build_http::<Full<Bytes>>();
+    let request = hyper::Request::post(uri.clone())
+        .header(hyper::header::CONTENT_TYPE, "application/json")
+        .body(Full::new(Bytes::from(body)))
+        .map_err(|e| e.to_string())?;
+    let response = tokio::time::timeout(WEBHOOK_TIMEOUT, client.request(request))
+        .await
+        .map_err(|_| format!("Iris: el webhook {} no respondió en {:?}", uri, WEBHOOK_TIMEOUT))?
+        .map_err(|e| format!("Iris no pudo contactar el webhook {}: {}", uri, e))?;
+    if !response.status().is_success() {
+        return Err(format!("Iris: el webhook {} respondió {}", uri, response.status()));
+    }
+    Ok(())
+}
+
+/// Código numérico aleatorio de `OTP_DIGITS` cifras
+pub fn generate_code() -> String {
+    // Rechazo de los valores por encima del último múltiplo de 10^6 para no sesgar
+    const LIMIT: u32 = u32::MAX - u32::MAX % 1_000_000;
+    loop {
+        let value = OsRng.next_u32();
+        if value < LIMIT {
+            return format!("{:0width$}", value % 1_000_000, width = OTP_DIGITS);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_are_six_random_digits() {
+        let codes: Vec<String> = (0..20).map(|_| generate_code()).collect();
+        assert!(codes.iter().all(|c| c.len() == OTP_DIGITS && c.chars().all(|d| d.is_ascii_digit())));
+        assert!(codes.iter().any(|c| c != &codes[0]));
+
+        assert!(OtpDelivery::new(Environment::Development, OtpChannel::Log).expose_in_response);
+        assert!(!OtpDelivery::new(Environment::Staging, OtpChannel::Log).expose_in_response);
+        assert!(!OtpDelivery::new(Environment::Production, OtpChannel::Log).expose_in_response);
+    }
+
+    #[test]
+    fn test_channel_is_required_outside_development() {
+        assert!(OtpDelivery::configure(Environment::Development, None).is_ok());
+        assert!(OtpDelivery::configure(Environment::Production, None).is_err());
+        assert!(OtpDelivery::configure(Environment::Staging, Some("log")).is_err());
+
+        let webhook = OtpDelivery::configure(Environment::Production, Some("webhook:http://relay:8025/otp")).unwrap();
+        assert_eq!(webhook.channel.as_str(), "webhook");
+        assert!(!webhook.expose_in_response);
+        assert!(OtpChannel::parse("webhook:https://relay/otp").is_err());
+        assert!(OtpChannel::parse("webhook:").is_err());
+        assert!(OtpChannel::parse("sms").is_err());
+        assert_eq!(OtpChannel::parse("file:/tmp/otp.jsonl").unwrap(), OtpChannel::File(PathBuf::from("/tmp/otp.jsonl")));
+    }
+
+    #[tokio::test]
+    async fn test_webhook_receives_the_code() {
+        use axum::{extract::State, http::StatusCode, routing::post, Json, Router};
+        use tokio::sync::mpsc;
+
+        let (tx, mut rx) = mpsc::unbounded_channel::<serde_json::Value>();
+        let app = Router::new()
+            .route("/otp", post(|State(tx): State<mpsc::UnboundedSender<serde_json::Value>>, Json(body): Json<serde_json::Value>| async move {
+                let _ = tx.send(body);
+                StatusCode::ACCEPTED
+            }))
+            .route("/down", post(|| async { StatusCode::BAD_GATEWAY }))
+            .with_state(tx);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let channel = OtpChannel::parse(&format!("webhook:http://{}/otp", addr)).unwrap();
+        OtpDelivery::new(Environment::Production, channel).deliver("ana", "123456").await.unwrap();
+        let received = rx.recv().await.unwrap();
+        assert_eq!(received["username"], "ana");
+        assert_eq!(received["otp_code"], "123456");
+
+        let down = OtpChannel::parse(&format!("webhook:http://{}/down", addr)).unwrap();
+        assert!(OtpDelivery::new(Environment::Production, down).deliver("ana", "123456").await.is_err());
+    }
+}
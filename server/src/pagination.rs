@@ -0,0 +1,273 @@
+// server/src/pagination.rs
+// Paginación y orden comunes para todos los endpoints de listado
+
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Query},
+    http::{request::Parts, StatusCode},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::cmp::Ordering;
+use std::sync::OnceLock;
+
+pub const DEFAULT_LIMIT: usize = 50;
+pub const MAX_LIMIT: usize = 500;
+
+pub const DEFAULT_PAGE_SIZE_ENV: &str = "OLYMPUS_DEFAULT_PAGE_SIZE";
+pub const MAX_PAGE_SIZE_ENV: &str = "OLYMPUS_MAX_PAGE_SIZE";
+
+/// Tamaño de página cuando se omite `limit` y tope al que se recorta cualquier `limit`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageLimits {
+    pub default: usize,
+    pub max: usize,
+}
+
+impl Default for PageLimits {
+    fn default() -> Self {
+        Self { default: DEFAULT_LIMIT, max: MAX_LIMIT }
+    }
+}
+
+impl PageLimits {
+    pub fn new(default: usize, max: usize) -> Result<Self, String> {
+        if default == 0 || max == 0 {
+            return Err("los tamaños de página deben ser mayores que 0".to_string());
+        }
+        if default > max {
+            return Err(format!("el tamaño por defecto ({}) supera el máximo ({})", default, max));
+        }
+        Ok(Self { default, max })
+    }
+
+    pub fn from_env() -> Result<Self, String> {
+        let read = |name: &str, fallback: usize| match std::env::var(name) {
+            Ok(raw) => raw.trim().parse().map_err(|_| format!("{} debe ser un entero: '{}'", name, raw)),
+            Err(_) => Ok(fallback),
+        };
+        let max = read(MAX_PAGE_SIZE_ENV, MAX_LIMIT)?;
+        // Sin default explícito, un máximo configurado por debajo de 50 también lo acota
+        let default = read(DEFAULT_PAGE_SIZE_ENV, DEFAULT_LIMIT.min(max))?;
+        Self::new(default, max)
+    }
+}
+
+static PAGE_LIMITS: OnceLock<PageLimits> = OnceLock::new();
+
+/// Fija los límites para todo el proceso; se llama una vez al arrancar
+pub fn configure(limits: PageLimits) {
+    let _ = PAGE_LIMITS.set(limits);
+}
+
+pub fn limits() -> PageLimits {
+    PAGE_LIMITS.get().copied().unwrap_or_default()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// `?limit=&offset=&sort=&order=` validados
+#[derive(Debug, Clone)]
+pub struct PageParams {
+    pub limit: usize,
+    pub offset: usize,
+    pub sort: Option<String>,
+    pub order: SortOrder,
+}
+
+impl Default for PageParams {
+    fn default() -> Self {
+        Self {
+            limit: limits().default,
+            offset: 0,
+            sort: None,
+            order: SortOrder::Asc,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct PageQuery {
+    limit: Option<usize>,
+    offset: Option<usize>,
+    sort: Option<String>,
+    order: Option<String>,
+}
+
+/// Respuesta paginada con la misma forma en todos los listados
+#[derive(Debug, Clone, Serialize)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    pub total: usize,
+    pub limit: usize,
+    pub offset: usize,
+}
+
+impl PageParams {
+    /// Un `limit` por encima del máximo se recorta en vez de rechazarse
+    fn from_query(q: PageQuery, limits: PageLimits) -> Result<Self, String> {
+        let limit = q.limit.unwrap_or(limits.default);
+        if limit == 0 {
+            return Err("limit debe ser mayor que 0".to_string());
+        }
+        let limit = limit.min(limits.max);
+
+        let order = match q.order.as_deref().map(str::to_ascii_lowercase).as_deref() {
+            None | Some("asc") => SortOrder::Asc,
+            Some("desc") => SortOrder::Desc,
+            Some(other) => return Err(format!("order inválido '{}': use asc o desc", other)),
+        };
+
+        let sort = q.sort.filter(|s| !s.trim().is_empty());
+        if let Some(field) = &sort {
+            if !field.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(format!("sort inválido '{}'", field));
+            }
+        }
+
+        Ok(Self {
+            limit,
+            offset: q.offset.unwrap_or(0),
+            sort,
+            order,
+        })
+    }
+
+    /// Usa `field` como orden si el cliente no pidió otro
+    pub fn sort_or(mut self, field: &str) -> Self {
+        if self.sort.is_none() {
+            self.sort = Some(field.to_string());
+        }
+        self
+    }
+
+    /// Ordena por el campo pedido (los registros sin el campo van al final) y recorta la página
+    pub fn apply(&self, mut items: Vec<Value>) -> Paginated<Value> {
+        if let Some(field) = &self.sort {
+            items.sort_by(|a, b| self.compare_field(field, a, b));
+        }
+        self.slice(items)
+    }
+
+    /// Igual que `apply` para registros tipados: se ordena por el campo serializado
+    pub fn apply_to<T: Serialize>(&self, items: Vec<T>) -> Paginated<T> {
+        let mut keyed: Vec<(Value, T)> = items.into_iter().map(|item| (json!(item), item)).collect();
+        if let Some(field) = &self.sort {
+            keyed.sort_by(|(a, _), (b, _)| self.compare_field(field, a, b));
+        }
+        self.slice(keyed.into_iter().map(|(_, item)| item).collect())
+    }
+
+    fn compare_field(&self, field: &str, a: &Value, b: &Value) -> Ordering {
+        let ordering = match (a.get(field), b.get(field)) {
+            (Some(x), Some(y)) => compare_values(x, y),
+            (Some(_), None) => return Ordering::Less,
+            (None, Some(_)) => return Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+        match self.order {
+            SortOrder::Asc => ordering,
+            SortOrder::Desc => ordering.reverse(),
+        }
+    }
+
+    fn slice<T>(&self, items: Vec<T>) -> Paginated<T> {
+        let total = items.len();
+        let items = items.into_iter().skip(self.offset).take(self.limit).collect();
+
+        Paginated {
+            items,
+            total,
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+fn compare_values(a: &Value, b: &Value) -> Ordering {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(x), Some(y)) => x.total_cmp(&y),
+        _ => match (a.as_str(), b.as_str()) {
+            (Some(x), Some(y)) => x.to_lowercase().cmp(&y.to_lowercase()),
+            _ => a.to_string().cmp(&b.to_string()),
+        },
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for PageParams {
+    type Rejection = (StatusCode, Json<Value>);
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let bad_request = |error: String| {
+            (StatusCode::BAD_REQUEST, Json(json!({ "success": false, "error": error })))
+        };
+
+        let Query(q) = Query::<PageQuery>::from_request_parts(parts, state)
+            .await
+            .map_err(|e| bad_request(format!("Parámetros de paginación inválidos: {}", e.body_text())))?;
+
+        Self::from_query(q, limits()).map_err(bad_request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(limit: Option<usize>, sort: Option<&str>, order: Option<&str>) -> PageQuery {
+        PageQuery {
+            limit,
+            offset: None,
+            sort: sort.map(String::from),
+            order: order.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_sort_and_slice() {
+        let params = PageParams::from_query(PageQuery {
+            offset: Some(1),
+            ..query(Some(2), Some("last_name"), Some("desc"))
+        }, PageLimits::default())
+        .unwrap();
+
+        let items = vec![
+            json!({ "last_name": "acosta" }),
+            json!({ "last_name": "Zamora" }),
+            json!({ "last_name": "Mendez" }),
+            json!({}),
+        ];
+        let page = params.apply(items);
+
+        assert_eq!(page.total, 4);
+        let names: Vec<_> = page.items.iter().map(|p| p["last_name"].clone()).collect();
+        assert_eq!(names, vec![json!("Mendez"), json!("acosta")]);
+    }
+
+    #[test]
+    fn test_rejects_invalid_params() {
+        let limits = PageLimits::default();
+        assert!(PageParams::from_query(query(Some(0), None, None), limits).is_err());
+        assert!(PageParams::from_query(query(None, None, Some("up")), limits).is_err());
+        assert!(PageParams::from_query(query(None, Some("name;drop"), None), limits).is_err());
+    }
+
+    #[test]
+    fn test_limit_is_clamped_and_defaulted() {
+        let limits = PageLimits::new(20, 200).unwrap();
+        assert_eq!(PageParams::from_query(query(Some(100_000), None, None), limits).unwrap().limit, 200);
+        assert_eq!(PageParams::from_query(query(None, None, None), limits).unwrap().limit, 20);
+        assert_eq!(PageParams::from_query(query(Some(30), None, None), limits).unwrap().limit, 30);
+
+        assert!(PageLimits::new(300, 200).is_err());
+        assert!(PageLimits::new(0, 200).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+// server/src/patient_counts.rs
+// Recuentos de pacientes por estado a partir de los grupos que Poseidon cuenta en
+// SurrealDB (`SELECT count() ... GROUP BY`), sin transferir los registros
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Valor de `admission_type` para registros que no lo tienen
+pub const UNKNOWN_ADMISSION_TYPE: &str = "unknown";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct StatusCounts {
+    pub active: usize,
+    pub discharged: usize,
+    pub deleted: usize,
+    /// Ingresos rápidos sin completar; también cuentan como activos o egresados
+    pub incomplete: usize,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct PatientCounts {
+    /// Pacientes no eliminados (activos + egresados)
+    pub total: usize,
+    pub by_status: StatusCounts,
+    /// Solo con `?by=admission_type`; excluye eliminados
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_admission_type: Option<BTreeMap<String, usize>>,
+}
+
+/// Una fila del recuento agrupado de la tabla `patient`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CountGroup {
+    #[serde(default)]
+    pub is_deleted: bool,
+    /// Tiene `discharged_at` (lo fija `POST /api/patients/:id/discharge`)
+    #[serde(default)]
+    pub is_discharged: bool,
+    #[serde(default)]
+    pub is_incomplete: bool,
+    #[serde(default)]
+    pub admission_type: Option<String>,
+    pub count: usize,
+}
+
+pub fn from_groups(groups: &[CountGroup], by_admission_type: bool) -> PatientCounts {
+    let mut counts = PatientCounts {
+        by_admission_type: by_admission_type.then(BTreeMap::new),
+        ..PatientCounts::default()
+    };
+
+    for group in groups {
+        if group.is_deleted {
+            counts.by_status.deleted += group.count;
+            continue;
+        }
+        counts.total += group.count;
+        if group.is_discharged {
+            counts.by_status.discharged += group.count;
+        } else {
+            counts.by_status.active += group.count;
+        }
+        if group.is_incomplete {
+            counts.by_status.incomplete += group.count;
+        }
+        if let Some(by_type) = counts.by_admission_type.as_mut() {
+            let admission_type = group
+                .admission_type
+                .as_deref()
+                .filter(|t| !t.is_empty())
+                .unwrap_or(UNKNOWN_ADMISSION_TYPE);
+            *by_type.entry(admission_type.to_string()).or_insert(0) += group.count;
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_counts_by_status_and_admission_type() {
+        let groups: Vec<CountGroup> = serde_json::from_value(json!([
+            { "admission_type": "emergency", "count": 1 },
+            { "admission_type": "emergency", "is_incomplete": true, "count": 1 },
+            { "admission_type": "elective", "is_discharged": true, "count": 1 },
+            { "admission_type": null, "count": 1 },
+            { "admission_type": "elective", "is_deleted": true, "count": 1 },
+        ]))
+        .unwrap();
+
+        let counts = from_groups(&groups, true);
+        assert_eq!(counts.total, 4);
+        assert_eq!(counts.by_status, StatusCounts { active: 3, discharged: 1, deleted: 1, incomplete: 1 });
+        let by_type = counts.by_admission_type.unwrap();
+        assert_eq!(by_type["emergency"], 2);
+        assert_eq!(by_type["elective"], 1);
+        assert_eq!(by_type[UNKNOWN_ADMISSION_TYPE], 1);
+
+        assert!(from_groups(&groups, false).by_admission_type.is_none());
+    }
+}
@@ -0,0 +1,145 @@
+// server/src/patient_patch.rs
+// Actualización parcial de pacientes: JSON Patch (RFC 6902) o JSON Merge Patch (RFC 7396)
+
+use serde_json::Value;
+
+/// Campos obligatorios: deben seguir presentes y no vacíos tras el parche
+const REQUIRED_FIELDS: [&str; 4] = ["first_name", "last_name", "identity_card", "principal_diagnosis"];
+
+/// Campos gestionados por el servidor que un parche no puede modificar
+const READ_ONLY_FIELDS: [&str; 7] = ["id", "deleted", "merged_into", "incomplete", "discharged_at", "created_at", "updated_at"];
+
+/// Prefijo de la cédula provisional que recibe un ingreso rápido
+pub const PROVISIONAL_ID_PREFIX: &str = "PROV-";
+
+pub fn provisional_identity_card(patient_id: &str) -> String {
+    format!("{}{}", PROVISIONAL_ID_PREFIX, patient_id.chars().take(8).collect::<String>().to_uppercase())
+}
+
+/// Registra una escritura: `updated_at` pasa a ahora y, en el alta, `created_at` también.
+/// Ancho fijo (microsegundos, `Z`) para que ordenar como texto sea ordenar por fecha.
+pub fn touch(record: &mut Value) {
+    let now = Value::String(chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Micros, true));
+    if record.get("created_at").is_none() {
+        record["created_at"] = now.clone();
+    }
+    record["updated_at"] = now;
+}
+
+/// Datos demográficos aún provisionales (ingreso rápido sin completar)
+pub fn has_placeholder_demographics(record: &Value) -> bool {
+    record
+        .get("identity_card")
+        .and_then(|v| v.as_str())
+        .is_none_or(|card| card.starts_with(PROVISIONAL_ID_PREFIX))
+}
+
+/// Aplica el parche sobre una copia del registro; el original no cambia si algo falla.
+/// Un arreglo se interpreta como JSON Patch y un objeto como Merge Patch.
+pub fn apply(record: &Value, patch: &Value) -> Result<Value, String> {
+    let mut patched = record.clone();
+
+    match patch {
+        Value::Array(_) => {
+            let ops: json_patch::Patch = serde_json::from_value(patch.clone())
+                .map_err(|e| format!("JSON Patch inválido: {}", e))?;
+            json_patch::patch(&mut patched, &ops)
+                .map_err(|e| format!("No se pudo aplicar la operación {} ({}): {}", e.operation, e.path, e.kind))?;
+        }
+        Value::Object(_) => json_patch::merge(&mut patched, patch),
+        _ => return Err("El parche debe ser un arreglo (JSON Patch) o un objeto (Merge Patch)".to_string()),
+    }
+
+    let violations = validate(record, &patched);
+    if !violations.is_empty() {
+        return Err(violations.join("; "));
+    }
+
+    // Un ingreso rápido deja de estar incompleto cuando recibe su cédula real
+    if record.get("incomplete").is_some() {
+        patched["incomplete"] = Value::Bool(has_placeholder_demographics(&patched));
+    }
+    Ok(patched)
+}
+
+/// Reglas de Hera sobre el registro resultante
+pub fn validate(original: &Value, patched: &Value) -> Vec<String> {
+    let mut violations = Vec::new();
+
+    if !patched.is_object() {
+        violations.push("El paciente debe ser un objeto".to_string());
+        return violations;
+    }
+
+    for field in READ_ONLY_FIELDS {
+        if original.get(field) != patched.get(field) {
+            violations.push(format!("El campo '{}' no se puede modificar", field));
+        }
+    }
+
+    for field in REQUIRED_FIELDS {
+        match patched.get(field).and_then(|v| v.as_str()) {
+            Some(value) if !value.trim().is_empty() => {}
+            Some(_) => violations.push(format!("El campo '{}' no puede estar vacío", field)),
+            None => violations.push(format!("El campo '{}' es obligatorio y debe ser texto", field)),
+        }
+    }
+
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn patient() -> Value {
+        json!({
+            "id": "p1",
+            "first_name": "Ana",
+            "last_name": "Pérez",
+            "identity_card": "V-123",
+            "principal_diagnosis": "Neumonía",
+        })
+    }
+
+    #[test]
+    fn test_replace_updates_only_that_field() {
+        let patch = json!([{ "op": "replace", "path": "/principal_diagnosis", "value": "Sepsis" }]);
+        let patched = apply(&patient(), &patch).unwrap();
+
+        let mut expected = patient();
+        expected["principal_diagnosis"] = json!("Sepsis");
+        assert_eq!(patched, expected);
+    }
+
+    #[test]
+    fn test_rejects_required_and_read_only_violations() {
+        let remove = json!([{ "op": "remove", "path": "/last_name" }]);
+        assert!(apply(&patient(), &remove).unwrap_err().contains("last_name"));
+
+        let merge = json!({ "id": "otro", "first_name": "" });
+        let err = apply(&patient(), &merge).unwrap_err();
+        assert!(err.contains("'id'") && err.contains("first_name"));
+
+        let failed_test = json!([
+            { "op": "replace", "path": "/first_name", "value": "Eva" },
+            { "op": "test", "path": "/identity_card", "value": "V-999" },
+        ]);
+        assert!(apply(&patient(), &failed_test).is_err());
+    }
+
+    #[test]
+    fn test_completing_quick_admission_clears_incomplete() {
+        let mut quick = patient();
+        quick["identity_card"] = json!(provisional_identity_card("p1"));
+        quick["incomplete"] = json!(true);
+
+        let still_provisional = apply(&quick, &json!({ "principal_diagnosis": "Politrauma" })).unwrap();
+        assert_eq!(still_provisional["incomplete"], true);
+        assert!(apply(&quick, &json!({ "incomplete": false })).unwrap_err().contains("'incomplete'"));
+
+        let completed = apply(&quick, &json!({ "identity_card": "V-456" })).unwrap();
+        assert_eq!(completed["incomplete"], false);
+    }
+}
@@ -0,0 +1,283 @@
+// server/src/reassessment.rs
+// Recordatorios de reevaluación: Chronos revisa periódicamente los pacientes activos y
+// avisa cuando su último NEWS2 es más antiguo de lo que permite su nivel de riesgo
+
+use chrono::{DateTime, Duration, Utc};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+use crate::actors::{ActorMessage, GodName, MessagePayload};
+use crate::clinical_alerts::{self, AlertCategory, AlertFilter, AlertSeverity, ClinicalAlert, NewClinicalAlert, NEWS2_ALERT_THRESHOLD, NEWS2_EMERGENCY_THRESHOLD};
+use crate::AppState;
+
+pub const CADENCE_ENV: &str = "OLYMPUS_REASSESSMENT_CADENCE_MIN";
+pub const SCAN_INTERVAL_ENV: &str = "OLYMPUS_REASSESSMENT_SCAN_SECS";
+pub const DEFAULT_SCAN_INTERVAL_SECS: u64 = 300;
+pub const SCAN_ACTION: &str = "reassessment_scan";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+}
+
+impl RiskLevel {
+    /// Mismos umbrales que las alertas de NEWS2 alto
+    pub fn from_news2(total: i64) -> Self {
+        if total >= NEWS2_EMERGENCY_THRESHOLD {
+            RiskLevel::High
+        } else if total >= NEWS2_ALERT_THRESHOLD {
+            RiskLevel::Medium
+        } else {
+            RiskLevel::Low
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RiskLevel::Low => "low",
+            RiskLevel::Medium => "medium",
+            RiskLevel::High => "high",
+        }
+    }
+}
+
+/// Intervalo máximo entre dos NEWS2 según el riesgo del último
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cadence {
+    intervals: HashMap<RiskLevel, Duration>,
+}
+
+impl Default for Cadence {
+    /// Bajo riesgo cada 12 h, riesgo medio cada 4 h y alto riesgo cada hora
+    fn default() -> Self {
+        Self {
+            intervals: HashMap::from([
+                (RiskLevel::Low, Duration::hours(12)),
+                (RiskLevel::Medium, Duration::hours(4)),
+                (RiskLevel::High, Duration::hours(1)),
+            ]),
+        }
+    }
+}
+
+impl Cadence {
+    /// `low=720,medium=240,high=60` (minutos); los niveles omitidos conservan su valor por defecto
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let mut cadence = Self::default();
+        for entry in raw.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (level, minutes) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("Entrada de cadencia sin '=': '{}'", entry))?;
+            let level = match level.trim().to_ascii_lowercase().as_str() {
+                "low" => RiskLevel::Low,
+                "medium" => RiskLevel::Medium,
+                "high" => RiskLevel::High,
+                other => return Err(format!("Nivel de riesgo desconocido: '{}' (use low, medium o high)", other)),
+            };
+            let minutes: i64 = match minutes.trim().parse() {
+                Ok(m) if m > 0 => m,
+                _ => return Err(format!("La cadencia de '{}' debe ser un número de minutos mayor que 0", level.as_str())),
+            };
+            cadence.intervals.insert(level, Duration::minutes(minutes));
+        }
+        Ok(cadence)
+    }
+
+    pub fn interval(&self, level: RiskLevel) -> Duration {
+        self.intervals[&level]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReminderConfig {
+    pub cadence: Cadence,
+    /// Cada cuánto se revisan los pacientes; cero desactiva los recordatorios
+    pub scan_interval: std::time::Duration,
+}
+
+impl ReminderConfig {
+    /// `OLYMPUS_REASSESSMENT_CADENCE_MIN` y `OLYMPUS_REASSESSMENT_SCAN_SECS` (300 por defecto; 0 los desactiva)
+    pub fn from_env() -> Result<Self, String> {
+        let cadence = match std::env::var(CADENCE_ENV) {
+            Ok(raw) => Cadence::parse(&raw).map_err(|e| format!("{} inválido: {}", CADENCE_ENV, e))?,
+            Err(_) => Cadence::default(),
+        };
+        let secs = match std::env::var(SCAN_INTERVAL_ENV) {
+            Ok(raw) => raw
+                .trim()
+                .parse()
+                .map_err(|_| format!("{} debe ser un número de segundos: '{}'", SCAN_INTERVAL_ENV, raw))?,
+            Err(_) => DEFAULT_SCAN_INTERVAL_SECS,
+        };
+        Ok(Self { cadence, scan_interval: std::time::Duration::from_secs(secs) })
+    }
+}
+
+/// Pacientes activos con el NEWS2 vencido: riesgo, último total y hora del último NEWS2
+fn due(
+    patients: &HashMap<String, Value>,
+    assessments: &HashMap<String, Value>,
+    cadence: &Cadence,
+    now: DateTime<Utc>,
+) -> HashMap<String, (RiskLevel, i64, DateTime<Utc>)> {
+    let mut latest: HashMap<&str, (DateTime<Utc>, i64)> = HashMap::new();
+    for assessment in assessments.values().filter(|a| a["scale"] == "NEWS2") {
+        let (Some(patient_id), Some(total)) = (assessment["patient_id"].as_str(), assessment["total"].as_i64()) else {
+            continue;
+        };
+        let Some(assessed_at) = assessment["assessed_at"]
+            .as_str()
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+            .map(|t| t.with_timezone(&Utc))
+        else {
+            continue;
+        };
+        if latest.get(patient_id).is_none_or(|(previous, _)| assessed_at > *previous) {
+            latest.insert(patient_id, (assessed_at, total));
+        }
+    }
+
+    patients
+        .iter()
+        .filter(|(_, p)| !p.get("deleted").and_then(|d| d.as_bool()).unwrap_or(false))
+        .filter_map(|(id, _)| {
+            let (assessed_at, total) = *latest.get(id.as_str())?;
+            let level = RiskLevel::from_news2(total);
+            (now > assessed_at + cadence.interval(level)).then(|| (id.clone(), (level, total, assessed_at)))
+        })
+        .collect()
+}
+
+/// Recordatorios nuevos para los pacientes con el NEWS2 vencido que aún no tienen uno sin resolver
+pub fn overdue(
+    patients: &HashMap<String, Value>,
+    assessments: &HashMap<String, Value>,
+    pending: &[ClinicalAlert],
+    cadence: &Cadence,
+    now: DateTime<Utc>,
+) -> Vec<NewClinicalAlert> {
+    let mut reminders: Vec<NewClinicalAlert> = due(patients, assessments, cadence, now)
+        .into_iter()
+        .filter(|(id, _)| !pending.iter().any(|a| a.patient_id == *id && a.category == AlertCategory::OverdueReassessment))
+        .map(|(patient_id, (level, total, assessed_at))| NewClinicalAlert {
+            patient_id,
+            category: AlertCategory::OverdueReassessment,
+            severity: match level {
+                RiskLevel::High => AlertSeverity::High,
+                RiskLevel::Medium => AlertSeverity::Medium,
+                RiskLevel::Low => AlertSeverity::Low,
+            },
+            message: format!(
+                "Reevaluación NEWS2 vencida: riesgo {} (NEWS2 {}), última hace {} min, cadencia de {} min",
+                level.as_str(),
+                total,
+                (now - assessed_at).num_minutes(),
+                cadence.interval(level).num_minutes()
+            ),
+        })
+        .collect();
+    reminders.sort_by(|a, b| a.patient_id.cmp(&b.patient_id));
+    reminders
+}
+
+/// Una pasada: resuelve los recordatorios de los pacientes ya reevaluados (o dados de baja)
+/// y emite los nuevos como alertas clínicas
+pub async fn scan(state: &AppState, cadence: &Cadence, now: DateTime<Utc>) -> Vec<ClinicalAlert> {
+    let pending: Vec<ClinicalAlert> = clinical_alerts::list(&state.hestia, &AlertFilter::default())
+        .into_iter()
+        .filter(|a| a.category == AlertCategory::OverdueReassessment)
+        .collect();
+    let (due, reminders) = {
+        let patients = state.patients.read().await;
+        let assessments = state.assessments.read().await;
+        (
+            due(&patients, &assessments, cadence, now),
+            overdue(&patients, &assessments, &pending, cadence, now),
+        )
+    };
+
+    for settled in pending.iter().filter(|a| !due.contains_key(&a.patient_id)) {
+        if let Err(e) = clinical_alerts::resolve(&state.hestia, &settled.id).await {
+            tracing::error!("🚨 No se pudo resolver el recordatorio {}: {}", settled.id, e);
+        }
+    }
+
+    let mut raised = Vec::new();
+    for reminder in reminders {
+        match clinical_alerts::raise(&state.hestia, reminder).await {
+            Ok(alert) => {
+                tracing::info!("⏰ Chronos: {} ({})", alert.message, alert.patient_id);
+                raised.push(alert);
+            }
+            Err(e) => tracing::error!("🚨 No se pudo guardar el recordatorio de reevaluación: {}", e),
+        }
+    }
+    raised
+}
+
+/// Job periódico de Chronos: cada pasada se anota en su buzón y solo corre mientras
+/// Chronos esté habilitado y disponible y no haya descarga de carga
+pub async fn watch(state: AppState, config: ReminderConfig) {
+    if config.scan_interval.is_zero() || !state.genesis_config.is_enabled(GodName::Chronos) {
+        return;
+    }
+    let mut interval = tokio::time::interval(config.scan_interval);
+    loop {
+        interval.tick().await;
+        if state.load_shedding.is_active() {
+            tracing::debug!("⏰ Chronos: revisión de reevaluaciones en pausa por descarga de carga");
+            continue;
+        }
+        let tick = ActorMessage::new(
+            GodName::Zeus,
+            GodName::Chronos,
+            MessagePayload::Command { action: SCAN_ACTION.to_string(), data: json!({}) },
+        );
+        if crate::send_to_god(&state, GodName::Chronos, tick).await.is_some() {
+            tracing::warn!("⏰ Chronos no disponible: se omite la revisión de reevaluaciones");
+            continue;
+        }
+        scan(&state, &config.cadence, Utc::now()).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn news2(patient_id: &str, total: i64, assessed_at: DateTime<Utc>) -> Value {
+        json!({ "scale": "NEWS2", "patient_id": patient_id, "total": total, "assessed_at": assessed_at.to_rfc3339() })
+    }
+
+    #[test]
+    fn test_medium_risk_patient_overdue_after_cadence() {
+        let now = Utc::now();
+        let patients = HashMap::from([
+            ("p1".to_string(), json!({ "id": "p1" })),
+            ("p2".to_string(), json!({ "id": "p2" })),
+            ("p3".to_string(), json!({ "id": "p3", "deleted": true })),
+        ]);
+        let assessments = HashMap::from([
+            // p1: riesgo medio hace 5 h (cadencia 4 h)
+            ("a1".to_string(), news2("p1", 5, now - Duration::hours(5))),
+            // p2: bajo riesgo hace 5 h (cadencia 12 h)
+            ("a2".to_string(), news2("p2", 2, now - Duration::hours(5))),
+            ("a3".to_string(), news2("p3", 8, now - Duration::hours(5))),
+        ]);
+        let cadence = Cadence::default();
+
+        let reminders = overdue(&patients, &assessments, &[], &cadence, now);
+        assert_eq!(reminders.len(), 1);
+        assert_eq!(reminders[0].patient_id, "p1");
+        assert_eq!(reminders[0].category, AlertCategory::OverdueReassessment);
+        assert_eq!(reminders[0].severity, AlertSeverity::Medium);
+
+        // Una cadencia más laxa para riesgo medio no lo da por vencido
+        let relaxed = Cadence::parse("medium=360").unwrap();
+        assert!(overdue(&patients, &assessments, &[], &relaxed, now).is_empty());
+        assert!(Cadence::parse("medium=0").is_err());
+        assert!(Cadence::parse("urgent=60").is_err());
+    }
+}
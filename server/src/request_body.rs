@@ -0,0 +1,67 @@
+// server/src/request_body.rs
+// Cuerpos comprimidos: `Content-Encoding: gzip` se descomprime antes de los extractores
+// (JSON, NDJSON de importación). El límite se mide sobre el cuerpo ya descomprimido
+// para que un archivo pequeño no pueda expandirse sin control (zip bomb).
+
+use axum::Router;
+use tower_http::{decompression::RequestDecompressionLayer, limit::RequestBodyLimitLayer};
+
+/// Tamaño máximo descomprimido, configurable con `OLYMPUS_MAX_BODY_BYTES`
+pub const MAX_BODY_ENV: &str = "OLYMPUS_MAX_BODY_BYTES";
+pub const DEFAULT_MAX_BODY_BYTES: usize = 50 * 1024 * 1024;
+
+pub fn max_bytes_from_env() -> Result<usize, String> {
+    match std::env::var(MAX_BODY_ENV) {
+        Ok(raw) => match raw.trim().parse() {
+            Ok(0) | Err(_) => Err(format!("{} debe ser un entero positivo: '{}'", MAX_BODY_ENV, raw)),
+            Ok(max) => Ok(max),
+        },
+        Err(_) => Ok(DEFAULT_MAX_BODY_BYTES),
+    }
+}
+
+/// El límite queda por dentro de la descompresión: cuenta los bytes ya expandidos.
+/// Superarlo responde 413 o corta el flujo de lectura con error.
+pub fn apply<S: Clone + Send + Sync + 'static>(router: Router<S>, max_bytes: usize) -> Router<S> {
+    router
+        .layer(RequestBodyLimitLayer::new(max_bytes))
+        .layer(RequestDecompressionLayer::new().gzip(true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request, http::StatusCode, routing::post};
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+    use tower::ServiceExt;
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    async fn post_gzip(max_bytes: usize, body: &[u8]) -> (StatusCode, String) {
+        let app = apply(Router::new().route("/echo", post(|body: String| async move { body })), max_bytes);
+        let request = Request::post("/echo")
+            .header("content-encoding", "gzip")
+            .body(Body::from(gzip(body)))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8_lossy(&bytes).to_string())
+    }
+
+    #[tokio::test]
+    async fn test_gzip_body_is_decoded_and_limited() {
+        let ndjson = "{\"identity_card\":\"V-1\",\"scale\":\"gcs\"}\n".repeat(100);
+        assert_eq!(post_gzip(1024 * 1024, ndjson.as_bytes()).await, (StatusCode::OK, ndjson.clone()));
+
+        // Se comprime muy por debajo del límite pero se expande por encima
+        let bomb = vec![b'0'; 64 * 1024];
+        assert!(gzip(&bomb).len() < 4 * 1024);
+        assert_eq!(post_gzip(4 * 1024, &bomb).await.0, StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
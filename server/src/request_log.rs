@@ -0,0 +1,217 @@
+// server/src/request_log.rs
+// Registro de auditoría de peticiones (OLYMPUS_REQUEST_LOG): una línea estructurada por
+// petición con método, ruta, estado y latencia y, opcionalmente, los cuerpos JSON con los
+// datos personales enmascarados. Nunca se registran cabeceras (Authorization) ni credenciales.
+
+use axum::{
+    body::{Body, Bytes, HttpBody},
+    extract::State,
+    http::{header, HeaderMap, Request},
+    middleware::{self, Next},
+    response::Response,
+    Router,
+};
+use serde_json::Value;
+
+pub const REQUEST_LOG_ENV: &str = "OLYMPUS_REQUEST_LOG";
+/// Cuerpos más grandes no se registran (solo su tamaño)
+pub const MAX_LOGGED_BODY_BYTES: usize = 64 * 1024;
+pub const REDACTED: &str = "***";
+
+/// Datos personales y diagnósticos del paciente: se registran como `***`
+const PII_FIELDS: [&str; 7] = [
+    "identity_card",
+    "first_name",
+    "last_name",
+    "full_name",
+    "patient_name",
+    "principal_diagnosis",
+    "diagnosis",
+];
+/// Credenciales: se eliminan del registro
+const CREDENTIAL_FIELDS: [&str; 7] = ["password", "current_password", "new_password", "otp_code", "otp", "token", "session_id"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestLog {
+    Off,
+    /// Método, ruta, estado y latencia
+    Summary,
+    /// Además, los cuerpos JSON enmascarados
+    Bodies,
+}
+
+impl RequestLog {
+    /// `OLYMPUS_REQUEST_LOG`: `off` (por defecto), `on` o `bodies`
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var(REQUEST_LOG_ENV) {
+            Ok(raw) => match raw.trim().to_ascii_lowercase().as_str() {
+                "" | "off" | "false" => Ok(RequestLog::Off),
+                "on" | "true" => Ok(RequestLog::Summary),
+                "bodies" => Ok(RequestLog::Bodies),
+                _ => Err(format!("{} debe ser off, on o bodies: '{}'", REQUEST_LOG_ENV, raw)),
+            },
+            Err(_) => Ok(RequestLog::Off),
+        }
+    }
+}
+
+pub fn apply<S: Clone + Send + Sync + 'static>(router: Router<S>, mode: RequestLog) -> Router<S> {
+    match mode {
+        RequestLog::Off => router,
+        mode => router.layer(middleware::from_fn_with_state(mode, log_request)),
+    }
+}
+
+async fn log_request(State(mode): State<RequestLog>, req: Request<Body>, next: Next) -> Response {
+    let method = req.method().clone();
+    let path = req.uri().path().to_string();
+    let started = std::time::Instant::now();
+
+    let (req, request_body) = if mode == RequestLog::Bodies {
+        let (parts, body) = req.into_parts();
+        let (body, logged) = capture(&parts.headers, body).await;
+        (Request::from_parts(parts, body), logged)
+    } else {
+        (req, None)
+    };
+
+    let response = next.run(req).await;
+    let status = response.status().as_u16();
+    let elapsed_ms = started.elapsed().as_millis() as u64;
+
+    let (response, response_body) = if mode == RequestLog::Bodies {
+        let (parts, body) = response.into_parts();
+        let (body, logged) = capture(&parts.headers, body).await;
+        (Response::from_parts(parts, body), logged)
+    } else {
+        (response, None)
+    };
+
+    tracing::info!(
+        target: "olympus::requests",
+        method = %method,
+        path = %path,
+        status,
+        elapsed_ms,
+        request_body = request_body.as_deref().unwrap_or(""),
+        response_body = response_body.as_deref().unwrap_or(""),
+        "{} {} → {}",
+        method,
+        path,
+        status
+    );
+    response
+}
+
+/// Lee un cuerpo JSON de tamaño conocido y devuelve una copia intacta más su versión
+/// enmascarada. Los demás cuerpos (streams, NDJSON, binarios) pasan sin leerse.
+async fn capture(headers: &HeaderMap, body: Body) -> (Body, Option<String>) {
+    let is_json = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"));
+    let compressed = headers.contains_key(header::CONTENT_ENCODING);
+    let size = HttpBody::size_hint(&body).exact();
+    let Some(size) = size.filter(|_| is_json && !compressed) else {
+        return (body, None);
+    };
+    if size > MAX_LOGGED_BODY_BYTES as u64 {
+        return (body, Some(format!("<{} bytes>", size)));
+    }
+
+    match axum::body::to_bytes(body, MAX_LOGGED_BODY_BYTES).await {
+        Ok(bytes) => {
+            let logged = redact_body(&bytes);
+            (Body::from(bytes), Some(logged))
+        }
+        Err(e) => (Body::empty(), Some(format!("<cuerpo ilegible: {}>", e))),
+    }
+}
+
+fn redact_body(bytes: &Bytes) -> String {
+    match serde_json::from_slice::<Value>(bytes) {
+        Ok(mut value) => {
+            redact(&mut value);
+            value.to_string()
+        }
+        Err(_) => format!("<{} bytes no JSON>", bytes.len()),
+    }
+}
+
+/// Enmascara los datos personales y elimina las credenciales a cualquier profundidad
+pub fn redact(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|key, _| !CREDENTIAL_FIELDS.contains(&key.as_str()));
+            for (key, field) in map.iter_mut() {
+                if PII_FIELDS.contains(&key.as_str()) && !field.is_null() {
+                    *field = Value::String(REDACTED.to_string());
+                } else {
+                    redact(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log_stream::LogStream;
+    use axum::{routing::post, Json};
+    use serde_json::json;
+    use tower::ServiceExt;
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::Layer;
+
+    #[tokio::test]
+    async fn test_one_redacted_line_per_request() {
+        let stream = LogStream::new(tracing::Level::INFO);
+        let mut records = stream.subscribe();
+        let subscriber = tracing_subscriber::registry().with(stream.clone().with_filter(stream.filter()));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let app = apply(
+            Router::new().route("/api/patients", post(|Json(body): Json<Value>| async move {
+                Json(json!({ "success": true, "patient": body, "token": "secreto" }))
+            })),
+            RequestLog::Bodies,
+        );
+        let request = Request::post("/api/patients")
+            .header("content-type", "application/json")
+            .header("authorization", "Bearer abc.def.ghi")
+            .body(Body::from(
+                json!({
+                    "first_name": "Ana",
+                    "identity_card": "V-123",
+                    "principal_diagnosis": "Sepsis de origen abdominal",
+                    "otp_code": "654321",
+                    "age": 40
+                })
+                .to_string(),
+            ))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+
+        // La respuesta llega completa al cliente
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["patient"]["first_name"], "Ana");
+        assert_eq!(body["token"], "secreto");
+
+        let record = records.try_recv().unwrap();
+        assert!(records.try_recv().is_err());
+        assert_eq!(record.fields["status"], 200);
+        assert_eq!(record.fields["method"], "POST");
+        assert_eq!(
+            serde_json::from_str::<Value>(record.fields["request_body"].as_str().unwrap()).unwrap(),
+            json!({ "first_name": "***", "identity_card": "***", "principal_diagnosis": "***", "age": 40 })
+        );
+        let logged = serde_json::to_string(&record).unwrap();
+        for secret in ["Ana", "V-123", "Sepsis", "654321", "abc.def.ghi", "secreto"] {
+            assert!(!logged.contains(secret), "{} en el registro", secret);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+// server/src/scale_cache.rs
+// Resultados de escalas recientes por hash de la entrada: una UI que recalcula en cada
+// tecla no repite el cálculo ni guarda la misma evaluación una y otra vez
+
+use serde::Serialize;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+
+use crate::actors::HestiaCache;
+
+pub const TTL_ENV: &str = "OLYMPUS_SCALE_CACHE_TTL_MS";
+pub const DEFAULT_TTL: Duration = Duration::from_secs(5);
+
+#[derive(Clone)]
+pub struct ScaleCache {
+    cache: HestiaCache,
+    /// Cero desactiva la caché
+    ttl: Duration,
+}
+
+impl ScaleCache {
+    pub fn new(cache: HestiaCache, ttl: Duration) -> Self {
+        Self { cache, ttl }
+    }
+
+    /// `OLYMPUS_SCALE_CACHE_TTL_MS` (5000 por defecto; 0 la desactiva)
+    pub fn from_env(cache: HestiaCache) -> Result<Self, String> {
+        let ttl = match std::env::var(TTL_ENV) {
+            Ok(raw) => Duration::from_millis(
+                raw.trim()
+                    .parse()
+                    .map_err(|_| format!("{} debe ser un número de milisegundos: '{}'", TTL_ENV, raw))?,
+            ),
+            Err(_) => DEFAULT_TTL,
+        };
+        Ok(Self::new(cache, ttl))
+    }
+
+    /// Resultado calculado hace menos de un TTL para esta misma entrada
+    pub fn get(&self, scale: &str, input: &impl Serialize) -> Option<serde_json::Value> {
+        if self.ttl.is_zero() {
+            return None;
+        }
+        self.cache.get(&input_key(scale, input))
+    }
+
+    pub fn put(&self, scale: &str, input: &impl Serialize, result: &serde_json::Value) {
+        if !self.ttl.is_zero() {
+            self.cache.set(&input_key(scale, input), result.clone(), self.ttl);
+        }
+    }
+
+    pub fn stats(&self) -> serde_json::Value {
+        let mut stats = self.cache.stats();
+        stats["ttl_ms"] = serde_json::json!(self.ttl.as_millis() as u64);
+        stats
+    }
+}
+
+/// Clave de caché: escala + hash de la entrada serializada (incluye el paciente)
+fn input_key(scale: &str, input: &impl Serialize) -> String {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_vec(input).unwrap_or_default().hash(&mut hasher);
+    format!("scale_cache:{}:{:016x}", scale, hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_hit_requires_identical_input() {
+        let cache = ScaleCache::new(HestiaCache::new(), Duration::from_secs(60));
+        let input = json!({ "respiratory": 2, "renal": 1 });
+        assert_eq!(cache.get("sofa", &input), None);
+
+        cache.put("sofa", &input, &json!({ "total": 3 }));
+        assert_eq!(cache.get("sofa", &input), Some(json!({ "total": 3 })));
+        assert_eq!(cache.get("sofa", &json!({ "respiratory": 2, "renal": 2 })), None);
+        assert_eq!(cache.get("apache", &input), None);
+
+        let disabled = ScaleCache::new(HestiaCache::new(), Duration::ZERO);
+        disabled.put("sofa", &input, &json!({ "total": 3 }));
+        assert_eq!(disabled.get("sofa", &input), None);
+    }
+}
@@ -0,0 +1,1040 @@
+// server/src/scales.rs
+// Cálculo de escalas a partir de un único conjunto de signos vitales
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use olympus_core::apache::{ApacheIIRequest, ApacheIIResponse};
+use olympus_core::news2::{news2_score, News2Vitals};
+use olympus_core::saps::{SAPSIIRequest, SAPSIIResponse};
+use olympus_core::vitals::{CompleteVitalSigns, VitalSignError, VitalSigns};
+
+use crate::i18n::Lang;
+use crate::{GlasgowRequest, News2Request, SofaRequest};
+
+/// Escalas que admiten las rutas genéricas (`/scales/:scale/...`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scale {
+    Glasgow,
+    Sofa,
+    News2,
+    Apache,
+    Saps,
+}
+
+impl Scale {
+    pub const ALL: [Scale; 5] = [Scale::Glasgow, Scale::Sofa, Scale::News2, Scale::Apache, Scale::Saps];
+
+    /// Nombre canónico, el que se guarda en el campo `scale` de cada evaluación
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scale::Glasgow => "Glasgow",
+            Scale::Sofa => "SOFA",
+            Scale::News2 => "NEWS2",
+            Scale::Apache => "APACHE II",
+            Scale::Saps => "SAPS II",
+        }
+    }
+
+    /// En Glasgow un total mayor es mejor; en las demás, peor
+    pub fn higher_is_worse(&self) -> bool {
+        !matches!(self, Scale::Glasgow)
+    }
+
+    /// Bandas de interpretación del total y campo del resultado que las refleja
+    pub fn bands(&self) -> (&'static str, &'static [ScoreBand]) {
+        match self {
+            Scale::Glasgow => ("interpretation", &GLASGOW_BANDS),
+            Scale::Sofa => ("predicted_mortality", &SOFA_MORTALITY_BANDS),
+            Scale::News2 => ("risk_level", &NEWS2_RISK_BANDS),
+            Scale::Apache => ("severity", &APACHE_SEVERITY_BANDS),
+            Scale::Saps => ("severity", &SAPS_SEVERITY_BANDS),
+        }
+    }
+}
+
+impl std::str::FromStr for Scale {
+    type Err = String;
+
+    /// Acepta el nombre canónico y los alias habituales, sin distinguir mayúsculas
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.trim().to_lowercase().replace(['-', '_', ' '], "").as_str() {
+            "glasgow" | "gcs" | "glasgowcomascale" => Ok(Scale::Glasgow),
+            "sofa" => Ok(Scale::Sofa),
+            "news2" | "news" => Ok(Scale::News2),
+            "apache" | "apacheii" | "apache2" => Ok(Scale::Apache),
+            "saps" | "sapsii" | "saps2" => Ok(Scale::Saps),
+            _ => {
+                let known: Vec<&str> = Scale::ALL.iter().map(Scale::as_str).collect();
+                Err(format!("Escala desconocida: '{}' (admitidas: {})", name, known.join(", ")))
+            }
+        }
+    }
+}
+
+/// Vista común de los resultados de escala: la comparación, las respuestas de APACHE II y
+/// SAPS II y la exportación CSV del historial los recorren como `dyn ScaleResult`
+pub trait ScaleResult {
+    fn scale(&self) -> Scale;
+    fn score(&self) -> i32;
+    /// Etiqueta de la banda del total (el campo de `Scale::bands`); None si el total no se interpreta
+    fn interpretation(&self) -> Option<&str>;
+    /// Mortalidad predicha (%), solo en las escalas que la estiman
+    fn mortality(&self) -> Option<f32> {
+        None
+    }
+    /// Campos propios de la escala que acompañan al resumen
+    fn details(&self) -> serde_json::Map<String, serde_json::Value> {
+        serde_json::Map::new()
+    }
+}
+
+/// Resumen de un resultado: detalles, `total`, la interpretación bajo el campo de su escala
+/// y `predicted_mortality` cuando la escala la estima
+pub fn summary(result: &dyn ScaleResult) -> serde_json::Value {
+    let mut body = result.details();
+    body.insert("total".to_string(), json!(result.score()));
+    if let Some(interpretation) = result.interpretation() {
+        body.insert(result.scale().bands().0.to_string(), json!(interpretation));
+    }
+    if let Some(mortality) = result.mortality() {
+        body.insert("predicted_mortality".to_string(), json!(mortality));
+    }
+    serde_json::Value::Object(body)
+}
+
+impl ScaleResult for ApacheIIResponse {
+    fn scale(&self) -> Scale {
+        Scale::Apache
+    }
+    fn score(&self) -> i32 {
+        self.score as i32
+    }
+    fn interpretation(&self) -> Option<&str> {
+        Some(self.severity.as_str())
+    }
+    fn mortality(&self) -> Option<f32> {
+        Some(self.predicted_mortality)
+    }
+}
+
+impl ScaleResult for SAPSIIResponse {
+    fn scale(&self) -> Scale {
+        Scale::Saps
+    }
+    fn score(&self) -> i32 {
+        self.score as i32
+    }
+    fn interpretation(&self) -> Option<&str> {
+        Some(self.severity.as_str())
+    }
+    fn mortality(&self) -> Option<f32> {
+        Some(self.predicted_mortality)
+    }
+}
+
+/// Glasgow de la comparación
+pub struct GlasgowScore {
+    pub total: i32,
+    pub interpretation: String,
+}
+
+impl ScaleResult for GlasgowScore {
+    fn scale(&self) -> Scale {
+        Scale::Glasgow
+    }
+    fn score(&self) -> i32 {
+        self.total
+    }
+    fn interpretation(&self) -> Option<&str> {
+        Some(self.interpretation.as_str())
+    }
+}
+
+/// NEWS2 simplificado de la comparación
+pub struct News2Score {
+    pub total: i32,
+    pub risk_level: String,
+}
+
+impl ScaleResult for News2Score {
+    fn scale(&self) -> Scale {
+        Scale::News2
+    }
+    fn score(&self) -> i32 {
+        self.total
+    }
+    fn interpretation(&self) -> Option<&str> {
+        Some(self.risk_level.as_str())
+    }
+}
+
+/// SOFA de la comparación, calculado con los órganos que tienen datos
+pub struct SofaScore {
+    pub components: serde_json::Map<String, serde_json::Value>,
+    pub total: i32,
+    pub missing_organs: Vec<serde_json::Value>,
+    pub note: String,
+}
+
+impl SofaScore {
+    fn partial(&self) -> bool {
+        !self.missing_organs.is_empty()
+    }
+}
+
+impl ScaleResult for SofaScore {
+    fn scale(&self) -> Scale {
+        Scale::Sofa
+    }
+    fn score(&self) -> i32 {
+        self.total
+    }
+    /// Un SOFA parcial es solo un mínimo: su mortalidad no se interpreta
+    fn interpretation(&self) -> Option<&str> {
+        (!self.partial()).then(|| sofa_mortality(self.total))
+    }
+    fn details(&self) -> serde_json::Map<String, serde_json::Value> {
+        let mut details = serde_json::Map::new();
+        details.insert("components".to_string(), json!(self.components));
+        details.insert("partial".to_string(), json!(self.partial()));
+        if self.partial() {
+            details.insert("missing_organs".to_string(), json!(self.missing_organs));
+            details.insert("note".to_string(), json!(self.note));
+        }
+        details
+    }
+}
+
+/// Evaluación guardada (el JSON de `store_assessment`) vista como resultado de su escala
+pub struct StoredResult<'a> {
+    scale: Scale,
+    assessment: &'a serde_json::Value,
+}
+
+impl<'a> StoredResult<'a> {
+    /// None si la evaluación no nombra una escala conocida
+    pub fn new(assessment: &'a serde_json::Value) -> Option<Self> {
+        let scale = assessment.get("scale")?.as_str()?.parse().ok()?;
+        Some(Self { scale, assessment })
+    }
+}
+
+impl ScaleResult for StoredResult<'_> {
+    fn scale(&self) -> Scale {
+        self.scale
+    }
+    fn score(&self) -> i32 {
+        self.assessment["total"].as_i64().unwrap_or_default() as i32
+    }
+    fn interpretation(&self) -> Option<&str> {
+        self.assessment[self.scale.bands().0].as_str()
+    }
+    fn mortality(&self) -> Option<f32> {
+        self.assessment["predicted_mortality"].as_f64().map(|m| m as f32)
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Historial como CSV: una fila por evaluación con su fecha, sea cual sea la escala
+pub fn export_csv(rows: &[(&str, &dyn ScaleResult)]) -> String {
+    let mut csv = String::from("assessed_at,scale,total,interpretation,predicted_mortality\n");
+    for (assessed_at, result) in rows {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            csv_field(assessed_at),
+            csv_field(result.scale().as_str()),
+            result.score(),
+            csv_field(result.interpretation().unwrap_or_default()),
+            result.mortality().map(|m| format!("{:.1}", m)).unwrap_or_default(),
+        ));
+    }
+    csv
+}
+
+/// Banda de interpretación de un total: `min..=max` (None = sin límite superior)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ScoreBand {
+    pub min: i32,
+    pub max: Option<i32>,
+    pub label: &'static str,
+}
+
+const fn score_band(min: i32, max: Option<i32>, label: &'static str) -> ScoreBand {
+    ScoreBand { min, max, label }
+}
+
+pub const GLASGOW_BANDS: [ScoreBand; 3] = [
+    score_band(3, Some(8), "Coma severo"),
+    score_band(9, Some(12), "Coma moderado"),
+    score_band(13, Some(15), "Coma leve/Normal"),
+];
+
+/// GCS-P (1-15): por debajo de 7 la mortalidad crece de forma marcada (Brennan et al., 2018)
+pub const GCS_P_BANDS: [ScoreBand; 4] = [
+    score_band(1, Some(6), "Coma profundo"),
+    score_band(7, Some(8), "Coma severo"),
+    score_band(9, Some(12), "Coma moderado"),
+    score_band(13, Some(15), "Coma leve/Normal"),
+];
+
+pub const SOFA_MORTALITY_BANDS: [ScoreBand; 4] = [
+    score_band(0, Some(6), "< 10%"),
+    score_band(7, Some(9), "15-20%"),
+    score_band(10, Some(12), "40-50%"),
+    score_band(13, Some(24), "> 80%"),
+];
+
+pub const NEWS2_RISK_BANDS: [ScoreBand; 3] = [
+    score_band(0, Some(4), "Bajo riesgo"),
+    score_band(5, Some(6), "Riesgo moderado"),
+    score_band(7, None, "Alto riesgo - respuesta de emergencia"),
+];
+
+/// Gravedad APACHE II: los mismos tramos que `olympus_core::apache::severity`
+pub const APACHE_SEVERITY_BANDS: [ScoreBand; 5] = [
+    score_band(0, Some(9), "Bajo riesgo"),
+    score_band(10, Some(14), "Riesgo moderado"),
+    score_band(15, Some(24), "Alto riesgo"),
+    score_band(25, Some(34), "Riesgo muy alto"),
+    score_band(35, None, "Riesgo extremo"),
+];
+
+/// Gravedad SAPS II: los mismos tramos que `olympus_core::saps::severity`
+pub const SAPS_SEVERITY_BANDS: [ScoreBand; 4] = [
+    score_band(0, Some(29), "Bajo riesgo"),
+    score_band(30, Some(49), "Riesgo moderado"),
+    score_band(50, Some(69), "Alto riesgo"),
+    score_band(70, None, "Riesgo muy alto"),
+];
+
+/// Etiqueta de la banda que contiene el total; "Error" si queda fuera de rango
+pub fn band_label(bands: &[ScoreBand], total: i32) -> &'static str {
+    bands
+        .iter()
+        .find(|b| total >= b.min && b.max.is_none_or(|max| total <= max))
+        .map(|b| b.label)
+        .unwrap_or("Error")
+}
+
+/// Interpretación de Glasgow según el total (3-15)
+pub fn glasgow_interpretation(total: i32) -> &'static str {
+    band_label(&GLASGOW_BANDS, total)
+}
+
+/// Reactividad pupilar para GCS-P: pupilas que responden a la luz
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Pupils {
+    Both,
+    One,
+    None,
+}
+
+impl Pupils {
+    /// Puntos que se restan al Glasgow: 0 ambas reactivas, 1 una, 2 ninguna
+    pub fn deduction(self) -> i32 {
+        match self {
+            Pupils::Both => 0,
+            Pupils::One => 1,
+            Pupils::None => 2,
+        }
+    }
+}
+
+/// Interpretación de GCS-P (Glasgow menos la deducción pupilar, 1-15)
+pub fn gcs_p_interpretation(gcs_p: i32) -> &'static str {
+    band_label(&GCS_P_BANDS, gcs_p)
+}
+
+/// Mortalidad estimada según el total SOFA
+pub fn sofa_mortality(total: i32) -> &'static str {
+    band_label(&SOFA_MORTALITY_BANDS, total)
+}
+
+/// Umbrales de interpretación de una escala, para auditarlos (`GET /api/scales/:scale/bands`)
+pub fn bands_body(scale: Scale, lang: Lang) -> serde_json::Value {
+    let (field, bands) = scale.bands();
+    let bands: Vec<serde_json::Value> = bands
+        .iter()
+        .map(|b| json!({ "min": b.min, "max": b.max, "label": lang.tr(b.label) }))
+        .collect();
+
+    json!({
+        "success": true,
+        "scale": scale.as_str(),
+        "field": field,
+        "bands": bands,
+    })
+}
+
+/// Banda NEWS2 de un parámetro: `min..=max` (None = sin límite) puntúa `score`.
+/// Los límites son los del gráfico de observaciones NEWS2 (temperatura con un decimal).
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct News2Band {
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    pub score: i32,
+}
+
+const fn band(min: Option<f32>, max: Option<f32>, score: i32) -> News2Band {
+    News2Band { min, max, score }
+}
+
+pub const RESPIRATION_RATE_BANDS: [News2Band; 5] = [
+    band(None, Some(8.0), 3),
+    band(Some(9.0), Some(11.0), 1),
+    band(Some(12.0), Some(20.0), 0),
+    band(Some(21.0), Some(24.0), 2),
+    band(Some(25.0), None, 3),
+];
+
+pub const OXYGEN_SATURATION_BANDS: [News2Band; 4] = [
+    band(None, Some(91.0), 3),
+    band(Some(92.0), Some(93.0), 2),
+    band(Some(94.0), Some(95.0), 1),
+    band(Some(96.0), None, 0),
+];
+
+pub const TEMPERATURE_BANDS: [News2Band; 5] = [
+    band(None, Some(35.0), 3),
+    band(Some(35.1), Some(36.0), 1),
+    band(Some(36.1), Some(38.0), 0),
+    band(Some(38.1), Some(39.0), 1),
+    band(Some(39.1), None, 2),
+];
+
+pub const HEART_RATE_BANDS: [News2Band; 6] = [
+    band(None, Some(40.0), 3),
+    band(Some(41.0), Some(50.0), 1),
+    band(Some(51.0), Some(90.0), 0),
+    band(Some(91.0), Some(110.0), 1),
+    band(Some(111.0), Some(130.0), 2),
+    band(Some(131.0), None, 3),
+];
+
+pub const SYSTOLIC_BP_BANDS: [News2Band; 5] = [
+    band(None, Some(90.0), 3),
+    band(Some(91.0), Some(100.0), 2),
+    band(Some(101.0), Some(110.0), 1),
+    band(Some(111.0), Some(219.0), 0),
+    band(Some(220.0), None, 3),
+];
+
+/// Color de cada sub-puntuación en el gráfico (0 blanco, 1 amarillo, 2 naranja, 3 rojo)
+pub const NEWS2_SCORE_COLORS: [&str; 4] = ["#ffffff", "#fde047", "#fb923c", "#ef4444"];
+
+/// Parámetros en el mismo orden y con los mismos nombres que `News2Request`
+pub const NEWS2_PARAMETERS: [(&str, &str, &[News2Band]); 5] = [
+    ("respiration_rate", "rpm", &RESPIRATION_RATE_BANDS),
+    ("oxygen_saturation", "%", &OXYGEN_SATURATION_BANDS),
+    ("temperature", "°C", &TEMPERATURE_BANDS),
+    ("heart_rate", "lpm", &HEART_RATE_BANDS),
+    ("systolic_bp", "mmHg", &SYSTOLIC_BP_BANDS),
+];
+
+/// Sub-puntuación de un valor: se redondea a un decimal como en el gráfico
+pub fn news2_sub_score(bands: &[News2Band], value: f32) -> i32 {
+    let value = (value * 10.0).round() / 10.0;
+    bands
+        .iter()
+        .find(|b| b.min.is_none_or(|min| value >= min) && b.max.is_none_or(|max| value <= max))
+        .map(|b| b.score)
+        .unwrap_or(3)
+}
+
+/// Total NEWS2 simplificado (FR, SpO2, temperatura, FC, PAS). La puntuación es la de
+/// `olympus_core::news2`; las bandas de arriba solo describen el gráfico
+pub fn news2_total(respiration_rate: i32, oxygen_saturation: i32, temperature: f32, heart_rate: i32, systolic_bp: i32) -> i32 {
+    let vitals = News2Vitals {
+        respiratory_rate: respiration_rate,
+        oxygen_saturation,
+        supplemental_oxygen: false,
+        temperature,
+        systolic_bp,
+        heart_rate,
+        alert: true,
+    };
+    news2_score(&vitals).0
+}
+
+fn news2_vitals_total(vitals: &CompleteVitalSigns) -> i32 {
+    news2_total(vitals.respiration_rate, vitals.oxygen_saturation, vitals.temperature, vitals.heart_rate, vitals.systolic_bp)
+}
+
+/// Cuerpo de error para constantes vitales fuera de su rango plausible
+pub fn invalid_vitals_body(errors: &[VitalSignError]) -> serde_json::Value {
+    let fields: serde_json::Map<String, serde_json::Value> = errors
+        .iter()
+        .map(|e| (e.field.to_string(), json!(e.to_string())))
+        .collect();
+    json!({
+        "success": false,
+        "error": format!("Constantes vitales fuera de rango: {}", fields.keys().cloned().collect::<Vec<_>>().join(", ")),
+        "errors": fields,
+    })
+}
+
+/// Puntos del NEWS2 por oxígeno suplementario
+pub const NEWS2_SUPPLEMENTAL_OXYGEN_SCORE: i32 = 2;
+
+/// Nivel de conciencia ACVPU del componente de conciencia de NEWS2
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Acvpu {
+    Alert,
+    /// Confusión de nueva aparición
+    NewConfusion,
+    Voice,
+    Pain,
+    Unresponsive,
+}
+
+impl Acvpu {
+    /// Alerta puntúa 0; cualquier otro nivel, 3
+    pub fn news2_score(&self) -> i32 {
+        match self {
+            Acvpu::Alert => 0,
+            _ => 3,
+        }
+    }
+}
+
+/// ACVPU aproximado a partir del Glasgow total. Es una equivalencia orientativa
+/// (A ≈ 15, V ≈ 12-14, P ≈ 6-11, U ≈ 3-5), no una valoración ACVPU real: la confusión
+/// de nueva aparición no se puede deducir del Glasgow.
+pub fn avpu_from_gcs(gcs: i32) -> Acvpu {
+    match gcs {
+        15.. => Acvpu::Alert,
+        12..=14 => Acvpu::Voice,
+        6..=11 => Acvpu::Pain,
+        _ => Acvpu::Unresponsive,
+    }
+}
+
+/// Conciencia del NEWS2: la ACVPU informada tiene prioridad sobre la derivada del Glasgow
+pub fn news2_consciousness(req: &News2Request) -> Option<(Acvpu, &'static str)> {
+    req.consciousness
+        .map(|level| (level, "reported"))
+        .or_else(|| req.gcs.map(|gcs| (avpu_from_gcs(gcs), "gcs")))
+}
+
+/// Bandas de cada parámetro con su color, para colorear los gráficos igual que la puntuación
+pub fn news2_chart_thresholds() -> serde_json::Value {
+    let parameters: Vec<serde_json::Value> = NEWS2_PARAMETERS
+        .iter()
+        .map(|(key, unit, bands)| {
+            let bands: Vec<serde_json::Value> = bands
+                .iter()
+                .map(|b| json!({ "min": b.min, "max": b.max, "score": b.score, "color": NEWS2_SCORE_COLORS[b.score as usize] }))
+                .collect();
+            json!({ "parameter": key, "unit": unit, "bands": bands })
+        })
+        .collect();
+
+    json!({
+        "scale": "NEWS2",
+        "colors": NEWS2_SCORE_COLORS,
+        "parameters": parameters,
+    })
+}
+
+/// Nivel de riesgo NEWS2 según el total
+pub fn news2_risk(total: i32) -> &'static str {
+    band_label(&NEWS2_RISK_BANDS, total)
+}
+
+/// Resultado Glasgow tal como se devuelve y almacena
+pub fn glasgow_result(req: &GlasgowRequest, lang: Lang) -> serde_json::Value {
+    let total = req.eye + req.verbal + req.motor;
+
+    let mut result = json!({
+        "success": true,
+        "scale": "Glasgow",
+        "patient_id": req.patient_id,
+        "eye": req.eye,
+        "verbal": req.verbal,
+        "motor": req.motor,
+        "total": total,
+        "interpretation": lang.tr(glasgow_interpretation(total)),
+        "calculated_by": "Athena"
+    });
+
+    // `total` sigue siendo el Glasgow sin modificar; GCS-P se añade aparte
+    if let Some(pupils) = req.pupils {
+        let gcs_p = total - pupils.deduction();
+        result["pupils"] = json!(pupils);
+        result["pupil_deduction"] = json!(pupils.deduction());
+        result["gcs_p"] = json!(gcs_p);
+        result["gcs_p_interpretation"] = json!(lang.tr(gcs_p_interpretation(gcs_p)));
+    }
+    result
+}
+
+/// Resultado SOFA tal como se devuelve y almacena
+pub fn sofa_result(req: &SofaRequest) -> serde_json::Value {
+    let total = req.respiratory + req.coagulation + req.liver + req.cardiovascular + req.cns + req.renal;
+
+    json!({
+        "success": true,
+        "scale": "SOFA",
+        "patient_id": req.patient_id,
+        "components": {
+            "respiratory": req.respiratory,
+            "coagulation": req.coagulation,
+            "liver": req.liver,
+            "cardiovascular": req.cardiovascular,
+            "cns": req.cns,
+            "renal": req.renal,
+        },
+        "total": total,
+        "predicted_mortality": sofa_mortality(total),
+        "calculated_by": "Athena"
+    })
+}
+
+/// Resultado APACHE II tal como se devuelve y almacena; Err si la petición no es válida
+pub fn apache_result(req: &ApacheIIRequest) -> Result<serde_json::Value, String> {
+    Ok(score_result(&req.patient_id, &req.calculate()?))
+}
+
+pub fn saps_result(req: &SAPSIIRequest) -> Result<serde_json::Value, String> {
+    Ok(score_result(&req.patient_id, &req.calculate()?))
+}
+
+/// Respuesta de olympus-core con los campos comunes; `total` repite `score` como en las demás escalas
+fn score_result(patient_id: &Option<String>, response: &(impl ScaleResult + Serialize)) -> serde_json::Value {
+    let mut result = json!(response);
+    result["success"] = json!(true);
+    result["scale"] = json!(response.scale().as_str());
+    result["patient_id"] = json!(patient_id.as_deref().unwrap_or_default());
+    result["total"] = result["score"].clone();
+    result["calculated_by"] = json!("Athena");
+    result
+}
+
+/// Resultado NEWS2 simplificado tal como se devuelve y almacena
+pub fn news2_result(req: &News2Request, lang: Lang) -> serde_json::Value {
+    let mut total = news2_vitals_total(&req.vitals);
+    let consciousness = news2_consciousness(req);
+    if let Some((level, _)) = consciousness {
+        total += level.news2_score();
+    }
+    if req.supplemental_oxygen {
+        total += NEWS2_SUPPLEMENTAL_OXYGEN_SCORE;
+    }
+
+    let mut result = json!({
+        "success": true,
+        "scale": "NEWS2",
+        "patient_id": req.patient_id,
+        "total": total,
+        "risk_level": lang.tr(news2_risk(total)),
+        "supplemental_oxygen": req.supplemental_oxygen,
+        "calculated_by": "Athena"
+    });
+    if let Some((level, source)) = consciousness {
+        result["consciousness"] = json!({
+            "level": level,
+            "score": level.news2_score(),
+            "source": source,
+            "approximation": source == "gcs",
+        });
+    }
+    result
+}
+
+/// Signos vitales y laboratorios; cada escala usa los campos que necesita
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Vitals {
+    #[serde(default)]
+    pub patient_id: String,
+    #[serde(flatten)]
+    pub vital_signs: VitalSigns,
+    pub diastolic_bp: Option<i32>,
+    /// Glasgow total; alternativa a los tres componentes
+    pub gcs: Option<i32>,
+    pub gcs_eye: Option<i32>,
+    pub gcs_verbal: Option<i32>,
+    pub gcs_motor: Option<i32>,
+    pub pao2: Option<f64>,
+    /// Fracción (0.21-1.0) o porcentaje (21-100)
+    pub fio2: Option<f64>,
+    pub platelets: Option<f64>,
+    pub bilirubin: Option<f64>,
+    pub creatinine: Option<f64>,
+}
+
+impl Vitals {
+    /// Glasgow total: explícito o sumando los componentes
+    fn gcs_total(&self) -> Option<i32> {
+        self.gcs.or(match (self.gcs_eye, self.gcs_verbal, self.gcs_motor) {
+            (Some(e), Some(v), Some(m)) => Some(e + v + m),
+            _ => None,
+        })
+    }
+
+    fn mean_arterial_pressure(&self) -> Option<f64> {
+        let (sbp, dbp) = (self.vital_signs.systolic_bp?, self.diastolic_bp?);
+        Some((sbp as f64 + 2.0 * dbp as f64) / 3.0)
+    }
+
+    fn pao2_fio2(&self) -> Option<f64> {
+        let fio2 = self.fio2?;
+        let fio2 = if fio2 > 1.0 { fio2 / 100.0 } else { fio2 };
+        if fio2 <= 0.0 {
+            return None;
+        }
+        Some(self.pao2? / fio2)
+    }
+}
+
+fn sofa_respiratory(ratio: f64) -> i32 {
+    match ratio {
+        r if r < 100.0 => 4,
+        r if r < 200.0 => 3,
+        r if r < 300.0 => 2,
+        r if r < 400.0 => 1,
+        _ => 0,
+    }
+}
+
+fn sofa_coagulation(platelets: f64) -> i32 {
+    match platelets {
+        p if p < 20.0 => 4,
+        p if p < 50.0 => 3,
+        p if p < 100.0 => 2,
+        p if p < 150.0 => 1,
+        _ => 0,
+    }
+}
+
+fn sofa_liver(bilirubin: f64) -> i32 {
+    match bilirubin {
+        b if b >= 12.0 => 4,
+        b if b >= 6.0 => 3,
+        b if b >= 2.0 => 2,
+        b if b >= 1.2 => 1,
+        _ => 0,
+    }
+}
+
+// Sin datos de vasopresores solo se distingue PAM < 70
+fn sofa_cardiovascular(map: f64) -> i32 {
+    if map < 70.0 { 1 } else { 0 }
+}
+
+fn sofa_cns(gcs: i32) -> i32 {
+    match gcs {
+        15 => 0,
+        13..=14 => 1,
+        10..=12 => 2,
+        6..=9 => 3,
+        _ => 4,
+    }
+}
+
+fn sofa_renal(creatinine: f64) -> i32 {
+    match creatinine {
+        c if c >= 5.0 => 4,
+        c if c >= 3.5 => 3,
+        c if c >= 2.0 => 2,
+        c if c >= 1.2 => 1,
+        _ => 0,
+    }
+}
+
+/// Calcula todas las escalas posibles y detalla las que faltan por datos
+pub fn compare(vitals: &Vitals, lang: Lang) -> serde_json::Value {
+    let mut results: Vec<Box<dyn ScaleResult>> = Vec::new();
+    let mut not_computed = Vec::new();
+
+    // Glasgow
+    match vitals.gcs_total() {
+        Some(total) => results.push(Box::new(GlasgowScore {
+            total,
+            interpretation: lang.tr(glasgow_interpretation(total)).to_string(),
+        })),
+        None => not_computed.push(json!({
+            "scale": "Glasgow",
+            "missing": ["gcs"],
+        })),
+    }
+
+    // NEWS2
+    match CompleteVitalSigns::try_from(vitals.vital_signs) {
+        Ok(complete) => {
+            let total = news2_vitals_total(&complete);
+            results.push(Box::new(News2Score { total, risk_level: lang.tr(news2_risk(total)).to_string() }));
+        }
+        Err(missing) => not_computed.push(json!({ "scale": "NEWS2", "missing": missing })),
+    }
+
+    // SOFA: se calcula por órgano con lo disponible
+    let organs: [(&str, Option<i32>, &str); 6] = [
+        ("respiratory", vitals.pao2_fio2().map(sofa_respiratory), "pao2, fio2"),
+        ("coagulation", vitals.platelets.map(sofa_coagulation), "platelets"),
+        ("liver", vitals.bilirubin.map(sofa_liver), "bilirubin"),
+        ("cardiovascular", vitals.mean_arterial_pressure().map(sofa_cardiovascular), "systolic_bp, diastolic_bp"),
+        ("cns", vitals.gcs_total().map(sofa_cns), "gcs"),
+        ("renal", vitals.creatinine.map(sofa_renal), "creatinine"),
+    ];
+
+    let mut components = serde_json::Map::new();
+    let mut missing_organs = Vec::new();
+    let mut total = 0;
+    for (organ, score, fields) in organs {
+        match score {
+            Some(score) => {
+                total += score;
+                components.insert(organ.to_string(), json!(score));
+            }
+            None => missing_organs.push(json!({ "organ": organ, "missing": fields })),
+        }
+    }
+
+    if components.is_empty() {
+        not_computed.push(json!({ "scale": "SOFA", "missing": missing_organs }));
+    } else {
+        results.push(Box::new(SofaScore {
+            components,
+            total,
+            missing_organs,
+            note: lang.tr("SOFA parcial: faltan datos de algunos órganos; el total es un mínimo").to_string(),
+        }));
+    }
+
+    let scales: serde_json::Map<String, serde_json::Value> = results
+        .iter()
+        .map(|result| (result.scale().as_str().to_string(), summary(result.as_ref())))
+        .collect();
+
+    json!({
+        "success": true,
+        "patient_id": vitals.patient_id,
+        "scales": scales,
+        "not_computed": not_computed,
+        "calculated_by": "Athena",
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use olympus_core::news2::{heart_rate_score, oxygen_saturation_score, respiratory_rate_score, systolic_bp_score, temperature_score};
+
+    #[test]
+    fn test_scale_from_str_aliases() {
+        assert_eq!("gcs".parse(), Ok(Scale::Glasgow));
+        assert_eq!("Glasgow".parse(), Ok(Scale::Glasgow));
+        assert_eq!("SOFA".parse(), Ok(Scale::Sofa));
+        assert_eq!("news-2".parse(), Ok(Scale::News2));
+        assert_eq!("apache-ii".parse(), Ok(Scale::Apache));
+        assert_eq!("SAPS II".parse(), Ok(Scale::Saps));
+        assert!("bogus".parse::<Scale>().is_err());
+        assert!(Scale::ALL.iter().all(|scale| scale.as_str().parse() == Ok(*scale)));
+    }
+
+    #[test]
+    fn test_apache_and_saps_bands_match_core_severity() {
+        for score in 0..=u8::MAX {
+            assert_eq!(band_label(&APACHE_SEVERITY_BANDS, score as i32), olympus_core::apache::severity(score).0, "APACHE {}", score);
+            assert_eq!(band_label(&SAPS_SEVERITY_BANDS, score as i32), olympus_core::saps::severity(score).0, "SAPS {}", score);
+        }
+    }
+
+    #[test]
+    fn test_news2_bands_match_chart() {
+        assert_eq!(news2_sub_score(&RESPIRATION_RATE_BANDS, 25.0), 3);
+        assert_eq!(news2_sub_score(&RESPIRATION_RATE_BANDS, 22.0), 2);
+        assert_eq!(news2_sub_score(&TEMPERATURE_BANDS, 36.0), 1);
+        assert_eq!(news2_sub_score(&TEMPERATURE_BANDS, 36.1), 0);
+        assert_eq!(news2_sub_score(&TEMPERATURE_BANDS, 34.9), 3);
+        assert_eq!(news2_sub_score(&TEMPERATURE_BANDS, 35.0), 3);
+        assert_eq!(news2_sub_score(&TEMPERATURE_BANDS, 35.1), 1);
+        assert_eq!(news2_sub_score(&SYSTOLIC_BP_BANDS, 220.0), 3);
+        assert_eq!(news2_total(16, 97, 37.0, 80, 120), 0);
+        assert_eq!(news2_total(30, 90, 34.0, 140, 85), 15);
+
+        // Las bandas del gráfico puntúan igual que olympus-core en todo el rango
+        for rr in 0..=60 {
+            assert_eq!(news2_sub_score(&RESPIRATION_RATE_BANDS, rr as f32), respiratory_rate_score(rr), "FR {}", rr);
+        }
+        for spo2 in 50..=100 {
+            assert_eq!(news2_sub_score(&OXYGEN_SATURATION_BANDS, spo2 as f32), oxygen_saturation_score(spo2), "SpO2 {}", spo2);
+        }
+        for tenths in 300..=430 {
+            let temperature = tenths as f32 / 10.0;
+            assert_eq!(news2_sub_score(&TEMPERATURE_BANDS, temperature), temperature_score(temperature), "T {}", temperature);
+        }
+        for hr in 20..=200 {
+            assert_eq!(news2_sub_score(&HEART_RATE_BANDS, hr as f32), heart_rate_score(hr), "FC {}", hr);
+        }
+        for sbp in 50..=260 {
+            assert_eq!(news2_sub_score(&SYSTOLIC_BP_BANDS, sbp as f32), systolic_bp_score(sbp), "PAS {}", sbp);
+        }
+
+        let chart = news2_chart_thresholds();
+        let rr = &chart["parameters"][0];
+        assert_eq!(rr["parameter"], "respiration_rate");
+        assert_eq!(rr["bands"][3]["score"], 2);
+        assert_eq!(rr["bands"][3]["color"], NEWS2_SCORE_COLORS[2]);
+    }
+
+    #[test]
+    fn test_sofa_bands_endpoint_matches_scoring() {
+        let body = bands_body(Scale::Sofa, Lang::Es);
+        assert_eq!(body["field"], "predicted_mortality");
+        let ranges: Vec<(i64, i64)> = body["bands"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|b| (b["min"].as_i64().unwrap(), b["max"].as_i64().unwrap()))
+            .collect();
+        assert_eq!(ranges, vec![(0, 6), (7, 9), (10, 12), (13, 24)]);
+
+        for band in body["bands"].as_array().unwrap() {
+            let req = SofaRequest {
+                patient_id: String::new(),
+                respiratory: band["max"].as_i64().unwrap() as i32,
+                coagulation: 0,
+                liver: 0,
+                cardiovascular: 0,
+                cns: 0,
+                renal: 0,
+                assessed_at: None,
+            };
+            assert_eq!(sofa_result(&req)["predicted_mortality"], band["label"]);
+        }
+
+        assert_eq!(glasgow_interpretation(2), "Error");
+        assert_eq!(news2_risk(20), "Alto riesgo - respuesta de emergencia");
+        assert!(bands_body(Scale::News2, Lang::Es)["bands"][2]["max"].is_null());
+    }
+
+    #[test]
+    fn test_gcs_p_subtracts_pupil_deduction() {
+        let req = |pupils| GlasgowRequest { patient_id: String::new(), eye: 2, verbal: 2, motor: 4, pupils };
+
+        let plain = glasgow_result(&req(None), Lang::Es);
+        assert_eq!(plain["total"], 8);
+        assert!(plain.get("gcs_p").is_none());
+
+        let both = glasgow_result(&req(Some(Pupils::Both)), Lang::Es);
+        assert_eq!(both["gcs_p"], 8);
+        assert_eq!(both["gcs_p_interpretation"], "Coma severo");
+
+        let none = glasgow_result(&req(Some(Pupils::None)), Lang::En);
+        assert_eq!(none["total"], 8);
+        assert_eq!(none["interpretation"], "Severe coma");
+        assert_eq!(none["pupils"], "none");
+        assert_eq!(none["gcs_p"], 6);
+        assert_eq!(none["gcs_p_interpretation"], "Deep coma");
+
+        // GCS-P llega hasta 1: Glasgow 3 sin reactividad pupilar
+        assert_eq!(gcs_p_interpretation(3 - Pupils::None.deduction()), "Coma profundo");
+        assert_eq!(serde_json::from_value::<Pupils>(json!("one")).unwrap(), Pupils::One);
+    }
+
+    #[test]
+    fn test_news2_consciousness_from_gcs() {
+        assert_eq!(avpu_from_gcs(15), Acvpu::Alert);
+        assert_eq!(avpu_from_gcs(14), Acvpu::Voice);
+        assert_eq!(avpu_from_gcs(6), Acvpu::Pain);
+        assert_eq!(avpu_from_gcs(3), Acvpu::Unresponsive);
+
+        let mut req: News2Request = serde_json::from_value(json!({
+            "patient_id": "p1", "respiration_rate": 16, "oxygen_saturation": 97,
+            "temperature": 37.0, "heart_rate": 80, "systolic_bp": 120, "gcs": 15,
+        }))
+        .unwrap();
+        let alert = news2_result(&req, Lang::Es);
+        assert_eq!((alert["total"].as_i64(), alert["consciousness"]["score"].as_i64()), (Some(0), Some(0)));
+
+        req.gcs = Some(6);
+        let pain = news2_result(&req, Lang::Es);
+        assert_eq!(pain["total"], 3);
+        assert_eq!(pain["consciousness"]["approximation"], true);
+
+        // La ACVPU informada prevalece sobre el Glasgow
+        req.consciousness = Some(Acvpu::Alert);
+        assert_eq!(news2_result(&req, Lang::Es)["total"], 0);
+
+        req.supplemental_oxygen = true;
+        assert_eq!(news2_result(&req, Lang::Es)["total"], NEWS2_SUPPLEMENTAL_OXYGEN_SCORE);
+    }
+
+    #[test]
+    fn test_compare_news2_and_partial_sofa() {
+        let vitals = Vitals {
+            vital_signs: VitalSigns {
+                respiration_rate: Some(22),
+                oxygen_saturation: Some(93),
+                temperature: Some(38.5),
+                heart_rate: Some(115),
+                systolic_bp: Some(95),
+            },
+            gcs: Some(13),
+            ..Default::default()
+        };
+
+        let result = compare(&vitals, Lang::Es);
+        assert_eq!(result["scales"]["NEWS2"]["total"], 9);
+        assert_eq!(result["scales"]["Glasgow"]["total"], 13);
+        assert_eq!(result["scales"]["SOFA"]["partial"], true);
+        assert_eq!(result["scales"]["SOFA"]["components"]["cns"], 1);
+        assert_eq!(result["scales"]["SOFA"]["missing_organs"].as_array().unwrap().len(), 5);
+        assert!(result["not_computed"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_results_export_through_the_shared_trait() {
+        let apache = ApacheIIResponse {
+            score: 29,
+            predicted_mortality: 55.0,
+            severity: "Riesgo muy alto".to_string(),
+            recommendation: String::new(),
+        };
+        let glasgow = GlasgowScore { total: 13, interpretation: "Coma leve/Normal".to_string() };
+        let stored = json!({ "scale": "SOFA", "total": 8, "predicted_mortality": "15-20%" });
+        let sofa = StoredResult::new(&stored).unwrap();
+        assert!(StoredResult::new(&json!({ "scale": "bogus", "total": 1 })).is_none());
+
+        assert_eq!(summary(&apache), json!({ "total": 29, "severity": "Riesgo muy alto", "predicted_mortality": 55.0 }));
+        assert_eq!(summary(&sofa), json!({ "total": 8, "predicted_mortality": "15-20%" }));
+
+        let rows: [(&str, &dyn ScaleResult); 3] = [("2026-01-01T08:00:00+00:00", &glasgow), ("2026-01-01T09:00:00+00:00", &sofa), ("2026-01-01T10:00:00+00:00", &apache)];
+        let csv = export_csv(&rows);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "assessed_at,scale,total,interpretation,predicted_mortality");
+        assert_eq!(lines[1], "2026-01-01T08:00:00+00:00,Glasgow,13,Coma leve/Normal,");
+        assert_eq!(lines[2], "2026-01-01T09:00:00+00:00,SOFA,8,15-20%,");
+        assert_eq!(lines[3], "2026-01-01T10:00:00+00:00,APACHE II,29,Riesgo muy alto,55.0");
+        assert_eq!(csv_field("Coma, \"severo\""), "\"Coma, \"\"severo\"\"\"");
+    }
+
+    #[test]
+    fn test_compare_reports_missing_fields() {
+        let vitals = Vitals {
+            vital_signs: VitalSigns { heart_rate: Some(80), ..Default::default() },
+            ..Default::default()
+        };
+
+        let result = compare(&vitals, Lang::Es);
+        let scales: Vec<&str> = result["not_computed"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|s| s["scale"].as_str())
+            .collect();
+        assert_eq!(scales, vec!["Glasgow", "NEWS2", "SOFA"]);
+        assert!(result["scales"].as_object().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,94 @@
+// server/src/static_files.rs
+// Cabeceras de caché para el bundle del frontend (dist/ generado por Trunk)
+
+use axum::{
+    body::Body,
+    http::{header, HeaderMap, HeaderValue, Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+/// Assets con hash en el nombre: el contenido nunca cambia bajo la misma URL
+const IMMUTABLE: &str = "public, max-age=31536000, immutable";
+/// index.html y rutas del SPA: siempre revalidar para recoger el bundle nuevo
+const REVALIDATE: &str = "no-cache";
+/// Resto de archivos estáticos sin hash
+const SHORT_LIVED: &str = "public, max-age=3600";
+
+/// Trunk añade `-<hash hex de 16>` al nombre (p. ej. `olympus-client-1a2b...f0_bg.wasm`)
+pub fn is_hashed_asset(path: &str) -> bool {
+    let file = path.rsplit('/').next().unwrap_or(path);
+    file.split(['-', '_', '.']).any(|part| part.len() >= 16 && part.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+pub fn cache_control_for(path: &str) -> &'static str {
+    let file = path.rsplit('/').next().unwrap_or(path);
+    if file.is_empty() || file.ends_with(".html") || !file.contains('.') {
+        REVALIDATE
+    } else if is_hashed_asset(path) {
+        IMMUTABLE
+    } else {
+        SHORT_LIVED
+    }
+}
+
+/// ETag débil a partir de tamaño y fecha de modificación que ya calcula ServeDir
+fn weak_etag(headers: &HeaderMap) -> Option<String> {
+    let length = headers.get(header::CONTENT_LENGTH)?.to_str().ok()?;
+    let modified = headers.get(header::LAST_MODIFIED)?.to_str().ok()?;
+    let stamp = crate::etag::etag_for(format!("{}-{}", length, modified).as_bytes());
+    Some(format!("W/{}", stamp))
+}
+
+fn matches(request_headers: &HeaderMap, etag: &str) -> bool {
+    request_headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|tag| tag.trim() == "*" || tag.trim() == etag)
+}
+
+/// Middleware para el servicio de estáticos: Cache-Control según el tipo de archivo,
+/// ETag y 304 cuando el cliente ya tiene la versión actual
+pub async fn cache_headers(request: Request<Body>, next: Next) -> Response {
+    let path = request.uri().path().to_string();
+    let request_headers = request.headers().clone();
+    let mut response = next.run(request).await;
+
+    if !response.status().is_success() {
+        return response;
+    }
+
+    let cache_control = HeaderValue::from_static(cache_control_for(&path));
+    let etag = weak_etag(response.headers()).and_then(|tag| HeaderValue::from_str(&tag).ok());
+
+    if let Some(etag) = etag {
+        if matches(&request_headers, etag.to_str().unwrap_or_default()) {
+            return (
+                StatusCode::NOT_MODIFIED,
+                [(header::ETAG, etag), (header::CACHE_CONTROL, cache_control)],
+            )
+                .into_response();
+        }
+        response.headers_mut().insert(header::ETAG, etag);
+    }
+    response.headers_mut().insert(header::CACHE_CONTROL, cache_control);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_policy_by_path() {
+        assert_eq!(cache_control_for("/"), REVALIDATE);
+        assert_eq!(cache_control_for("/index.html"), REVALIDATE);
+        assert_eq!(cache_control_for("/patients"), REVALIDATE);
+        assert_eq!(cache_control_for("/olympus-client-4f2a9c81b7d3e605_bg.wasm"), IMMUTABLE);
+        assert_eq!(cache_control_for("/olympus-client-4f2a9c81b7d3e605.js"), IMMUTABLE);
+        assert_eq!(cache_control_for("/favicon.ico"), SHORT_LIVED);
+        assert!(!is_hashed_asset("/olympus-client_bg.wasm"));
+    }
+}
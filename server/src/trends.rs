@@ -0,0 +1,92 @@
+// server/src/trends.rs
+// Velocidad de cambio de una escala: pendiente (puntos/hora) por mínimos cuadrados
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+
+use crate::scales::Scale;
+
+/// Por debajo de esta pendiente (en valor absoluto) la tendencia se considera estable
+pub const STABLE_SLOPE_PER_HOUR: f64 = 0.05;
+pub const DEFAULT_WINDOW_HOURS: f64 = 24.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrendDirection {
+    Improving,
+    Stable,
+    Worsening,
+}
+
+/// Pendiente de la recta de regresión de `(momento, total)`, en puntos por hora.
+/// None con menos de dos puntos o si todos comparten el mismo momento.
+pub fn slope_per_hour(points: &[(DateTime<Utc>, f64)]) -> Option<f64> {
+    let first = points.iter().map(|(at, _)| *at).min()?;
+    let xy: Vec<(f64, f64)> = points
+        .iter()
+        .map(|(at, value)| ((*at - first).num_milliseconds() as f64 / 3_600_000.0, *value))
+        .collect();
+    if xy.len() < 2 {
+        return None;
+    }
+
+    let n = xy.len() as f64;
+    let mean_x = xy.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = xy.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let sxx: f64 = xy.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    let sxy: f64 = xy.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    (sxx > 0.0).then(|| sxy / sxx)
+}
+
+/// Inicio de la ventana de `window_hours` horas que termina en `now`. None si la
+/// duración no es positiva o la resta sale del rango de fechas representable
+pub fn window_start(now: DateTime<Utc>, window_hours: f64) -> Option<DateTime<Utc>> {
+    let seconds = window_hours * 3600.0;
+    if !seconds.is_finite() || seconds <= 0.0 || seconds > i64::MAX as f64 {
+        return None;
+    }
+    now.checked_sub_signed(TimeDelta::try_seconds(seconds as i64)?)
+}
+
+pub fn direction(scale: Scale, slope: f64) -> TrendDirection {
+    if slope.abs() < STABLE_SLOPE_PER_HOUR {
+        TrendDirection::Stable
+    } else if (slope > 0.0) == scale.higher_is_worse() {
+        TrendDirection::Worsening
+    } else {
+        TrendDirection::Improving
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_rising_sofa_is_worsening() {
+        let start = Utc::now() - Duration::hours(12);
+        let points = vec![(start, 2.0), (start + Duration::hours(6), 4.0), (start + Duration::hours(12), 6.0)];
+
+        let slope = slope_per_hour(&points).unwrap();
+        assert!((slope - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(direction(Scale::Sofa, slope), TrendDirection::Worsening);
+        // El mismo ascenso en Glasgow es una mejoría
+        assert_eq!(direction(Scale::Glasgow, slope), TrendDirection::Improving);
+        assert_eq!(direction(Scale::News2, 0.01), TrendDirection::Stable);
+
+        assert!(slope_per_hour(&points[..1]).is_none());
+        assert!(slope_per_hour(&[(start, 2.0), (start, 5.0)]).is_none());
+    }
+
+    #[test]
+    fn test_window_start_rejects_unrepresentable_windows() {
+        let now = Utc::now();
+        assert_eq!(window_start(now, 24.0), Some(now - Duration::hours(24)));
+        assert!(window_start(now, 0.0).is_none());
+        assert!(window_start(now, -1.0).is_none());
+        assert!(window_start(now, f64::NAN).is_none());
+        assert!(window_start(now, 1e12).is_none());
+        assert!(window_start(now, 1e300).is_none());
+    }
+}
@@ -0,0 +1,98 @@
+// server/src/ventilation.rs
+// Peso ideal (Devine) y volumen corriente de ventilación protectora
+
+use serde::{Deserialize, Serialize};
+
+/// Talla aceptada en cm
+pub const MIN_HEIGHT_CM: f64 = 120.0;
+pub const MAX_HEIGHT_CM: f64 = 250.0;
+
+/// Rango de mL/kg de peso ideal considerado protector
+pub const MIN_ML_PER_KG: f64 = 4.0;
+pub const MAX_ML_PER_KG: f64 = 8.0;
+pub const DEFAULT_ML_PER_KG: f64 = 6.0;
+
+const CM_PER_INCH: f64 = 2.54;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Sex {
+    Male,
+    Female,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TidalVolumeRequest {
+    pub height_cm: f64,
+    pub sex: Sex,
+    #[serde(default)]
+    pub target_ml_per_kg: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TidalVolumeResult {
+    pub ideal_body_weight_kg: f64,
+    pub target_ml_per_kg: f64,
+    pub tidal_volume_ml: f64,
+    /// Rango protector (4-8 mL/kg de peso ideal)
+    pub min_tidal_volume_ml: f64,
+    pub max_tidal_volume_ml: f64,
+}
+
+/// Peso corporal ideal según Devine: 50 kg (hombre) o 45.5 kg (mujer) + 2.3 kg por pulgada sobre 60
+pub fn ideal_body_weight(height_cm: f64, sex: Sex) -> f64 {
+    let base = match sex {
+        Sex::Male => 50.0,
+        Sex::Female => 45.5,
+    };
+    base + 2.3 * (height_cm / CM_PER_INCH - 60.0)
+}
+
+fn round1(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+pub fn tidal_volume(req: &TidalVolumeRequest) -> Result<TidalVolumeResult, String> {
+    if !(MIN_HEIGHT_CM..=MAX_HEIGHT_CM).contains(&req.height_cm) {
+        return Err(format!("La talla debe estar entre {} y {} cm", MIN_HEIGHT_CM, MAX_HEIGHT_CM));
+    }
+
+    let target = req.target_ml_per_kg.unwrap_or(DEFAULT_ML_PER_KG);
+    if !(MIN_ML_PER_KG..=MAX_ML_PER_KG).contains(&target) {
+        return Err(format!("El objetivo debe estar entre {} y {} mL/kg", MIN_ML_PER_KG, MAX_ML_PER_KG));
+    }
+
+    let ibw = ideal_body_weight(req.height_cm, req.sex);
+
+    Ok(TidalVolumeResult {
+        ideal_body_weight_kg: round1(ibw),
+        target_ml_per_kg: target,
+        tidal_volume_ml: (ibw * target).round(),
+        min_tidal_volume_ml: (ibw * MIN_ML_PER_KG).round(),
+        max_tidal_volume_ml: (ibw * MAX_ML_PER_KG).round(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_male_170cm() {
+        let req = TidalVolumeRequest { height_cm: 170.0, sex: Sex::Male, target_ml_per_kg: None };
+        let result = tidal_volume(&req).unwrap();
+
+        assert!((result.ideal_body_weight_kg - 65.9).abs() < 0.5);
+        assert_eq!(result.tidal_volume_ml, 396.0);
+        assert_eq!((result.min_tidal_volume_ml, result.max_tidal_volume_ml), (264.0, 527.0));
+    }
+
+    #[test]
+    fn test_rejects_out_of_bounds() {
+        let short = TidalVolumeRequest { height_cm: 90.0, sex: Sex::Female, target_ml_per_kg: None };
+        assert!(tidal_volume(&short).is_err());
+
+        let high_target = TidalVolumeRequest { height_cm: 160.0, sex: Sex::Female, target_ml_per_kg: Some(10.0) };
+        assert!(tidal_volume(&high_target).is_err());
+    }
+}
@@ -0,0 +1,41 @@
+// server/src/version.rs
+// Versión del sistema - única fuente de verdad para los endpoints de estado
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Versión del crate, tal como aparece en Cargo.toml
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Commit de git con el que se compiló el binario (inyectado por build.rs)
+pub const GIT_COMMIT: &str = match option_env!("OLYMPUS_GIT_COMMIT") {
+    Some(commit) => commit,
+    None => "unknown",
+};
+
+const BUILD_TIMESTAMP: Option<&str> = option_env!("OLYMPUS_BUILD_TIMESTAMP");
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildInfo {
+    pub version: String,
+    pub git_commit: String,
+    pub build_timestamp: Option<String>,
+}
+
+/// Versión con el prefijo "v" usada en las respuestas de estado
+pub fn display_version() -> String {
+    format!("v{}", VERSION)
+}
+
+pub fn build_info() -> BuildInfo {
+    let build_timestamp = BUILD_TIMESTAMP
+        .and_then(|ts| ts.parse::<i64>().ok())
+        .and_then(|secs| DateTime::<Utc>::from_timestamp(secs, 0))
+        .map(|dt| dt.to_rfc3339());
+
+    BuildInfo {
+        version: VERSION.to_string(),
+        git_commit: GIT_COMMIT.to_string(),
+        build_timestamp,
+    }
+}
@@ -12,6 +12,8 @@ pub struct EventMetrics {
     pub total_errors: u64,
     pub avg_latency_ms: f64,
     pub last_event_time: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default)]
+    pub events_dropped_total: u64,
 }
 
 impl EventMetrics {
@@ -25,6 +27,10 @@ impl EventMetrics {
         self.last_event_time = Some(chrono::Utc::now());
     }
 
+    pub fn record_dropped(&mut self) {
+        self.events_dropped_total += 1;
+    }
+
     pub fn record_error(&mut self) {
         self.total_errors += 1;
     }
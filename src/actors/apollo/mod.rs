@@ -4,6 +4,7 @@ use tokio::sync::RwLock;
 use tracing::info;
 
 use crate::actors::{GodName, DivineDomain};
+use crate::actors::ring_buffer::RingBuffer;
 use crate::traits::{OlympianActor, ActorState, ActorConfig, GodHeartbeat, HealthStatus};
 use crate::traits::message::{ActorMessage, MessagePayload, CommandPayload, ResponsePayload, QueryPayload};
 use crate::errors::ActorError;
@@ -17,14 +18,17 @@ pub use events::ApolloEvent;
 pub use logging::{LogEntry, LogLevel};
 pub use metrics::EventMetrics;
 
+/// Capacidad del histórico en memoria de eventos y logs
+pub const HISTORY_CAPACITY: usize = 1000;
+
 #[derive(Debug)]
 #[allow(dead_code)]
 pub struct Apollo {
     name: GodName,
     state: ActorState,
     config: ActorConfig,
-    events: Arc<RwLock<Vec<ApolloEvent>>>,
-    logs: Arc<RwLock<Vec<LogEntry>>>,
+    events: Arc<RwLock<RingBuffer<ApolloEvent>>>,
+    logs: Arc<RwLock<RingBuffer<LogEntry>>>,
     metrics: Arc<RwLock<EventMetrics>>,
 }
 
@@ -34,29 +38,30 @@ impl Apollo {
             name: GodName::Apollo,
             state: ActorState::new(GodName::Apollo),
             config: ActorConfig::default(),
-            events: Arc::new(RwLock::new(Vec::with_capacity(1000))),
-            logs: Arc::new(RwLock::new(Vec::with_capacity(1000))),
+            events: Arc::new(RwLock::new(RingBuffer::new(HISTORY_CAPACITY))),
+            logs: Arc::new(RwLock::new(RingBuffer::new(HISTORY_CAPACITY))),
             metrics: Arc::new(RwLock::new(EventMetrics::default())),
         }
     }
 
     async fn record_event(&self, event: ApolloEvent) {
         let mut events = self.events.write().await;
-        if events.len() >= 1000 {
-            events.remove(0);
-        }
-        events.push(event.clone());
+        let source = event.source;
+        let event_type = event.event_type.clone();
+        let overwritten = events.push(event);
         
         let mut metrics = self.metrics.write().await;
-        metrics.record_event(event.source, &event.event_type);
+        metrics.record_event(source, &event_type);
+        if overwritten {
+            metrics.record_dropped();
+        }
     }
 
     async fn record_log(&self, log: LogEntry) {
         let mut logs = self.logs.write().await;
-        if logs.len() >= 1000 {
-            logs.remove(0);
+        if logs.push(log) {
+            self.metrics.write().await.record_dropped();
         }
-        logs.push(log);
     }
 }
 
@@ -153,6 +158,7 @@ mod tests {
 
         // Simular la llegada de un evento
         let event_msg = ActorMessage {
+            version: crate::traits::message::MESSAGE_SCHEMA_VERSION,
             id: "evt1".to_string(),
             from: Some(GodName::Athena),
             to: GodName::Apollo,
@@ -182,6 +188,7 @@ mod tests {
         }));
         
         let log_msg = ActorMessage {
+            version: crate::traits::message::MESSAGE_SCHEMA_VERSION,
             id: "log1".to_string(),
             from: Some(GodName::Athena),
             to: GodName::Apollo,
@@ -204,6 +211,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_apollo_event_overflow_is_counted() -> Result<(), ActorError> {
+        let mut apollo = Apollo::new().await;
+
+        for i in 0..(HISTORY_CAPACITY + 5) {
+            let event_msg = ActorMessage {
+                version: crate::traits::message::MESSAGE_SCHEMA_VERSION,
+                id: format!("evt{}", i),
+                from: Some(GodName::Hermes),
+                to: GodName::Apollo,
+                priority: crate::traits::message::MessagePriority::Normal,
+                payload: MessagePayload::Event(EventPayload::ActorStarted { actor: GodName::Hermes }),
+                timestamp: chrono::Utc::now(),
+                metadata: json!({}),
+            };
+            apollo.handle_message(event_msg).await?;
+        }
+
+        let stats_resp = apollo.handle_query(QueryPayload::Metrics).await?;
+        if let ResponsePayload::Stats { data } = stats_resp {
+            assert_eq!(data["total_events"], (HISTORY_CAPACITY + 5) as u64);
+            assert_eq!(data["events_dropped_total"], 5);
+        } else {
+            panic!("Expected Stats response");
+        }
+
+        let events_resp = apollo.handle_query(QueryPayload::Custom(json!({"query_type": "recent_events"}))).await?;
+        if let ResponsePayload::Data { data } = events_resp {
+            assert_eq!(data.as_array().unwrap().len(), HISTORY_CAPACITY);
+        } else {
+            panic!("Expected Data response");
+        }
+
+        Ok(())
+    }
 }
 
 impl Apollo {
@@ -229,13 +272,13 @@ impl Apollo {
                             self.record_log(LogEntry::new(level, actor, message.to_string())).await;
                             Ok(ResponsePayload::Success { message: "Log recorded".to_string() })
                         }
-                        _ => Err(ActorError::InvalidCommand { god: GodName::Apollo, reason: format!("Action '{}' not supported", action) }),
+                        _ => Err(ActorError::unsupported(GodName::Apollo, "command", action)),
                     }
                 } else {
                     Err(ActorError::InvalidCommand { god: GodName::Apollo, reason: "Missing action".to_string() })
                 }
             }
-            _ => Err(ActorError::InvalidCommand { god: GodName::Apollo, reason: "Command not supported".to_string() }),
+            other => Err(ActorError::unsupported_variant(GodName::Apollo, "command", &other)),
         }
     }
 
@@ -256,10 +299,10 @@ impl Apollo {
                         let logs = self.logs.read().await;
                         Ok(ResponsePayload::Data { data: serde_json::to_value(&*logs).unwrap_or_default() })
                     }
-                    _ => Err(ActorError::InvalidQuery { god: GodName::Apollo, reason: "Query type not supported".to_string() }),
+                    _ => Err(ActorError::unsupported(GodName::Apollo, "query", query_type)),
                 }
             }
-            _ => Err(ActorError::InvalidQuery { god: GodName::Apollo, reason: "Query not supported".to_string() }),
+            other => Err(ActorError::unsupported_variant(GodName::Apollo, "query", &other)),
         }
     }
 }
@@ -382,16 +382,10 @@ impl Ares {
                             message: format!("Conflictos de más de {} días limpiados", days) 
                         })
                     }
-                    _ => Err(ActorError::InvalidCommand { 
-                        god: GodName::Ares, 
-                        reason: format!("Acción '{}' no soportada", action.unwrap_or("unknown")) 
-                    }),
+                    _ => Err(ActorError::unsupported(GodName::Ares, "command", action.unwrap_or("unknown"))),
                 }
             }
-            _ => Err(ActorError::InvalidCommand { 
-                god: GodName::Ares, 
-                reason: "Comando no soportado".to_string() 
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Ares, "command", &other)),
         }
     }
 
@@ -496,16 +490,10 @@ impl Ares {
                             data: serde_json::to_value(&stats).unwrap_or_default()
                         })
                     }
-                    _ => Err(ActorError::InvalidQuery { 
-                        god: GodName::Ares, 
-                        reason: format!("Query type '{}' no soportado", query_type) 
-                    }),
+                    _ => Err(ActorError::unsupported(GodName::Ares, "query", query_type)),
                 }
             }
-            _ => Err(ActorError::InvalidQuery { 
-                god: GodName::Ares, 
-                reason: "Query no soportado".to_string() 
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Ares, "query", &other)),
         }
     }
 }
@@ -121,10 +121,10 @@ impl Artemis {
 
                     Ok(ResponsePayload::Ack { message_id: "idx_done".to_string() })
                 } else {
-                    Err(ActorError::InvalidCommand { god: GodName::Artemis, reason: format!("Action '{}' not supported", action) })
+                    Err(ActorError::unsupported(GodName::Artemis, "command", action))
                 }
             }
-            _ => Err(ActorError::InvalidCommand { god: GodName::Artemis, reason: "Command not supported".to_string() }),
+            other => Err(ActorError::unsupported_variant(GodName::Artemis, "command", &other)),
         }
     }
 
@@ -134,7 +134,7 @@ impl Artemis {
                 let results = self.searcher.read().await.search_patients(&query)?;
                 Ok(ResponsePayload::Data { data: serde_json::json!(results) })
             }
-            _ => Err(ActorError::InvalidQuery { god: GodName::Artemis, reason: "Query not supported".to_string() }),
+            other => Err(ActorError::unsupported_variant(GodName::Artemis, "query", &other)),
         }
     }
 }
@@ -161,6 +161,7 @@ mod tests {
         }));
 
         artemis.handle_message(ActorMessage {
+            version: crate::traits::message::MESSAGE_SCHEMA_VERSION,
             id: "msg1".to_string(),
             from: Some(GodName::Zeus),
             to: GodName::Artemis,
@@ -175,6 +176,7 @@ mod tests {
         
         let search_query = QueryPayload::Search { query: "hipertensión".to_string() };
         let response = artemis.handle_message(ActorMessage {
+            version: crate::traits::message::MESSAGE_SCHEMA_VERSION,
             id: "msg2".to_string(),
             from: Some(GodName::Zeus),
             to: GodName::Artemis,
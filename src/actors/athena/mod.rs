@@ -126,9 +126,7 @@ impl OlympianActor for Athena {
 
 impl Athena {
     async fn handle_command(&mut self, cmd: CommandPayload) -> Result<ResponsePayload, ActorError> {
-        match cmd {
-            _ => Ok(ResponsePayload::Error { error: "Unknown command".to_string(), code: 400 }),
-        }
+        Err(ActorError::unsupported_variant(GodName::Athena, "command", &cmd))
     }
     
     async fn handle_query(&self, _query: crate::traits::message::QueryPayload) -> Result<ResponsePayload, ActorError> {
@@ -15,17 +15,93 @@ use crate::actors::{GodName, DivineDomain};
 use crate::traits::{OlympianActor, ActorState, ActorConfig, GodHeartbeat, HealthStatus};
 use crate::traits::message::{ActorMessage, MessagePayload, CommandPayload, ResponsePayload, QueryPayload};
 use crate::errors::ActorError;
+use crate::infrastructure::SurrealStore;
 
 // Submódulos
 pub mod scheduler;
 pub mod tasks;
 pub mod time_events;
 pub mod statistics;
+pub mod persistence;
 
-pub use scheduler::TaskScheduler;
-pub use tasks::{ScheduledTask, TaskDefinition, TaskStatus, TaskType, TaskResult};
+pub use scheduler::{CronExpression, TaskScheduler};
+pub use tasks::{ScheduledTask, TaskDefinition, TaskSchedule, TaskStatus, TaskType, TaskResult};
 pub use time_events::TimeEvent;
-pub use statistics::SchedulerMetrics;
+pub use statistics::{LoopHealth, SchedulerMetrics};
+pub use persistence::PersistedTask;
+
+/// Periodo del loop del scheduler
+const TICK_PERIOD_MS: i64 = 1000;
+/// Retraso a partir del cual una ejecución cuenta como tardía
+const LATE_THRESHOLD_MS: i64 = 2000;
+/// Espera máxima de `shutdown` a que terminen las ejecuciones en curso
+const SHUTDOWN_DRAIN_TIMEOUT_MS: u64 = 30_000;
+/// Intervalo con que el drenaje revisa las tareas en ejecución
+const DRAIN_POLL_MS: u64 = 50;
+
+/// Query custom con las próximas ejecuciones (`{"query_type": "get_next_executions", "limit": n}`)
+pub const NEXT_EXECUTIONS_QUERY: &str = "get_next_executions";
+/// Ejecuciones devueltas si la query no indica `limit`
+const DEFAULT_NEXT_EXECUTIONS: usize = 10;
+
+/// Próxima ejecución de una tarea, con su nombre y estado
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct UpcomingExecution {
+    pub task_id: String,
+    pub name: String,
+    pub status: TaskStatus,
+    pub fires_at: DateTime<Utc>,
+}
+
+/// Consultas de la agenda de Chronos sin pasar por su mailbox: comparten el scheduler
+/// y el registro de tareas con el actor (`GET /api/chronos/schedule`)
+#[derive(Debug, Clone)]
+pub struct ChronosScheduleQueries {
+    scheduler: Arc<RwLock<TaskScheduler>>,
+    tasks: Arc<RwLock<HashMap<String, ScheduledTask>>>,
+}
+
+impl ChronosScheduleQueries {
+    /// Ejecuciones en orden cronológico. Un id de la cola cuya tarea aún no está
+    /// registrada (`schedule_task` en curso) se omite.
+    pub async fn next_executions(&self, limit: usize) -> Vec<UpcomingExecution> {
+        let executions = self.scheduler.read().await.get_next_executions(limit);
+        let tasks = self.tasks.read().await;
+        executions
+            .into_iter()
+            .filter_map(|(task_id, fires_at)| {
+                let task = tasks.get(&task_id)?;
+                Some(UpcomingExecution {
+                    name: task.name.clone(),
+                    status: task.status.clone(),
+                    task_id,
+                    fires_at,
+                })
+            })
+            .collect()
+    }
+
+    pub async fn execute(&self, query: QueryPayload) -> Result<ResponsePayload, ActorError> {
+        match query {
+            QueryPayload::Custom(data) if data.get("query_type").and_then(|v| v.as_str()) == Some(NEXT_EXECUTIONS_QUERY) => {
+                let limit = data
+                    .get("limit")
+                    .and_then(|v| v.as_u64())
+                    .map_or(DEFAULT_NEXT_EXECUTIONS, |limit| limit as usize);
+                let executions = self.next_executions(limit).await;
+                Ok(ResponsePayload::Data {
+                    data: serde_json::json!({
+                        "executions": executions,
+                    })
+                })
+            }
+            other => Err(ActorError::InvalidQuery {
+                god: GodName::Chronos,
+                reason: format!("{:?} no es una query de la agenda", other),
+            }),
+        }
+    }
+}
 
 /// Chronos - Dios del Scheduling
 /// Gestiona la programación y ejecución de tareas en el sistema
@@ -42,6 +118,10 @@ pub struct Chronos {
     metrics: Arc<RwLock<SchedulerMetrics>>,
     /// Flag para controlar el loop de scheduling
     running: Arc<RwLock<bool>>,
+    /// Deriva del loop y ejecuciones tardías
+    loop_health: Arc<RwLock<LoopHealth>>,
+    /// Persistencia de definiciones de tareas (sin store, solo en memoria)
+    store: Option<Arc<SurrealStore>>,
 }
 
 impl Chronos {
@@ -56,11 +136,36 @@ impl Chronos {
             tasks: Arc::new(RwLock::new(HashMap::new())),
             metrics: Arc::new(RwLock::new(SchedulerMetrics::default())),
             running: Arc::new(RwLock::new(false)),
+            loop_health: Arc::new(RwLock::new(LoopHealth::new(TICK_PERIOD_MS, LATE_THRESHOLD_MS))),
+            store: None,
+        }
+    }
+
+    pub fn set_store(&mut self, store: Arc<SurrealStore>) {
+        self.store = Some(store);
+    }
+
+    /// Acceso compartido a la agenda para la API (`GET /api/chronos/schedule`)
+    pub fn schedule_queries(&self) -> ChronosScheduleQueries {
+        ChronosScheduleQueries {
+            scheduler: self.scheduler.clone(),
+            tasks: self.tasks.clone(),
         }
     }
 
+    pub async fn loop_health(&self) -> LoopHealth {
+        self.loop_health.read().await.clone()
+    }
+
     /// Programa una nueva tarea
     pub async fn schedule_task(&self, definition: TaskDefinition) -> Result<String, ActorError> {
+        let definition = definition
+            .resolve_schedule()
+            .and_then(Self::validate_schedule)
+            .map_err(|reason| ActorError::InvalidCommand {
+                god: GodName::Chronos,
+                reason,
+            })?;
         let task_id = format!("task_{}_{}", Utc::now().timestamp_millis(), std::process::id());
         let name = definition.name.clone(); // Clonar antes de mover
         
@@ -77,6 +182,9 @@ impl Chronos {
         let mut scheduler = self.scheduler.write().await;
         scheduler.schedule_task(&task)?;
         
+        drop(scheduler);
+        self.persist_task(&task).await;
+        
         // Registrar la tarea
         let mut tasks = self.tasks.write().await;
         tasks.insert(task_id.clone(), task);
@@ -90,6 +198,16 @@ impl Chronos {
         Ok(task_id)
     }
 
+    /// Una tarea recurrente exige una expresión cron válida y, si otra tarea trae una,
+    /// también se valida. Una one-shot sin expresión queda a la espera de `execute_now`.
+    fn validate_schedule(definition: TaskDefinition) -> Result<TaskDefinition, String> {
+        match (&definition.task_type, &definition.cron_expression) {
+            (TaskType::Recurring, None) => Err("Una tarea recurrente requiere una expresión cron".to_string()),
+            (_, Some(expression)) => CronExpression::parse(expression).map(|_| definition),
+            _ => Ok(definition),
+        }
+    }
+
     /// Cancela una tarea programada
     pub async fn cancel_task(&self, task_id: &str) -> Result<(), ActorError> {
         let mut scheduler = self.scheduler.write().await;
@@ -99,6 +217,9 @@ impl Chronos {
         if let Some(task) = tasks.get_mut(task_id) {
             task.status = TaskStatus::Cancelled;
             task.updated_at = Utc::now();
+            let task = task.clone();
+            drop(tasks);
+            self.persist_task(&task).await;
             info!("⏰ Chronos: Tarea {} cancelada", task_id);
             Ok(())
         } else {
@@ -120,6 +241,9 @@ impl Chronos {
             }
             task.status = TaskStatus::Paused;
             task.updated_at = Utc::now();
+            let task = task.clone();
+            drop(tasks);
+            self.persist_task(&task).await;
             info!("⏰ Chronos: Tarea {} pausada", task_id);
             Ok(())
         } else {
@@ -145,6 +269,10 @@ impl Chronos {
             // Recalcular próxima ejecución
             let mut scheduler = self.scheduler.write().await;
             scheduler.reschedule_task(&task)?;
+            drop(scheduler);
+            let task = task.clone();
+            drop(tasks);
+            self.persist_task(&task).await;
             
             info!("⏰ Chronos: Tarea {} reanudada", task_id);
             Ok(())
@@ -183,6 +311,11 @@ impl Chronos {
                 t.last_result = Some(result.clone());
                 t.updated_at = Utc::now();
             }
+            let finished = tasks.get(task_id).cloned();
+            drop(tasks);
+            if let Some(task) = finished {
+                self.persist_task(&task).await;
+            }
             
             // Actualizar métricas
             let mut metrics = self.metrics.write().await;
@@ -222,9 +355,81 @@ impl Chronos {
         scheduler.get_next_executions(limit)
     }
 
+    /// Guarda la definición de la tarea, o la borra si ya no debe restaurarse
+    async fn persist_task(&self, task: &ScheduledTask) {
+        let Some(store) = &self.store else { return };
+        let result = match PersistedTask::from_task(task) {
+            Some(record) => persistence::save(store, &record).await,
+            None => persistence::remove(store, &task.id).await,
+        };
+        if let Err(e) = result {
+            warn!("⏰ Chronos: No se pudo persistir la tarea {}: {}", task.id, e);
+        }
+    }
+
+    /// Restaura y reprograma las tareas guardadas
+    pub async fn restore_tasks(&self) -> Result<usize, ActorError> {
+        let Some(store) = &self.store else { return Ok(0) };
+        let records = persistence::load_all(store).await.map_err(|e| ActorError::StateError {
+            god: GodName::Chronos,
+            message: format!("No se pudieron cargar las tareas persistidas: {}", e),
+        })?;
+        Ok(self.restore(records).await)
+    }
+
+    async fn restore(&self, records: Vec<PersistedTask>) -> usize {
+        let mut tasks = self.tasks.write().await;
+        let mut scheduler = self.scheduler.write().await;
+        persistence::restore_into(&mut tasks, &mut scheduler, records)
+    }
+
+    /// Espera (hasta `timeout`) a que terminen las tareas en `Running`; las que siguen
+    /// en ejecución al vencer el plazo se marcan `Interrupted`. Devuelve sus ids.
+    pub async fn drain(&self, timeout: tokio::time::Duration) -> Vec<String> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let running = self.tasks.read().await.values().filter(|t| t.status == TaskStatus::Running).count();
+            if running == 0 {
+                return Vec::new();
+            }
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                break;
+            }
+            debug!("⏰ Chronos: Esperando {} tareas en ejecución", running);
+            tokio::time::sleep((deadline - now).min(tokio::time::Duration::from_millis(DRAIN_POLL_MS))).await;
+        }
+
+        let now = Utc::now();
+        let mut tasks = self.tasks.write().await;
+        let interrupted: Vec<ScheduledTask> = tasks
+            .values_mut()
+            .filter(|t| t.status == TaskStatus::Running)
+            .map(|t| {
+                t.status = TaskStatus::Interrupted;
+                t.last_result = Some(TaskResult {
+                    success: false,
+                    message: "Interrumpida al detener Chronos".to_string(),
+                    executed_at: now,
+                    duration_ms: t.last_execution.map_or(0, |started| (now - started).num_milliseconds().max(0) as u64),
+                    output: serde_json::Value::Null,
+                });
+                t.updated_at = now;
+                t.clone()
+            })
+            .collect();
+        drop(tasks);
+
+        for task in &interrupted {
+            warn!("⏰ Chronos: Tarea '{}' ({}) interrumpida al detener el scheduler", task.name, task.id);
+            self.persist_task(task).await;
+        }
+        interrupted.into_iter().map(|t| t.id).collect()
+    }
+
     /// Loop principal del scheduler
     async fn start_scheduler_loop(&self) {
-        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_millis(TICK_PERIOD_MS as u64));
         
         loop {
             interval.tick().await;
@@ -239,17 +444,27 @@ impl Chronos {
             
             // Revisar tareas pendientes
             let now = Utc::now();
+            self.loop_health.write().await.record_tick(now);
             let due_tasks = {
                 let scheduler = self.scheduler.read().await;
-                scheduler.get_due_tasks(now)
+                scheduler.get_due_executions(now)
             };
             
             // Ejecutar tareas vencidas
-            for task_id in due_tasks {
+            for (task_id, scheduled_at) in due_tasks {
+                if self.loop_health.write().await.record_execution(scheduled_at, now) {
+                    warn!(
+                        "⏰ Chronos: Tarea {} disparada {}ms tarde",
+                        task_id,
+                        (now - scheduled_at).num_milliseconds()
+                    );
+                }
                 if let Err(e) = self.execute_task_by_id(&task_id).await {
                     error!("⏰ Chronos: Error ejecutando tarea {}: {:?}", task_id, e);
                 }
             }
+            
+            crate::olympus_services::record_chronos_health(self.loop_health().await).await;
         }
     }
 
@@ -299,6 +514,11 @@ impl Chronos {
                 t.last_result = Some(result.clone());
                 t.updated_at = Utc::now();
             }
+            let executed = tasks.get(task_id).cloned();
+            drop(tasks);
+            if let Some(task) = executed {
+                self.persist_task(&task).await;
+            }
             
             // Actualizar métricas
             let mut metrics = self.metrics.write().await;
@@ -386,18 +606,37 @@ impl OlympianActor for Chronos {
         let tasks = self.tasks.read().await;
         let metrics = self.metrics.read().await;
         
+        let definitions: Vec<PersistedTask> = tasks.values().filter_map(PersistedTask::from_task).collect();
+        
         serde_json::json!({
             "name": "Chronos",
             "messages": self.state.message_count,
             "tasks_count": tasks.len(),
+            "tasks": definitions,
             "tasks_scheduled": metrics.tasks_scheduled,
             "tasks_executed": metrics.tasks_executed,
             "status": self.state.status,
         })
     }
 
-    fn load_state(&mut self, _state: &serde_json::Value) -> Result<(), ActorError> {
-        // TODO: Restaurar tareas desde persistencia
+    fn load_state(&mut self, state: &serde_json::Value) -> Result<(), ActorError> {
+        let records: Vec<PersistedTask> = match state.get("tasks") {
+            Some(tasks) => serde_json::from_value(tasks.clone()).map_err(|e| ActorError::StateError {
+                god: GodName::Chronos,
+                message: format!("Tareas persistidas inválidas: {}", e),
+            })?,
+            None => return Ok(()),
+        };
+        
+        // Con `&mut self` nadie más retiene los locks salvo un loop en marcha
+        let busy = || ActorError::StateError {
+            god: GodName::Chronos,
+            message: "Scheduler ocupado, no se puede restaurar el estado".to_string(),
+        };
+        let mut tasks = self.tasks.try_write().map_err(|_| busy())?;
+        let mut scheduler = self.scheduler.try_write().map_err(|_| busy())?;
+        let restored = persistence::restore_into(&mut tasks, &mut scheduler, records);
+        info!("⏰ Chronos: {} tareas restauradas desde el estado", restored);
         Ok(())
     }
 
@@ -436,6 +675,12 @@ impl OlympianActor for Chronos {
 
     async fn initialize(&mut self) -> Result<(), ActorError> {
         info!("⏰ Chronos: Scheduler v15 iniciado");
+        
+        match self.restore_tasks().await {
+            Ok(0) => {}
+            Ok(restored) => info!("⏰ Chronos: {} tareas restauradas desde persistencia", restored),
+            Err(e) => warn!("⏰ Chronos: {}", e),
+        }
         info!("⏰ Chronos: Listo para programar tareas");
         
         // Iniciar el loop de scheduling
@@ -448,10 +693,15 @@ impl OlympianActor for Chronos {
     async fn shutdown(&mut self) -> Result<(), ActorError> {
         info!("⏰ Chronos: Deteniendo scheduler...");
         
-        let mut running = self.running.write().await;
-        *running = false;
+        // Primero dejar de disparar tareas nuevas, luego esperar a las que están en curso
+        *self.running.write().await = false;
+        let interrupted = self.drain(tokio::time::Duration::from_millis(SHUTDOWN_DRAIN_TIMEOUT_MS)).await;
         
-        info!("⏰ Chronos: Scheduler detenido");
+        if interrupted.is_empty() {
+            info!("⏰ Chronos: Scheduler detenido");
+        } else {
+            warn!("⏰ Chronos: Scheduler detenido con {} tareas interrumpidas", interrupted.len());
+        }
         Ok(())
     }
 
@@ -537,16 +787,10 @@ impl Chronos {
                             })
                         })
                     }
-                    _ => Err(ActorError::InvalidCommand { 
-                        god: GodName::Chronos, 
-                        reason: format!("Acción '{}' no soportada", action.unwrap_or("unknown")) 
-                    }),
+                    _ => Err(ActorError::unsupported(GodName::Chronos, "command", action.unwrap_or("unknown"))),
                 }
             }
-            _ => Err(ActorError::InvalidCommand { 
-                god: GodName::Chronos, 
-                reason: "Comando no soportado".to_string() 
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Chronos, "command", &other)),
         }
     }
 
@@ -560,6 +804,7 @@ impl Chronos {
                 let running = tasks.values().filter(|t| t.status == TaskStatus::Running).count() as u64;
                 let completed = tasks.values().filter(|t| t.status == TaskStatus::Completed).count() as u64;
                 let failed = tasks.values().filter(|t| t.status == TaskStatus::Failed).count() as u64;
+                let interrupted = tasks.values().filter(|t| t.status == TaskStatus::Interrupted).count() as u64;
                 
                 Ok(ResponsePayload::Stats { 
                     data: serde_json::json!({
@@ -571,6 +816,7 @@ impl Chronos {
                         "tasks_running": running,
                         "tasks_completed": completed,
                         "tasks_failed_total": failed,
+                        "tasks_interrupted": interrupted,
                     })
                 })
             }
@@ -595,24 +841,16 @@ impl Chronos {
                             })?;
                         
                         if let Some(task) = self.get_task_status(task_id).await {
-                            Ok(ResponsePayload::Data { 
-                                data: serde_json::to_value(&task).unwrap_or_default()
-                            })
+                            let mut data = serde_json::to_value(&task).unwrap_or_default();
+                            data["next_execution"] = serde_json::json!(self.scheduler.read().await.next_execution_of(task_id));
+                            Ok(ResponsePayload::Data { data })
                         } else {
                             Err(ActorError::NotFound { 
                                 god: GodName::Chronos
                             })
                         }
                     }
-                    "get_next_executions" => {
-                        let limit = data.get("limit").and_then(|v| v.as_u64()).unwrap_or(10) as usize;
-                        let executions = self.get_next_executions(limit).await;
-                        Ok(ResponsePayload::Data { 
-                            data: serde_json::json!({
-                                "executions": executions,
-                            })
-                        })
-                    }
+                    NEXT_EXECUTIONS_QUERY => self.schedule_queries().execute(QueryPayload::Custom(data.clone())).await,
                     "scheduler_health" => {
                         let running = *self.running.read().await;
                         let scheduler = self.scheduler.read().await;
@@ -621,19 +859,14 @@ impl Chronos {
                                 "running": running,
                                 "scheduled_count": scheduler.task_count(),
                                 "timestamp": Utc::now(),
+                                "loop": self.loop_health.read().await.report(Utc::now()),
                             })
                         })
                     }
-                    _ => Err(ActorError::InvalidQuery { 
-                        god: GodName::Chronos, 
-                        reason: format!("Query type '{}' no soportado", query_type) 
-                    }),
+                    _ => Err(ActorError::unsupported(GodName::Chronos, "query", query_type)),
                 }
             }
-            _ => Err(ActorError::InvalidQuery { 
-                god: GodName::Chronos, 
-                reason: "Query no soportado".to_string() 
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Chronos, "query", &other)),
         }
     }
 }
@@ -663,6 +896,7 @@ mod tests {
             name: "Test Task".to_string(),
             task_type: TaskType::OneShot,
             cron_expression: Some("0 * * * * *".to_string()), // Cada minuto
+            schedule: None,
             payload: json!({"action": "test"}),
             creator: Some(GodName::Zeus),
         };
@@ -685,6 +919,7 @@ mod tests {
             name: "Task to Cancel".to_string(),
             task_type: TaskType::OneShot,
             cron_expression: Some("0 0 * * * *".to_string()),
+            schedule: None,
             payload: json!({}),
             creator: None,
         };
@@ -706,6 +941,7 @@ mod tests {
             name: "Executable Task".to_string(),
             task_type: TaskType::OneShot,
             cron_expression: None,
+            schedule: None,
             payload: json!({"test": true}),
             creator: Some(GodName::Athena),
         };
@@ -722,6 +958,165 @@ mod tests {
         
         Ok(())
     }
-}
 
+    #[tokio::test]
+    async fn test_chronos_drain_waits_for_running_task() -> Result<(), ActorError> {
+        let chronos = Chronos::new().await;
+        let definition = |name: &str| TaskDefinition {
+            name: name.to_string(),
+            task_type: TaskType::OneShot,
+            cron_expression: None,
+            schedule: None,
+            payload: json!({}),
+            creator: None,
+        };
+
+        // La ejecución simulada dura 100ms: el drenaje espera a que termine
+        let task_id = chronos.schedule_task(definition("Larga")).await?;
+        let (result, interrupted) = tokio::join!(chronos.execute_now(&task_id), async {
+            tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+            chronos.drain(tokio::time::Duration::from_secs(5)).await
+        });
+        assert!(result?.success);
+        assert!(interrupted.is_empty());
+        assert_eq!(chronos.get_task_status(&task_id).await.unwrap().status, TaskStatus::Completed);
+
+        // Con un plazo menor que la ejecución queda marcada como interrumpida
+        tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        let task_id = chronos.schedule_task(definition("Cortada")).await?;
+        let (_, status) = tokio::join!(chronos.execute_now(&task_id), async {
+            tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+            let interrupted = chronos.drain(tokio::time::Duration::from_millis(10)).await;
+            assert_eq!(interrupted, vec![task_id.clone()]);
+            chronos.get_task_status(&task_id).await.unwrap()
+        });
+        assert_eq!(status.status, TaskStatus::Interrupted);
+        assert!(!status.last_result.unwrap().success);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_chronos_rejects_malformed_cron() -> Result<(), ActorError> {
+        let chronos = Chronos::new().await;
+        let definition = |task_type: TaskType, cron: Option<&str>| TaskDefinition {
+            name: "Backup nocturno".to_string(),
+            task_type,
+            cron_expression: cron.map(str::to_string),
+            schedule: None,
+            payload: json!({}),
+            creator: None,
+        };
+
+        let error = chronos.schedule_task(definition(TaskType::Recurring, Some("* * *"))).await.unwrap_err();
+        assert!(matches!(error, ActorError::InvalidCommand { ref reason, .. } if reason.contains("6 campos")));
+        assert!(chronos.schedule_task(definition(TaskType::Recurring, None)).await.is_err());
+        assert!(chronos.schedule_task(definition(TaskType::OneShot, Some("0 99 * * * *"))).await.is_err());
+        assert!(chronos.list_tasks(None).await.is_empty());
+
+        chronos.schedule_task(definition(TaskType::Recurring, Some("0 0 3 * * *"))).await?;
+        // La one-shot sin cron se registra pero no se programa
+        tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        let one_shot = chronos.schedule_task(definition(TaskType::OneShot, None)).await?;
+        assert!(chronos.scheduler.read().await.next_execution_of(&one_shot).is_none());
+        assert_eq!(chronos.list_tasks(None).await.len(), 2);
 
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_chronos_schedule_lists_next_executions_with_task_names() -> Result<(), ActorError> {
+        let chronos = Chronos::new().await;
+        let backup: TaskDefinition = serde_json::from_value(json!({
+            "name": "Backup nocturno",
+            "schedule": { "type": "cron", "expression": "0 0 3 * * *" },
+        })).unwrap();
+        let backup_id = chronos.schedule_task(backup).await?;
+        tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        let report: TaskDefinition = serde_json::from_value(json!({
+            "name": "Informe",
+            "schedule": { "type": "interval", "seconds": 60 },
+        })).unwrap();
+        let report_id = chronos.schedule_task(report).await?;
+
+        let query = QueryPayload::Custom(json!({ "query_type": NEXT_EXECUTIONS_QUERY, "limit": 5 }));
+        let ResponsePayload::Data { data } = chronos.schedule_queries().execute(query).await? else {
+            panic!("se esperaba ResponsePayload::Data");
+        };
+        let executions = data["executions"].as_array().unwrap();
+        assert_eq!(executions.len(), 2);
+        let execution = |id: &str| executions.iter().find(|e| e["task_id"] == id).unwrap();
+        assert_eq!(execution(&report_id)["name"], "Informe");
+        assert_eq!(execution(&backup_id)["name"], "Backup nocturno");
+        assert_eq!(execution(&backup_id)["status"], "pending");
+        assert!(execution(&backup_id)["fires_at"].as_str().unwrap().ends_with("T03:00:00Z"));
+
+        assert!(chronos.schedule_queries().execute(QueryPayload::Metrics).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_chronos_interval_schedule_via_api() -> Result<(), ActorError> {
+        let chronos = Chronos::new().await;
+
+        let definition: TaskDefinition = serde_json::from_value(json!({
+            "name": "Cada 5 minutos",
+            "schedule": { "type": "interval", "seconds": 300 },
+        })).unwrap();
+
+        let task_id = chronos.schedule_task(definition).await?;
+        let task = chronos.get_task_status(&task_id).await.unwrap();
+        assert_eq!(task.task_type, TaskType::Interval(300));
+        assert!(task.cron_expression.is_none());
+
+        let next = chronos.scheduler.read().await.next_execution_of(&task_id).unwrap();
+        let delta = (next - Utc::now()).num_seconds();
+        assert!((298..=300).contains(&delta));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_chronos_restores_task_definitions() -> Result<(), ActorError> {
+        let chronos = Chronos::new().await;
+
+        let backup: TaskDefinition = serde_json::from_value(json!({
+            "name": "Backup",
+            "schedule": { "type": "interval", "seconds": 3600 },
+            "payload": { "action": "backup" },
+        })).unwrap();
+        let backup_id = chronos.schedule_task(backup).await?;
+        let last_execution = Utc::now() - chrono::Duration::minutes(10);
+        chronos.tasks.write().await.get_mut(&backup_id).unwrap().last_execution = Some(last_execution);
+
+        // Los ids llevan el timestamp en ms
+        tokio::time::sleep(tokio::time::Duration::from_millis(2)).await;
+        let one_shot = TaskDefinition {
+            name: "Una vez".to_string(),
+            task_type: TaskType::OneShot,
+            cron_expression: None,
+            schedule: None,
+            payload: json!({}),
+            creator: None,
+        };
+        let one_shot_id = chronos.schedule_task(one_shot).await?;
+        chronos.execute_now(&one_shot_id).await?;
+
+        let state = chronos.persistent_state().await;
+
+        // "Reinicio": un Chronos nuevo a partir del estado guardado
+        let mut restarted = Chronos::new().await;
+        restarted.load_state(&state)?;
+
+        let task = restarted.get_task_status(&backup_id).await.unwrap();
+        assert_eq!(task.status, TaskStatus::Pending);
+        assert_eq!(task.payload, json!({ "action": "backup" }));
+        let next = restarted.scheduler.read().await.next_execution_of(&backup_id);
+        assert_eq!(next, Some(last_execution + chrono::Duration::seconds(3600)));
+
+        assert!(restarted.get_task_status(&one_shot_id).await.is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+// src/actors/chronos/persistence.rs
+// OLYMPUS v15 - Persistencia de definiciones de tareas de Chronos
+// Solo se guarda la definición (qué, cuándo, pausada o no); el estado transitorio
+// de ejecución (Running, último resultado) no sobrevive a un reinicio
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::scheduler::TaskScheduler;
+use super::tasks::{ScheduledTask, TaskStatus, TaskType};
+use crate::actors::GodName;
+use crate::infrastructure::query::{record_id, BoundQuery};
+use crate::infrastructure::surreal::{SurrealError, SurrealStore};
+
+/// Tabla de SurrealDB con las tareas programadas
+pub const TASKS_TABLE: &str = "chronos_task";
+
+/// Definición persistida de una tarea. El id de la tarea va en `task_id` porque
+/// `id` es el id de registro de SurrealDB (`chronos_task:<task_id>`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PersistedTask {
+    pub task_id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub task_type: TaskType,
+    pub cron_expression: Option<String>,
+    #[serde(default)]
+    pub payload: serde_json::Value,
+    pub creator: GodName,
+    #[serde(default)]
+    pub paused: bool,
+    pub created_at: DateTime<Utc>,
+    /// Base de cálculo de las tareas por intervalo
+    pub last_execution: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub execution_count: u64,
+}
+
+impl PersistedTask {
+    /// `None` si la tarea no debe restaurarse: cancelada, o one-shot ya ejecutada
+    pub fn from_task(task: &ScheduledTask) -> Option<Self> {
+        let restorable = match task.status {
+            TaskStatus::Cancelled => false,
+            _ if task.task_type.is_recurring() => true,
+            TaskStatus::Pending | TaskStatus::Paused => true,
+            _ => false,
+        };
+        restorable.then(|| Self {
+            task_id: task.id.clone(),
+            name: task.name.clone(),
+            task_type: task.task_type.clone(),
+            cron_expression: task.cron_expression.clone(),
+            payload: task.payload.clone(),
+            creator: task.creator,
+            paused: task.status == TaskStatus::Paused,
+            created_at: task.created_at,
+            last_execution: task.last_execution,
+            execution_count: task.execution_count,
+        })
+    }
+
+    pub fn into_task(self) -> ScheduledTask {
+        let mut task = ScheduledTask::new(
+            self.task_id,
+            self.name,
+            self.task_type,
+            self.cron_expression,
+            self.payload,
+            self.creator,
+        );
+        task.status = if self.paused { TaskStatus::Paused } else { TaskStatus::Pending };
+        task.created_at = self.created_at;
+        task.last_execution = self.last_execution;
+        task.execution_count = self.execution_count;
+        task
+    }
+}
+
+/// Registra las tareas restauradas y programa las que no están pausadas.
+/// Las que ya existen en memoria se conservan. Devuelve cuántas se restauraron.
+pub fn restore_into(
+    tasks: &mut HashMap<String, ScheduledTask>,
+    scheduler: &mut TaskScheduler,
+    records: Vec<PersistedTask>,
+) -> usize {
+    let mut restored = 0;
+    for record in records {
+        if tasks.contains_key(&record.task_id) {
+            continue;
+        }
+        let task = record.into_task();
+        if task.status == TaskStatus::Pending {
+            if let Err(e) = scheduler.schedule_task(&task) {
+                tracing::warn!("⏰ Chronos: Tarea persistida {} no reprogramable: {:?}", task.id, e);
+                continue;
+            }
+        }
+        tasks.insert(task.id.clone(), task);
+        restored += 1;
+    }
+    restored
+}
+
+/// Guarda (o reemplaza) la definición de la tarea
+pub async fn save(store: &SurrealStore, record: &PersistedTask) -> Result<(), SurrealError> {
+    let id = record_id(TASKS_TABLE, &record.task_id)?;
+    store.update(TASKS_TABLE, &id.to_string(), record).await?;
+    Ok(())
+}
+
+pub async fn remove(store: &SurrealStore, task_id: &str) -> Result<(), SurrealError> {
+    let id = record_id(TASKS_TABLE, task_id)?;
+    store.delete(TASKS_TABLE, &id.to_string()).await
+}
+
+pub async fn load_all(store: &SurrealStore) -> Result<Vec<PersistedTask>, SurrealError> {
+    let query = BoundQuery::with_table("SELECT * FROM {table}", TASKS_TABLE)?;
+    store.query_bound(query).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn task(task_type: TaskType, status: TaskStatus) -> ScheduledTask {
+        let mut task = ScheduledTask::new(
+            "task_1".to_string(),
+            "Backup".to_string(),
+            task_type,
+            None,
+            json!({ "action": "backup" }),
+            GodName::Hestia,
+        );
+        task.status = status;
+        task
+    }
+
+    #[test]
+    fn test_only_pending_definitions_are_restorable() {
+        assert!(PersistedTask::from_task(&task(TaskType::Interval(60), TaskStatus::Running)).is_some());
+        assert!(PersistedTask::from_task(&task(TaskType::Interval(60), TaskStatus::Cancelled)).is_none());
+        assert!(PersistedTask::from_task(&task(TaskType::OneShot, TaskStatus::Pending)).is_some());
+        assert!(PersistedTask::from_task(&task(TaskType::OneShot, TaskStatus::Completed)).is_none());
+        assert!(PersistedTask::from_task(&task(TaskType::OneShot, TaskStatus::Failed)).is_none());
+
+        let paused = PersistedTask::from_task(&task(TaskType::Interval(60), TaskStatus::Paused)).unwrap();
+        assert_eq!(paused.into_task().status, TaskStatus::Paused);
+
+        // Una tarea en ejecución al guardarse vuelve como pendiente
+        let running = PersistedTask::from_task(&task(TaskType::Interval(60), TaskStatus::Running)).unwrap();
+        assert_eq!(running.into_task().status, TaskStatus::Pending);
+    }
+}
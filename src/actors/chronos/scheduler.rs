@@ -1,7 +1,7 @@
 // src/actors/chronos/scheduler.rs
 // OLYMPUS v15 - Task Scheduler con parser cron
 
-use crate::actors::chronos::tasks::{ScheduledTask, TaskStatus};
+use crate::actors::chronos::tasks::{ScheduledTask, TaskStatus, TaskType};
 use crate::actors::GodName;
 use crate::errors::ActorError;
 use chrono::{DateTime, Datelike, Timelike, Utc};
@@ -25,20 +25,46 @@ impl TaskScheduler {
         }
     }
 
-    /// Programa una tarea en el scheduler
-    pub fn schedule_task(&mut self, task: &ScheduledTask) -> Result<(), ActorError> {
-        // Calcular próxima ejecución basada en la expresión cron
-        let next_execution = if let Some(ref cron) = task.cron_expression {
-            self.cron_parser
-                .next_execution(cron, Utc::now())
-                .ok_or_else(|| ActorError::InvalidCommand {
+    /// Calcula la próxima ejecución de una tarea
+    /// - Intervalo: última ejecución (o `now` si nunca corrió) + intervalo
+    /// - Cron: siguiente coincidencia de la expresión
+    /// - Sin programación: inmediata
+    pub fn next_execution_for(&self, task: &ScheduledTask, now: DateTime<Utc>) -> Result<DateTime<Utc>, ActorError> {
+        if let TaskType::Interval(seconds) = task.task_type {
+            if seconds == 0 {
+                return Err(ActorError::InvalidCommand {
                     god: GodName::Chronos,
-                    reason: format!("Expresión cron inválida: {}", cron),
-                })?
-        } else {
-            // Si no hay cron, es one-shot inmediato
-            Utc::now()
-        };
+                    reason: "El intervalo debe ser de al menos 1 segundo".to_string(),
+                });
+            }
+            let base = task.last_execution.unwrap_or(now);
+            return Ok(base + chrono::Duration::seconds(seconds as i64));
+        }
+
+        match task.cron_expression {
+            Some(ref cron) => {
+                CronExpression::parse(cron).map_err(|reason| ActorError::InvalidCommand {
+                    god: GodName::Chronos,
+                    reason,
+                })?;
+                self.cron_parser
+                    .next_execution(cron, now)
+                    .ok_or_else(|| ActorError::InvalidCommand {
+                        god: GodName::Chronos,
+                        reason: format!("La expresión cron '{}' no se cumple en los próximos 4 años", cron),
+                    })
+            }
+            None => Ok(now),
+        }
+    }
+
+    /// Programa una tarea en el scheduler. Una one-shot sin expresión cron no entra en
+    /// la cola: solo se ejecuta con `execute_now`.
+    pub fn schedule_task(&mut self, task: &ScheduledTask) -> Result<(), ActorError> {
+        if task.task_type == TaskType::OneShot && task.cron_expression.is_none() {
+            return Ok(());
+        }
+        let next_execution = self.next_execution_for(task, Utc::now())?;
 
         // Agregar a la cola
         self.execution_queue
@@ -49,6 +75,14 @@ impl TaskScheduler {
         Ok(())
     }
 
+    /// Próxima ejecución programada de una tarea concreta
+    pub fn next_execution_of(&self, task_id: &str) -> Option<DateTime<Utc>> {
+        self.execution_queue
+            .iter()
+            .find(|(_, ids)| ids.iter().any(|id| id == task_id))
+            .map(|(timestamp, _)| *timestamp)
+    }
+
     /// Cancela una tarea del scheduler
     pub fn cancel_task(&mut self, task_id: &str) -> Result<(), ActorError> {
         // Remover de todas las entradas de la cola
@@ -97,6 +131,14 @@ impl TaskScheduler {
         due_tasks
     }
 
+    /// Como `get_due_tasks`, con la hora a la que estaba programada cada ejecución
+    pub fn get_due_executions(&self, now: DateTime<Utc>) -> Vec<(String, DateTime<Utc>)> {
+        self.execution_queue
+            .range(..=now)
+            .flat_map(|(timestamp, task_ids)| task_ids.iter().map(move |id| (id.clone(), *timestamp)))
+            .collect()
+    }
+
     /// Obtiene las próximas N ejecuciones programadas
     pub fn get_next_executions(&self, limit: usize) -> Vec<(String, DateTime<Utc>)> {
         let mut executions = Vec::new();
@@ -228,8 +270,8 @@ impl CronParser {
                     values.push(v);
                 }
             } else if part.starts_with("*/") {
-                // Cada N unidades
-                let step: i32 = part[2..].parse().ok()?;
+                // Cada N unidades (un paso 0 no avanzaría nunca)
+                let step: i32 = part[2..].parse().ok().filter(|step| *step > 0)?;
                 let mut v = min;
                 while v <= max {
                     values.push(v);
@@ -294,6 +336,16 @@ impl Default for CronParser {
     }
 }
 
+/// Campos de una expresión cron, en orden, con su rango de valores
+const CRON_FIELDS: [(&str, i32, i32); 6] = [
+    ("segundo", 0, 59),
+    ("minuto", 0, 59),
+    ("hora", 0, 23),
+    ("día", 1, 31),
+    ("mes", 1, 12),
+    ("día de la semana", 0, 6),
+];
+
 /// Representa una expresión cron parseada
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CronExpression {
@@ -309,6 +361,29 @@ impl CronExpression {
         }
     }
 
+    /// Valida los 6 campos con las mismas reglas que usa el scheduler para calcular
+    /// las ejecuciones; el error indica el campo que falla
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let parts: Vec<&str> = expression.split_whitespace().collect();
+        if parts.len() != CRON_FIELDS.len() {
+            return Err(format!(
+                "Expresión cron inválida '{}': se esperan 6 campos (segundo minuto hora día mes día_semana), tiene {}",
+                expression,
+                parts.len()
+            ));
+        }
+        let parser = CronParser::new();
+        for (part, (field, min, max)) in parts.iter().zip(CRON_FIELDS) {
+            if parser.parse_field(part, min, max).is_none() {
+                return Err(format!(
+                    "Expresión cron inválida '{}': {} '{}' no válido (rango {}-{})",
+                    expression, field, part, min, max
+                ));
+            }
+        }
+        Ok(Self::new(expression))
+    }
+
     /// Genera una descripción legible de la expresión cron
     fn describe(expression: &str) -> String {
         // Descripciones básicas
@@ -323,3 +398,65 @@ impl CronExpression {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval_task(seconds: u64) -> ScheduledTask {
+        ScheduledTask::new(
+            "task_interval".to_string(),
+            "Cada 5 minutos".to_string(),
+            TaskType::Interval(seconds),
+            None,
+            serde_json::json!({}),
+            GodName::Zeus,
+        )
+    }
+
+    #[test]
+    fn test_interval_reschedules_from_last_execution() {
+        let mut scheduler = TaskScheduler::new();
+        let mut task = interval_task(300);
+        let before = Utc::now();
+
+        scheduler.schedule_task(&task).unwrap();
+        let first = scheduler.next_execution_of(&task.id).unwrap();
+        assert!(first >= before + chrono::Duration::seconds(300));
+        assert!(first <= Utc::now() + chrono::Duration::seconds(300));
+
+        // Tras ejecutarse, la siguiente es última ejecución + intervalo, no un parse cron
+        let executed_at = first;
+        task.last_execution = Some(executed_at);
+        scheduler.reschedule_task(&task).unwrap();
+        assert_eq!(scheduler.next_execution_of(&task.id), Some(executed_at + chrono::Duration::seconds(300)));
+        assert_eq!(scheduler.task_count(), 1);
+
+        assert!(scheduler.schedule_task(&interval_task(0)).is_err());
+    }
+
+    #[test]
+    fn test_cron_expression_validation() {
+        assert!(CronExpression::parse("0 */15 8-20 * * 1,3,5").is_ok());
+
+        let error = CronExpression::parse("* * *").unwrap_err();
+        assert!(error.contains("se esperan 6 campos"), "{}", error);
+        let error = CronExpression::parse("0 61 * * * *").unwrap_err();
+        assert!(error.contains("minuto '61'"), "{}", error);
+        assert!(CronExpression::parse("*/0 * * * * *").is_err());
+        assert!(CronExpression::parse("0 0 * * * lunes").is_err());
+
+        // Sin expresión cron, una one-shot no entra en la cola
+        let mut scheduler = TaskScheduler::new();
+        let one_shot = ScheduledTask::new(
+            "task_once".to_string(),
+            "Una vez".to_string(),
+            TaskType::OneShot,
+            None,
+            serde_json::json!({}),
+            GodName::Zeus,
+        );
+        scheduler.schedule_task(&one_shot).unwrap();
+        assert_eq!(scheduler.task_count(), 0);
+    }
+}
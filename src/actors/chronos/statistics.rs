@@ -307,6 +307,69 @@ impl SchedulerReport {
     }
 }
 
+/// Salud del loop del scheduler. Chronos programa contra `Utc::now()`: un loop bloqueado
+/// o un salto de reloj no dan error, solo retrasan las ejecuciones, así que se mide la
+/// deriva entre el tick esperado y el real y cuántas tareas se dispararon tarde.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoopHealth {
+    pub last_tick: Option<DateTime<Utc>>,
+    pub tick_period_ms: i64,
+    /// Mayor deriva observada entre dos ticks consecutivos
+    pub max_drift_ms: i64,
+    /// Tareas ejecutadas más de `late_threshold_ms` después de su hora programada
+    pub late_executions: u64,
+    pub late_threshold_ms: i64,
+}
+
+impl LoopHealth {
+    pub fn new(tick_period_ms: i64, late_threshold_ms: i64) -> Self {
+        Self {
+            last_tick: None,
+            tick_period_ms,
+            max_drift_ms: 0,
+            late_executions: 0,
+            late_threshold_ms,
+        }
+    }
+
+    /// Retraso respecto al tick esperado (último tick + periodo); 0 si aún no toca
+    pub fn drift_ms(&self, now: DateTime<Utc>) -> i64 {
+        self.last_tick
+            .map(|last| (now - last).num_milliseconds() - self.tick_period_ms)
+            .unwrap_or(0)
+            .max(0)
+    }
+
+    pub fn record_tick(&mut self, now: DateTime<Utc>) {
+        self.max_drift_ms = self.max_drift_ms.max(self.drift_ms(now));
+        self.last_tick = Some(now);
+    }
+
+    /// Registra el disparo de una tarea; devuelve `true` si llegó tarde
+    pub fn record_execution(&mut self, scheduled_at: DateTime<Utc>, fired_at: DateTime<Utc>) -> bool {
+        let late = (fired_at - scheduled_at).num_milliseconds() > self.late_threshold_ms;
+        if late {
+            self.late_executions += 1;
+        }
+        late
+    }
+
+    /// Estado para `/api/chronos/health`; la deriva se calcula en el momento de la
+    /// consulta, de modo que un loop detenido muestra una deriva creciente
+    pub fn report(&self, now: DateTime<Utc>) -> serde_json::Value {
+        serde_json::json!({
+            "time_source": "system_utc",
+            "now": now,
+            "last_tick": self.last_tick,
+            "tick_period_ms": self.tick_period_ms,
+            "drift_ms": self.drift_ms(now),
+            "max_drift_ms": self.max_drift_ms.max(self.drift_ms(now)),
+            "late_executions": self.late_executions,
+            "late_threshold_ms": self.late_threshold_ms,
+        })
+    }
+}
+
 /// Resumen de una tarea para reportes
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskSummary {
@@ -364,6 +427,26 @@ mod tests {
         assert!(metrics.hourly_metrics.contains_key(&hour_key));
     }
 
+    #[test]
+    fn test_loop_health_drift_and_late_executions() {
+        let start = Utc::now();
+        let mut health = LoopHealth::new(1000, 2000);
+        health.record_tick(start);
+
+        // Loop bloqueado: la deriva crece mientras no haya tick
+        assert_eq!(health.drift_ms(start + Duration::milliseconds(900)), 0);
+        assert_eq!(health.drift_ms(start + Duration::seconds(3)), 2000);
+        assert_eq!(health.drift_ms(start + Duration::seconds(6)), 5000);
+
+        let resumed = start + Duration::seconds(6);
+        health.record_tick(resumed);
+        assert_eq!(health.max_drift_ms, 5000);
+
+        assert!(health.record_execution(start + Duration::seconds(1), resumed));
+        assert!(!health.record_execution(resumed - Duration::milliseconds(500), resumed));
+        assert_eq!(health.late_executions, 1);
+    }
+
     #[test]
     fn test_scheduler_report() {
         let mut metrics = SchedulerMetrics::default();
@@ -11,16 +11,52 @@ pub struct TaskDefinition {
     /// Nombre descriptivo de la tarea
     pub name: String,
     /// Tipo de tarea
-    #[serde(rename = "type")]
+    #[serde(rename = "type", default)]
     pub task_type: TaskType,
     /// Expresión cron (opcional para one-shot)
     pub cron_expression: Option<String>,
+    /// Programación alternativa a `type` + `cron_expression`:
+    /// `{"type": "interval", "seconds": 300}` o `{"type": "cron", "expression": "..."}`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<TaskSchedule>,
     /// Payload de la tarea (JSON)
+    #[serde(default)]
     pub payload: serde_json::Value,
     /// Actor creador de la tarea
     pub creator: Option<GodName>,
 }
 
+impl TaskDefinition {
+    /// Traduce `schedule` (si viene) a tipo de tarea y expresión cron
+    pub fn resolve_schedule(mut self) -> Result<Self, String> {
+        match self.schedule.take() {
+            Some(TaskSchedule::Interval { seconds: 0 }) => {
+                return Err("El intervalo debe ser de al menos 1 segundo".to_string());
+            }
+            Some(TaskSchedule::Interval { seconds }) => {
+                self.task_type = TaskType::Interval(seconds);
+                self.cron_expression = None;
+            }
+            Some(TaskSchedule::Cron { expression }) => {
+                self.task_type = TaskType::Recurring;
+                self.cron_expression = Some(expression);
+            }
+            None => {}
+        }
+        Ok(self)
+    }
+}
+
+/// Especificación de programación aceptada por la API
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TaskSchedule {
+    /// Intervalo fijo desde la última ejecución
+    Interval { seconds: u64 },
+    /// Expresión cron de 6 campos
+    Cron { expression: String },
+}
+
 /// Tarea programada completa
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScheduledTask {
@@ -104,10 +140,11 @@ impl ScheduledTask {
 }
 
 /// Tipos de tareas soportadas
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum TaskType {
     /// Tarea única (one-shot)
+    #[default]
     OneShot,
     /// Tarea recurrente con cron
     Recurring,
@@ -146,6 +183,8 @@ pub enum TaskStatus {
     Cancelled,
     /// Pausada temporalmente
     Paused,
+    /// En ejecución cuando Chronos se detuvo y no terminó dentro del plazo de drenaje
+    Interrupted,
 }
 
 /// Resultado de la ejecución de una tarea
@@ -274,6 +313,7 @@ impl TaskDefinitionBuilder {
             name: self.name,
             task_type: self.task_type,
             cron_expression: self.cron_expression,
+            schedule: None,
             payload: self.payload,
             creator: self.creator,
         }
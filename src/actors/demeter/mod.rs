@@ -472,16 +472,10 @@ impl Demeter {
                             data: serde_json::to_value(&snapshot).unwrap_or_default()
                         })
                     }
-                    _ => Err(ActorError::InvalidCommand { 
-                        god: GodName::Demeter, 
-                        reason: format!("Acción '{}' no soportada", action.unwrap_or("unknown")) 
-                    }),
+                    _ => Err(ActorError::unsupported(GodName::Demeter, "command", action.unwrap_or("unknown"))),
                 }
             }
-            _ => Err(ActorError::InvalidCommand { 
-                god: GodName::Demeter, 
-                reason: "Comando no soportado".to_string() 
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Demeter, "command", &other)),
         }
     }
 
@@ -591,16 +585,10 @@ impl Demeter {
                             })
                         })
                     }
-                    _ => Err(ActorError::InvalidQuery { 
-                        god: GodName::Demeter, 
-                        reason: format!("Query type '{}' no soportado", query_type) 
-                    }),
+                    _ => Err(ActorError::unsupported(GodName::Demeter, "query", query_type)),
                 }
             }
-            _ => Err(ActorError::InvalidQuery { 
-                god: GodName::Demeter, 
-                reason: "Query no soportado".to_string() 
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Demeter, "query", &other)),
         }
     }
 }
@@ -236,16 +236,10 @@ impl Dionysus {
                             message: "Caché invalidada".to_string() 
                         })
                     }
-                    _ => Err(ActorError::InvalidCommand { 
-                        god: GodName::Dionysus, 
-                        reason: format!("Acción '{}' no soportada", action.unwrap_or("unknown")) 
-                    }),
+                    _ => Err(ActorError::unsupported(GodName::Dionysus, "command", action.unwrap_or("unknown"))),
                 }
             }
-            _ => Err(ActorError::InvalidCommand { 
-                god: GodName::Dionysus, 
-                reason: "Comando no soportado".to_string() 
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Dionysus, "command", &other)),
         }
     }
 
@@ -368,10 +362,7 @@ impl Dionysus {
                             })
                         })
                     }
-                    _ => Err(ActorError::InvalidQuery { 
-                        god: GodName::Dionysus, 
-                        reason: format!("Query type '{}' no soportado", query_type) 
-                    }),
+                    _ => Err(ActorError::unsupported(GodName::Dionysus, "query", query_type)),
                 };
                 
                 // Guardar en caché si es exitoso
@@ -382,10 +373,7 @@ impl Dionysus {
                 
                 result
             }
-            _ => Err(ActorError::InvalidQuery { 
-                god: GodName::Dionysus, 
-                reason: "Query no soportado".to_string() 
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Dionysus, "query", &other)),
         }
     }
 }
@@ -414,6 +402,7 @@ mod tests {
 
         // Simular evento de actor iniciado
         let event_msg = ActorMessage {
+            version: crate::traits::message::MESSAGE_SCHEMA_VERSION,
             id: "evt1".to_string(),
             from: Some(GodName::Athena),
             to: GodName::Dionysus,
@@ -451,6 +440,7 @@ mod tests {
         // Procesar algunos eventos primero
         for i in 0..5 {
             let event_msg = ActorMessage {
+                version: crate::traits::message::MESSAGE_SCHEMA_VERSION,
                 id: format!("evt{}", i),
                 from: Some(GodName::Athena),
                 to: GodName::Dionysus,
@@ -465,6 +455,7 @@ mod tests {
         // Query de health
         let query = QueryPayload::Custom(json!({"query_type": "system_health"}));
         let query_msg = ActorMessage {
+            version: crate::traits::message::MESSAGE_SCHEMA_VERSION,
             id: "q1".to_string(),
             from: Some(GodName::Zeus),
             to: GodName::Dionysus,
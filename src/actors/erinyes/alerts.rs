@@ -12,6 +12,7 @@ use std::time::{Duration, Instant};
 use tracing::{info, warn, error};
 
 use super::GodName;
+use super::dispatch::{self, ChannelDispatcher, DefaultDispatcher};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Alert {
@@ -30,6 +31,9 @@ pub struct Alert {
     pub resolution_note: Option<String>,
     pub correlation_id: Option<String>,
     pub metadata: serde_json::Value,
+    /// Destinos de la alerta; el log se escribe siempre
+    #[serde(default)]
+    pub channels: Vec<AlertChannel>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -115,10 +119,17 @@ pub struct AlertSystem {
     groups: Arc<RwLock<HashMap<String, AlertGroup>>>,
     alert_tx: mpsc::Sender<Alert>,
     alert_rx: Arc<RwLock<mpsc::Receiver<Alert>>>,
+    dispatcher: Arc<dyn ChannelDispatcher>,
+    /// Canales de las alertas que no provienen de una regla
+    default_channels: Arc<RwLock<Vec<AlertChannel>>>,
 }
 
 impl AlertSystem {
     pub fn new() -> Self {
+        Self::with_dispatcher(Arc::new(DefaultDispatcher::from_env()))
+    }
+    
+    pub fn with_dispatcher(dispatcher: Arc<dyn ChannelDispatcher>) -> Self {
         let (alert_tx, alert_rx) = mpsc::channel(1000);
         
         Self {
@@ -128,13 +139,20 @@ impl AlertSystem {
             groups: Arc::new(RwLock::new(HashMap::new())),
             alert_tx,
             alert_rx: Arc::new(RwLock::new(alert_rx)),
+            dispatcher,
+            default_channels: Arc::new(RwLock::new(vec![AlertChannel::Log])),
         }
     }
     
+    pub async fn set_default_channels(&self, channels: Vec<AlertChannel>) {
+        *self.default_channels.write().await = channels;
+    }
+    
     pub async fn start_processor(&self) {
         let rx = self.alert_rx.clone();
         let alerts = self.alerts.clone();
         let groups = self.groups.clone();
+        let dispatcher = self.dispatcher.clone();
         
         tokio::spawn(async move {
             let mut rx = rx.write().await;
@@ -177,6 +195,8 @@ impl AlertSystem {
                         error!("🚨 CRITICAL Alert: {} - {}", alert.title, alert.message);
                     }
                 }
+                
+                dispatch::dispatch_all(dispatcher.clone(), &alert, &alert.channels);
             }
         });
     }
@@ -208,6 +228,56 @@ impl AlertSystem {
         message: String,
         correlation_id: Option<String>,
         metadata: Option<serde_json::Value>,
+    ) -> String {
+        let channels = self.default_channels.read().await.clone();
+        self.emit(severity, category, source, title, message, correlation_id, metadata, channels).await
+    }
+    
+    /// Dispara la regla `rule_id` con sus propios canales. Devuelve `None` si la
+    /// regla no existe, está desactivada o sigue en cooldown.
+    pub async fn trigger_rule(
+        &self,
+        rule_id: &str,
+        source: GodName,
+        message: String,
+        metadata: Option<serde_json::Value>,
+    ) -> Option<String> {
+        let rule = self.rules.read().await.iter().find(|r| r.id == rule_id && r.enabled).cloned()?;
+        
+        {
+            let mut cooldown = self.cooldown.write().await;
+            let cooldown_key = format!("{}:{:?}", rule.id, source);
+            if let Some(last) = cooldown.get(&cooldown_key) {
+                if last.elapsed() < Duration::from_secs(rule.cooldown_seconds) {
+                    return None;
+                }
+            }
+            cooldown.insert(cooldown_key, Instant::now());
+        }
+        
+        Some(self.emit(
+            rule.severity,
+            rule.category,
+            source,
+            rule.name,
+            message,
+            Some(rule.id),
+            metadata,
+            rule.channels,
+        ).await)
+    }
+    
+    #[allow(clippy::too_many_arguments)]
+    async fn emit(
+        &self,
+        severity: AlertSeverity,
+        category: AlertCategory,
+        source: GodName,
+        title: String,
+        message: String,
+        correlation_id: Option<String>,
+        metadata: Option<serde_json::Value>,
+        channels: Vec<AlertChannel>,
     ) -> String {
         let alert_id = uuid::Uuid::new_v4().to_string();
         
@@ -227,6 +297,7 @@ impl AlertSystem {
             resolution_note: None,
             correlation_id,
             metadata: metadata.unwrap_or_default(),
+            channels,
         };
         
         // Send to processor
@@ -302,8 +373,10 @@ impl AlertSystem {
             .count()
     }
     
+    /// Añade la regla o reemplaza la que tenga el mismo id
     pub async fn add_rule(&self, rule: AlertRule) {
         let mut rules = self.rules.write().await;
+        rules.retain(|r| r.id != rule.id);
         rules.push(rule);
     }
     
@@ -0,0 +1,365 @@
+// src/actors/erinyes/dispatch.rs
+// OLYMPUS v15 - Erinyes Alert Dispatch
+// Entrega de alertas a destinos reales: webhook, email (SMTP), Slack y PagerDuty
+// Solo la usa el Erinyes de este árbol; el servidor de actores (server/) no tiene AlertChannel.
+
+#![allow(dead_code)]
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tracing::{debug, warn};
+
+use super::alerts::{Alert, AlertChannel, AlertSeverity};
+
+/// Tiempo máximo por entrega; un destino lento no retiene a los demás
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+const PAGERDUTY_EVENTS_URL: &str = "https://events.pagerduty.com/v2/enqueue";
+
+/// Servidor SMTP para el canal `Email`. La conexión es SMTP plano, sin STARTTLS ni AUTH:
+/// el mensaje viaja en claro, así que debe apuntar a un relay de confianza en la red
+/// interna (p. ej. el MTA local), que es quien lo entrega con TLS.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub from: String,
+}
+
+impl SmtpConfig {
+    /// `OLYMPUS_SMTP_HOST`, `OLYMPUS_SMTP_PORT` (25) y `OLYMPUS_SMTP_FROM`
+    pub fn from_env() -> Option<Self> {
+        let host = std::env::var("OLYMPUS_SMTP_HOST").ok().filter(|h| !h.is_empty())?;
+        let port = std::env::var("OLYMPUS_SMTP_PORT")
+            .ok()
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(25);
+        let from = std::env::var("OLYMPUS_SMTP_FROM").unwrap_or_else(|_| "erinyes@olympus.local".to_string());
+        Some(Self { host, port, from })
+    }
+}
+
+/// Punto de extensión: entrega una alerta a un canal concreto
+#[async_trait]
+pub trait ChannelDispatcher: Send + Sync + std::fmt::Debug {
+    async fn deliver(&self, channel: &AlertChannel, alert: &Alert) -> Result<(), String>;
+}
+
+/// Despachador por defecto con HTTP (webhook, Slack, PagerDuty) y SMTP
+#[derive(Debug, Clone)]
+pub struct DefaultDispatcher {
+    http: reqwest::Client,
+    smtp: Option<SmtpConfig>,
+}
+
+impl DefaultDispatcher {
+    pub fn new(smtp: Option<SmtpConfig>) -> Self {
+        Self {
+            http: reqwest::Client::builder()
+                .timeout(DELIVERY_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+            smtp,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(SmtpConfig::from_env())
+    }
+
+    async fn post_json(&self, url: &str, headers: &[(&String, &String)], body: serde_json::Value) -> Result<(), String> {
+        let mut request = self.http.post(url).json(&body);
+        for (name, value) in headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        let response = request.send().await.map_err(|e| format!("POST {} falló: {}", url, e))?;
+        if !response.status().is_success() {
+            return Err(format!("POST {} respondió {}", url, response.status()));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ChannelDispatcher for DefaultDispatcher {
+    async fn deliver(&self, channel: &AlertChannel, alert: &Alert) -> Result<(), String> {
+        match channel {
+            // El procesador de alertas ya escribe el log
+            AlertChannel::Log => Ok(()),
+            AlertChannel::Webhook { url, headers } => {
+                let headers: Vec<_> = headers.iter().collect();
+                self.post_json(url, &headers, webhook_payload(alert)).await
+            }
+            AlertChannel::Slack { webhook_url, channel } => {
+                self.post_json(webhook_url, &[], slack_payload(alert, channel)).await
+            }
+            AlertChannel::PagerDuty { service_key } => {
+                self.post_json(PAGERDUTY_EVENTS_URL, &[], pagerduty_payload(alert, service_key)).await
+            }
+            AlertChannel::Email { to } => {
+                let smtp = self.smtp.as_ref().ok_or("canal Email sin configuración SMTP")?;
+                send_email(smtp, to, alert).await
+            }
+            AlertChannel::Notification { .. } => {
+                Err("canal Notification no soportado por el despachador".to_string())
+            }
+        }
+    }
+}
+
+/// Entrega la alerta a todos sus canales en tareas independientes: el fallo o la
+/// lentitud de un canal no bloquea al resto ni al procesador de alertas
+pub fn dispatch_all(dispatcher: Arc<dyn ChannelDispatcher>, alert: &Alert, channels: &[AlertChannel]) {
+    for channel in channels.iter().filter(|c| !matches!(c, AlertChannel::Log)) {
+        let dispatcher = dispatcher.clone();
+        let channel = channel.clone();
+        let alert = alert.clone();
+        tokio::spawn(async move {
+            let name = channel_name(&channel);
+            match tokio::time::timeout(DELIVERY_TIMEOUT, dispatcher.deliver(&channel, &alert)).await {
+                Ok(Ok(())) => debug!("📨 Alert {} entregada por {}", alert.id, name),
+                Ok(Err(e)) => warn!("📨 Alert {} no entregada por {}: {}", alert.id, name, e),
+                Err(_) => warn!("📨 Alert {} no entregada por {}: timeout", alert.id, name),
+            }
+        });
+    }
+}
+
+pub fn channel_name(channel: &AlertChannel) -> &'static str {
+    match channel {
+        AlertChannel::Log => "log",
+        AlertChannel::Webhook { .. } => "webhook",
+        AlertChannel::Email { .. } => "email",
+        AlertChannel::Slack { .. } => "slack",
+        AlertChannel::PagerDuty { .. } => "pagerduty",
+        AlertChannel::Notification { .. } => "notification",
+    }
+}
+
+fn webhook_payload(alert: &Alert) -> serde_json::Value {
+    json!({
+        "id": alert.id,
+        "timestamp": alert.timestamp.to_rfc3339(),
+        "severity": alert.severity,
+        "category": alert.category,
+        "source": alert.source,
+        "title": alert.title,
+        "message": alert.message,
+        "correlation_id": alert.correlation_id,
+        "metadata": alert.metadata,
+    })
+}
+
+fn slack_payload(alert: &Alert, channel: &str) -> serde_json::Value {
+    let icon = match alert.severity {
+        AlertSeverity::Info => ":information_source:",
+        AlertSeverity::Warning => ":warning:",
+        AlertSeverity::Error => ":x:",
+        AlertSeverity::Critical => ":rotating_light:",
+    };
+    json!({
+        "channel": channel,
+        "text": format!("{} *[{:?}] {}* ({:?})\n{}", icon, alert.severity, alert.title, alert.source, alert.message),
+    })
+}
+
+fn pagerduty_payload(alert: &Alert, service_key: &str) -> serde_json::Value {
+    let severity = match alert.severity {
+        AlertSeverity::Info => "info",
+        AlertSeverity::Warning => "warning",
+        AlertSeverity::Error => "error",
+        AlertSeverity::Critical => "critical",
+    };
+    json!({
+        "routing_key": service_key,
+        "event_action": "trigger",
+        "dedup_key": alert.correlation_id.clone().unwrap_or_else(|| alert.id.clone()),
+        "payload": {
+            "summary": format!("{}: {}", alert.title, alert.message),
+            "source": format!("{:?}", alert.source),
+            "severity": severity,
+        },
+    })
+}
+
+/// Texto de cabecera seguro: sin CR/LF ni otros controles (no se pueden inyectar
+/// cabeceras) y, si no es ASCII, codificado como encoded-words de RFC 2047
+fn header_text(value: &str) -> String {
+    let clean: String = value
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect();
+    if clean.is_ascii() {
+        return clean;
+    }
+
+    // Cada encoded-word cabe en 75 caracteres: 45 bytes de UTF-8 dan 60 de base64
+    const MAX_CHUNK_BYTES: usize = 45;
+    let mut words = Vec::new();
+    let mut chunk = String::new();
+    for c in clean.chars() {
+        if chunk.len() + c.len_utf8() > MAX_CHUNK_BYTES {
+            words.push(format!("=?utf-8?B?{}?=", BASE64.encode(&chunk)));
+            chunk.clear();
+        }
+        chunk.push(c);
+    }
+    if !chunk.is_empty() {
+        words.push(format!("=?utf-8?B?{}?=", BASE64.encode(&chunk)));
+    }
+    words.join("\r\n ")
+}
+
+/// Dirección para `MAIL FROM`/`RCPT TO` y las cabeceras: sin espacios, controles ni `<>`
+fn smtp_address(address: &str) -> Result<&str, String> {
+    let address = address.trim();
+    let valid = address.contains('@')
+        && !address.chars().any(|c| c.is_control() || c.is_whitespace() || c == '<' || c == '>');
+    if valid {
+        Ok(address)
+    } else {
+        Err(format!("dirección de correo no válida: {:?}", address))
+    }
+}
+
+fn email_message(from: &str, to: &[String], alert: &Alert) -> String {
+    // Las líneas que empiezan por "." se duplican (dot-stuffing, RFC 5321)
+    let body = alert
+        .message
+        .lines()
+        .map(|line| if line.starts_with('.') { format!(".{}", line) } else { line.to_string() })
+        .collect::<Vec<_>>()
+        .join("\r\n");
+    format!(
+        "From: {}\r\nTo: {}\r\nDate: {}\r\nSubject: {}\r\nMIME-Version: 1.0\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Transfer-Encoding: 8bit\r\n\r\nOrigen: {:?}\r\nFecha: {}\r\n\r\n{}\r\n.\r\n",
+        from,
+        to.join(", "),
+        alert.timestamp.to_rfc2822(),
+        header_text(&format!("[OLYMPUS {:?}] {}", alert.severity, alert.title)),
+        alert.source,
+        alert.timestamp.to_rfc3339(),
+        body,
+    )
+}
+
+async fn send_email(smtp: &SmtpConfig, to: &[String], alert: &Alert) -> Result<(), String> {
+    if to.is_empty() {
+        return Err("canal Email sin destinatarios".to_string());
+    }
+    let from = smtp_address(&smtp.from)?.to_string();
+    let to = to
+        .iter()
+        .map(|recipient| smtp_address(recipient).map(str::to_string))
+        .collect::<Result<Vec<_>, _>>()?;
+    let stream = TcpStream::connect((smtp.host.as_str(), smtp.port))
+        .await
+        .map_err(|e| format!("SMTP {}:{} inaccesible: {}", smtp.host, smtp.port, e))?;
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+
+    smtp_expect(&mut reader, 220).await?;
+    smtp_command(&mut writer, &mut reader, "HELO olympus\r\n", 250).await?;
+    smtp_command(&mut writer, &mut reader, &format!("MAIL FROM:<{}>\r\n", from), 250).await?;
+    for recipient in &to {
+        smtp_command(&mut writer, &mut reader, &format!("RCPT TO:<{}>\r\n", recipient), 250).await?;
+    }
+    smtp_command(&mut writer, &mut reader, "DATA\r\n", 354).await?;
+    smtp_command(&mut writer, &mut reader, &email_message(&from, &to, alert), 250).await?;
+    let _ = writer.write_all(b"QUIT\r\n").await;
+    Ok(())
+}
+
+async fn smtp_command(
+    writer: &mut tokio::net::tcp::OwnedWriteHalf,
+    reader: &mut BufReader<tokio::net::tcp::OwnedReadHalf>,
+    command: &str,
+    expected: u16,
+) -> Result<(), String> {
+    writer.write_all(command.as_bytes()).await.map_err(|e| format!("SMTP: {}", e))?;
+    smtp_expect(reader, expected).await
+}
+
+/// Lee una respuesta (posiblemente multilínea) y comprueba su código
+async fn smtp_expect(reader: &mut BufReader<tokio::net::tcp::OwnedReadHalf>, expected: u16) -> Result<(), String> {
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).await.map_err(|e| format!("SMTP: {}", e))? == 0 {
+            return Err("SMTP: conexión cerrada".to_string());
+        }
+        let code: u16 = line.get(..3).and_then(|c| c.parse().ok()).unwrap_or(0);
+        if code != expected {
+            return Err(format!("SMTP: esperado {}, recibido '{}'", expected, line.trim_end()));
+        }
+        // "250-..." indica que siguen más líneas
+        if line.as_bytes().get(3) != Some(&b'-') {
+            return Ok(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actors::GodName;
+    use crate::actors::erinyes::alerts::AlertCategory;
+
+    fn alert() -> Alert {
+        Alert {
+            id: "a-1".to_string(),
+            timestamp: chrono::Utc::now(),
+            severity: AlertSeverity::Critical,
+            category: AlertCategory::Health,
+            source: GodName::Zeus,
+            title: "Zeus caído".to_string(),
+            message: "sin heartbeat\n.fin".to_string(),
+            acknowledged: false,
+            acknowledged_by: None,
+            acknowledged_at: None,
+            resolved: false,
+            resolved_at: None,
+            resolution_note: None,
+            correlation_id: None,
+            metadata: serde_json::Value::Null,
+            channels: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_payloads() {
+        let alert = alert();
+        let webhook = webhook_payload(&alert);
+        assert_eq!(webhook["severity"], "Critical");
+        assert_eq!(webhook["title"], "Zeus caído");
+
+        assert_eq!(pagerduty_payload(&alert, "key")["dedup_key"], "a-1");
+
+        let message = email_message("erinyes@olympus.local", &["uci@hospital.org".to_string()], &alert);
+        assert!(message.contains(&format!("Subject: =?utf-8?B?{}?=\r\n", BASE64.encode("[OLYMPUS Critical] Zeus caído"))));
+        assert!(message.contains("\r\n..fin\r\n"));
+        assert!(message.ends_with("\r\n.\r\n"));
+    }
+
+    #[test]
+    fn test_headers_cannot_be_injected() {
+        let mut alert = alert();
+        alert.title = "Zeus\r\nBcc: todos@hospital.org".to_string();
+        let message = email_message("erinyes@olympus.local", &["uci@hospital.org".to_string()], &alert);
+        let headers = message.split("\r\n\r\n").next().unwrap();
+        assert!(headers.lines().all(|line| !line.starts_with("Bcc:")));
+        assert!(headers.contains("Subject: [OLYMPUS Critical] Zeus  Bcc: todos@hospital.org"));
+
+        // Los títulos largos se parten en varias encoded-words de 75 caracteres como máximo
+        let long = header_text(&"ñ".repeat(60));
+        assert!(long.split("\r\n ").all(|word| word.len() <= 75 && word.starts_with("=?utf-8?B?")));
+
+        assert!(smtp_address("uci@hospital.org").is_ok());
+        assert!(smtp_address("uci@hospital.org>\r\nRCPT TO:<x@y").is_err());
+        assert!(smtp_address("sin-arroba").is_err());
+    }
+}
@@ -15,11 +15,22 @@ use crate::traits::message::RecoveryStrategy;
 use crate::traits::actor_trait::ActorStatus;
 use crate::actors::erinyes::alerts::{AlertSeverity, AlertSystem};
 
+/// Margen por defecto para el primer heartbeat de un actor recién arrancado
+pub const DEFAULT_STARTUP_GRACE_MS: u64 = 5_000;
+
+fn default_startup_grace_ms() -> u64 {
+    DEFAULT_STARTUP_GRACE_MS
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HeartbeatConfig {
     pub interval_ms: u64,
     pub timeout_ms: u64,
     pub strategy: RecoveryStrategy,
+    /// Tras el registro, los heartbeats perdidos no cuentan hasta que llegue el primero
+    /// o pase este margen; evita recuperar (y reiniciar en bucle) actores que arrancan
+    #[serde(default = "default_startup_grace_ms")]
+    pub startup_grace_ms: u64,
 }
 
 impl Default for HeartbeatConfig {
@@ -28,6 +39,7 @@ impl Default for HeartbeatConfig {
             interval_ms: 1000,
             timeout_ms: 1500,
             strategy: RecoveryStrategy::OneForOne,
+            startup_grace_ms: DEFAULT_STARTUP_GRACE_MS,
         }
     }
 }
@@ -43,6 +55,7 @@ pub struct HeartbeatState {
     pub history: VecDeque<HeartbeatRecord>,
     pub total_heartbeats: u64,
     pub missed_heartbeats: u64,
+    pub registered_at: chrono::DateTime<chrono::Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -54,9 +67,10 @@ pub struct HeartbeatRecord {
 
 impl HeartbeatState {
     pub fn new(actor: GodName, config: HeartbeatConfig) -> Self {
+        let now = chrono::Utc::now();
         Self {
             actor,
-            last_seen: chrono::Utc::now(),
+            last_seen: now,
             status: ActorStatus::Healthy,
             consecutive_misses: 0,
             consecutive_successes: 0,
@@ -64,11 +78,25 @@ impl HeartbeatState {
             history: VecDeque::with_capacity(100),
             total_heartbeats: 0,
             missed_heartbeats: 0,
+            registered_at: now,
         }
     }
     
+    /// Sin ningún heartbeat todavía y dentro del margen de arranque
+    pub fn in_startup_grace(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.total_heartbeats == 0
+            && (now - self.registered_at).num_milliseconds() < self.config.startup_grace_ms as i64
+    }
+    
     pub fn is_timed_out(&self) -> bool {
-        let elapsed = (chrono::Utc::now() - self.last_seen).num_milliseconds() as u64;
+        self.is_timed_out_at(chrono::Utc::now())
+    }
+    
+    pub fn is_timed_out_at(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        if self.in_startup_grace(now) {
+            return false;
+        }
+        let elapsed = (now - self.last_seen).num_milliseconds() as u64;
         elapsed > self.config.timeout_ms
     }
     
@@ -169,9 +197,14 @@ pub struct HeartbeatMonitor {
 
 impl HeartbeatMonitor {
     pub fn new(alert_system: Arc<AlertSystem>) -> Self {
+        Self::with_default_config(alert_system, HeartbeatConfig::default())
+    }
+    
+    /// Configuración para actores registrados sin una propia (incluido el auto-registro)
+    pub fn with_default_config(alert_system: Arc<AlertSystem>, default_config: HeartbeatConfig) -> Self {
         Self {
             actors: Arc::new(RwLock::new(HashMap::new())),
-            default_config: HeartbeatConfig::default(),
+            default_config,
             alert_system,
         }
     }
@@ -388,3 +421,29 @@ impl HeartbeatStats {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_startup_grace_delays_missed_heartbeats() {
+        let config = HeartbeatConfig { startup_grace_ms: 5_000, ..HeartbeatConfig::default() };
+
+        // Primer heartbeat a los 2 s: dentro del margen no cuenta como perdido
+        let mut slow_start = HeartbeatState::new(GodName::Hermes, config.clone());
+        let registered = slow_start.registered_at;
+        assert!(!slow_start.is_timed_out_at(registered + Duration::seconds(2)));
+        slow_start.mark_received(0);
+        assert!(!slow_start.in_startup_grace(registered + Duration::seconds(3)));
+
+        // Sin ningún heartbeat, el timeout normal aplica al agotarse el margen
+        let silent = HeartbeatState::new(GodName::Iris, config);
+        assert!(!silent.is_timed_out_at(silent.registered_at + Duration::seconds(4)));
+        assert!(silent.is_timed_out_at(silent.registered_at + Duration::seconds(6)));
+
+        let no_grace = HeartbeatState::new(GodName::Chaos, HeartbeatConfig { startup_grace_ms: 0, ..HeartbeatConfig::default() });
+        assert!(no_grace.is_timed_out_at(no_grace.registered_at + Duration::seconds(2)));
+    }
+}
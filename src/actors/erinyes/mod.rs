@@ -22,12 +22,14 @@ pub mod recovery;
 pub mod dead_letter;
 pub mod watchdog;
 pub mod alerts;
+pub mod dispatch;
 
 pub use heartbeat::{HeartbeatMonitor, HeartbeatState, HeartbeatConfig};
 pub use recovery::{RecoveryEngine, RecoveryUrgency};
 pub use dead_letter::{DeadLetterQueue};
-pub use watchdog::{Watchdog, WatchdogEventType, WatchdogSeverity, SystemStatus};
-pub use alerts::{AlertSystem, AlertSeverity, AlertChannel};
+pub use watchdog::{Watchdog, WatchdogEventType, WatchdogSeverity, SystemStatus, HealthThresholds};
+pub use alerts::{AlertSystem, AlertSeverity, AlertChannel, AlertRule};
+pub use dispatch::{ChannelDispatcher, DefaultDispatcher, SmtpConfig};
 
 /// Erinyes: La Guardiana de la Integridad
 /// Vigila la Trinidad Suprema (Zeus, Hades, Poseidón) y todos los actores
@@ -51,6 +53,7 @@ pub struct Erinyes {
     monitoring_interval_ms: u64,
     auto_recovery_enabled: bool,
     escalation_enabled: bool,
+    startup_grace_ms: u64,
     
     // Valkey for persistence
     valkey: Arc<ValkeyStore>,
@@ -74,11 +77,16 @@ pub enum ErinyesCommand {
     GetSystemHealth,
     GetActorHealth { actor: GodName },
     GetRecoveryHistory { actor: Option<GodName>, limit: usize },
+    ListRecoveries,
+    CancelRecovery { recovery_id: String },
     GetDeadLetters,
     RetryDeadLetter { letter_id: String },
     PurgeOldData { max_age_seconds: u64 },
     ConfigureMonitoring { interval_ms: u64 },
     SetTrinityPriority { actor: GodName, is_trinity: bool },
+    ConfigureHealthThresholds { thresholds: HealthThresholds },
+    ConfigureAlertRule { rule: AlertRule },
+    SetAlertChannels { channels: Vec<AlertChannel> },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -132,6 +140,7 @@ impl Erinyes {
             monitoring_interval_ms: 500,
             auto_recovery_enabled: true,
             escalation_enabled: true,
+            startup_grace_ms: heartbeat::DEFAULT_STARTUP_GRACE_MS,
             
             valkey,
             command_tx,
@@ -148,10 +157,19 @@ impl Erinyes {
         let (command_tx, command_rx) = mpsc::channel(100);
         
         let alert_system = Arc::new(AlertSystem::new());
-        let heartbeat_monitor = Arc::new(HeartbeatMonitor::new(alert_system.clone()));
+        let heartbeat_monitor = Arc::new(HeartbeatMonitor::with_default_config(
+            alert_system.clone(),
+            HeartbeatConfig { startup_grace_ms: config.startup_grace_ms, ..HeartbeatConfig::default() },
+        ));
         let recovery_engine = Arc::new(RecoveryEngine::new(alert_system.clone()));
-        let watchdog = Arc::new(Watchdog::new());
+        // La Trinidad configurada manda sobre la de los umbrales
+        let thresholds = HealthThresholds {
+            trinity: config.trinity_members.clone(),
+            ..config.health_thresholds.clone()
+        };
+        let watchdog = Arc::new(Watchdog::with_thresholds(thresholds));
         let dead_letter_queue = Arc::new(DeadLetterQueue::new(valkey.clone()));
+        alert_system.set_default_channels(config.alert_channels.clone()).await;
         
         // Start alert processor
         alert_system.start_processor().await;
@@ -171,6 +189,7 @@ impl Erinyes {
             monitoring_interval_ms: config.monitoring_interval_ms,
             auto_recovery_enabled: config.auto_recovery_enabled,
             escalation_enabled: config.escalation_enabled,
+            startup_grace_ms: config.startup_grace_ms,
             
             valkey,
             command_tx,
@@ -192,6 +211,7 @@ impl Erinyes {
                 interval_ms: 250,  // More frequent (every 250ms)
                 timeout_ms: 500,   // Shorter timeout
                 strategy: RecoveryStrategy::OneForOne,
+                startup_grace_ms: self.startup_grace_ms,
             };
             
             let _ = self.heartbeat_monitor.register(god.clone(), Some(config)).await;
@@ -303,6 +323,21 @@ pub struct ErinyesConfig {
     pub monitoring_interval_ms: u64,
     pub auto_recovery_enabled: bool,
     pub escalation_enabled: bool,
+    pub health_thresholds: HealthThresholds,
+    /// Canales de las alertas que no provienen de una regla
+    pub alert_channels: Vec<AlertChannel>,
+    /// Margen de arranque antes de contar heartbeats perdidos
+    pub startup_grace_ms: u64,
+}
+
+impl ErinyesConfig {
+    /// Perfil de desarrollo: solo la caída de la Trinidad es crítica
+    pub fn development() -> Self {
+        Self {
+            health_thresholds: HealthThresholds::relaxed(),
+            ..Self::default()
+        }
+    }
 }
 
 impl Default for ErinyesConfig {
@@ -316,6 +351,9 @@ impl Default for ErinyesConfig {
             monitoring_interval_ms: 500,
             auto_recovery_enabled: true,
             escalation_enabled: true,
+            health_thresholds: HealthThresholds::default(),
+            alert_channels: vec![AlertChannel::Log],
+            startup_grace_ms: heartbeat::DEFAULT_STARTUP_GRACE_MS,
         }
     }
 }
@@ -459,10 +497,7 @@ impl Erinyes {
                     })
                 }
             }
-            _ => Err(ActorError::InvalidCommand { 
-                god: GodName::Erinyes, 
-                reason: "Command not supported by Erinyes".to_string() 
-            })
+            other => Err(ActorError::unsupported_variant(GodName::Erinyes, "command", &other))
         }
     }
     
@@ -537,10 +572,13 @@ impl Erinyes {
                                 data: serde_json::to_value(history).unwrap_or_default() 
                             })
                         }
-                        _ => Err(ActorError::InvalidQuery { 
-                            god: GodName::Erinyes, 
-                            reason: format!("Unknown query type: {}", query_type) 
-                        })
+                        "active_recoveries" => {
+                            let recoveries = self.recovery_engine.list_active().await;
+                            Ok(ResponsePayload::Data { 
+                                data: serde_json::to_value(recoveries).unwrap_or_default() 
+                            })
+                        }
+                        _ => Err(ActorError::unsupported(GodName::Erinyes, "query", query_type))
                     }
                 } else {
                     Err(ActorError::InvalidQuery { 
@@ -549,10 +587,7 @@ impl Erinyes {
                     })
                 }
             }
-            _ => Err(ActorError::InvalidQuery { 
-                god: GodName::Erinyes, 
-                reason: "Unsupported query type".to_string() 
-            })
+            other => Err(ActorError::unsupported_variant(GodName::Erinyes, "query", &other)),
         }
     }
     
@@ -661,6 +696,20 @@ impl Erinyes {
                     data: serde_json::to_value(history).unwrap_or_default() 
                 })
             }
+            ErinyesCommand::ListRecoveries => {
+                let recoveries = self.recovery_engine.list_active().await;
+                Ok(ResponsePayload::Data { 
+                    data: serde_json::to_value(recoveries).unwrap_or_default() 
+                })
+            }
+            ErinyesCommand::CancelRecovery { recovery_id } => {
+                let cancelled = self.recovery_engine.cancel_recovery(&recovery_id).await
+                    .map_err(|e| ActorError::Unknown { god: GodName::Erinyes, message: e })?;
+                
+                Ok(ResponsePayload::Success { 
+                    message: format!("Recovery {} for {:?} cancelled", recovery_id, cancelled.actor) 
+                })
+            }
             ErinyesCommand::PurgeOldData { max_age_seconds } => {
                 let max_age = Duration::from_secs(max_age_seconds);
                 self.watchdog.clear_old_records(chrono::Duration::from_std(max_age).unwrap_or(chrono::Duration::zero())).await;
@@ -670,6 +719,43 @@ impl Erinyes {
                     message: "Old data purged".to_string() 
                 })
             }
+            ErinyesCommand::SetTrinityPriority { actor, is_trinity } => {
+                let mut trinity = self.trinity_members.write().await;
+                trinity.retain(|g| *g != actor);
+                if is_trinity {
+                    trinity.push(actor);
+                }
+                drop(trinity);
+                self.watchdog.set_trinity_member(actor, is_trinity).await;
+                
+                Ok(ResponsePayload::Success { 
+                    message: format!("{:?} Trinity priority: {}", actor, is_trinity) 
+                })
+            }
+            ErinyesCommand::ConfigureHealthThresholds { mut thresholds } => {
+                // La Trinidad se gestiona con SetTrinityPriority
+                thresholds.trinity = self.trinity_members.read().await.clone();
+                self.watchdog.set_thresholds(thresholds).await;
+                
+                Ok(ResponsePayload::Success { 
+                    message: "Health thresholds updated".to_string() 
+                })
+            }
+            ErinyesCommand::ConfigureAlertRule { rule } => {
+                let rule_id = rule.id.clone();
+                self.alert_system.add_rule(rule).await;
+                
+                Ok(ResponsePayload::Success { 
+                    message: format!("Alert rule {} configured", rule_id) 
+                })
+            }
+            ErinyesCommand::SetAlertChannels { channels } => {
+                self.alert_system.set_default_channels(channels).await;
+                
+                Ok(ResponsePayload::Success { 
+                    message: "Default alert channels updated".to_string() 
+                })
+            }
             _ => Err(ActorError::InvalidCommand { 
                 god: GodName::Erinyes, 
                 reason: "Command not yet implemented".to_string() 
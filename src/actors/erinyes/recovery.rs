@@ -5,7 +5,7 @@
 #![allow(dead_code)]
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Instant;
 use tokio::sync::{RwLock, mpsc};
@@ -95,13 +95,35 @@ pub struct RecoveryEngine {
     alert_system: Arc<AlertSystem>,
     recovery_tx: mpsc::Sender<RecoveryRequest>,
     recovery_rx: Arc<RwLock<mpsc::Receiver<RecoveryRequest>>>,
+    // Recuperaciones en cola o en curso, y las canceladas por un operador
+    active: Arc<RwLock<HashMap<String, ActiveRecovery>>>,
+    cancelled: Arc<RwLock<HashSet<String>>>,
 }
 
 #[derive(Debug, Clone)]
 pub struct RecoveryRequest {
+    pub id: String,
+    pub actor: GodName,
+    pub strategy: RecoveryStrategy,
+    pub urgency: RecoveryUrgency,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RecoveryStatus {
+    Queued,
+    Running,
+}
+
+/// Recuperación aún no finalizada, visible para operadores
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveRecovery {
+    pub id: String,
     pub actor: GodName,
     pub strategy: RecoveryStrategy,
     pub urgency: RecoveryUrgency,
+    pub status: RecoveryStatus,
+    pub attempts: u32,
+    pub requested_at: chrono::DateTime<chrono::Utc>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -124,6 +146,8 @@ impl RecoveryEngine {
             alert_system,
             recovery_tx,
             recovery_rx: Arc::new(RwLock::new(recovery_rx)),
+            active: Arc::new(RwLock::new(HashMap::new())),
+            cancelled: Arc::new(RwLock::new(HashSet::new())),
         }
     }
     
@@ -138,6 +162,8 @@ impl RecoveryEngine {
             alert_system,
             recovery_tx,
             recovery_rx: Arc::new(RwLock::new(recovery_rx)),
+            active: Arc::new(RwLock::new(HashMap::new())),
+            cancelled: Arc::new(RwLock::new(HashSet::new())),
         }
     }
     
@@ -148,6 +174,8 @@ impl RecoveryEngine {
         let circuit_breaker = self.circuit_breaker.clone();
         let alert_system = self.alert_system.clone();
         let config = self.config.clone();
+        let active = self.active.clone();
+        let cancelled = self.cancelled.clone();
         
         tokio::spawn(async move {
             let mut rx = rx.write().await;
@@ -156,11 +184,22 @@ impl RecoveryEngine {
                 let actor = request.actor;
                 let strategy = request.strategy;
                 
+                // Cancelada mientras estaba en cola: no se ejecuta
+                if cancelled.write().await.remove(&request.id) {
+                    info!("🛑 Recovery {} for {:?} was cancelled, skipping", request.id, actor);
+                    continue;
+                }
+                
+                if let Some(entry) = active.write().await.get_mut(&request.id) {
+                    entry.status = RecoveryStatus::Running;
+                }
+                
                 // Check circuit breaker
                 if config.enable_circuit_breaker {
                     let cb = circuit_breaker.read().await;
                     if cb.get(&actor).copied().unwrap_or(false) {
                         warn!("Circuit breaker open for {:?}, skipping recovery", actor);
+                        active.write().await.remove(&request.id);
                         continue;
                     }
                 }
@@ -193,6 +232,13 @@ impl RecoveryEngine {
                         
                         warn!("❌ Recovery failed for {:?}: {}", actor, error_msg);
                         
+                        // Cancelada durante la ejecución: no consume intentos
+                        if cancelled.write().await.remove(&request.id) {
+                            active.write().await.remove(&request.id);
+                            records.write().await.push(record);
+                            continue;
+                        }
+                        
                         // Update restart counter
                         let mut counters = counters.write().await;
                         let now = Instant::now();
@@ -224,6 +270,9 @@ impl RecoveryEngine {
                     }
                 }
                 
+                active.write().await.remove(&request.id);
+                cancelled.write().await.remove(&request.id);
+                
                 // Store record
                 let mut records = records.write().await;
                 records.push(record);
@@ -236,7 +285,7 @@ impl RecoveryEngine {
         });
     }
     
-    pub async fn request_recovery(&self, actor: GodName, strategy: RecoveryStrategy, urgency: RecoveryUrgency) -> Result<(), ActorError> {
+    pub async fn request_recovery(&self, actor: GodName, strategy: RecoveryStrategy, urgency: RecoveryUrgency) -> Result<String, ActorError> {
         // Check if should escalate to Zeus
         if self.should_escalate(&actor).await {
             return Err(ActorError::RecoveryFailed {
@@ -246,17 +295,53 @@ impl RecoveryEngine {
             });
         }
         
-        let request = RecoveryRequest { actor, strategy, urgency };
+        let id = uuid::Uuid::new_v4().to_string();
+        self.active.write().await.insert(id.clone(), ActiveRecovery {
+            id: id.clone(),
+            actor,
+            strategy: strategy.clone(),
+            urgency: urgency.clone(),
+            status: RecoveryStatus::Queued,
+            attempts: self.get_restart_count(&actor).await,
+            requested_at: chrono::Utc::now(),
+        });
         
-        self.recovery_tx
-            .send(request)
-            .await
-            .map_err(|_| ActorError::Unknown { 
+        let request = RecoveryRequest { id: id.clone(), actor, strategy, urgency };
+        
+        if self.recovery_tx.send(request).await.is_err() {
+            self.active.write().await.remove(&id);
+            return Err(ActorError::Unknown { 
                 god: GodName::Erinyes, 
                 message: "Failed to queue recovery request".to_string() 
-            })?;
+            });
+        }
+        
+        Ok(id)
+    }
+    
+    /// Recuperaciones en cola o en curso, con su urgencia y los intentos acumulados
+    pub async fn list_active(&self) -> Vec<ActiveRecovery> {
+        let counters = self.restart_counters.read().await;
+        let mut list: Vec<ActiveRecovery> = self.active.read().await
+            .values()
+            .cloned()
+            .map(|mut r| {
+                r.attempts = counters.get(&r.actor).map(|w| w.count).unwrap_or(0);
+                r
+            })
+            .collect();
+        list.sort_by_key(|r| r.requested_at);
+        list
+    }
+    
+    /// Cancela una recuperación; si ya está en curso, su resultado no cuenta como intento
+    pub async fn cancel_recovery(&self, id: &str) -> Result<ActiveRecovery, String> {
+        let removed = self.active.write().await.remove(id)
+            .ok_or_else(|| format!("Recovery {} not found or already finished", id))?;
         
-        Ok(())
+        self.cancelled.write().await.insert(id.to_string());
+        info!("🛑 Recovery {} for {:?} cancelled", id, removed.actor);
+        Ok(removed)
     }
     
     pub async fn trigger_recovery(&self, actor: GodName, strategy: RecoveryStrategy) {
@@ -369,3 +454,32 @@ pub struct RecoveryStats {
     pub circuit_breakers_open: usize,
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_cancelled_recovery_does_not_consume_attempts() {
+        let engine = RecoveryEngine::new(Arc::new(AlertSystem::new()));
+
+        let id = engine
+            .request_recovery(GodName::Hermes, RecoveryStrategy::OneForOne, RecoveryUrgency::High)
+            .await
+            .unwrap();
+        assert_eq!(engine.list_active().await.len(), 1);
+
+        engine.cancel_recovery(&id).await.unwrap();
+        assert!(engine.list_active().await.is_empty());
+        assert!(engine.cancel_recovery(&id).await.is_err());
+
+        // El worker descarta la petición cancelada sin invocar la recuperación
+        engine
+            .start_recovery_worker(|_| Box::pin(async { Err(ActorError::Unknown { god: GodName::Hermes, message: "stuck".to_string() }) }))
+            .await;
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        assert_eq!(engine.get_restart_count(&GodName::Hermes).await, 0);
+        assert!(engine.get_recovery_history(None, 10).await.is_empty());
+    }
+}
@@ -81,8 +81,44 @@ pub struct ActorActivity {
     pub cpu_usage_percent: f64,
 }
 
+/// Umbrales para escalar el estado del sistema; ajustables por despliegue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthThresholds {
+    /// Dioses cuya muerte siempre vuelve crítico el sistema
+    pub trinity: Vec<GodName>,
+    /// Muertes fuera de la Trinidad toleradas antes de escalar (None = sin límite)
+    pub max_non_trinity_deaths: Option<usize>,
+    /// Errores recientes a partir de los cuales el sistema es crítico (None = no escala por errores)
+    pub critical_errors: Option<usize>,
+    /// Errores recientes a partir de los cuales el sistema está degradado
+    pub degraded_errors: usize,
+}
+
+impl Default for HealthThresholds {
+    fn default() -> Self {
+        Self {
+            trinity: vec![GodName::Zeus, GodName::Hades, GodName::Poseidon],
+            max_non_trinity_deaths: Some(0),
+            critical_errors: Some(10),
+            degraded_errors: 5,
+        }
+    }
+}
+
+impl HealthThresholds {
+    /// Perfil de desarrollo: los dioses ausentes fuera de la Trinidad solo degradan
+    pub fn relaxed() -> Self {
+        Self {
+            max_non_trinity_deaths: None,
+            critical_errors: None,
+            ..Self::default()
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Watchdog {
+    thresholds: Arc<RwLock<HealthThresholds>>,
     events: Arc<RwLock<Vec<WatchdogEvent>>>,
     death_records: Arc<RwLock<HashMap<GodName, DeathRecord>>>,
     activity_log: Arc<RwLock<HashMap<GodName, VecDeque<ActorActivity>>>>,
@@ -92,6 +128,7 @@ pub struct Watchdog {
 impl std::fmt::Debug for Watchdog {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Watchdog")
+            .field("thresholds", &"Arc<RwLock<HealthThresholds>>")
             .field("events", &"Arc<RwLock<Vec<WatchdogEvent>>>")
             .field("death_records", &"Arc<RwLock<HashMap<GodName, DeathRecord>>>")
             .field("activity_log", &"Arc<RwLock<HashMap<GodName, VecDeque<ActorActivity>>>>")
@@ -101,7 +138,12 @@ impl std::fmt::Debug for Watchdog {
 
 impl Watchdog {
     pub fn new() -> Self {
+        Self::with_thresholds(HealthThresholds::default())
+    }
+
+    pub fn with_thresholds(thresholds: HealthThresholds) -> Self {
         Self {
+            thresholds: Arc::new(RwLock::new(thresholds)),
             events: Arc::new(RwLock::new(Vec::new())),
             death_records: Arc::new(RwLock::new(HashMap::new())),
             activity_log: Arc::new(RwLock::new(HashMap::new())),
@@ -109,6 +151,23 @@ impl Watchdog {
         }
     }
     
+    pub async fn thresholds(&self) -> HealthThresholds {
+        self.thresholds.read().await.clone()
+    }
+
+    pub async fn set_thresholds(&self, thresholds: HealthThresholds) {
+        *self.thresholds.write().await = thresholds;
+    }
+
+    /// Agrega o quita un dios de la Trinidad para la evaluación de salud
+    pub async fn set_trinity_member(&self, actor: GodName, is_trinity: bool) {
+        let mut thresholds = self.thresholds.write().await;
+        thresholds.trinity.retain(|g| *g != actor);
+        if is_trinity {
+            thresholds.trinity.push(actor);
+        }
+    }
+
     pub async fn report_event(
         &self,
         event_type: WatchdogEventType,
@@ -247,7 +306,14 @@ impl Watchdog {
         let events = self.events.read().await;
         let log = self.activity_log.read().await;
         
+        let thresholds = self.thresholds.read().await;
+        
         let active_deaths = records.values().filter(|r| !r.was_recovered).count();
+        let trinity_deaths = records
+            .values()
+            .filter(|r| !r.was_recovered && thresholds.trinity.contains(&r.actor))
+            .count();
+        let non_trinity_deaths = active_deaths - trinity_deaths;
         let recent_errors = events
             .iter()
             .rev()
@@ -264,9 +330,16 @@ impl Watchdog {
             }
         }
         
-        let status = if active_deaths > 0 || recent_errors > 10 {
+        let deaths_exceeded = thresholds
+            .max_non_trinity_deaths
+            .is_some_and(|max| non_trinity_deaths > max);
+        let errors_exceeded = thresholds
+            .critical_errors
+            .is_some_and(|max| recent_errors > max);
+        
+        let status = if trinity_deaths > 0 || deaths_exceeded || errors_exceeded {
             SystemStatus::Critical
-        } else if recent_errors > 5 {
+        } else if active_deaths > 0 || recent_errors > thresholds.degraded_errors {
             SystemStatus::Degraded
         } else {
             SystemStatus::Healthy
@@ -275,6 +348,7 @@ impl Watchdog {
         SystemHealth {
             status,
             active_deaths,
+            trinity_deaths,
             recent_errors,
             high_memory_actors,
             total_events_24h: events.len(), // Simplified, should filter by time
@@ -414,6 +488,8 @@ impl Watchdog {
 pub struct SystemHealth {
     pub status: SystemStatus,
     pub active_deaths: usize,
+    #[serde(default)]
+    pub trinity_deaths: usize,
     pub recent_errors: usize,
     pub high_memory_actors: Vec<(GodName, f64)>,
     pub total_events_24h: usize,
@@ -441,3 +517,29 @@ pub enum ExportFormat {
     Json,
     Csv,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_relaxed_profile_only_escalates_trinity_deaths() {
+        let watchdog = Watchdog::with_thresholds(HealthThresholds::relaxed());
+
+        watchdog.report_death(GodName::Chaos, "no iniciado".to_string()).await;
+        watchdog.report_death(GodName::Iris, "no iniciado".to_string()).await;
+        assert_eq!(watchdog.check_system_health().await.status, SystemStatus::Degraded);
+
+        watchdog.report_death(GodName::Hades, "panic".to_string()).await;
+        let health = watchdog.check_system_health().await;
+        assert_eq!(health.status, SystemStatus::Critical);
+        assert_eq!(health.trinity_deaths, 1);
+    }
+
+    #[tokio::test]
+    async fn test_default_profile_escalates_any_death() {
+        let watchdog = Watchdog::new();
+        watchdog.report_death(GodName::Chaos, "no iniciado".to_string()).await;
+        assert_eq!(watchdog.check_system_health().await.status, SystemStatus::Critical);
+    }
+}
@@ -15,6 +15,9 @@ use tracing::{info, warn};
 
 use crate::actors::hades::audit::{AuditLogger, AuditResult};
 
+const JWT_AUDIENCE: &str = "olympus";
+const JWT_ISSUER: &str = "hades";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OtpCode {
     pub code: String,
@@ -190,6 +193,12 @@ impl AuthenticationService {
                 secret
             });
         
+        Self::with_jwt_secret(audit_logger, jwt_secret)
+    }
+    
+    /// Sign and validate tokens with a secret loaded at startup (see `secrets::Secrets`),
+    /// so tokens issued before a restart stay valid
+    pub fn with_jwt_secret(audit_logger: Arc<RwLock<AuditLogger>>, jwt_secret: String) -> Self {
         Self {
             users: Arc::new(RwLock::new(HashMap::new())),
             sessions: Arc::new(RwLock::new(HashMap::new())),
@@ -716,8 +725,8 @@ impl AuthenticationService {
             iat: now.timestamp(),
             exp: exp.timestamp(),
             jti: uuid::Uuid::new_v4().to_string(),
-            aud: "olympus".to_string(),
-            iss: "hades".to_string(),
+            aud: JWT_AUDIENCE.to_string(),
+            iss: JWT_ISSUER.to_string(),
         };
         
         let secret = self.jwt_secret.read().await;
@@ -734,10 +743,15 @@ impl AuthenticationService {
     pub async fn validate_token(&self, token: &str) -> Result<JwtClaims, AuthenticationError> {
         let secret = self.jwt_secret.read().await;
         
+        // Issued tokens carry aud/iss: the validation must expect them or it rejects every token
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.set_audience(&[JWT_AUDIENCE]);
+        validation.set_issuer(&[JWT_ISSUER]);
+        
         let token_data = decode::<JwtClaims>(
             token,
             &DecodingKey::from_secret(secret.as_bytes()),
-            &Validation::new(Algorithm::HS256),
+            &validation,
         ).map_err(|e| match e.kind() {
             jsonwebtoken::errors::ErrorKind::ExpiredSignature => AuthenticationError::TokenExpired,
             _ => AuthenticationError::InvalidToken,
@@ -954,3 +968,26 @@ pub struct OtpStatus {
     pub expires_at: u64,
     pub pending_otp: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_tokens_survive_restart_with_configured_secret() {
+        let secret = "s".repeat(32);
+        let before = AuthenticationService::with_jwt_secret(Arc::new(RwLock::new(AuditLogger::new())), secret.clone());
+        before
+            .create_user("medico".to_string(), "medico@uci.local".to_string(), "Clave-Segura-2026".to_string(), vec![Role::Doctor])
+            .await
+            .unwrap();
+        let (user, token) = before.authenticate("medico", "Clave-Segura-2026", None, None).await.unwrap();
+
+        // Reinicio: un servicio nuevo con el mismo secreto acepta el token
+        let after = AuthenticationService::with_jwt_secret(Arc::new(RwLock::new(AuditLogger::new())), secret);
+        assert_eq!(after.validate_token(&token).await.unwrap().sub, user.id);
+
+        let other = AuthenticationService::with_jwt_secret(Arc::new(RwLock::new(AuditLogger::new())), "o".repeat(32));
+        assert!(matches!(other.validate_token(&token).await, Err(AuthenticationError::InvalidToken)));
+    }
+}
@@ -64,6 +64,8 @@ impl SecretKey {
 pub struct EncryptionService {
     key_manager: Arc<RwLock<KeyManager>>,
     default_algorithm: EncryptionAlgorithm,
+    /// Key used when the caller does not name one (the configured master key)
+    default_key_id: Option<String>,
     #[allow(dead_code)]
     rng: SystemRandom,
 }
@@ -73,6 +75,7 @@ impl EncryptionService {
         Self {
             key_manager: Arc::new(RwLock::new(KeyManager::new())),
             default_algorithm: EncryptionAlgorithm::default(),
+            default_key_id: None,
             rng: SystemRandom::new(),
         }
     }
@@ -81,6 +84,7 @@ impl EncryptionService {
         Self {
             key_manager: Arc::new(RwLock::new(KeyManager::new())),
             default_algorithm: algorithm,
+            default_key_id: None,
             rng: SystemRandom::new(),
         }
     }
@@ -96,9 +100,10 @@ impl EncryptionService {
         
         // Get or generate key
         let key_manager = self.key_manager.read().await;
-        let key_id = key_id.map(|s| s.to_string()).unwrap_or_else(|| {
-            uuid::Uuid::new_v4().to_string()
-        });
+        let key_id = key_id
+            .map(|s| s.to_string())
+            .or_else(|| self.default_key_id.clone())
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
         
         let key = key_manager.get_or_create_key(&key_id, algo).await?;
         drop(key_manager);
@@ -316,6 +321,19 @@ impl EncryptionService {
         Ok(key_id)
     }
     
+    /// Register the configured master key and use it when no key id is given,
+    /// so data encrypted before a restart can still be decrypted
+    pub async fn install_master_key(&mut self, key_id: &str, key_bytes: Vec<u8>) -> Result<(), EncryptionError> {
+        if key_bytes.len() != 32 {
+            return Err(EncryptionError::InvalidKeySize);
+        }
+        let key = SecretKey::new(key_bytes, key_id.to_string(), self.default_algorithm);
+        self.key_manager.write().await.store_key(key).await;
+        self.default_key_id = Some(key_id.to_string());
+        info!("🔑 Master encryption key installed: {}", key_id);
+        Ok(())
+    }
+    
     /// Get the default algorithm
     pub fn default_algorithm(&self) -> EncryptionAlgorithm {
         self.default_algorithm
@@ -20,11 +20,13 @@ pub mod encryption;
 pub mod auth;
 pub mod keys;
 pub mod audit;
+pub mod secrets;
 
 pub use encryption::{EncryptionService, EncryptionAlgorithm};
 pub use auth::{AuthenticationService, User, Role, Permission, JwtClaims};
 pub use keys::{KeyManager, KeyStatus};
 pub use audit::{AuditLogger, AuditResult, AuditQuery, DataSensitivity, ExportFormat};
+pub use secrets::{Secrets, SecretError};
 
 /// Hades: Guardián de la Seguridad
 /// Parte de la Trinidad Suprema junto con Zeus y Poseidón
@@ -95,6 +97,33 @@ impl Hades {
         }
     }
     
+    /// Hades with the JWT secret and master encryption key loaded at startup
+    pub async fn with_secrets(secrets: &Secrets) -> Result<Self, HadesError> {
+        let audit = Arc::new(RwLock::new(AuditLogger::new()));
+        let mut encryption = EncryptionService::new();
+        encryption
+            .install_master_key(secrets::MASTER_KEY_ID, secrets.encryption_key().to_vec())
+            .await
+            .map_err(|e| HadesError::EncryptionError(e.to_string()))?;
+        
+        Ok(Self {
+            name: GodName::Hades,
+            state: ActorState::new(GodName::Hades),
+            config: ActorConfig::default(),
+            
+            encryption: Arc::new(RwLock::new(encryption)),
+            auth: Arc::new(RwLock::new(AuthenticationService::with_jwt_secret(
+                audit.clone(),
+                secrets.jwt_secret().to_string(),
+            ))),
+            keys: Arc::new(RwLock::new(KeyManager::new())),
+            audit,
+            
+            default_algorithm: EncryptionAlgorithm::ChaCha20Poly1305,
+            hipaa_mode: true,
+        })
+    }
+    
     pub async fn with_config(algorithm: EncryptionAlgorithm, hipaa_mode: bool) -> Self {
         let audit = Arc::new(RwLock::new(AuditLogger::new()));
         
@@ -421,10 +450,7 @@ impl Hades {
                                 data: serde_json::to_value(key_list).unwrap_or_default() 
                             })
                         }
-                        _ => Err(ActorError::InvalidQuery { 
-                            god: GodName::Hades, 
-                            reason: format!("Unknown query type: {}", query_type) 
-                        })
+                        _ => Err(ActorError::unsupported(GodName::Hades, "query", query_type))
                     }
                 } else {
                     Err(ActorError::InvalidQuery { 
@@ -433,10 +459,7 @@ impl Hades {
                     })
                 }
             }
-            _ => Err(ActorError::InvalidQuery { 
-                god: GodName::Hades, 
-                reason: "Unsupported query type".to_string() 
-            })
+            other => Err(ActorError::unsupported_variant(GodName::Hades, "query", &other)),
         }
     }
     
@@ -0,0 +1,207 @@
+// src/actors/hades/secrets.rs
+// OLYMPUS v15 - Hades Secret Store
+// Secreto de firma JWT y clave maestra de cifrado cargados al arrancar (env o archivo)
+
+#![allow(dead_code)]
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use ring::rand::{SecureRandom, SystemRandom};
+use tracing::warn;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::actors::zeus::config::Environment;
+
+pub const JWT_SECRET_ENV: &str = "OLYMPUS_JWT_SECRET";
+pub const JWT_SECRET_FILE_ENV: &str = "OLYMPUS_JWT_SECRET_FILE";
+/// Legacy name, still honoured when `OLYMPUS_JWT_SECRET` is not set
+pub const LEGACY_JWT_SECRET_ENV: &str = "JWT_SECRET";
+pub const ENCRYPTION_KEY_ENV: &str = "OLYMPUS_ENCRYPTION_KEY";
+pub const ENCRYPTION_KEY_FILE_ENV: &str = "OLYMPUS_ENCRYPTION_KEY_FILE";
+
+/// HS256 needs at least 256 bits of secret
+pub const JWT_SECRET_MIN_BYTES: usize = 32;
+/// AES-256-GCM and ChaCha20-Poly1305 keys
+pub const ENCRYPTION_KEY_BYTES: usize = 32;
+/// Key id under which the configured encryption key is registered
+pub const MASTER_KEY_ID: &str = "olympus-master";
+
+#[derive(Debug, thiserror::Error)]
+pub enum SecretError {
+    #[error("{0} is required in {1} mode (set it or its _FILE variant)")]
+    Missing(&'static str, Environment),
+
+    #[error("{name} is too short: {len} bytes, at least {min} required")]
+    TooShort { name: &'static str, len: usize, min: usize },
+
+    #[error("{0} must be {1} bytes encoded as base64")]
+    InvalidKey(&'static str, usize),
+
+    #[error("Cannot read {name} from {path}: {error}")]
+    Io { name: &'static str, path: String, error: String },
+
+    #[error("RNG error")]
+    RngError,
+}
+
+/// Secrets Hades signs and encrypts with. Ephemeral secrets (development only)
+/// change on every boot, so tokens and ciphertexts do not survive a restart.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct Secrets {
+    jwt_secret: String,
+    encryption_key: Vec<u8>,
+    #[zeroize(skip)]
+    ephemeral: bool,
+}
+
+impl std::fmt::Debug for Secrets {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Secrets")
+            .field("jwt_secret", &"<redacted>")
+            .field("encryption_key", &"<redacted>")
+            .field("ephemeral", &self.ephemeral)
+            .finish()
+    }
+}
+
+impl Secrets {
+    /// Load from the process environment; `OLYMPUS_ENV` selects the mode
+    pub fn from_env() -> Result<Self, SecretError> {
+        let environment = std::env::var("OLYMPUS_ENV")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(Environment::Development);
+        Self::load(environment, |name| std::env::var(name).ok())
+    }
+
+    /// Only development may fall back to ephemeral secrets; staging and production fail fast
+    pub fn load(environment: Environment, env: impl Fn(&str) -> Option<String>) -> Result<Self, SecretError> {
+        let allow_ephemeral = environment == Environment::Development;
+        let mut ephemeral = false;
+
+        let jwt_secret = match read_secret(&env, JWT_SECRET_ENV, JWT_SECRET_FILE_ENV)?
+            .or_else(|| env(LEGACY_JWT_SECRET_ENV).filter(|s| !s.trim().is_empty()))
+        {
+            Some(secret) => {
+                let secret = secret.trim().to_string();
+                if secret.len() < JWT_SECRET_MIN_BYTES {
+                    return Err(SecretError::TooShort { name: JWT_SECRET_ENV, len: secret.len(), min: JWT_SECRET_MIN_BYTES });
+                }
+                secret
+            }
+            None if allow_ephemeral => {
+                warn!("🔑 {} not set: using an ephemeral JWT secret, tokens will not survive a restart", JWT_SECRET_ENV);
+                ephemeral = true;
+                BASE64.encode(random_bytes(JWT_SECRET_MIN_BYTES)?)
+            }
+            None => return Err(SecretError::Missing(JWT_SECRET_ENV, environment)),
+        };
+
+        let encryption_key = match read_secret(&env, ENCRYPTION_KEY_ENV, ENCRYPTION_KEY_FILE_ENV)? {
+            Some(encoded) => {
+                let key = BASE64
+                    .decode(encoded.trim())
+                    .map_err(|_| SecretError::InvalidKey(ENCRYPTION_KEY_ENV, ENCRYPTION_KEY_BYTES))?;
+                if key.len() != ENCRYPTION_KEY_BYTES {
+                    return Err(SecretError::InvalidKey(ENCRYPTION_KEY_ENV, ENCRYPTION_KEY_BYTES));
+                }
+                key
+            }
+            None if allow_ephemeral => {
+                warn!("🔑 {} not set: using an ephemeral encryption key, ciphertexts will not survive a restart", ENCRYPTION_KEY_ENV);
+                ephemeral = true;
+                random_bytes(ENCRYPTION_KEY_BYTES)?
+            }
+            None => return Err(SecretError::Missing(ENCRYPTION_KEY_ENV, environment)),
+        };
+
+        Ok(Self { jwt_secret, encryption_key, ephemeral })
+    }
+
+    pub fn jwt_secret(&self) -> &str {
+        &self.jwt_secret
+    }
+
+    pub fn encryption_key(&self) -> &[u8] {
+        &self.encryption_key
+    }
+
+    pub fn is_ephemeral(&self) -> bool {
+        self.ephemeral
+    }
+}
+
+/// The value of `var`, or the contents of the file named by `file_var`
+fn read_secret(
+    env: &impl Fn(&str) -> Option<String>,
+    var: &'static str,
+    file_var: &'static str,
+) -> Result<Option<String>, SecretError> {
+    if let Some(value) = env(var).filter(|v| !v.trim().is_empty()) {
+        return Ok(Some(value));
+    }
+    match env(file_var).filter(|p| !p.trim().is_empty()) {
+        Some(path) => std::fs::read_to_string(path.trim())
+            .map(Some)
+            .map_err(|e| SecretError::Io { name: var, path, error: e.to_string() }),
+        None => Ok(None),
+    }
+}
+
+fn random_bytes(len: usize) -> Result<Vec<u8>, SecretError> {
+    let mut bytes = vec![0u8; len];
+    SystemRandom::new().fill(&mut bytes).map_err(|_| SecretError::RngError)?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env(vars: &[(&str, String)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.clone())).collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_production_requires_configured_secrets() {
+        let missing = Secrets::load(Environment::Production, env(&[]));
+        assert!(matches!(missing, Err(SecretError::Missing(JWT_SECRET_ENV, _))));
+
+        let short = Secrets::load(Environment::Production, env(&[(JWT_SECRET_ENV, "corto".to_string())]));
+        assert!(matches!(short, Err(SecretError::TooShort { .. })));
+
+        let jwt = (JWT_SECRET_ENV, "x".repeat(JWT_SECRET_MIN_BYTES));
+        let bad_key = Secrets::load(Environment::Production, env(&[jwt.clone(), (ENCRYPTION_KEY_ENV, BASE64.encode([0u8; 16]))]));
+        assert!(matches!(bad_key, Err(SecretError::InvalidKey(..))));
+
+        let key = (ENCRYPTION_KEY_ENV, BASE64.encode([7u8; ENCRYPTION_KEY_BYTES]));
+        let first = Secrets::load(Environment::Production, env(&[jwt.clone(), key.clone()])).unwrap();
+        let second = Secrets::load(Environment::Production, env(&[jwt, key])).unwrap();
+        assert!(!first.is_ephemeral());
+        assert_eq!(first.jwt_secret(), second.jwt_secret());
+        assert_eq!(first.encryption_key(), &[7u8; ENCRYPTION_KEY_BYTES]);
+    }
+
+    #[test]
+    fn test_development_falls_back_to_ephemeral_secrets() {
+        let first = Secrets::load(Environment::Development, env(&[])).unwrap();
+        let second = Secrets::load(Environment::Development, env(&[])).unwrap();
+        assert!(first.is_ephemeral());
+        assert_ne!(first.jwt_secret(), second.jwt_secret());
+        assert_eq!(first.encryption_key().len(), ENCRYPTION_KEY_BYTES);
+    }
+
+    #[test]
+    fn test_secret_file() {
+        let path = std::env::temp_dir().join(format!("olympus-jwt-{}", uuid::Uuid::new_v4()));
+        std::fs::write(&path, format!("{}\n", "s".repeat(40))).unwrap();
+        let secrets = Secrets::load(
+            Environment::Development,
+            env(&[(JWT_SECRET_FILE_ENV, path.display().to_string())]),
+        )
+        .unwrap();
+        assert_eq!(secrets.jwt_secret(), "s".repeat(40));
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -340,16 +340,10 @@ impl Hefesto {
                             message: format!("Migración '{}' ejecutada", migration_id) 
                         })
                     }
-                    _ => Err(ActorError::InvalidCommand { 
-                        god: GodName::Hefesto, 
-                        reason: format!("Acción '{}' no soportada", action.unwrap_or("unknown")) 
-                    }),
+                    _ => Err(ActorError::unsupported(GodName::Hefesto, "command", action.unwrap_or("unknown"))),
                 }
             }
-            _ => Err(ActorError::InvalidCommand { 
-                god: GodName::Hefesto, 
-                reason: "Comando no soportado".to_string() 
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Hefesto, "command", &other)),
         }
     }
 
@@ -426,16 +420,10 @@ impl Hefesto {
                             data: serde_json::to_value(&stats).unwrap_or_default()
                         })
                     }
-                    _ => Err(ActorError::InvalidQuery { 
-                        god: GodName::Hefesto, 
-                        reason: format!("Query type '{}' no soportado", query_type) 
-                    }),
+                    _ => Err(ActorError::unsupported(GodName::Hefesto, "query", query_type)),
                 }
             }
-            _ => Err(ActorError::InvalidQuery { 
-                god: GodName::Hefesto, 
-                reason: "Query no soportado".to_string() 
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Hefesto, "query", &other)),
         }
     }
 }
@@ -219,12 +219,7 @@ impl OlympianActor for Hera {
 
 impl Hera {
     async fn handle_command(&mut self, cmd: CommandPayload) -> Result<ResponsePayload, ActorError> {
-        match cmd {
-            _ => Ok(ResponsePayload::Error { 
-                error: "Unknown command".to_string(), 
-                code: 400 
-            }),
-        }
+        Err(ActorError::unsupported_variant(GodName::Hera, "command", &cmd))
     }
     
     async fn handle_query(&self, query: QueryPayload) -> Result<ResponsePayload, ActorError> {
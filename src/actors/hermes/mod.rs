@@ -192,10 +192,7 @@ impl Hermes {
                                 })
                             }
                         }
-                        _ => Err(ActorError::InvalidQuery { 
-                            god: GodName::Hermes, 
-                            reason: format!("Unknown query type: {}", query_type) 
-                        })
+                        _ => Err(ActorError::unsupported(GodName::Hermes, "query", query_type))
                     }
                 } else {
                     Err(ActorError::InvalidQuery { 
@@ -204,10 +201,7 @@ impl Hermes {
                     })
                 }
             }
-            _ => Err(ActorError::InvalidQuery { 
-                god: GodName::Hermes, 
-                reason: "Unsupported query type".to_string() 
-            })
+            other => Err(ActorError::unsupported_variant(GodName::Hermes, "query", &other)),
         }
     }
 
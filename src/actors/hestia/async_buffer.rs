@@ -2,6 +2,7 @@
 // OLYMPUS v15 - Hestia Async Buffer
 // Buffer de escritura asíncrona con batching inteligente, backpressure y retry con backoff
 
+use std::future::Future;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use std::collections::{HashMap, VecDeque};
@@ -14,6 +15,9 @@ use tracing::{debug, error, info, warn};
 use crate::infrastructure::{ValkeyStore, SurrealStore};
 use crate::errors::PersistenceError;
 
+/// Tope del backoff entre reintentos
+pub const MAX_RETRY_BACKOFF_MS: u64 = 5000;
+
 /// Estado de una operación en buffer
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum BufferStatus {
@@ -81,12 +85,13 @@ impl BufferedOperation {
         }
     }
     
-    pub fn mark_attempt(&mut self, error: Option<String>) {
+    /// Registra un intento fallido; agotados `max_retries` reintentos pasa a dead letter
+    pub fn mark_attempt(&mut self, error: Option<String>, max_retries: u32) {
         self.attempts += 1;
         self.last_attempt = Some(chrono::Utc::now());
         self.error_message = error;
         
-        if self.attempts >= 5 {
+        if self.attempts > max_retries {
             self.status = BufferStatus::DeadLetter;
         } else if self.error_message.is_some() {
             self.status = BufferStatus::Failed;
@@ -98,15 +103,52 @@ impl BufferedOperation {
         self.error_message = None;
     }
     
-    pub fn can_retry(&self) -> bool {
-        self.attempts < 5 && self.status != BufferStatus::DeadLetter
+    pub fn can_retry(&self, max_retries: u32) -> bool {
+        self.attempts <= max_retries && self.status != BufferStatus::DeadLetter
+    }
+    
+    pub fn retry_delay(&self, backoff: Duration) -> Duration {
+        // Backoff exponencial desde la base: 100ms, 200ms, 400ms, 800ms... con la base por defecto
+        let factor = 2u32.saturating_pow(self.attempts.saturating_sub(1));
+        backoff.saturating_mul(factor).min(Duration::from_millis(MAX_RETRY_BACKOFF_MS))
     }
     
-    pub fn retry_delay(&self) -> Duration {
-        // Backoff exponencial: 100ms, 200ms, 400ms, 800ms, 1.6s
-        let base = 100u64;
-        let delay = base * 2u64.pow(self.attempts);
-        Duration::from_millis(delay.min(5000)) // Max 5 segundos
+    /// Vuelve a dejar la operación como nueva para reencolarla desde el dead letter
+    pub fn requeue(&mut self) {
+        self.status = BufferStatus::Pending;
+        self.attempts = 0;
+        self.error_message = None;
+    }
+}
+
+/// Ejecuta `write` hasta que tenga éxito o se agoten los reintentos, esperando el backoff
+/// entre intentos. Devuelve la operación completada o, si se agotan, marcada como dead letter.
+async fn persist_with_retry<F, Fut>(
+    mut op: BufferedOperation,
+    max_retries: u32,
+    backoff: Duration,
+    mut write: F,
+) -> Result<BufferedOperation, BufferedOperation>
+where
+    F: FnMut(BufferedOperation) -> Fut,
+    Fut: Future<Output = Result<(), String>>,
+{
+    loop {
+        match write(op.clone()).await {
+            Ok(()) => {
+                op.mark_completed();
+                return Ok(op);
+            }
+            Err(e) => {
+                op.mark_attempt(Some(e), max_retries);
+                if !op.can_retry(max_retries) {
+                    return Err(op);
+                }
+                let delay = op.retry_delay(backoff);
+                debug!("Operation {} failed (attempt {}), retrying in {:?}", op.id, op.attempts, delay);
+                sleep(delay).await;
+            }
+        }
     }
 }
 
@@ -121,6 +163,16 @@ pub struct AsyncBufferConfig {
     pub enable_compression: bool,
     pub dead_letter_enabled: bool,
     pub backpressure_threshold: usize,
+    /// Reintentos de una escritura fallida antes de moverla al dead letter
+    pub max_retries: u32,
+    /// Espera antes del primer reintento; se duplica en cada intento hasta `MAX_RETRY_BACKOFF_MS`
+    pub retry_backoff_ms: u64,
+}
+
+impl AsyncBufferConfig {
+    fn retry_backoff(&self) -> Duration {
+        Duration::from_millis(self.retry_backoff_ms)
+    }
 }
 
 impl Default for AsyncBufferConfig {
@@ -134,6 +186,8 @@ impl Default for AsyncBufferConfig {
             enable_compression: false,
             dead_letter_enabled: true,
             backpressure_threshold: 8000,
+            max_retries: 4,
+            retry_backoff_ms: 100,
         }
     }
 }
@@ -372,8 +426,8 @@ impl AsyncBuffer {
                     
                     // Reintentar individualmente
                     for mut op in batch {
-                        op.mark_attempt(Some(e.to_string()));
-                        if op.can_retry() {
+                        op.mark_attempt(Some(e.to_string()), config.max_retries);
+                        if op.can_retry(config.max_retries) {
                             let mut pending = self.pending_ops.write().await;
                             pending.push_back(op);
                         } else {
@@ -432,22 +486,27 @@ impl AsyncBuffer {
         self.dead_letter_queue.read().await.iter().cloned().collect()
     }
     
-    /// Reintenta operaciones en dead letter
+    /// Reencola las operaciones en dead letter con los intentos a cero: se vuelven a
+    /// escribir con la misma política de reintentos y, si L3 sigue caído, regresan al dead letter
     pub async fn retry_dead_letter(&self) -> Result<u64, PersistenceError> {
         let ops: Vec<BufferedOperation> = {
             let mut dlq = self.dead_letter_queue.write().await;
             dlq.drain(..).collect()
         };
         
-        let mut retried = 0;
-        for mut op in ops {
-            op.status = BufferStatus::Pending;
-            op.attempts = 0;
-            op.error_message = None;
-            
+        let retried = ops.len() as u64;
+        {
             let mut pending = self.pending_ops.write().await;
-            pending.push_back(op);
-            retried += 1;
+            for mut op in ops {
+                op.requeue();
+                pending.push_back(op);
+            }
+        }
+        
+        {
+            let mut stats = self.stats.write().await;
+            stats.dead_letter_operations = stats.dead_letter_operations.saturating_sub(retried);
+            stats.pending_operations += retried as usize;
         }
         
         self.flush_notify.notify_one();
@@ -565,10 +624,10 @@ impl AsyncBuffer {
                         }
                     }
                     
-                    // Remover de processing
+                    // Remover de processing (las fallidas ya están en el dead letter)
                     {
                         let mut processing = self.processing_ops.write().await;
-                        for op in &completed {
+                        for op in &batch {
                             processing.remove(&op.id);
                         }
                     }
@@ -586,17 +645,14 @@ impl AsyncBuffer {
                     warn!("Batch processing failed: {}", e);
                     
                     // Manejar fallos individuales
+                    let mut delay = Duration::ZERO;
                     for mut op in batch {
-                        op.mark_attempt(Some(e.to_string()));
+                        self.processing_ops.write().await.remove(&op.id);
+                        op.mark_attempt(Some(e.to_string()), config.max_retries);
                         
-                        if op.can_retry() {
-                            // Reintentar con delay
-                            let delay = op.retry_delay();
-                            tokio::spawn(async move {
-                                sleep(delay).await;
-                                // El op se vuelve a agregar en el próximo ciclo
-                            });
-                            
+                        if op.can_retry(config.max_retries) {
+                            // Se reintenta en el próximo ciclo, tras el backoff
+                            delay = delay.max(op.retry_delay(config.retry_backoff()));
                             let mut pending = self.pending_ops.write().await;
                             pending.push_back(op);
                         } else {
@@ -605,9 +661,12 @@ impl AsyncBuffer {
                         }
                     }
                     
-                    let mut stats = self.stats.write().await;
-                    stats.batches_failed += 1;
-                    stats.processing_operations = 0;
+                    {
+                        let mut stats = self.stats.write().await;
+                        stats.batches_failed += 1;
+                        stats.processing_operations = 0;
+                    }
+                    sleep(delay).await;
                 }
             }
         }
@@ -620,48 +679,20 @@ impl AsyncBuffer {
             by_table.entry(op.table.clone()).or_insert_with(Vec::new).push(op);
         }
         
+        let config = self.config.read().await.clone();
         let mut completed = Vec::new();
         
         // Procesar cada tabla
         for (table, ops) in by_table {
-            // Construir query batch para SurrealDB
             // Nota: En una implementación real, usarías transacciones de SurrealDB
-            for mut op in ops {
-                let result = match op.operation_type {
-                    OperationType::Create => {
-                        self.surreal.create(&table, &op.value).await
-                            .map(|_| ())
-                    }
-                    OperationType::Update => {
-                        // Crear ID si no existe
-                        let id = op.key.clone();
-                        self.surreal.update(&table, &id, &op.value).await
-                            .map(|_| ())
-                    }
-                    OperationType::Delete => {
-                        let id = op.key.clone();
-                        self.surreal.delete(&table, &id).await
-                    }
-                    OperationType::Upsert => {
-                        let id = op.key.clone();
-                        // Intentar update primero, luego create si falla
-                        match self.surreal.update(&table, &id, &op.value).await {
-                            Ok(_) => Ok(()),
-                            Err(_) => self.surreal.create(&table, &op.value).await.map(|_| ()),
-                        }
-                    }
-                };
-                
-                match result {
-                    Ok(_) => {
-                        op.mark_completed();
-                        completed.push(op);
-                    }
-                    Err(e) => {
-                        op.mark_attempt(Some(e.to_string()));
-                        if !op.can_retry() {
-                            self.move_to_dead_letter(op).await?;
-                        }
+            for op in ops {
+                let write = |op: BufferedOperation| self.write_operation(&table, op);
+                match persist_with_retry(op, config.max_retries, config.retry_backoff(), write).await {
+                    Ok(op) => completed.push(op),
+                    Err(op) => {
+                        warn!("Operation {} failed after {} attempts: {}", op.id, op.attempts, op.error_message.as_deref().unwrap_or(""));
+                        self.stats.write().await.failed_operations += 1;
+                        self.move_to_dead_letter(op).await?;
                     }
                 }
             }
@@ -670,6 +701,31 @@ impl AsyncBuffer {
         Ok(completed)
     }
     
+    /// Un intento de escritura de la operación en SurrealDB (L3)
+    async fn write_operation(&self, table: &str, op: BufferedOperation) -> Result<(), String> {
+        let result = match op.operation_type {
+            OperationType::Create => {
+                self.surreal.create(table, &op.value).await
+                    .map(|_| ())
+            }
+            OperationType::Update => {
+                self.surreal.update(table, &op.key, &op.value).await
+                    .map(|_| ())
+            }
+            OperationType::Delete => {
+                self.surreal.delete(table, &op.key).await
+            }
+            OperationType::Upsert => {
+                // Intentar update primero, luego create si falla
+                match self.surreal.update(table, &op.key, &op.value).await {
+                    Ok(_) => Ok(()),
+                    Err(_) => self.surreal.create(table, &op.value).await.map(|_| ()),
+                }
+            }
+        };
+        result.map_err(|e| e.to_string())
+    }
+    
     async fn move_to_dead_letter(&self, op: BufferedOperation) -> Result<(), PersistenceError> {
         if !self.config.read().await.dead_letter_enabled {
             return Ok(());
@@ -715,3 +771,52 @@ pub struct FlushResult {
     pub failed: u64,
     pub duration_ms: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn operation() -> BufferedOperation {
+        BufferedOperation::new("patients", "patients:1".to_string(), serde_json::json!({ "name": "Ana" }), OperationType::Create, OperationPriority::Normal)
+    }
+
+    /// L3 que falla las primeras `failures` escrituras
+    fn flaky(calls: &AtomicU32, failures: u32) -> impl FnMut(BufferedOperation) -> std::future::Ready<Result<(), String>> + '_ {
+        move |_| {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            std::future::ready(if call < failures { Err("connection reset".to_string()) } else { Ok(()) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transient_failure_retried_then_dead_lettered_and_recovered() {
+        let backoff = Duration::from_millis(1);
+
+        // Dos fallos transitorios caben en tres reintentos
+        let calls = AtomicU32::new(0);
+        let op = persist_with_retry(operation(), 3, backoff, flaky(&calls, 2)).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(op.status, BufferStatus::Completed);
+        assert_eq!(op.attempts, 2);
+
+        // Con L3 caído se hace el intento inicial más `max_retries` antes del dead letter
+        let calls = AtomicU32::new(0);
+        let mut op = persist_with_retry(operation(), 3, backoff, flaky(&calls, u32::MAX)).await.unwrap_err();
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+        assert_eq!(op.status, BufferStatus::DeadLetter);
+        assert_eq!(op.error_message.as_deref(), Some("connection reset"));
+
+        // RetryDeadLetter la reencola con la misma política y se persiste al volver L3
+        op.requeue();
+        let calls = AtomicU32::new(0);
+        let op = persist_with_retry(op, 3, backoff, flaky(&calls, 1)).await.unwrap();
+        assert_eq!(op.status, BufferStatus::Completed);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        assert_eq!(operation().retry_delay(Duration::from_millis(100)), Duration::from_millis(100));
+        let mut late = operation();
+        late.attempts = 10;
+        assert_eq!(late.retry_delay(Duration::from_millis(100)), Duration::from_millis(MAX_RETRY_BACKOFF_MS));
+    }
+}
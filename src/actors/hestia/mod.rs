@@ -125,6 +125,16 @@ pub enum HestiaCommand {
 }
 
 /// Queries específicos de Hestia
+/// Clave de caché de la ficha de un paciente (la que rellena `get_patient`)
+pub fn patient_cache_key(patient_id: &str) -> String {
+    format!("patient:{}", patient_id)
+}
+
+/// Tag con el que se guardan las evaluaciones cacheadas de un paciente
+pub fn patient_assessments_tag(patient_id: &str) -> String {
+    format!("patient:{}:assessments", patient_id)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum HestiaQuery {
     // Queries básicos
@@ -423,6 +433,14 @@ impl Hestia {
         })
     }
     
+    /// Reencola el dead letter del buffer con la política de reintentos configurada
+    pub async fn retry_dead_letter(&self) -> Result<u64, ActorError> {
+        self.async_buffer.retry_dead_letter().await.map_err(|e| ActorError::StateError {
+            god: GodName::Hestia,
+            message: format!("Dead letter retry failed: {}", e),
+        })
+    }
+    
     /// Obtiene todas las estadísticas
     pub async fn get_full_stats(&self) -> serde_json::Value {
         let cache_stats = self.cache.get_stats().await;
@@ -711,10 +729,7 @@ impl Hestia {
                     data: serde_json::to_value(result).unwrap_or_default()
                 })
             }
-            _ => Err(ActorError::InvalidCommand {
-                god: GodName::Hestia,
-                reason: format!("Unsupported command: {:?}", cmd),
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Hestia, "command", &other)),
         }
     }
     
@@ -747,10 +762,15 @@ impl Hestia {
                 })?;
                 Ok(ResponsePayload::Data { data: serde_json::to_value(items).unwrap_or_default() })
             }
-            _ => Err(ActorError::InvalidQuery {
-                god: GodName::Hestia,
-                reason: "Unsupported query type".to_string(),
-            }),
+            // Queries ricos (GetCacheStats, GetConflicts, GetDeadLetter...), igual que Configure en comandos
+            QueryPayload::Custom(value) => match serde_json::from_value::<HestiaQuery>(value) {
+                Ok(hestia_query) => self.execute_hestia_query(hestia_query).await,
+                Err(e) => Err(ActorError::InvalidQuery {
+                    god: GodName::Hestia,
+                    reason: format!("Unknown Hestia query format: {}", e),
+                }),
+            },
+            other => Err(ActorError::unsupported_variant(GodName::Hestia, "query", &other)),
         }
     }
     
@@ -802,6 +822,12 @@ impl Hestia {
                     data: serde_json::to_value(&result).unwrap_or_default()
                 })
             }
+            HestiaCommand::RetryDeadLetter => {
+                let retried = self.retry_dead_letter().await?;
+                Ok(ResponsePayload::Success { 
+                    message: format!("Requeued {} dead letter operations", retried) 
+                })
+            }
             HestiaCommand::Sync { direction: _, keys } => {
                 let result = self.sync(keys).await?;
                 Ok(ResponsePayload::Data { 
@@ -820,6 +846,15 @@ impl Hestia {
                     message: format!("Invalidated key '{}'", key) 
                 })
             }
+            HestiaCommand::InvalidateTag { tag } => {
+                let count = self.cache.invalidate_by_tag(&tag).await.map_err(|e| ActorError::StateError {
+                    god: GodName::Hestia,
+                    message: e.to_string(),
+                })?;
+                Ok(ResponsePayload::Success { 
+                    message: format!("Invalidated {} keys tagged '{}'", count, tag) 
+                })
+            }
             HestiaCommand::Backup { table } => {
                 let meta = self.sync_manager.backup_table(&table).await.map_err(|e| ActorError::StateError {
                     god: GodName::Hestia,
@@ -853,10 +888,7 @@ impl Hestia {
                     message: "Statistics reset".to_string() 
                 })
             }
-            _ => Err(ActorError::InvalidCommand {
-                god: GodName::Hestia,
-                reason: "Command not implemented".to_string(),
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Hestia, "command", &other)),
         }
     }
     
@@ -909,10 +941,7 @@ impl Hestia {
                 let dlq = self.async_buffer.get_dead_letter().await;
                 Ok(ResponsePayload::Data { data: serde_json::json!(dlq) })
             }
-            _ => Err(ActorError::InvalidQuery {
-                god: GodName::Hestia,
-                reason: "Query not implemented".to_string(),
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Hestia, "query", &other)),
         }
     }
     
@@ -11,7 +11,7 @@ use tokio::sync::{RwLock, mpsc};
 use tokio::time::{interval, sleep};
 use tracing::{debug, info, warn, error, instrument};
 
-use crate::infrastructure::{ValkeyStore, SurrealStore};
+use crate::infrastructure::{BoundQuery, ValkeyStore, SurrealStore};
 use crate::errors::PersistenceError;
 
 /// Dirección de sincronización
@@ -445,8 +445,9 @@ impl SyncManager {
         let start = Instant::now();
         
         // Obtener todos los registros de la tabla
-        let query = format!("SELECT * FROM {}", table);
-        let records: Vec<serde_json::Value> = self.surreal.query(&query).await
+        let query = BoundQuery::with_table("SELECT * FROM {table}", table)
+            .map_err(|e| PersistenceError::SurrealError(e.to_string()))?;
+        let records: Vec<serde_json::Value> = self.surreal.query_bound(query).await
             .map_err(|e| PersistenceError::SurrealError(e.to_string()))?;
         
         // Calcular checksum y metadata
@@ -51,6 +51,9 @@ pub mod hestia;         // 🏠 Hestia - Diosa del Hogar y Persistencia
 // 🦋 DIOSITAS DE JUSTICIA (1 dios)
 pub mod nemesis;        // 🦋 Némesis - Diosa de la Justicia Legal y Cumplimiento
 
+// Utilidades compartidas
+pub mod ring_buffer;
+
 // Actor interfaces for v13
 use serde::{Deserialize, Serialize};
 
@@ -417,16 +417,10 @@ impl Moirai {
                             message: format!("Thread cerrado para paciente {}", patient_id) 
                         })
                     }
-                    _ => Err(ActorError::InvalidCommand { 
-                        god: GodName::Moirai, 
-                        reason: format!("Acción '{}' no soportada", action.unwrap_or("unknown")) 
-                    }),
+                    _ => Err(ActorError::unsupported(GodName::Moirai, "command", action.unwrap_or("unknown"))),
                 }
             }
-            _ => Err(ActorError::InvalidCommand { 
-                god: GodName::Moirai, 
-                reason: "Comando no soportado".to_string() 
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Moirai, "command", &other)),
         }
     }
 
@@ -551,16 +545,10 @@ impl Moirai {
                             })
                         })
                     }
-                    _ => Err(ActorError::InvalidQuery { 
-                        god: GodName::Moirai, 
-                        reason: format!("Query type '{}' no soportado", query_type) 
-                    }),
+                    _ => Err(ActorError::unsupported(GodName::Moirai, "query", query_type)),
                 }
             }
-            _ => Err(ActorError::InvalidQuery { 
-                god: GodName::Moirai, 
-                reason: "Query no soportado".to_string() 
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Moirai, "query", &other)),
         }
     }
 }
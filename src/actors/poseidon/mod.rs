@@ -529,7 +529,7 @@ impl Poseidon {
                 info!("🌊 Poseidon: Configurando con {:?}", config);
                 Ok(ResponsePayload::Ack { message_id: uuid::Uuid::new_v4().to_string() })
             }
-            _ => Ok(ResponsePayload::Error { error: "Unknown command".to_string(), code: 400 }),
+            other => Err(ActorError::unsupported_variant(GodName::Poseidon, "command", &other)),
         }
     }
     
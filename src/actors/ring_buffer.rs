@@ -0,0 +1,97 @@
+// src/actors/ring_buffer.rs
+// OLYMPUS v15 - Ring Buffer
+// Histórico en memoria de capacidad fija que contabiliza lo que se sobrescribe
+
+use serde::{Serialize, Serializer};
+use std::collections::VecDeque;
+
+/// Buffer circular acotado: al llenarse descarta el elemento más antiguo
+/// y lo cuenta en `dropped`, en lugar de crecer o perderlo en silencio.
+#[derive(Debug, Clone)]
+pub struct RingBuffer<T> {
+    items: VecDeque<T>,
+    capacity: usize,
+    dropped: u64,
+}
+
+impl<T> RingBuffer<T> {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            items: VecDeque::with_capacity(capacity),
+            capacity,
+            dropped: 0,
+        }
+    }
+
+    /// Inserta un elemento; devuelve `true` si se sobrescribió el más antiguo
+    pub fn push(&mut self, item: T) -> bool {
+        let overwritten = if self.items.len() >= self.capacity {
+            self.items.pop_front();
+            self.dropped += 1;
+            true
+        } else {
+            false
+        };
+        self.items.push_back(item);
+        overwritten
+    }
+
+    /// Elimina el elemento más antiguo sin contarlo como descartado
+    /// (p.ej. expiración por retención)
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.items.pop_front()
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.items.front()
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.items.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Total de elementos sobrescritos desde la creación
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+}
+
+impl<T: Serialize> Serialize for RingBuffer<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.items.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_buffer_counts_overwrites() {
+        let mut buffer = RingBuffer::new(3);
+        for i in 0..5 {
+            buffer.push(i);
+        }
+
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.dropped(), 2);
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+
+        buffer.pop_front();
+        assert_eq!(buffer.dropped(), 2);
+        assert_eq!(serde_json::to_value(&buffer).unwrap(), serde_json::json!([3, 4]));
+    }
+}
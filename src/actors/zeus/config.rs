@@ -69,6 +69,12 @@ pub struct ZeusConfig {
     // Métricas
     pub metrics_retention_hours: u64,
     pub metrics_export_interval_seconds: u64,
+    /// Cada cuánto se toma un snapshot histórico (independiente de la auto-evaluación)
+    #[serde(default = "default_metrics_snapshot_interval_seconds")]
+    pub metrics_snapshot_interval_seconds: u64,
+    /// Snapshots retenidos como máximo; al superarlo se descarta el más antiguo
+    #[serde(default = "default_metrics_snapshot_retention")]
+    pub metrics_snapshot_retention: usize,
     pub prometheus_enabled: bool,
     pub prometheus_port: u16,
     
@@ -101,6 +107,35 @@ pub struct ZeusConfig {
     pub overrides: ConfigOverrides,
 }
 
+fn default_metrics_snapshot_interval_seconds() -> u64 {
+    60
+}
+
+/// 24 h de snapshots con el intervalo por defecto
+fn default_metrics_snapshot_retention() -> usize {
+    1440
+}
+
+const REDACTED: &str = "***";
+
+/// Campos que nunca se exponen fuera del proceso
+fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    ["password", "secret", "token", "api_key", "credential"]
+        .iter()
+        .any(|marker| key.contains(marker))
+}
+
+/// Intervalos con los que arrancaron los loops de Zeus.
+/// Un cambio posterior de configuración no los altera hasta volver a montar el Olimpo.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ActiveIntervals {
+    pub self_evaluation_interval_seconds: Option<u64>,
+    pub health_check_interval_seconds: Option<u64>,
+    pub metrics_snapshot_interval_seconds: Option<u64>,
+    pub metrics_snapshot_retention: Option<usize>,
+}
+
 /// Overrides por ambiente
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ConfigOverrides {
@@ -166,6 +201,8 @@ impl Default for ZeusConfig {
             
             metrics_retention_hours: 24,
             metrics_export_interval_seconds: 60,
+            metrics_snapshot_interval_seconds: default_metrics_snapshot_interval_seconds(),
+            metrics_snapshot_retention: default_metrics_snapshot_retention(),
             prometheus_enabled: true,
             prometheus_port: 9090,
             
@@ -244,6 +281,18 @@ impl ZeusConfig {
             }
         }
         
+        if let Ok(val) = std::env::var("ZEUS_SNAPSHOT_INTERVAL_S") {
+            if let Ok(v) = val.parse() {
+                config.metrics_snapshot_interval_seconds = v;
+            }
+        }
+        
+        if let Ok(val) = std::env::var("ZEUS_SNAPSHOT_RETENTION") {
+            if let Ok(v) = val.parse() {
+                config.metrics_snapshot_retention = v;
+            }
+        }
+        
         if let Ok(val) = std::env::var("ZEUS_GOVERNANCE_ENABLED") {
             if let Ok(v) = val.parse() {
                 config.governance_enabled = v;
@@ -362,6 +411,20 @@ impl ZeusConfig {
             });
         }
         
+        if self.metrics_snapshot_interval_seconds < 1 {
+            return Err(ConfigError::ValidationError {
+                field: "metrics_snapshot_interval_seconds".to_string(),
+                reason: "Must be at least 1 second".to_string(),
+            });
+        }
+        
+        if self.metrics_snapshot_retention == 0 {
+            return Err(ConfigError::ValidationError {
+                field: "metrics_snapshot_retention".to_string(),
+                reason: "Must keep at least 1 snapshot".to_string(),
+            });
+        }
+        
         if self.prometheus_port == 0 {
             return Err(ConfigError::ValidationError {
                 field: "prometheus_port".to_string(),
@@ -432,6 +495,24 @@ impl ZeusConfig {
         }
     }
     
+    /// Configuración serializada con los valores sensibles ocultos
+    pub fn redacted(&self) -> serde_json::Value {
+        let mut value = serde_json::to_value(self).unwrap_or_default();
+        if let Some(map) = value.as_object_mut() {
+            for (key, v) in map.iter_mut() {
+                if is_sensitive_key(key) {
+                    *v = serde_json::Value::String(REDACTED.to_string());
+                }
+            }
+        }
+        value
+    }
+    
+    /// Un único valor (redactado si es sensible) por nombre de campo
+    pub fn redacted_value(&self, key: &str) -> Option<serde_json::Value> {
+        self.redacted().get(key).cloned()
+    }
+    
     /// Obtiene configuración específica para producción
     pub fn for_production() -> Self {
         let mut config = Self::default();
@@ -643,3 +724,22 @@ impl std::fmt::Display for ConfigError {
 }
 
 impl std::error::Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redacted_hides_sensitive_keys() {
+        assert!(is_sensitive_key("db_password"));
+        assert!(is_sensitive_key("JWT_SECRET"));
+        assert!(!is_sensitive_key("heartbeat_interval_ms"));
+
+        let config = ZeusConfig::default();
+        assert_eq!(
+            config.redacted_value("health_check_interval_seconds"),
+            Some(serde_json::json!(config.health_check_interval_seconds))
+        );
+        assert_eq!(config.redacted_value("unknown_key"), None);
+    }
+}
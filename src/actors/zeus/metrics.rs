@@ -5,7 +5,7 @@
 #![allow(dead_code)]
 
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, VecDeque};
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -13,6 +13,11 @@ use chrono::Utc;
 use tracing::{info, warn};
 
 use super::GodName;
+use crate::actors::ring_buffer::RingBuffer;
+use crate::system::unsupported::UnsupportedCounts;
+
+/// Capacidad máxima del histórico de snapshots
+pub const HISTORY_CAPACITY: usize = 10000;
 
 /// Métricas principales de Zeus
 #[derive(Debug, Clone)]
@@ -29,12 +34,14 @@ pub struct ZeusMetrics {
     pub total_recoveries: Arc<AtomicU64>,
     pub total_panics: Arc<AtomicU64>,
     pub total_dead_letters: Arc<AtomicU64>,
+    /// Snapshots del histórico sobrescritos al llenarse el buffer (no son eventos perdidos)
+    pub snapshots_overwritten_total: Arc<AtomicU64>,
 
     // Métricas de actores
     pub actor_metrics: Arc<RwLock<HashMap<GodName, ActorMetrics>>>,
 
     // Histórico temporal
-    pub historical_data: Arc<RwLock<VecDeque<HistoricalSnapshot>>>,
+    pub historical_data: Arc<RwLock<RingBuffer<HistoricalSnapshot>>>,
 
     // Alertas basadas en thresholds
     pub alert_thresholds: Arc<RwLock<AlertThresholds>>,
@@ -46,6 +53,9 @@ pub struct ZeusMetrics {
     // Métricas de la Trinidad
     pub trinity_metrics: Arc<RwLock<TrinityMetrics>>,
 
+    // Comandos y queries que ningún dios atiende (los anotan los runners)
+    pub unsupported: UnsupportedCounts,
+
     // Configuración
     pub retention_hours: u64,
     pub snapshot_interval_seconds: u64,
@@ -79,6 +89,16 @@ pub struct HistoricalSnapshot {
     pub memory_usage_mb: f64,
 }
 
+/// Intervalo y retención efectivos del histórico de snapshots
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistorySettings {
+    pub snapshot_interval_seconds: u64,
+    pub max_snapshots: usize,
+    pub retention_hours: u64,
+    pub retained_snapshots: usize,
+    pub evicted_snapshots: u64,
+}
+
 /// Thresholds para alertas
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AlertThresholds {
@@ -182,15 +202,17 @@ impl ZeusMetrics {
             total_recoveries: Arc::new(AtomicU64::new(0)),
             total_panics: Arc::new(AtomicU64::new(0)),
             total_dead_letters: Arc::new(AtomicU64::new(0)),
+            snapshots_overwritten_total: Arc::new(AtomicU64::new(0)),
             
             actor_metrics: Arc::new(RwLock::new(HashMap::new())),
-            historical_data: Arc::new(RwLock::new(VecDeque::new())),
+            historical_data: Arc::new(RwLock::new(RingBuffer::new(HISTORY_CAPACITY))),
             
             alert_thresholds: Arc::new(RwLock::new(AlertThresholds::default())),
             active_alerts: Arc::new(RwLock::new(Vec::new())),
             
             system_metrics: Arc::new(RwLock::new(SystemMetrics::default())),
             trinity_metrics: Arc::new(RwLock::new(TrinityMetrics::default())),
+            unsupported: UnsupportedCounts::new(),
             
             retention_hours: 24,
             snapshot_interval_seconds: 60,
@@ -203,6 +225,27 @@ impl ZeusMetrics {
         metrics
     }
 
+    /// Histórico con intervalo y tope de snapshots propios: con 10 s y 100 snapshots
+    /// se conservan los últimos ~16 minutos
+    pub fn with_history(snapshot_interval_seconds: u64, max_snapshots: usize, retention_hours: u64) -> Self {
+        let mut metrics = Self::with_retention(retention_hours);
+        metrics.snapshot_interval_seconds = snapshot_interval_seconds.max(1);
+        metrics.historical_data = Arc::new(RwLock::new(RingBuffer::new(max_snapshots)));
+        metrics
+    }
+
+    /// Configuración efectiva del histórico
+    pub async fn history_settings(&self) -> HistorySettings {
+        let history = self.historical_data.read().await;
+        HistorySettings {
+            snapshot_interval_seconds: self.snapshot_interval_seconds,
+            max_snapshots: history.capacity(),
+            retention_hours: self.retention_hours,
+            retained_snapshots: history.len(),
+            evicted_snapshots: history.dropped(),
+        }
+    }
+
     // ==================== Operaciones Atómicas ====================
 
     pub fn increment_messages(&self) {
@@ -229,6 +272,14 @@ impl ZeusMetrics {
         self.total_dead_letters.fetch_add(1, Ordering::SeqCst);
     }
 
+    pub fn increment_snapshots_overwritten(&self) {
+        self.snapshots_overwritten_total.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn get_snapshots_overwritten(&self) -> u64 {
+        self.snapshots_overwritten_total.load(Ordering::SeqCst)
+    }
+
     pub fn get_total_messages(&self) -> u64 {
         self.total_messages.load(Ordering::SeqCst)
     }
@@ -332,7 +383,10 @@ impl ZeusMetrics {
             memory_usage_mb: system.memory_usage_mb,
         };
         
-        history.push_back(snapshot);
+        // Al superar la capacidad se sobrescribe el más antiguo y se contabiliza
+        if history.push(snapshot) {
+            self.increment_snapshots_overwritten();
+        }
         
         // Mantener solo snapshots dentro del período de retención
         let cutoff = Utc::now() - chrono::Duration::hours(self.retention_hours as i64);
@@ -343,11 +397,6 @@ impl ZeusMetrics {
                 break;
             }
         }
-        
-        // Limitar tamaño máximo
-        while history.len() > 10000 {
-            history.pop_front();
-        }
     }
 
     pub async fn get_historical_data(
@@ -356,7 +405,9 @@ impl ZeusMetrics {
         limit: Option<usize>
     ) -> Vec<HistoricalSnapshot> {
         let history = self.historical_data.read().await;
-        let mut data: Vec<_> = history.iter().cloned().collect();
+        // Lo que supera la retención no se devuelve aunque el loop aún no lo haya purgado
+        let cutoff = Utc::now() - chrono::Duration::hours(self.retention_hours as i64);
+        let mut data: Vec<_> = history.iter().filter(|s| s.timestamp >= cutoff).cloned().collect();
         
         if let Some(since_time) = since {
             data.retain(|s| s.timestamp >= since_time);
@@ -563,6 +614,10 @@ impl ZeusMetrics {
         output.push_str(&format!("# TYPE olympus_recoveries_total counter\n"));
         output.push_str(&format!("olympus_recoveries_total {}\n\n", self.get_total_recoveries()));
         
+        output.push_str(&format!("# HELP olympus_snapshots_overwritten_total Metric snapshots overwritten in the bounded history\n"));
+        output.push_str(&format!("# TYPE olympus_snapshots_overwritten_total counter\n"));
+        output.push_str(&format!("olympus_snapshots_overwritten_total {}\n\n", self.get_snapshots_overwritten()));
+        
         output.push_str(&format!("# HELP olympus_error_rate Error rate percentage\n"));
         output.push_str(&format!("# TYPE olympus_error_rate gauge\n"));
         output.push_str(&format!("olympus_error_rate {:.4}\n\n", self.get_error_rate()));
@@ -627,7 +682,10 @@ impl ZeusMetrics {
         
         output.push_str(&format!("# HELP olympus_trinity_poseidon_health Poseidon health score\n"));
         output.push_str(&format!("# TYPE olympus_trinity_poseidon_health gauge\n"));
-        output.push_str(&format!("olympus_trinity_poseidon_health {:.2}\n", trinity.poseidon_health_score));
+        output.push_str(&format!("olympus_trinity_poseidon_health {:.2}\n\n", trinity.poseidon_health_score));
+        
+        // Comandos y queries que ningún dios atiende
+        output.push_str(&self.unsupported.export_prometheus_format());
         
         output
     }
@@ -651,6 +709,7 @@ impl ZeusMetrics {
             active_actors: actor_metrics.values().filter(|m| m.status == "running").count(),
             avg_recovery_time_ms: 0, // Se calcularía desde histórico
             dead_letters: self.total_dead_letters.load(Ordering::SeqCst),
+            snapshots_overwritten_total: self.get_snapshots_overwritten(),
             system_memory_mb: system.memory_usage_mb,
             system_cpu_percent: system.cpu_usage_percent,
             active_alerts: active_alerts.len(),
@@ -660,8 +719,10 @@ impl ZeusMetrics {
 
     // ==================== Background Tasks ====================
 
-    pub fn start_snapshot_loop(&self, interval_seconds: u64) {
+    /// Toma un snapshot cada `snapshot_interval_seconds`
+    pub fn start_snapshot_loop(&self) {
         let metrics = self.clone();
+        let interval_seconds = self.snapshot_interval_seconds.max(1);
         
         tokio::spawn(async move {
             let mut ticker = tokio::time::interval(tokio::time::Duration::from_secs(interval_seconds));
@@ -714,6 +775,7 @@ pub struct MetricsSummary {
     pub active_actors: usize,
     pub avg_recovery_time_ms: u64,
     pub dead_letters: u64,
+    pub snapshots_overwritten_total: u64,
     pub system_memory_mb: f64,
     pub system_cpu_percent: f64,
     pub active_alerts: usize,
@@ -736,3 +798,29 @@ impl Default for ZeusMetrics {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_history_evicts_beyond_retention_cap() {
+        let metrics = ZeusMetrics::with_history(10, 100, 24);
+
+        for _ in 0..150 {
+            metrics.increment_messages();
+            metrics.record_snapshot(20, 0).await;
+        }
+
+        let history = metrics.get_historical_data(None, None).await;
+        assert_eq!(history.len(), 100);
+        // Se descartaron los 50 más antiguos
+        assert_eq!(history.first().unwrap().total_messages, 51);
+        assert_eq!(metrics.get_historical_data(None, Some(10)).await.len(), 10);
+
+        let settings = metrics.history_settings().await;
+        assert_eq!((settings.snapshot_interval_seconds, settings.max_snapshots), (10, 100));
+        assert_eq!(settings.evicted_snapshots, 50);
+        assert_eq!(metrics.get_snapshots_overwritten(), 50);
+    }
+}
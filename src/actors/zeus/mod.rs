@@ -16,6 +16,7 @@ use crate::traits::{OlympianActor, ActorState, ActorConfig, ActorStatus, GodHear
 use crate::traits::message::{ActorMessage, MessagePayload, CommandPayload, QueryPayload, EventPayload, ResponsePayload, RecoveryStrategy};
 use crate::traits::supervisor_trait::{Supervisor, SupervisionTree, SupervisedActor, ActorSupervisionStatus};
 use crate::errors::ActorError;
+use crate::system::unsupported::UnsupportedCounts;
 
 pub mod thunder;
 pub mod supervisor;
@@ -25,9 +26,9 @@ pub mod config;
 
 pub use thunder::{Thunderbolt, ThunderEvent, ThunderSeverity};
 pub use supervisor::{SupervisionManager, LifecycleEvent, RestartResult};
-pub use metrics::{ZeusMetrics, AlertSeverity, TrinityMetrics, TrinityStatus};
+pub use metrics::{ZeusMetrics, AlertSeverity, HistorySettings, TrinityMetrics, TrinityStatus};
 pub use governance::{GovernanceController, GovernanceDecision, GovernanceSituation, CircuitState};
-pub use config::{ZeusConfig, ConfigManager, Environment};
+pub use config::{ZeusConfig, ConfigManager, Environment, ActiveIntervals};
 
 /// Comandos completos de Zeus
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -173,6 +174,7 @@ pub struct Zeus {
     state: ActorState,
     config: ActorConfig,
     zeus_config: Arc<RwLock<ZeusConfig>>,
+    active_intervals: Arc<RwLock<ActiveIntervals>>,
     
     // Componentes
     thunderbolt: Arc<Thunderbolt>,
@@ -203,6 +205,63 @@ pub struct Zeus {
     shutdown_signal: Arc<RwLock<Option<tokio::sync::oneshot::Sender<()>>>>,
 }
 
+/// Queries de métricas de Zeus sin pasar por su mailbox: comparten el colector
+/// con el actor, de modo que la API HTTP ve las mismas cifras
+#[derive(Debug, Clone)]
+pub struct ZeusMetricsQueries {
+    metrics: Arc<RwLock<ZeusMetrics>>,
+}
+
+impl ZeusMetricsQueries {
+    pub async fn unsupported(&self) -> UnsupportedCounts {
+        self.metrics.read().await.unsupported.clone()
+    }
+
+    pub async fn execute(&self, query: ZeusQuery) -> Result<ResponsePayload, ActorError> {
+        match query {
+            ZeusQuery::GetAllMetrics => {
+                let summary = self.metrics.read().await.get_summary().await;
+                let actor_metrics = self.metrics.read().await.get_all_actor_metrics().await;
+                let system = self.metrics.read().await.get_system_metrics().await;
+                
+                Ok(ResponsePayload::Data { 
+                    data: serde_json::json!({
+                        "summary": summary,
+                        "actors": actor_metrics,
+                        "system": system,
+                    })
+                })
+            }
+
+            ZeusQuery::GetActorMetrics { actor } => {
+                let metrics = self.metrics.read().await.get_actor_metrics(actor).await;
+                
+                if let Some(m) = metrics {
+                    Ok(ResponsePayload::Data { 
+                        data: serde_json::to_value(m).unwrap_or_default() 
+                    })
+                } else {
+                    Err(ActorError::NotFound { 
+                        god: actor, 
+                    })
+                }
+            }
+
+            ZeusQuery::GetHistoricalMetrics { since, limit } => {
+                let history = self.metrics.read().await.get_historical_data(since, limit).await;
+                Ok(ResponsePayload::Data { 
+                    data: serde_json::to_value(history).unwrap_or_default() 
+                })
+            }
+
+            other => Err(ActorError::InvalidQuery {
+                god: GodName::Zeus,
+                reason: format!("{:?} no es una query de métricas", other),
+            }),
+        }
+    }
+}
+
 impl Zeus {
     /// Constructor principal
     pub async fn new(zeus_config: ZeusConfig) -> Self {
@@ -212,16 +271,22 @@ impl Zeus {
         let (lifecycle_tx, lifecycle_rx) = mpsc::channel(1000);
         
         let config_manager = Arc::new(RwLock::new(ConfigManager::new(zeus_config.clone())));
+        let metrics = ZeusMetrics::with_history(
+            zeus_config.metrics_snapshot_interval_seconds,
+            zeus_config.metrics_snapshot_retention,
+            zeus_config.metrics_retention_hours,
+        );
         
         Self {
             name: GodName::Zeus,
             state: ActorState::new(GodName::Zeus),
             config: ActorConfig::default(),
             zeus_config: Arc::new(RwLock::new(zeus_config)),
+            active_intervals: Arc::new(RwLock::new(ActiveIntervals::default())),
             
             thunderbolt: Arc::new(Thunderbolt::new(thunder_tx)),
-            supervision_manager: Arc::new(RwLock::new(SupervisionManager::new())),
-            metrics: Arc::new(RwLock::new(ZeusMetrics::new())),
+            supervision_manager: Arc::new(RwLock::new(SupervisionManager::new().with_lifecycle_tx(lifecycle_tx.clone()))),
+            metrics: Arc::new(RwLock::new(metrics)),
             governance: Arc::new(RwLock::new(GovernanceController::new())),
             config_manager,
             
@@ -243,6 +308,16 @@ impl Zeus {
         }
     }
     
+    /// Acceso compartido a las métricas para la API (`GET /api/olympus/gods/:name/metrics`)
+    pub fn metrics_queries(&self) -> ZeusMetricsQueries {
+        ZeusMetricsQueries { metrics: self.metrics.clone() }
+    }
+
+    /// Contadores de mensajes no soportados, para entregarlos a cada `ActorRunner`
+    pub async fn unsupported_counts(&self) -> UnsupportedCounts {
+        self.metrics.read().await.unsupported.clone()
+    }
+    
     /// Constructor con configuración por ambiente
     pub async fn for_environment(env: Environment) -> Self {
         let config = match env {
@@ -296,14 +371,14 @@ impl Zeus {
         // Iniciar loop de sincronización de la Trinidad
         self.start_trinity_sync().await;
         
-        // Iniciar loop de métricas históricas
+        // Iniciar loop de métricas históricas (intervalo y retención propios)
         {
-            let metrics = self.metrics.clone();
-            let interval_secs = {
-                let config = self.zeus_config.read().await;
-                config.self_evaluation_interval_seconds
-            };
-            metrics.read().await.start_snapshot_loop(interval_secs);
+            let metrics = self.metrics.read().await;
+            let settings = metrics.history_settings().await;
+            let mut active = self.active_intervals.write().await;
+            active.metrics_snapshot_interval_seconds = Some(settings.snapshot_interval_seconds);
+            active.metrics_snapshot_retention = Some(settings.max_snapshots);
+            metrics.start_snapshot_loop();
         }
         
         // Marcar como corriendo
@@ -337,22 +412,18 @@ impl Zeus {
         Ok(())
     }
     
-    /// Loop de auto-evaluación cada 5 segundos
+    /// Loop de auto-evaluación (self_evaluation_interval_seconds)
     async fn start_self_evaluation(&self) {
         let metrics = self.metrics.clone();
         let governance = self.governance.clone();
         let supervision = self.supervision_manager.clone();
         let event_tx = self.event_tx.clone();
         let trinity_state = self.trinity_state.clone();
-        let zeus_config = self.zeus_config.clone();
+        let interval_secs = self.zeus_config.read().await.self_evaluation_interval_seconds;
+        self.active_intervals.write().await.self_evaluation_interval_seconds = Some(interval_secs);
         let running = self.running.clone();
         
         tokio::spawn(async move {
-            let interval_secs = {
-                let config = zeus_config.read().await;
-                config.self_evaluation_interval_seconds
-            };
-            
             let mut ticker = interval(Duration::from_secs(interval_secs));
             
             loop {
@@ -428,22 +499,18 @@ impl Zeus {
             }
         });
         
-        info!("⚡ Zeus: Self-evaluation loop started (interval: 5s)");
+        info!("⚡ Zeus: Self-evaluation loop started (interval: {}s)", interval_secs);
     }
     
     /// Loop de sincronización de la Trinidad (Zeus, Hades, Poseidón, Erinyes)
     async fn start_trinity_sync(&self) {
         let trinity_state = self.trinity_state.clone();
         let event_tx = self.event_tx.clone();
-        let zeus_config = self.zeus_config.clone();
+        let interval_secs = self.zeus_config.read().await.health_check_interval_seconds;
+        self.active_intervals.write().await.health_check_interval_seconds = Some(interval_secs);
         let running = self.running.clone();
         
         tokio::spawn(async move {
-            let interval_secs = {
-                let config = zeus_config.read().await;
-                config.health_check_interval_seconds
-            };
-            
             let mut ticker = interval(Duration::from_secs(interval_secs));
             
             loop {
@@ -513,12 +580,17 @@ impl Zeus {
                         });
                     }
                     LifecycleEvent::Failed { actor, error } => {
+                        crate::olympus_services::record_failure(&actor.to_string(), &error).await;
                         let _ = event_tx.send(ZeusEvent::ActorFailed { 
                             actor, 
                             error, 
                             timestamp: chrono::Utc::now() 
                         });
                     }
+                    LifecycleEvent::Restarted { actor, attempt } => {
+                        // Reinicios y último fallo visibles en /api/olympus/gods
+                        crate::olympus_services::record_restart(&actor.to_string(), attempt).await;
+                    }
                     _ => {}
                 }
             }
@@ -791,10 +863,7 @@ impl Zeus {
                 }
             }
             
-            _ => Ok(ResponsePayload::Error { 
-                error: "Command not supported by Zeus".to_string(), 
-                code: 400 
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Zeus, "command", &other)),
         }
     }
     
@@ -1027,10 +1096,7 @@ impl Zeus {
                                 data: serde_json::to_value(breakers).unwrap_or_default() 
                             })
                         }
-                        _ => Err(ActorError::InvalidQuery { 
-                            god: GodName::Zeus, 
-                            reason: format!("Unknown query type: {}", query_type) 
-                        })
+                        _ => Err(ActorError::unsupported(GodName::Zeus, "query", query_type))
                     }
                 } else {
                     Err(ActorError::InvalidQuery { 
@@ -1040,10 +1106,7 @@ impl Zeus {
                 }
             }
             
-            _ => Ok(ResponsePayload::Error { 
-                error: "Query not supported".to_string(), 
-                code: 400 
-            }),
+            other => Err(ActorError::unsupported_variant(GodName::Zeus, "query", &other)),
         }
     }
     
@@ -1103,40 +1166,9 @@ impl Zeus {
                 })
             }
             
-            ZeusQuery::GetAllMetrics => {
-                let summary = self.metrics.read().await.get_summary().await;
-                let actor_metrics = self.metrics.read().await.get_all_actor_metrics().await;
-                let system = self.metrics.read().await.get_system_metrics().await;
-                
-                Ok(ResponsePayload::Data { 
-                    data: serde_json::json!({
-                        "summary": summary,
-                        "actors": actor_metrics,
-                        "system": system,
-                    })
-                })
-            }
-            
-            ZeusQuery::GetActorMetrics { actor } => {
-                let metrics = self.metrics.read().await.get_actor_metrics(actor).await;
-                
-                if let Some(m) = metrics {
-                    Ok(ResponsePayload::Data { 
-                        data: serde_json::to_value(m).unwrap_or_default() 
-                    })
-                } else {
-                    Err(ActorError::NotFound { 
-                        god: actor, 
-                    })
-                }
-            }
-            
-            ZeusQuery::GetHistoricalMetrics { since, limit } => {
-                let history = self.metrics.read().await.get_historical_data(since, limit).await;
-                Ok(ResponsePayload::Data { 
-                    data: serde_json::to_value(history).unwrap_or_default() 
-                })
-            }
+            query @ (ZeusQuery::GetAllMetrics
+            | ZeusQuery::GetActorMetrics { .. }
+            | ZeusQuery::GetHistoricalMetrics { .. }) => self.metrics_queries().execute(query).await,
             
             ZeusQuery::GetGovernanceHistory { limit } => {
                 let history = self.governance.read().await.get_history(limit).await;
@@ -1174,12 +1206,28 @@ impl Zeus {
             }
             
             ZeusQuery::GetConfig => {
-                let config = self.zeus_config.read().await.clone();
+                let config = self.zeus_config.read().await.redacted();
+                let active = self.active_intervals.read().await.clone();
                 Ok(ResponsePayload::Data { 
-                    data: serde_json::to_value(config).unwrap_or_default() 
+                    data: serde_json::json!({
+                        "config": config,
+                        "active_intervals": active,
+                    })
                 })
             }
             
+            ZeusQuery::GetConfigValue { key } => {
+                match self.zeus_config.read().await.redacted_value(&key) {
+                    Some(value) => Ok(ResponsePayload::Data { 
+                        data: serde_json::json!({ "key": key, "value": value })
+                    }),
+                    None => Ok(ResponsePayload::Error { 
+                        error: format!("Unknown config key: {}", key), 
+                        code: 404 
+                    }),
+                }
+            }
+            
             _ => Ok(ResponsePayload::Error { 
                 error: "ZeusQuery not yet implemented".to_string(), 
                 code: 501 
@@ -1285,6 +1333,8 @@ impl Supervisor for Zeus {
                 status: ActorSupervisionStatus::Running,
                 restarts: 0,
                 last_restart: None,
+                last_failure: None,
+                last_failure_at: None,
                 strategy: RecoveryStrategy::OneForOne,
                 children: vec![],
             },
@@ -114,6 +114,12 @@ impl SupervisionManager {
         }
     }
     
+    /// Envía los eventos del ciclo de vida al procesador de Zeus en lugar de descartarlos
+    pub fn with_lifecycle_tx(mut self, lifecycle_tx: mpsc::Sender<LifecycleEvent>) -> Self {
+        self.lifecycle_tx = lifecycle_tx;
+        self
+    }
+    
     /// Registra un actor en el árbol de supervisión
     pub async fn register_actor(
         &self, 
@@ -132,6 +138,8 @@ impl SupervisionManager {
             status: ActorSupervisionStatus::Starting,
             restarts: 0,
             last_restart: None,
+            last_failure: None,
+            last_failure_at: None,
             strategy: strategy.clone(),
             children: Vec::new(),
         };
@@ -299,6 +307,10 @@ impl SupervisionManager {
         let auto_recovery = *self.auto_recovery.read().await;
         
         self.update_status(actor, ActorSupervisionStatus::Failed).await;
+        if let Some(a) = self.actors.write().await.get_mut(&actor) {
+            a.last_failure = Some(error.clone());
+            a.last_failure_at = Some(Utc::now());
+        }
         
         let _ = self.lifecycle_tx.send(LifecycleEvent::Failed { 
             actor, 
@@ -430,6 +442,8 @@ impl SupervisionManager {
                 status: ActorSupervisionStatus::Running,
                 restarts: 0,
                 last_restart: None,
+                last_failure: None,
+                last_failure_at: None,
                 strategy: RecoveryStrategy::OneForOne,
                 children: deps.get(&GodName::Zeus).cloned().unwrap_or_default(),
             },
@@ -579,6 +593,11 @@ impl SupervisionManager {
         actors.iter().map(|(name, actor)| (*name, actor.status.clone())).collect()
     }
     
+    /// Estado de supervisión de un actor: reinicios, último reinicio y último fallo
+    pub async fn get_actor(&self, actor: GodName) -> Option<SupervisedActor> {
+        self.actors.read().await.get(&actor).cloned()
+    }
+    
     /// Obtiene actores por estado
     pub async fn get_actors_by_status(&self, status: ActorSupervisionStatus) -> Vec<GodName> {
         let actors = self.actors.read().await;
@@ -650,3 +669,33 @@ impl Default for SupervisionManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_restarts_and_last_failure_are_tracked() {
+        let (lifecycle_tx, mut lifecycle_rx) = mpsc::channel(100);
+        let manager = SupervisionManager::new().with_lifecycle_tx(lifecycle_tx);
+        manager.register_actor(GodName::Athena, None, RecoveryStrategy::OneForOne).await.unwrap();
+
+        for error in ["timeout en cálculo SOFA", "buzón cerrado", "panic en handler NEWS2"] {
+            manager.mark_failed(GodName::Athena, error.to_string()).await.unwrap();
+        }
+
+        let athena = manager.get_actor(GodName::Athena).await.unwrap();
+        assert_eq!(athena.restarts, 3);
+        assert!(athena.last_restart.is_some());
+        assert_eq!(athena.last_failure.as_deref(), Some("panic en handler NEWS2"));
+
+        // Los eventos llegan al canal de Zeus
+        let mut restarted = 0;
+        while let Ok(event) = lifecycle_rx.try_recv() {
+            if matches!(event, LifecycleEvent::Restarted { .. }) {
+                restarted += 1;
+            }
+        }
+        assert_eq!(restarted, 3);
+    }
+}
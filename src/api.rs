@@ -0,0 +1,146 @@
+// API del servidor: respuestas tipadas y errores con su código HTTP
+// Éxitos y errores comparten la forma `{ "success": bool, ... }`
+
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::Serialize;
+
+pub type ApiResult<T> = Result<Json<T>, ApiError>;
+
+/// Error de un handler: se serializa como `{ "success": false, "error": "..." }`
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub message: String,
+}
+
+impl ApiError {
+    pub fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self { status, message: message.into() }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, message)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::NOT_FOUND, message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::INTERNAL_SERVER_ERROR, message)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ErrorBody {
+    pub success: bool,
+    pub error: String,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = ErrorBody { success: false, error: self.message };
+        (self.status, Json(body)).into_response()
+    }
+}
+
+/// Registro de paciente tal como lo devuelve SurrealDB (esquema flexible)
+pub type PatientRecord = serde_json::Value;
+
+/// El id se expone como texto canónico `patient:<ULID>` (igual que el servidor de actores).
+/// Los ULID ordenan por fecha de creación.
+pub const SELECT_PATIENTS: &str = "SELECT *, <string> id AS id FROM patient ORDER BY id";
+pub const SELECT_PATIENT_BY_ID: &str = "SELECT *, <string> id AS id FROM patient WHERE id = $id";
+
+/// Acepta el id canónico (`patient:<ULID>`) o solo la clave
+pub fn canonical_patient_id(id: &str) -> String {
+    if id.starts_with("patient:") {
+        id.to_string()
+    } else {
+        format!("patient:{}", id)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PatientsResponse {
+    pub success: bool,
+    pub patients: Vec<PatientRecord>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PatientResponse {
+    pub success: bool,
+    pub patient: PatientRecord,
+}
+
+/// Evaluación de una escala tal como la devuelve SurrealDB
+pub type AssessmentRecord = serde_json::Value;
+
+/// Última evaluación de una tabla de escala para un paciente (`$table` validada por SurrealDB)
+pub const SELECT_LATEST_ASSESSMENT: &str = "SELECT *, <string> id AS id FROM type::table($table) \
+    WHERE patient_id = $patient_id ORDER BY assessed_at DESC LIMIT 1";
+
+/// Última evaluación de cada escala; `null` si nunca se registró
+#[derive(Debug, Serialize)]
+pub struct LatestAssessmentsResponse {
+    pub success: bool,
+    pub patient_id: String,
+    pub glasgow: Option<AssessmentRecord>,
+    pub sofa: Option<AssessmentRecord>,
+    pub apache: Option<AssessmentRecord>,
+    pub saps: Option<AssessmentRecord>,
+    pub news2: Option<AssessmentRecord>,
+}
+
+/// Paciente de demostración cuando SurrealDB no está disponible
+#[derive(Debug, Clone, Serialize)]
+pub struct DemoPatient {
+    pub id: &'static str,
+    pub first_name: &'static str,
+    pub last_name: &'static str,
+    pub principal_diagnosis: &'static str,
+    pub gender: &'static str,
+    pub date_of_birth: &'static str,
+}
+
+pub const DEMO_PATIENTS: [DemoPatient; 2] = [
+    DemoPatient {
+        id: "patient:1",
+        first_name: "Juan",
+        last_name: "Pérez",
+        principal_diagnosis: "Neumonía",
+        gender: "Male",
+        date_of_birth: "1960-05-15",
+    },
+    DemoPatient {
+        id: "patient:2",
+        first_name: "María",
+        last_name: "García",
+        principal_diagnosis: "Postquirúrgico",
+        gender: "Female",
+        date_of_birth: "1975-08-22",
+    },
+];
+
+impl DemoPatient {
+    pub fn record(&self) -> PatientRecord {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoginResponse {
+    pub success: bool,
+    pub token: String,
+    pub user_id: String,
+    pub role: String,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LogoutResponse {
+    pub success: bool,
+    pub message: String,
+}
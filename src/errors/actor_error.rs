@@ -42,6 +42,10 @@ pub enum ActorError {
     #[error("Invalid query for actor {god}: {reason}")]
     InvalidQuery { god: GodName, reason: String },
 
+    /// Variante de comando o query que el dios no implementa (se cuenta en las métricas)
+    #[error("Unsupported {kind} '{variant}' for actor {god}")]
+    Unsupported { god: GodName, kind: String, variant: String },
+
     #[error("State error in actor {god}: {message}")]
     StateError { god: GodName, message: String },
 
@@ -94,6 +98,20 @@ impl ActorError {
         }
     }
 
+    /// `kind` es "command" o "query"; `variant`, la variante o acción que no se atiende
+    pub fn unsupported(god: GodName, kind: &str, variant: impl Into<String>) -> Self {
+        Self::Unsupported {
+            god,
+            kind: kind.to_string(),
+            variant: variant.into(),
+        }
+    }
+
+    /// Igual que `unsupported`, con el nombre de la variante del enum recibido
+    pub fn unsupported_variant(god: GodName, kind: &str, value: &impl Serialize) -> Self {
+        Self::unsupported(god, kind, variant_name(value))
+    }
+
     pub fn validation_error(god: GodName, reason: &str) -> Self {
         Self::ValidationError {
             god,
@@ -110,3 +128,13 @@ impl From<usize> for ActorError {
         }
     }
 }
+
+/// Nombre de la variante de un enum serializado con el formato por defecto de serde
+/// (`"Variant"` o `{"Variant": ...}`)
+fn variant_name(value: &impl Serialize) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        Ok(serde_json::Value::Object(map)) if map.len() == 1 => map.keys().next().cloned().unwrap_or_default(),
+        _ => "unknown".to_string(),
+    }
+}
@@ -4,6 +4,7 @@ pub mod apache_form;
 pub mod app;
 pub mod components;
 pub mod dashboard;
+pub mod form_draft;
 pub mod glasgow_form;
 pub mod i18n;
 pub mod login;
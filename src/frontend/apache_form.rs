@@ -1,6 +1,7 @@
 use crate::frontend::components::export_button::ExportButton;
+use crate::frontend::form_draft::{DraftRestoreBanner, FormDraft};
 use crate::frontend::i18n::{t, use_i18n};
-use crate::uci::scale::apache::{ApacheIIRequest, ApacheIIResponse};
+use crate::uci::scale::apache::{ApacheIIRequest, ApacheIIResponse, AA_GRADIENT_FIO2_THRESHOLD};
 use leptos::*;
 use leptos_router::use_params_map;
 use reqwasm::http::Request;
@@ -19,6 +20,7 @@ pub fn ApacheForm() -> impl IntoView {
     let (respiratory_rate, set_respiratory_rate) = create_signal(16i32);
     let (oxygenation_type, set_oxygenation_type) = create_signal("pao2".to_string());
     let (oxygenation_value, set_oxygenation_value) = create_signal(80i32);
+    let (fio2, set_fio2) = create_signal(0.21f32);
     let (arterial_ph, set_arterial_ph) = create_signal(7.4f32);
     let (serum_sodium, set_serum_sodium) = create_signal(140i32);
     let (serum_potassium, set_serum_potassium) = create_signal(4.0f32);
@@ -32,6 +34,56 @@ pub fn ApacheForm() -> impl IntoView {
     // Signal to store the result
     let (result, set_result) = create_signal(Option::<ApacheIIResponse>::None);
     let (loading, set_loading) = create_signal(false);
+    let (error, set_error) = create_signal(Option::<String>::None);
+
+    // Current form state as an API request
+    let build_request = move || ApacheIIRequest {
+        temperature: temperature.get(),
+        mean_arterial_pressure: mean_arterial_pressure.get(),
+        heart_rate: heart_rate.get(),
+        respiratory_rate: respiratory_rate.get(),
+        oxygenation_type: oxygenation_type.get(),
+        oxygenation_value: oxygenation_value.get(),
+        fio2: fio2.get(),
+        arterial_ph: arterial_ph.get(),
+        serum_sodium: serum_sodium.get(),
+        serum_potassium: serum_potassium.get(),
+        serum_creatinine: serum_creatinine.get(),
+        hematocrit: hematocrit.get(),
+        white_blood_count: white_blood_count.get(),
+        glasgow_coma_score: glasgow_coma_score.get(),
+        age: age.get(),
+        chronic_health: chronic_health.get(),
+        patient_id: patient_id(),
+    };
+
+    // Auto-save draft on input (debounced); skip the initial defaults
+    let draft = FormDraft::<ApacheIIRequest>::new("apache", patient_id());
+    create_effect(move |prev: Option<()>| {
+        let request = build_request();
+        if prev.is_some() {
+            draft.save_debounced(request);
+        }
+    });
+
+    let restore_draft = move |d: ApacheIIRequest| {
+        set_temperature.set(d.temperature);
+        set_mean_arterial_pressure.set(d.mean_arterial_pressure);
+        set_heart_rate.set(d.heart_rate);
+        set_respiratory_rate.set(d.respiratory_rate);
+        set_oxygenation_type.set(d.oxygenation_type);
+        set_oxygenation_value.set(d.oxygenation_value);
+        set_fio2.set(d.fio2);
+        set_arterial_ph.set(d.arterial_ph);
+        set_serum_sodium.set(d.serum_sodium);
+        set_serum_potassium.set(d.serum_potassium);
+        set_serum_creatinine.set(d.serum_creatinine);
+        set_hematocrit.set(d.hematocrit);
+        set_white_blood_count.set(d.white_blood_count);
+        set_glasgow_coma_score.set(d.glasgow_coma_score);
+        set_age.set(d.age);
+        set_chronic_health.set(d.chronic_health);
+    };
 
     // Smart Pre-fill Logic
     create_effect(move |_| {
@@ -89,25 +141,9 @@ pub fn ApacheForm() -> impl IntoView {
     // Calculate function
     let calculate = move |_| {
         set_loading.set(true);
+        set_error.set(None);
 
-        let request = ApacheIIRequest {
-            temperature: temperature.get(),
-            mean_arterial_pressure: mean_arterial_pressure.get(),
-            heart_rate: heart_rate.get(),
-            respiratory_rate: respiratory_rate.get(),
-            oxygenation_type: oxygenation_type.get(),
-            oxygenation_value: oxygenation_value.get(),
-            arterial_ph: arterial_ph.get(),
-            serum_sodium: serum_sodium.get(),
-            serum_potassium: serum_potassium.get(),
-            serum_creatinine: serum_creatinine.get(),
-            hematocrit: hematocrit.get(),
-            white_blood_count: white_blood_count.get(),
-            glasgow_coma_score: glasgow_coma_score.get(),
-            age: age.get(),
-            chronic_health: chronic_health.get(),
-            patient_id: patient_id(),
-        };
+        let request = build_request();
 
         spawn_local(async move {
             let token: Option<String> = window()
@@ -132,7 +168,18 @@ pub fn ApacheForm() -> impl IntoView {
                     if resp.ok() {
                         if let Ok(data) = resp.json::<ApacheIIResponse>().await {
                             set_result.set(Some(data));
+                            draft.clear();
                         }
+                    } else {
+                        // 422: los datos no se pueden convertir en una puntuación
+                        let message = resp
+                            .json::<serde_json::Value>()
+                            .await
+                            .ok()
+                            .and_then(|body| body["error"].as_str().map(str::to_string))
+                            .unwrap_or_else(|| format!("Error {}", resp.status()));
+                        set_result.set(None);
+                        set_error.set(Some(message));
                     }
                 }
                 Err(_) => {}
@@ -152,6 +199,8 @@ pub fn ApacheForm() -> impl IntoView {
                     <p class="text-sm text-gray-600 mt-1">{move || t(lang.get(), "apache_subtitle")}</p>
                 </div>
 
+                <DraftRestoreBanner draft=draft on_restore=restore_draft/>
+
                 // Calculate Button (Top)
                 <div class="flex justify-center mb-6">
                     <button
@@ -203,6 +252,11 @@ pub fn ApacheForm() -> impl IntoView {
 
                 // Results (Top)
                 <div class="min-h-[100px] mb-8">
+                    {move || error.get().map(|message| view! {
+                        <div class="p-4 mb-4 rounded-xl bg-red-50 border border-red-300 text-red-700 text-sm">
+                            <i class="fas fa-exclamation-triangle mr-2"></i>{message}
+                        </div>
+                    })}
                     {move || {
                         if let Some(data) = result.get() {
                             let (bg_color, text_color) = if data.score < 10 {
@@ -294,7 +348,8 @@ pub fn ApacheForm() -> impl IntoView {
                                                         "respiratory_rate": respiratory_rate.get(),
                                                         "oxygenation": {
                                                             "type": oxygenation_type.get(),
-                                                            "value": oxygenation_value.get()
+                                                            "value": oxygenation_value.get(),
+                                                            "fio2": fio2.get()
                                                         },
                                                         "ph": arterial_ph.get(),
                                                         "sodium": serum_sodium.get(),
@@ -384,10 +439,26 @@ pub fn ApacheForm() -> impl IntoView {
                             <i class="fas fa-lungs text-blue-600 mr-2"></i>{move || t(lang.get(), "oxygenation_ph")}
                         </h3>
                         <div class="space-y-6">
+                            <div>
+                                <label class="flex justify-between text-sm font-medium text-gray-700 mb-1">
+                                    <span class="flex items-center"><i class="fas fa-wind text-blue-500 mr-2 w-5 text-center"></i>"FiO2"</span>
+                                    <span class="text-blue-600 font-bold">{move || format!("{:.2}", fio2.get())}</span>
+                                </label>
+                                // FiO2 decides the branch: A-aDO2 from 0.5, PaO2 below
+                                <input type="range" min="0.21" max="1.0" step="0.01"
+                                    prop:value=move || fio2.get()
+                                    on:input=move |ev| {
+                                        let value: f32 = event_target_value(&ev).parse().unwrap_or(0.21);
+                                        set_fio2.set(value);
+                                        set_oxygenation_type.set(if value >= AA_GRADIENT_FIO2_THRESHOLD { "aa_gradient" } else { "pao2" }.to_string());
+                                    }
+                                    class="w-full h-2 bg-gray-200 rounded-lg appearance-none cursor-pointer accent-blue-600"/>
+                            </div>
                             <div>
                                 <label class="block text-sm font-medium text-gray-700 mb-1 flex items-center"><i class="fas fa-sliders-h text-blue-500 mr-2 w-5 text-center"></i>{move || t(lang.get(), "oxygenation_type")}</label>
                                 <select
                                     on:change=move |ev| set_oxygenation_type.set(event_target_value(&ev))
+                                    prop:value=move || oxygenation_type.get()
                                     class="w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-blue-500">
                                     <option value="pao2" selected>{move || t(lang.get(), "pao2")}</option>
                                     <option value="aa_gradient">{move || t(lang.get(), "a_a_gradient")}</option>
@@ -505,6 +576,7 @@ pub fn ApacheForm() -> impl IntoView {
                                 <label class="block text-sm font-medium text-gray-700 mb-1 flex items-center"><i class="fas fa-procedures text-indigo-500 mr-2 w-5 text-center"></i>{move || t(lang.get(), "chronic_health")}</label>
                                 <select
                                     on:change=move |ev| set_chronic_health.set(event_target_value(&ev))
+                                    prop:value=move || chronic_health.get()
                                     class="w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-indigo-500">
                                     <option value="none" selected>{move || t(lang.get(), "none")}</option>
                                     <option value="elective">{move || t(lang.get(), "elective_surgery")}</option>
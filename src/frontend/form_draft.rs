@@ -0,0 +1,132 @@
+use crate::frontend::i18n::{t, use_i18n};
+use gloo_timers::callback::Timeout;
+use leptos::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Delay between the last input and the draft being written
+const SAVE_DEBOUNCE_MS: u32 = 500;
+
+fn storage() -> Option<web_sys::Storage> {
+    window().local_storage().ok().flatten()
+}
+
+/// Auto-saved draft of an in-progress scale form, kept in localStorage
+/// under a patient+scale key until the form is successfully submitted.
+pub struct FormDraft<T: 'static> {
+    key: StoredValue<String>,
+    pending: RwSignal<Option<T>>,
+    timer: StoredValue<Option<Timeout>>,
+}
+
+impl<T: 'static> Clone for FormDraft<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for FormDraft<T> {}
+
+impl<T> FormDraft<T>
+where
+    T: Serialize + DeserializeOwned + Clone + 'static,
+{
+    /// Looks up a previous draft; if found it is offered for restore
+    pub fn new(scale: &str, patient_id: Option<String>) -> Self {
+        let key = format!(
+            "uci_draft:{}:{}",
+            scale,
+            patient_id.unwrap_or_else(|| "new".to_string())
+        );
+        let existing = storage()
+            .and_then(|s| s.get_item(&key).ok().flatten())
+            .and_then(|json| serde_json::from_str::<T>(&json).ok());
+
+        Self {
+            key: store_value(key),
+            pending: create_rw_signal(existing),
+            timer: store_value(None),
+        }
+    }
+
+    /// Draft waiting for the user to restore or discard it
+    pub fn pending(&self) -> Option<T> {
+        self.pending.get()
+    }
+
+    /// Schedules a save; each call resets the debounce timer.
+    /// Skipped while a previous draft is still on offer so it isn't overwritten.
+    pub fn save_debounced(&self, value: T) {
+        if self.pending.with_untracked(|p| p.is_some()) {
+            return;
+        }
+
+        let key = self.key.get_value();
+        let timeout = Timeout::new(SAVE_DEBOUNCE_MS, move || {
+            if let (Some(storage), Ok(json)) = (storage(), serde_json::to_string(&value)) {
+                let _ = storage.set_item(&key, &json);
+            }
+        });
+        // Replacing the handle drops (and cancels) the previous timer
+        self.timer.set_value(Some(timeout));
+    }
+
+    /// Accepts the offered draft; the caller applies it to the form
+    pub fn take(&self) -> Option<T> {
+        let draft = self.pending.get_untracked();
+        self.pending.set(None);
+        draft
+    }
+
+    /// Rejects the offered draft and removes it from storage
+    pub fn discard(&self) {
+        self.pending.set(None);
+        self.clear();
+    }
+
+    /// Removes the stored draft (after a successful submit)
+    pub fn clear(&self) {
+        self.timer.set_value(None);
+        if let Some(storage) = storage() {
+            let _ = storage.remove_item(&self.key.get_value());
+        }
+    }
+}
+
+/// Banner offering to restore an auto-saved draft
+#[component]
+pub fn DraftRestoreBanner<T, R>(draft: FormDraft<T>, on_restore: R) -> impl IntoView
+where
+    T: Serialize + DeserializeOwned + Clone + 'static,
+    R: Fn(T) + Copy + 'static,
+{
+    let lang = use_i18n();
+
+    move || {
+        draft.pending().map(|_| {
+            view! {
+                <div class="mb-6 p-4 bg-amber-50 border-l-4 border-amber-500 rounded-r-lg shadow-sm flex flex-col md:flex-row md:items-center justify-between gap-3">
+                    <div class="flex items-center text-amber-800 text-sm">
+                        <i class="fas fa-history mr-2"></i>
+                        {move || t(lang.get(), "draft_found")}
+                    </div>
+                    <div class="flex gap-2">
+                        <button
+                            on:click=move |_| {
+                                if let Some(value) = draft.take() {
+                                    on_restore(value);
+                                }
+                            }
+                            class="px-4 py-2 bg-amber-500 text-white text-sm font-semibold rounded-lg hover:bg-amber-600">
+                            <i class="fas fa-undo mr-1"></i>{move || t(lang.get(), "draft_restore")}
+                        </button>
+                        <button
+                            on:click=move |_| draft.discard()
+                            class="px-4 py-2 bg-white border border-amber-300 text-amber-800 text-sm rounded-lg hover:bg-amber-100">
+                            {move || t(lang.get(), "draft_discard")}
+                        </button>
+                    </div>
+                </div>
+            }
+        })
+    }
+}
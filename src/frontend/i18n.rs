@@ -87,6 +87,18 @@ pub fn t(lang: Language, key: &str) -> String {
         (Language::En, "recommendation") => "Recommendation".to_string(),
         (Language::Es, "recommendation") => "Recomendación".to_string(),
 
+        // Form drafts
+        (Language::En, "draft_found") => {
+            "An unsaved draft of this form was found. Restore it?".to_string()
+        }
+        (Language::Es, "draft_found") => {
+            "Se encontró un borrador sin enviar de este formulario. ¿Restaurarlo?".to_string()
+        }
+        (Language::En, "draft_restore") => "Restore".to_string(),
+        (Language::Es, "draft_restore") => "Restaurar".to_string(),
+        (Language::En, "draft_discard") => "Discard".to_string(),
+        (Language::Es, "draft_discard") => "Descartar".to_string(),
+
         // APACHE II Fields
         (Language::En, "apache_title") => "APACHE II Score".to_string(),
         (Language::Es, "apache_title") => "Puntaje APACHE II".to_string(),
@@ -347,6 +359,16 @@ pub fn t(lang: Language, key: &str) -> String {
         (Language::Es, "other") => "Otro".to_string(),
         (Language::En, "skin_color") => "Skin Color".to_string(),
         (Language::Es, "skin_color") => "Color de Piel".to_string(),
+        (Language::En, "skin_very_fair") => "Very Fair".to_string(),
+        (Language::Es, "skin_very_fair") => "Muy Clara".to_string(),
+        (Language::En, "skin_fair") => "Fair".to_string(),
+        (Language::Es, "skin_fair") => "Clara".to_string(),
+        (Language::En, "skin_olive") => "Olive".to_string(),
+        (Language::Es, "skin_olive") => "Trigueña".to_string(),
+        (Language::En, "skin_brown") => "Brown".to_string(),
+        (Language::Es, "skin_brown") => "Morena".to_string(),
+        (Language::En, "skin_dark_brown") => "Dark Brown".to_string(),
+        (Language::Es, "skin_dark_brown") => "Morena Oscura".to_string(),
         (Language::En, "white") => "White".to_string(),
         (Language::Es, "white") => "Blanco".to_string(),
         (Language::En, "mixed") => "Mixed/Moreno".to_string(),
@@ -444,6 +466,28 @@ pub fn t(lang: Language, key: &str) -> String {
         (Language::En, "cr_gte_5") => "≥5.0 mg/dL".to_string(),
         (Language::Es, "cr_gte_5") => "≥5.0 mg/dL".to_string(),
 
+        // SOFA Interpretation
+        (Language::En, "sofa_severity_mild") => "Mild organ failure".to_string(),
+        (Language::Es, "sofa_severity_mild") => "Falla orgánica leve".to_string(),
+        (Language::En, "sofa_recommendation_mild") => "Mortality < 10%. Continuous monitoring.".to_string(),
+        (Language::Es, "sofa_recommendation_mild") => "Mortalidad < 10%. Monitoreo continuo.".to_string(),
+        (Language::En, "sofa_severity_moderate") => "Moderate organ failure".to_string(),
+        (Language::Es, "sofa_severity_moderate") => "Falla orgánica moderada".to_string(),
+        (Language::En, "sofa_recommendation_moderate") => "Mortality 15-20%. Close monitoring.".to_string(),
+        (Language::Es, "sofa_recommendation_moderate") => "Mortalidad 15-20%. Vigilancia estrecha.".to_string(),
+        (Language::En, "sofa_severity_severe") => "Severe organ failure".to_string(),
+        (Language::Es, "sofa_severity_severe") => "Falla orgánica severa".to_string(),
+        (Language::En, "sofa_recommendation_severe") => "Mortality 40-50%. Maximum intensive care.".to_string(),
+        (Language::Es, "sofa_recommendation_severe") => "Mortalidad 40-50%. Cuidados intensivos máximos.".to_string(),
+        (Language::En, "sofa_severity_very_severe") => "Very severe organ failure".to_string(),
+        (Language::Es, "sofa_severity_very_severe") => "Falla orgánica muy severa".to_string(),
+        (Language::En, "sofa_recommendation_very_severe") => "Mortality > 50%. Consider advanced therapies.".to_string(),
+        (Language::Es, "sofa_recommendation_very_severe") => "Mortalidad > 50%. Considerar terapias avanzadas.".to_string(),
+        (Language::En, "sofa_severity_critical") => "Critical organ failure".to_string(),
+        (Language::Es, "sofa_severity_critical") => "Falla orgánica crítica".to_string(),
+        (Language::En, "sofa_recommendation_critical") => "Mortality > 80%. Very guarded prognosis.".to_string(),
+        (Language::Es, "sofa_recommendation_critical") => "Mortalidad > 80%. Pronóstico muy reservado.".to_string(),
+
         // Login Page
         (Language::En, "secure_access") => "Secure Access".to_string(),
         (Language::Es, "secure_access") => "Acceso Seguro".to_string(),
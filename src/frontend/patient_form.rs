@@ -22,12 +22,12 @@ pub fn PatientForm() -> impl IntoView {
     // New fields
     let (hospital_admission, set_hospital_admission) = create_signal(String::new());
     let (uci_admission, set_uci_admission) = create_signal(String::new());
-    let (skin_color, set_skin_color) = create_signal("White".to_string());
+    let (skin_color, set_skin_color) = create_signal(SkinColor::default().as_str().to_string());
     let (diagnosis, set_diagnosis) = create_signal(String::new());
     let (mech_vent, set_mech_vent) = create_signal(false);
     let (uci_hist, set_uci_hist) = create_signal(false);
     let (transfer, set_transfer) = create_signal(false);
-    let (admission_type, set_admission_type) = create_signal("Urgent".to_string());
+    let (admission_type, set_admission_type) = create_signal(AdmissionType::default().as_str().to_string());
     let (invasive, set_invasive) = create_signal(false);
 
     let (submit_status, set_submit_status) = create_signal(Option::<String>::None);
@@ -65,20 +65,12 @@ pub fn PatientForm() -> impl IntoView {
                                     .unwrap_or_default()
                                     .to_string(),
                             );
-                            set_skin_color.set(match p.skin_color {
-                                SkinColor::White => "White".to_string(),
-                                SkinColor::Mixed => "Mixed".to_string(),
-                                SkinColor::Black => "Black".to_string(),
-                            });
+                            set_skin_color.set(p.skin_color.as_str().to_string());
                             set_diagnosis.set(p.principal_diagnosis);
                             set_mech_vent.set(p.mechanical_ventilation);
                             set_uci_hist.set(p.uci_history);
                             set_transfer.set(p.transfer_from_other_center);
-                            set_admission_type.set(match p.admission_type {
-                                AdmissionType::Urgent => "Urgent".to_string(),
-                                AdmissionType::Programmed => "Programmed".to_string(),
-                                AdmissionType::Transfer => "Transfer".to_string(),
-                            });
+                            set_admission_type.set(p.admission_type.as_str().to_string());
                             set_invasive.set(p.invasive_processes);
                         }
                     }
@@ -109,17 +101,8 @@ pub fn PatientForm() -> impl IntoView {
     let on_submit = move |ev: leptos::ev::SubmitEvent| {
         ev.prevent_default();
 
-        let skin_enum = match skin_color.get().as_str() {
-            "Mixed" => SkinColor::Mixed,
-            "Black" => SkinColor::Black,
-            _ => SkinColor::White,
-        };
-
-        let adm_enum = match admission_type.get().as_str() {
-            "Programmed" => AdmissionType::Programmed,
-            "Transfer" => AdmissionType::Transfer,
-            _ => AdmissionType::Urgent,
-        };
+        let skin_enum: SkinColor = skin_color.get().parse().unwrap_or_default();
+        let adm_enum: AdmissionType = admission_type.get().parse().unwrap_or_default();
 
         // Ensure dates are in ISO 8601 format (simplified for this context, assuming standard date input YYYY-MM-DD)
         // We append T00:00:00Z for simplicity as the input type="date" returns YYYY-MM-DD
@@ -261,9 +244,9 @@ pub fn PatientForm() -> impl IntoView {
                                     </label>
                                     <select class="w-full rounded-lg border-gray-300 shadow-sm focus:border-indigo-500 focus:ring-indigo-500 py-2 px-3 cursor-pointer"
                                         prop:value=skin_color on:change=move |ev| set_skin_color.set(event_target_value(&ev))>
-                                        <option value="White">{move || format!("⚪ {}", t(lang.get(), "white"))}</option>
-                                        <option value="Mixed">{move || format!("🏽 {}", t(lang.get(), "mixed"))}</option>
-                                        <option value="Black">{move || format!("⚫ {}", t(lang.get(), "black"))}</option>
+                                        {SkinColor::ALL.into_iter().map(|c| view! {
+                                            <option value=c.as_str()>{move || t(lang.get(), c.label_key())}</option>
+                                        }).collect_view()}
                                     </select>
                                 </div>
                             </div>
@@ -312,9 +295,9 @@ pub fn PatientForm() -> impl IntoView {
                                 </label>
                                 <select class="w-full rounded-lg border-gray-300 shadow-sm focus:border-teal-500 focus:ring-teal-500 py-2 px-3 cursor-pointer"
                                     prop:value=admission_type on:change=move |ev| set_admission_type.set(event_target_value(&ev))>
-                                    <option value="Urgent">{move || format!("🚑 {}", t(lang.get(), "urgent"))}</option>
-                                    <option value="Programmed">{move || format!("📅 {}", t(lang.get(), "programmed"))}</option>
-                                    <option value="Transfer">{move || format!("🔄 {}", t(lang.get(), "transfer"))}</option>
+                                    {AdmissionType::ALL.into_iter().map(|a| view! {
+                                        <option value=a.as_str()>{move || t(lang.get(), a.label_key())}</option>
+                                    }).collect_view()}
                                 </select>
                             </div>
 
@@ -1,4 +1,5 @@
 use crate::frontend::components::export_button::ExportButton;
+use crate::frontend::form_draft::{DraftRestoreBanner, FormDraft};
 use crate::frontend::i18n::{t, use_i18n};
 use crate::uci::scale::saps::{SAPSIIRequest, SAPSIIResponse};
 use leptos::*;
@@ -37,6 +38,60 @@ pub fn SapsForm() -> impl IntoView {
     let (result, set_result) = create_signal(Option::<SAPSIIResponse>::None);
     let (loading, set_loading) = create_signal(false);
 
+    // Current form state as an API request
+    let build_request = move || SAPSIIRequest {
+        age: age.get(),
+        heart_rate: heart_rate.get(),
+        systolic_bp: systolic_bp.get(),
+        temperature: temperature.get(),
+        pao2_fio2: if ventilated.get() {
+            Some(pao2_fio2.get())
+        } else {
+            None
+        },
+        urinary_output: urinary_output.get(),
+        serum_urea: serum_urea.get(),
+        white_blood_count: white_blood_count.get(),
+        serum_potassium: serum_potassium.get(),
+        serum_sodium: serum_sodium.get(),
+        serum_bicarbonate: serum_bicarbonate.get(),
+        bilirubin: bilirubin.get(),
+        glasgow: glasgow.get(),
+        chronic_disease: chronic_disease.get(),
+        admission_type: admission_type.get(),
+        patient_id: patient_id(),
+    };
+
+    // Auto-save draft on input (debounced); skip the initial defaults
+    let draft = FormDraft::<SAPSIIRequest>::new("saps", patient_id());
+    create_effect(move |prev: Option<()>| {
+        let request = build_request();
+        if prev.is_some() {
+            draft.save_debounced(request);
+        }
+    });
+
+    let restore_draft = move |d: SAPSIIRequest| {
+        set_age.set(d.age);
+        set_heart_rate.set(d.heart_rate);
+        set_systolic_bp.set(d.systolic_bp);
+        set_temperature.set(d.temperature);
+        set_ventilated.set(d.pao2_fio2.is_some());
+        if let Some(ratio) = d.pao2_fio2 {
+            set_pao2_fio2.set(ratio);
+        }
+        set_urinary_output.set(d.urinary_output);
+        set_serum_urea.set(d.serum_urea);
+        set_white_blood_count.set(d.white_blood_count);
+        set_serum_potassium.set(d.serum_potassium);
+        set_serum_sodium.set(d.serum_sodium);
+        set_serum_bicarbonate.set(d.serum_bicarbonate);
+        set_bilirubin.set(d.bilirubin);
+        set_glasgow.set(d.glasgow);
+        set_chronic_disease.set(d.chronic_disease);
+        set_admission_type.set(d.admission_type);
+    };
+
     // Smart Pre-fill Logic
     create_effect(move |_| {
         if let Some(id) = patient_id() {
@@ -64,6 +119,9 @@ pub fn SapsForm() -> impl IntoView {
                             let age_val = now.years_since(dob).unwrap_or(50) as u8;
                             set_age.set(age_val.clamp(18, 110));
                         }
+                        if let Some(category) = patient.admission_type.saps_admission() {
+                            set_admission_type.set(category.to_string());
+                        }
                     }
                 }
 
@@ -93,28 +151,7 @@ pub fn SapsForm() -> impl IntoView {
     let calculate = move |_| {
         set_loading.set(true);
 
-        let request = SAPSIIRequest {
-            age: age.get(),
-            heart_rate: heart_rate.get(),
-            systolic_bp: systolic_bp.get(),
-            temperature: temperature.get(),
-            pao2_fio2: if ventilated.get() {
-                Some(pao2_fio2.get())
-            } else {
-                None
-            },
-            urinary_output: urinary_output.get(),
-            serum_urea: serum_urea.get(),
-            white_blood_count: white_blood_count.get(),
-            serum_potassium: serum_potassium.get(),
-            serum_sodium: serum_sodium.get(),
-            serum_bicarbonate: serum_bicarbonate.get(),
-            bilirubin: bilirubin.get(),
-            glasgow: glasgow.get(),
-            chronic_disease: chronic_disease.get(),
-            admission_type: admission_type.get(),
-            patient_id: patient_id(),
-        };
+        let request = build_request();
 
         spawn_local(async move {
             let token: Option<String> = window()
@@ -139,6 +176,7 @@ pub fn SapsForm() -> impl IntoView {
                     if resp.ok() {
                         if let Ok(data) = resp.json::<SAPSIIResponse>().await {
                             set_result.set(Some(data));
+                            draft.clear();
                         }
                     }
                 }
@@ -159,6 +197,8 @@ pub fn SapsForm() -> impl IntoView {
                 <p class="text-sm text-gray-600 mt-1">{move || t(lang.get(), "saps_subtitle")}</p>
             </div>
 
+            <DraftRestoreBanner draft=draft on_restore=restore_draft/>
+
             // Calculate Button
             <div class="flex justify-center mb-6">
                  <button
@@ -428,6 +468,7 @@ pub fn SapsForm() -> impl IntoView {
                          <label class="block text-sm font-medium text-gray-700 mb-1"><i class="fas fa-hospital-user mr-2 text-gray-500"></i>{move || t(lang.get(), "admission_type")}</label>
                         <select
                             on:change=move |ev| set_admission_type.set(event_target_value(&ev))
+                            prop:value=move || admission_type.get()
                             class="w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-gray-500">
                             <option value="scheduled" selected="selected">{move || t(lang.get(), "scheduled_surgical")}</option>
                             <option value="medical">{move || t(lang.get(), "medical")}</option>
@@ -439,6 +480,7 @@ pub fn SapsForm() -> impl IntoView {
                         <label class="block text-sm font-medium text-gray-700 mb-1"><i class="fas fa-virus mr-2 text-green-700"></i>{move || t(lang.get(), "chronic_disease")}</label>
                         <select
                             on:change=move |ev| set_chronic_disease.set(event_target_value(&ev))
+                            prop:value=move || chronic_disease.get()
                             class="w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-gray-500">
                             <option value="none" selected="selected">{move || t(lang.get(), "none")}</option>
                             <option value="cancer">{move || t(lang.get(), "metastatic_cancer")}</option>
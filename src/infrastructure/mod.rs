@@ -5,6 +5,7 @@
 
 pub mod valkey;
 pub mod surreal;
+pub mod query;
 
 pub use valkey::{
     ValkeyStore,
@@ -13,3 +14,7 @@ pub use valkey::{
 pub use surreal::{
     SurrealStore,
 };
+
+pub use query::{
+    BoundQuery, record_id,
+};
@@ -0,0 +1,113 @@
+// src/infrastructure/query.rs
+// OLYMPUS v13 - Consultas SurrealQL con parámetros enlazados
+//
+// La entrada del usuario nunca se interpola en el texto SurrealQL: los valores viajan
+// como parámetros (`$nombre`) y los ids de registro se parsean con `thing()`.
+
+use surrealdb::engine::any::Any;
+use surrealdb::sql::{Thing, Value};
+use surrealdb::Surreal;
+
+use super::surreal::SurrealError;
+
+/// Valida un nombre de tabla o campo: `[A-Za-z_][A-Za-z0-9_]*`
+pub fn ident(name: &str) -> Result<&str, SurrealError> {
+    let mut chars = name.chars();
+    let valid_start = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+    if valid_start && chars.all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        Ok(name)
+    } else {
+        Err(SurrealError::InvalidIdentifier(name.to_string()))
+    }
+}
+
+/// Id de registro `tabla:id` parseado por SurrealDB; cualquier resto (`x OR 1=1`) falla.
+/// Los UUID (con guiones) se escapan con ⟨⟩ para que SurrealDB los lea como un único id.
+pub fn record_id(table: &str, id: &str) -> Result<Thing, SurrealError> {
+    let table = ident(table)?;
+    if id.contains('-') && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return parse_thing(&format!("{}:⟨{}⟩", table, id));
+    }
+    parse_thing(&format!("{}:{}", table, id))
+}
+
+/// Parsea un id completo (`patient:abc`) sin aceptar expresiones adicionales
+pub fn parse_thing(raw: &str) -> Result<Thing, SurrealError> {
+    surrealdb::sql::thing(raw).map_err(|_| SurrealError::InvalidIdentifier(raw.to_string()))
+}
+
+/// Consulta cuyo texto es fijo y cuyos valores van siempre enlazados
+#[derive(Debug, Clone)]
+pub struct BoundQuery {
+    sql: String,
+    bindings: Vec<(String, Value)>,
+}
+
+impl BoundQuery {
+    /// Solo acepta texto estático: los datos del usuario deben entrar por `bind`
+    pub fn new(sql: &'static str) -> Self {
+        Self {
+            sql: sql.to_string(),
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Sustituye `{table}` en la plantilla por un identificador validado
+    pub fn with_table(template: &'static str, table: &str) -> Result<Self, SurrealError> {
+        Ok(Self {
+            sql: template.replace("{table}", ident(table)?),
+            bindings: Vec::new(),
+        })
+    }
+
+    pub fn bind(mut self, name: &str, value: impl Into<Value>) -> Self {
+        self.bindings.push((name.to_string(), value.into()));
+        self
+    }
+
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    pub fn bindings(&self) -> &[(String, Value)] {
+        &self.bindings
+    }
+
+    /// Ejecuta la consulta y devuelve el primer conjunto de resultados
+    pub async fn fetch<T: for<'de> serde::Deserialize<'de>>(self, client: &Surreal<Any>) -> Result<Vec<T>, SurrealError> {
+        let mut query = client.query(self.sql);
+        for (name, value) in self.bindings {
+            query = query.bind((name, value));
+        }
+
+        let mut response = query.await.map_err(|e| SurrealError::QueryFailed(e.to_string()))?;
+        response.take(0).map_err(|e| SurrealError::QueryFailed(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_injection_attempts_are_rejected() {
+        assert!(record_id("patient", "abc123").is_ok());
+        assert!(record_id("patient", "550e8400-e29b-41d4-a716-446655440000").is_ok());
+        assert!(record_id("patient", "x OR 1=1").is_err());
+        assert!(record_id("patient", "x; DELETE patient").is_err());
+        assert!(record_id("patient; DELETE user", "abc").is_err());
+        assert!(ident("glasgow").is_ok());
+        assert!(ident("glasgow WHERE 1=1").is_err());
+    }
+
+    #[test]
+    fn test_values_never_reach_the_sql_text() {
+        let malicious = "x' OR 1=1; DELETE patient; --";
+        let query = BoundQuery::new("SELECT * FROM glasgow WHERE patient_id = $patient_id")
+            .bind("patient_id", malicious);
+
+        assert!(!query.sql().contains(malicious));
+        assert_eq!(query.bindings().len(), 1);
+        assert!(BoundQuery::with_table("SELECT * FROM {table}", "sofa; DELETE sofa").is_err());
+    }
+}
@@ -6,6 +6,8 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use thiserror::Error;
 
+use super::query::{ident, parse_thing, BoundQuery};
+
 #[derive(Debug, Error, Serialize, Deserialize)]
 pub enum SurrealError {
     #[error("Connection failed: {0}")]
@@ -25,6 +27,9 @@ pub enum SurrealError {
 
     #[error("Record not found: {0}")]
     RecordNotFound(String),
+
+    #[error("Invalid identifier: {0}")]
+    InvalidIdentifier(String),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -91,7 +96,9 @@ impl SurrealStore {
 
         let mut response = client.as_ref()
             .ok_or_else(|| SurrealError::ConnectionFailed("Not connected".to_string()))?
-            .query(format!("CREATE {} CONTENT {}", table, data))
+            .query("CREATE type::table($table) CONTENT $data")
+            .bind(("table", ident(table)?.to_string()))
+            .bind(("data", data))
             .await
             .map_err(|e| SurrealError::QueryFailed(e.to_string()))?;
 
@@ -106,8 +113,9 @@ impl SurrealStore {
         
         let mut response = client.as_ref()
             .ok_or_else(|| SurrealError::ConnectionFailed("Not connected".to_string()))?
-            .query(format!("SELECT * FROM {} WHERE id = $id", table))
-            .bind(("id", id.to_string()))
+            .query("SELECT * FROM type::table($table) WHERE id = $id")
+            .bind(("table", ident(table)?.to_string()))
+            .bind(("id", super::query::record_id(table, id)?))
             .await
             .map_err(|e| SurrealError::QueryFailed(e.to_string()))?;
 
@@ -124,7 +132,9 @@ impl SurrealStore {
 
         let mut response = client.as_ref()
             .ok_or_else(|| SurrealError::ConnectionFailed("Not connected".to_string()))?
-            .query(format!("UPDATE {} CONTENT {}", id, data))
+            .query("UPDATE $id CONTENT $data")
+            .bind(("id", parse_thing(id)?))
+            .bind(("data", data))
             .await
             .map_err(|e| SurrealError::QueryFailed(e.to_string()))?;
 
@@ -139,7 +149,8 @@ impl SurrealStore {
         
         client.as_ref()
             .ok_or_else(|| SurrealError::ConnectionFailed("Not connected".to_string()))?
-            .query(format!("DELETE {}", id))
+            .query("DELETE $id")
+            .bind(("id", parse_thing(id)?))
             .await
             .map_err(|e| SurrealError::QueryFailed(e.to_string()))?;
 
@@ -161,6 +172,15 @@ impl SurrealStore {
         Ok(result)
     }
 
+    /// Ejecuta una consulta con parámetros enlazados (ver `BoundQuery`)
+    pub async fn query_bound<T: for<'de> serde::Deserialize<'de>>(&self, query: BoundQuery) -> Result<Vec<T>, SurrealError> {
+        let client = self.client.read().await;
+        let client = client.as_ref()
+            .ok_or_else(|| SurrealError::ConnectionFailed("Not connected".to_string()))?;
+
+        query.fetch(client).await
+    }
+
     pub async fn health_check(&self) -> Result<bool, SurrealError> {
         let client = self.client.read().await;
         if let Some(ref c) = *client {
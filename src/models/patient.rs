@@ -20,6 +20,52 @@ impl Default for SkinColor {
     }
 }
 
+impl SkinColor {
+    pub const ALL: [SkinColor; 6] = [
+        SkinColor::VeryFair,
+        SkinColor::Fair,
+        SkinColor::Olive,
+        SkinColor::Brown,
+        SkinColor::DarkBrown,
+        SkinColor::Black,
+    ];
+
+    /// Serialized value, as expected by the backend
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SkinColor::VeryFair => "VeryFair",
+            SkinColor::Fair => "Fair",
+            SkinColor::Olive => "Olive",
+            SkinColor::Brown => "Brown",
+            SkinColor::DarkBrown => "DarkBrown",
+            SkinColor::Black => "Black",
+        }
+    }
+
+    /// i18n key for the UI label
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            SkinColor::VeryFair => "skin_very_fair",
+            SkinColor::Fair => "skin_fair",
+            SkinColor::Olive => "skin_olive",
+            SkinColor::Brown => "skin_brown",
+            SkinColor::DarkBrown => "skin_dark_brown",
+            SkinColor::Black => "black",
+        }
+    }
+}
+
+impl std::str::FromStr for SkinColor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.as_str() == s)
+            .ok_or_else(|| format!("Unknown skin color: {}", s))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum AdmissionType {
     Elective,
@@ -33,6 +79,53 @@ impl Default for AdmissionType {
     }
 }
 
+impl AdmissionType {
+    pub const ALL: [AdmissionType; 3] = [
+        AdmissionType::Urgent,
+        AdmissionType::Elective,
+        AdmissionType::Transfer,
+    ];
+
+    /// Serialized value, as expected by the backend
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AdmissionType::Elective => "Elective",
+            AdmissionType::Urgent => "Urgent",
+            AdmissionType::Transfer => "Transfer",
+        }
+    }
+
+    /// i18n key for the UI label
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            AdmissionType::Elective => "programmed",
+            AdmissionType::Urgent => "urgent",
+            AdmissionType::Transfer => "transfer",
+        }
+    }
+
+    /// Suggested SAPS II admission category (`SAPSIIRequest::admission_type`).
+    /// A transfer says nothing about surgery, so the clinician picks it.
+    pub fn saps_admission(&self) -> Option<&'static str> {
+        match self {
+            AdmissionType::Elective => Some("scheduled"),
+            AdmissionType::Urgent => Some("unscheduled"),
+            AdmissionType::Transfer => None,
+        }
+    }
+}
+
+impl std::str::FromStr for AdmissionType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|a| a.as_str() == s)
+            .ok_or_else(|| format!("Unknown admission type: {}", s))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum CivilStatus {
     Single,
@@ -7,6 +7,11 @@ use tokio::sync::RwLock;
 use once_cell::sync::Lazy;
 use chrono::Utc;
 
+use crate::actors::chronos::{ChronosScheduleQueries, LoopHealth};
+use crate::actors::zeus::{ZeusMetricsQueries, ZeusQuery};
+use crate::errors::ActorError;
+use crate::traits::message::{QueryPayload, ResponsePayload};
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // DOMINIOS DE LOS DIOSES
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -44,6 +49,14 @@ pub struct GodStatus {
     pub uptime_seconds: u64,
     pub messages_processed: u64,
     pub last_heartbeat: String,
+    /// Reinicios aplicados por Zeus (SupervisionManager)
+    #[serde(default)]
+    pub restarts: u32,
+    #[serde(default)]
+    pub last_restart: Option<String>,
+    /// Motivo del fallo más reciente
+    #[serde(default)]
+    pub last_failure: Option<String>,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -63,35 +76,35 @@ impl OlympusServices {
         Self {
             gods: vec![
                 // Trinidad Suprema
-                GodStatus { name: "Zeus".to_string(), domain: DivineDomain::Governance, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
-                GodStatus { name: "Erinyes".to_string(), domain: DivineDomain::Integrity, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
-                GodStatus { name: "Poseidon".to_string(), domain: DivineDomain::DataFlow, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
+                GodStatus { name: "Zeus".to_string(), domain: DivineDomain::Governance, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
+                GodStatus { name: "Erinyes".to_string(), domain: DivineDomain::Integrity, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
+                GodStatus { name: "Poseidon".to_string(), domain: DivineDomain::DataFlow, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
                 // Dioses Clínicos
-                GodStatus { name: "Athena".to_string(), domain: DivineDomain::Clinical, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
-                GodStatus { name: "Apollo".to_string(), domain: DivineDomain::Events, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
-                GodStatus { name: "Artemis".to_string(), domain: DivineDomain::Search, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
-                GodStatus { name: "Hermes".to_string(), domain: DivineDomain::Messaging, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
+                GodStatus { name: "Athena".to_string(), domain: DivineDomain::Clinical, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
+                GodStatus { name: "Apollo".to_string(), domain: DivineDomain::Events, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
+                GodStatus { name: "Artemis".to_string(), domain: DivineDomain::Search, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
+                GodStatus { name: "Hermes".to_string(), domain: DivineDomain::Messaging, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
                 // Seguridad
-                GodStatus { name: "Hades".to_string(), domain: DivineDomain::Security, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
-                GodStatus { name: "Hera".to_string(), domain: DivineDomain::Validation, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
+                GodStatus { name: "Hades".to_string(), domain: DivineDomain::Security, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
+                GodStatus { name: "Hera".to_string(), domain: DivineDomain::Validation, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
                 // Gobierno
-                GodStatus { name: "Ares".to_string(), domain: DivineDomain::ConflictResolution, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
-                GodStatus { name: "Hefesto".to_string(), domain: DivineDomain::Configuration, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
+                GodStatus { name: "Ares".to_string(), domain: DivineDomain::ConflictResolution, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
+                GodStatus { name: "Hefesto".to_string(), domain: DivineDomain::Configuration, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
                 // Tiempo y Destino
-                GodStatus { name: "Chronos".to_string(), domain: DivineDomain::Scheduling, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
-                GodStatus { name: "Moirai".to_string(), domain: DivineDomain::Predictions, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
+                GodStatus { name: "Chronos".to_string(), domain: DivineDomain::Scheduling, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
+                GodStatus { name: "Moirai".to_string(), domain: DivineDomain::Predictions, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
                 // Caos y Esperanza
-                GodStatus { name: "Chaos".to_string(), domain: DivineDomain::Testing, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
-                GodStatus { name: "Aurora".to_string(), domain: DivineDomain::NewBeginnings, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
+                GodStatus { name: "Chaos".to_string(), domain: DivineDomain::Testing, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
+                GodStatus { name: "Aurora".to_string(), domain: DivineDomain::NewBeginnings, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
                 // UI y Comunicaciones
-                GodStatus { name: "Aphrodite".to_string(), domain: DivineDomain::UI, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
-                GodStatus { name: "Iris".to_string(), domain: DivineDomain::Communications, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
+                GodStatus { name: "Aphrodite".to_string(), domain: DivineDomain::UI, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
+                GodStatus { name: "Iris".to_string(), domain: DivineDomain::Communications, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
                 // Recursos y Análisis
-                GodStatus { name: "Demeter".to_string(), domain: DivineDomain::Resources, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
-                GodStatus { name: "Dionysus".to_string(), domain: DivineDomain::Analysis, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
+                GodStatus { name: "Demeter".to_string(), domain: DivineDomain::Resources, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
+                GodStatus { name: "Dionysus".to_string(), domain: DivineDomain::Analysis, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
                 // Persistencia y Justicia
-                GodStatus { name: "Hestia".to_string(), domain: DivineDomain::Persistence, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
-                GodStatus { name: "Nemesis".to_string(), domain: DivineDomain::LegalCompliance, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339() },
+                GodStatus { name: "Hestia".to_string(), domain: DivineDomain::Persistence, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
+                GodStatus { name: "Nemesis".to_string(), domain: DivineDomain::LegalCompliance, active: true, uptime_seconds: 0, messages_processed: 0, last_heartbeat: Utc::now().to_rfc3339(), restarts: 0, last_restart: None, last_failure: None },
             ],
             startup_time: Utc::now(),
         }
@@ -117,6 +130,67 @@ pub async fn get_god_by_domain(domain: DivineDomain) -> Option<GodStatus> {
     guard.gods.iter().find(|g| g.domain == domain).cloned()
 }
 
+/// Registra un fallo informado por Zeus; `name` es el nombre del dios (`GodName` en texto)
+pub async fn record_failure(name: &str, error: &str) {
+    let mut guard = OLYMPUS_SERVICES.write().await;
+    if let Some(god) = guard.gods.iter_mut().find(|g| g.name == name) {
+        god.last_failure = Some(error.to_string());
+    }
+}
+
+/// Registra un reinicio aplicado por Zeus; `attempt` es el total de reinicios del dios
+pub async fn record_restart(name: &str, attempt: u32) {
+    let mut guard = OLYMPUS_SERVICES.write().await;
+    if let Some(god) = guard.gods.iter_mut().find(|g| g.name == name) {
+        god.restarts = attempt;
+        god.last_restart = Some(Utc::now().to_rfc3339());
+    }
+}
+
+/// Último estado del loop de Chronos, publicado en cada tick
+static CHRONOS_HEALTH: Lazy<Arc<RwLock<Option<LoopHealth>>>> = Lazy::new(|| Arc::new(RwLock::new(None)));
+
+pub async fn record_chronos_health(health: LoopHealth) {
+    *CHRONOS_HEALTH.write().await = Some(health);
+}
+
+/// `None` si el scheduler aún no ha hecho ningún tick
+pub async fn get_chronos_health() -> Option<serde_json::Value> {
+    CHRONOS_HEALTH.read().await.as_ref().map(|health| health.report(Utc::now()))
+}
+
+/// Agenda de Chronos, registrada al montar el Olimpo
+static CHRONOS_SCHEDULE: Lazy<Arc<RwLock<Option<ChronosScheduleQueries>>>> = Lazy::new(|| Arc::new(RwLock::new(None)));
+
+pub async fn register_chronos_schedule(queries: ChronosScheduleQueries) {
+    *CHRONOS_SCHEDULE.write().await = Some(queries);
+}
+
+/// `None` si Chronos aún no se ha montado
+pub async fn chronos_schedule_query(query: QueryPayload) -> Option<Result<ResponsePayload, ActorError>> {
+    let queries = CHRONOS_SCHEDULE.read().await.clone()?;
+    Some(queries.execute(query).await)
+}
+
+/// Métricas de Zeus, registradas al montar el Olimpo
+static ZEUS_METRICS: Lazy<Arc<RwLock<Option<ZeusMetricsQueries>>>> = Lazy::new(|| Arc::new(RwLock::new(None)));
+
+pub async fn register_zeus_metrics(queries: ZeusMetricsQueries) {
+    *ZEUS_METRICS.write().await = Some(queries);
+}
+
+/// `None` si Zeus aún no se ha montado
+pub async fn zeus_metrics_query(query: ZeusQuery) -> Option<Result<ResponsePayload, ActorError>> {
+    let queries = ZEUS_METRICS.read().await.clone()?;
+    Some(queries.execute(query).await)
+}
+
+/// Mensajes no soportados que Zeus ha contado para `god`; `None` si Zeus aún no se ha montado
+pub async fn zeus_unsupported_for(god: crate::actors::GodName) -> Option<serde_json::Value> {
+    let queries = ZEUS_METRICS.read().await.clone()?;
+    Some(queries.unsupported().await.for_god(god))
+}
+
 pub async fn get_active_gods_count() -> usize {
     let guard = OLYMPUS_SERVICES.read().await;
     guard.gods.iter().filter(|g| g.active).count()
@@ -0,0 +1,218 @@
+// Resumen imprimible de un paciente: un documento HTML autocontenido (sin recursos
+// externos) con los datos demográficos y la última evaluación de cada escala, pensado
+// para que el navegador lo imprima en una página o lo guarde como PDF
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::api::{AssessmentRecord, PatientRecord};
+use crate::olympus_services::hades;
+
+/// Campos demográficos en el orden en que se imprimen
+const DEMOGRAPHICS: [(&str, &str); 10] = [
+    ("identity_card", "Cédula"),
+    ("clinical_history_number", "Historia clínica"),
+    ("date_of_birth", "Fecha de nacimiento"),
+    ("gender", "Sexo"),
+    ("nationality", "Nacionalidad"),
+    ("admission_type", "Tipo de ingreso"),
+    ("hospital_admission_date", "Ingreso hospitalario"),
+    ("uci_admission_date", "Ingreso en UCI"),
+    ("diagnosis", "Diagnóstico"),
+    ("principal_diagnosis", "Diagnóstico principal"),
+];
+
+/// Campos de interpretación según la escala, en orden de preferencia
+const INTERPRETATION_FIELDS: [&str; 5] = ["severity", "risk_level", "interpretation", "diagnosis", "predicted_mortality"];
+
+const STYLE: &str = "\
+@page { size: A4; margin: 15mm; }
+* { box-sizing: border-box; }
+body { font-family: 'Helvetica Neue', Arial, sans-serif; font-size: 11pt; color: #111; margin: 0 auto; max-width: 180mm; }
+h1 { font-size: 18pt; margin: 0 0 2mm; }
+h2 { font-size: 12pt; margin: 6mm 0 2mm; border-bottom: 1px solid #999; padding-bottom: 1mm; }
+table { width: 100%; border-collapse: collapse; }
+th, td { text-align: left; padding: 1.5mm 2mm; border-bottom: 1px solid #ddd; vertical-align: top; }
+th { width: 35%; font-weight: 600; color: #333; }
+thead th { width: auto; background: #f2f2f2; }
+.meta { color: #555; font-size: 9pt; }
+.empty { color: #777; font-style: italic; }
+.integrity { margin-top: 6mm; padding: 2mm 3mm; border: 1px solid #999; font-size: 9pt; }
+.integrity.verified { border-color: #2e7d32; }
+.integrity.mismatch { border-color: #c62828; font-weight: 600; }
+@media print { body { max-width: none; } }
+";
+
+/// Estado del `integrity_hash` guardado frente al contenido actual del registro
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityStatus {
+    Verified,
+    Mismatch,
+    /// El registro no tiene hash (creado antes de calcularlo o datos de demostración)
+    Missing,
+}
+
+impl IntegrityStatus {
+    /// El hash cubre el registro completo salvo `id` y el propio `integrity_hash`
+    pub fn of(patient: &PatientRecord) -> Self {
+        let Some(stored) = patient.get("integrity_hash").and_then(Value::as_str).filter(|h| !h.is_empty()) else {
+            return IntegrityStatus::Missing;
+        };
+        let mut content = patient.clone();
+        if let Some(map) = content.as_object_mut() {
+            map.remove("id");
+            map.remove("integrity_hash");
+        }
+        if hades::verify_integrity(&content.to_string(), stored) {
+            IntegrityStatus::Verified
+        } else {
+            IntegrityStatus::Mismatch
+        }
+    }
+
+    fn class(&self) -> &'static str {
+        match self {
+            IntegrityStatus::Verified => "verified",
+            IntegrityStatus::Mismatch => "mismatch",
+            IntegrityStatus::Missing => "missing",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            IntegrityStatus::Verified => "Integridad verificada: el registro coincide con su hash",
+            IntegrityStatus::Mismatch => "Integridad NO verificada: el registro no coincide con su hash",
+            IntegrityStatus::Missing => "Sin hash de integridad registrado",
+        }
+    }
+}
+
+/// Escapa texto para insertarlo en HTML
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Texto de un campo del registro; `None` si falta, es nulo o está vacío
+fn field_text(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) if s.trim().is_empty() => None,
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+fn scale_row(name: &str, assessment: Option<&AssessmentRecord>) -> String {
+    let Some(assessment) = assessment else {
+        return format!("<tr><td>{}</td><td colspan=\"3\" class=\"empty\">Sin evaluaciones</td></tr>", escape(name));
+    };
+    let score = ["score", "total"]
+        .iter()
+        .find_map(|f| field_text(&assessment[*f]))
+        .unwrap_or_else(|| "—".to_string());
+    let interpretation = INTERPRETATION_FIELDS
+        .iter()
+        .find_map(|f| field_text(&assessment[*f]))
+        .unwrap_or_else(|| "—".to_string());
+    let assessed_at = field_text(&assessment["assessed_at"]).unwrap_or_else(|| "—".to_string());
+    format!(
+        "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+        escape(name),
+        escape(&score),
+        escape(&interpretation),
+        escape(&assessed_at)
+    )
+}
+
+/// Documento completo; `scales` es la última evaluación de cada escala (nombre, registro)
+pub fn render(patient: &PatientRecord, scales: &[(&str, Option<AssessmentRecord>)], generated_at: DateTime<Utc>) -> String {
+    let name = [&patient["first_name"], &patient["last_name"]]
+        .into_iter()
+        .filter_map(field_text)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let id = field_text(&patient["id"]).unwrap_or_default();
+
+    let demographics: String = DEMOGRAPHICS
+        .iter()
+        .filter_map(|(field, label)| {
+            field_text(&patient[*field]).map(|value| format!("<tr><th>{}</th><td>{}</td></tr>", label, escape(&value)))
+        })
+        .collect();
+    let scale_rows: String = scales.iter().map(|(name, assessment)| scale_row(name, assessment.as_ref())).collect();
+    let integrity = IntegrityStatus::of(patient);
+
+    format!(
+        "<!DOCTYPE html>
+<html lang=\"es\">
+<head>
+<meta charset=\"utf-8\">
+<title>Resumen de {name}</title>
+<style>{style}</style>
+</head>
+<body>
+<h1>{name}</h1>
+<div class=\"meta\">{id} · Generado el {generated}</div>
+<h2>Datos del paciente</h2>
+<table>{demographics}</table>
+<h2>Últimas escalas</h2>
+<table>
+<thead><tr><th>Escala</th><th>Puntuación</th><th>Interpretación</th><th>Fecha</th></tr></thead>
+<tbody>{scale_rows}</tbody>
+</table>
+<div class=\"integrity {integrity_class}\">{integrity_label}</div>
+</body>
+</html>
+",
+        name = escape(&name),
+        style = STYLE,
+        id = escape(&id),
+        generated = generated_at.format("%Y-%m-%d %H:%M UTC"),
+        demographics = demographics,
+        scale_rows = scale_rows,
+        integrity_class = integrity.class(),
+        integrity_label = integrity.label(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_summary_is_escaped_and_checks_integrity() {
+        let mut patient = json!({
+            "id": "patient:1",
+            "first_name": "Juan",
+            "last_name": "<Pérez>",
+            "identity_card": "V-123",
+        });
+        let content = json!({ "first_name": "Juan", "last_name": "<Pérez>", "identity_card": "V-123" });
+        patient["integrity_hash"] = json!(hades::hash_data(&content.to_string()));
+        let glasgow = json!({ "score": 14, "diagnosis": "Leve", "assessed_at": "2026-03-01T08:00:00Z" });
+
+        let html = render(&patient, &[("Glasgow", Some(glasgow)), ("SOFA", None)], Utc::now());
+        assert!(html.contains("Juan &lt;Pérez&gt;"));
+        assert!(!html.contains("<Pérez>"));
+        assert!(html.contains("<td>14</td><td>Leve</td>"));
+        assert!(html.contains("Sin evaluaciones"));
+        assert!(html.contains("integrity verified"));
+        assert!(!html.contains("http"));
+
+        patient["identity_card"] = json!("V-999");
+        assert_eq!(IntegrityStatus::of(&patient), IntegrityStatus::Mismatch);
+        assert_eq!(IntegrityStatus::of(&json!({ "first_name": "Ana" })), IntegrityStatus::Missing);
+    }
+}
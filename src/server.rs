@@ -1,4 +1,12 @@
+pub mod api;
+pub mod infrastructure;
 pub mod olympus_services;
+pub mod patient_summary;
+
+/// Misma fuente de versión que el servidor; el commit lo inyecta server/build.rs, que
+/// el manifiesto de este crate debe declarar como `build`
+#[path = "../server/src/version.rs"]
+mod version;
 
 use axum::{
     routing::get,
@@ -15,6 +23,11 @@ use surrealdb::Surreal;
 use serde::{Deserialize, Serialize};
 use once_cell::sync::Lazy;
 
+use api::{
+    ApiError, ApiResult, AssessmentRecord, LatestAssessmentsResponse, LoginResponse, LogoutResponse, PatientResponse,
+    PatientsResponse,
+};
+
 static DB: Lazy<Arc<RwLock<Option<Surreal<Any>>>>> = Lazy::new(|| Arc::new(RwLock::new(None)));
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -80,15 +93,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let app = Router::new()
         .route("/", get(index))
         .route("/api/status", get(api_status))
+        .route("/api/version", get(api_version))
         .route("/api/olympus/gods", get(api_olympus_gods))
         .route("/api/olympus/god/:domain", get(api_olympus_god))
+        .route("/api/olympus/gods/:name/metrics", get(api_god_metrics))
         .route("/api/scales/glasgow", get(api_glasgow))
         .route("/api/scales/sofa", get(api_sofa))
         .route("/api/patients", get(api_patients))
         .route("/api/patient/:id", get(api_patient))
+        .route("/api/patients/:id/assessments/latest-all", get(api_latest_assessments))
+        .route("/api/patients/:id/summary", get(api_patient_summary))
         .route("/api/login", get(api_login))
         .route("/api/logout", get(api_logout))
         .route("/api/admin/stats", get(api_stats))
+        .route("/api/chronos/health", get(api_chronos_health))
+        .route("/api/chronos/schedule", get(api_chronos_schedule))
         .nest_service("/static", ServeDir::new("dist"))
         .fallback_service(ServeDir::new("dist"));
 
@@ -151,7 +170,7 @@ async fn api_status() -> Json<serde_json::Value> {
 
     Json(serde_json::json!({
         "status": "active",
-        "version": "v15.0.0",
+        "version": version::display_version(),
         "mode": "Olympus + SurrealDB",
         "surreal_db": db_healthy,
         "active_gods": active_gods,
@@ -159,6 +178,10 @@ async fn api_status() -> Json<serde_json::Value> {
     }))
 }
 
+async fn api_version() -> Json<version::BuildInfo> {
+    Json(version::build_info())
+}
+
 async fn api_olympus_gods() -> Json<serde_json::Value> {
     let gods = olympus_services::get_gods_status().await;
     Json(serde_json::json!({
@@ -184,6 +207,69 @@ async fn api_olympus_god(axum::extract::Path(domain): axum::extract::Path<String
     }))
 }
 
+/// Contadores de un solo dios según Zeus (`ZeusQuery::GetActorMetrics`)
+async fn api_god_metrics(axum::extract::Path(name): axum::extract::Path<String>) -> ApiResult<serde_json::Value> {
+    use crate::actors::zeus::ZeusQuery;
+    use crate::errors::ActorError;
+    use crate::traits::message::ResponsePayload;
+
+    // GodName se serializa con mayúscula inicial ("Poseidon"); la ruta la admite en cualquier caso
+    let canonical: String = name
+        .chars()
+        .enumerate()
+        .map(|(i, c)| if i == 0 { c.to_ascii_uppercase() } else { c.to_ascii_lowercase() })
+        .collect();
+    let Ok(actor) = serde_json::from_value::<crate::actors::GodName>(serde_json::json!(canonical)) else {
+        return Err(ApiError::not_found(format!("Dios desconocido: {}", name)));
+    };
+
+    match olympus_services::zeus_metrics_query(ZeusQuery::GetActorMetrics { actor }).await {
+        Some(Ok(ResponsePayload::Data { data })) => Ok(Json(serde_json::json!({
+            "success": true,
+            "god": actor,
+            "metrics": data,
+            "unsupported": olympus_services::zeus_unsupported_for(actor).await,
+        }))),
+        Some(Err(ActorError::NotFound { .. })) => {
+            Err(ApiError::not_found(format!("Zeus no tiene métricas de {}", actor)))
+        }
+        Some(Ok(_)) | Some(Err(_)) => Err(ApiError::internal("Respuesta inesperada de Zeus")),
+        None => Err(ApiError::new(axum::http::StatusCode::SERVICE_UNAVAILABLE, "Zeus no está en marcha")),
+    }
+}
+
+async fn api_chronos_health() -> Json<serde_json::Value> {
+    match olympus_services::get_chronos_health().await {
+        Some(health) => Json(serde_json::json!({ "running": true, "health": health })),
+        None => Json(serde_json::json!({ "running": false, "health": null })),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ScheduleParams {
+    limit: Option<u64>,
+}
+
+/// Próximas ejecuciones de Chronos (`?limit=`, 10 por defecto) con el nombre y el estado
+/// de cada tarea y la hora de disparo en ISO-8601
+async fn api_chronos_schedule(axum::extract::Query(params): axum::extract::Query<ScheduleParams>) -> ApiResult<serde_json::Value> {
+    use crate::actors::chronos::NEXT_EXECUTIONS_QUERY;
+    use crate::traits::message::{QueryPayload, ResponsePayload};
+
+    let mut query = serde_json::json!({ "query_type": NEXT_EXECUTIONS_QUERY });
+    if let Some(limit) = params.limit {
+        query["limit"] = serde_json::json!(limit);
+    }
+    match olympus_services::chronos_schedule_query(QueryPayload::Custom(query)).await {
+        Some(Ok(ResponsePayload::Data { data })) => Ok(Json(serde_json::json!({
+            "success": true,
+            "executions": data["executions"],
+        }))),
+        Some(Ok(_)) | Some(Err(_)) => Err(ApiError::internal("Respuesta inesperada de Chronos")),
+        None => Err(ApiError::new(axum::http::StatusCode::SERVICE_UNAVAILABLE, "Chronos no está en marcha")),
+    }
+}
+
 async fn api_glasgow() -> Json<serde_json::Value> {
     let result = olympus_services::athena::calculate_glasgow(3, 4, 5).await;
     Json(serde_json::json!({
@@ -200,69 +286,172 @@ async fn api_sofa() -> Json<serde_json::Value> {
     }))
 }
 
-async fn api_patients() -> Json<serde_json::Value> {
+async fn api_patients() -> ApiResult<PatientsResponse> {
     let db = get_db().await;
     let guard = db.read().await;
-    
-    if let Some(ref client) = *guard {
-        match client.query("SELECT * FROM patient").await {
-            Ok(mut response) => {
-                if let Ok(patients) = response.take::<Vec<serde_json::Value>>(0) {
-                    return Json(serde_json::json!({ "patients": patients }));
-                }
-            }
-            Err(e) => {
-                info!("DB query error: {}", e);
-            }
-        }
-    }
-    
-    Json(serde_json::json!({
-        "patients": [
-            {"first_name": "Juan", "last_name": "Pérez", "principal_diagnosis": "Neumonía", "gender": "Male", "date_of_birth": "1960-05-15", "id": "1"},
-            {"first_name": "María", "last_name": "García", "principal_diagnosis": "Postquirúrgico", "gender": "Female", "date_of_birth": "1975-08-22", "id": "2"}
-        ]
-    }))
+
+    let Some(ref client) = *guard else {
+        let patients = api::DEMO_PATIENTS.iter().map(|p| p.record()).collect();
+        return Ok(Json(PatientsResponse { success: true, patients }));
+    };
+
+    let patients = client
+        .query(api::SELECT_PATIENTS)
+        .await
+        .and_then(|mut response| response.take::<Vec<api::PatientRecord>>(0))
+        .map_err(|e| {
+            error!("DB query error: {}", e);
+            ApiError::internal("No se pudieron leer los pacientes")
+        })?;
+
+    Ok(Json(PatientsResponse { success: true, patients }))
+}
+
+async fn api_patient(axum::extract::Path(id): axum::extract::Path<String>) -> ApiResult<PatientResponse> {
+    let patient = find_patient(&id).await?;
+    Ok(Json(PatientResponse { success: true, patient }))
 }
 
-async fn api_patient(axum::extract::Path(id): axum::extract::Path<String>) -> Json<serde_json::Value> {
+/// Registro `patient:<clave>` validado por `record_id`; acepta el id canónico o solo la clave
+fn patient_record(id: &str) -> Result<surrealdb::sql::Thing, ApiError> {
+    let key = id.strip_prefix("patient:").unwrap_or(id);
+    infrastructure::record_id("patient", key).map_err(|_| ApiError::bad_request("Id de paciente inválido"))
+}
+
+/// Paciente por id (canónico o solo la clave); sin base de datos, los de demostración
+async fn find_patient(id: &str) -> Result<api::PatientRecord, ApiError> {
     let db = get_db().await;
     let guard = db.read().await;
     
-    if let Some(ref client) = *guard {
-        match client.query("SELECT * FROM patient WHERE id = $id").bind(("id", format!("patient:{}", id))).await {
-            Ok(mut response) => {
-                if let Ok(patients) = response.take::<Vec<serde_json::Value>>(0) {
-                    if let Some(patient) = patients.into_iter().next() {
-                        return Json(serde_json::json!({ "patient": patient }));
-                    }
-                }
-            }
-            Err(e) => {
-                info!("DB query error: {}", e);
-            }
+    // El id se parsea como registro y se enlaza: nunca se interpola en la consulta
+    let record = patient_record(id)?;
+    let id = api::canonical_patient_id(id);
+
+    let Some(ref client) = *guard else {
+        return api::DEMO_PATIENTS
+            .iter()
+            .find(|p| p.id == id)
+            .map(|p| p.record())
+            .ok_or_else(|| ApiError::not_found(format!("Paciente {} no encontrado", id)));
+    };
+
+    let patients = client
+        .query(api::SELECT_PATIENT_BY_ID)
+        .bind(("id", record))
+        .await
+        .and_then(|mut response| response.take::<Vec<api::PatientRecord>>(0))
+        .map_err(|e| {
+            error!("DB query error: {}", e);
+            ApiError::internal("No se pudo leer el paciente")
+        })?;
+
+    patients
+        .into_iter()
+        .next()
+        .ok_or_else(|| ApiError::not_found(format!("Paciente {} no encontrado", id)))
+}
+
+async fn latest_assessment(
+    client: &Surreal<Any>,
+    table: &'static str,
+    patient: surrealdb::sql::Thing,
+) -> Result<Option<AssessmentRecord>, surrealdb::Error> {
+    let mut response = client
+        .query(api::SELECT_LATEST_ASSESSMENT)
+        .bind(("table", table))
+        .bind(("patient_id", patient))
+        .await?;
+    let records: Vec<AssessmentRecord> = response.take(0)?;
+    Ok(records.into_iter().next())
+}
+
+/// Última evaluación de cada escala en una sola llamada: una consulta `LIMIT 1` por tabla, en paralelo
+async fn api_latest_assessments(
+    axum::extract::Path(id): axum::extract::Path<String>,
+) -> ApiResult<LatestAssessmentsResponse> {
+    let db = get_db().await;
+    let guard = db.read().await;
+
+    let patient = patient_record(&id)?;
+    let id = api::canonical_patient_id(&id);
+
+    let Some(ref client) = *guard else {
+        return Err(ApiError::new(
+            axum::http::StatusCode::SERVICE_UNAVAILABLE,
+            "Base de datos no disponible",
+        ));
+    };
+
+    let (glasgow, sofa, apache, saps, news2) = latest_of_each_scale(client, patient).await?;
+
+    Ok(Json(LatestAssessmentsResponse { success: true, patient_id: id, glasgow, sofa, apache, saps, news2 }))
+}
+
+type LatestScales = (
+    Option<AssessmentRecord>,
+    Option<AssessmentRecord>,
+    Option<AssessmentRecord>,
+    Option<AssessmentRecord>,
+    Option<AssessmentRecord>,
+);
+
+/// Glasgow, SOFA, APACHE, SAPS y NEWS2 más recientes, consultados en paralelo
+async fn latest_of_each_scale(client: &Surreal<Any>, patient: surrealdb::sql::Thing) -> Result<LatestScales, ApiError> {
+    tokio::try_join!(
+        latest_assessment(client, "glasgow", patient.clone()),
+        latest_assessment(client, "sofa", patient.clone()),
+        latest_assessment(client, "apache", patient.clone()),
+        latest_assessment(client, "saps", patient.clone()),
+        latest_assessment(client, "news2", patient),
+    )
+    .map_err(|e| {
+        error!("DB query error: {}", e);
+        ApiError::internal("No se pudieron leer las evaluaciones")
+    })
+}
+
+/// Resumen imprimible (HTML autocontenido) con los datos demográficos y la última
+/// evaluación de cada escala; el navegador lo imprime o lo guarda como PDF
+async fn api_patient_summary(
+    axum::extract::Path(id): axum::extract::Path<String>,
+) -> Result<axum::response::Html<String>, ApiError> {
+    let patient = find_patient(&id).await?;
+
+    let db = get_db().await;
+    let guard = db.read().await;
+    let (glasgow, sofa, apache, saps, news2) = match *guard {
+        Some(ref client) => {
+            latest_of_each_scale(client, patient_record(&id)?).await?
         }
-    }
-    
-    Json(serde_json::json!({
-        "patient": {"first_name": "Demo", "last_name": "Patient"}
-    }))
+        // Pacientes de demostración: sin evaluaciones guardadas
+        None => (None, None, None, None, None),
+    };
+    drop(guard);
+
+    let scales = [
+        ("Glasgow", glasgow),
+        ("SOFA", sofa),
+        ("APACHE II", apache),
+        ("SAPS II", saps),
+        ("NEWS2", news2),
+    ];
+    Ok(axum::response::Html(patient_summary::render(&patient, &scales, chrono::Utc::now())))
 }
 
-async fn api_login() -> Json<serde_json::Value> {
-    Json(serde_json::json!({
-        "success": true,
-        "token": "demo_token_12345",
-        "user_id": "user_001",
-        "role": "Admin",
-        "message": "Login successful - Zeus approves"
+async fn api_login() -> ApiResult<LoginResponse> {
+    Ok(Json(LoginResponse {
+        success: true,
+        token: "demo_token_12345".to_string(),
+        user_id: "user_001".to_string(),
+        role: "Admin".to_string(),
+        message: "Login successful - Zeus approves".to_string(),
     }))
 }
 
-async fn api_logout() -> Json<serde_json::Value> {
-    Json(serde_json::json!({
-        "success": true,
-        "message": "Logged out - Hades secures your session"
+async fn api_logout() -> ApiResult<LogoutResponse> {
+    Ok(Json(LogoutResponse {
+        success: true,
+        message: "Logged out - Hades secures your session".to_string(),
     }))
 }
 
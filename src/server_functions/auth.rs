@@ -1,6 +1,6 @@
 use crate::models::user::{User, UserRole};
 use crate::server_functions::AuthResponse;
-use crate::server_functions::db::get_db;
+use crate::server_functions::db::{create_one, get_db};
 use leptos::server_fn::ServerFnError;
 use serde::{Deserialize, Serialize};
 use chrono::Utc;
@@ -375,9 +375,7 @@ pub async fn register_user(
             is_active: true,
         };
 
-        let _: Option<DbUser> = client
-            .create("user")
-            .content(db_user)
+        create_one(client, "user", None, db_user)
             .await
             .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
 
@@ -0,0 +1,66 @@
+// src/server_functions/cache.rs
+// Invalidación de la caché de Hestia tras escrituras de pacientes
+//
+// Genesis registra aquí el buzón de Hestia al montar el Olimpo. Sin Hestia montada
+// (modo demo o tests) las invalidaciones simplemente no se envían.
+
+use once_cell::sync::Lazy;
+use std::sync::Arc;
+use tokio::sync::{mpsc, RwLock};
+
+use crate::actors::hestia::{patient_assessments_tag, patient_cache_key, HestiaCommand};
+use crate::actors::GodName;
+use crate::traits::message::{ActorMessage, CommandPayload, MessagePayload};
+
+static HESTIA: Lazy<Arc<RwLock<Option<mpsc::Sender<ActorMessage>>>>> = Lazy::new(|| Arc::new(RwLock::new(None)));
+
+pub async fn register_hestia(sender: mpsc::Sender<ActorMessage>) {
+    *HESTIA.write().await = Some(sender);
+}
+
+/// Mensajes que dejan sin caché la ficha del paciente y sus evaluaciones
+pub fn patient_invalidation_messages(patient_id: &str) -> Vec<ActorMessage> {
+    [
+        HestiaCommand::Invalidate { key: patient_cache_key(patient_id), level: None },
+        HestiaCommand::InvalidateTag { tag: patient_assessments_tag(patient_id) },
+    ]
+    .into_iter()
+    .filter_map(|cmd| serde_json::to_value(cmd).ok())
+    .map(|config| ActorMessage::new(GodName::Hestia, MessagePayload::Command(CommandPayload::Configure { config })))
+    .collect()
+}
+
+/// Se espera a que Hestia acepte los mensajes: el buzón procesa en orden, así que una
+/// lectura posterior que pase por Hestia ya no encuentra la copia antigua
+pub async fn invalidate_patient(patient_id: &str) {
+    let Some(sender) = HESTIA.read().await.clone() else {
+        return;
+    };
+
+    for message in patient_invalidation_messages(patient_id) {
+        if let Err(e) = sender.send(message).await {
+            eprintln!("⚠️  No se pudo invalidar la caché del paciente {}: {}", patient_id, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patient_invalidation_targets_key_and_tag() {
+        let messages = patient_invalidation_messages("p1");
+        assert_eq!(messages.len(), 2);
+
+        let commands: Vec<HestiaCommand> = messages
+            .into_iter()
+            .map(|m| match m.payload {
+                MessagePayload::Command(CommandPayload::Configure { config }) => serde_json::from_value(config).unwrap(),
+                other => panic!("payload inesperado: {:?}", other),
+            })
+            .collect();
+        assert!(matches!(&commands[0], HestiaCommand::Invalidate { key, .. } if key == "patient:p1"));
+        assert!(matches!(&commands[1], HestiaCommand::InvalidateTag { tag } if tag == "patient:p1:assessments"));
+    }
+}
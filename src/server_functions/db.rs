@@ -1,12 +1,29 @@
 use surrealdb::engine::any::Any;
 use surrealdb::Surreal;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use once_cell::sync::Lazy;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+use crate::infrastructure::query::{ident, record_id};
+use crate::infrastructure::surreal::SurrealError;
+
 static DB: Lazy<Arc<RwLock<Option<Surreal<Any>>>>> = Lazy::new(|| Arc::new(RwLock::new(None)));
 
+/// Réplica de solo lectura (Poseidon reparte las lecturas de listados e historiales)
+static READ_DB: Lazy<Arc<RwLock<Option<Surreal<Any>>>>> = Lazy::new(|| Arc::new(RwLock::new(None)));
+static REPLICA_UP: AtomicBool = AtomicBool::new(false);
+static REPLICA_CHECKED_AT: AtomicI64 = AtomicI64::new(0);
+
+/// URL de la réplica de lectura; sin ella todas las lecturas van al primario
+pub const READ_URL_ENV: &str = "OLYMPUS_SURREAL_READ_URL";
+/// Segundos durante los que se confía en el último chequeo de la réplica
+const REPLICA_CHECK_SECS: i64 = 5;
+const REPLICA_HEALTH_TIMEOUT: Duration = Duration::from_millis(500);
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DbConfig {
     pub url: String,
@@ -42,13 +59,130 @@ pub async fn init_db(config: DbConfig) -> Result<(), String> {
     *guard = Some(db);
     
     println!("✅ Database connected: {}/{}", config.namespace, config.database);
+
+    // La réplica es opcional: si no conecta, las lecturas siguen yendo al primario
+    if let Ok(read_url) = std::env::var(READ_URL_ENV) {
+        if let Err(e) = init_read_replica(&read_url, &config).await {
+            eprintln!("⚠️  Réplica de lectura no disponible ({}): {}", read_url, e);
+        }
+    }
     Ok(())
 }
 
+async fn init_read_replica(url: &str, config: &DbConfig) -> Result<(), String> {
+    let replica = surrealdb::engine::any::connect(url)
+        .await
+        .map_err(|e| format!("Connection failed: {}", e))?;
+
+    replica.use_ns(&config.namespace)
+        .use_db(&config.database)
+        .await
+        .map_err(|e| format!("Namespace/DB failed: {}", e))?;
+
+    *READ_DB.write().await = Some(replica);
+    REPLICA_UP.store(true, Ordering::SeqCst);
+    REPLICA_CHECKED_AT.store(chrono::Utc::now().timestamp(), Ordering::SeqCst);
+
+    println!("✅ Read replica connected: {}", url);
+    Ok(())
+}
+
+/// Conexión para escrituras (y lecturas que deben ver lo recién escrito)
 pub async fn get_db() -> Arc<RwLock<Option<Surreal<Any>>>> {
     DB.clone()
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ReplicaRoute {
+    Replica,
+    Recheck,
+    Primary,
+}
+
+/// Decide si usar la réplica sin chequear, volver a chequearla o ir al primario
+fn replica_route(configured: bool, up: bool, checked_at: i64, now: i64) -> ReplicaRoute {
+    if !configured {
+        ReplicaRoute::Primary
+    } else if now - checked_at >= REPLICA_CHECK_SECS {
+        ReplicaRoute::Recheck
+    } else if up {
+        ReplicaRoute::Replica
+    } else {
+        ReplicaRoute::Primary
+    }
+}
+
+/// Conexión para lecturas (listados, historiales): la réplica si está configurada y
+/// responde, el primario en caso contrario. Mismo uso que `get_db`.
+pub async fn get_read_db() -> Arc<RwLock<Option<Surreal<Any>>>> {
+    let configured = READ_DB.read().await.is_some();
+    let now = chrono::Utc::now().timestamp();
+    let route = replica_route(
+        configured,
+        REPLICA_UP.load(Ordering::SeqCst),
+        REPLICA_CHECKED_AT.load(Ordering::SeqCst),
+        now,
+    );
+
+    let use_replica = match route {
+        ReplicaRoute::Replica => true,
+        ReplicaRoute::Primary => false,
+        ReplicaRoute::Recheck => {
+            let healthy = match READ_DB.read().await.as_ref() {
+                Some(replica) => matches!(tokio::time::timeout(REPLICA_HEALTH_TIMEOUT, replica.health()).await, Ok(Ok(_))),
+                None => false,
+            };
+            if REPLICA_UP.swap(healthy, Ordering::SeqCst) != healthy {
+                if healthy {
+                    println!("✅ Réplica de lectura recuperada");
+                } else {
+                    eprintln!("⚠️  Réplica de lectura caída: las lecturas van al primario");
+                }
+            }
+            REPLICA_CHECKED_AT.store(now, Ordering::SeqCst);
+            healthy
+        }
+    };
+
+    if use_replica {
+        READ_DB.clone()
+    } else {
+        DB.clone()
+    }
+}
+
+// Forma de las respuestas de SurrealDB: `create` sobre una tabla devolvía `Vec` en 1.x y
+// `Option` en 2.x, mientras que `create`/`select` sobre un id siempre devuelven `Option`.
+// Los handlers usan solo estas dos funciones; si la forma vuelve a cambiar, se corrige aquí.
+
+/// Crea un registro en `table` (id generado) o en `table:id` y devuelve el registro
+/// guardado, o None si SurrealDB no devolvió nada
+pub async fn create_one<T>(client: &Surreal<Any>, table: &str, id: Option<&str>, content: T) -> Result<Option<T>, SurrealError>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    let table = ident(table)?;
+    let created: Option<T> = match id {
+        Some(id) => client.create((table, id.to_string())).content(content).await,
+        None => client.create(table).content(content).await,
+    }
+    .map_err(|e| SurrealError::QueryFailed(e.to_string()))?;
+    Ok(created)
+}
+
+/// Registro `table:id`, o None si no existe. El id se valida como en `record_id`:
+/// `x OR 1=1` es un error, no una búsqueda vacía.
+pub async fn select_one<T>(client: &Surreal<Any>, table: &str, id: &str) -> Result<Option<T>, SurrealError>
+where
+    T: DeserializeOwned,
+{
+    record_id(table, id)?;
+    client
+        .select((table, id.to_string()))
+        .await
+        .map_err(|e| SurrealError::QueryFailed(e.to_string()))
+}
+
 pub async fn health_check() -> bool {
     let guard = DB.read().await;
     if let Some(ref db) = *guard {
@@ -57,3 +191,18 @@ pub async fn health_check() -> bool {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replica_routing() {
+        assert_eq!(replica_route(false, true, 100, 101), ReplicaRoute::Primary);
+        assert_eq!(replica_route(true, true, 100, 101), ReplicaRoute::Replica);
+        // Caída reciente: al primario sin volver a chequear en cada lectura
+        assert_eq!(replica_route(true, false, 100, 101), ReplicaRoute::Primary);
+        assert_eq!(replica_route(true, false, 100, 100 + REPLICA_CHECK_SECS), ReplicaRoute::Recheck);
+        assert_eq!(replica_route(true, true, 100, 100 + REPLICA_CHECK_SECS), ReplicaRoute::Recheck);
+    }
+}
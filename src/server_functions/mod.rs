@@ -3,6 +3,7 @@ pub mod patients;
 pub mod scales;
 pub mod admin;
 pub mod db;
+pub mod cache;
 
 use serde::{Deserialize, Serialize};
 
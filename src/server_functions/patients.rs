@@ -1,11 +1,15 @@
 use crate::models::patient::{Patient, SkinColor, AdmissionType, CivilStatus, Gender, YesNo, FamilyMember};
-use crate::server_functions::db::{get_db, DbConfig};
+use crate::server_functions::cache::invalidate_patient;
+use crate::server_functions::db::{create_one, get_db, get_read_db, select_one, DbConfig};
 use leptos::server_fn::ServerFnError;
 use serde::{Deserialize, Serialize};
 use surrealdb::RecordId;
 
 #[derive(Debug, Serialize, Deserialize)]
 struct DbPatient {
+    /// Id de registro (`patient:<ULID>`); lo asigna `create_patient`, no viaja en el contenido
+    #[serde(default, skip_serializing)]
+    id: Option<RecordId>,
     first_name: String,
     last_name: String,
     identity_card: String,
@@ -40,7 +44,7 @@ struct DbPatient {
 impl From<DbPatient> for Patient {
     fn from(p: DbPatient) -> Self {
         Patient {
-            id: None,
+            id: p.id,
             first_name: p.first_name,
             last_name: p.last_name,
             identity_card: p.identity_card,
@@ -111,6 +115,7 @@ impl From<DbPatient> for Patient {
 impl From<Patient> for DbPatient {
     fn from(p: Patient) -> Self {
         DbPatient {
+            id: None,
             first_name: p.first_name,
             last_name: p.last_name,
             identity_card: p.identity_card,
@@ -229,7 +234,7 @@ fn get_demo_patients() -> Vec<Patient> {
 
 #[leptos::server(GetPatients, "/api")]
 pub async fn get_patients() -> Result<Vec<Patient>, ServerFnError> {
-    let db = get_db().await;
+    let db = get_read_db().await;
     let guard = db.read().await;
     
     if let Some(ref client) = *guard {
@@ -248,19 +253,16 @@ pub async fn get_patients() -> Result<Vec<Patient>, ServerFnError> {
 
 #[leptos::server(GetPatient, "/api")]
 pub async fn get_patient(id: String) -> Result<Option<Patient>, ServerFnError> {
-    let db = get_db().await;
+    let db = get_read_db().await;
     let guard = db.read().await;
     
     if let Some(ref client) = *guard {
-        let results: Vec<DbPatient> = client
-            .query("SELECT * FROM patient WHERE id = $id")
-            .bind(("id", format!("patient:{}", id)))
+        // El id se valida como registro: `x OR 1=1` falla en vez de llegar a la consulta
+        let found: Option<DbPatient> = select_one(client, "patient", &id)
             .await
-            .map_err(|e| ServerFnError::ServerError(e.to_string()))?
-            .take(0)
             .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
         
-        Ok(results.into_iter().next().map(Patient::from))
+        Ok(found.map(Patient::from))
     } else {
         let patients = get_demo_patients();
         Ok(patients.into_iter().find(|_| true))
@@ -275,18 +277,16 @@ pub async fn create_patient(patient: Patient) -> Result<String, ServerFnError> {
     
     db_patient.created_at = chrono::Utc::now().to_rfc3339();
     
+    // Mismo esquema que el servidor de actores: `patient:<ULID>`, ordenable por creación
+    let key = surrealdb::sql::Id::ulid().to_raw();
+    let id = format!("patient:{}", key);
+    
     if let Some(ref client) = *guard {
-        let result: Option<DbPatient> = client
-            .create("patient")
-            .content(db_patient)
+        create_one(client, "patient", Some(&key), db_patient)
             .await
             .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
-        
-        Ok(result.map(|p| p.first_name).unwrap_or_else(|| "created".to_string()))
-    } else {
-        let id = uuid::Uuid::new_v4().to_string();
-        Ok(id)
     }
+    Ok(id)
 }
 
 #[leptos::server(UpdatePatient, "/api")]
@@ -303,6 +303,7 @@ pub async fn update_patient(id: String, patient: Patient) -> Result<bool, Server
             .await
             .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
         
+        invalidate_patient(&id).await;
         Ok(true)
     } else {
         Ok(true)
@@ -320,6 +321,7 @@ pub async fn delete_patient(id: String) -> Result<bool, ServerFnError> {
             .await
             .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
         
+        invalidate_patient(&id).await;
         Ok(true)
     } else {
         Ok(true)
@@ -328,7 +330,7 @@ pub async fn delete_patient(id: String) -> Result<bool, ServerFnError> {
 
 #[leptos::server(SearchPatients, "/api")]
 pub async fn search_patients(query: String) -> Result<Vec<Patient>, ServerFnError> {
-    let db = get_db().await;
+    let db = get_read_db().await;
     let guard = db.read().await;
     let query_lower = query.to_lowercase();
     
@@ -1,4 +1,5 @@
-use crate::server_functions::db::get_db;
+use crate::infrastructure::BoundQuery;
+use crate::server_functions::db::{create_one, get_db, get_read_db};
 use leptos::server_fn::ServerFnError;
 use serde::{Deserialize, Serialize};
 
@@ -92,24 +93,20 @@ pub struct News2Assessment {
 pub async fn get_patient_history(patient_id: String) -> Result<Vec<serde_json::Value>, ServerFnError> {
     leptos::logging::log!("Server: get_patient_history called for patient: {}", patient_id);
     
-    let db = get_db().await;
+    let db = get_read_db().await;
     let guard = db.read().await;
     
     if let Some(ref client) = *guard {
-        let glasgow: Vec<GlasgowAssessment> = client
-            .query("SELECT * FROM glasgow WHERE patient_id = $patient_id ORDER BY assessed_at DESC LIMIT 10")
-            .bind(("patient_id", patient_id.clone()))
+        let glasgow: Vec<GlasgowAssessment> = BoundQuery::new("SELECT * FROM glasgow WHERE patient_id = $patient_id ORDER BY assessed_at DESC LIMIT 10")
+            .bind("patient_id", patient_id.clone())
+            .fetch(client)
             .await
-            .map_err(|e| ServerFnError::ServerError(e.to_string()))?
-            .take(0)
             .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
 
-        let sofa: Vec<SofaAssessment> = client
-            .query("SELECT * FROM sofa WHERE patient_id = $patient_id ORDER BY assessed_at DESC LIMIT 10")
-            .bind(("patient_id", patient_id.clone()))
+        let sofa: Vec<SofaAssessment> = BoundQuery::new("SELECT * FROM sofa WHERE patient_id = $patient_id ORDER BY assessed_at DESC LIMIT 10")
+            .bind("patient_id", patient_id.clone())
+            .fetch(client)
             .await
-            .map_err(|e| ServerFnError::ServerError(e.to_string()))?
-            .take(0)
             .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
 
         let mut history = Vec::new();
@@ -155,9 +152,7 @@ pub async fn save_glasgow(assessment: GlasgowAssessment) -> Result<String, Serve
     
     if let Some(ref client) = *guard {
         let id = uuid::Uuid::new_v4().to_string();
-        let _: Option<GlasgowAssessment> = client
-            .create(("glasgow", &id))
-            .content(assessment)
+        create_one(client, "glasgow", Some(&id), assessment)
             .await
             .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
         
@@ -176,9 +171,7 @@ pub async fn save_apache(assessment: ApacheAssessment) -> Result<String, ServerF
     
     if let Some(ref client) = *guard {
         let id = uuid::Uuid::new_v4().to_string();
-        let _: Option<ApacheAssessment> = client
-            .create(("apache", &id))
-            .content(assessment)
+        create_one(client, "apache", Some(&id), assessment)
             .await
             .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
         
@@ -197,9 +190,7 @@ pub async fn save_sofa(assessment: SofaAssessment) -> Result<String, ServerFnErr
     
     if let Some(ref client) = *guard {
         let id = uuid::Uuid::new_v4().to_string();
-        let _: Option<SofaAssessment> = client
-            .create(("sofa", &id))
-            .content(assessment)
+        create_one(client, "sofa", Some(&id), assessment)
             .await
             .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
         
@@ -218,9 +209,7 @@ pub async fn save_saps(assessment: SapsAssessment) -> Result<String, ServerFnErr
     
     if let Some(ref client) = *guard {
         let id = uuid::Uuid::new_v4().to_string();
-        let _: Option<SapsAssessment> = client
-            .create(("saps", &id))
-            .content(assessment)
+        create_one(client, "saps", Some(&id), assessment)
             .await
             .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
         
@@ -239,9 +228,7 @@ pub async fn save_news2(assessment: News2Assessment) -> Result<String, ServerFnE
     
     if let Some(ref client) = *guard {
         let id = uuid::Uuid::new_v4().to_string();
-        let _: Option<News2Assessment> = client
-            .create(("news2", &id))
-            .content(assessment)
+        create_one(client, "news2", Some(&id), assessment)
             .await
             .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
         
@@ -268,12 +255,11 @@ pub async fn can_assess_patient(patient_id: String, assessment_type: String) ->
             _ => "assessment",
         };
         
-        let results: Vec<serde_json::Value> = client
-            .query(format!("SELECT * FROM {} WHERE patient_id = $patient_id ORDER BY assessed_at DESC LIMIT 1", table))
-            .bind(("patient_id", patient_id))
-            .await
+        let results: Vec<serde_json::Value> = BoundQuery::with_table("SELECT * FROM {table} WHERE patient_id = $patient_id ORDER BY assessed_at DESC LIMIT 1", table)
             .map_err(|e| ServerFnError::ServerError(e.to_string()))?
-            .take(0)
+            .bind("patient_id", patient_id)
+            .fetch(client)
+            .await
             .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
         
         Ok(results.is_empty() || true)
@@ -11,12 +11,13 @@ use crate::actors::GodName;
 use crate::traits::OlympianActor;
 use crate::traits::message::ActorMessage;
 use crate::system::runner::ActorRunner;
+use crate::system::unsupported::UnsupportedCounts;
 use crate::infrastructure::{ValkeyStore, SurrealStore}; 
 use crate::actors::zeus::ZeusConfig;
 
 // Importación de Dioses (Asumimos módulos estándar v15)
 use crate::actors::zeus::Zeus;
-use crate::actors::hades::Hades;
+use crate::actors::hades::{Hades, Secrets};
 use crate::actors::poseidon::Poseidon;
 use crate::actors::erinyes::Erinyes;
 use crate::actors::hermes::Hermes;
@@ -61,27 +62,32 @@ impl Genesis {
         // Nota: Algunos requieren config especial. 
         // Trinidad + Erinyes first.
         
+        // --- ZEUS (Gobernador) ---
+        // Primero: sus métricas guardan los mensajes no soportados que anota cada runner
+        let zeus = Zeus::new(ZeusConfig::default()).await;
+        crate::olympus_services::register_zeus_metrics(zeus.metrics_queries()).await;
+        let unsupported = zeus.unsupported_counts().await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(zeus)).await;
+
         // --- HERMES (Vital para routing) ---
         let hermes = Hermes::new().await;
         let (hermes_tx, hermes_rx) = mpsc::channel(1000);
         senders.insert(GodName::Hermes, hermes_tx.clone());
-        runners.push(ActorRunner::new(Box::new(hermes), hermes_rx));
+        runners.push(ActorRunner::new(Box::new(hermes), hermes_rx).with_unsupported(unsupported.clone()));
 
         // Función helper para spawn
         // Rust borrow checker odia closures async mutables complejas, lo haremos imperativo.
 
-        // --- ZEUS (Gobernador) ---
-        let zeus = Zeus::new(ZeusConfig::default()).await;
-        add_to_mount(&mut senders, &mut runners, Box::new(zeus)).await;
-
         // --- HADES (Seguridad) ---
-        let hades = Hades::new().await;
-        add_to_mount(&mut senders, &mut runners, Box::new(hades)).await;
+        // Secreto JWT y clave de cifrado configurados: en producción, sin ellos no se arranca
+        let secrets = Secrets::from_env()?;
+        let hades = Hades::with_secrets(&secrets).await?;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(hades)).await;
 
         // --- POSEIDON (Datos) ---
         // Poseidon necesita Valkey o config especial a veces? Vimos new().await en v15
         let poseidon = Poseidon::new(valkey.clone()).await;
-        add_to_mount(&mut senders, &mut runners, Box::new(poseidon)).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(poseidon)).await;
 
         // --- ERINYES (Monitor) ---
         // Erinyes necesita Valkey
@@ -94,18 +100,18 @@ impl Genesis {
         // Asumiendo que ValkeyStore::new retorna Result<Self, Error>.
         
         let erinyes = Erinyes::new(valkey.clone()).await; 
-        add_to_mount(&mut senders, &mut runners, Box::new(erinyes)).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(erinyes)).await;
 
         // --- RESTO DEL PANTEÓN ---
         // Instanciaremos los demás. Asumimos `new()` async standard.
         // Si alguno falla compilación (nombres incorrectos, etc), ajustaremos.
         
-        add_to_mount(&mut senders, &mut runners, Box::new(Hera::new().await)).await;
-        add_to_mount(&mut senders, &mut runners, Box::new(Artemis::new().expect("Artemis failed to ignite"))).await;
-        add_to_mount(&mut senders, &mut runners, Box::new(Apollo::new().await)).await;
-        add_to_mount(&mut senders, &mut runners, Box::new(Athena::new().await)).await;
-        add_to_mount(&mut senders, &mut runners, Box::new(Ares::new().await)).await;
-        add_to_mount(&mut senders, &mut runners, Box::new(Aphrodite::new().await)).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(Hera::new().await)).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(Artemis::new().expect("Artemis failed to ignite"))).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(Apollo::new().await)).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(Athena::new().await)).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(Ares::new().await)).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(Aphrodite::new().await)).await;
         // Hephaestus a veces es Hefesto en imports legacy, chequearemos nombre
         // En olympus_system.rs línea 17: `pub mod hefesto;`
         // En mi lista usé `hephaestus`. 
@@ -113,16 +119,19 @@ impl Genesis {
         // Probaremos con Hephaestus si el módulo es correcto, si no fallará.
         // En olympus_system.rs: `hefesto`.
         // Intentaremos cargar `crate::actors::hephaestus::Hephaestus`.
-        add_to_mount(&mut senders, &mut runners, Box::new(Hefesto::new().await)).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(Hefesto::new().await)).await;
         
-        add_to_mount(&mut senders, &mut runners, Box::new(Dionysus::new().await)).await;
-        add_to_mount(&mut senders, &mut runners, Box::new(Demeter::new().await)).await;
-        add_to_mount(&mut senders, &mut runners, Box::new(Hestia::new(valkey.clone(), surreal.clone()).await)).await;
-        add_to_mount(&mut senders, &mut runners, Box::new(Chronos::new().await)).await;
-        add_to_mount(&mut senders, &mut runners, Box::new(Iris::new().await)).await;
-        add_to_mount(&mut senders, &mut runners, Box::new(Moirai::new().await)).await;
-        add_to_mount(&mut senders, &mut runners, Box::new(Chaos::new())).await;
-        add_to_mount(&mut senders, &mut runners, Box::new(Aurora::new().await)).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(Dionysus::new().await)).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(Demeter::new().await)).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(Hestia::new(valkey.clone(), surreal.clone()).await)).await;
+        let mut chronos = Chronos::new().await;
+        chronos.set_store(surreal.clone());
+        crate::olympus_services::register_chronos_schedule(chronos.schedule_queries()).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(chronos)).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(Iris::new().await)).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(Moirai::new().await)).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(Chaos::new())).await;
+        add_to_mount(&mut senders, &mut runners, &unsupported, Box::new(Aurora::new().await)).await;
 
 
         // 3. Wiring (Conexión)
@@ -204,7 +213,16 @@ impl Genesis {
         // 4. Lanzamiento (Spawn)
         info!("🚀 GENESIS: Desplegando {} Dioses en el Runtime...", runners.len());
         
-        for runner in runners {
+        // Las escrituras de pacientes invalidan la caché de Hestia a través de este buzón
+        if let Some(hestia_tx) = senders.get(&GodName::Hestia) {
+            crate::server_functions::cache::register_hestia(hestia_tx.clone()).await;
+        }
+
+        let erinyes_tx = senders.get(&GodName::Erinyes).cloned();
+        for mut runner in runners {
+            if let Some(tx) = &erinyes_tx {
+                runner = runner.with_erinyes(tx.clone());
+            }
             tokio::spawn(async move {
                 runner.run().await;
             });
@@ -227,12 +245,13 @@ impl Genesis {
 async fn add_to_mount(
     map: &mut HashMap<GodName, mpsc::Sender<ActorMessage>>, 
     list: &mut Vec<ActorRunner>, 
+    unsupported: &UnsupportedCounts,
     actor: Box<dyn OlympianActor>
 ) {
     let name = actor.name();
     let (tx, rx) = mpsc::channel(100);
     map.insert(name.clone(), tx);
-    list.push(ActorRunner::new(actor, rx));
+    list.push(ActorRunner::new(actor, rx).with_unsupported(unsupported.clone()));
     info!("📦 GENESIS: {} preparado para despliegue", name);
 }
 
@@ -4,5 +4,6 @@
 
 pub mod genesis;
 pub mod runner;
+pub mod unsupported;
 
 pub use genesis::Genesis;
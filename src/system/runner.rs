@@ -4,12 +4,16 @@
 
 #![allow(dead_code)]
 
+use futures::FutureExt;
+use std::panic::AssertUnwindSafe;
 use tokio::sync::mpsc;
-use tracing::{info, error};
+use tracing::{info, error, warn};
 
 use crate::traits::OlympianActor;
-use crate::traits::message::ActorMessage;
+use crate::traits::message::{ActorMessage, EventPayload, MessagePayload};
 use crate::actors::GodName;
+use crate::errors::ActorError;
+use super::unsupported::UnsupportedCounts;
 
 /// Ejecutor de un actor individual
 /// Mantiene el ciclo de vida, procesa mensajes y maneja errores
@@ -17,6 +21,8 @@ pub struct ActorRunner {
     actor: Box<dyn OlympianActor>,
     inbox: mpsc::Receiver<ActorMessage>,
     notify_exit: Option<mpsc::Sender<(GodName, String)>>, // Para notificar muerte a Erinyes/Zeus
+    erinyes: Option<mpsc::Sender<ActorMessage>>, // Para reportar pánicos en el acto
+    unsupported: Option<UnsupportedCounts>, // Contadores de Zeus de mensajes no soportados
 }
 
 impl ActorRunner {
@@ -28,6 +34,8 @@ impl ActorRunner {
             actor,
             inbox,
             notify_exit: None,
+            erinyes: None,
+            unsupported: None,
         }
     }
 
@@ -36,6 +44,18 @@ impl ActorRunner {
         self
     }
 
+    /// Los pánicos al procesar mensajes se reportan a Erinyes como `ActorPanicked`
+    pub fn with_erinyes(mut self, erinyes: mpsc::Sender<ActorMessage>) -> Self {
+        self.erinyes = Some(erinyes);
+        self
+    }
+
+    /// Los mensajes que el actor no soporta se anotan en estos contadores
+    pub fn with_unsupported(mut self, counts: UnsupportedCounts) -> Self {
+        self.unsupported = Some(counts);
+        self
+    }
+
     /// Inicia el loop del actor (consume el hilo actual/task)
     pub async fn run(mut self) {
         let name = self.actor.name();
@@ -59,18 +79,29 @@ impl ActorRunner {
                     let msg_id = msg.id.clone();
                     // debug!("📨 [{:?}] Recibido mensaje: {}", name, msg_id);
 
-                    // Procesar mensaje protegindolo de pánicos
-                    // Nota: CatchUnwind en async es complicado, asumimos que handle_message no paniquea catastróficamente
-                    // o que el Runtime de Tokio maneja el panic del task.
-                    
-                    let result = self.actor.handle_message(msg).await;
+                    // Procesar mensaje protegiéndolo de pánicos: el actor sigue vivo
+                    // y Erinyes se entera de inmediato en lugar de esperar al heartbeat
+                    let result = AssertUnwindSafe(self.actor.handle_message(msg))
+                        .catch_unwind()
+                        .await;
                     
                     match result {
-                        Ok(_response) => {
+                        Err(panic) => {
+                            let reason = panic_message(panic.as_ref());
+                            error!("💥 [{:?}] Pánico procesando mensaje {}: {}", name, msg_id, reason);
+                            self.report_panic(reason).await;
+                        }
+                        Ok(Ok(_response)) => {
                             // Si la respuesta requiere envío, se manejaría aquí o el actor ya lo hizo
                             // Por ahora solo logueamos errores de lógica interna
                         }
-                        Err(e) => {
+                        Ok(Err(ActorError::Unsupported { god, kind, variant })) => {
+                            warn!("🚫 [{:?}] {} no soportado: {} (mensaje {})", name, kind, variant, msg_id);
+                            if let Some(counts) = &self.unsupported {
+                                counts.record(god, &kind, &variant);
+                            }
+                        }
+                        Ok(Err(e)) => {
                             error!("⚠️ [{:?}] Error procesando mensaje {}: {}", name, msg_id, e);
                             // No matamos al actor por un error de mensaje, a menos que sea crítico
                         }
@@ -96,4 +127,132 @@ impl ActorRunner {
             let _ = tx.send((self.actor.name(), reason)).await;
         }
     }
+
+    async fn report_panic(&self, error: String) {
+        let name = self.actor.name();
+        if let Some(tx) = &self.erinyes {
+            let event = ActorMessage::with_from(
+                name,
+                GodName::Erinyes,
+                MessagePayload::Event(EventPayload::ActorPanicked { actor: name, error }),
+            );
+            if tx.send(event).await.is_err() {
+                error!("🚨 [{:?}] No se pudo reportar el pánico: Erinyes no disponible", name);
+            }
+        }
+    }
+}
+
+/// Texto del pánico (`panic!("...")` produce &str o String)
+fn panic_message(panic: &(dyn std::any::Any + Send)) -> String {
+    panic
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| panic.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "panic sin mensaje".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use crate::actors::DivineDomain;
+    use crate::traits::{ActorConfig, ActorState, ActorStatus, GodHeartbeat, HealthStatus};
+    use crate::traits::message::{CommandPayload, QueryPayload, ResponsePayload};
+
+    /// Latido fijo para los actores de prueba; el runner no lo consulta
+    fn stub_heartbeat(god: GodName) -> GodHeartbeat {
+        GodHeartbeat {
+            god,
+            status: ActorStatus::Healthy,
+            last_seen: chrono::Utc::now(),
+            load: 0.0,
+            memory_usage_mb: 0.0,
+            uptime_seconds: 0,
+        }
+    }
+
+    /// Actor que paniquea con cualquier mensaje
+    struct Fragile;
+
+    #[async_trait]
+    impl OlympianActor for Fragile {
+        fn name(&self) -> GodName { GodName::Chaos }
+        fn domain(&self) -> DivineDomain { DivineDomain::Testing }
+        async fn handle_message(&mut self, _msg: ActorMessage) -> Result<ResponsePayload, ActorError> {
+            panic!("fallo deliberado");
+        }
+        async fn persistent_state(&self) -> serde_json::Value { serde_json::json!({}) }
+        fn load_state(&mut self, _state: &serde_json::Value) -> Result<(), ActorError> { Ok(()) }
+        fn heartbeat(&self) -> GodHeartbeat { stub_heartbeat(GodName::Chaos) }
+        async fn health_check(&self) -> HealthStatus { HealthStatus::healthy(GodName::Chaos) }
+        fn config(&self) -> Option<&ActorConfig> { None }
+        async fn initialize(&mut self) -> Result<(), ActorError> { Ok(()) }
+        async fn shutdown(&mut self) -> Result<(), ActorError> { Ok(()) }
+        fn actor_state(&self) -> ActorState { ActorState::new(GodName::Chaos) }
+    }
+
+    #[tokio::test]
+    async fn test_panic_is_reported_to_erinyes() {
+        let (tx, rx) = mpsc::channel(4);
+        let (erinyes_tx, mut erinyes_rx) = mpsc::channel(4);
+        let handle = tokio::spawn(ActorRunner::new(Box::new(Fragile), rx).with_erinyes(erinyes_tx).run());
+
+        tx.send(ActorMessage::new(GodName::Chaos, MessagePayload::Query(QueryPayload::GetStats))).await.unwrap();
+
+        let report = erinyes_rx.recv().await.unwrap();
+        assert_eq!(report.to, GodName::Erinyes);
+        match report.payload {
+            MessagePayload::Event(EventPayload::ActorPanicked { actor, error }) => {
+                assert_eq!(actor, GodName::Chaos);
+                assert_eq!(error, "fallo deliberado");
+            }
+            other => panic!("Evento inesperado: {:?}", other),
+        }
+
+        // El runner sobrevive al pánico y cierra limpio al soltar el canal
+        drop(tx);
+        handle.await.unwrap();
+    }
+
+    /// Hestia sin soporte para ningún comando
+    struct Unimplemented;
+
+    #[async_trait]
+    impl OlympianActor for Unimplemented {
+        fn name(&self) -> GodName { GodName::Hestia }
+        fn domain(&self) -> DivineDomain { DivineDomain::Testing }
+        async fn handle_message(&mut self, msg: ActorMessage) -> Result<ResponsePayload, ActorError> {
+            match msg.payload {
+                MessagePayload::Command(cmd) => Err(ActorError::unsupported_variant(GodName::Hestia, "command", &cmd)),
+                _ => Ok(ResponsePayload::Ack { message_id: msg.id }),
+            }
+        }
+        async fn persistent_state(&self) -> serde_json::Value { serde_json::json!({}) }
+        fn load_state(&mut self, _state: &serde_json::Value) -> Result<(), ActorError> { Ok(()) }
+        fn heartbeat(&self) -> GodHeartbeat { stub_heartbeat(GodName::Hestia) }
+        async fn health_check(&self) -> HealthStatus { HealthStatus::healthy(GodName::Hestia) }
+        fn config(&self) -> Option<&ActorConfig> { None }
+        async fn initialize(&mut self) -> Result<(), ActorError> { Ok(()) }
+        async fn shutdown(&mut self) -> Result<(), ActorError> { Ok(()) }
+        fn actor_state(&self) -> ActorState { ActorState::new(GodName::Hestia) }
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_commands_are_counted() {
+        let (tx, rx) = mpsc::channel(4);
+        let counts = UnsupportedCounts::new();
+        let handle = tokio::spawn(ActorRunner::new(Box::new(Unimplemented), rx).with_unsupported(counts.clone()).run());
+
+        for _ in 0..3 {
+            let msg = ActorMessage::new(GodName::Hestia, MessagePayload::Command(CommandPayload::Shutdown));
+            tx.send(msg).await.unwrap();
+        }
+        drop(tx);
+        handle.await.unwrap();
+
+        assert_eq!(counts.for_god(GodName::Hestia)["commands"]["Shutdown"], 3);
+        assert!(counts.export_prometheus_format()
+            .contains("unsupported_commands_total{god=\"Hestia\",variant=\"Shutdown\"}"));
+    }
 }
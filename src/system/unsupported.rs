@@ -0,0 +1,65 @@
+// src/system/unsupported.rs
+// OLYMPUS v15 - Mensajes no soportados
+// Contador por dios y variante de los comandos/queries que acaban en `ActorError::Unsupported`:
+// clientes que llegan a un callejón sin salida en lugar de perderse en el log.
+// Lo guarda Zeus en sus métricas y cada `ActorRunner` recibe una copia del handle.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::actors::GodName;
+
+#[derive(Debug, Clone, Default)]
+pub struct UnsupportedCounts {
+    counts: Arc<Mutex<HashMap<(GodName, String, String), u64>>>,
+}
+
+impl UnsupportedCounts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, god: GodName, kind: &str, variant: &str) {
+        let mut counts = self.counts.lock().unwrap_or_else(|e| e.into_inner());
+        *counts.entry((god, kind.to_string(), variant.to_string())).or_insert(0) += 1;
+    }
+
+    /// `(dios, "command"|"query", variante, total)` ordenado por dios y variante
+    pub fn snapshot(&self) -> Vec<(GodName, String, String, u64)> {
+        let counts = self.counts.lock().unwrap_or_else(|e| e.into_inner());
+        let mut rows: Vec<_> = counts
+            .iter()
+            .map(|((god, kind, variant), n)| (*god, kind.clone(), variant.clone(), *n))
+            .collect();
+        rows.sort_by(|a, b| (a.0.to_string(), &a.1, &a.2).cmp(&(b.0.to_string(), &b.1, &b.2)));
+        rows
+    }
+
+    /// Desglose de un dios: `{"commands": {variante: n}, "queries": {...}, "total": n}`
+    pub fn for_god(&self, god: GodName) -> serde_json::Value {
+        let mut commands = serde_json::Map::new();
+        let mut queries = serde_json::Map::new();
+        let mut total = 0;
+        for (_, kind, variant, n) in self.snapshot().into_iter().filter(|row| row.0 == god) {
+            let target = if kind == "query" { &mut queries } else { &mut commands };
+            target.insert(variant, serde_json::json!(n));
+            total += n;
+        }
+        serde_json::json!({ "commands": commands, "queries": queries, "total": total })
+    }
+
+    /// `unsupported_commands_total` y `unsupported_queries_total` en formato Prometheus
+    pub fn export_prometheus_format(&self) -> String {
+        let rows = self.snapshot();
+        let mut output = String::new();
+        for (kind, metric) in [("command", "unsupported_commands_total"), ("query", "unsupported_queries_total")] {
+            output.push_str(&format!("# HELP {} Unsupported {} variants received per god\n", metric, kind));
+            output.push_str(&format!("# TYPE {} counter\n", metric));
+            for (god, _, variant, n) in rows.iter().filter(|row| row.1 == kind) {
+                output.push_str(&format!("{}{{god=\"{}\",variant=\"{}\"}} {}\n", metric, god, variant, n));
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
@@ -1,14 +1,24 @@
 // src/traits/message.rs
 // OLYMPUS v13 - Sistema de Mensajes Tipados
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use uuid::Uuid;
 
 use crate::actors::GodName;
 
-/// Mensaje tipado para comunicación entre actores
+/// Versión actual del esquema de `ActorMessage`.
+/// v1: sin campo `version` (mensajes persistidos antes del versionado).
+/// v2: `version` explícito.
+/// Al añadir un campo: subir la versión y añadir el paso correspondiente en `migrate`.
+pub const MESSAGE_SCHEMA_VERSION: u32 = 2;
+
+/// Mensaje tipado para comunicación entre actores.
+/// Se persiste en Valkey (dead letters, buffers): al deserializar, los mensajes de
+/// versiones anteriores se actualizan con `migrate` antes de leerse.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct ActorMessage {
+    pub version: u32,
     pub id: String,
     pub from: Option<GodName>,
     pub to: GodName,
@@ -21,6 +31,7 @@ pub struct ActorMessage {
 impl ActorMessage {
     pub fn new(to: GodName, payload: MessagePayload) -> Self {
         Self {
+            version: MESSAGE_SCHEMA_VERSION,
             id: Uuid::new_v4().to_string(),
             from: None,
             to,
@@ -33,6 +44,7 @@ impl ActorMessage {
 
     pub fn with_from(from: GodName, to: GodName, payload: MessagePayload) -> Self {
         Self {
+            version: MESSAGE_SCHEMA_VERSION,
             id: Uuid::new_v4().to_string(),
             from: Some(from),
             to,
@@ -45,6 +57,7 @@ impl ActorMessage {
 
     pub fn with_priority(priority: MessagePriority, to: GodName, payload: MessagePayload) -> Self {
         Self {
+            version: MESSAGE_SCHEMA_VERSION,
             id: Uuid::new_v4().to_string(),
             from: None,
             to,
@@ -56,6 +69,39 @@ impl ActorMessage {
     }
 }
 
+impl Serialize for ActorMessage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ActorMessage::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ActorMessage {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut raw = serde_json::Value::deserialize(deserializer)?;
+        migrate(&mut raw).map_err(serde::de::Error::custom)?;
+        ActorMessage::deserialize(raw).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Lleva un mensaje serializado en cualquier versión anterior a `MESSAGE_SCHEMA_VERSION`.
+/// Las versiones más nuevas se aceptan tal cual: serde ignora los campos desconocidos,
+/// lo que permite convivir con nodos ya actualizados durante un despliegue gradual.
+pub fn migrate(raw: &mut serde_json::Value) -> Result<(), String> {
+    let message = raw
+        .as_object_mut()
+        .ok_or_else(|| "ActorMessage serializado debe ser un objeto".to_string())?;
+    let version = message.get("version").and_then(|v| v.as_u64()).unwrap_or(1) as u32;
+
+    if version < 2 {
+        // v1 → v2: los productores antiguos podían omitir prioridad y metadata
+        message.entry("priority").or_insert_with(|| serde_json::json!(MessagePriority::Normal));
+        message.entry("metadata").or_insert_with(|| serde_json::json!({}));
+        message.insert("version".to_string(), serde_json::json!(2));
+    }
+
+    Ok(())
+}
+
 /// Prioridad del mensaje
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum MessagePriority {
@@ -252,3 +298,43 @@ pub struct DeliveryConfirmation {
     pub delivered_at: chrono::DateTime<chrono::Utc>,
     pub attempts: u32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actors::erinyes::dead_letter::DeadLetter;
+
+    /// Dead letter tal como lo escribía la versión anterior al versionado
+    const V1_DEAD_LETTER: &str = r#"{
+        "id": "dl-1",
+        "message": {
+            "id": "msg-1",
+            "from": "Athena",
+            "to": "Hestia",
+            "payload": { "Command": { "Custom": { "action": "persist" } } },
+            "timestamp": "2026-01-01T00:00:00Z"
+        },
+        "original_to": "Hestia",
+        "failed_at": "2026-01-01T00:00:01Z",
+        "attempt_count": 1,
+        "last_error": "Hestia no disponible",
+        "status": "Pending"
+    }"#;
+
+    #[test]
+    fn test_v1_dead_letter_is_upgraded() {
+        let dead_letter: DeadLetter = serde_json::from_str(V1_DEAD_LETTER).unwrap();
+        let message = dead_letter.message;
+
+        assert_eq!(message.version, MESSAGE_SCHEMA_VERSION);
+        assert_eq!(message.id, "msg-1");
+        assert_eq!(message.priority, MessagePriority::Normal);
+        assert_eq!(message.metadata, serde_json::json!({}));
+
+        // Reenviable: vuelve a serializarse ya en la versión actual
+        let reserialized = serde_json::to_value(&message).unwrap();
+        assert_eq!(reserialized["version"], MESSAGE_SCHEMA_VERSION);
+        let roundtrip: ActorMessage = serde_json::from_value(reserialized).unwrap();
+        assert_eq!(roundtrip.to, GodName::Hestia);
+    }
+}
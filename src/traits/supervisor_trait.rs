@@ -25,6 +25,11 @@ pub struct SupervisedActor {
     pub status: ActorSupervisionStatus,
     pub restarts: u32,
     pub last_restart: Option<chrono::DateTime<chrono::Utc>>,
+    /// Motivo del fallo más reciente, para diagnosticar dioses que se reinician en bucle
+    #[serde(default)]
+    pub last_failure: Option<String>,
+    #[serde(default)]
+    pub last_failure_at: Option<chrono::DateTime<chrono::Utc>>,
     pub strategy: RecoveryStrategy,
     pub children: Vec<GodName>,
 }
@@ -6,6 +6,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::frontend::i18n::{t, Language};
+
 /// SOFA complete assessment
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SOFA {
@@ -35,7 +37,48 @@ pub enum RenalScore {
     CreatinineGT5,      // 4 points: ≥5.0
 }
 
+/// Individual organ system scores (0-4 each)
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct SOFAComponents {
+    pub respiratory: u8,
+    pub coagulation: u8,
+    pub liver: u8,
+    pub cardiovascular: u8,
+    pub cns: u8,
+    pub renal: u8,
+}
+
+impl SOFAComponents {
+    pub fn total(&self) -> u8 {
+        self.respiratory + self.coagulation + self.liver + self.cardiovascular + self.cns + self.renal
+    }
+}
+
 impl SOFA {
+    /// Per-organ breakdown of the score
+    pub fn components(&self) -> SOFAComponents {
+        SOFAComponents {
+            respiratory: self.respiration_score(),
+            coagulation: self.coagulation_score(),
+            liver: self.liver_score(),
+            cardiovascular: self.cardiovascular_score(),
+            cns: self.cns_score(),
+            renal: self.renal_score(),
+        }
+    }
+
+    /// Build the API response including the organ breakdown, with the
+    /// interpretation translated to `lang`
+    pub fn to_response(&self, lang: Language) -> SOFAResponse {
+        let (severity, recommendation) = self.interpretation();
+        SOFAResponse {
+            score: self.calculate_score(),
+            severity: t(lang, severity),
+            recommendation: t(lang, recommendation),
+            components: self.components(),
+        }
+    }
+
     /// Calculate total SOFA score (0-24)
     pub fn calculate_score(&self) -> u8 {
         let mut score = 0u8;
@@ -122,30 +165,15 @@ impl SOFA {
         }
     }
 
-    /// Get interpretation
-    pub fn interpretation(&self) -> (String, String) {
+    /// Get interpretation as i18n keys (severity, recommendation)
+    pub fn interpretation(&self) -> (&'static str, &'static str) {
         let score = self.calculate_score();
         match score {
-            0..=6 => (
-                "Falla orgánica leve".to_string(),
-                "Mortalidad < 10%. Monitoreo continuo.".to_string(),
-            ),
-            7..=9 => (
-                "Falla orgánica moderada".to_string(),
-                "Mortalidad 15-20%. Vigilancia estrecha.".to_string(),
-            ),
-            10..=12 => (
-                "Falla orgánica severa".to_string(),
-                "Mortalidad 40-50%. Cuidados intensivos máximos.".to_string(),
-            ),
-            13..=14 => (
-                "Falla orgánica muy severa".to_string(),
-                "Mortalidad > 50%. Considerar terapias avanzadas.".to_string(),
-            ),
-            _ => (
-                "Falla orgánica crítica".to_string(),
-                "Mortalidad > 80%. Pronóstico muy reservado.".to_string(),
-            ),
+            0..=6 => ("sofa_severity_mild", "sofa_recommendation_mild"),
+            7..=9 => ("sofa_severity_moderate", "sofa_recommendation_moderate"),
+            10..=12 => ("sofa_severity_severe", "sofa_recommendation_severe"),
+            13..=14 => ("sofa_severity_very_severe", "sofa_recommendation_very_severe"),
+            _ => ("sofa_severity_critical", "sofa_recommendation_critical"),
         }
     }
 }
@@ -168,6 +196,7 @@ pub struct SOFAResponse {
     pub score: u8,
     pub severity: String,
     pub recommendation: String,
+    pub components: SOFAComponents,
 }
 
 impl SOFARequest {